@@ -8,11 +8,11 @@ use openlimits::model::websocket::WebSocketResponse::Generic;
 
 #[tokio::main]
 async fn main() {
-    let binance_websocket = OpenLimitsWs {
-        websocket: BinanceWebsocket::new(BinanceParameters::prod())
+    let binance_websocket = OpenLimitsWs::new(
+        BinanceWebsocket::new(BinanceParameters::prod())
             .await
-            .expect("Failed to create Client")
-    };
+            .expect("Failed to create Client"),
+    );
 
     binance_websocket.subscribe(OrderBookUpdates("btceur".to_string()), move |m| {
         let r = m.as_ref();
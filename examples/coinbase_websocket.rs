@@ -8,9 +8,7 @@ use openlimits::model::websocket::WebSocketResponse::Generic;
 
 #[tokio::main]
 async fn main() {
-    let coinbase_websocket = OpenLimitsWs {
-        websocket: CoinbaseWebsocket::new(CoinbaseParameters::prod())
-    };
+    let coinbase_websocket = OpenLimitsWs::new(CoinbaseWebsocket::new(CoinbaseParameters::prod()));
 
     coinbase_websocket.subscribe(OrderBookUpdates("BTC-EUR".to_string()), move |m| {
         let r = m.as_ref();
@@ -0,0 +1,172 @@
+use std::collections::BTreeMap;
+use rust_decimal::prelude::{Decimal, Zero};
+use crate::model::websocket::OpenLimitsWebSocketMessage;
+use crate::model::OrderBookResponse;
+use super::{AggregatedLevel, CrossedMarket, VenueBook};
+
+/// This struct represents the consolidated order book of a market quoted on several venues.
+/// The books of the stale venues are left out of the levels, the depths and the crossings.
+#[derive(Clone, Debug, Default)]
+pub struct AggregatedBook {
+    pub venues: BTreeMap<String, VenueBook>,
+}
+
+impl AggregatedBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies an order book message of `venue`. Snapshots replace the book of the venue,
+    /// unless `incremental` is set for the exchanges which send their updates as snapshots.
+    /// Returns whether the book of the venue is in sync.
+    pub fn update(
+        &mut self,
+        venue: &str,
+        message: &OpenLimitsWebSocketMessage,
+        incremental: bool,
+    ) -> bool {
+        let book = self.venues.entry(venue.to_string()).or_default();
+        match message {
+            OpenLimitsWebSocketMessage::OrderBook(update) if !incremental => {
+                book.replace(update);
+                true
+            }
+            OpenLimitsWebSocketMessage::OrderBook(update)
+            | OpenLimitsWebSocketMessage::OrderBookDiff(update) => book.apply(update),
+            _ => !book.stale,
+        }
+    }
+
+    /// Replaces the book of `venue` with a snapshot
+    pub fn replace(&mut self, venue: &str, snapshot: &OrderBookResponse) {
+        self.venues.entry(venue.to_string()).or_default().replace(snapshot);
+    }
+
+    /// Leaves the book of `venue` out until it's replaced, e.g. when its stream failed
+    pub fn mark_stale(&mut self, venue: &str) {
+        self.venues.entry(venue.to_string()).or_default().stale = true;
+    }
+
+    /// Removes the book of a venue, e.g. when its stream ends
+    pub fn remove_venue(&mut self, venue: &str) -> Option<VenueBook> {
+        self.venues.remove(venue)
+    }
+
+    /// Bids of every venue, best first
+    pub fn bids(&self) -> Vec<AggregatedLevel> {
+        let mut bids = self.levels(|book| &book.bids);
+        bids.sort_by(|a, b| b.price.cmp(&a.price).then_with(|| a.venue.cmp(&b.venue)));
+        bids
+    }
+
+    /// Asks of every venue, best first
+    pub fn asks(&self) -> Vec<AggregatedLevel> {
+        let mut asks = self.levels(|book| &book.asks);
+        asks.sort_by(|a, b| a.price.cmp(&b.price).then_with(|| a.venue.cmp(&b.venue)));
+        asks
+    }
+
+    pub fn best_bid(&self) -> Option<AggregatedLevel> {
+        self.best_bids().into_iter().max_by(|a, b| a.price.cmp(&b.price))
+    }
+
+    pub fn best_ask(&self) -> Option<AggregatedLevel> {
+        self.best_asks().into_iter().min_by(|a, b| a.price.cmp(&b.price))
+    }
+
+    /// Quantity bid on every venue
+    pub fn bid_depth(&self) -> Decimal {
+        self.live()
+            .flat_map(|book| book.bids.values())
+            .fold(Decimal::zero(), |depth, qty| depth + qty)
+    }
+
+    /// Quantity asked on every venue
+    pub fn ask_depth(&self) -> Decimal {
+        self.live()
+            .flat_map(|book| book.asks.values())
+            .fold(Decimal::zero(), |depth, qty| depth + qty)
+    }
+
+    /// Quantity bid at `price` or above
+    pub fn bid_depth_at(&self, price: Decimal) -> Decimal {
+        self.live()
+            .flat_map(|book| book.bids.range(price..).map(|(_, qty)| qty))
+            .fold(Decimal::zero(), |depth, qty| depth + qty)
+    }
+
+    /// Quantity asked at `price` or below
+    pub fn ask_depth_at(&self, price: Decimal) -> Decimal {
+        self.live()
+            .flat_map(|book| book.asks.range(..=price).map(|(_, qty)| qty))
+            .fold(Decimal::zero(), |depth, qty| depth + qty)
+    }
+
+    /// The widest crossing between the best bid of a venue and the best ask of another one.
+    /// A venue crossing its own book is ignored, it only means its book is stale.
+    pub fn crossed(&self) -> Option<CrossedMarket> {
+        let bids = self.best_bids();
+        let asks = self.best_asks();
+        bids.iter()
+            .flat_map(|bid| asks.iter().map(move |ask| (bid, ask)))
+            .filter(|(bid, ask)| bid.venue != ask.venue && bid.price > ask.price)
+            .max_by(|(bid_a, ask_a), (bid_b, ask_b)| {
+                (bid_a.price - ask_a.price).cmp(&(bid_b.price - ask_b.price))
+            })
+            .map(|(bid, ask)| CrossedMarket {
+                bid: bid.clone(),
+                ask: ask.clone(),
+            })
+    }
+
+    pub fn is_crossed(&self) -> bool {
+        self.crossed().is_some()
+    }
+
+    fn live_venues(&self) -> impl Iterator<Item = (&String, &VenueBook)> {
+        self.venues.iter().filter(|(_, book)| !book.stale)
+    }
+
+    fn live(&self) -> impl Iterator<Item = &VenueBook> {
+        self.live_venues().map(|(_, book)| book)
+    }
+
+    fn levels<F>(&self, side: F) -> Vec<AggregatedLevel>
+    where
+        F: Fn(&VenueBook) -> &BTreeMap<Decimal, Decimal>,
+    {
+        self.live_venues()
+            .flat_map(|(venue, book)| {
+                side(book).iter().map(move |(price, qty)| AggregatedLevel {
+                    venue: venue.clone(),
+                    price: *price,
+                    qty: *qty,
+                })
+            })
+            .collect()
+    }
+
+    fn best_bids(&self) -> Vec<AggregatedLevel> {
+        self.live_venues()
+            .filter_map(|(venue, book)| {
+                book.best_bid().map(|best| AggregatedLevel {
+                    venue: venue.clone(),
+                    price: best.price,
+                    qty: best.qty,
+                })
+            })
+            .collect()
+    }
+
+    fn best_asks(&self) -> Vec<AggregatedLevel> {
+        self.live_venues()
+            .filter_map(|(venue, book)| {
+                book.best_ask().map(|best| AggregatedLevel {
+                    venue: venue.clone(),
+                    price: best.price,
+                    qty: best.qty,
+                })
+            })
+            .collect()
+    }
+}
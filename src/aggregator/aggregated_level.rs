@@ -0,0 +1,10 @@
+use rust_decimal::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// This struct represents a price level of the consolidated book and the venue it's quoted on
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AggregatedLevel {
+    pub venue: String,
+    pub price: Decimal,
+    pub qty: Decimal,
+}
@@ -0,0 +1,62 @@
+use crate::exchange::any::{AnyExchange, AnyWsExchange};
+use crate::exchange::shared::Result;
+use crate::exchange::traits::stream::ExchangeWs;
+use crate::exchange::traits::ExchangeMarketData;
+use crate::model::Instrument;
+
+/// This struct represents a venue of the aggregated book: the websocket client, the symbol of
+/// the market on this exchange and the name its levels are tagged with.
+/// `incremental` is set for the exchanges which send their order book updates as
+/// `OrderBook` messages instead of `OrderBookDiff` ones. Their streams don't start with the
+/// whole book, it's read from the REST api of `snapshots` first and again when the book
+/// can't be kept in sync.
+pub struct AggregatorVenue<'a, E: ExchangeWs> {
+    pub name: String,
+    pub websocket: &'a E,
+    pub symbol: String,
+    pub incremental: bool,
+    pub snapshots: Option<&'a (dyn ExchangeMarketData + Sync)>,
+}
+
+impl<'a, E: ExchangeWs> AggregatorVenue<'a, E> {
+    pub fn new(name: &str, websocket: &'a E, symbol: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            websocket,
+            symbol: symbol.to_string(),
+            incremental: false,
+            snapshots: None,
+        }
+    }
+
+    pub fn with_incremental_books(mut self, incremental: bool) -> Self {
+        self.incremental = incremental;
+        self
+    }
+
+    pub fn with_snapshots(mut self, snapshots: &'a (dyn ExchangeMarketData + Sync)) -> Self {
+        self.snapshots = Some(snapshots);
+        self
+    }
+}
+
+impl<'a> AggregatorVenue<'a, AnyWsExchange> {
+    /// Venue of the market of `base` and `quote`, its symbol is found in the exchange
+    /// information of `exchange`, which also serves the snapshots of the incremental books.
+    /// The currency codes are the ones of the exchange or one of their aliases.
+    pub fn from_any(
+        name: &str,
+        exchange: &'a AnyExchange,
+        websocket: &'a AnyWsExchange,
+        base: &str,
+        quote: &str,
+    ) -> Result<Self> {
+        let symbol = exchange.symbol(&Instrument::new(base, quote).to_string())?;
+        let incremental = matches!(websocket, AnyWsExchange::Binance(_) | AnyWsExchange::Nash(_));
+        let venue = Self::new(name, websocket, &symbol).with_incremental_books(incremental);
+        Ok(match incremental {
+            true => venue.with_snapshots(exchange),
+            false => venue,
+        })
+    }
+}
@@ -0,0 +1,21 @@
+use rust_decimal::prelude::Decimal;
+use super::AggregatedLevel;
+
+/// This struct represents a bid of a venue above an ask of another venue
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrossedMarket {
+    pub bid: AggregatedLevel,
+    pub ask: AggregatedLevel,
+}
+
+impl CrossedMarket {
+    /// Price difference earned by buying the ask and selling to the bid
+    pub fn spread(&self) -> Decimal {
+        self.bid.price - self.ask.price
+    }
+
+    /// Quantity available on both sides
+    pub fn qty(&self) -> Decimal {
+        self.bid.qty.min(self.ask.qty)
+    }
+}
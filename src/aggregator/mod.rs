@@ -0,0 +1,201 @@
+//! This module provides a consolidated order book of a market quoted on several exchanges.
+//! The books streamed by every venue are merged into one, with every level tagged with its
+//! venue, to read the best bid and offer across venues, the total depth and the crossed
+//! markets that can be arbitraged. The books of the venues streaming diffs are seeded with a
+//! REST snapshot and sequenced with their update ids, a venue whose book can't be kept in
+//! sync is left out until it's read again.
+//! # Example
+//! ```no_run
+//! use openlimits::aggregator::{AggregatorVenue, OrderBookAggregator};
+//! use openlimits::exchange::any::{AnyExchange, AnyWsExchange, InitAnyExchange};
+//! use openlimits::exchange::binance::BinanceParameters;
+//! use openlimits::exchange::coinbase::CoinbaseParameters;
+//! use openlimits::prelude::*;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let binance = InitAnyExchange::Binance(BinanceParameters::prod());
+//!     let coinbase = InitAnyExchange::Coinbase(CoinbaseParameters::prod());
+//!     let binance_rest = AnyExchange::new(binance.clone())
+//!                         .await
+//!                         .expect("Couldn't create binance client");
+//!     let binance_ws = AnyWsExchange::new(binance)
+//!                         .await
+//!                         .expect("Couldn't create binance websocket client");
+//!     let coinbase_rest = AnyExchange::new(coinbase.clone())
+//!                         .await
+//!                         .expect("Couldn't create coinbase client");
+//!     let coinbase_ws = AnyWsExchange::new(coinbase)
+//!                         .await
+//!                         .expect("Couldn't create coinbase websocket client");
+//!     let venues = [
+//!         AggregatorVenue::from_any("binance", &binance_rest, &binance_ws, "BTC", "USDT")
+//!                         .expect("Unknown market"),
+//!         AggregatorVenue::from_any("coinbase", &coinbase_rest, &coinbase_ws, "BTC", "USD")
+//!                         .expect("Unknown market"),
+//!     ];
+//!
+//!     let aggregator = OrderBookAggregator::new();
+//!     let monitor = aggregator.clone();
+//!     tokio::spawn(async move {
+//!         loop {
+//!             tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+//!             if let Ok(Some(crossed)) = monitor.crossed() {
+//!                 println!("{:?}", crossed);
+//!             }
+//!         }
+//!     });
+//!     aggregator.run(&venues).await.expect("Aggregation failed");
+//! }
+//! ```
+
+use std::slice;
+use std::sync::{Arc, RwLock, RwLockWriteGuard};
+use futures::stream::{self, select_all, StreamExt};
+use crate::errors::OpenLimitsError;
+use crate::exchange::shared::Result;
+use crate::exchange::traits::stream::ExchangeWs;
+use crate::model::websocket::{OpenLimitsWebSocketMessage, Subscription, WebSocketResponse};
+use crate::model::OrderBookRequest;
+
+mod aggregated_book;
+mod aggregated_level;
+mod aggregator_venue;
+mod crossed_market;
+mod venue_book;
+
+pub use aggregated_book::AggregatedBook;
+pub use aggregated_level::AggregatedLevel;
+pub use aggregator_venue::AggregatorVenue;
+pub use crossed_market::CrossedMarket;
+pub use venue_book::VenueBook;
+
+/// This struct represents the consolidated book fed by the venue streams, it can be shared
+/// with the tasks reading it.
+#[derive(Clone, Default)]
+pub struct OrderBookAggregator {
+    book: Arc<RwLock<AggregatedBook>>,
+}
+
+impl OrderBookAggregator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Copy of the consolidated book
+    pub fn book(&self) -> Result<AggregatedBook> {
+        self.book
+            .read()
+            .map(|book| book.clone())
+            .map_err(|_| OpenLimitsError::PoisonError())
+    }
+
+    /// Applies an order book message of `venue`, returns whether its book is in sync
+    pub fn update(
+        &self,
+        venue: &str,
+        message: &OpenLimitsWebSocketMessage,
+        incremental: bool,
+    ) -> Result<bool> {
+        Ok(self.write()?.update(venue, message, incremental))
+    }
+
+    pub fn best_bid(&self) -> Result<Option<AggregatedLevel>> {
+        self.read(AggregatedBook::best_bid)
+    }
+
+    pub fn best_ask(&self) -> Result<Option<AggregatedLevel>> {
+        self.read(AggregatedBook::best_ask)
+    }
+
+    pub fn crossed(&self) -> Result<Option<CrossedMarket>> {
+        self.read(AggregatedBook::crossed)
+    }
+
+    /// Subscribes to the order book of every venue and consolidates the updates until all
+    /// the streams end. The book of a venue is removed when its stream ends. An error of a
+    /// stream or a gap in the updates makes the venue stale, and its book is read again from
+    /// the snapshots of the venue when it has some.
+    pub async fn run<E>(&self, venues: &[AggregatorVenue<'_, E>]) -> Result<()>
+    where
+        E: ExchangeWs,
+    {
+        let mut streams = Vec::new();
+        for venue in venues {
+            let subscription = Subscription::OrderBookUpdates(venue.symbol.clone());
+            let name = venue.name.clone();
+            let incremental = venue.incremental;
+            let ended = stream::once(futures::future::ready((name.clone(), incremental, None)));
+            let stream = venue
+                .websocket
+                .create_stream(slice::from_ref(&subscription))
+                .await?
+                .map(move |message| (name.clone(), incremental, Some(message)))
+                .chain(ended);
+            streams.push(stream.boxed());
+        }
+        // The updates received meanwhile wait in the streams, the ones the snapshots already
+        // have are dropped
+        for venue in venues {
+            self.resync(venue).await?;
+        }
+
+        let mut messages = select_all(streams);
+        while let Some((name, incremental, message)) = messages.next().await {
+            let in_sync = match message {
+                Some(Ok(WebSocketResponse::Generic(message))) => {
+                    self.update(&name, &message, incremental)?
+                }
+                Some(Ok(WebSocketResponse::Raw(_))) => continue,
+                Some(Err(_)) => {
+                    self.write()?.mark_stale(&name);
+                    false
+                }
+                None => {
+                    self.write()?.remove_venue(&name);
+                    continue;
+                }
+            };
+            if !in_sync {
+                if let Some(venue) = venues.iter().find(|venue| venue.name == name) {
+                    self.resync(venue).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces the book of a venue with a snapshot, the venue stays stale when the snapshot
+    /// can't be read
+    async fn resync<E>(&self, venue: &AggregatorVenue<'_, E>) -> Result<()>
+    where
+        E: ExchangeWs,
+    {
+        let snapshots = match venue.snapshots {
+            Some(snapshots) => snapshots,
+            None => return Ok(()),
+        };
+        let req = OrderBookRequest {
+            market_pair: venue.symbol.clone(),
+        };
+        match snapshots.order_book(&req).await {
+            Ok(snapshot) => self.write()?.replace(&venue.name, &snapshot),
+            Err(_) => self.write()?.mark_stale(&venue.name),
+        }
+        Ok(())
+    }
+
+    fn write(&self) -> Result<RwLockWriteGuard<AggregatedBook>> {
+        self.book.write().map_err(|_| OpenLimitsError::PoisonError())
+    }
+
+    fn read<T, F>(&self, read: F) -> Result<T>
+    where
+        F: FnOnce(&AggregatedBook) -> T,
+    {
+        self.book
+            .read()
+            .map(|book| read(&book))
+            .map_err(|_| OpenLimitsError::PoisonError())
+    }
+}
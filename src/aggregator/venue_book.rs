@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+use rust_decimal::prelude::{Decimal, Zero};
+use crate::model::{AskBid, OrderBookResponse};
+
+/// This struct represents the order book of a venue, price levels with their quantity.
+/// A book is `stale` when an update was missed, it's left out of the consolidated book until
+/// it's replaced by a snapshot.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VenueBook {
+    pub bids: BTreeMap<Decimal, Decimal>,
+    pub asks: BTreeMap<Decimal, Decimal>,
+    pub update_id: Option<u64>,
+    pub stale: bool,
+}
+
+impl VenueBook {
+    /// Replaces the levels with the ones of a snapshot
+    pub fn replace(&mut self, book: &OrderBookResponse) {
+        self.bids.clear();
+        self.asks.clear();
+        apply_levels(&mut self.bids, &book.bids);
+        apply_levels(&mut self.asks, &book.asks);
+        self.update_id = book.update_id.or(book.last_update_id);
+        self.stale = false;
+    }
+
+    /// Sets the quantity of the levels of an update, levels without quantity are removed.
+    /// Updates with the range of ids they cover, from `last_update_id` to `update_id`, are
+    /// sequenced: the ones already in the book are dropped and a gap makes the book stale.
+    /// Returns whether the book is still in sync.
+    pub fn apply(&mut self, book: &OrderBookResponse) -> bool {
+        if let (Some(current), Some(first), Some(last)) =
+            (self.update_id, book.last_update_id, book.update_id)
+        {
+            if last <= current {
+                return !self.stale;
+            }
+            if first > current + 1 {
+                self.stale = true;
+            }
+        }
+        if self.stale {
+            return false;
+        }
+        apply_levels(&mut self.bids, &book.bids);
+        apply_levels(&mut self.asks, &book.asks);
+        self.update_id = book.update_id.or(book.last_update_id).or(self.update_id);
+        true
+    }
+
+    pub fn best_bid(&self) -> Option<AskBid> {
+        self.bids
+            .iter()
+            .next_back()
+            .map(|(price, qty)| AskBid::new(*price, *qty))
+    }
+
+    pub fn best_ask(&self) -> Option<AskBid> {
+        self.asks
+            .iter()
+            .next()
+            .map(|(price, qty)| AskBid::new(*price, *qty))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bids.is_empty() && self.asks.is_empty()
+    }
+}
+
+fn apply_levels(side: &mut BTreeMap<Decimal, Decimal>, levels: &[AskBid]) {
+    for level in levels {
+        if level.qty.is_zero() {
+            side.remove(&level.price);
+        } else {
+            side.insert(level.price, level.qty);
+        }
+    }
+}
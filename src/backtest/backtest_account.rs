@@ -0,0 +1,105 @@
+use std::sync::{Mutex, MutexGuard};
+use async_trait::async_trait;
+use crate::errors::OpenLimitsError;
+use crate::exchange::shared::Result;
+use crate::exchange::traits::ExchangeAccount;
+use crate::model::{
+    Balance, CancelAllOrdersRequest, CancelOrderRequest, GetOrderHistoryRequest, GetOrderRequest,
+    OpenLimitOrderRequest, OpenMarketOrderRequest, Order, OrderCanceled, Paginator, Side, Trade,
+    TradeHistoryRequest,
+};
+use super::{BacktestConfig, ExecutionSimulator};
+
+/// This struct is the `ExchangeAccount` strategies trade with during a backtest
+#[derive(Debug)]
+pub struct BacktestAccount {
+    simulator: Mutex<ExecutionSimulator>,
+}
+
+impl BacktestAccount {
+    pub fn new(config: &BacktestConfig) -> Self {
+        Self {
+            simulator: Mutex::new(ExecutionSimulator::new(config)),
+        }
+    }
+
+    pub fn simulator(&self) -> Result<MutexGuard<'_, ExecutionSimulator>> {
+        self.simulator.lock().map_err(|_| OpenLimitsError::PoisonError())
+    }
+}
+
+#[async_trait]
+impl ExchangeAccount for BacktestAccount {
+    async fn limit_buy(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        self.simulator()?.limit_order(Side::Buy, req)
+    }
+
+    async fn limit_sell(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        self.simulator()?.limit_order(Side::Sell, req)
+    }
+
+    async fn market_buy(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        self.simulator()?.market_order(Side::Buy, req)
+    }
+
+    async fn market_sell(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        self.simulator()?.market_order(Side::Sell, req)
+    }
+
+    async fn cancel_order(&self, req: &CancelOrderRequest) -> Result<OrderCanceled> {
+        self.simulator()?.cancel(&req.id)
+    }
+
+    async fn cancel_all_orders(&self, req: &CancelAllOrdersRequest) -> Result<Vec<OrderCanceled>> {
+        Ok(self.simulator()?.cancel_all(req.market_pair.as_deref()))
+    }
+
+    async fn get_all_open_orders(&self) -> Result<Vec<Order>> {
+        Ok(self.simulator()?.open_orders())
+    }
+
+    async fn get_order_history(&self, req: &GetOrderHistoryRequest) -> Result<Vec<Order>> {
+        Ok(self
+            .simulator()?
+            .orders()
+            .into_iter()
+            .filter(|order| {
+                req.market_pair
+                    .as_ref()
+                    .map_or(true, |market_pair| order.market_pair.eq_ignore_ascii_case(market_pair))
+            })
+            .filter(|order| {
+                req.order_status
+                    .as_ref()
+                    .map_or(true, |statuses| statuses.contains(&order.status))
+            })
+            .collect())
+    }
+
+    async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>> {
+        Ok(self
+            .simulator()?
+            .trades()
+            .into_iter()
+            .filter(|trade| {
+                req.market_pair
+                    .as_ref()
+                    .map_or(true, |market_pair| trade.market_pair.eq_ignore_ascii_case(market_pair))
+            })
+            .filter(|trade| {
+                req.order_id.as_ref().map_or(true, |order_id| {
+                    trade.buyer_order_id.as_ref() == Some(order_id)
+                        || trade.seller_order_id.as_ref() == Some(order_id)
+                })
+            })
+            .collect())
+    }
+
+    async fn get_account_balances(&self, _paginator: Option<Paginator>) -> Result<Vec<Balance>> {
+        Ok(self.simulator()?.balances())
+    }
+
+    async fn get_order(&self, req: &GetOrderRequest) -> Result<Order> {
+        self.simulator()?.order(&req.id)
+    }
+}
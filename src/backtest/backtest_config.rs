@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+use rust_decimal::prelude::{Decimal, Zero};
+use crate::exchange::traits::info::MarketPair;
+
+/// This struct represents the simulated markets and execution conditions of a backtest.
+/// `latency` is the time in milliseconds an order or a cancellation takes to reach the market,
+/// `slippage` is the fraction of the price lost by every taker fill and the equity is valued
+/// in `valuation_asset`. The sharpe ratio is computed from the returns of every
+/// `sharpe_period` milliseconds, a day by default.
+#[derive(Clone, Debug)]
+pub struct BacktestConfig {
+    pub pairs: Vec<MarketPair>,
+    pub valuation_asset: String,
+    pub balances: HashMap<String, Decimal>,
+    pub maker_fee: Decimal,
+    pub taker_fee: Decimal,
+    pub latency: u64,
+    pub slippage: Decimal,
+    pub sharpe_period: u64,
+}
+
+impl BacktestConfig {
+    pub fn new(pairs: Vec<MarketPair>, valuation_asset: &str) -> Self {
+        Self {
+            pairs,
+            valuation_asset: valuation_asset.to_uppercase(),
+            balances: HashMap::new(),
+            maker_fee: Decimal::zero(),
+            taker_fee: Decimal::zero(),
+            latency: 0,
+            slippage: Decimal::zero(),
+            sharpe_period: 24 * 60 * 60 * 1000,
+        }
+    }
+
+    pub fn with_balance(mut self, asset: &str, amount: Decimal) -> Self {
+        self.balances.insert(asset.to_uppercase(), amount);
+        self
+    }
+
+    pub fn with_fees(mut self, maker_fee: Decimal, taker_fee: Decimal) -> Self {
+        self.maker_fee = maker_fee;
+        self.taker_fee = taker_fee;
+        self
+    }
+
+    pub fn with_latency(mut self, latency: u64) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    pub fn with_slippage(mut self, slippage: Decimal) -> Self {
+        self.slippage = slippage;
+        self
+    }
+
+    pub fn with_sharpe_period(mut self, sharpe_period: u64) -> Self {
+        self.sharpe_period = sharpe_period;
+        self
+    }
+}
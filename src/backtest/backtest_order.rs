@@ -0,0 +1,60 @@
+use rust_decimal::Decimal;
+use crate::exchange::matching::FundedOrder;
+use crate::model::{Order, OrderStatus, TimeInForce};
+
+/// This struct represents an order of a backtest with its execution state.
+/// Orders are `Pending` until they reach the market at `active_at`, resting limit orders then
+/// wait for the `queue_ahead` quantity at their price to trade before they're filled.
+#[derive(Clone, Debug)]
+pub struct BacktestOrder {
+    pub order: Order,
+    pub base: String,
+    pub quote: String,
+    pub locked: Decimal,
+    pub time_in_force: TimeInForce,
+    pub post_only: bool,
+    pub active_at: u64,
+    pub canceled_at: Option<u64>,
+    pub expires_at: Option<u64>,
+    pub queue_ahead: Decimal,
+}
+
+impl BacktestOrder {
+    pub fn is_pending(&self) -> bool {
+        self.order.status == OrderStatus::Pending
+    }
+}
+
+impl FundedOrder for BacktestOrder {
+    fn order(&self) -> &Order {
+        &self.order
+    }
+
+    fn order_mut(&mut self) -> &mut Order {
+        &mut self.order
+    }
+
+    fn base(&self) -> &str {
+        &self.base
+    }
+
+    fn quote(&self) -> &str {
+        &self.quote
+    }
+
+    fn locked(&self) -> Decimal {
+        self.locked
+    }
+
+    fn set_locked(&mut self, locked: Decimal) {
+        self.locked = locked;
+    }
+
+    /// Orders pending cancel rest on the market and can still be filled
+    fn is_open(&self) -> bool {
+        matches!(
+            self.order.status,
+            OrderStatus::Open | OrderStatus::PartiallyFilled | OrderStatus::PendingCancel
+        )
+    }
+}
@@ -0,0 +1,125 @@
+use rust_decimal::prelude::{Decimal, ToPrimitive, Zero};
+use crate::model::{Balance, Order, Trade};
+
+/// This struct represents the value of the account after a market event
+#[derive(Clone, Debug, PartialEq)]
+pub struct EquityPoint {
+    pub timestamp: u64,
+    pub equity: Decimal,
+}
+
+/// Milliseconds in a year, markets are open every day
+const YEAR: u64 = 365 * 24 * 60 * 60 * 1000;
+
+/// This struct represents the statistics of a backtest, values are in the valuation asset.
+/// `sharpe` is the mean of the returns of the equity resampled every `sharpe_period` of the
+/// config, divided by their standard deviation and annualized. `max_drawdown` is a fraction
+/// of the peak equity and `turnover` is the traded volume divided by the initial equity.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BacktestStatistics {
+    pub initial_equity: Decimal,
+    pub final_equity: Decimal,
+    pub pnl: Decimal,
+    pub sharpe: f64,
+    pub max_drawdown: Decimal,
+    pub volume: Decimal,
+    pub turnover: Decimal,
+    pub fees: Decimal,
+    pub fills: usize,
+}
+
+impl BacktestStatistics {
+    pub fn new(
+        equity: &[EquityPoint],
+        period: u64,
+        fills: usize,
+        volume: Decimal,
+        fees: Decimal,
+    ) -> Self {
+        let initial_equity = equity.first().map(|point| point.equity).unwrap_or_default();
+        let final_equity = equity.last().map(|point| point.equity).unwrap_or_default();
+
+        let mut peak = Decimal::zero();
+        let mut max_drawdown = Decimal::zero();
+        for point in equity {
+            peak = peak.max(point.equity);
+            if peak > Decimal::zero() {
+                max_drawdown = max_drawdown.max((peak - point.equity) / peak);
+            }
+        }
+
+        let returns: Vec<f64> = resample(equity, period)
+            .windows(2)
+            .filter(|window| window[0] > Decimal::zero())
+            .filter_map(|window| (window[1] / window[0] - Decimal::new(1, 0)).to_f64())
+            .collect();
+        let sharpe = if returns.len() < 2 {
+            0.0
+        } else {
+            let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+            let variance = returns
+                .iter()
+                .map(|value| (value - mean).powi(2))
+                .sum::<f64>()
+                / (returns.len() - 1) as f64;
+            if variance > 0.0 {
+                mean / variance.sqrt() * (YEAR as f64 / period as f64).sqrt()
+            } else {
+                0.0
+            }
+        };
+
+        let turnover = if initial_equity > Decimal::zero() {
+            volume / initial_equity
+        } else {
+            Decimal::zero()
+        };
+
+        Self {
+            initial_equity,
+            final_equity,
+            pnl: final_equity - initial_equity,
+            sharpe,
+            max_drawdown,
+            volume,
+            turnover,
+            fees,
+            fills,
+        }
+    }
+}
+
+/// The equity at the start and at the end of every complete `period` since the first point,
+/// periods without points keep the equity of the previous one
+fn resample(equity: &[EquityPoint], period: u64) -> Vec<Decimal> {
+    let (first, last) = match (equity.first(), equity.last()) {
+        (Some(first), Some(last)) if period > 0 => (first, last),
+        _ => return Vec::new(),
+    };
+    let mut points = equity.iter().peekable();
+    let mut value = first.equity;
+    let mut samples = vec![value];
+    let mut end = first.timestamp + period;
+    while end <= last.timestamp {
+        while let Some(point) = points.peek() {
+            if point.timestamp > end {
+                break;
+            }
+            value = point.equity;
+            points.next();
+        }
+        samples.push(value);
+        end += period;
+    }
+    samples
+}
+
+/// This struct represents the result of a backtest
+#[derive(Clone, Debug)]
+pub struct BacktestReport {
+    pub fills: Vec<Trade>,
+    pub equity: Vec<EquityPoint>,
+    pub orders: Vec<Order>,
+    pub balances: Vec<Balance>,
+    pub statistics: BacktestStatistics,
+}
@@ -0,0 +1,574 @@
+use std::collections::HashMap;
+use chrono::Duration;
+use rust_decimal::prelude::{Decimal, One, Zero};
+use crate::errors::OpenLimitsError;
+use crate::exchange::matching::{
+    crossing_levels, resting_orders, walk, BookLiquidity, FundedOrder, Ledger,
+};
+use crate::exchange::shared::Result;
+use crate::exchange::traits::info::MarketPair;
+use crate::model::{
+    AskBid, Balance, Candle, Liquidity, OpenLimitOrderRequest, OpenMarketOrderRequest, Order,
+    OrderBookResponse, OrderCanceled, OrderStatus, OrderType, Side, TimeInForce, Trade,
+};
+use super::{BacktestConfig, BacktestOrder, MarketEvent};
+
+/// This struct simulates the execution of the orders of a backtest against historical market
+/// events. Fees are charged in the quote currency of the market.
+///
+/// Orders and cancellations reach the market after the configured latency. Taker fills walk
+/// the last book, or fill at the last price when no book was seen, and lose the configured
+/// slippage. Resting limit orders join the back of the queue of their price level: trades at
+/// their price first consume the quantity ahead of them, books shrinking the level move them
+/// forward, and trades or books going through their price fill them. Candles fill resting
+/// orders whose price is strictly inside their range.
+#[derive(Debug)]
+pub struct ExecutionSimulator {
+    pairs: Vec<MarketPair>,
+    ledger: Ledger,
+    orders: Vec<BacktestOrder>,
+    books: HashMap<String, OrderBookResponse>,
+    liquidity: BookLiquidity,
+    prices: HashMap<String, Decimal>,
+    slippage: Decimal,
+    latency: u64,
+    now: u64,
+}
+
+impl ExecutionSimulator {
+    pub fn new(config: &BacktestConfig) -> Self {
+        Self {
+            pairs: config.pairs.clone(),
+            ledger: Ledger::new(config.balances.clone(), config.maker_fee, config.taker_fee),
+            orders: Vec::new(),
+            books: HashMap::new(),
+            liquidity: BookLiquidity::default(),
+            prices: HashMap::new(),
+            slippage: config.slippage,
+            latency: config.latency,
+            now: 0,
+        }
+    }
+
+    pub fn now(&self) -> u64 {
+        self.now
+    }
+
+    pub fn balances(&self) -> Vec<Balance> {
+        self.ledger.balances(|asset| self.free(asset))
+    }
+
+    /// Balance not locked by pending and open orders
+    pub fn free(&self, asset: &str) -> Decimal {
+        self.ledger.free(asset, self.funded())
+    }
+
+    fn funded(&self) -> impl Iterator<Item = &BacktestOrder> {
+        self.orders
+            .iter()
+            .filter(|order| order.is_pending() || order.is_open())
+    }
+
+    pub fn orders(&self) -> Vec<Order> {
+        self.orders.iter().map(|order| order.order.clone()).collect()
+    }
+
+    /// Orders that are pending or open
+    pub fn open_orders(&self) -> Vec<Order> {
+        self.orders
+            .iter()
+            .filter(|order| order.is_pending() || order.is_open())
+            .map(|order| order.order.clone())
+            .collect()
+    }
+
+    pub fn order(&self, id: &str) -> Result<Order> {
+        self.orders
+            .iter()
+            .find(|order| order.order.id == id)
+            .map(|order| order.order.clone())
+            .ok_or_else(|| OpenLimitsError::InvalidParameter(format!("Order {} not found", id)))
+    }
+
+    pub fn trades(&self) -> Vec<Trade> {
+        let mut trades: Vec<Trade> = self
+            .orders
+            .iter()
+            .flat_map(|order| order.order.trades.iter().cloned())
+            .collect();
+        trades.sort_by_key(|trade| trade.created_at);
+        trades
+    }
+
+    /// Last traded price of a market, or middle of its last book
+    pub fn price(&self, market_pair: &str) -> Option<Decimal> {
+        self.prices.get(&market_pair.to_uppercase()).copied()
+    }
+
+    /// Value of an amount of `asset` in `valuation_asset`, at the last prices of the markets
+    /// between them. Assets without such a market are worth nothing.
+    pub fn value(&self, asset: &str, amount: Decimal, valuation_asset: &str) -> Decimal {
+        if asset.eq_ignore_ascii_case(valuation_asset) {
+            return amount;
+        }
+        for pair in &self.pairs {
+            let price = match self.price(&pair.symbol) {
+                Some(price) if !price.is_zero() => price,
+                _ => continue,
+            };
+            if pair.base.eq_ignore_ascii_case(asset) && pair.quote.eq_ignore_ascii_case(valuation_asset) {
+                return amount * price;
+            }
+            if pair.base.eq_ignore_ascii_case(valuation_asset) && pair.quote.eq_ignore_ascii_case(asset) {
+                return amount / price;
+            }
+        }
+        Decimal::zero()
+    }
+
+    /// Value of all the balances in `valuation_asset`
+    pub fn equity(&self, valuation_asset: &str) -> Decimal {
+        self.ledger
+            .totals()
+            .iter()
+            .fold(Decimal::zero(), |acc, (asset, total)| {
+                acc + self.value(asset, *total, valuation_asset)
+            })
+    }
+
+    /// Value of the notional and the fees of a fill in `valuation_asset`
+    pub fn fill_value(&self, fill: &Trade, valuation_asset: &str) -> (Decimal, Decimal) {
+        match self.pair(&fill.market_pair) {
+            Ok(pair) => (
+                self.value(&pair.quote, fill.price * fill.qty, valuation_asset),
+                self.value(&pair.quote, fill.fees.unwrap_or_default(), valuation_asset),
+            ),
+            Err(_) => (Decimal::zero(), Decimal::zero()),
+        }
+    }
+
+    pub fn limit_order(&mut self, side: Side, req: &OpenLimitOrderRequest) -> Result<Order> {
+        let pair = self.pair(&req.market_pair)?.clone();
+        if req.size <= Decimal::zero() || req.price <= Decimal::zero() {
+            return Err(OpenLimitsError::InvalidParameter(String::from(
+                "Order size and price must be positive",
+            )));
+        }
+        if let TimeInForce::GoodTillTime(duration) = req.time_in_force {
+            if duration <= Duration::zero() {
+                return Err(OpenLimitsError::InvalidParameter(format!(
+                    "Good till time duration of {}ms must be positive",
+                    duration.num_milliseconds()
+                )));
+            }
+        }
+        let locked = match side {
+            Side::Buy => req.size * req.price * (Decimal::one() + self.ledger.taker_fee),
+            Side::Sell => req.size,
+        };
+        self.ledger.check_funds(&pair, &side, locked, self.funded())?;
+
+        let index = self.insert(&pair, side, OrderType::Limit, req.size, Some(req.price));
+        let order = &mut self.orders[index];
+        order.locked = locked;
+        order.time_in_force = req.time_in_force;
+        order.post_only = req.post_only;
+        Ok(order.order.clone())
+    }
+
+    /// Market buys lock the funds needed to buy at the current price, with the slippage and
+    /// the taker fee.
+    pub fn market_order(&mut self, side: Side, req: &OpenMarketOrderRequest) -> Result<Order> {
+        let pair = self.pair(&req.market_pair)?.clone();
+        if req.size <= Decimal::zero() {
+            return Err(OpenLimitsError::InvalidParameter(String::from(
+                "Order size must be positive",
+            )));
+        }
+        let locked = match side {
+            Side::Buy => {
+                let levels = self.taker_levels(&pair.symbol, &side, req.size);
+                if levels.is_empty() {
+                    return Err(OpenLimitsError::InvalidParameter(format!(
+                        "No price for {}",
+                        pair.symbol
+                    )));
+                }
+                let qty = levels.iter().fold(Decimal::zero(), |acc, level| acc + level.qty);
+                let cost = levels
+                    .iter()
+                    .fold(Decimal::zero(), |acc, level| acc + level.price * level.qty);
+                let last = levels.last().map(|level| level.price).unwrap_or_default();
+                (cost + (req.size - qty) * last)
+                    * (Decimal::one() + self.slippage)
+                    * (Decimal::one() + self.ledger.taker_fee)
+            }
+            Side::Sell => req.size,
+        };
+        self.ledger.check_funds(&pair, &side, locked, self.funded())?;
+
+        let index = self.insert(&pair, side, OrderType::Market, req.size, None);
+        self.orders[index].locked = locked;
+        Ok(self.orders[index].order.clone())
+    }
+
+    /// Open orders are canceled when the cancellation reaches the market, they can still be
+    /// filled until then.
+    pub fn cancel(&mut self, id: &str) -> Result<OrderCanceled> {
+        let index = self
+            .orders
+            .iter()
+            .position(|order| order.order.id == id && (order.is_pending() || order.is_open()))
+            .ok_or_else(|| OpenLimitsError::InvalidParameter(format!("Order {} is not open", id)))?;
+        self.request_cancel(index);
+
+        Ok(OrderCanceled { id: id.to_string() })
+    }
+
+    pub fn cancel_all(&mut self, market_pair: Option<&str>) -> Vec<OrderCanceled> {
+        let indexes: Vec<usize> = self
+            .orders
+            .iter()
+            .enumerate()
+            .filter(|(_, order)| order.is_pending() || order.is_open())
+            .filter(|(_, order)| {
+                market_pair.map_or(true, |market_pair| {
+                    order.order.market_pair.eq_ignore_ascii_case(market_pair)
+                })
+            })
+            .map(|(index, _)| index)
+            .collect();
+
+        indexes
+            .into_iter()
+            .map(|index| {
+                self.request_cancel(index);
+                OrderCanceled {
+                    id: self.orders[index].order.id.clone(),
+                }
+            })
+            .collect()
+    }
+
+    fn request_cancel(&mut self, index: usize) {
+        let canceled_at = self.now + self.latency;
+        let order = &mut self.orders[index];
+        if order.canceled_at.is_none() {
+            order.canceled_at = Some(canceled_at);
+        }
+        if order.is_open() {
+            order.order.status = OrderStatus::PendingCancel;
+        }
+    }
+
+    /// Moves the time forward to `now`: the orders and cancellations reaching the market until
+    /// then are executed in the order they arrive, and the expired orders are closed.
+    pub fn advance(&mut self, now: u64) -> Vec<Trade> {
+        let mut actions: Vec<(u64, usize)> = Vec::new();
+        for (index, order) in self.orders.iter().enumerate() {
+            if order.is_pending() && order.active_at <= now {
+                actions.push((order.active_at, index));
+            }
+            if let Some(canceled_at) = order.canceled_at {
+                if canceled_at <= now && (order.is_pending() || order.is_open()) {
+                    actions.push((canceled_at, index));
+                }
+            }
+        }
+        actions.sort();
+
+        let mut fills = Vec::new();
+        for (time, index) in actions {
+            self.now = self.now.max(time);
+            self.expire(self.now);
+            let order = &self.orders[index];
+            if order.canceled_at.map_or(false, |canceled_at| canceled_at <= time) {
+                if order.is_pending() || order.is_open() {
+                    self.close(index, OrderStatus::Canceled);
+                }
+            } else if order.is_pending() {
+                fills.append(&mut self.activate(index));
+            }
+        }
+
+        self.now = self.now.max(now);
+        self.expire(self.now);
+        fills
+    }
+
+    /// Applies a market event at the current time and returns the fills it caused
+    pub fn on_event(&mut self, event: &MarketEvent) -> Vec<Trade> {
+        match event {
+            MarketEvent::Trade { trade, .. } => {
+                self.prices.insert(trade.market_pair.to_uppercase(), trade.price);
+                self.on_trade(trade)
+            }
+            MarketEvent::Book { symbol, book, .. } => {
+                if let Some(price) = middle(book) {
+                    self.prices.insert(symbol.to_uppercase(), price);
+                }
+                let fills = self.on_book(symbol, book);
+                self.books.insert(symbol.to_uppercase(), book.clone());
+                fills
+            }
+            MarketEvent::Candle { symbol, candle, .. } => {
+                self.prices.insert(symbol.to_uppercase(), candle.close);
+                self.on_candle(symbol, candle)
+            }
+        }
+    }
+
+    fn expire(&mut self, now: u64) {
+        let indexes: Vec<usize> = self
+            .orders
+            .iter()
+            .enumerate()
+            .filter(|(_, order)| order.is_open())
+            .filter(|(_, order)| order.expires_at.map_or(false, |expires_at| expires_at <= now))
+            .map(|(index, _)| index)
+            .collect();
+
+        for index in indexes {
+            self.close(index, OrderStatus::Expired);
+        }
+    }
+
+    /// The order reaches the market: it takes the liquidity it crosses and a limit order rests
+    /// with the rest, behind the quantity of its price level.
+    fn activate(&mut self, index: usize) -> Vec<Trade> {
+        let order = &self.orders[index];
+        let symbol = order.order.market_pair.clone();
+        let side = order.order.side.clone();
+        let limit = order.order.price;
+        let size = order.order.size;
+        let time_in_force = order.time_in_force;
+        let post_only = order.post_only;
+
+        let levels: Vec<AskBid> = self
+            .taker_levels(&symbol, &side, size)
+            .into_iter()
+            .filter(|level| match (&side, limit) {
+                (Side::Buy, Some(limit)) => level.price <= limit,
+                (Side::Sell, Some(limit)) => level.price >= limit,
+                (_, None) => true,
+            })
+            .collect();
+        let filled = levels.iter().fold(Decimal::zero(), |acc, level| acc + level.qty);
+
+        self.orders[index].order.status = OrderStatus::Open;
+        if limit.is_some() && post_only && !levels.is_empty() {
+            self.close(index, OrderStatus::Rejected);
+            return Vec::new();
+        }
+        if time_in_force == TimeInForce::FillOrKill && filled < size {
+            self.close(index, OrderStatus::Expired);
+            return Vec::new();
+        }
+
+        let mut fills = Vec::new();
+        for level in levels {
+            let slipped = match side {
+                Side::Buy => level.price * (Decimal::one() + self.slippage),
+                Side::Sell => level.price * (Decimal::one() - self.slippage),
+            };
+            let price = match (&side, limit) {
+                (Side::Buy, Some(limit)) => slipped.min(limit),
+                (Side::Sell, Some(limit)) => slipped.max(limit),
+                (_, None) => slipped,
+            };
+            fills.push(self.fill(index, price, level.qty, Liquidity::Taker));
+        }
+
+        let order = &mut self.orders[index];
+        match limit {
+            Some(price) if order.is_open() => {
+                if time_in_force == TimeInForce::ImmediateOrCancelled {
+                    self.close(index, OrderStatus::Expired);
+                } else {
+                    if let TimeInForce::GoodTillTime(duration) = time_in_force {
+                        order.expires_at = Some(order.active_at + duration.num_milliseconds() as u64);
+                    }
+                    order.queue_ahead = self
+                        .books
+                        .get(&symbol.to_uppercase())
+                        .map(|book| level_qty(book, &side, price))
+                        .unwrap_or_default();
+                }
+            }
+            None if order.is_open() => self.close(index, OrderStatus::Expired),
+            _ => (),
+        }
+        fills
+    }
+
+    /// Levels a taker order of `size` takes: the opposite side of the last book or the last
+    /// price with enough liquidity.
+    fn taker_levels(&self, symbol: &str, side: &Side, size: Decimal) -> Vec<AskBid> {
+        let symbol = symbol.to_uppercase();
+        match self.books.get(&symbol) {
+            Some(book) => walk(&crossing_levels(book, side, None), size),
+            None => self
+                .prices
+                .get(&symbol)
+                .map(|price| vec![AskBid { price: *price, qty: size }])
+                .unwrap_or_default(),
+        }
+    }
+
+    /// A public trade first consumes the queue ahead of the orders at its price, the quantity
+    /// left fills them at their price. Trades through the price of an order fill it without
+    /// waiting. The quantity is shared by the orders following price-time priority.
+    fn on_trade(&mut self, trade: &Trade) -> Vec<Trade> {
+        let mut available = trade.qty;
+        let mut fills = Vec::new();
+        for index in resting_orders(&self.orders, &trade.market_pair) {
+            if available.is_zero() {
+                break;
+            }
+            let order = &mut self.orders[index];
+            let price = order.order.price.unwrap_or_default();
+            let (reaches, through) = match order.order.side {
+                Side::Buy => (trade.price <= price, trade.price < price),
+                Side::Sell => (trade.price >= price, trade.price > price),
+            };
+            if !reaches {
+                continue;
+            }
+            if !through {
+                let consumed = available.min(order.queue_ahead);
+                order.queue_ahead -= consumed;
+                available -= consumed;
+            }
+
+            let qty = available.min(order.remaining());
+            if qty.is_zero() {
+                continue;
+            }
+            available -= qty;
+            fills.push(self.fill(index, price, qty, Liquidity::Maker));
+        }
+        fills
+    }
+
+    /// Books shrinking the level of an order move it forward in the queue, the quantity of the
+    /// levels going through its price fills it at its price. The quantity of a level fills the
+    /// orders once until a book shows it with another quantity.
+    fn on_book(&mut self, symbol: &str, book: &OrderBookResponse) -> Vec<Trade> {
+        self.liquidity.update(symbol, book);
+        let mut fills = Vec::new();
+        for index in resting_orders(&self.orders, symbol) {
+            let order = &mut self.orders[index];
+            let price = order.order.price.unwrap_or_default();
+            let side = order.order.side.clone();
+            order.queue_ahead = order.queue_ahead.min(level_qty(book, &side, price));
+
+            let levels: Vec<AskBid> = crossing_levels(book, &side, None)
+                .into_iter()
+                .filter(|level| match side {
+                    Side::Buy => level.price < price,
+                    Side::Sell => level.price > price,
+                })
+                .collect();
+            let qty = self
+                .liquidity
+                .take(symbol, &side, &levels, order.remaining());
+            if qty.is_zero() {
+                continue;
+            }
+            fills.push(self.fill(index, price, qty, Liquidity::Maker));
+        }
+        fills
+    }
+
+    fn on_candle(&mut self, symbol: &str, candle: &Candle) -> Vec<Trade> {
+        let mut fills = Vec::new();
+        for index in resting_orders(&self.orders, symbol) {
+            let order = &self.orders[index];
+            let price = order.order.price.unwrap_or_default();
+            let through = match order.order.side {
+                Side::Buy => candle.low < price,
+                Side::Sell => candle.high > price,
+            };
+            if through {
+                let qty = order.remaining();
+                fills.push(self.fill(index, price, qty, Liquidity::Maker));
+            }
+        }
+        fills
+    }
+
+    fn pair(&self, market_pair: &str) -> Result<&MarketPair> {
+        self.pairs
+            .iter()
+            .find(|pair| pair.symbol.eq_ignore_ascii_case(market_pair))
+            .ok_or(OpenLimitsError::SymbolNotFound())
+    }
+
+    fn insert(
+        &mut self,
+        pair: &MarketPair,
+        side: Side,
+        order_type: OrderType,
+        size: Decimal,
+        price: Option<Decimal>,
+    ) -> usize {
+        let id = (self.orders.len() + 1).to_string();
+        self.orders.push(BacktestOrder {
+            order: Order {
+                id,
+                market_pair: pair.symbol.clone(),
+                instrument: None,
+                client_order_id: None,
+                created_at: Some(self.now),
+                order_type,
+                side,
+                status: OrderStatus::Pending,
+                size,
+                price,
+                remaining: Some(size),
+                trades: Vec::new(),
+            },
+            base: pair.base.to_uppercase(),
+            quote: pair.quote.to_uppercase(),
+            locked: Decimal::zero(),
+            time_in_force: TimeInForce::GoodTillCancelled,
+            post_only: false,
+            active_at: self.now + self.latency,
+            canceled_at: None,
+            expires_at: None,
+            queue_ahead: Decimal::zero(),
+        });
+        self.orders.len() - 1
+    }
+
+    fn close(&mut self, index: usize, status: OrderStatus) {
+        let order = &mut self.orders[index];
+        order.order.status = status;
+        order.locked = Decimal::zero();
+    }
+
+    fn fill(&mut self, index: usize, price: Decimal, qty: Decimal, liquidity: Liquidity) -> Trade {
+        self.ledger.fill(&mut self.orders[index], price, qty, liquidity, self.now)
+    }
+}
+
+/// Quantity of the level of `price` on the side of the book an order of `side` rests on
+fn level_qty(book: &OrderBookResponse, side: &Side, price: Decimal) -> Decimal {
+    let levels = match side {
+        Side::Buy => &book.bids,
+        Side::Sell => &book.asks,
+    };
+    levels
+        .iter()
+        .filter(|level| level.price == price)
+        .fold(Decimal::zero(), |acc, level| acc + level.qty)
+}
+
+fn middle(book: &OrderBookResponse) -> Option<Decimal> {
+    let bid = book.bids.iter().map(|bid| bid.price).max();
+    let ask = book.asks.iter().map(|ask| ask.price).min();
+    match (bid, ask) {
+        (Some(bid), Some(ask)) => Some((bid + ask) / Decimal::new(2, 0)),
+        (bid, ask) => bid.or(ask),
+    }
+}
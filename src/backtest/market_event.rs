@@ -0,0 +1,95 @@
+use std::path::Path;
+use crate::exchange::shared::Result;
+use crate::exchange::traits::ExchangeMarketData;
+use crate::model::{Candle, GetHistoricRatesRequest, Interval, OrderBookResponse, Trade};
+use crate::model::websocket::OpenLimitsWebSocketMessage;
+use crate::recorder::{RecordReader, RecordedMessage, RecordedPayload};
+
+/// This enum represents a historical market event fed to a backtest.
+/// Books are full order books, candles are delivered when they close, at their `time` plus
+/// their `interval`, so strategies don't see their close before it happens.
+#[derive(Clone, Debug)]
+pub enum MarketEvent {
+    Candle { symbol: String, candle: Candle, interval: Interval },
+    Trade { timestamp: u64, trade: Trade },
+    Book { symbol: String, timestamp: u64, book: OrderBookResponse },
+}
+
+impl MarketEvent {
+    pub fn timestamp(&self) -> u64 {
+        match self {
+            MarketEvent::Candle { candle, interval, .. } => {
+                candle.time + interval.to_duration().num_milliseconds() as u64
+            }
+            MarketEvent::Trade { timestamp, .. } => *timestamp,
+            MarketEvent::Book { timestamp, .. } => *timestamp,
+        }
+    }
+
+    pub fn symbol(&self) -> &str {
+        match self {
+            MarketEvent::Candle { symbol, .. } => symbol,
+            MarketEvent::Trade { trade, .. } => &trade.market_pair,
+            MarketEvent::Book { symbol, .. } => symbol,
+        }
+    }
+
+    pub fn from_candles(symbol: &str, interval: Interval, candles: Vec<Candle>) -> Vec<Self> {
+        candles
+            .into_iter()
+            .map(|candle| MarketEvent::Candle {
+                symbol: symbol.to_string(),
+                candle,
+                interval,
+            })
+            .collect()
+    }
+
+    /// Trades happen at their `created_at` time
+    pub fn from_trades(trades: Vec<Trade>) -> Vec<Self> {
+        trades
+            .into_iter()
+            .map(|trade| MarketEvent::Trade {
+                timestamp: trade.created_at,
+                trade,
+            })
+            .collect()
+    }
+
+    /// Events of a recorded message, at the time it was received.
+    /// Order book diffs and raw messages aren't replayed.
+    pub fn from_record(record: RecordedMessage) -> Vec<Self> {
+        let timestamp = record.timestamp;
+        match (record.symbol, record.payload) {
+            (_, RecordedPayload::Message(OpenLimitsWebSocketMessage::Trades(trades))) => trades
+                .into_iter()
+                .map(|trade| MarketEvent::Trade { timestamp, trade })
+                .collect(),
+            (Some(symbol), RecordedPayload::Message(OpenLimitsWebSocketMessage::OrderBook(book)))
+            | (Some(symbol), RecordedPayload::Snapshot(book)) => vec![MarketEvent::Book {
+                symbol,
+                timestamp,
+                book,
+            }],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Events of a file written by the recorder
+    pub fn from_recording(path: impl AsRef<Path>) -> Result<Vec<Self>> {
+        let mut events = Vec::new();
+        for record in RecordReader::open(path)? {
+            events.append(&mut Self::from_record(record?));
+        }
+        Ok(events)
+    }
+
+    /// Candles of a market read through `get_historic_rates`
+    pub async fn historic_rates<M>(market: &M, req: &GetHistoricRatesRequest) -> Result<Vec<Self>>
+    where
+        M: ExchangeMarketData,
+    {
+        let candles = market.get_historic_rates(req).await?;
+        Ok(Self::from_candles(&req.market_pair, req.interval, candles))
+    }
+}
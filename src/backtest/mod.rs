@@ -0,0 +1,148 @@
+//! This module provides an event-driven backtester. Historical candles, trades and order books
+//! are replayed through the hooks of a `Strategy`, which trades with a `BacktestAccount`
+//! simulating latency, fees, slippage and queue position. Strategies only depend on
+//! `ExchangeAccount`, so the same code runs live with an exchange client.
+//! # Example
+//! ```no_run
+//! use openlimits::backtest::{Backtester, BacktestConfig, MarketEvent, Strategy};
+//! use openlimits::exchange::binance::{Binance, BinanceParameters};
+//! use openlimits::exchange::shared::Result;
+//! use openlimits::model::{Candle, GetHistoricRatesRequest, Interval, OpenMarketOrderRequest};
+//! use openlimits::prelude::*;
+//! use async_trait::async_trait;
+//! use rust_decimal::Decimal;
+//!
+//! struct BuyTheDip;
+//!
+//! #[async_trait]
+//! impl Strategy for BuyTheDip {
+//!     async fn on_candle<A>(&mut self, account: &A, symbol: &str, candle: &Candle) -> Result<()>
+//!     where
+//!         A: ExchangeAccount + Sync,
+//!     {
+//!         if candle.close < candle.open {
+//!             account.market_buy(&OpenMarketOrderRequest {
+//!                 market_pair: symbol.to_string(),
+//!                 size: Decimal::new(1, 2),
+//!             }).await?;
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let binance = Binance::new(BinanceParameters::prod())
+//!                     .await
+//!                     .expect("Couldn't create binance client");
+//!     let events = MarketEvent::historic_rates(&binance, &GetHistoricRatesRequest {
+//!                     market_pair: "BTCUSDT".to_string(),
+//!                     paginator: None,
+//!                     interval: Interval::OneHour,
+//!                 })
+//!                 .await
+//!                 .expect("Couldn't get candles");
+//!     let pair = binance.get_pair("BTCUSDT").await.expect("Couldn't get pair").read().expect("Couldn't read pair");
+//!
+//!     let config = BacktestConfig::new(vec![pair], "USDT")
+//!                     .with_balance("USDT", Decimal::new(10000, 0))
+//!                     .with_fees(Decimal::new(1, 3), Decimal::new(1, 3))
+//!                     .with_latency(50);
+//!     let report = Backtester::new(config)
+//!                     .run(&mut BuyTheDip, events)
+//!                     .await
+//!                     .expect("Backtest failed");
+//!     println!("{:?}", report.statistics);
+//! }
+//! ```
+
+use rust_decimal::prelude::{Decimal, Zero};
+use crate::exchange::shared::Result;
+
+mod backtest_account;
+mod backtest_config;
+mod backtest_order;
+mod backtest_report;
+mod execution_simulator;
+mod market_event;
+mod strategy;
+
+pub use backtest_account::BacktestAccount;
+pub use backtest_config::BacktestConfig;
+pub use backtest_order::BacktestOrder;
+pub use backtest_report::{BacktestReport, BacktestStatistics, EquityPoint};
+pub use execution_simulator::ExecutionSimulator;
+pub use market_event::MarketEvent;
+pub use strategy::{dispatch, Strategy};
+
+/// This struct runs strategies over historical market events
+#[derive(Clone, Debug)]
+pub struct Backtester {
+    pub config: BacktestConfig,
+}
+
+impl Backtester {
+    pub fn new(config: BacktestConfig) -> Self {
+        Self { config }
+    }
+
+    /// Replays the events in time order. For every event, the orders reaching the market
+    /// until then are executed, the event is applied to the simulated market, the strategy is
+    /// told about the fills and then about the event, and the equity is recorded.
+    pub async fn run<S>(&self, strategy: &mut S, mut events: Vec<MarketEvent>) -> Result<BacktestReport>
+    where
+        S: Strategy,
+    {
+        events.sort_by_key(|event| event.timestamp());
+        let valuation_asset = &self.config.valuation_asset;
+        let account = BacktestAccount::new(&self.config);
+        let mut fills = Vec::new();
+        let mut equity = Vec::new();
+        let mut volume = Decimal::zero();
+        let mut fees = Decimal::zero();
+
+        for event in &events {
+            let new_fills = {
+                let mut simulator = account.simulator()?;
+                let mut new_fills = simulator.advance(event.timestamp());
+                new_fills.append(&mut simulator.on_event(event));
+                for fill in &new_fills {
+                    let (notional, fee) = simulator.fill_value(fill, valuation_asset);
+                    volume += notional;
+                    fees += fee;
+                }
+                new_fills
+            };
+
+            for fill in &new_fills {
+                strategy.on_fill(&account, fill).await?;
+            }
+            match event {
+                MarketEvent::Trade { trade, .. } => strategy.on_trade(&account, trade).await?,
+                MarketEvent::Book { symbol, book, .. } => strategy.on_book(&account, symbol, book).await?,
+                MarketEvent::Candle { symbol, candle, .. } => strategy.on_candle(&account, symbol, candle).await?,
+            }
+            fills.extend(new_fills);
+
+            equity.push(EquityPoint {
+                timestamp: event.timestamp(),
+                equity: account.simulator()?.equity(valuation_asset),
+            });
+        }
+
+        let simulator = account.simulator()?;
+        Ok(BacktestReport {
+            statistics: BacktestStatistics::new(
+                &equity,
+                self.config.sharpe_period,
+                fills.len(),
+                volume,
+                fees,
+            ),
+            fills,
+            equity,
+            orders: simulator.orders(),
+            balances: simulator.balances(),
+        })
+    }
+}
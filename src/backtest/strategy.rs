@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use crate::exchange::shared::Result;
+use crate::exchange::traits::ExchangeAccount;
+use crate::model::{Candle, OrderBookResponse, Trade};
+use crate::model::websocket::OpenLimitsWebSocketMessage;
+
+/// This trait represents a trading strategy driven by market events.
+/// Orders are sent through the `ExchangeAccount` given to every hook: a `BacktestAccount` in a
+/// backtest, an exchange client when running live.
+#[async_trait]
+pub trait Strategy: Send {
+    async fn on_trade<A>(&mut self, _account: &A, _trade: &Trade) -> Result<()>
+    where
+        A: ExchangeAccount + Sync,
+    {
+        Ok(())
+    }
+
+    async fn on_book<A>(&mut self, _account: &A, _symbol: &str, _book: &OrderBookResponse) -> Result<()>
+    where
+        A: ExchangeAccount + Sync,
+    {
+        Ok(())
+    }
+
+    async fn on_candle<A>(&mut self, _account: &A, _symbol: &str, _candle: &Candle) -> Result<()>
+    where
+        A: ExchangeAccount + Sync,
+    {
+        Ok(())
+    }
+
+    /// Called for every fill of the orders of the strategy
+    async fn on_fill<A>(&mut self, _account: &A, _fill: &Trade) -> Result<()>
+    where
+        A: ExchangeAccount + Sync,
+    {
+        Ok(())
+    }
+}
+
+/// Forwards a websocket message of `symbol` to the hooks of a strategy, to run it live
+pub async fn dispatch<S, A>(
+    strategy: &mut S,
+    account: &A,
+    symbol: &str,
+    message: &OpenLimitsWebSocketMessage,
+) -> Result<()>
+where
+    S: Strategy,
+    A: ExchangeAccount + Sync,
+{
+    match message {
+        OpenLimitsWebSocketMessage::Trades(trades) => {
+            for trade in trades {
+                strategy.on_trade(account, trade).await?;
+            }
+            Ok(())
+        }
+        OpenLimitsWebSocketMessage::OrderBook(book) => strategy.on_book(account, symbol, book).await,
+        _ => Ok(()),
+    }
+}
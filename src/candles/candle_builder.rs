@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+use futures::future;
+use futures::stream::{self, BoxStream, Stream, StreamExt};
+use crate::exchange::shared::Result;
+use crate::model::{Candle, Trade};
+use super::CandlePeriod;
+
+/// This struct represents a candle being built from trades or from candles of a finer period.
+/// Data has to be pushed in time order, data older than the candle in progress is ignored.
+#[derive(Clone, Debug)]
+pub struct CandleBuilder {
+    pub period: CandlePeriod,
+    current: Option<Candle>,
+}
+
+impl CandleBuilder {
+    pub fn new(period: CandlePeriod) -> Self {
+        Self {
+            period,
+            current: None,
+        }
+    }
+
+    /// Candle in progress
+    pub fn current(&self) -> Option<&Candle> {
+        self.current.as_ref()
+    }
+
+    /// Adds a trade, returns the previous candle when the trade starts a new one
+    pub fn push_trade(&mut self, trade: &Trade) -> Option<Candle> {
+        self.push(Candle {
+            market_pair: Some(trade.market_pair.clone()),
+            instrument: trade.instrument.clone(),
+            time: trade.created_at,
+            low: trade.price,
+            high: trade.price,
+            open: trade.price,
+            close: trade.price,
+            volume: trade.qty,
+        })
+    }
+
+    /// Adds a candle of a finer period, returns the previous candle when it starts a new one
+    pub fn push_candle(&mut self, candle: &Candle) -> Option<Candle> {
+        self.push(candle.clone())
+    }
+
+    /// Returns the candle in progress
+    pub fn finish(&mut self) -> Option<Candle> {
+        self.current.take()
+    }
+
+    fn push(&mut self, mut data: Candle) -> Option<Candle> {
+        let start = self.period.start(data.time);
+        match self.current.as_mut() {
+            Some(current) if start < current.time => None,
+            Some(current) if start == current.time => {
+                current.high = current.high.max(data.high);
+                current.low = current.low.min(data.low);
+                current.close = data.close;
+                current.volume += data.volume;
+                None
+            }
+            _ => {
+                data.time = start;
+                self.current.replace(data)
+            }
+        }
+    }
+}
+
+/// Merges candles into candles of `period`, which should be a multiple of theirs
+pub fn resample(candles: &[Candle], period: CandlePeriod) -> Vec<Candle> {
+    let sorted: BTreeMap<u64, &Candle> = candles.iter().map(|candle| (candle.time, candle)).collect();
+    let mut builder = CandleBuilder::new(period);
+    let mut resampled: Vec<Candle> = sorted
+        .values()
+        .filter_map(|candle| builder.push_candle(candle))
+        .collect();
+    resampled.extend(builder.finish());
+    resampled
+}
+
+/// Candles of `period` of the trades, periods without trades have no candle
+pub fn aggregate_trades(trades: &[Trade], period: CandlePeriod) -> Vec<Candle> {
+    let mut sorted: Vec<&Trade> = trades.iter().collect();
+    sorted.sort_by_key(|trade| trade.created_at);
+    let mut builder = CandleBuilder::new(period);
+    let mut candles: Vec<Candle> = sorted
+        .into_iter()
+        .filter_map(|trade| builder.push_trade(trade))
+        .collect();
+    candles.extend(builder.finish());
+    candles
+}
+
+/// Streams the candles of a live trade stream, every candle is returned once the first trade
+/// of the next one is received, and the last one when the trade stream ends. Errors of the
+/// trade stream are returned as they come.
+pub fn trade_candles<'a, S>(trades: S, period: CandlePeriod) -> BoxStream<'a, Result<Candle>>
+where
+    S: Stream<Item = Result<Trade>> + Send + 'a,
+{
+    let mut builder = CandleBuilder::new(period);
+    trades
+        .map(Some)
+        .chain(stream::once(future::ready(None)))
+        .filter_map(move |trade| {
+            let candle = match trade {
+                Some(Ok(trade)) => builder.push_trade(&trade).map(Ok),
+                Some(Err(err)) => Some(Err(err)),
+                None => builder.finish().map(Ok),
+            };
+            future::ready(candle)
+        })
+        .boxed()
+}
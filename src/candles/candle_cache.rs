@@ -0,0 +1,82 @@
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use crate::errors::OpenLimitsError;
+use crate::exchange::shared::Result;
+use crate::model::{Candle, Interval};
+use super::TimeRange;
+
+/// This struct represents the cached candles of a market and the time ranges that were
+/// downloaded, so ranges without any candle aren't requested again.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CachedCandles {
+    pub covered: Vec<TimeRange>,
+    pub candles: Vec<Candle>,
+}
+
+/// This struct represents an on-disk cache of candles with one JSON file per exchange, market
+/// and interval.
+#[derive(Clone, Debug)]
+pub struct CandleCache {
+    pub directory: PathBuf,
+}
+
+impl CandleCache {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    pub fn path(&self, exchange: &str, symbol: &str, interval: Interval) -> PathBuf {
+        self.directory
+            .join(sanitize(exchange))
+            .join(format!("{}-{}.json", sanitize(symbol), interval_code(interval)))
+    }
+
+    pub fn load(&self, exchange: &str, symbol: &str, interval: Interval) -> Result<CachedCandles> {
+        let path = self.path(exchange, symbol, interval);
+        if !path.exists() {
+            return Ok(CachedCandles::default());
+        }
+        let reader = BufReader::new(File::open(path)?);
+        serde_json::from_reader(reader).map_err(OpenLimitsError::JsonError)
+    }
+
+    /// The file is replaced at once, an interrupted write leaves the previous cache
+    pub fn store(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        interval: Interval,
+        cached: &CachedCandles,
+    ) -> Result<()> {
+        let path = self.path(exchange, symbol, interval);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let temporary = path.with_extension("json.tmp");
+        {
+            let mut writer = BufWriter::new(File::create(&temporary)?);
+            serde_json::to_writer(&mut writer, cached)?;
+            writer.flush()?;
+        }
+        fs::rename(&temporary, &path)?;
+        Ok(())
+    }
+}
+
+/// Short name of an interval, as serialized
+pub fn interval_code(interval: Interval) -> String {
+    serde_json::to_value(interval)
+        .ok()
+        .and_then(|value| value.as_str().map(String::from))
+        .unwrap_or_else(|| format!("{:?}", interval))
+}
+
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
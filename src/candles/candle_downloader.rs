@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+use chrono::Utc;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use futures::TryFutureExt;
+use crate::errors::OpenLimitsError;
+use crate::exchange::shared::Result;
+use crate::exchange::traits::ExchangeMarketData;
+use crate::model::{Candle, GetHistoricRatesRequest, Interval, Paginator};
+use super::{
+    interval_millis, CachedCandles, CandleCache, CandleDownloaderConfig, CandlePeriod, CandleSeries,
+    TimeRange,
+};
+
+/// This struct represents a downloader of the candle history of an exchange.
+/// A range is split in chunks of as many candles as the exchange returns per request, the
+/// chunks are requested concurrently and, with a cache, only the ranges that weren't
+/// downloaded before are requested. A chunk is cached as downloaded when it returned candles,
+/// or when it returned none but the exchange has candles after it.
+pub struct CandleDownloader<'a, M: ExchangeMarketData + Sync> {
+    market: &'a M,
+    pub config: CandleDownloaderConfig,
+}
+
+impl<'a, M: ExchangeMarketData + Sync> CandleDownloader<'a, M> {
+    pub fn new(market: &'a M, config: CandleDownloaderConfig) -> Self {
+        Self { market, config }
+    }
+
+    /// Candles of `symbol` opened between `start` and `end`, in milliseconds with `end`
+    /// excluded, sorted and without duplicates.
+    pub async fn download(
+        &self,
+        symbol: &str,
+        interval: Interval,
+        start: u64,
+        end: u64,
+    ) -> Result<CandleSeries> {
+        if !self.config.limits.supports(interval) {
+            return Err(OpenLimitsError::InvalidParameter(format!(
+                "{:?} candles aren't supported by {}",
+                interval, self.config.exchange
+            )));
+        }
+        let period = CandlePeriod::from(interval);
+        let range = TimeRange::new(period.start(start), end);
+        // Only candles that are already closed are final and can be cached
+        let now = Utc::now().timestamp_millis() as u64;
+        let complete = TimeRange::new(range.start, end.min(period.start(now)));
+
+        let cache = self.config.cache_directory.as_ref().map(CandleCache::new);
+        let mut cached = match &cache {
+            Some(cache) => cache.load(&self.config.exchange, symbol, interval)?,
+            None => CachedCandles::default(),
+        };
+
+        let chunk = interval_millis(interval).saturating_mul(self.config.limits.max_candles.max(1));
+        let chunks: Vec<TimeRange> = range
+            .subtract(&cached.covered)
+            .into_iter()
+            .flat_map(|missing| missing.split(chunk))
+            .collect();
+
+        let fetched: Vec<(TimeRange, Vec<Candle>)> = stream::iter(chunks)
+            .map(|chunk| {
+                self.fetch(symbol, interval, chunk)
+                    .map_ok(move |candles| (chunk, candles))
+            })
+            .buffer_unordered(self.config.concurrency.max(1))
+            .try_collect()
+            .await?;
+
+        let mut candles: BTreeMap<u64, Candle> = cached
+            .candles
+            .drain(..)
+            .map(|candle| (candle.time, candle))
+            .collect();
+        let mut downloaded = Vec::new();
+        let mut empty = Vec::new();
+        for (chunk, fetched) in fetched {
+            if fetched.is_empty() {
+                empty.push(chunk);
+            } else {
+                downloaded.push(chunk);
+            }
+            for candle in fetched {
+                candles.insert(candle.time, candle);
+            }
+        }
+        // An empty chunk may be the exchange lagging behind, it's only known to have no
+        // candles when there are candles after it
+        let last = candles.keys().next_back().copied();
+        downloaded.extend(
+            empty
+                .into_iter()
+                .filter(|chunk| last.map_or(false, |last| last >= chunk.end)),
+        );
+
+        if let Some(cache) = &cache {
+            let mut covered = cached.covered.clone();
+            covered.extend(
+                downloaded
+                    .iter()
+                    .map(|chunk| TimeRange::new(chunk.start, chunk.end.min(complete.end))),
+            );
+            cached.covered = TimeRange::merge(covered);
+            cached.candles = candles
+                .values()
+                .filter(|candle| cached.covered.iter().any(|range| range.contains(candle.time)))
+                .cloned()
+                .collect();
+            cache.store(&self.config.exchange, symbol, interval, &cached)?;
+        }
+
+        let candles: Vec<Candle> = candles
+            .range(range.start..range.end)
+            .map(|(_, candle)| candle.clone())
+            .collect();
+        let mut series = CandleSeries::new(interval, candles, complete);
+        if self.config.fill_gaps {
+            series.fill_gaps();
+        }
+        Ok(series)
+    }
+
+    async fn fetch(&self, symbol: &str, interval: Interval, chunk: TimeRange) -> Result<Vec<Candle>> {
+        let req = GetHistoricRatesRequest {
+            market_pair: symbol.to_string(),
+            interval,
+            paginator: Some(Paginator {
+                start_time: Some(chunk.start),
+                end_time: Some(chunk.end - 1),
+                limit: Some(self.config.limits.max_candles),
+                ..Default::default()
+            }),
+        };
+        let candles = self.market.get_historic_rates(&req).await?;
+        Ok(candles
+            .into_iter()
+            .filter(|candle| chunk.contains(candle.time))
+            .collect())
+    }
+}
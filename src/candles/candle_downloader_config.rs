@@ -0,0 +1,41 @@
+use std::path::PathBuf;
+use super::CandleLimits;
+
+/// This struct represents how candles are downloaded from an exchange.
+/// `exchange` names the cache of the exchange, `concurrency` is the number of requests in
+/// flight and missing candles are synthesized when `fill_gaps` is set.
+#[derive(Clone, Debug)]
+pub struct CandleDownloaderConfig {
+    pub exchange: String,
+    pub limits: CandleLimits,
+    pub concurrency: usize,
+    pub cache_directory: Option<PathBuf>,
+    pub fill_gaps: bool,
+}
+
+impl CandleDownloaderConfig {
+    pub fn new(exchange: &str, limits: CandleLimits) -> Self {
+        Self {
+            exchange: exchange.to_string(),
+            limits,
+            concurrency: 4,
+            cache_directory: None,
+            fill_gaps: false,
+        }
+    }
+
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn with_cache(mut self, cache_directory: impl Into<PathBuf>) -> Self {
+        self.cache_directory = Some(cache_directory.into());
+        self
+    }
+
+    pub fn with_gap_filling(mut self, fill_gaps: bool) -> Self {
+        self.fill_gaps = fill_gaps;
+        self
+    }
+}
@@ -0,0 +1,138 @@
+use crate::model::Interval;
+use super::{CandlePeriod, INTERVALS};
+
+/// This struct represents what an exchange accepts in a `get_historic_rates` request: the
+/// number of candles of a response and, when restricted, the intervals.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CandleLimits {
+    pub max_candles: u64,
+    pub intervals: Option<Vec<Interval>>,
+}
+
+impl CandleLimits {
+    pub fn new(max_candles: u64) -> Self {
+        Self {
+            max_candles,
+            intervals: None,
+        }
+    }
+
+    pub fn with_intervals(mut self, intervals: &[Interval]) -> Self {
+        self.intervals = Some(intervals.to_vec());
+        self
+    }
+
+    pub fn binance() -> Self {
+        Self::new(1000)
+    }
+
+    pub fn coinbase() -> Self {
+        Self::new(300).with_intervals(&[
+            Interval::OneMinute,
+            Interval::FiveMinutes,
+            Interval::FifteenMinutes,
+            Interval::OneHour,
+            Interval::SixHours,
+            Interval::OneDay,
+        ])
+    }
+
+    pub fn nash() -> Self {
+        Self::new(1000).with_intervals(&[
+            Interval::OneMinute,
+            Interval::FiveMinutes,
+            Interval::FifteenMinutes,
+            Interval::ThirtyMinutes,
+            Interval::OneHour,
+            Interval::SixHours,
+            Interval::TwelveHours,
+            Interval::OneDay,
+        ])
+    }
+
+    pub fn bitfinex() -> Self {
+        Self::new(10000).with_intervals(&[
+            Interval::OneMinute,
+            Interval::FiveMinutes,
+            Interval::FifteenMinutes,
+            Interval::ThirtyMinutes,
+            Interval::OneHour,
+            Interval::SixHours,
+            Interval::TwelveHours,
+            Interval::OneDay,
+            Interval::OneWeek,
+            Interval::OneMonth,
+        ])
+    }
+
+    pub fn gemini() -> Self {
+        Self::new(1440).with_intervals(&[
+            Interval::OneMinute,
+            Interval::FiveMinutes,
+            Interval::FifteenMinutes,
+            Interval::ThirtyMinutes,
+            Interval::OneHour,
+            Interval::SixHours,
+            Interval::OneDay,
+        ])
+    }
+
+    pub fn kucoin() -> Self {
+        Self::new(1500).with_intervals(&[
+            Interval::OneMinute,
+            Interval::ThreeMinutes,
+            Interval::FiveMinutes,
+            Interval::FifteenMinutes,
+            Interval::ThirtyMinutes,
+            Interval::OneHour,
+            Interval::TwoHours,
+            Interval::FourHours,
+            Interval::SixHours,
+            Interval::EightHours,
+            Interval::TwelveHours,
+            Interval::OneDay,
+            Interval::OneWeek,
+        ])
+    }
+
+    pub fn okx() -> Self {
+        Self::new(300).with_intervals(&[
+            Interval::OneMinute,
+            Interval::ThreeMinutes,
+            Interval::FiveMinutes,
+            Interval::FifteenMinutes,
+            Interval::ThirtyMinutes,
+            Interval::OneHour,
+            Interval::TwoHours,
+            Interval::FourHours,
+            Interval::SixHours,
+            Interval::TwelveHours,
+            Interval::OneDay,
+            Interval::ThreeDays,
+            Interval::OneWeek,
+            Interval::OneMonth,
+        ])
+    }
+
+    pub fn supports(&self, interval: Interval) -> bool {
+        self.intervals
+            .as_ref()
+            .map_or(true, |intervals| intervals.contains(&interval))
+    }
+
+    /// Coarsest supported interval candles of `period` can be built from
+    pub fn base_interval(&self, period: CandlePeriod) -> Option<Interval> {
+        INTERVALS
+            .iter()
+            .rev()
+            .copied()
+            .filter(|interval| self.supports(*interval))
+            .find(|interval| period.is_multiple_of(CandlePeriod::from(*interval)))
+    }
+}
+
+impl Default for CandleLimits {
+    fn default() -> Self {
+        Self::new(500)
+    }
+}
@@ -0,0 +1,135 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use crate::exchange::shared::timestamp_to_naive_datetime;
+use crate::model::Interval;
+
+const DAY: u64 = 86_400_000;
+/// 1970-01-05, the first Monday since the Unix epoch
+const FIRST_MONDAY: u64 = 4 * DAY;
+
+/// Every `Interval`, finest first
+pub const INTERVALS: [Interval; 15] = [
+    Interval::OneMinute,
+    Interval::ThreeMinutes,
+    Interval::FiveMinutes,
+    Interval::FifteenMinutes,
+    Interval::ThirtyMinutes,
+    Interval::OneHour,
+    Interval::TwoHours,
+    Interval::FourHours,
+    Interval::SixHours,
+    Interval::EightHours,
+    Interval::TwelveHours,
+    Interval::OneDay,
+    Interval::ThreeDays,
+    Interval::OneWeek,
+    Interval::OneMonth,
+];
+
+/// This struct represents the duration of a candle, in milliseconds. It can be any duration,
+/// not only an `Interval`. Candles start at multiples of the period since the Unix epoch,
+/// except weekly candles, which start on Mondays, and monthly candles, which start on the first
+/// day of the month.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CandlePeriod {
+    millis: u64,
+    alignment: Alignment,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Alignment {
+    Epoch,
+    Monday,
+    Month,
+}
+
+impl CandlePeriod {
+    pub fn from_millis(millis: u64) -> Self {
+        Self {
+            millis: millis.max(1),
+            alignment: Alignment::Epoch,
+        }
+    }
+
+    pub fn from_duration(duration: Duration) -> Self {
+        Self::from_millis(duration.num_milliseconds().max(1) as u64)
+    }
+
+    /// Candles of `weeks` weeks starting on Mondays
+    pub fn weeks(weeks: u64) -> Self {
+        Self {
+            millis: weeks.max(1) * 7 * DAY,
+            alignment: Alignment::Monday,
+        }
+    }
+
+    /// Candles of a calendar month
+    pub fn month() -> Self {
+        Self {
+            millis: 30 * DAY,
+            alignment: Alignment::Month,
+        }
+    }
+
+    /// Length of a candle, 30 days for months
+    pub fn millis(&self) -> u64 {
+        self.millis
+    }
+
+    /// Start of the candle `time` belongs to. The first weekly candles start on the Unix epoch.
+    pub fn start(&self, time: u64) -> u64 {
+        match self.alignment {
+            Alignment::Epoch => time / self.millis * self.millis,
+            Alignment::Monday if time < FIRST_MONDAY => 0,
+            Alignment::Monday => (time - FIRST_MONDAY) / self.millis * self.millis + FIRST_MONDAY,
+            Alignment::Month => {
+                let date = timestamp_to_naive_datetime(time).date();
+                month_start(date.year(), date.month())
+            }
+        }
+    }
+
+    /// Start of the candle after the one `time` belongs to
+    pub fn next(&self, time: u64) -> u64 {
+        match self.alignment {
+            Alignment::Epoch => self.start(time) + self.millis,
+            Alignment::Monday if time < FIRST_MONDAY => FIRST_MONDAY,
+            Alignment::Monday => self.start(time) + self.millis,
+            Alignment::Month => {
+                let date = timestamp_to_naive_datetime(time).date();
+                match date.month() {
+                    12 => month_start(date.year() + 1, 1),
+                    month => month_start(date.year(), month + 1),
+                }
+            }
+        }
+    }
+
+    /// Whether candles of `other` can be merged into candles of this period
+    pub fn is_multiple_of(&self, other: CandlePeriod) -> bool {
+        match (self.alignment, other.alignment) {
+            (Alignment::Month, Alignment::Month) => true,
+            (Alignment::Month, Alignment::Epoch) => DAY % other.millis == 0,
+            (Alignment::Monday, Alignment::Epoch) => DAY % other.millis == 0,
+            (alignment, other_alignment) if alignment == other_alignment => {
+                self.millis % other.millis == 0
+            }
+            _ => false,
+        }
+    }
+}
+
+fn month_start(year: i32, month: u32) -> u64 {
+    NaiveDate::from_ymd(year, month, 1)
+        .and_hms(0, 0, 0)
+        .timestamp_millis() as u64
+}
+
+impl From<Interval> for CandlePeriod {
+    fn from(interval: Interval) -> Self {
+        match interval {
+            Interval::OneWeek => Self::weeks(1),
+            Interval::OneMonth => Self::month(),
+            _ => Self::from_duration(interval.to_duration()),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use rust_decimal::prelude::{Decimal, Zero};
+use crate::model::{Candle, Interval};
+use super::{CandlePeriod, TimeRange};
+
+/// This struct represents the candles of a market over a time range, oldest first, and the
+/// intervals of the range without a candle.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CandleSeries {
+    pub interval: Interval,
+    pub candles: Vec<Candle>,
+    pub gaps: Vec<TimeRange>,
+}
+
+impl CandleSeries {
+    /// `candles` must be sorted. Candles are expected every interval from the first candle
+    /// start in `range` to `range.end`.
+    pub fn new(interval: Interval, candles: Vec<Candle>, range: TimeRange) -> Self {
+        let gaps = detect_gaps(&candles, interval, range);
+        Self {
+            interval,
+            candles,
+            gaps,
+        }
+    }
+
+    /// Fills the gaps with flat candles at the close of the candle before them, or at the
+    /// open of the candle after them at the start of the series, without volume. The flat
+    /// candles have the market of the candle they copy.
+    pub fn fill_gaps(&mut self) {
+        if self.gaps.is_empty() || self.candles.is_empty() {
+            return;
+        }
+        let period = CandlePeriod::from(self.interval);
+        let mut filled = Vec::with_capacity(self.candles.len());
+        let mut candles = self.candles.drain(..).peekable();
+        for gap in self.gaps.drain(..) {
+            while let Some(candle) = candles.next_if(|candle| candle.time < gap.start) {
+                filled.push(candle);
+            }
+            let neighbour = match (filled.last(), candles.peek()) {
+                (Some(previous), _) => Some((previous.close, previous)),
+                (None, Some(next)) => Some((next.open, next)),
+                (None, None) => None,
+            };
+            let price = neighbour.map_or(Decimal::zero(), |(price, _)| price);
+            let market_pair = neighbour.and_then(|(_, candle)| candle.market_pair.clone());
+            let instrument = neighbour.and_then(|(_, candle)| candle.instrument.clone());
+            let mut time = gap.start;
+            while time < gap.end {
+                filled.push(Candle {
+                    market_pair: market_pair.clone(),
+                    instrument: instrument.clone(),
+                    time,
+                    low: price,
+                    high: price,
+                    open: price,
+                    close: price,
+                    volume: Decimal::zero(),
+                });
+                time = period.next(time);
+            }
+        }
+        filled.extend(candles);
+        self.candles = filled;
+    }
+}
+
+pub fn interval_millis(interval: Interval) -> u64 {
+    interval.to_duration().num_milliseconds().max(1) as u64
+}
+
+/// Ranges of the expected candles of `range` missing from the sorted `candles`
+pub fn detect_gaps(candles: &[Candle], interval: Interval, range: TimeRange) -> Vec<TimeRange> {
+    let period = CandlePeriod::from(interval);
+    let mut gaps: Vec<TimeRange> = Vec::new();
+    let mut candles = candles.iter().map(|candle| candle.time).peekable();
+    let mut time = match period.start(range.start) {
+        start if start < range.start => period.next(start),
+        start => start,
+    };
+    while time < range.end {
+        let next = period.next(time);
+        while candles.next_if(|candle| *candle < time).is_some() {}
+        if candles.next_if_eq(&time).is_none() {
+            match gaps.last_mut() {
+                Some(gap) if gap.end == time => gap.end = next,
+                _ => gaps.push(TimeRange::new(time, next)),
+            }
+        }
+        time = next;
+    }
+    gaps
+}
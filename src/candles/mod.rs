@@ -0,0 +1,50 @@
+//! This module provides the download of the candle history of a market.
+//! Ranges are requested in chunks of the size the exchange allows, with bounded concurrency,
+//! and the candles are returned sorted, without duplicates and with the missing intervals
+//! detected or filled. With a cache directory, re-runs only request the new candles.
+//! Candles of any period can also be built from finer candles or from trades, see
+//! `ResampledMarketData` and `trade_candles`.
+//! # Example
+//! ```no_run
+//! use openlimits::candles::{CandleDownloader, CandleDownloaderConfig, CandleLimits};
+//! use openlimits::exchange::binance::{Binance, BinanceParameters};
+//! use openlimits::model::Interval;
+//! use openlimits::prelude::*;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let binance = Binance::new(BinanceParameters::prod())
+//!                         .await
+//!                         .expect("Couldn't create binance client");
+//!     let config = CandleDownloaderConfig::new("binance", CandleLimits::binance())
+//!                         .with_concurrency(8)
+//!                         .with_cache("candles")
+//!                         .with_gap_filling(true);
+//!     let downloader = CandleDownloader::new(&binance, config);
+//!
+//!     let series = downloader.download("BNBBTC", Interval::OneHour, 1577836800000, 1609459200000)
+//!                         .await
+//!                         .expect("Couldn't download candles");
+//!     println!("{} candles, {} gaps", series.candles.len(), series.gaps.len());
+//! }
+//! ```
+
+mod candle_builder;
+mod candle_cache;
+mod candle_downloader;
+mod candle_downloader_config;
+mod candle_limits;
+mod candle_period;
+mod candle_series;
+mod resampled_market_data;
+mod time_range;
+
+pub use candle_builder::{aggregate_trades, resample, trade_candles, CandleBuilder};
+pub use candle_cache::{interval_code, CachedCandles, CandleCache};
+pub use candle_downloader::CandleDownloader;
+pub use candle_downloader_config::CandleDownloaderConfig;
+pub use candle_limits::CandleLimits;
+pub use candle_period::{CandlePeriod, INTERVALS};
+pub use candle_series::{detect_gaps, interval_millis, CandleSeries};
+pub use resampled_market_data::ResampledMarketData;
+pub use time_range::TimeRange;
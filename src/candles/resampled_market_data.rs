@@ -0,0 +1,88 @@
+use async_trait::async_trait;
+use crate::errors::OpenLimitsError;
+use crate::exchange::shared::Result;
+use crate::exchange::traits::ExchangeMarketData;
+use crate::model::{
+    Candle, GetHistoricRatesRequest, GetHistoricTradesRequest, GetPriceTickerRequest,
+    OrderBookRequest, OrderBookResponse, Paginator, Ticker, Trade,
+};
+use super::{aggregate_trades, resample, CandleLimits, CandlePeriod};
+
+/// This struct represents the market data of an exchange serving every interval.
+/// Candles of the intervals the exchange doesn't support are built from the coarsest one it
+/// does, the other requests are forwarded.
+pub struct ResampledMarketData<'a, M: ExchangeMarketData + Sync> {
+    market: &'a M,
+    pub limits: CandleLimits,
+}
+
+impl<'a, M: ExchangeMarketData + Sync> ResampledMarketData<'a, M> {
+    pub fn new(market: &'a M, limits: CandleLimits) -> Self {
+        Self { market, limits }
+    }
+
+    /// Candles of any period. The start time of the paginator is moved to the start of its
+    /// candle and its limit counts candles of `period`, up to what a single request of the
+    /// exchange returns. Without a start time, the oldest candle can be partial.
+    pub async fn historic_candles(
+        &self,
+        market_pair: &str,
+        period: CandlePeriod,
+        paginator: Option<Paginator>,
+    ) -> Result<Vec<Candle>> {
+        let interval = self.limits.base_interval(period).ok_or_else(|| {
+            OpenLimitsError::InvalidParameter(format!(
+                "Candles of {}ms can't be built from the supported intervals",
+                period.millis()
+            ))
+        })?;
+        let factor = period.millis() / CandlePeriod::from(interval).millis();
+        let paginator = paginator.map(|paginator| Paginator {
+            start_time: paginator.start_time.map(|start| period.start(start)),
+            limit: paginator
+                .limit
+                .map(|limit| limit.saturating_mul(factor).min(self.limits.max_candles)),
+            ..paginator
+        });
+        let req = GetHistoricRatesRequest {
+            market_pair: market_pair.to_string(),
+            interval,
+            paginator,
+        };
+        let candles = self.market.get_historic_rates(&req).await?;
+        Ok(resample(&candles, period))
+    }
+
+    /// Candles of any period built from the trades of the request
+    pub async fn candles_from_trades(
+        &self,
+        req: &GetHistoricTradesRequest,
+        period: CandlePeriod,
+    ) -> Result<Vec<Candle>> {
+        let trades = self.market.get_historic_trades(req).await?;
+        Ok(aggregate_trades(&trades, period))
+    }
+}
+
+#[async_trait]
+impl<'a, M: ExchangeMarketData + Sync> ExchangeMarketData for ResampledMarketData<'a, M> {
+    async fn order_book(&self, req: &OrderBookRequest) -> Result<OrderBookResponse> {
+        self.market.order_book(req).await
+    }
+
+    async fn get_price_ticker(&self, req: &GetPriceTickerRequest) -> Result<Ticker> {
+        self.market.get_price_ticker(req).await
+    }
+
+    async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
+        if self.limits.supports(req.interval) {
+            return self.market.get_historic_rates(req).await;
+        }
+        self.historic_candles(&req.market_pair, req.interval.into(), req.paginator.clone())
+            .await
+    }
+
+    async fn get_historic_trades(&self, req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
+        self.market.get_historic_trades(req).await
+    }
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+/// This struct represents a range of millisecond timestamps, `end` excluded
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimeRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl TimeRange {
+    pub fn new(start: u64, end: u64) -> Self {
+        Self { start, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn contains(&self, time: u64) -> bool {
+        self.start <= time && time < self.end
+    }
+
+    /// Pieces of the range of `length` at most
+    pub fn split(&self, length: u64) -> Vec<TimeRange> {
+        let mut pieces = Vec::new();
+        let mut start = self.start;
+        while start < self.end {
+            let end = self.end.min(start.saturating_add(length.max(1)));
+            pieces.push(TimeRange::new(start, end));
+            start = end;
+        }
+        pieces
+    }
+
+    /// Parts of the range outside of `ranges`
+    pub fn subtract(&self, ranges: &[TimeRange]) -> Vec<TimeRange> {
+        let mut remaining = vec![*self];
+        for range in ranges {
+            remaining = remaining
+                .into_iter()
+                .flat_map(|piece| {
+                    vec![
+                        TimeRange::new(piece.start, piece.end.min(range.start)),
+                        TimeRange::new(piece.start.max(range.end), piece.end),
+                    ]
+                })
+                .filter(|piece| !piece.is_empty())
+                .collect();
+        }
+        remaining
+    }
+
+    /// Sorted union of ranges, touching ranges are merged
+    pub fn merge(mut ranges: Vec<TimeRange>) -> Vec<TimeRange> {
+        ranges.retain(|range| !range.is_empty());
+        ranges.sort_by_key(|range| range.start);
+        let mut merged: Vec<TimeRange> = Vec::new();
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        merged
+    }
+}
@@ -0,0 +1,115 @@
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt;
+
+/// Fragments of the messages of the exchanges, lower case, and the kind of error they report.
+/// The first fragment found in a message wins.
+const MESSAGE_KINDS: &[(&str, ErrorKind)] = &[
+    ("insufficient", ErrorKind::InsufficientFunds),
+    ("not enough balance", ErrorKind::InsufficientFunds),
+    ("banned", ErrorKind::Banned),
+    ("rate limit", ErrorKind::RateLimited),
+    ("too many", ErrorKind::RateLimited),
+    ("order not found", ErrorKind::OrderNotFound),
+    ("unknown order", ErrorKind::OrderNotFound),
+    ("order does not exist", ErrorKind::OrderNotFound),
+    ("product not found", ErrorKind::SymbolNotFound),
+    ("market not found", ErrorKind::SymbolNotFound),
+    ("invalid market", ErrorKind::SymbolNotFound),
+    ("invalid product", ErrorKind::SymbolNotFound),
+    ("invalid signature", ErrorKind::AuthFailure),
+    ("invalid api key", ErrorKind::AuthFailure),
+    ("invalid passphrase", ErrorKind::AuthFailure),
+    ("unauthorized", ErrorKind::AuthFailure),
+    ("not authenticated", ErrorKind::AuthFailure),
+    ("timestamp", ErrorKind::Transient),
+    ("timeout", ErrorKind::Transient),
+    ("timed out", ErrorKind::Transient),
+    ("unavailable", ErrorKind::Transient),
+    ("internal server error", ErrorKind::Transient),
+    ("disconnected", ErrorKind::Transient),
+    ("post only", ErrorKind::InvalidOrder),
+    ("post-only", ErrorKind::InvalidOrder),
+    ("too small", ErrorKind::InvalidOrder),
+    ("too large", ErrorKind::InvalidOrder),
+    ("too accurate", ErrorKind::InvalidOrder),
+    ("minimum", ErrorKind::InvalidOrder),
+    ("maximum", ErrorKind::InvalidOrder),
+    ("invalid order", ErrorKind::InvalidOrder),
+    ("invalid price", ErrorKind::InvalidOrder),
+    ("invalid size", ErrorKind::InvalidOrder),
+    ("invalid amount", ErrorKind::InvalidOrder),
+];
+
+/// This enum represents the kind of an error, whatever the exchange reporting it
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    InsufficientFunds,
+    RateLimited,
+    /// The IP or the account is banned for a while, sending requests again extends the ban
+    Banned,
+    /// The order breaks a rule of the market, e.g. its size or its price
+    InvalidOrder,
+    OrderNotFound,
+    SymbolNotFound,
+    AuthFailure,
+    /// The exchange or the network failed, the same request may succeed later
+    Transient,
+    /// The request is malformed or a parameter is missing
+    InvalidRequest,
+    Unsupported,
+    Unknown,
+}
+
+impl ErrorKind {
+    /// Whether sending the same request again may succeed
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::RateLimited | Self::Transient)
+    }
+
+    /// Kind of an HTTP status that isn't a success
+    pub fn from_status(status: u16) -> Self {
+        match status {
+            401 | 403 => Self::AuthFailure,
+            418 => Self::Banned,
+            429 => Self::RateLimited,
+            400 | 405 | 413 | 415 | 422 => Self::InvalidRequest,
+            408 | 500..=599 => Self::Transient,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Kind of an error message of an exchange
+    pub fn from_message(message: &str) -> Self {
+        let message = message.to_lowercase();
+        MESSAGE_KINDS
+            .iter()
+            .find(|(fragment, _)| message.contains(fragment))
+            .map_or(Self::Unknown, |(_, kind)| *kind)
+    }
+}
+
+impl Default for ErrorKind {
+    fn default() -> Self {
+        Self::Unknown
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Self::InsufficientFunds => "insufficient funds",
+            Self::RateLimited => "rate limited",
+            Self::Banned => "banned",
+            Self::InvalidOrder => "invalid order",
+            Self::OrderNotFound => "order not found",
+            Self::SymbolNotFound => "symbol not found",
+            Self::AuthFailure => "authentication failure",
+            Self::Transient => "transient failure",
+            Self::InvalidRequest => "invalid request",
+            Self::Unsupported => "unsupported",
+            Self::Unknown => "unknown error",
+        };
+        write!(f, "{}", name)
+    }
+}
@@ -1,8 +1,10 @@
 // This module contains all the possible OpenLimits errors.
 
+mod error_kind;
 mod missing_implementation_content;
 mod open_limit_error;
 
+pub use error_kind::ErrorKind;
 pub use missing_implementation_content::MissingImplementationContent;
 pub use open_limit_error::OpenLimitsError;
 
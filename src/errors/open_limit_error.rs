@@ -1,37 +1,57 @@
+use rust_decimal::Decimal;
 use thiserror::Error;
 use crate::exchange::binance::BinanceContentError;
+use crate::exchange::bitfinex::BitfinexContentError;
 use crate::exchange::coinbase::CoinbaseContentError;
+use crate::exchange::gemini::GeminiContentError;
+use crate::exchange::kucoin::KucoinContentError;
+use crate::exchange::okx::OkxContentError;
+use crate::validation::MarketRule;
+use super::ErrorKind;
 use super::MissingImplementationContent;
 
+/// This enum represents the errors of the library. Whatever the exchange, `kind` tells what
+/// went wrong and `is_retryable` whether the request may succeed later, while `code`, `status`
+/// and the message keep what the exchange answered.
 #[derive(Error, Debug)]
 pub enum OpenLimitsError {
-    #[error("")]
+    #[error("no market pair")]
     NoMarketPair,
     #[error(transparent)]
     BinanceError(#[from] BinanceContentError),
     #[error(transparent)]
+    BitfinexError(#[from] BitfinexContentError),
+    #[error(transparent)]
     CoinbaseError(#[from] CoinbaseContentError),
     #[error(transparent)]
+    GeminiError(#[from] GeminiContentError),
+    #[error(transparent)]
+    KucoinError(#[from] KucoinContentError),
+    #[error(transparent)]
+    OkxError(#[from] OkxContentError),
+    #[error(transparent)]
     NashProtocolError(#[from] nash_protocol::errors::ProtocolError),
     #[error(transparent)]
     MissingImplementation(#[from] MissingImplementationContent),
-    #[error("")]
+    #[error("asset not found")]
     AssetNotFound(),
-    #[error("")]
+    #[error("no api key set")]
     NoApiKeySet(),
-    #[error("")]
+    #[error("internal server error")]
     InternalServerError(),
-    #[error("")]
+    #[error("service unavailable")]
     ServiceUnavailable(),
-    #[error("")]
+    #[error("too many requests")]
+    TooManyRequests(),
+    #[error("unauthorized")]
     Unauthorized(),
-    #[error("")]
+    #[error("symbol not found")]
     SymbolNotFound(),
-    #[error("")]
+    #[error("socket error")]
     SocketError(),
-    #[error("")]
+    #[error("websocket message not supported")]
     WebSocketMessageNotSupported(),
-    #[error("")]
+    #[error("couldn't get timestamp")]
     GetTimestampFailed(),
     #[error(transparent)]
     ReqError(#[from] reqwest::Error),
@@ -41,7 +61,7 @@ pub enum OpenLimitsError {
     InvalidPayloadSignature(#[from] serde_urlencoded::ser::Error),
     #[error(transparent)]
     IoError(#[from] std::io::Error),
-    #[error("")]
+    #[error("lock poisoned")]
     PoisonError(),
     #[error(transparent)]
     JsonError(#[from] serde_json::Error),
@@ -53,12 +73,108 @@ pub enum OpenLimitsError {
     Tungstenite(#[from] tokio_tungstenite::tungstenite::Error),
     #[error(transparent)]
     TimestampError(#[from] std::time::SystemTimeError),
-    #[error("")]
+    #[error("unknown response: {0}")]
     UnkownResponse(String),
-    #[error("")]
+    #[error("response not parsable: {0}")]
     NotParsableResponse(String),
-    #[error("")]
+    #[error("missing parameter: {0}")]
     MissingParameter(String),
-    #[error("")]
+    #[error("invalid parameter: {0}")]
     InvalidParameter(String),
+    #[error("checksum mismatch: {0}")]
+    ChecksumMismatch(String),
+    /// An order of a simulated venue needs more funds than the account has free
+    #[error("insufficient {asset} balance: {required} required, {available} available")]
+    InsufficientFunds {
+        asset: String,
+        required: Decimal,
+        available: Decimal,
+    },
+    /// A response that isn't a success and whose body isn't an error of the exchange
+    #[error("http status {status}: {body}")]
+    HttpError { status: u16, body: String },
+}
+
+impl OpenLimitsError {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::BinanceError(error) => error.kind(),
+            Self::CoinbaseError(error) => error.kind(),
+            Self::BitfinexError(error) => ErrorKind::from_message(&error.msg),
+            Self::GeminiError(error) => ErrorKind::from_message(&error.message),
+            Self::KucoinError(error) => ErrorKind::from_message(&error.msg),
+            Self::OkxError(error) => ErrorKind::from_message(&error.msg),
+            Self::NashProtocolError(error) => ErrorKind::from_message(&error.to_string()),
+            Self::HttpError { status, .. } => ErrorKind::from_status(*status),
+            Self::ReqError(error) => match error.status() {
+                Some(status) => ErrorKind::from_status(status.as_u16()),
+                None if error.is_timeout() || error.is_connect() || error.is_request() => {
+                    ErrorKind::Transient
+                }
+                None => ErrorKind::Unknown,
+            },
+            Self::InvalidParameter(_) if MarketRule::violated_by(self).is_some() => {
+                ErrorKind::InvalidOrder
+            }
+            Self::InsufficientFunds { .. } => ErrorKind::InsufficientFunds,
+            Self::TooManyRequests() => ErrorKind::RateLimited,
+            Self::InternalServerError()
+            | Self::ServiceUnavailable()
+            | Self::SocketError()
+            | Self::Tungstenite(_)
+            | Self::ChecksumMismatch(_) => ErrorKind::Transient,
+            Self::Unauthorized() | Self::NoApiKeySet() => ErrorKind::AuthFailure,
+            Self::SymbolNotFound() => ErrorKind::SymbolNotFound,
+            Self::MissingImplementation(_) | Self::WebSocketMessageNotSupported() => {
+                ErrorKind::Unsupported
+            }
+            Self::NoMarketPair
+            | Self::AssetNotFound()
+            | Self::InvalidHeaderError(_)
+            | Self::InvalidPayloadSignature(_)
+            | Self::ParseFloatError(_)
+            | Self::UrlParserError(_)
+            | Self::MissingParameter(_)
+            | Self::InvalidParameter(_) => ErrorKind::InvalidRequest,
+            Self::GetTimestampFailed()
+            | Self::IoError(_)
+            | Self::PoisonError()
+            | Self::JsonError(_)
+            | Self::TimestampError(_)
+            | Self::UnkownResponse(_)
+            | Self::NotParsableResponse(_) => ErrorKind::Unknown,
+        }
+    }
+
+    /// Whether sending the same request again may succeed
+    pub fn is_retryable(&self) -> bool {
+        self.kind().is_retryable()
+    }
+
+    /// Error code of the exchange, when it has codes
+    pub fn code(&self) -> Option<String> {
+        match self {
+            Self::BinanceError(error) => Some(error.code.to_string()),
+            Self::BitfinexError(error) => Some(error.code.to_string()),
+            Self::GeminiError(error) => Some(error.reason.clone()),
+            Self::KucoinError(error) => Some(error.code.clone()),
+            Self::OkxError(error) => Some(error.code.clone()),
+            _ => None,
+        }
+    }
+
+    /// HTTP status of the response reporting the error
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Self::BinanceError(error) => error.status,
+            Self::CoinbaseError(error) => error.status,
+            Self::HttpError { status, .. } => Some(*status),
+            Self::ReqError(error) => error.status().map(|status| status.as_u16()),
+            Self::TooManyRequests() => Some(429),
+            Self::InternalServerError() => Some(500),
+            Self::ServiceUnavailable() => Some(503),
+            Self::Unauthorized() => Some(401),
+            _ => None,
+        }
+    }
 }
@@ -1,5 +1,8 @@
 use async_trait::async_trait;
+use crate::candles::{CandleLimits, ResampledMarketData};
 use crate::exchange::coinbase::Coinbase;
+use crate::exchange::traits::info::CurrencyAliases;
+use crate::exchange::traits::info::ExchangeInfo;
 use crate::exchange::traits::info::ExchangeInfoRetrieval;
 use crate::exchange::traits::info::MarketPair;
 use crate::exchange::traits::info::MarketPairHandle;
@@ -8,21 +11,84 @@ use crate::exchange::traits::ExchangeAccount;
 use crate::exchange::traits::ExchangeMarketData;
 use crate::exchange::nash::Nash;
 use crate::exchange::binance::Binance;
+use crate::exchange::bitfinex::Bitfinex;
+use crate::exchange::kucoin::Kucoin;
+use crate::exchange::okx::Okx;
+use crate::exchange::gemini::Gemini;
+use crate::exchange::paper::PaperExchange;
 use crate::model::{
     Balance, CancelAllOrdersRequest, CancelOrderRequest, Candle,
     GetHistoricRatesRequest, GetHistoricTradesRequest, GetOrderHistoryRequest, GetOrderRequest,
-    GetPriceTickerRequest, OpenLimitOrderRequest, OpenMarketOrderRequest, Order,
+    GetPriceTickerRequest, Instrument, OpenLimitOrderRequest, OpenMarketOrderRequest, Order,
     OrderBookRequest, OrderBookResponse, OrderCanceled, Paginator, Ticker, Trade,
     TradeHistoryRequest,
 };
 use super::shared::Result;
 use super::InitAnyExchange;
 
-/// Exchanges avaliables: Nash, Binance and Coinbase
+/// Exchanges avaliables: Nash, Binance, Bitfinex, Coinbase, Gemini, Kucoin and Okx.
+/// Paper simulates the orders against the market data of any of them.
+/// The market pairs of the requests are either symbols of the exchange or instruments, e.g.
+/// `BTC/USDT`, and the responses carry both.
 pub enum AnyExchange {
     Nash(Nash),
     Binance(Binance),
+    Bitfinex(Bitfinex),
     Coinbase(Coinbase),
+    Kucoin(Kucoin),
+    Okx(Okx),
+    Gemini(Gemini),
+    Paper(Box<PaperExchange<AnyExchange>>),
+}
+
+impl AnyExchange {
+    pub fn exchange_info(&self) -> &ExchangeInfo {
+        match self {
+            Self::Nash(nash) => &nash.exchange_info,
+            Self::Binance(binance) => &binance.exchange_info,
+            Self::Bitfinex(bitfinex) => &bitfinex.exchange_info,
+            Self::Coinbase(coinbase) => &coinbase.exchange_info,
+            Self::Kucoin(kucoin) => &kucoin.exchange_info,
+            Self::Okx(okx) => &okx.exchange_info,
+            Self::Gemini(gemini) => &gemini.exchange_info,
+            Self::Paper(paper) => paper.market.exchange_info(),
+        }
+    }
+
+    /// What the `get_historic_rates` requests of the exchange accept. Candles of the other
+    /// intervals are built from the coarsest supported one.
+    pub fn candle_limits(&self) -> CandleLimits {
+        match self {
+            Self::Nash(_) => CandleLimits::nash(),
+            Self::Binance(_) => CandleLimits::binance(),
+            Self::Bitfinex(_) => CandleLimits::bitfinex(),
+            Self::Coinbase(_) => CandleLimits::coinbase(),
+            Self::Kucoin(_) => CandleLimits::kucoin(),
+            Self::Okx(_) => CandleLimits::okx(),
+            Self::Gemini(_) => CandleLimits::gemini(),
+            Self::Paper(paper) => paper.market.candle_limits(),
+        }
+    }
+
+    /// Currencies considered the same when translating instruments, e.g. `USDC` and `USD`
+    pub fn set_currency_aliases(&self, aliases: CurrencyAliases) -> Result<()> {
+        self.exchange_info().set_aliases(aliases)
+    }
+
+    pub fn instrument(&self, market_pair: &str) -> Result<Instrument> {
+        self.exchange_info().instrument(market_pair)
+    }
+
+    pub fn symbol(&self, market_pair: &str) -> Result<String> {
+        self.exchange_info().symbol(market_pair)
+    }
+
+    fn optional_symbol(&self, market_pair: &Option<String>) -> Result<Option<String>> {
+        market_pair
+            .as_ref()
+            .map(|market_pair| self.symbol(market_pair))
+            .transpose()
+    }
 }
 
 #[async_trait]
@@ -37,9 +103,24 @@ impl Exchange for AnyExchange {
             InitAnyExchange::Binance(params) => {
                 Binance::new(params).await.map(|exchange| exchange.into())
             }
+            InitAnyExchange::Bitfinex(params) => {
+                Bitfinex::new(params).await.map(|exchange| exchange.into())
+            }
             InitAnyExchange::Coinbase(params) => {
                 Coinbase::new(params).await.map(|exchange| exchange.into())
             }
+            InitAnyExchange::Kucoin(params) => {
+                Kucoin::new(params).await.map(|exchange| exchange.into())
+            }
+            InitAnyExchange::Okx(params) => {
+                Okx::new(params).await.map(|exchange| exchange.into())
+            }
+            InitAnyExchange::Gemini(params) => {
+                Gemini::new(params).await.map(|exchange| exchange.into())
+            }
+            InitAnyExchange::Paper(params) => {
+                PaperExchange::<AnyExchange>::new(*params).await.map(|exchange| exchange.into())
+            }
         }
     }
     /// not particularly useful to access the inner client with this type. could wrap the inner
@@ -56,21 +137,36 @@ impl ExchangeInfoRetrieval for AnyExchange {
         match self {
             Self::Nash(nash) => nash.get_pair(name).await,
             Self::Binance(binance) => binance.get_pair(name).await,
+            Self::Bitfinex(bitfinex) => bitfinex.get_pair(name).await,
             Self::Coinbase(coinbase) => coinbase.get_pair(name).await,
+            Self::Kucoin(kucoin) => kucoin.get_pair(name).await,
+            Self::Okx(okx) => okx.get_pair(name).await,
+            Self::Gemini(gemini) => gemini.get_pair(name).await,
+            Self::Paper(paper) => paper.get_pair(name).await,
         }
     }
     async fn retrieve_pairs(&self) -> Result<Vec<MarketPair>> {
         match self {
             Self::Nash(nash) => nash.retrieve_pairs().await,
             Self::Binance(binance) => binance.retrieve_pairs().await,
+            Self::Bitfinex(bitfinex) => bitfinex.retrieve_pairs().await,
             Self::Coinbase(coinbase) => coinbase.retrieve_pairs().await,
+            Self::Kucoin(kucoin) => kucoin.retrieve_pairs().await,
+            Self::Okx(okx) => okx.retrieve_pairs().await,
+            Self::Gemini(gemini) => gemini.retrieve_pairs().await,
+            Self::Paper(paper) => paper.retrieve_pairs().await,
         }
     }
     async fn refresh_market_info(&self) -> Result<Vec<MarketPairHandle>> {
         match self {
             Self::Nash(nash) => nash.refresh_market_info().await,
             Self::Binance(binance) => binance.refresh_market_info().await,
+            Self::Bitfinex(bitfinex) => bitfinex.refresh_market_info().await,
             Self::Coinbase(coinbase) => coinbase.refresh_market_info().await,
+            Self::Kucoin(kucoin) => kucoin.refresh_market_info().await,
+            Self::Okx(okx) => okx.refresh_market_info().await,
+            Self::Gemini(gemini) => gemini.refresh_market_info().await,
+            Self::Paper(paper) => paper.refresh_market_info().await,
         }
     }
 }
@@ -78,113 +174,256 @@ impl ExchangeInfoRetrieval for AnyExchange {
 #[async_trait]
 impl ExchangeAccount for AnyExchange {
     async fn limit_buy(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
-        match self {
+        let req = &OpenLimitOrderRequest {
+            market_pair: self.symbol(&req.market_pair)?,
+            ..req.clone()
+        };
+        let order = match self {
             Self::Nash(nash) => nash.limit_buy(req).await,
             Self::Binance(binance) => binance.limit_buy(req).await,
+            Self::Bitfinex(bitfinex) => bitfinex.limit_buy(req).await,
             Self::Coinbase(coinbase) => coinbase.limit_buy(req).await,
-        }
+            Self::Kucoin(kucoin) => kucoin.limit_buy(req).await,
+            Self::Okx(okx) => okx.limit_buy(req).await,
+            Self::Gemini(gemini) => gemini.limit_buy(req).await,
+            Self::Paper(paper) => paper.limit_buy(req).await,
+        }?;
+        Ok(self.exchange_info().translate(order))
     }
     async fn limit_sell(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
-        match self {
+        let req = &OpenLimitOrderRequest {
+            market_pair: self.symbol(&req.market_pair)?,
+            ..req.clone()
+        };
+        let order = match self {
             Self::Nash(nash) => nash.limit_sell(req).await,
             Self::Binance(binance) => binance.limit_sell(req).await,
+            Self::Bitfinex(bitfinex) => bitfinex.limit_sell(req).await,
             Self::Coinbase(coinbase) => coinbase.limit_sell(req).await,
-        }
+            Self::Kucoin(kucoin) => kucoin.limit_sell(req).await,
+            Self::Okx(okx) => okx.limit_sell(req).await,
+            Self::Gemini(gemini) => gemini.limit_sell(req).await,
+            Self::Paper(paper) => paper.limit_sell(req).await,
+        }?;
+        Ok(self.exchange_info().translate(order))
     }
     async fn market_buy(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
-        match self {
+        let req = &OpenMarketOrderRequest {
+            market_pair: self.symbol(&req.market_pair)?,
+            ..req.clone()
+        };
+        let order = match self {
             Self::Nash(nash) => nash.market_buy(req).await,
             Self::Binance(binance) => binance.market_buy(req).await,
+            Self::Bitfinex(bitfinex) => bitfinex.market_buy(req).await,
             Self::Coinbase(coinbase) => coinbase.market_buy(req).await,
-        }
+            Self::Kucoin(kucoin) => kucoin.market_buy(req).await,
+            Self::Okx(okx) => okx.market_buy(req).await,
+            Self::Gemini(gemini) => gemini.market_buy(req).await,
+            Self::Paper(paper) => paper.market_buy(req).await,
+        }?;
+        Ok(self.exchange_info().translate(order))
     }
     async fn market_sell(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
-        match self {
+        let req = &OpenMarketOrderRequest {
+            market_pair: self.symbol(&req.market_pair)?,
+            ..req.clone()
+        };
+        let order = match self {
             Self::Nash(nash) => nash.market_sell(req).await,
             Self::Binance(binance) => binance.market_sell(req).await,
+            Self::Bitfinex(bitfinex) => bitfinex.market_sell(req).await,
             Self::Coinbase(coinbase) => coinbase.market_sell(req).await,
-        }
+            Self::Kucoin(kucoin) => kucoin.market_sell(req).await,
+            Self::Okx(okx) => okx.market_sell(req).await,
+            Self::Gemini(gemini) => gemini.market_sell(req).await,
+            Self::Paper(paper) => paper.market_sell(req).await,
+        }?;
+        Ok(self.exchange_info().translate(order))
     }
     async fn cancel_order(&self, req: &CancelOrderRequest) -> Result<OrderCanceled> {
+        let req = &CancelOrderRequest {
+            market_pair: self.optional_symbol(&req.market_pair)?,
+            ..req.clone()
+        };
         match self {
             Self::Nash(nash) => nash.cancel_order(req).await,
             Self::Binance(binance) => binance.cancel_order(req).await,
+            Self::Bitfinex(bitfinex) => bitfinex.cancel_order(req).await,
             Self::Coinbase(coinbase) => coinbase.cancel_order(req).await,
+            Self::Kucoin(kucoin) => kucoin.cancel_order(req).await,
+            Self::Okx(okx) => okx.cancel_order(req).await,
+            Self::Gemini(gemini) => gemini.cancel_order(req).await,
+            Self::Paper(paper) => paper.cancel_order(req).await,
         }
     }
     async fn cancel_all_orders(&self, req: &CancelAllOrdersRequest) -> Result<Vec<OrderCanceled>> {
+        let req = &CancelAllOrdersRequest {
+            market_pair: self.optional_symbol(&req.market_pair)?,
+            ..req.clone()
+        };
         match self {
             Self::Nash(nash) => nash.cancel_all_orders(req).await,
             Self::Binance(binance) => binance.cancel_all_orders(req).await,
+            Self::Bitfinex(bitfinex) => bitfinex.cancel_all_orders(req).await,
             Self::Coinbase(coinbase) => coinbase.cancel_all_orders(req).await,
+            Self::Kucoin(kucoin) => kucoin.cancel_all_orders(req).await,
+            Self::Okx(okx) => okx.cancel_all_orders(req).await,
+            Self::Gemini(gemini) => gemini.cancel_all_orders(req).await,
+            Self::Paper(paper) => paper.cancel_all_orders(req).await,
         }
     }
     async fn get_all_open_orders(&self) -> Result<Vec<Order>> {
-        match self {
+        let orders = match self {
             Self::Nash(nash) => nash.get_all_open_orders().await,
             Self::Binance(binance) => binance.get_all_open_orders().await,
+            Self::Bitfinex(bitfinex) => bitfinex.get_all_open_orders().await,
             Self::Coinbase(coinbase) => coinbase.get_all_open_orders().await,
-        }
+            Self::Kucoin(kucoin) => kucoin.get_all_open_orders().await,
+            Self::Okx(okx) => okx.get_all_open_orders().await,
+            Self::Gemini(gemini) => gemini.get_all_open_orders().await,
+            Self::Paper(paper) => paper.get_all_open_orders().await,
+        }?;
+        Ok(self.exchange_info().translate(orders))
     }
     async fn get_order_history(&self, req: &GetOrderHistoryRequest) -> Result<Vec<Order>> {
-        match self {
+        let req = &GetOrderHistoryRequest {
+            market_pair: self.optional_symbol(&req.market_pair)?,
+            ..req.clone()
+        };
+        let orders = match self {
             Self::Nash(nash) => nash.get_order_history(req).await,
             Self::Binance(binance) => binance.get_order_history(req).await,
+            Self::Bitfinex(bitfinex) => bitfinex.get_order_history(req).await,
             Self::Coinbase(coinbase) => coinbase.get_order_history(req).await,
-        }
+            Self::Kucoin(kucoin) => kucoin.get_order_history(req).await,
+            Self::Okx(okx) => okx.get_order_history(req).await,
+            Self::Gemini(gemini) => gemini.get_order_history(req).await,
+            Self::Paper(paper) => paper.get_order_history(req).await,
+        }?;
+        Ok(self.exchange_info().translate(orders))
     }
     async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>> {
-        match self {
+        let req = &TradeHistoryRequest {
+            market_pair: self.optional_symbol(&req.market_pair)?,
+            ..req.clone()
+        };
+        let trades = match self {
             Self::Nash(nash) => nash.get_trade_history(req).await,
             Self::Binance(binance) => binance.get_trade_history(req).await,
+            Self::Bitfinex(bitfinex) => bitfinex.get_trade_history(req).await,
             Self::Coinbase(coinbase) => coinbase.get_trade_history(req).await,
-        }
+            Self::Kucoin(kucoin) => kucoin.get_trade_history(req).await,
+            Self::Okx(okx) => okx.get_trade_history(req).await,
+            Self::Gemini(gemini) => gemini.get_trade_history(req).await,
+            Self::Paper(paper) => paper.get_trade_history(req).await,
+        }?;
+        Ok(self.exchange_info().translate(trades))
     }
     async fn get_account_balances(&self, paginator: Option<Paginator>) -> Result<Vec<Balance>> {
         match self {
             Self::Nash(nash) => nash.get_account_balances(paginator).await,
             Self::Binance(binance) => binance.get_account_balances(paginator).await,
+            Self::Bitfinex(bitfinex) => bitfinex.get_account_balances(paginator).await,
             Self::Coinbase(coinbase) => coinbase.get_account_balances(paginator).await,
+            Self::Kucoin(kucoin) => kucoin.get_account_balances(paginator).await,
+            Self::Okx(okx) => okx.get_account_balances(paginator).await,
+            Self::Gemini(gemini) => gemini.get_account_balances(paginator).await,
+            Self::Paper(paper) => paper.get_account_balances(paginator).await,
         }
     }
     async fn get_order(&self, req: &GetOrderRequest) -> Result<Order> {
-        match self {
+        let req = &GetOrderRequest {
+            market_pair: self.optional_symbol(&req.market_pair)?,
+            ..req.clone()
+        };
+        let order = match self {
             Self::Nash(nash) => nash.get_order(req).await,
             Self::Binance(binance) => binance.get_order(req).await,
+            Self::Bitfinex(bitfinex) => bitfinex.get_order(req).await,
             Self::Coinbase(coinbase) => coinbase.get_order(req).await,
-        }
+            Self::Kucoin(kucoin) => kucoin.get_order(req).await,
+            Self::Okx(okx) => okx.get_order(req).await,
+            Self::Gemini(gemini) => gemini.get_order(req).await,
+            Self::Paper(paper) => paper.get_order(req).await,
+        }?;
+        Ok(self.exchange_info().translate(order))
     }
 }
 
 #[async_trait]
 impl ExchangeMarketData for AnyExchange {
     async fn order_book(&self, req: &OrderBookRequest) -> Result<OrderBookResponse> {
-        match self {
+        let req = &OrderBookRequest {
+            market_pair: self.symbol(&req.market_pair)?,
+            ..req.clone()
+        };
+        let book = match self {
             Self::Nash(nash) => nash.order_book(req).await,
             Self::Binance(binance) => binance.order_book(req).await,
+            Self::Bitfinex(bitfinex) => bitfinex.order_book(req).await,
             Self::Coinbase(coinbase) => coinbase.order_book(req).await,
-        }
+            Self::Kucoin(kucoin) => kucoin.order_book(req).await,
+            Self::Okx(okx) => okx.order_book(req).await,
+            Self::Gemini(gemini) => gemini.order_book(req).await,
+            Self::Paper(paper) => paper.order_book(req).await,
+        }?;
+        Ok(self.exchange_info().translate_market(&req.market_pair, book))
     }
     async fn get_price_ticker(&self, req: &GetPriceTickerRequest) -> Result<Ticker> {
-        match self {
+        let req = &GetPriceTickerRequest {
+            market_pair: self.symbol(&req.market_pair)?,
+            ..req.clone()
+        };
+        let ticker = match self {
             Self::Nash(nash) => nash.get_price_ticker(req).await,
             Self::Binance(binance) => binance.get_price_ticker(req).await,
+            Self::Bitfinex(bitfinex) => bitfinex.get_price_ticker(req).await,
             Self::Coinbase(coinbase) => coinbase.get_price_ticker(req).await,
-        }
+            Self::Kucoin(kucoin) => kucoin.get_price_ticker(req).await,
+            Self::Okx(okx) => okx.get_price_ticker(req).await,
+            Self::Gemini(gemini) => gemini.get_price_ticker(req).await,
+            Self::Paper(paper) => paper.get_price_ticker(req).await,
+        }?;
+        Ok(self.exchange_info().translate_market(&req.market_pair, ticker))
     }
     async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
-        match self {
+        let limits = self.candle_limits();
+        if !limits.supports(req.interval) {
+            return ResampledMarketData::new(self, limits).get_historic_rates(req).await;
+        }
+        let req = &GetHistoricRatesRequest {
+            market_pair: self.symbol(&req.market_pair)?,
+            ..req.clone()
+        };
+        let candles = match self {
             Self::Nash(nash) => nash.get_historic_rates(req).await,
             Self::Binance(binance) => binance.get_historic_rates(req).await,
+            Self::Bitfinex(bitfinex) => bitfinex.get_historic_rates(req).await,
             Self::Coinbase(coinbase) => coinbase.get_historic_rates(req).await,
-        }
+            Self::Kucoin(kucoin) => kucoin.get_historic_rates(req).await,
+            Self::Okx(okx) => okx.get_historic_rates(req).await,
+            Self::Gemini(gemini) => gemini.get_historic_rates(req).await,
+            Self::Paper(paper) => paper.get_historic_rates(req).await,
+        }?;
+        Ok(self.exchange_info().translate_market(&req.market_pair, candles))
     }
     async fn get_historic_trades(&self, req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
-        match self {
+        let req = &GetHistoricTradesRequest {
+            market_pair: self.symbol(&req.market_pair)?,
+            ..req.clone()
+        };
+        let trades = match self {
             Self::Nash(nash) => nash.get_historic_trades(req).await,
             Self::Binance(binance) => binance.get_historic_trades(req).await,
+            Self::Bitfinex(bitfinex) => bitfinex.get_historic_trades(req).await,
             Self::Coinbase(coinbase) => coinbase.get_historic_trades(req).await,
-        }
+            Self::Kucoin(kucoin) => kucoin.get_historic_trades(req).await,
+            Self::Okx(okx) => okx.get_historic_trades(req).await,
+            Self::Gemini(gemini) => gemini.get_historic_trades(req).await,
+            Self::Paper(paper) => paper.get_historic_trades(req).await,
+        }?;
+        Ok(self.exchange_info().translate(trades))
     }
 }
 
@@ -204,4 +443,34 @@ impl From<Binance> for AnyExchange {
     fn from(binance: Binance) -> Self {
         Self::Binance(binance)
     }
-}
\ No newline at end of file
+}
+
+impl From<Bitfinex> for AnyExchange {
+    fn from(bitfinex: Bitfinex) -> Self {
+        Self::Bitfinex(bitfinex)
+    }
+}
+
+impl From<Kucoin> for AnyExchange {
+    fn from(kucoin: Kucoin) -> Self {
+        Self::Kucoin(kucoin)
+    }
+}
+
+impl From<Okx> for AnyExchange {
+    fn from(okx: Okx) -> Self {
+        Self::Okx(okx)
+    }
+}
+
+impl From<Gemini> for AnyExchange {
+    fn from(gemini: Gemini) -> Self {
+        Self::Gemini(gemini)
+    }
+}
+
+impl From<PaperExchange<AnyExchange>> for AnyExchange {
+    fn from(paper: PaperExchange<AnyExchange>) -> Self {
+        Self::Paper(Box::new(paper))
+    }
+}
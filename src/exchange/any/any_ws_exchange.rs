@@ -3,18 +3,26 @@ use async_trait::async_trait;
 use futures::stream::BoxStream;
 use futures::stream::StreamExt;
 use crate::exchange::coinbase::client::websocket::CoinbaseWebsocket;
+use crate::exchange::traits::info::ExchangeInfo;
 use crate::exchange::traits::stream::ExchangeWs;
 use crate::exchange::traits::stream::OpenLimitsWs;
 use crate::exchange::traits::stream::Subscriptions;
 use crate::exchange::nash::NashWebsocket;
 use crate::exchange::binance::BinanceWebsocket;
+use crate::exchange::bitfinex::BitfinexWebsocket;
+use crate::exchange::kucoin::KucoinWebsocket;
+use crate::exchange::okx::OkxWebsocket;
+use crate::exchange::gemini::GeminiWebsocket;
 use crate::model::websocket::OpenLimitsWebSocketMessage;
 use crate::model::websocket::WebSocketResponse;
+use crate::model::websocket::AccountOrders;
 use crate::model::websocket::Subscription;
 use super::shared::Result;
 use super::InitAnyExchange;
 
-/// Websockets clients, this enum provides a websocket communication with the exchanges
+/// Websockets clients, this enum provides a websocket communication with the exchanges.
+/// With the exchange informations of the exchange, e.g. the ones of its `AnyExchange`, the
+/// markets of the subscriptions are either symbols or instruments and the messages carry both.
 /// # Example
 /// ```
 /// # Example
@@ -31,7 +39,7 @@ use super::InitAnyExchange;
 ///
 /// #[tokio::main]
 /// async fn main() {
-///     // Binance, Coinbase and Nash availables
+///     // Binance, Bitfinex, Coinbase, Gemini, Kucoin, Nash and Okx availables
 ///     let binance_websocket = AnyWsExchange::new(InitAnyExchange::Binance(BinanceParameters::prod()))
 ///                                 .await
 ///                                 .expect("Couldn't create binance websocket client");
@@ -54,7 +62,60 @@ use super::InitAnyExchange;
 pub enum AnyWsExchange {
     Nash(OpenLimitsWs<NashWebsocket>),
     Binance(OpenLimitsWs<BinanceWebsocket>),
+    Bitfinex(OpenLimitsWs<BitfinexWebsocket>),
     Coinbase(OpenLimitsWs<CoinbaseWebsocket>),
+    Kucoin(OpenLimitsWs<KucoinWebsocket>),
+    Okx(OpenLimitsWs<OkxWebsocket>),
+    Gemini(OpenLimitsWs<GeminiWebsocket>),
+}
+
+impl AnyWsExchange {
+    pub fn exchange_info(&self) -> Option<&ExchangeInfo> {
+        match self {
+            Self::Nash(nash) => nash.exchange_info.as_ref(),
+            Self::Binance(binance) => binance.exchange_info.as_ref(),
+            Self::Bitfinex(bitfinex) => bitfinex.exchange_info.as_ref(),
+            Self::Coinbase(coinbase) => coinbase.exchange_info.as_ref(),
+            Self::Kucoin(kucoin) => kucoin.exchange_info.as_ref(),
+            Self::Okx(okx) => okx.exchange_info.as_ref(),
+            Self::Gemini(gemini) => gemini.exchange_info.as_ref(),
+        }
+    }
+
+    pub fn with_exchange_info(self, exchange_info: ExchangeInfo) -> Self {
+        match self {
+            Self::Nash(nash) => Self::Nash(nash.with_exchange_info(exchange_info)),
+            Self::Binance(binance) => Self::Binance(binance.with_exchange_info(exchange_info)),
+            Self::Bitfinex(bitfinex) => Self::Bitfinex(bitfinex.with_exchange_info(exchange_info)),
+            Self::Coinbase(coinbase) => Self::Coinbase(coinbase.with_exchange_info(exchange_info)),
+            Self::Kucoin(kucoin) => Self::Kucoin(kucoin.with_exchange_info(exchange_info)),
+            Self::Okx(okx) => Self::Okx(okx.with_exchange_info(exchange_info)),
+            Self::Gemini(gemini) => Self::Gemini(gemini.with_exchange_info(exchange_info)),
+        }
+    }
+}
+
+/// The subscription to the symbol of the exchange of its market
+fn subscription_symbol(info: &ExchangeInfo, subscription: Subscription) -> Result<Subscription> {
+    Ok(match subscription {
+        Subscription::Ticker(market) => Subscription::Ticker(info.symbol(&market)?),
+        Subscription::OrderBookUpdates(market) => {
+            Subscription::OrderBookUpdates(info.symbol(&market)?)
+        }
+        Subscription::Trades(market) => Subscription::Trades(info.symbol(&market)?),
+        Subscription::AccountTrades(market) => Subscription::AccountTrades(info.symbol(&market)?),
+        Subscription::AccountBalance(market) => {
+            Subscription::AccountBalance(info.symbol(&market)?)
+        }
+        Subscription::AccountOrders(orders) => Subscription::AccountOrders(AccountOrders {
+            market: orders
+                .market
+                .as_ref()
+                .map(|market| info.symbol(market))
+                .transpose()?,
+            ..orders
+        }),
+    })
 }
 
 #[async_trait]
@@ -73,11 +134,33 @@ impl ExchangeWs for AnyWsExchange {
                     .await
                     .map(|exchange| exchange.into())
             }
+            InitAnyExchange::Bitfinex(params) => {
+                OpenLimitsWs::<BitfinexWebsocket>::instantiate(params)
+                    .await
+                    .map(|exchange| exchange.into())
+            }
             InitAnyExchange::Coinbase(params) => {
                 OpenLimitsWs::<CoinbaseWebsocket>::instantiate(params)
                     .await
                     .map(|exchange| exchange.into())
             }
+            InitAnyExchange::Kucoin(params) => {
+                OpenLimitsWs::<KucoinWebsocket>::instantiate(params)
+                    .await
+                    .map(|exchange| exchange.into())
+            }
+            InitAnyExchange::Okx(params) => {
+                OpenLimitsWs::<OkxWebsocket>::instantiate(params)
+                    .await
+                    .map(|exchange| exchange.into())
+            }
+            InitAnyExchange::Gemini(params) => {
+                OpenLimitsWs::<GeminiWebsocket>::instantiate(params)
+                    .await
+                    .map(|exchange| exchange.into())
+            }
+            // Paper trading streams the market data of the exchange it simulates
+            InitAnyExchange::Paper(params) => Self::new(params.market).await,
         }
     }
 
@@ -86,6 +169,10 @@ impl ExchangeWs for AnyWsExchange {
             Self::Nash(exchange) => exchange.disconnect().await,
             Self::Coinbase(exchange) => exchange.disconnect().await,
             Self::Binance(exchange) => exchange.disconnect().await,
+            Self::Bitfinex(exchange) => exchange.disconnect().await,
+            Self::Kucoin(exchange) => exchange.disconnect().await,
+            Self::Okx(exchange) => exchange.disconnect().await,
+            Self::Gemini(exchange) => exchange.disconnect().await,
         }
     }
 
@@ -93,6 +180,16 @@ impl ExchangeWs for AnyWsExchange {
         &self,
         subscriptions: Subscriptions<Self::Subscription>,
     ) -> Result<BoxStream<'static, Result<Self::Response>>> {
+        let info = self.exchange_info().cloned();
+        let subscriptions: Subscriptions<Subscription> = match &info {
+            Some(info) => subscriptions
+                .into_iter()
+                .map(|subscription| subscription_symbol(info, subscription))
+                .collect::<Result<Vec<Subscription>>>()?
+                .as_slice()
+                .into(),
+            None => subscriptions,
+        };
         let s = match self {
             Self::Nash(nash) => nash
                 .create_stream_specific(subscriptions.as_slice().into())
@@ -124,6 +221,20 @@ impl ExchangeWs for AnyWsExchange {
                     })
                 })
                 .boxed(),
+            // Bitfinex sends events, checksums and account messages that have no generic
+            // counterpart, they are skipped instead of being treated as unreachable.
+            Self::Bitfinex(bitfinex) => bitfinex
+                .create_stream_specific(subscriptions.as_slice().into())
+                .await?
+                .map(|r| r.and_then(WebSocketResponse::try_from))
+                .filter_map(|r| {
+                    futures::future::ready(match r {
+                        Ok(WebSocketResponse::Generic(generic)) => Some(Ok(generic)),
+                        Ok(WebSocketResponse::Raw(_)) => None,
+                        Err(err) => Some(Err(err)),
+                    })
+                })
+                .boxed(),
             Self::Coinbase(coinbase) => coinbase
                 .create_stream_specific(subscriptions.as_slice().into())
                 .await?
@@ -139,8 +250,50 @@ impl ExchangeWs for AnyWsExchange {
                     })
                 })
                 .boxed(),
+            // Acks, welcome and account messages have no generic counterpart either
+            Self::Kucoin(kucoin) => kucoin
+                .create_stream_specific(subscriptions.as_slice().into())
+                .await?
+                .map(|r| r.and_then(WebSocketResponse::try_from))
+                .filter_map(|r| {
+                    futures::future::ready(match r {
+                        Ok(WebSocketResponse::Generic(generic)) => Some(Ok(generic)),
+                        Ok(WebSocketResponse::Raw(_)) => None,
+                        Err(err) => Some(Err(err)),
+                    })
+                })
+                .boxed(),
+            Self::Okx(okx) => okx
+                .create_stream_specific(subscriptions.as_slice().into())
+                .await?
+                .map(|r| r.and_then(WebSocketResponse::try_from))
+                .filter_map(|r| {
+                    futures::future::ready(match r {
+                        Ok(WebSocketResponse::Generic(generic)) => Some(Ok(generic)),
+                        Ok(WebSocketResponse::Raw(_)) => None,
+                        Err(err) => Some(Err(err)),
+                    })
+                })
+                .boxed(),
+            Self::Gemini(gemini) => gemini
+                .create_stream_specific(subscriptions.as_slice().into())
+                .await?
+                .map(|r| r.and_then(WebSocketResponse::try_from))
+                .filter_map(|r| {
+                    futures::future::ready(match r {
+                        Ok(WebSocketResponse::Generic(generic)) => Some(Ok(generic)),
+                        Ok(WebSocketResponse::Raw(_)) => None,
+                        Err(err) => Some(Err(err)),
+                    })
+                })
+                .boxed(),
         };
-        Ok(s)
+        Ok(match info {
+            Some(info) => s
+                .map(move |message| message.map(|message| info.translate(message)))
+                .boxed(),
+            None => s,
+        })
     }
 }
 
@@ -156,8 +309,32 @@ impl From<OpenLimitsWs<BinanceWebsocket>> for AnyWsExchange {
     }
 }
 
+impl From<OpenLimitsWs<BitfinexWebsocket>> for AnyWsExchange {
+    fn from(bitfinex: OpenLimitsWs<BitfinexWebsocket>) -> Self {
+        Self::Bitfinex(bitfinex)
+    }
+}
+
 impl From<OpenLimitsWs<CoinbaseWebsocket>> for AnyWsExchange {
     fn from(coinbase: OpenLimitsWs<CoinbaseWebsocket>) -> Self {
         Self::Coinbase(coinbase)
     }
-}
\ No newline at end of file
+}
+
+impl From<OpenLimitsWs<KucoinWebsocket>> for AnyWsExchange {
+    fn from(kucoin: OpenLimitsWs<KucoinWebsocket>) -> Self {
+        Self::Kucoin(kucoin)
+    }
+}
+
+impl From<OpenLimitsWs<OkxWebsocket>> for AnyWsExchange {
+    fn from(okx: OpenLimitsWs<OkxWebsocket>) -> Self {
+        Self::Okx(okx)
+    }
+}
+
+impl From<OpenLimitsWs<GeminiWebsocket>> for AnyWsExchange {
+    fn from(gemini: OpenLimitsWs<GeminiWebsocket>) -> Self {
+        Self::Gemini(gemini)
+    }
+}
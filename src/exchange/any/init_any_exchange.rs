@@ -1,11 +1,21 @@
 use crate::exchange::coinbase::CoinbaseParameters;
 use crate::exchange::nash::NashParameters;
 use crate::exchange::binance::BinanceParameters;
+use crate::exchange::bitfinex::BitfinexParameters;
+use crate::exchange::kucoin::KucoinParameters;
+use crate::exchange::okx::OkxParameters;
+use crate::exchange::gemini::GeminiParameters;
+use crate::exchange::paper::PaperParameters;
 
 /// Exchange parameters, this is used in AnyExchange enum
 #[derive(Clone)]
 pub enum InitAnyExchange {
     Nash(NashParameters),
     Binance(BinanceParameters),
+    Bitfinex(BitfinexParameters),
     Coinbase(CoinbaseParameters),
+    Kucoin(KucoinParameters),
+    Okx(OkxParameters),
+    Gemini(GeminiParameters),
+    Paper(Box<PaperParameters<InitAnyExchange>>),
 }
\ No newline at end of file
@@ -11,7 +11,7 @@
 //!
 //! #[tokio::main]
 //! async fn main() {
-//!     // Binance, Coinbase and Nash availables
+//!     // Binance, Bitfinex, Coinbase, Gemini, Kucoin, Nash and Okx availables
 //!     let binance = AnyExchange::new(InitAnyExchange::Binance(BinanceParameters::prod()))
 //!                     .await
 //!                     .expect("Couldn't create binance client");
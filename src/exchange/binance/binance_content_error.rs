@@ -4,20 +4,58 @@ use serde_json::Value;
 use std::collections::HashMap;
 use std::fmt;
 use thiserror::Error;
+use crate::errors::ErrorKind;
 
 
-/// This struct represents a binance content error
+/// This struct represents a binance content error, `status` is the HTTP status it came with
 #[derive(Serialize, Deserialize, Debug, Error)]
 pub struct BinanceContentError {
     pub code: i16,
     pub msg: String,
+    #[serde(skip)]
+    pub status: Option<u16>,
 
     #[serde(flatten)]
     extra: HashMap<String, Value>,
 }
 
+impl BinanceContentError {
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Kind of the error code, the codes that aren't specific enough fall back on the message
+    /// and then on the HTTP status. Binance answers 418 with the rate limit code once the IP
+    /// is banned.
+    pub fn kind(&self) -> ErrorKind {
+        if self.status == Some(418) {
+            return ErrorKind::Banned;
+        }
+        let kind = match self.code {
+            -1003 | -1015 => ErrorKind::RateLimited,
+            -1000 | -1001 | -1006 | -1007 | -1008 | -1021 => ErrorKind::Transient,
+            -1002 | -1022 | -2014 | -2015 => ErrorKind::AuthFailure,
+            -1121 => ErrorKind::SymbolNotFound,
+            -2013 => ErrorKind::OrderNotFound,
+            -2010 | -2011 => match ErrorKind::from_message(&self.msg) {
+                ErrorKind::InsufficientFunds => ErrorKind::InsufficientFunds,
+                ErrorKind::OrderNotFound => ErrorKind::OrderNotFound,
+                _ => ErrorKind::InvalidOrder,
+            },
+            -1013 | -1111 | -1115 | -1116 | -1117 | -2021 | -2022 => ErrorKind::InvalidOrder,
+            -1199..=-1100 => ErrorKind::InvalidRequest,
+            _ => ErrorKind::from_message(&self.msg),
+        };
+        match (kind, self.status) {
+            (ErrorKind::Unknown, Some(status)) => ErrorKind::from_status(status),
+            (kind, _) => kind,
+        }
+    }
+}
+
 impl fmt::Display for BinanceContentError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "error code: {} msg: {}", self.code, self.msg)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,47 @@
+/// This enum represents the family of endpoints used to reach a binance compatible exchange.
+/// Binance.US and the exchanges cloning the binance api share its REST schema on other hosts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BinanceEnvironment {
+    /// api.binance.com
+    Global,
+    /// api.binance.us
+    Us,
+    /// testnet.binance.vision
+    Testnet,
+    /// Any other binance compatible exchange
+    Custom { rest_url: String, ws_url: String },
+}
+
+impl Default for BinanceEnvironment {
+    fn default() -> Self {
+        BinanceEnvironment::Global
+    }
+}
+
+impl BinanceEnvironment {
+    /// Base url of the REST api
+    pub fn rest_url(&self) -> &str {
+        match self {
+            BinanceEnvironment::Global => "https://api.binance.com",
+            BinanceEnvironment::Us => "https://api.binance.us",
+            BinanceEnvironment::Testnet => "https://testnet.binance.vision",
+            BinanceEnvironment::Custom { rest_url, .. } => rest_url,
+        }
+    }
+
+    /// Url of the combined streams websocket endpoint
+    pub fn ws_url(&self) -> &str {
+        match self {
+            BinanceEnvironment::Global => "wss://stream.binance.com:9443/stream",
+            BinanceEnvironment::Us => "wss://stream.binance.us:9443/stream",
+            BinanceEnvironment::Testnet => "wss://testnet.binance.vision/stream",
+            BinanceEnvironment::Custom { ws_url, .. } => ws_url,
+        }
+    }
+
+    /// Whether open orders can be canceled with a single request (`DELETE /api/v3/openOrders`),
+    /// the clones of the binance api don't always implement it.
+    pub fn supports_cancel_all(&self) -> bool {
+        !matches!(self, BinanceEnvironment::Custom { .. })
+    }
+}
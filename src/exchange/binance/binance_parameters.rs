@@ -1,17 +1,25 @@
-use super::BinanceCredentials;
+use crate::exchange::traits::HttpConfig;
+use crate::exchange::traits::info::ExchangeInfoCache;
+use crate::validation::ValidationConfig;
+use super::{BinanceCredentials, BinanceEnvironment};
 
-/// This struct represents the type of environment that will be used and receives a boolean and the credentials as parameters.
+/// This struct represents the environment that will be used and receives the environment, the credentials and the networking options as parameters.
+/// Orders are validated against the filters of their symbol as configured by `validation`.
+/// The exchange information is read from `exchange_info_cache` when there's one.
 #[derive(Default, Clone, Debug)]
 pub struct BinanceParameters {
-    pub sandbox: bool,
+    pub environment: BinanceEnvironment,
     pub credentials: Option<BinanceCredentials>,
+    pub http: HttpConfig,
+    pub validation: ValidationConfig,
+    pub exchange_info_cache: Option<ExchangeInfoCache>,
 }
 
 impl BinanceParameters {
     /// Sandbox environment
     pub fn sandbox() -> Self {
         Self {
-            sandbox: true,
+            environment: BinanceEnvironment::Testnet,
             ..Default::default()
         }
     }
@@ -19,8 +27,37 @@ impl BinanceParameters {
     /// Production environment
     pub fn prod() -> Self {
         Self {
-            sandbox: false,
+            environment: BinanceEnvironment::Global,
             ..Default::default()
         }
     }
+
+    /// Binance.US environment
+    pub fn us() -> Self {
+        Self {
+            environment: BinanceEnvironment::Us,
+            ..Default::default()
+        }
+    }
+
+    /// Binance compatible exchange served from other hosts
+    pub fn custom(rest_url: &str, ws_url: &str) -> Self {
+        Self {
+            environment: BinanceEnvironment::Custom {
+                rest_url: rest_url.to_string(),
+                ws_url: ws_url.to_string(),
+            },
+            ..Default::default()
+        }
+    }
+
+    pub fn with_validation(mut self, validation: ValidationConfig) -> Self {
+        self.validation = validation;
+        self
+    }
+
+    pub fn with_exchange_info_cache(mut self, cache: ExchangeInfoCache) -> Self {
+        self.exchange_info_cache = Some(cache);
+        self
+    }
 }
\ No newline at end of file
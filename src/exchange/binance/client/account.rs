@@ -94,11 +94,8 @@ impl BaseClient {
         .to_string();
         let buy: OrderRequest = OrderRequest {
             symbol: pair.symbol,
-            quantity: qty.round_dp(pair.base_increment.normalize().scale()),
-            price: Some(price.round_dp_with_strategy(
-                pair.quote_increment.normalize().scale(),
-                RoundingStrategy::ToZero,
-            )),
+            quantity: qty.normalize(),
+            price: Some(price.normalize()),
             order_side: ORDER_SIDE_BUY.to_string(),
             order_type,
             time_in_force: Some(tif),
@@ -129,11 +126,8 @@ impl BaseClient {
         .to_string();
         let sell: OrderRequest = OrderRequest {
             symbol: pair.symbol,
-            quantity: qty.round_dp(pair.base_increment.normalize().scale()),
-            price: Some(price.round_dp_with_strategy(
-                pair.quote_increment.normalize().scale(),
-                RoundingStrategy::AwayFromZero,
-            )),
+            quantity: qty.normalize(),
+            price: Some(price.normalize()),
             order_side: ORDER_SIDE_SELL.to_string(),
             order_type,
             time_in_force: Some(tif),
@@ -151,7 +145,7 @@ impl BaseClient {
     pub async fn market_buy(&self, pair: MarketPair, qty: Decimal) -> Result<Order> {
         let buy: OrderRequest = OrderRequest {
             symbol: pair.symbol,
-            quantity: qty.round_dp(pair.base_increment.normalize().scale()),
+            quantity: qty.normalize(),
             price: None,
             order_side: ORDER_SIDE_BUY.to_string(),
             order_type: ORDER_TYPE_MARKET.to_string(),
@@ -170,7 +164,7 @@ impl BaseClient {
     pub async fn market_sell(&self, pair: MarketPair, qty: Decimal) -> Result<Order> {
         let sell: OrderRequest = OrderRequest {
             symbol: pair.symbol,
-            quantity: qty.round_dp(pair.base_increment.normalize().scale()),
+            quantity: qty.normalize(),
             price: None,
             order_side: ORDER_SIDE_SELL.to_string(),
             order_type: ORDER_TYPE_MARKET.to_string(),
@@ -195,6 +189,15 @@ impl BaseClient {
     }
 
     pub async fn cancel_all_orders(&self, symbol: &str) -> Result<Vec<OrderCanceled>> {
+        // Not every binance compatible exchange cancels the open orders at once
+        if !self.transport.environment.supports_cancel_all() {
+            let mut orders_canceled = Vec::new();
+            for order in self.get_open_orders(symbol).await? {
+                orders_canceled.push(self.cancel_order(symbol, order.order_id).await?);
+            }
+            return Ok(orders_canceled);
+        }
+
         let params = json! {{"symbol":symbol}};
         let orders_canceled = self
             .transport
@@ -3,8 +3,8 @@ use serde_json::Value;
 use super::BaseClient;
 use crate::{
     exchange::binance::model::{
-        BookTickers, KlineParams, KlineSummaries, KlineSummary, OrderBook, PriceStats, Prices,
-        SymbolPrice, Ticker,
+        AveragePrice, BookTickers, KlineParams, KlineSummaries, KlineSummary, OrderBook,
+        PriceStats, Prices, SymbolPrice, Ticker,
     },
     errors::OpenLimitsError,
 };
@@ -44,6 +44,18 @@ impl BaseClient {
         Ok(price)
     }
 
+    // Average price of ONE symbol, used by the PERCENT_PRICE filter
+    pub async fn get_average_price(&self, symbol: &str) -> Result<AveragePrice> {
+        let params = json! {{"symbol": symbol}};
+
+        let price = self
+            .transport
+            .get("/api/v3/avgPrice", Some(&params))
+            .await?;
+
+        Ok(price)
+    }
+
     // Symbols order book ticker
     // -> Best price/qty on the order book for ALL symbols.
     pub async fn get_all_book_tickers(&self) -> Result<BookTickers> {
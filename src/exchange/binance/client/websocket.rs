@@ -1,5 +1,6 @@
 use std::{convert::TryFrom, fmt::Display};
 use std::sync::Mutex;
+use std::time::Duration;
 use async_trait::async_trait;
 use futures::{SinkExt, stream::BoxStream, StreamExt};
 use serde::{de, Deserialize, Serialize};
@@ -10,6 +11,7 @@ use crate::{
     errors::OpenLimitsError,
     exchange::binance::{
         BinanceParameters,
+        client::{BaseClient, Transport},
         model::websocket::{BinanceSubscription, BinanceWebsocketMessage},
     },
     model::websocket::OpenLimitsWebSocketMessage,
@@ -19,9 +21,6 @@ use crate::{
 use crate::exchange::traits::stream::{ExchangeWs, Subscriptions};
 use super::shared::Result;
 
-const WS_URL_PROD: &str = "wss://stream.binance.com:9443/stream";
-const WS_URL_SANDBOX: &str = "wss://testnet.binance.vision/stream";
-
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
 enum Either<L, R> {
@@ -29,9 +28,15 @@ enum Either<L, R> {
     Right(R),
 }
 
-/// This struct is used for websocket communications with binance exchange
+/// Listen keys expire after an hour without a keepalive
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// This struct is used for websocket communications with binance exchange.
+/// The user data stream is read from a listen key requested over REST before connecting, and
+/// kept alive while the connection is open.
 pub struct BinanceWebsocket {
     parameters: BinanceParameters,
+    client: BaseClient,
     disconnection_senders: Mutex<Vec<UnboundedSender<()>>>,
 }
 
@@ -42,8 +47,19 @@ impl ExchangeWs for BinanceWebsocket {
     type Response = BinanceWebsocketMessage;
 
     async fn new(parameters: Self::InitParams) -> Result<Self> {
+        let transport = match &parameters.credentials {
+            Some(credentials) => Transport::with_credential(
+                &credentials.api_key,
+                &credentials.api_secret,
+                parameters.environment.clone(),
+                &parameters.http,
+            )?,
+            None => Transport::new(parameters.environment.clone(), &parameters.http)?,
+        };
+
         Ok(BinanceWebsocket {
             parameters,
+            client: BaseClient { transport },
             disconnection_senders: Default::default(),
         })
     }
@@ -61,25 +77,54 @@ impl ExchangeWs for BinanceWebsocket {
         &self,
         subscriptions: Subscriptions<Self::Subscription>,
     ) -> Result<BoxStream<'static, Result<Self::Response>>> {
-        let streams = subscriptions
-            .into_iter()
-            .map(|bs| bs.to_string())
-            .collect::<Vec<String>>()
-            .join("/");
-
-        let ws_url = match self.parameters.sandbox {
-            true => WS_URL_SANDBOX,
-            false => WS_URL_PROD,
-        };
+        let mut listen_key = None;
+        let mut streams = Vec::new();
+        for subscription in subscriptions {
+            match subscription {
+                BinanceSubscription::Unsupported(_) => {
+                    return Err(OpenLimitsError::WebSocketMessageNotSupported())
+                }
+                BinanceSubscription::UserDataStream => {
+                    if listen_key.is_none() {
+                        let key = self.client.user_stream_start().await?.listen_key;
+                        streams.push(BinanceSubscription::UserData(key.clone()).to_string());
+                        listen_key = Some(key);
+                    }
+                }
+                subscription => streams.push(subscription.to_string()),
+            }
+        }
+        let streams = streams.join("/");
+
+        let ws_url = self.parameters.environment.ws_url();
         let endpoint = url::Url::parse(&format!("{}?streams={}", ws_url, streams))
             .map_err(OpenLimitsError::UrlParserError)?;
         let (ws_stream, _) = connect_async(endpoint).await?;
 
         let (mut sink, stream) = ws_stream.split();
         let (disconnection_sender, mut disconnection_receiver) = unbounded_channel();
+        let client = self.client.clone();
         tokio::spawn(async move {
-            if disconnection_receiver.recv().await.is_some() {
-                sink.close().await.ok();
+            let mut interval = tokio::time::interval(KEEP_ALIVE_INTERVAL);
+            // The first tick completes immediately, the key was just requested
+            interval.tick().await;
+            loop {
+                tokio::select! {
+                    _ = disconnection_receiver.recv() => {
+                        sink.close().await.ok();
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        if let Some(key) = &listen_key {
+                            if client.user_stream_keep_alive(key).await.is_err() {
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+            if let Some(key) = &listen_key {
+                client.user_stream_close(key).await.ok();
             }
         });
 
@@ -147,7 +192,17 @@ impl<'de> Deserialize<'de> for BinanceWebsocketMessage {
                 serde_json::from_value(stream.data).map_err(de::Error::custom)?,
             ))
         } else {
-            panic!("Not supported Subscription");
+            // The streams of the user data are named after their listen key
+            let event = stream.data.get("e").and_then(Value::as_str).unwrap_or_default();
+            match event {
+                "executionReport" => Ok(BinanceWebsocketMessage::UserOrderUpdate(
+                    serde_json::from_value(stream.data).map_err(de::Error::custom)?,
+                )),
+                "outboundAccountInfo" => Ok(BinanceWebsocketMessage::UserAccountUpdate(
+                    serde_json::from_value(stream.data).map_err(de::Error::custom)?,
+                )),
+                _ => Ok(BinanceWebsocketMessage::Unknown(stream.data)),
+            }
         }
     }
 }
@@ -172,16 +227,25 @@ impl Display for BinanceSubscription {
             BinanceSubscription::TickerAll => write!(f, "!ticker@arr"),
             BinanceSubscription::Trade(ref symbol) => write!(f, "{}@trade", symbol),
             BinanceSubscription::UserData(ref key) => write!(f, "{}", key),
+            BinanceSubscription::UserDataStream => write!(f, "userData"),
+            BinanceSubscription::Unsupported(subscription) => write!(f, "{:?}", subscription),
         }
     }
 }
 
 impl From<Subscription> for BinanceSubscription {
     fn from(subscription: Subscription) -> Self {
+        // Stream names are lower case, the symbols of the REST api upper case
         match subscription {
-            Subscription::OrderBookUpdates(symbol) => BinanceSubscription::Depth(symbol, None),
-            Subscription::Trades(symbol) => BinanceSubscription::Trade(symbol),
-            _ => unimplemented!(),
+            Subscription::OrderBookUpdates(symbol) => {
+                BinanceSubscription::Depth(symbol.to_lowercase(), None)
+            }
+            Subscription::Trades(symbol) => BinanceSubscription::Trade(symbol.to_lowercase()),
+            // The order updates of every symbol are sent on the user data stream
+            Subscription::AccountOrders(_) | Subscription::AccountTrades(_) => {
+                BinanceSubscription::UserDataStream
+            }
+            subscription => BinanceSubscription::Unsupported(subscription),
         }
     }
 }
@@ -197,6 +261,9 @@ impl TryFrom<BinanceWebsocketMessage> for WebSocketResponse<BinanceWebsocketMess
             BinanceWebsocketMessage::Trade(trade) => Ok(WebSocketResponse::Generic(
                 OpenLimitsWebSocketMessage::Trades(trade.into()),
             )),
+            BinanceWebsocketMessage::UserOrderUpdate(update) => Ok(WebSocketResponse::Generic(
+                OpenLimitsWebSocketMessage::Orders(vec![update.into()]),
+            )),
             BinanceWebsocketMessage::Ping => {
                 Ok(WebSocketResponse::Generic(OpenLimitsWebSocketMessage::Ping))
             }
@@ -23,10 +23,17 @@ use async_trait::async_trait;
 use model::KlineSummaries;
 use transport::Transport;
 use client::BaseClient;
+use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::sync::{Arc, RwLock};
+use rust_decimal::prelude::{Decimal, Zero};
 use crate::{
-    exchange::binance::model::{websocket::TradeMessage, SymbolFilter, ORDER_TYPE_LIMIT, ORDER_TYPE_MARKET},
-    errors::OpenLimitsError,
+    exchange::binance::model::{
+        websocket::{TradeMessage, UserOrderUpdate},
+        OrderExecType, SymbolFilter, ORDER_TYPE_LIMIT, ORDER_TYPE_LIMIT_MAKER, ORDER_TYPE_MARKET,
+    },
+    exchange::traits::info::MarketStatus,
+    errors::{MissingImplementationContent, OpenLimitsError},
     model::{
         AskBid, Balance, CancelAllOrdersRequest, CancelOrderRequest, Candle,
         GetHistoricRatesRequest, GetHistoricTradesRequest, GetOrderHistoryRequest, GetOrderRequest,
@@ -35,11 +42,13 @@ use crate::{
         Paginator, Side, Ticker, TimeInForce, Trade, TradeHistoryRequest, Transaction,
     },
     prelude::*,
+    validation::{MarketRules, OrderContext, OrderValidator, StepRange, ValidationConfig},
 };
 use super::shared::Result;
 
 mod binance_content_error;
 mod binance_credentials;
+mod binance_environment;
 mod binance_parameters;
 mod transport;
 pub mod client;
@@ -48,14 +57,66 @@ pub mod model;
 pub use client::websocket::BinanceWebsocket;
 pub use binance_content_error::BinanceContentError;
 pub use binance_credentials::BinanceCredentials;
+pub use binance_environment::BinanceEnvironment;
 pub use binance_parameters::BinanceParameters;
 pub use super::shared;
 
-/// The main struct of the binance module
+/// The main struct of the binance module.
+/// Orders are checked against the filters of their symbol, read with the exchange info,
+/// before they're sent.
 #[derive(Clone)]
 pub struct Binance {
     pub exchange_info: ExchangeInfo,
     pub client: BaseClient,
+    pub validation: ValidationConfig,
+    market_rules: Arc<RwLock<HashMap<String, MarketRules>>>,
+}
+
+impl Binance {
+    /// The validator of the orders of a symbol, with the rounding of the configuration.
+    /// The markets read from a cache only have the rules of their `MarketPair` until they're
+    /// refreshed.
+    pub fn order_validator(&self, symbol: &str) -> Result<OrderValidator> {
+        let rules = self
+            .market_rules
+            .read()
+            .map_err(|_| OpenLimitsError::PoisonError())?
+            .get(symbol)
+            .cloned();
+        let rules = match rules {
+            Some(rules) => rules,
+            None => MarketRules::from(&self.exchange_info.get_pair(symbol)?.read()?),
+        };
+        Ok(OrderValidator::new(rules).with_rounding(self.validation.rounding))
+    }
+
+    /// The average price and the number of open orders of a symbol, when the configuration
+    /// checks the market state
+    pub async fn order_context(&self, symbol: &str) -> Result<OrderContext> {
+        if !self.validation.market_state {
+            return Ok(OrderContext::new());
+        }
+        let average_price = self.client.get_average_price(symbol).await?;
+        let open_orders = self.client.get_open_orders(symbol).await?;
+        Ok(OrderContext::new()
+            .with_average_price(average_price.price)
+            .with_open_orders(open_orders.len() as u64))
+    }
+
+    async fn validate_limit(&self, req: &OpenLimitOrderRequest) -> Result<OpenLimitOrderRequest> {
+        let validator = self.order_validator(&req.market_pair)?;
+        let context = self.order_context(&req.market_pair).await?;
+        validator.validate_limit(req, &context)
+    }
+
+    async fn validate_market(
+        &self,
+        req: &OpenMarketOrderRequest,
+    ) -> Result<OpenMarketOrderRequest> {
+        let validator = self.order_validator(&req.market_pair)?;
+        let context = self.order_context(&req.market_pair).await?;
+        validator.validate_market(req, &context)
+    }
 }
 
 #[async_trait]
@@ -71,19 +132,29 @@ impl Exchange for Binance {
                     transport: Transport::with_credential(
                         &credentials.api_key,
                         &credentials.api_secret,
-                        parameters.sandbox,
+                        parameters.environment,
+                        &parameters.http,
                     )?,
                 },
+                validation: parameters.validation,
+                market_rules: Default::default(),
             },
             None => Binance {
                 exchange_info: ExchangeInfo::new(),
                 client: BaseClient {
-                    transport: Transport::new(parameters.sandbox)?,
+                    transport: Transport::new(parameters.environment, &parameters.http)?,
                 },
+                validation: parameters.validation,
+                market_rules: Default::default(),
             },
         };
 
-        binance.refresh_market_info().await?;
+        match &parameters.exchange_info_cache {
+            Some(cache) => cache.initialize(&binance, &binance.exchange_info).await?,
+            None => {
+                binance.refresh_market_info().await?;
+            }
+        }
         Ok(binance)
     }
 
@@ -96,46 +167,13 @@ impl Exchange for Binance {
 impl ExchangeInfoRetrieval for Binance {
     async fn retrieve_pairs(&self) -> Result<Vec<MarketPair>> {
         self.client.get_exchange_info().await.map(|v| {
-            v.symbols
-                .into_iter()
-                .map(|symbol| {
-                    let lot_size = symbol
-                        .filters
-                        .iter()
-                        .find_map(|f| match f {
-                            SymbolFilter::LotSize {
-                                max_qty: _,
-                                min_qty: _,
-                                step_size,
-                            } => Some(step_size),
-                            _ => None,
-                        })
-                        .expect("Couldn't find lot size.");
-
-                    let tick_size = symbol
-                        .filters
-                        .iter()
-                        .find_map(|f| match f {
-                            SymbolFilter::PriceFilter {
-                                min_price: _,
-                                max_price: _,
-                                tick_size,
-                            } => Some(tick_size),
-                            _ => None,
-                        })
-                        .expect("Couldn't find tick size.");
-
-                    MarketPair {
-                        base: symbol.base_asset,
-                        quote: symbol.quote_asset,
-                        symbol: symbol.symbol,
-                        base_increment: *lot_size,
-                        quote_increment: *tick_size,
-                        min_base_trade_size: None,
-                        min_quote_trade_size: None,
-                    }
-                })
-                .collect()
+            if let Ok(mut market_rules) = self.market_rules.write() {
+                for symbol in v.symbols.iter() {
+                    market_rules.insert(symbol.symbol.clone(), MarketRules::from(symbol));
+                }
+            }
+
+            v.symbols.into_iter().map(MarketPair::from).collect()
         })
     }
 
@@ -156,14 +194,16 @@ impl ExchangeMarketData for Binance {
         self.client
             .get_depth(req.market_pair.as_str(), None)
             .await
-            .map(Into::into)
+            .map(|book: OrderBookResponse| {
+                self.exchange_info.translate_market(&req.market_pair, book)
+            })
     }
 
     async fn get_price_ticker(&self, req: &GetPriceTickerRequest) -> Result<Ticker> {
         self.client
             .get_price(&req.market_pair)
             .await
-            .map(Into::into)
+            .map(|ticker: Ticker| self.exchange_info.translate_market(&req.market_pair, ticker))
     }
 
     async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
@@ -173,10 +213,15 @@ impl ExchangeMarketData for Binance {
             .get_klines(&params)
             .await
             .map(|KlineSummaries::AllKlineSummaries(v)| v.into_iter().map(Into::into).collect())
+            .map(|candles: Vec<Candle>| {
+                self.exchange_info.translate_market(&req.market_pair, candles)
+            })
     }
 
     async fn get_historic_trades(&self, _req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
-        unimplemented!("Only implemented for Nash right now");
+        Err(OpenLimitsError::MissingImplementation(MissingImplementationContent {
+            message: String::from("Binance doesn't serve historic trades yet"),
+        }))
     }
 }
 
@@ -184,6 +229,7 @@ impl ExchangeMarketData for Binance {
 impl ExchangeAccount for Binance {
     async fn limit_buy(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
         let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
+        let req = self.validate_limit(req).await?;
         self.client
             .limit_buy(
                 pair,
@@ -193,10 +239,11 @@ impl ExchangeAccount for Binance {
                 req.post_only,
             )
             .await
-            .map(Into::into)
+            .map(|order: Order| self.exchange_info.translate(order))
     }
     async fn limit_sell(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
         let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
+        let req = self.validate_limit(req).await?;
         self.client
             .limit_sell(
                 pair,
@@ -206,19 +253,24 @@ impl ExchangeAccount for Binance {
                 req.post_only,
             )
             .await
-            .map(Into::into)
+            .map(|order: Order| self.exchange_info.translate(order))
     }
 
     async fn market_buy(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
         let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
-        self.client.market_buy(pair, req.size).await.map(Into::into)
+        let req = self.validate_market(req).await?;
+        self.client
+            .market_buy(pair, req.size)
+            .await
+            .map(|order: Order| self.exchange_info.translate(order))
     }
     async fn market_sell(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
         let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
+        let req = self.validate_market(req).await?;
         self.client
             .market_sell(pair, req.size)
             .await
-            .map(Into::into)
+            .map(|order: Order| self.exchange_info.translate(order))
     }
     async fn cancel_order(&self, req: &CancelOrderRequest) -> Result<OrderCanceled> {
         if let Some(pair) = req.market_pair.as_ref() {
@@ -252,7 +304,7 @@ impl ExchangeAccount for Binance {
         self.client
             .get_all_open_orders()
             .await
-            .map(|v| v.into_iter().map(Into::into).collect())
+            .map(|v| self.exchange_info.translate(v.into_iter().map(Into::into).collect()))
     }
 
     async fn get_order_history(&self, req: &GetOrderHistoryRequest) -> Result<Vec<Order>> {
@@ -260,7 +312,7 @@ impl ExchangeAccount for Binance {
         self.client
             .get_all_orders(&req)
             .await
-            .map(|v| v.into_iter().map(Into::into).collect())
+            .map(|v| self.exchange_info.translate(v.into_iter().map(Into::into).collect()))
     }
 
     async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>> {
@@ -268,7 +320,7 @@ impl ExchangeAccount for Binance {
         self.client
             .trade_history(&req)
             .await
-            .map(|v| v.into_iter().map(Into::into).collect())
+            .map(|v| self.exchange_info.translate(v.into_iter().map(Into::into).collect()))
     }
 
     async fn get_account_balances(&self, _paginator: Option<Paginator>) -> Result<Vec<Balance>> {
@@ -286,13 +338,162 @@ impl ExchangeAccount for Binance {
             .id
             .parse::<u64>()
             .expect("binance order id did not parse as u64");
-        self.client.get_order(&pair, u64_id).await.map(Into::into)
+        self.client
+            .get_order(&pair, u64_id)
+            .await
+            .map(|order: Order| self.exchange_info.translate(order))
+    }
+}
+
+impl From<&model::Symbol> for MarketRules {
+    fn from(symbol: &model::Symbol) -> Self {
+        let mut rules = MarketRules::new();
+        for filter in symbol.filters.iter() {
+            rules = match filter {
+                SymbolFilter::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => rules.with_lot_size(StepRange::new(*min_qty, *max_qty, *step_size)),
+                SymbolFilter::MarketLotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => rules.with_market_lot_size(StepRange::new(*min_qty, *max_qty, *step_size)),
+                SymbolFilter::PriceFilter {
+                    min_price,
+                    max_price,
+                    tick_size,
+                } => rules.with_price(StepRange::new(*min_price, *max_price, *tick_size)),
+                SymbolFilter::MinNotional { min_notional } => {
+                    rules.with_min_notional(*min_notional)
+                }
+                SymbolFilter::Notional {
+                    min_notional,
+                    max_notional,
+                } => {
+                    let rules = rules.with_min_notional(*min_notional);
+                    match max_notional.is_zero() {
+                        true => rules,
+                        false => rules.with_max_notional(*max_notional),
+                    }
+                }
+                SymbolFilter::PercentPrice {
+                    multiplier_up,
+                    multiplier_down,
+                    avg_price_mins: _,
+                } => rules.with_percent_price(*multiplier_up, *multiplier_down),
+                SymbolFilter::MaxNumOrders { max_num_orders } => {
+                    rules.with_max_num_orders(*max_num_orders)
+                }
+                SymbolFilter::IcebergParts { limit } => rules.with_iceberg_parts(*limit),
+                _ => rules,
+            };
+        }
+        rules
+    }
+}
+
+/// Binance filters use zero for the bounds that aren't checked
+fn non_zero(value: Decimal) -> Option<Decimal> {
+    Some(value).filter(|value| !value.is_zero())
+}
+
+/// Symbols are only traded in the `TRADING` status, the others are breaks and auctions
+fn market_status(status: &str) -> MarketStatus {
+    match status {
+        "TRADING" => MarketStatus::Trading,
+        _ => MarketStatus::Halted,
+    }
+}
+
+fn order_types(order_types: &[String]) -> Vec<OrderType> {
+    let mut types = Vec::new();
+    for order_type in order_types {
+        let order_type = match order_type.as_str() {
+            ORDER_TYPE_LIMIT | ORDER_TYPE_LIMIT_MAKER => OrderType::Limit,
+            ORDER_TYPE_MARKET => OrderType::Market,
+            "STOP_LOSS" | "TAKE_PROFIT" => OrderType::StopMarket,
+            "STOP_LOSS_LIMIT" | "TAKE_PROFIT_LIMIT" => OrderType::StopLimit,
+            _ => continue,
+        };
+        if !types.contains(&order_type) {
+            types.push(order_type);
+        }
+    }
+    types
+}
+
+/// Binance compatible exchanges don't always set the lot size and price filters, the rules of
+/// their symbols are left empty
+impl From<model::Symbol> for MarketPair {
+    fn from(symbol: model::Symbol) -> Self {
+        let (min_qty, max_qty, step_size) = symbol
+            .filters
+            .iter()
+            .find_map(|f| match f {
+                SymbolFilter::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => Some((*min_qty, *max_qty, *step_size)),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        let (min_price, max_price, tick_size) = symbol
+            .filters
+            .iter()
+            .find_map(|f| match f {
+                SymbolFilter::PriceFilter {
+                    min_price,
+                    max_price,
+                    tick_size,
+                } => Some((*min_price, *max_price, *tick_size)),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        let (min_notional, max_notional) = symbol
+            .filters
+            .iter()
+            .find_map(|f| match f {
+                SymbolFilter::MinNotional { min_notional } => {
+                    Some((*min_notional, Decimal::zero()))
+                }
+                SymbolFilter::Notional {
+                    min_notional,
+                    max_notional,
+                } => Some((*min_notional, *max_notional)),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        Self {
+            status: market_status(&symbol.status),
+            order_types: order_types(&symbol.order_types),
+            base: symbol.base_asset,
+            quote: symbol.quote_asset,
+            symbol: symbol.symbol,
+            base_increment: step_size,
+            quote_increment: tick_size,
+            min_base_trade_size: non_zero(min_qty),
+            min_quote_trade_size: non_zero(min_notional),
+            max_base_trade_size: non_zero(max_qty),
+            max_quote_trade_size: non_zero(max_notional),
+            min_price: non_zero(min_price),
+            max_price: non_zero(max_price),
+            base_precision: Some(symbol.base_asset_precision),
+            quote_precision: Some(symbol.quote_precision),
+        }
     }
 }
 
 impl From<model::OrderBook> for OrderBookResponse {
     fn from(book: model::OrderBook) -> Self {
         Self {
+            market_pair: None,
+            instrument: None,
             last_update_id: None,
             update_id: Some(book.last_update_id),
             bids: book.bids.into_iter().map(Into::into).collect(),
@@ -304,6 +505,8 @@ impl From<model::OrderBook> for OrderBookResponse {
 impl From<model::websocket::Depth> for OrderBookResponse {
     fn from(depth: model::websocket::Depth) -> Self {
         Self {
+            market_pair: Some(depth.symbol),
+            instrument: None,
             last_update_id: Some(depth.first_update_id),
             update_id: Some(depth.final_update_id),
             bids: depth.bids.into_iter().map(Into::into).collect(),
@@ -319,6 +522,7 @@ impl From<model::websocket::TradeMessage> for Vec<Trade> {
             buyer_order_id: Some(trade_message.buyer_order_id.to_string()),
             seller_order_id: Some(trade_message.buyer_order_id.to_string()),
             market_pair: trade_message.symbol,
+            instrument: None,
             price: trade_message.price,
             qty: trade_message.qty,
             fees: None,
@@ -339,6 +543,7 @@ impl From<TradeMessage> for Trade {
             buyer_order_id: Some(trade.buyer_order_id.to_string()),
             seller_order_id: Some(trade.seller_order_id.to_string()),
             market_pair: trade.symbol,
+            instrument: None,
             price: trade.price,
             qty: trade.qty,
             fees: None, // Binance does not return fee on trades over WS stream
@@ -353,6 +558,70 @@ impl From<TradeMessage> for Trade {
     }
 }
 
+/// Trade executions carry the fill of the order
+impl From<UserOrderUpdate> for Order {
+    fn from(update: UserOrderUpdate) -> Self {
+        let order_type = match update.order_type {
+            model::OrderType::Limit | model::OrderType::LimitMaker => OrderType::Limit,
+            model::OrderType::Market => OrderType::Market,
+            model::OrderType::StopLoss | model::OrderType::TakeProfit => OrderType::StopMarket,
+            model::OrderType::StopLossLimit | model::OrderType::TakeProfitLimit => {
+                OrderType::StopLimit
+            }
+        };
+        let side = match update.side {
+            model::Side::Buy => Side::Buy,
+            model::Side::Sell => Side::Sell,
+        };
+        let id = update.order_id.to_string();
+        let trades = match update.execution_type {
+            OrderExecType::Trade => {
+                let (buyer_order_id, seller_order_id) = match side {
+                    Side::Buy => (Some(id.clone()), None),
+                    Side::Sell => (None, Some(id.clone())),
+                };
+                vec![Trade {
+                    id: update.trade_id.to_string(),
+                    buyer_order_id,
+                    seller_order_id,
+                    market_pair: update.symbol.clone(),
+                    instrument: None,
+                    price: update.price_last_filled_trade,
+                    qty: update.qty_last_filled_trade,
+                    fees: Some(update.commission),
+                    side: side.clone(),
+                    liquidity: match update.is_buyer_maker {
+                        true => Some(Liquidity::Maker),
+                        false => Some(Liquidity::Taker),
+                    },
+                    created_at: update.trade_order_time,
+                }]
+            }
+            _ => Vec::new(),
+        };
+        // Cancellations are sent with the client id of the cancel request
+        let client_order_id = match update.c_ignore {
+            Some(original) if !original.is_empty() => original,
+            _ => update.new_client_order_id,
+        };
+
+        Self {
+            id,
+            market_pair: update.symbol,
+            instrument: None,
+            client_order_id: Some(client_order_id),
+            created_at: Some(update.order_creation_time),
+            order_type,
+            side,
+            status: update.order_status.into(),
+            size: update.qty,
+            price: non_zero(update.price),
+            remaining: Some(update.qty - update.accumulated_qty_filled_trades),
+            trades,
+        }
+    }
+}
+
 impl From<model::AskBid> for AskBid {
     fn from(bids: model::AskBid) -> Self {
         Self {
@@ -384,6 +653,7 @@ impl From<model::Order> for Order {
         Self {
             id: order.order_id.to_string(),
             market_pair: order.symbol,
+            instrument: None,
             client_order_id: Some(order.client_order_id),
             created_at: order.time,
             order_type,
@@ -411,6 +681,8 @@ impl From<model::Balance> for Balance {
             asset: balance.asset,
             free: balance.free,
             total: balance.locked + balance.free,
+            locked: balance.locked,
+            ..Default::default()
         }
     }
 }
@@ -426,6 +698,7 @@ impl From<model::TradeHistory> for Trade {
             buyer_order_id,
             seller_order_id,
             market_pair: trade_history.symbol,
+            instrument: None,
             price: trade_history.price,
             qty: trade_history.qty,
             fees: Some(trade_history.commission),
@@ -445,6 +718,8 @@ impl From<model::TradeHistory> for Trade {
 impl From<model::SymbolPrice> for Ticker {
     fn from(ticker: model::SymbolPrice) -> Self {
         Self {
+            market_pair: Some(ticker.symbol),
+            instrument: None,
             price: Some(ticker.price),
             price_24h: None,
         }
@@ -512,6 +787,8 @@ impl From<Interval> for &str {
 impl From<model::KlineSummary> for Candle {
     fn from(kline_summary: model::KlineSummary) -> Self {
         Self {
+            market_pair: None,
+            instrument: None,
             time: kline_summary.open_time as u64,
             low: kline_summary.low,
             high: kline_summary.high,
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents the average price of a symbol over the last `mins` minutes
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AveragePrice {
+    pub mins: u64,
+    #[serde(with = "string_to_decimal")]
+    pub price: Decimal,
+}
@@ -9,4 +9,6 @@ pub enum ExchangeFilter {
     ExchangeMaxNumOrders { max_num_orders: u64 },
     #[serde(rename_all = "camelCase")]
     ExchangeMaxNumAlgoOrders { max_num_algo_orders: u64 },
+    #[serde(other)]
+    Unknown,
 }
@@ -10,6 +10,7 @@ pub const TIME_IN_FORCE_GTC: &str = "GTC";
 mod account_information;
 mod all_order_req;
 mod ask_bid;
+mod average_price;
 mod balance;
 mod book_tickers;
 mod exchange_filter;
@@ -49,6 +50,7 @@ pub mod websocket;
 pub use account_information::AccountInformation;
 pub use all_order_req::AllOrderReq;
 pub use ask_bid::AskBid;
+pub use average_price::AveragePrice;
 pub use balance::Balance;
 pub use book_tickers::BookTickers;
 pub use exchange_filter::ExchangeFilter;
@@ -1,9 +1,12 @@
 use serde::Deserialize;
 use serde::Serialize;
 
-/// This enum represents the reason why an order was rejected
+/// This enum represents the reason why an order was rejected, the reasons of the rejected
+/// orders are sent as errors by the REST api
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OrderRejectReason {
     None,
+    #[serde(other)]
+    Other,
 }
\ No newline at end of file
@@ -11,7 +11,9 @@ pub struct Symbol {
     pub base_asset_precision: u32,
     pub quote_asset: String,
     pub quote_precision: u32,
+    #[serde(default)]
     pub order_types: Vec<String>,
+    #[serde(default)]
     pub iceberg_allowed: bool,
     pub filters: Vec<SymbolFilter>,
 }
\ No newline at end of file
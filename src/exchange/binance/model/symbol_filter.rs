@@ -3,6 +3,8 @@ use serde::Serialize;
 use rust_decimal::prelude::Decimal;
 use super::shared::string_to_decimal;
 
+/// This enum represents a symbol filter. The filters differ between the binance environments,
+/// the ones that aren't used are parsed as `Unknown`.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(tag = "filterType", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum SymbolFilter {
@@ -29,6 +31,14 @@ pub enum SymbolFilter {
         #[serde(with = "string_to_decimal")]
         min_notional: Decimal,
     },
+    /// Replaces `MinNotional` in the global exchange
+    #[serde(rename_all = "camelCase")]
+    Notional {
+        #[serde(with = "string_to_decimal")]
+        min_notional: Decimal,
+        #[serde(with = "string_to_decimal")]
+        max_notional: Decimal,
+    },
     #[serde(rename_all = "camelCase")]
     MaxNumAlgoOrders { max_num_algo_orders: u64 },
     #[serde(rename_all = "camelCase")]
@@ -57,4 +67,6 @@ pub enum SymbolFilter {
         #[serde(with = "string_to_decimal")]
         step_size: Decimal,
     },
+    #[serde(other)]
+    Unknown,
 }
\ No newline at end of file
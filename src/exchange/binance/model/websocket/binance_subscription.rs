@@ -1,8 +1,14 @@
+use crate::model::websocket::Subscription;
+
 /// Used for subscriptions
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum BinanceSubscription {
     /// Listen key
     UserData(String),   
+    /// The user data stream of the account, its listen key is requested when connecting
+    UserDataStream,
+    /// Subscriptions without a binance stream
+    Unsupported(Subscription),
     /// Symbol         
     AggregateTrade(String),      
     /// Symbol
@@ -1,4 +1,5 @@
 use serde::Serialize;
+use serde_json::Value;
 use super::AccountUpdate;
 use super::AggregateTrade;
 use super::CandlestickMessage;
@@ -27,4 +28,6 @@ pub enum BinanceWebsocketMessage {
     Pong,
     Close,
     Binary(Vec<u8>), // Unexpected, unparsed
+    /// Events of the user data stream that aren't parsed
+    Unknown(Value),
 }
\ No newline at end of file
@@ -1,14 +1,17 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
 use chrono::Utc;
 use hex::encode as hexify;
 use hmac::{Hmac, Mac, NewMac};
 use reqwest::header;
-use reqwest::Response;
-use reqwest::StatusCode;
+use reqwest::{Method, Request, Response, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use sha2::Sha256;
 use url::Url;
-use crate::exchange::binance::BinanceContentError;
+use crate::exchange::binance::{BinanceContentError, BinanceEnvironment};
+use crate::exchange::traits::{HttpConfig, HttpTransport};
 use crate::errors::OpenLimitsError;
 use super::shared::Result;
 
@@ -16,51 +19,47 @@ type HmacSha256 = Hmac<Sha256>;
 
 static RECV_WINDOW: usize = 7000;
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Transport {
     credential: Option<(String, String)>,
-    client: reqwest::Client,
-    pub recv_window: usize,
+    client: Arc<dyn HttpTransport>,
+    headers: header::HeaderMap<header::HeaderValue>,
+    timeout: Option<Duration>,
     base_url: String,
+    pub recv_window: usize,
+    pub environment: BinanceEnvironment,
 }
 
 impl Transport {
-    pub fn new(sandbox: bool) -> Result<Self> {
-        let default_headers = Transport::default_headers(None);
-        let client = reqwest::Client::builder()
-            .default_headers(default_headers)
-            .build()?;
-
+    pub fn new(environment: BinanceEnvironment, http: &HttpConfig) -> Result<Self> {
         Ok(Transport {
             credential: None,
-            client,
+            client: http.build_transport()?,
+            headers: Transport::default_headers(None),
+            timeout: http.timeout,
+            base_url: http.base_url_or(environment.rest_url()),
             recv_window: RECV_WINDOW,
-            base_url: Transport::get_base_url(sandbox),
+            environment,
         })
     }
 
-    pub fn with_credential(api_key: &str, api_secret: &str, sandbox: bool) -> Result<Self> {
-        let default_headers = Transport::default_headers(Some(api_key));
-        let client = reqwest::Client::builder()
-            .default_headers(default_headers)
-            .build()?;
-
+    pub fn with_credential(
+        api_key: &str,
+        api_secret: &str,
+        environment: BinanceEnvironment,
+        http: &HttpConfig,
+    ) -> Result<Self> {
         Ok(Transport {
-            client,
             credential: Some((api_key.into(), api_secret.into())),
+            client: http.build_transport()?,
+            headers: Transport::default_headers(Some(api_key)),
+            timeout: http.timeout,
+            base_url: http.base_url_or(environment.rest_url()),
             recv_window: RECV_WINDOW,
-            base_url: Transport::get_base_url(sandbox),
+            environment,
         })
     }
 
-    fn get_base_url(sandbox: bool) -> String {
-        if sandbox {
-            String::from("https://testnet.binance.vision")
-        } else {
-            String::from("https://api.binance.com")
-        }
-    }
-
     pub fn default_headers(api_key: Option<&str>) -> header::HeaderMap<header::HeaderValue> {
         let mut headers = header::HeaderMap::new();
         headers.insert(
@@ -88,7 +87,7 @@ impl Transport {
         S: Serialize,
     {
         let url = self.get_url(endpoint, params, false)?;
-        let request = self.client.get(url).send().await?;
+        let request = self.send::<()>(Method::GET, url, None).await?;
 
         Ok(self.response_handler(request).await?)
     }
@@ -99,7 +98,7 @@ impl Transport {
         D: Serialize,
     {
         let url = self.get_url::<()>(endpoint, None, false)?;
-        let request = self.client.post(url).form(&data).send().await?;
+        let request = self.send(Method::POST, url, data).await?;
 
         Ok(self.response_handler(request).await?)
     }
@@ -110,7 +109,7 @@ impl Transport {
         D: Serialize,
     {
         let url = self.get_url::<()>(endpoint, None, false)?;
-        let request = self.client.put(url).form(&data).send().await?;
+        let request = self.send(Method::PUT, url, data.as_ref()).await?;
 
         Ok(self.response_handler(request).await?)
     }
@@ -121,7 +120,7 @@ impl Transport {
         Q: Serialize,
     {
         let url = self.get_url::<()>(endpoint, None, false)?;
-        let request = self.client.delete(url).form(&data).send().await?;
+        let request = self.send(Method::DELETE, url, data).await?;
 
         Ok(self.response_handler(request).await?)
     }
@@ -136,7 +135,7 @@ impl Transport {
         let (_, signature) = self.signature::<()>(&url, None)?;
         url.query_pairs_mut().append_pair("signature", &signature);
 
-        let request = self.client.get(url).send().await?;
+        let request = self.send::<()>(Method::GET, url, None).await?;
 
         Ok(self.response_handler(request).await?)
     }
@@ -151,7 +150,7 @@ impl Transport {
         let (_, signature) = self.signature(&url, data)?;
         url.query_pairs_mut().append_pair("signature", &signature);
 
-        let request = self.client.post(url).form(&data).send().await?;
+        let request = self.send(Method::POST, url, data).await?;
         Ok(self.response_handler(request).await?)
     }
 
@@ -165,7 +164,7 @@ impl Transport {
         let (_, signature) = self.signature(&url, data)?;
         url.query_pairs_mut().append_pair("signature", &signature);
 
-        let request = self.client.put(url).form(&data).send().await?;
+        let request = self.send(Method::PUT, url, data).await?;
 
         Ok(self.response_handler(request).await?)
     }
@@ -180,11 +179,26 @@ impl Transport {
         let (_, signature) = self.signature(&url, data)?;
         url.query_pairs_mut().append_pair("signature", &signature);
 
-        let request = self.client.delete(url).form(&data).send().await?;
+        let request = self.send(Method::DELETE, url, data).await?;
 
         Ok(self.response_handler(request).await?)
     }
 
+    /// Requests are built here and sent by the http transport, the data is sent as a form
+    async fn send<D>(&self, method: Method, url: Url, data: Option<&D>) -> Result<Response>
+    where
+        D: Serialize,
+    {
+        let mut request = Request::new(method, url);
+        *request.headers_mut() = self.headers.clone();
+        *request.timeout_mut() = self.timeout;
+        if let Some(data) = data {
+            *request.body_mut() = Some(serde_urlencoded::to_string(data)?.into());
+        }
+
+        self.client.execute(request).await
+    }
+
     pub fn get_url<Q>(
         &self,
         endpoint: &str,
@@ -248,18 +262,30 @@ impl Transport {
     {
         match response.status() {
             StatusCode::OK => Ok(response.json::<O>().await?),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(OpenLimitsError::InternalServerError()),
-            StatusCode::SERVICE_UNAVAILABLE => Err(OpenLimitsError::ServiceUnavailable()),
-            StatusCode::UNAUTHORIZED => Err(OpenLimitsError::Unauthorized()),
-            StatusCode::BAD_REQUEST => {
-                let error: BinanceContentError = response.json().await?;
-
-                Err(OpenLimitsError::BinanceError(error))
+            // Binance explains most of the failures with a code in the body, whatever the status
+            status => {
+                let body = response.text().await?;
+                match serde_json::from_str::<BinanceContentError>(&body) {
+                    Ok(error) => Err(OpenLimitsError::BinanceError(
+                        error.with_status(status.as_u16()),
+                    )),
+                    Err(_) => Err(OpenLimitsError::HttpError {
+                        status: status.as_u16(),
+                        body,
+                    }),
+                }
             }
-            s => Err(OpenLimitsError::UnkownResponse(format!(
-                "Received response: {:?}",
-                s
-            ))),
         }
     }
 }
+
+impl fmt::Debug for Transport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Transport")
+            .field("base_url", &self.base_url)
+            .field("timeout", &self.timeout)
+            .field("recv_window", &self.recv_window)
+            .field("environment", &self.environment)
+            .finish()
+    }
+}
@@ -0,0 +1,33 @@
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use std::fmt;
+use thiserror::Error;
+
+/// This struct represents a bitfinex content error.
+/// Bitfinex sends errors as `["error", CODE, "MESSAGE"]`.
+#[derive(Serialize, Debug, Error)]
+pub struct BitfinexContentError {
+    pub code: i64,
+    pub msg: String,
+}
+
+impl<'de> Deserialize<'de> for BitfinexContentError {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (kind, code, msg): (String, i64, String) = Deserialize::deserialize(deserializer)?;
+        if kind != "error" {
+            return Err(de::Error::custom(format!("unexpected payload kind: {}", kind)));
+        }
+        Ok(Self { code, msg })
+    }
+}
+
+impl fmt::Display for BitfinexContentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "error code: {} msg: {}", self.code, self.msg)
+    }
+}
@@ -0,0 +1,6 @@
+/// This struct represents the credentials and receives the api key and api secret as parameters.
+#[derive(Clone, Debug)]
+pub struct BitfinexCredentials {
+    pub api_key: String,
+    pub api_secret: String,
+}
@@ -0,0 +1,26 @@
+use crate::exchange::traits::info::ExchangeInfoCache;
+use super::BitfinexCredentials;
+
+/// This struct represents the bitfinex parameters.
+/// Bitfinex has no sandbox environment, paper trading happens on the production endpoints
+/// with a dedicated sub-account, so only the credentials are configurable. The exchange
+/// information is read from `exchange_info_cache` when there's one.
+#[derive(Default, Clone, Debug)]
+pub struct BitfinexParameters {
+    pub credentials: Option<BitfinexCredentials>,
+    pub exchange_info_cache: Option<ExchangeInfoCache>,
+}
+
+impl BitfinexParameters {
+    /// Production environment
+    pub fn prod() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    pub fn with_exchange_info_cache(mut self, cache: ExchangeInfoCache) -> Self {
+        self.exchange_info_cache = Some(cache);
+        self
+    }
+}
@@ -0,0 +1,239 @@
+use rust_decimal::prelude::*;
+use crate::{
+    errors::OpenLimitsError,
+    exchange::bitfinex::{
+        BitfinexContentError,
+        model::{
+            AccountTrade, CancelOrderRequest, CancelOrdersRequest, Notification, Order,
+            OrderFilter, OrderRequest, Paginator, Position, Wallet, ORDER_FLAG_POST_ONLY,
+            ORDER_TYPE_EXCHANGE_MARKET,
+        },
+    },
+};
+use crate::exchange::traits::info::MarketPair;
+use super::BaseClient;
+use super::shared::Result;
+
+impl BaseClient {
+    pub async fn get_wallets(&self) -> Result<Vec<Wallet>> {
+        Ok(self
+            .transport
+            .signed_post::<_, ()>("/v2/auth/r/wallets", None)
+            .await?)
+    }
+
+    pub async fn get_positions(&self) -> Result<Vec<Position>> {
+        Ok(self
+            .transport
+            .signed_post::<_, ()>("/v2/auth/r/positions", None)
+            .await?)
+    }
+
+    // Active orders, for ONE symbol or for all of them
+    pub async fn get_active_orders(
+        &self,
+        symbol: Option<&str>,
+        filter: &OrderFilter,
+    ) -> Result<Vec<Order>> {
+        let endpoint = match symbol {
+            Some(symbol) => format!("/v2/auth/r/orders/{}", symbol),
+            None => String::from("/v2/auth/r/orders"),
+        };
+
+        Ok(self.transport.signed_post(&endpoint, Some(filter)).await?)
+    }
+
+    // Executed and canceled orders, bitfinex keeps them for two weeks
+    pub async fn get_orders_history(
+        &self,
+        symbol: Option<&str>,
+        filter: &OrderFilter,
+    ) -> Result<Vec<Order>> {
+        let endpoint = match symbol {
+            Some(symbol) => format!("/v2/auth/r/orders/{}/hist", symbol),
+            None => String::from("/v2/auth/r/orders/hist"),
+        };
+
+        Ok(self.transport.signed_post(&endpoint, Some(filter)).await?)
+    }
+
+    pub async fn get_trades_history(
+        &self,
+        symbol: Option<&str>,
+        paginator: Option<&Paginator>,
+    ) -> Result<Vec<AccountTrade>> {
+        let endpoint = match symbol {
+            Some(symbol) => format!("/v2/auth/r/trades/{}/hist", symbol),
+            None => String::from("/v2/auth/r/trades/hist"),
+        };
+
+        Ok(self.transport.signed_post(&endpoint, paginator).await?)
+    }
+
+    pub async fn get_order_trades(&self, symbol: &str, order_id: u64) -> Result<Vec<AccountTrade>> {
+        Ok(self
+            .transport
+            .signed_post::<_, ()>(&format!("/v2/auth/r/order/{}:{}/trades", symbol, order_id), None)
+            .await?)
+    }
+
+    // Place a LIMIT order - BUY
+    pub async fn limit_buy(
+        &self,
+        pair: MarketPair,
+        qty: Decimal,
+        price: Decimal,
+        order_type: &str,
+        tif: Option<String>,
+        post_only: bool,
+    ) -> Result<Order> {
+        let buy = OrderRequest {
+            order_type: order_type.to_string(),
+            symbol: pair.symbol,
+            amount: qty.round_dp(pair.base_increment.normalize().scale()),
+            price: Some(round_price(price, RoundingStrategy::ToZero)),
+            flags: post_only_flag(post_only),
+            tif,
+            cid: None,
+        };
+
+        self.submit_order(&buy).await
+    }
+
+    // Place a LIMIT order - SELL
+    pub async fn limit_sell(
+        &self,
+        pair: MarketPair,
+        qty: Decimal,
+        price: Decimal,
+        order_type: &str,
+        tif: Option<String>,
+        post_only: bool,
+    ) -> Result<Order> {
+        let sell = OrderRequest {
+            order_type: order_type.to_string(),
+            symbol: pair.symbol,
+            amount: -qty.round_dp(pair.base_increment.normalize().scale()),
+            price: Some(round_price(price, RoundingStrategy::AwayFromZero)),
+            flags: post_only_flag(post_only),
+            tif,
+            cid: None,
+        };
+
+        self.submit_order(&sell).await
+    }
+
+    // Place a MARKET order - BUY
+    pub async fn market_buy(&self, pair: MarketPair, qty: Decimal) -> Result<Order> {
+        let buy = OrderRequest {
+            order_type: ORDER_TYPE_EXCHANGE_MARKET.to_string(),
+            symbol: pair.symbol,
+            amount: qty.round_dp(pair.base_increment.normalize().scale()),
+            price: None,
+            flags: None,
+            tif: None,
+            cid: None,
+        };
+
+        self.submit_order(&buy).await
+    }
+
+    // Place a MARKET order - SELL
+    pub async fn market_sell(&self, pair: MarketPair, qty: Decimal) -> Result<Order> {
+        let sell = OrderRequest {
+            order_type: ORDER_TYPE_EXCHANGE_MARKET.to_string(),
+            symbol: pair.symbol,
+            amount: -qty.round_dp(pair.base_increment.normalize().scale()),
+            price: None,
+            flags: None,
+            tif: None,
+            cid: None,
+        };
+
+        self.submit_order(&sell).await
+    }
+
+    pub async fn submit_order(&self, order: &OrderRequest) -> Result<Order> {
+        let notification: Notification<Vec<Order>> = self
+            .transport
+            .signed_post("/v2/auth/w/order/submit", Some(order))
+            .await?;
+
+        notification_result(notification)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                OpenLimitsError::UnkownResponse("bitfinex didn't return the submitted order".to_string())
+            })
+    }
+
+    pub async fn cancel_order(&self, order_id: u64) -> Result<Order> {
+        let request = CancelOrderRequest { id: order_id };
+        let notification: Notification<Order> = self
+            .transport
+            .signed_post("/v2/auth/w/order/cancel", Some(&request))
+            .await?;
+
+        notification_result(notification)
+    }
+
+    pub async fn cancel_orders(&self, request: &CancelOrdersRequest) -> Result<Vec<Order>> {
+        let notification: Notification<Vec<Order>> = self
+            .transport
+            .signed_post("/v2/auth/w/order/cancel/multi", Some(request))
+            .await?;
+
+        notification_result(notification)
+    }
+}
+
+fn notification_result<T>(notification: Notification<T>) -> Result<T> {
+    if notification.is_success() {
+        Ok(notification.info)
+    } else {
+        Err(OpenLimitsError::BitfinexError(BitfinexContentError {
+            code: notification.code.unwrap_or_default(),
+            msg: notification.text.unwrap_or(notification.status),
+        }))
+    }
+}
+
+fn post_only_flag(post_only: bool) -> Option<u32> {
+    match post_only {
+        true => Some(ORDER_FLAG_POST_ONLY),
+        false => None,
+    }
+}
+
+/// Bitfinex prices have five significant digits and at most eight decimals.
+fn round_price(price: Decimal, strategy: RoundingStrategy) -> Decimal {
+    const SIGNIFICANT_DIGITS: i64 = 5;
+    const MAX_DECIMALS: i64 = 8;
+
+    if price.is_zero() {
+        return price;
+    }
+
+    let ten = Decimal::from(10);
+    let mut magnitude: i64 = 0;
+    let mut probe = price.abs();
+    while probe >= ten {
+        probe /= ten;
+        magnitude += 1;
+    }
+    while probe < Decimal::one() {
+        probe *= ten;
+        magnitude -= 1;
+    }
+
+    let scale = SIGNIFICANT_DIGITS - 1 - magnitude;
+    if scale >= 0 {
+        return price.round_dp_with_strategy(scale.min(MAX_DECIMALS) as u32, strategy);
+    }
+
+    let mut factor = Decimal::one();
+    for _ in 0..-scale {
+        factor *= ten;
+    }
+    (price / factor).round_dp_with_strategy(0, strategy) * factor
+}
@@ -0,0 +1,7 @@
+use super::Transport;
+
+/// The bitfinex client
+#[derive(Clone)]
+pub struct BaseClient {
+    pub transport: Transport,
+}
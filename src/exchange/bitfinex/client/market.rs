@@ -0,0 +1,59 @@
+use serde_json::json;
+use super::BaseClient;
+use crate::exchange::bitfinex::model::{Candle, PairInfo, Paginator, RawBookEntry, Ticker, TimeFrame, Trade};
+use super::shared::Result;
+
+/// Number of orders per side returned by the raw book endpoint (max 100)
+const BOOK_LENGTH: u64 = 100;
+
+// Market Data endpoints
+impl BaseClient {
+    // Trading pairs and their order size limits
+    pub async fn get_pair_info(&self) -> Result<Vec<PairInfo>> {
+        let pairs = self
+            .transport
+            .get::<Vec<Vec<PairInfo>>, ()>("/v2/conf/pub:info:pair", None)
+            .await?;
+
+        Ok(pairs.into_iter().flatten().collect())
+    }
+
+    pub async fn get_ticker(&self, symbol: &str) -> Result<Ticker> {
+        Ok(self
+            .transport
+            .get::<_, ()>(&format!("/v2/ticker/{}", symbol), None)
+            .await?)
+    }
+
+    // Raw order book, every entry is a single order
+    pub async fn get_raw_book(&self, symbol: &str) -> Result<Vec<RawBookEntry>> {
+        let params = json! {{"len": BOOK_LENGTH}};
+
+        Ok(self
+            .transport
+            .get(&format!("/v2/book/{}/R0", symbol), Some(&params))
+            .await?)
+    }
+
+    pub async fn get_candles(
+        &self,
+        symbol: &str,
+        time_frame: TimeFrame,
+        paginator: Option<&Paginator>,
+    ) -> Result<Vec<Candle>> {
+        Ok(self
+            .transport
+            .get(
+                &format!("/v2/candles/trade:{}:{}/hist", time_frame, symbol),
+                paginator,
+            )
+            .await?)
+    }
+
+    pub async fn get_trades(&self, symbol: &str, paginator: Option<&Paginator>) -> Result<Vec<Trade>> {
+        Ok(self
+            .transport
+            .get(&format!("/v2/trades/{}/hist", symbol), paginator)
+            .await?)
+    }
+}
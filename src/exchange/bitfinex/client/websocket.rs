@@ -0,0 +1,411 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::sync::Mutex;
+use async_trait::async_trait;
+use chrono::Utc;
+use futures::{SinkExt, stream::BoxStream, StreamExt};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
+use crate::{
+    errors::OpenLimitsError,
+    exchange::bitfinex::{
+        BitfinexParameters,
+        model::{
+            AccountTrade, Candle, Notification, Order, Position, RawBook, RawBookEntry, Trade,
+            Wallet,
+            array_payload::{integer, string, unsigned},
+            websocket::{
+                AuthRequest, BitfinexSubscription, BitfinexWebsocketMessage, ConfRequest, Event,
+                SubscribeRequest, CONF_FLAG_CHECKSUM,
+            },
+        },
+        transport::sign,
+    },
+    model::websocket::OpenLimitsWebSocketMessage,
+    model::websocket::WebSocketResponse,
+    model::MarketResponse,
+};
+use crate::exchange::traits::stream::{ExchangeWs, Subscriptions};
+use super::shared::Result;
+
+const WS_URL_PUBLIC: &str = "wss://api-pub.bitfinex.com/ws/2";
+const WS_URL_AUTHENTICATED: &str = "wss://api.bitfinex.com/ws/2";
+
+/// The authenticated account messages are all sent on channel 0
+const ACCOUNT_CHANNEL: u64 = 0;
+
+/// This struct is used for websocket communications with bitfinex exchange
+pub struct BitfinexWebsocket {
+    parameters: BitfinexParameters,
+    disconnection_senders: Mutex<Vec<UnboundedSender<()>>>,
+}
+
+#[async_trait]
+impl ExchangeWs for BitfinexWebsocket {
+    type InitParams = BitfinexParameters;
+    type Subscription = BitfinexSubscription;
+    type Response = BitfinexWebsocketMessage;
+
+    async fn new(parameters: Self::InitParams) -> Result<Self> {
+        Ok(BitfinexWebsocket {
+            parameters,
+            disconnection_senders: Default::default(),
+        })
+    }
+
+    async fn disconnect(&self) {
+        if let Ok(mut senders) = self.disconnection_senders.lock() {
+            for sender in senders.iter() {
+                sender.send(()).ok();
+            }
+            senders.clear();
+        }
+    }
+
+    async fn create_stream_specific(
+        &self,
+        subscriptions: Subscriptions<Self::Subscription>,
+    ) -> Result<BoxStream<'static, Result<Self::Response>>> {
+        let subscriptions: Vec<BitfinexSubscription> = subscriptions.into_iter().collect();
+        let auth = match subscriptions.contains(&BitfinexSubscription::Account) {
+            true => {
+                let credentials = self
+                    .parameters
+                    .credentials
+                    .as_ref()
+                    .ok_or(OpenLimitsError::NoApiKeySet())?;
+                Some(auth_request(&credentials.api_key, &credentials.api_secret))
+            }
+            false => None,
+        };
+
+        let ws_url = match auth {
+            Some(_) => WS_URL_AUTHENTICATED,
+            None => WS_URL_PUBLIC,
+        };
+        let endpoint = url::Url::parse(ws_url).map_err(OpenLimitsError::UrlParserError)?;
+        let (ws_stream, _) = connect_async(endpoint).await?;
+
+        let (mut sink, stream) = ws_stream.split();
+
+        let conf = ConfRequest::new(CONF_FLAG_CHECKSUM);
+        sink.send(Message::Text(serde_json::to_string(&conf)?)).await?;
+        if let Some(auth) = auth {
+            sink.send(Message::Text(serde_json::to_string(&auth)?)).await?;
+        }
+        for subscription in subscriptions.iter() {
+            if let Some(request) = SubscribeRequest::from_subscription(subscription) {
+                sink.send(Message::Text(serde_json::to_string(&request)?))
+                    .await?;
+            }
+        }
+
+        let (disconnection_sender, mut disconnection_receiver) = unbounded_channel();
+        tokio::spawn(async move {
+            if disconnection_receiver.recv().await.is_some() {
+                sink.close().await.ok();
+            }
+        });
+
+        if let Ok(mut senders) = self.disconnection_senders.lock() {
+            senders.push(disconnection_sender);
+        }
+
+        let mut state = StreamState::default();
+        let s = stream.map(move |message| match message {
+            Ok(msg) => state.parse_message(msg),
+            Err(_) => Err(OpenLimitsError::SocketError()),
+        });
+
+        Ok(s.boxed())
+    }
+}
+
+impl TryFrom<BitfinexWebsocketMessage> for WebSocketResponse<BitfinexWebsocketMessage> {
+    type Error = OpenLimitsError;
+
+    fn try_from(value: BitfinexWebsocketMessage) -> Result<Self> {
+        match value {
+            BitfinexWebsocketMessage::BookSnapshot { symbol, book } => {
+                Ok(WebSocketResponse::Generic(OpenLimitsWebSocketMessage::OrderBook(
+                    book.with_market_pair(&symbol),
+                )))
+            }
+            BitfinexWebsocketMessage::BookUpdate { symbol, levels, .. } => {
+                Ok(WebSocketResponse::Generic(OpenLimitsWebSocketMessage::OrderBookDiff(
+                    levels.with_market_pair(&symbol),
+                )))
+            }
+            BitfinexWebsocketMessage::TradesSnapshot { symbol, trades } => {
+                Ok(WebSocketResponse::Generic(OpenLimitsWebSocketMessage::Trades(
+                    trades
+                        .into_iter()
+                        .map(|trade| trade.into_trade(&symbol))
+                        .collect(),
+                )))
+            }
+            BitfinexWebsocketMessage::TradeExecuted { symbol, trade } => Ok(
+                WebSocketResponse::Generic(OpenLimitsWebSocketMessage::Trades(vec![
+                    trade.into_trade(&symbol),
+                ])),
+            ),
+            BitfinexWebsocketMessage::OrdersSnapshot(orders) => Ok(WebSocketResponse::Generic(
+                OpenLimitsWebSocketMessage::Orders(orders.into_iter().map(Into::into).collect()),
+            )),
+            BitfinexWebsocketMessage::OrderNew(order)
+            | BitfinexWebsocketMessage::OrderUpdate(order)
+            | BitfinexWebsocketMessage::OrderCancel(order) => Ok(WebSocketResponse::Generic(
+                OpenLimitsWebSocketMessage::Orders(vec![order.into()]),
+            )),
+            // The update repeats the execution with its fee, the fills are deduplicated by id
+            BitfinexWebsocketMessage::AccountTradeExecuted(trade)
+            | BitfinexWebsocketMessage::AccountTradeUpdate(trade) => Ok(
+                WebSocketResponse::Generic(OpenLimitsWebSocketMessage::Trades(vec![trade.into()])),
+            ),
+            BitfinexWebsocketMessage::Heartbeat | BitfinexWebsocketMessage::Ping => {
+                Ok(WebSocketResponse::Generic(OpenLimitsWebSocketMessage::Ping))
+            }
+            BitfinexWebsocketMessage::Close => Err(OpenLimitsError::SocketError()),
+            _ => Ok(WebSocketResponse::Raw(value)),
+        }
+    }
+}
+
+fn auth_request(api_key: &str, api_secret: &str) -> AuthRequest {
+    let nonce = (Utc::now().timestamp_nanos() / 1000).to_string();
+    let auth_payload = format!("AUTH{}", nonce);
+
+    AuthRequest {
+        event: String::from("auth"),
+        api_key: api_key.to_string(),
+        auth_sig: sign(api_secret, &auth_payload),
+        auth_nonce: nonce,
+        auth_payload,
+    }
+}
+
+/// Public channel a `chanId` was assigned to
+#[derive(Debug, Clone)]
+enum Channel {
+    Book(String),
+    Trades(String),
+    Ticker(String),
+    Candles(String),
+}
+
+/// Bitfinex messages only carry the channel id, the stream keeps the channels announced by the
+/// `subscribed` events and a local raw book per book channel to validate the checksums.
+#[derive(Default)]
+struct StreamState {
+    channels: HashMap<u64, Channel>,
+    books: HashMap<u64, RawBook>,
+}
+
+impl StreamState {
+    fn parse_message(&mut self, ws_message: Message) -> Result<BitfinexWebsocketMessage> {
+        let msg = match ws_message {
+            Message::Text(m) => m,
+            Message::Binary(b) => return Ok(BitfinexWebsocketMessage::Binary(b)),
+            Message::Pong(..) => return Ok(BitfinexWebsocketMessage::Pong),
+            Message::Ping(..) => return Ok(BitfinexWebsocketMessage::Ping),
+            Message::Close(..) => return Ok(BitfinexWebsocketMessage::Close),
+        };
+
+        match serde_json::from_str(&msg).map_err(OpenLimitsError::JsonError)? {
+            Value::Array(row) => self.parse_channel_message(&row),
+            value => self.parse_event(value),
+        }
+    }
+
+    fn parse_event(&mut self, value: Value) -> Result<BitfinexWebsocketMessage> {
+        let event = match serde_json::from_value::<Event>(value.clone()) {
+            Ok(event) => event,
+            Err(_) => return Ok(BitfinexWebsocketMessage::Unknown(value)),
+        };
+
+        match &event {
+            Event::Subscribed {
+                channel,
+                chan_id,
+                symbol,
+                key,
+            } => {
+                let channel = match (channel.as_str(), symbol, key) {
+                    ("book", Some(symbol), _) => Some(Channel::Book(symbol.clone())),
+                    ("trades", Some(symbol), _) => Some(Channel::Trades(symbol.clone())),
+                    ("ticker", Some(symbol), _) => Some(Channel::Ticker(symbol.clone())),
+                    ("candles", _, Some(key)) => Some(Channel::Candles(key.clone())),
+                    _ => None,
+                };
+                if let Some(channel) = channel {
+                    self.channels.insert(*chan_id, channel);
+                }
+            }
+            Event::Unsubscribed { chan_id, .. } => {
+                self.channels.remove(chan_id);
+                self.books.remove(chan_id);
+            }
+            Event::Error { code, msg } => {
+                return Err(OpenLimitsError::BitfinexError(
+                    crate::exchange::bitfinex::BitfinexContentError {
+                        code: *code,
+                        msg: msg.clone(),
+                    },
+                ))
+            }
+            _ => (),
+        }
+
+        Ok(BitfinexWebsocketMessage::Event(event))
+    }
+
+    fn parse_channel_message(&mut self, row: &[Value]) -> Result<BitfinexWebsocketMessage> {
+        let chan_id = unsigned(row, 0).map_err(OpenLimitsError::NotParsableResponse)?;
+        if let Some(Value::String(kind)) = row.get(1) {
+            if kind == "hb" {
+                return Ok(BitfinexWebsocketMessage::Heartbeat);
+            }
+        }
+
+        if chan_id == ACCOUNT_CHANNEL {
+            return parse_account_message(row);
+        }
+
+        match self.channels.get(&chan_id).cloned() {
+            Some(Channel::Book(symbol)) => self.parse_book_message(chan_id, symbol, row),
+            Some(Channel::Trades(symbol)) => parse_trades_message(symbol, row),
+            Some(Channel::Ticker(symbol)) => Ok(BitfinexWebsocketMessage::Ticker {
+                symbol,
+                ticker: parse_row(&row_value(row, 1)?)?,
+            }),
+            Some(Channel::Candles(key)) => {
+                let payload = row_value(row, 1)?;
+                match is_snapshot(&payload) {
+                    true => Ok(BitfinexWebsocketMessage::CandlesSnapshot {
+                        key,
+                        candles: parse_rows::<Candle>(&payload)?,
+                    }),
+                    false => Ok(BitfinexWebsocketMessage::CandleUpdate {
+                        key,
+                        candle: parse_row(&payload)?,
+                    }),
+                }
+            }
+            None => Ok(BitfinexWebsocketMessage::Unknown(Value::Array(row.to_vec()))),
+        }
+    }
+
+    fn parse_book_message(
+        &mut self,
+        chan_id: u64,
+        symbol: String,
+        row: &[Value],
+    ) -> Result<BitfinexWebsocketMessage> {
+        if let Some(Value::String(kind)) = row.get(1) {
+            if kind != "cs" {
+                return Ok(BitfinexWebsocketMessage::Unknown(Value::Array(row.to_vec())));
+            }
+
+            let checksum = integer(row, 2).map_err(OpenLimitsError::NotParsableResponse)? as i32;
+            let book = self.books.entry(chan_id).or_default();
+            if !book.is_valid(checksum) {
+                // The local book can't be trusted anymore, the channel has to be resubscribed.
+                self.books.remove(&chan_id);
+                return Err(OpenLimitsError::ChecksumMismatch(format!(
+                    "{} order book checksum mismatch, expected {}",
+                    symbol, checksum
+                )));
+            }
+            return Ok(BitfinexWebsocketMessage::Checksum { symbol, checksum });
+        }
+
+        let payload = row_value(row, 1)?;
+        if is_snapshot(&payload) {
+            let book = RawBook::from_entries(parse_rows::<RawBookEntry>(&payload)?);
+            let response = book.to_order_book();
+            self.books.insert(chan_id, book);
+            return Ok(BitfinexWebsocketMessage::BookSnapshot {
+                symbol,
+                book: response,
+            });
+        }
+
+        let entry: RawBookEntry = parse_row(&payload)?;
+        let levels = self.books.entry(chan_id).or_default().apply(entry.clone());
+        Ok(BitfinexWebsocketMessage::BookUpdate {
+            symbol,
+            entry,
+            levels,
+        })
+    }
+}
+
+fn parse_trades_message(symbol: String, row: &[Value]) -> Result<BitfinexWebsocketMessage> {
+    match row.get(1) {
+        Some(Value::String(kind)) if kind == "te" => Ok(BitfinexWebsocketMessage::TradeExecuted {
+            symbol,
+            trade: parse_row::<Trade>(&row_value(row, 2)?)?,
+        }),
+        Some(Value::String(kind)) if kind == "tu" => Ok(BitfinexWebsocketMessage::TradeUpdated {
+            symbol,
+            trade: parse_row::<Trade>(&row_value(row, 2)?)?,
+        }),
+        Some(payload @ Value::Array(_)) => Ok(BitfinexWebsocketMessage::TradesSnapshot {
+            symbol,
+            trades: parse_rows::<Trade>(payload)?,
+        }),
+        _ => Ok(BitfinexWebsocketMessage::Unknown(Value::Array(row.to_vec()))),
+    }
+}
+
+fn parse_account_message(row: &[Value]) -> Result<BitfinexWebsocketMessage> {
+    let kind = string(row, 1).map_err(OpenLimitsError::NotParsableResponse)?;
+    let payload = row_value(row, 2)?;
+
+    let message = match kind.as_str() {
+        "os" => BitfinexWebsocketMessage::OrdersSnapshot(parse_rows::<Order>(&payload)?),
+        "on" => BitfinexWebsocketMessage::OrderNew(parse_row(&payload)?),
+        "ou" => BitfinexWebsocketMessage::OrderUpdate(parse_row(&payload)?),
+        "oc" => BitfinexWebsocketMessage::OrderCancel(parse_row(&payload)?),
+        "ws" => BitfinexWebsocketMessage::WalletsSnapshot(parse_rows::<Wallet>(&payload)?),
+        "wu" => BitfinexWebsocketMessage::WalletUpdate(parse_row(&payload)?),
+        "ps" => BitfinexWebsocketMessage::PositionsSnapshot(parse_rows::<Position>(&payload)?),
+        "pn" => BitfinexWebsocketMessage::PositionNew(parse_row(&payload)?),
+        "pu" => BitfinexWebsocketMessage::PositionUpdate(parse_row(&payload)?),
+        "pc" => BitfinexWebsocketMessage::PositionClose(parse_row(&payload)?),
+        "te" => BitfinexWebsocketMessage::AccountTradeExecuted(parse_row::<AccountTrade>(&payload)?),
+        "tu" => BitfinexWebsocketMessage::AccountTradeUpdate(parse_row::<AccountTrade>(&payload)?),
+        "n" => BitfinexWebsocketMessage::Notification(parse_row::<Notification<Value>>(&payload)?),
+        _ => BitfinexWebsocketMessage::Unknown(Value::Array(row.to_vec())),
+    };
+
+    Ok(message)
+}
+
+fn row_value(row: &[Value], index: usize) -> Result<Value> {
+    row.get(index).cloned().ok_or_else(|| {
+        OpenLimitsError::NotParsableResponse(format!(
+            "missing index {} in {}",
+            index,
+            Value::Array(row.to_vec())
+        ))
+    })
+}
+
+/// Snapshots are arrays of rows, updates are a single row.
+fn is_snapshot(payload: &Value) -> bool {
+    match payload.as_array() {
+        Some(rows) => rows.first().map(Value::is_array).unwrap_or(true),
+        None => false,
+    }
+}
+
+fn parse_row<T: DeserializeOwned>(value: &Value) -> Result<T> {
+    serde_json::from_value(value.clone()).map_err(OpenLimitsError::JsonError)
+}
+
+fn parse_rows<T: DeserializeOwned>(value: &Value) -> Result<Vec<T>> {
+    serde_json::from_value(value.clone()).map_err(OpenLimitsError::JsonError)
+}
@@ -0,0 +1,520 @@
+//! This module provides functionality for communicating with the bitfinex API.
+//! # Example
+//! ```
+//! use openlimits::exchange::bitfinex::Bitfinex;
+//! use openlimits::exchange::bitfinex::BitfinexParameters;
+//! use openlimits::prelude::*;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let bitfinex = Bitfinex::new(BitfinexParameters::prod())
+//!                         .await
+//!                         .expect("Couldn't create bitfinex client");
+
+//!     let order_book = bitfinex.order_book(&OrderBookRequest {market_pair: "tBTCUSD".to_string()})
+//!                         .await
+//!                         .expect("Couldn't get order book");
+
+//!     println!("{:?}", order_book);
+//! }
+//! ```
+
+use std::convert::TryFrom;
+use async_trait::async_trait;
+use chrono::Utc;
+use client::BaseClient;
+use transport::Transport;
+use rust_decimal::prelude::*;
+use crate::{
+    errors::OpenLimitsError,
+    model::{
+        Balance, CancelAllOrdersRequest, CancelOrderRequest, Candle,
+        GetHistoricRatesRequest, GetHistoricTradesRequest, GetOrderHistoryRequest, GetOrderRequest,
+        GetPriceTickerRequest, Interval, Liquidity, OpenLimitOrderRequest, OpenMarketOrderRequest,
+        Order, OrderBookRequest, OrderBookResponse, OrderCanceled, OrderStatus, OrderType,
+        Paginator, Side, Ticker, TimeInForce, Trade, TradeHistoryRequest,
+    },
+};
+use crate::exchange::traits::info::{ExchangeInfoRetrieval, MarketPair, MarketPairHandle};
+use crate::exchange::traits::Exchange;
+use crate::prelude::*;
+use super::shared::Result;
+
+pub mod client;
+pub mod model;
+mod transport;
+mod bitfinex_content_error;
+mod bitfinex_credentials;
+mod bitfinex_parameters;
+
+pub use client::websocket::BitfinexWebsocket;
+pub use bitfinex_content_error::BitfinexContentError;
+pub use bitfinex_credentials::BitfinexCredentials;
+pub use bitfinex_parameters::BitfinexParameters;
+pub use super::shared;
+
+/// Bitfinex amounts have at most eight decimals
+const AMOUNT_DECIMALS: u32 = 8;
+
+/// The main struct of the bitfinex module
+#[derive(Clone)]
+pub struct Bitfinex {
+    pub exchange_info: ExchangeInfo,
+    pub client: BaseClient,
+}
+
+#[async_trait]
+impl Exchange for Bitfinex {
+    type InitParams = BitfinexParameters;
+    type InnerClient = BaseClient;
+
+    async fn new(parameters: Self::InitParams) -> Result<Self> {
+        let bitfinex = match parameters.credentials {
+            Some(credentials) => Bitfinex {
+                exchange_info: ExchangeInfo::new(),
+                client: BaseClient {
+                    transport: Transport::with_credential(
+                        &credentials.api_key,
+                        &credentials.api_secret,
+                    )?,
+                },
+            },
+            None => Bitfinex {
+                exchange_info: ExchangeInfo::new(),
+                client: BaseClient {
+                    transport: Transport::new()?,
+                },
+            },
+        };
+
+        match &parameters.exchange_info_cache {
+            Some(cache) => cache.initialize(&bitfinex, &bitfinex.exchange_info).await?,
+            None => {
+                bitfinex.refresh_market_info().await?;
+            }
+        }
+        Ok(bitfinex)
+    }
+
+    fn inner_client(&self) -> Option<&Self::InnerClient> {
+        Some(&self.client)
+    }
+}
+
+#[async_trait]
+impl ExchangeInfoRetrieval for Bitfinex {
+    async fn retrieve_pairs(&self) -> Result<Vec<MarketPair>> {
+        self.client
+            .get_pair_info()
+            .await
+            .map(|v| v.into_iter().map(Into::into).collect())
+    }
+
+    async fn refresh_market_info(&self) -> Result<Vec<MarketPairHandle>> {
+        self.exchange_info
+            .refresh(self as &dyn ExchangeInfoRetrieval)
+            .await
+    }
+
+    async fn get_pair(&self, name: &str) -> Result<MarketPairHandle> {
+        self.exchange_info.get_pair(name)
+    }
+}
+
+#[async_trait]
+impl ExchangeMarketData for Bitfinex {
+    async fn order_book(&self, req: &OrderBookRequest) -> Result<OrderBookResponse> {
+        self.client
+            .get_raw_book(&req.market_pair)
+            .await
+            .map(|entries| {
+                let book = model::RawBook::from_entries(entries).to_order_book();
+                self.exchange_info.translate_market(&req.market_pair, book)
+            })
+    }
+
+    async fn get_price_ticker(&self, req: &GetPriceTickerRequest) -> Result<Ticker> {
+        self.client
+            .get_ticker(&req.market_pair)
+            .await
+            .map(|ticker: Ticker| self.exchange_info.translate_market(&req.market_pair, ticker))
+    }
+
+    async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
+        let time_frame = model::TimeFrame::try_from(req.interval)?;
+        let paginator = req.paginator.clone().map(model::Paginator::from);
+        self.client
+            .get_candles(&req.market_pair, time_frame, paginator.as_ref())
+            .await
+            .map(|v| {
+                let candles: Vec<Candle> = v.into_iter().map(Into::into).collect();
+                self.exchange_info.translate_market(&req.market_pair, candles)
+            })
+    }
+
+    async fn get_historic_trades(&self, req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
+        let paginator = req.paginator.clone().map(model::Paginator::from);
+        self.client
+            .get_trades(&req.market_pair, paginator.as_ref())
+            .await
+            .map(|v| {
+                self.exchange_info.translate(
+                    v.into_iter()
+                        .map(|trade| trade.into_trade(&req.market_pair))
+                        .collect(),
+                )
+            })
+    }
+}
+
+#[async_trait]
+impl ExchangeAccount for Bitfinex {
+    async fn limit_buy(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
+        let (order_type, tif) = limit_order_type(req.time_in_force);
+        self.client
+            .limit_buy(pair, req.size, req.price, order_type, tif, req.post_only)
+            .await
+            .map(|order: Order| self.exchange_info.translate(order))
+    }
+
+    async fn limit_sell(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
+        let (order_type, tif) = limit_order_type(req.time_in_force);
+        self.client
+            .limit_sell(pair, req.size, req.price, order_type, tif, req.post_only)
+            .await
+            .map(|order: Order| self.exchange_info.translate(order))
+    }
+
+    async fn market_buy(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
+        self.client
+            .market_buy(pair, req.size)
+            .await
+            .map(|order: Order| self.exchange_info.translate(order))
+    }
+
+    async fn market_sell(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
+        self.client
+            .market_sell(pair, req.size)
+            .await
+            .map(|order: Order| self.exchange_info.translate(order))
+    }
+
+    async fn cancel_order(&self, req: &CancelOrderRequest) -> Result<OrderCanceled> {
+        let id = parse_order_id(&req.id)?;
+        self.client.cancel_order(id).await.map(Into::into)
+    }
+
+    async fn cancel_all_orders(&self, req: &CancelAllOrdersRequest) -> Result<Vec<OrderCanceled>> {
+        let request = match req.market_pair.as_ref() {
+            Some(pair) => {
+                // Bitfinex can only cancel everything at once, the orders of the pair are
+                // listed first and canceled by id.
+                let ids: Vec<u64> = self
+                    .client
+                    .get_active_orders(Some(pair), &model::OrderFilter::default())
+                    .await?
+                    .into_iter()
+                    .map(|order| order.id)
+                    .collect();
+                if ids.is_empty() {
+                    return Ok(Vec::new());
+                }
+                model::CancelOrdersRequest {
+                    id: Some(ids),
+                    all: None,
+                }
+            }
+            None => model::CancelOrdersRequest {
+                id: None,
+                all: Some(1),
+            },
+        };
+
+        self.client
+            .cancel_orders(&request)
+            .await
+            .map(|v| v.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_all_open_orders(&self) -> Result<Vec<Order>> {
+        self.client
+            .get_active_orders(None, &model::OrderFilter::default())
+            .await
+            .map(|v| self.exchange_info.translate(v.into_iter().map(Into::into).collect()))
+    }
+
+    async fn get_order_history(&self, req: &GetOrderHistoryRequest) -> Result<Vec<Order>> {
+        let filter = model::OrderFilter {
+            id: None,
+            paginator: req.paginator.clone().map(Into::into),
+        };
+        self.client
+            .get_orders_history(req.market_pair.as_deref(), &filter)
+            .await
+            .map(|v| self.exchange_info.translate(v.into_iter().map(Into::into).collect()))
+    }
+
+    async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>> {
+        let trades = match (req.market_pair.as_ref(), req.order_id.as_ref()) {
+            (Some(pair), Some(order_id)) => {
+                let order_id = parse_order_id(order_id)?;
+                self.client.get_order_trades(pair, order_id).await?
+            }
+            (None, Some(_)) => {
+                return Err(OpenLimitsError::MissingParameter(
+                    "market_pair parameter is required to filter by order_id.".to_string(),
+                ))
+            }
+            (pair, None) => {
+                let paginator = req.paginator.clone().map(model::Paginator::from);
+                self.client
+                    .get_trades_history(pair.map(String::as_str), paginator.as_ref())
+                    .await?
+            }
+        };
+
+        Ok(self
+            .exchange_info
+            .translate(trades.into_iter().map(Into::into).collect()))
+    }
+
+    async fn get_account_balances(&self, _paginator: Option<Paginator>) -> Result<Vec<Balance>> {
+        self.client.get_wallets().await.map(|v| {
+            v.into_iter()
+                .filter(|wallet| wallet.wallet_type == model::WALLET_TYPE_EXCHANGE)
+                .map(Into::into)
+                .collect()
+        })
+    }
+
+    async fn get_order(&self, req: &GetOrderRequest) -> Result<Order> {
+        let filter = model::OrderFilter {
+            id: Some(vec![parse_order_id(&req.id)?]),
+            paginator: None,
+        };
+        let symbol = req.market_pair.as_deref();
+
+        // Executed and canceled orders are only listed in the history
+        let active = self.client.get_active_orders(symbol, &filter).await?;
+        let order = match active.into_iter().next() {
+            Some(order) => order,
+            None => self
+                .client
+                .get_orders_history(symbol, &filter)
+                .await?
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    OpenLimitsError::InvalidParameter(format!("order {} not found", req.id))
+                })?,
+        };
+
+        Ok(self.exchange_info.translate(order.into()))
+    }
+}
+
+fn parse_order_id(id: &str) -> Result<u64> {
+    id.parse::<u64>().map_err(|_| {
+        OpenLimitsError::InvalidParameter(format!("bitfinex order id {} is not a number", id))
+    })
+}
+
+fn limit_order_type(tif: TimeInForce) -> (&'static str, Option<String>) {
+    match tif {
+        TimeInForce::GoodTillCancelled => (model::ORDER_TYPE_EXCHANGE_LIMIT, None),
+        TimeInForce::ImmediateOrCancelled => (model::ORDER_TYPE_EXCHANGE_IOC, None),
+        TimeInForce::FillOrKill => (model::ORDER_TYPE_EXCHANGE_FOK, None),
+        TimeInForce::GoodTillTime(duration) => (
+            model::ORDER_TYPE_EXCHANGE_LIMIT,
+            Some((Utc::now() + duration).format("%Y-%m-%d %H:%M:%S").to_string()),
+        ),
+    }
+}
+
+impl From<model::PairInfo> for MarketPair {
+    fn from(info: model::PairInfo) -> Self {
+        let (base, quote) = info.base_and_quote();
+        Self {
+            symbol: info.symbol(),
+            base,
+            quote,
+            base_increment: Decimal::new(1, AMOUNT_DECIMALS),
+            // Prices have five significant digits, the increment depends on the price.
+            quote_increment: Decimal::new(1, AMOUNT_DECIMALS),
+            min_base_trade_size: info.min_order_size,
+            max_base_trade_size: info.max_order_size,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<model::Ticker> for Ticker {
+    fn from(ticker: model::Ticker) -> Self {
+        Self {
+            market_pair: None,
+            instrument: None,
+            price: Some(ticker.last_price),
+            price_24h: Some(ticker.last_price - ticker.daily_change),
+        }
+    }
+}
+
+impl From<model::Candle> for Candle {
+    fn from(candle: model::Candle) -> Self {
+        Self {
+            market_pair: None,
+            instrument: None,
+            time: candle.mts,
+            low: candle.low,
+            high: candle.high,
+            open: candle.open,
+            close: candle.close,
+            volume: candle.volume,
+        }
+    }
+}
+
+impl From<model::Order> for Order {
+    fn from(order: model::Order) -> Self {
+        let order_type = if order.order_type.contains("STOP LIMIT") {
+            OrderType::StopLimit
+        } else if order.order_type.contains("STOP") {
+            OrderType::StopMarket
+        } else if order.order_type.contains("LIMIT")
+            || order.order_type.contains("IOC")
+            || order.order_type.contains("FOK")
+        {
+            OrderType::Limit
+        } else if order.order_type.contains("MARKET") {
+            OrderType::Market
+        } else {
+            OrderType::Unknown
+        };
+
+        Self {
+            id: order.id.to_string(),
+            market_pair: order.symbol,
+            instrument: None,
+            client_order_id: order.cid.map(|cid| cid.to_string()),
+            created_at: Some(order.mts_create),
+            order_type,
+            side: match order.amount_orig.is_sign_negative() {
+                true => Side::Sell,
+                false => Side::Buy,
+            },
+            status: order_status(&order.status),
+            size: order.amount_orig.abs(),
+            price: order.price,
+            remaining: Some(order.amount.abs()),
+            trades: Vec::new(),
+        }
+    }
+}
+
+impl From<model::Order> for OrderCanceled {
+    fn from(order: model::Order) -> Self {
+        Self {
+            id: order.id.to_string(),
+        }
+    }
+}
+
+/// Bitfinex statuses carry execution details, e.g. `EXECUTED @ 107.6(-0.2)` or
+/// `CANCELED was: PARTIALLY FILLED @ ...`, only their prefix is meaningful.
+fn order_status(status: &str) -> OrderStatus {
+    if status.starts_with("ACTIVE") {
+        OrderStatus::Open
+    } else if status.starts_with("PARTIALLY FILLED") {
+        OrderStatus::PartiallyFilled
+    } else if status.starts_with("EXECUTED") {
+        OrderStatus::Filled
+    } else if status.contains("CANCELED") {
+        OrderStatus::Canceled
+    } else {
+        OrderStatus::Rejected
+    }
+}
+
+impl From<model::Wallet> for Balance {
+    fn from(wallet: model::Wallet) -> Self {
+        let free = wallet.available_balance.unwrap_or(wallet.balance);
+        Self {
+            asset: wallet.currency,
+            free,
+            total: wallet.balance,
+            locked: wallet.balance - free,
+            // Bitfinex has exchange, margin and funding wallets
+            wallet: Some(wallet.wallet_type),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<model::AccountTrade> for Trade {
+    fn from(trade: model::AccountTrade) -> Self {
+        let side = match trade.exec_amount.is_sign_negative() {
+            true => Side::Sell,
+            false => Side::Buy,
+        };
+        let (buyer_order_id, seller_order_id) = match side {
+            Side::Buy => (Some(trade.order_id.to_string()), None),
+            Side::Sell => (None, Some(trade.order_id.to_string())),
+        };
+
+        Self {
+            id: trade.id.to_string(),
+            buyer_order_id,
+            seller_order_id,
+            market_pair: trade.symbol,
+            instrument: None,
+            price: trade.exec_price,
+            qty: trade.exec_amount.abs(),
+            // Bitfinex reports fees as negative amounts
+            fees: trade.fee.map(|fee| fee.abs()),
+            side,
+            liquidity: match trade.maker {
+                true => Some(Liquidity::Maker),
+                false => Some(Liquidity::Taker),
+            },
+            created_at: trade.mts,
+        }
+    }
+}
+
+impl From<Paginator> for model::Paginator {
+    fn from(paginator: Paginator) -> Self {
+        Self {
+            start: paginator.start_time,
+            end: paginator.end_time,
+            limit: paginator.limit,
+            sort: None,
+        }
+    }
+}
+
+impl TryFrom<Interval> for model::TimeFrame {
+    type Error = OpenLimitsError;
+
+    fn try_from(interval: Interval) -> Result<Self> {
+        match interval {
+            Interval::OneMinute => Ok(model::TimeFrame::OneMinute),
+            Interval::FiveMinutes => Ok(model::TimeFrame::FiveMinutes),
+            Interval::FifteenMinutes => Ok(model::TimeFrame::FifteenMinutes),
+            Interval::ThirtyMinutes => Ok(model::TimeFrame::ThirtyMinutes),
+            Interval::OneHour => Ok(model::TimeFrame::OneHour),
+            Interval::SixHours => Ok(model::TimeFrame::SixHours),
+            Interval::TwelveHours => Ok(model::TimeFrame::TwelveHours),
+            Interval::OneDay => Ok(model::TimeFrame::OneDay),
+            Interval::OneWeek => Ok(model::TimeFrame::OneWeek),
+            Interval::OneMonth => Ok(model::TimeFrame::OneMonth),
+            _ => Err(OpenLimitsError::InvalidParameter(format!(
+                "{:?} is not supported in Bitfinex",
+                interval,
+            ))),
+        }
+    }
+}
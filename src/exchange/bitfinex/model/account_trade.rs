@@ -0,0 +1,58 @@
+use std::convert::TryFrom;
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde_json::Value;
+use rust_decimal::prelude::Decimal;
+use super::array_payload::{decimal, integer, opt_decimal, opt_integer, opt_string, string, unsigned};
+
+/// This struct represents one of our own trades
+/// `[ID, SYMBOL, MTS, ORDER_ID, EXEC_AMOUNT, EXEC_PRICE, ORDER_TYPE, ORDER_PRICE, MAKER, FEE, FEE_CURRENCY, CID]`.
+/// The `te` websocket event doesn't carry the fee yet, it only comes with the following `tu`.
+#[derive(Debug, Serialize, Clone)]
+pub struct AccountTrade {
+    pub id: u64,
+    pub symbol: String,
+    pub mts: u64,
+    pub order_id: u64,
+    pub exec_amount: Decimal,
+    pub exec_price: Decimal,
+    pub order_type: Option<String>,
+    pub order_price: Option<Decimal>,
+    pub maker: bool,
+    pub fee: Option<Decimal>,
+    pub fee_currency: Option<String>,
+    pub cid: Option<i64>,
+}
+
+impl TryFrom<&[Value]> for AccountTrade {
+    type Error = String;
+
+    fn try_from(row: &[Value]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: unsigned(row, 0)?,
+            symbol: string(row, 1)?,
+            mts: unsigned(row, 2)?,
+            order_id: unsigned(row, 3)?,
+            exec_amount: decimal(row, 4)?,
+            exec_price: decimal(row, 5)?,
+            order_type: opt_string(row, 6)?,
+            order_price: opt_decimal(row, 7)?,
+            maker: integer(row, 8)? == 1,
+            fee: opt_decimal(row, 9)?,
+            fee_currency: opt_string(row, 10)?,
+            cid: opt_integer(row, 11)?,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountTrade {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let row: Vec<Value> = Deserialize::deserialize(deserializer)?;
+        AccountTrade::try_from(row.as_slice()).map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,89 @@
+//! Bitfinex v2 answers with positional arrays instead of objects, these helpers read a
+//! single field of such an array and describe what went wrong when it doesn't match.
+
+use std::str::FromStr;
+use rust_decimal::prelude::Decimal;
+use serde_json::Value;
+
+pub(crate) fn value(row: &[Value], index: usize) -> Result<&Value, String> {
+    row.get(index)
+        .ok_or_else(|| format!("missing field at index {}", index))
+}
+
+pub(crate) fn to_decimal(value: &Value) -> Result<Decimal, String> {
+    let s = match value {
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.clone(),
+        other => return Err(format!("expected a number, found {}", other)),
+    };
+    Decimal::from_str(&s)
+        .or_else(|_| Decimal::from_scientific(&s))
+        .map_err(|err| format!("couldn't parse {} as decimal: {}", s, err))
+}
+
+pub(crate) fn decimal(row: &[Value], index: usize) -> Result<Decimal, String> {
+    to_decimal(value(row, index)?)
+}
+
+pub(crate) fn opt_decimal(row: &[Value], index: usize) -> Result<Option<Decimal>, String> {
+    match row.get(index) {
+        None | Some(Value::Null) => Ok(None),
+        Some(v) => to_decimal(v).map(Some),
+    }
+}
+
+pub(crate) fn integer(row: &[Value], index: usize) -> Result<i64, String> {
+    value(row, index)?
+        .as_i64()
+        .ok_or_else(|| format!("expected an integer at index {}", index))
+}
+
+pub(crate) fn opt_integer(row: &[Value], index: usize) -> Result<Option<i64>, String> {
+    match row.get(index) {
+        None | Some(Value::Null) => Ok(None),
+        Some(v) => v
+            .as_i64()
+            .map(Some)
+            .ok_or_else(|| format!("expected an integer at index {}", index)),
+    }
+}
+
+pub(crate) fn unsigned(row: &[Value], index: usize) -> Result<u64, String> {
+    value(row, index)?
+        .as_u64()
+        .ok_or_else(|| format!("expected an unsigned integer at index {}", index))
+}
+
+pub(crate) fn opt_unsigned(row: &[Value], index: usize) -> Result<Option<u64>, String> {
+    match row.get(index) {
+        None | Some(Value::Null) => Ok(None),
+        Some(v) => v
+            .as_u64()
+            .map(Some)
+            .ok_or_else(|| format!("expected an unsigned integer at index {}", index)),
+    }
+}
+
+pub(crate) fn string(row: &[Value], index: usize) -> Result<String, String> {
+    value(row, index)?
+        .as_str()
+        .map(String::from)
+        .ok_or_else(|| format!("expected a string at index {}", index))
+}
+
+pub(crate) fn opt_string(row: &[Value], index: usize) -> Result<Option<String>, String> {
+    match row.get(index) {
+        None | Some(Value::Null) => Ok(None),
+        Some(v) => v
+            .as_str()
+            .map(|s| Some(String::from(s)))
+            .ok_or_else(|| format!("expected a string at index {}", index)),
+    }
+}
+
+pub(crate) fn array(row: &[Value], index: usize) -> Result<&[Value], String> {
+    value(row, index)?
+        .as_array()
+        .map(|v| v.as_slice())
+        .ok_or_else(|| format!("expected an array at index {}", index))
+}
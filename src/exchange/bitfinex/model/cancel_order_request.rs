@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents the cancellation of an order
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CancelOrderRequest {
+    pub id: u64,
+}
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents the cancellation of several orders at once,
+/// either by id or all of them when `all` is set to 1
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct CancelOrdersRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Vec<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all: Option<u8>,
+}
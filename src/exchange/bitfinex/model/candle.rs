@@ -0,0 +1,44 @@
+use std::convert::TryFrom;
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde_json::Value;
+use rust_decimal::prelude::Decimal;
+use super::array_payload::{decimal, unsigned};
+
+/// This struct represents a candle `[MTS, OPEN, CLOSE, HIGH, LOW, VOLUME]`
+#[derive(Debug, Serialize, Clone)]
+pub struct Candle {
+    pub mts: u64,
+    pub open: Decimal,
+    pub close: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub volume: Decimal,
+}
+
+impl TryFrom<&[Value]> for Candle {
+    type Error = String;
+
+    fn try_from(row: &[Value]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            mts: unsigned(row, 0)?,
+            open: decimal(row, 1)?,
+            close: decimal(row, 2)?,
+            high: decimal(row, 3)?,
+            low: decimal(row, 4)?,
+            volume: decimal(row, 5)?,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Candle {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let row: Vec<Value> = Deserialize::deserialize(deserializer)?;
+        Candle::try_from(row.as_slice()).map_err(de::Error::custom)
+    }
+}
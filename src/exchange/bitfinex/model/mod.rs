@@ -0,0 +1,49 @@
+//! This module provides models that are used in the bitfinex module
+
+pub const ORDER_TYPE_EXCHANGE_LIMIT: &str = "EXCHANGE LIMIT";
+pub const ORDER_TYPE_EXCHANGE_MARKET: &str = "EXCHANGE MARKET";
+pub const ORDER_TYPE_EXCHANGE_IOC: &str = "EXCHANGE IOC";
+pub const ORDER_TYPE_EXCHANGE_FOK: &str = "EXCHANGE FOK";
+pub const ORDER_FLAG_POST_ONLY: u32 = 4096;
+pub const WALLET_TYPE_EXCHANGE: &str = "exchange";
+
+pub(crate) mod array_payload;
+mod account_trade;
+mod cancel_order_request;
+mod cancel_orders_request;
+mod candle;
+mod notification;
+mod order;
+mod order_filter;
+mod order_request;
+mod pair_info;
+mod paginator;
+mod position;
+mod raw_book;
+mod raw_book_entry;
+mod ticker;
+mod time_frame;
+mod trade;
+mod wallet;
+pub mod websocket;
+
+pub use account_trade::AccountTrade;
+pub use cancel_order_request::CancelOrderRequest;
+pub use cancel_orders_request::CancelOrdersRequest;
+pub use candle::Candle;
+pub use notification::Notification;
+pub use notification::NOTIFICATION_STATUS_SUCCESS;
+pub use order::Order;
+pub use order_filter::OrderFilter;
+pub use order_request::OrderRequest;
+pub use pair_info::PairInfo;
+pub use paginator::Paginator;
+pub use position::Position;
+pub use raw_book::RawBook;
+pub use raw_book::CHECKSUM_DEPTH;
+pub use raw_book_entry::RawBookEntry;
+pub use ticker::Ticker;
+pub use time_frame::TimeFrame;
+pub use trade::Trade;
+pub use wallet::Wallet;
+pub use super::shared;
@@ -0,0 +1,56 @@
+use std::convert::TryFrom;
+use serde::de;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde_json::Value;
+use super::array_payload::{opt_integer, opt_string, string, unsigned, value};
+
+pub const NOTIFICATION_STATUS_SUCCESS: &str = "SUCCESS";
+
+/// This struct represents a notification
+/// `[MTS, TYPE, MESSAGE_ID, _PLACEHOLDER, NOTIFY_INFO, CODE, STATUS, TEXT]`,
+/// write endpoints such as order submission answer with one of those.
+#[derive(Debug, Serialize, Clone)]
+pub struct Notification<T> {
+    pub mts: u64,
+    pub notification_type: String,
+    pub message_id: Option<i64>,
+    pub info: T,
+    pub code: Option<i64>,
+    pub status: String,
+    pub text: Option<String>,
+}
+
+impl<T> Notification<T> {
+    pub fn is_success(&self) -> bool {
+        self.status == NOTIFICATION_STATUS_SUCCESS
+    }
+}
+
+impl<T: DeserializeOwned> TryFrom<&[Value]> for Notification<T> {
+    type Error = String;
+
+    fn try_from(row: &[Value]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            mts: unsigned(row, 0)?,
+            notification_type: string(row, 1)?,
+            message_id: opt_integer(row, 2)?,
+            info: serde_json::from_value(value(row, 4)?.clone()).map_err(|err| err.to_string())?,
+            code: opt_integer(row, 5)?,
+            status: string(row, 6)?,
+            text: opt_string(row, 7)?,
+        })
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Notification<T> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let row: Vec<Value> = Deserialize::deserialize(deserializer)?;
+        Notification::try_from(row.as_slice()).map_err(de::Error::custom)
+    }
+}
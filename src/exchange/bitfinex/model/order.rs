@@ -0,0 +1,67 @@
+use std::convert::TryFrom;
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde_json::Value;
+use rust_decimal::prelude::Decimal;
+use super::array_payload::{
+    decimal, opt_decimal, opt_integer, opt_string, opt_unsigned, string, unsigned,
+};
+
+/// This struct represents an order
+/// `[ID, GID, CID, SYMBOL, MTS_CREATE, MTS_UPDATE, AMOUNT, AMOUNT_ORIG, TYPE, TYPE_PREV, MTS_TIF,
+/// _PLACEHOLDER, FLAGS, STATUS, _PLACEHOLDER, _PLACEHOLDER, PRICE, PRICE_AVG, ...]`.
+/// `amount` is what remains to be filled and is negative for sell orders.
+#[derive(Debug, Serialize, Clone)]
+pub struct Order {
+    pub id: u64,
+    pub gid: Option<u64>,
+    pub cid: Option<i64>,
+    pub symbol: String,
+    pub mts_create: u64,
+    pub mts_update: Option<u64>,
+    pub amount: Decimal,
+    pub amount_orig: Decimal,
+    pub order_type: String,
+    pub type_prev: Option<String>,
+    pub mts_tif: Option<u64>,
+    pub flags: Option<i64>,
+    pub status: String,
+    pub price: Option<Decimal>,
+    pub price_avg: Option<Decimal>,
+}
+
+impl TryFrom<&[Value]> for Order {
+    type Error = String;
+
+    fn try_from(row: &[Value]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: unsigned(row, 0)?,
+            gid: opt_unsigned(row, 1)?,
+            cid: opt_integer(row, 2)?,
+            symbol: string(row, 3)?,
+            mts_create: unsigned(row, 4)?,
+            mts_update: opt_unsigned(row, 5)?,
+            amount: decimal(row, 6)?,
+            amount_orig: decimal(row, 7)?,
+            order_type: string(row, 8)?,
+            type_prev: opt_string(row, 9)?,
+            mts_tif: opt_unsigned(row, 10)?,
+            flags: opt_integer(row, 12)?,
+            status: string(row, 13)?,
+            price: opt_decimal(row, 16)?,
+            price_avg: opt_decimal(row, 17)?,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Order {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let row: Vec<Value> = Deserialize::deserialize(deserializer)?;
+        Order::try_from(row.as_slice()).map_err(de::Error::custom)
+    }
+}
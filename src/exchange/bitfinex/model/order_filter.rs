@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::Paginator;
+
+/// This struct filters the orders returned by the active orders and order history endpoints
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct OrderFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Vec<u64>>,
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paginator: Option<Paginator>,
+}
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+use super::shared::string_to_opt_decimal;
+
+/// This struct represents an order submission, sell orders have a negative amount
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderRequest {
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub symbol: String,
+    #[serde(with = "string_to_decimal")]
+    pub amount: Decimal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub price: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<u32>,
+    /// Good till date, formatted as `YYYY-MM-DD HH:MM:SS` in UTC
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tif: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cid: Option<i64>,
+}
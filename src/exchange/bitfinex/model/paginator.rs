@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents a paginator, `sort` set to 1 returns the oldest entries first
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct Paginator {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<i8>,
+}
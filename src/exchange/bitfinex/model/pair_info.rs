@@ -0,0 +1,67 @@
+use std::convert::TryFrom;
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde_json::Value;
+use rust_decimal::prelude::Decimal;
+use super::array_payload::{array, opt_decimal, string};
+
+/// This struct represents the trading rules of a pair as returned by `conf/pub:info:pair`
+/// `[PAIR, [_PLACEHOLDER, _PLACEHOLDER, _PLACEHOLDER, MIN_ORDER_SIZE, MAX_ORDER_SIZE,
+/// _PLACEHOLDER, _PLACEHOLDER, _PLACEHOLDER, INITIAL_MARGIN, MIN_MARGIN]]`
+#[derive(Debug, Serialize, Clone)]
+pub struct PairInfo {
+    pub pair: String,
+    pub min_order_size: Option<Decimal>,
+    pub max_order_size: Option<Decimal>,
+    pub initial_margin: Option<Decimal>,
+    pub min_margin: Option<Decimal>,
+}
+
+impl PairInfo {
+    /// Trading symbols are the pair prefixed with `t`, e.g. `tBTCUSD`.
+    pub fn symbol(&self) -> String {
+        format!("t{}", self.pair)
+    }
+
+    /// Pairs made of currencies longer than three letters are separated by a colon,
+    /// e.g. `TESTBTC:TESTUSD`, the others are the concatenation of two three letter currencies.
+    pub fn base_and_quote(&self) -> (String, String) {
+        match self.pair.find(':') {
+            Some(index) => (
+                self.pair[..index].to_string(),
+                self.pair[index + 1..].to_string(),
+            ),
+            None if self.pair.len() == 6 => {
+                (self.pair[..3].to_string(), self.pair[3..].to_string())
+            }
+            None => (self.pair.clone(), String::new()),
+        }
+    }
+}
+
+impl TryFrom<&[Value]> for PairInfo {
+    type Error = String;
+
+    fn try_from(row: &[Value]) -> Result<Self, Self::Error> {
+        let details = array(row, 1)?;
+        Ok(Self {
+            pair: string(row, 0)?,
+            min_order_size: opt_decimal(details, 3)?,
+            max_order_size: opt_decimal(details, 4)?,
+            initial_margin: opt_decimal(details, 8)?,
+            min_margin: opt_decimal(details, 9)?,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for PairInfo {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let row: Vec<Value> = Deserialize::deserialize(deserializer)?;
+        PairInfo::try_from(row.as_slice()).map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,59 @@
+use std::convert::TryFrom;
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde_json::Value;
+use rust_decimal::prelude::Decimal;
+use super::array_payload::{decimal, opt_decimal, opt_unsigned, string};
+
+/// This struct represents a margin position
+/// `[SYMBOL, STATUS, AMOUNT, BASE_PRICE, MARGIN_FUNDING, MARGIN_FUNDING_TYPE, PL, PL_PERC,
+/// PRICE_LIQ, LEVERAGE, _PLACEHOLDER, POSITION_ID, MTS_CREATE, MTS_UPDATE, ...]`.
+/// Profit and loss fields are only sent once bitfinex has computed them.
+#[derive(Debug, Serialize, Clone)]
+pub struct Position {
+    pub symbol: String,
+    pub status: String,
+    pub amount: Decimal,
+    pub base_price: Decimal,
+    pub margin_funding: Option<Decimal>,
+    pub pl: Option<Decimal>,
+    pub pl_perc: Option<Decimal>,
+    pub price_liq: Option<Decimal>,
+    pub leverage: Option<Decimal>,
+    pub position_id: Option<u64>,
+    pub mts_create: Option<u64>,
+    pub mts_update: Option<u64>,
+}
+
+impl TryFrom<&[Value]> for Position {
+    type Error = String;
+
+    fn try_from(row: &[Value]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            symbol: string(row, 0)?,
+            status: string(row, 1)?,
+            amount: decimal(row, 2)?,
+            base_price: decimal(row, 3)?,
+            margin_funding: opt_decimal(row, 4)?,
+            pl: opt_decimal(row, 6)?,
+            pl_perc: opt_decimal(row, 7)?,
+            price_liq: opt_decimal(row, 8)?,
+            leverage: opt_decimal(row, 9)?,
+            position_id: opt_unsigned(row, 11)?,
+            mts_create: opt_unsigned(row, 12)?,
+            mts_update: opt_unsigned(row, 13)?,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Position {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let row: Vec<Value> = Deserialize::deserialize(deserializer)?;
+        Position::try_from(row.as_slice()).map_err(de::Error::custom)
+    }
+}
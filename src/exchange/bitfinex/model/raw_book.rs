@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+use rust_decimal::prelude::{Decimal, Zero};
+use crate::model::{AskBid, OrderBookResponse};
+use super::RawBookEntry;
+
+/// Number of levels per side bitfinex uses to compute the book checksum.
+pub const CHECKSUM_DEPTH: usize = 25;
+
+/// This struct keeps a local copy of a raw (`R0`) order book.
+/// It applies snapshots and updates received from the `book` channel and computes the same
+/// CRC32 checksum bitfinex publishes, so the local book can be validated against the venue.
+#[derive(Debug, Clone, Default)]
+pub struct RawBook {
+    bids: HashMap<u64, RawBookEntry>,
+    asks: HashMap<u64, RawBookEntry>,
+}
+
+impl RawBook {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn from_entries(entries: Vec<RawBookEntry>) -> Self {
+        let mut book = Self::new();
+        for entry in entries {
+            book.apply(entry);
+        }
+        book
+    }
+
+    /// Applies an update and returns the aggregated levels it changed, a level with a zero
+    /// quantity has been emptied.
+    pub fn apply(&mut self, entry: RawBookEntry) -> OrderBookResponse {
+        let mut bids = Vec::new();
+        let mut asks = Vec::new();
+
+        let previous = self
+            .bids
+            .remove(&entry.order_id)
+            .or_else(|| self.asks.remove(&entry.order_id));
+
+        if !entry.price.is_zero() {
+            let price = entry.price;
+            if entry.amount > Decimal::zero() {
+                self.bids.insert(entry.order_id, entry);
+                bids.push(self.bid_level(price));
+            } else {
+                self.asks.insert(entry.order_id, entry);
+                asks.push(self.ask_level(price));
+            }
+        }
+
+        if let Some(previous) = previous {
+            let touched = bids.iter().chain(asks.iter()).any(|l| l.price == previous.price);
+            if !touched {
+                if previous.amount > Decimal::zero() {
+                    bids.push(self.bid_level(previous.price));
+                } else {
+                    asks.push(self.ask_level(previous.price));
+                }
+            }
+        }
+
+        OrderBookResponse {
+            market_pair: None,
+            instrument: None,
+            update_id: None,
+            last_update_id: None,
+            bids,
+            asks,
+        }
+    }
+
+    /// Bids sorted by descending price, orders at the same price are kept in ascending id order.
+    pub fn sorted_bids(&self) -> Vec<&RawBookEntry> {
+        let mut bids: Vec<&RawBookEntry> = self.bids.values().collect();
+        bids.sort_by(|a, b| b.price.cmp(&a.price).then(a.order_id.cmp(&b.order_id)));
+        bids
+    }
+
+    /// Asks sorted by ascending price, orders at the same price are kept in ascending id order.
+    pub fn sorted_asks(&self) -> Vec<&RawBookEntry> {
+        let mut asks: Vec<&RawBookEntry> = self.asks.values().collect();
+        asks.sort_by(|a, b| a.price.cmp(&b.price).then(a.order_id.cmp(&b.order_id)));
+        asks
+    }
+
+    /// The checksum is the signed CRC32 of the top 25 bids and asks, interleaved as
+    /// `bid_id:bid_amount:ask_id:ask_amount:...`.
+    pub fn checksum(&self) -> i32 {
+        let bids = self.sorted_bids();
+        let asks = self.sorted_asks();
+        let mut parts = Vec::with_capacity(CHECKSUM_DEPTH * 4);
+
+        for i in 0..CHECKSUM_DEPTH {
+            if let Some(bid) = bids.get(i) {
+                parts.push(bid.order_id.to_string());
+                parts.push(bid.amount.normalize().to_string());
+            }
+            if let Some(ask) = asks.get(i) {
+                parts.push(ask.order_id.to_string());
+                parts.push(ask.amount.normalize().to_string());
+            }
+        }
+
+        crc32fast::hash(parts.join(":").as_bytes()) as i32
+    }
+
+    pub fn is_valid(&self, checksum: i32) -> bool {
+        self.checksum() == checksum
+    }
+
+    /// Aggregates the raw orders by price.
+    pub fn to_order_book(&self) -> OrderBookResponse {
+        OrderBookResponse {
+            market_pair: None,
+            instrument: None,
+            update_id: None,
+            last_update_id: None,
+            bids: aggregate(self.sorted_bids()),
+            asks: aggregate(self.sorted_asks()),
+        }
+    }
+
+    fn bid_level(&self, price: Decimal) -> AskBid {
+        level(self.bids.values(), price)
+    }
+
+    fn ask_level(&self, price: Decimal) -> AskBid {
+        level(self.asks.values(), price)
+    }
+}
+
+fn level<'a>(entries: impl Iterator<Item = &'a RawBookEntry>, price: Decimal) -> AskBid {
+    let qty = entries
+        .filter(|e| e.price == price)
+        .map(|e| e.amount.abs())
+        .fold(Decimal::zero(), |acc, amount| acc + amount);
+    AskBid { price, qty }
+}
+
+fn aggregate(sorted: Vec<&RawBookEntry>) -> Vec<AskBid> {
+    let mut levels: Vec<AskBid> = Vec::new();
+    for entry in sorted {
+        match levels.last_mut() {
+            Some(last) if last.price == entry.price => last.qty += entry.amount.abs(),
+            _ => levels.push(AskBid {
+                price: entry.price,
+                qty: entry.amount.abs(),
+            }),
+        }
+    }
+    levels
+}
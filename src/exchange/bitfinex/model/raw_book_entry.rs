@@ -0,0 +1,40 @@
+use std::convert::TryFrom;
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde_json::Value;
+use rust_decimal::prelude::Decimal;
+use super::array_payload::{decimal, unsigned};
+
+/// This struct represents a single order of a raw (`R0`) order book
+/// `[ORDER_ID, PRICE, AMOUNT]`, a positive amount is a bid and a negative amount is an ask.
+/// A price of zero means the order was removed from the book.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct RawBookEntry {
+    pub order_id: u64,
+    pub price: Decimal,
+    pub amount: Decimal,
+}
+
+impl TryFrom<&[Value]> for RawBookEntry {
+    type Error = String;
+
+    fn try_from(row: &[Value]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            order_id: unsigned(row, 0)?,
+            price: decimal(row, 1)?,
+            amount: decimal(row, 2)?,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for RawBookEntry {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let row: Vec<Value> = Deserialize::deserialize(deserializer)?;
+        RawBookEntry::try_from(row.as_slice()).map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,53 @@
+use std::convert::TryFrom;
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde_json::Value;
+use rust_decimal::prelude::Decimal;
+use super::array_payload::decimal;
+
+/// This struct represents a trading pair ticker
+/// `[BID, BID_SIZE, ASK, ASK_SIZE, DAILY_CHANGE, DAILY_CHANGE_RELATIVE, LAST_PRICE, VOLUME, HIGH, LOW]`
+#[derive(Debug, Serialize, Clone)]
+pub struct Ticker {
+    pub bid: Decimal,
+    pub bid_size: Decimal,
+    pub ask: Decimal,
+    pub ask_size: Decimal,
+    pub daily_change: Decimal,
+    pub daily_change_relative: Decimal,
+    pub last_price: Decimal,
+    pub volume: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+}
+
+impl TryFrom<&[Value]> for Ticker {
+    type Error = String;
+
+    fn try_from(row: &[Value]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            bid: decimal(row, 0)?,
+            bid_size: decimal(row, 1)?,
+            ask: decimal(row, 2)?,
+            ask_size: decimal(row, 3)?,
+            daily_change: decimal(row, 4)?,
+            daily_change_relative: decimal(row, 5)?,
+            last_price: decimal(row, 6)?,
+            volume: decimal(row, 7)?,
+            high: decimal(row, 8)?,
+            low: decimal(row, 9)?,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Ticker {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let row: Vec<Value> = Deserialize::deserialize(deserializer)?;
+        Ticker::try_from(row.as_slice()).map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,52 @@
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt;
+
+/// This enum represents the candle time frames supported by bitfinex
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum TimeFrame {
+    #[serde(rename = "1m")]
+    OneMinute,
+    #[serde(rename = "5m")]
+    FiveMinutes,
+    #[serde(rename = "15m")]
+    FifteenMinutes,
+    #[serde(rename = "30m")]
+    ThirtyMinutes,
+    #[serde(rename = "1h")]
+    OneHour,
+    #[serde(rename = "3h")]
+    ThreeHours,
+    #[serde(rename = "6h")]
+    SixHours,
+    #[serde(rename = "12h")]
+    TwelveHours,
+    #[serde(rename = "1D")]
+    OneDay,
+    #[serde(rename = "1W")]
+    OneWeek,
+    #[serde(rename = "14D")]
+    FourteenDays,
+    #[serde(rename = "1M")]
+    OneMonth,
+}
+
+impl fmt::Display for TimeFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            TimeFrame::OneMinute => "1m",
+            TimeFrame::FiveMinutes => "5m",
+            TimeFrame::FifteenMinutes => "15m",
+            TimeFrame::ThirtyMinutes => "30m",
+            TimeFrame::OneHour => "1h",
+            TimeFrame::ThreeHours => "3h",
+            TimeFrame::SixHours => "6h",
+            TimeFrame::TwelveHours => "12h",
+            TimeFrame::OneDay => "1D",
+            TimeFrame::OneWeek => "1W",
+            TimeFrame::FourteenDays => "14D",
+            TimeFrame::OneMonth => "1M",
+        };
+        write!(f, "{}", s)
+    }
+}
@@ -0,0 +1,63 @@
+use std::convert::TryFrom;
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde_json::Value;
+use rust_decimal::prelude::Decimal;
+use super::array_payload::{decimal, unsigned};
+
+/// This struct represents a public trade `[ID, MTS, AMOUNT, PRICE]`,
+/// a positive amount means the taker bought.
+#[derive(Debug, Serialize, Clone)]
+pub struct Trade {
+    pub id: u64,
+    pub mts: u64,
+    pub amount: Decimal,
+    pub price: Decimal,
+}
+
+impl TryFrom<&[Value]> for Trade {
+    type Error = String;
+
+    fn try_from(row: &[Value]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: unsigned(row, 0)?,
+            mts: unsigned(row, 1)?,
+            amount: decimal(row, 2)?,
+            price: decimal(row, 3)?,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Trade {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let row: Vec<Value> = Deserialize::deserialize(deserializer)?;
+        Trade::try_from(row.as_slice()).map_err(de::Error::custom)
+    }
+}
+
+impl Trade {
+    /// Public trades don't carry their symbol, it comes from the request or the channel.
+    pub fn into_trade(self, symbol: &str) -> crate::model::Trade {
+        crate::model::Trade {
+            id: self.id.to_string(),
+            buyer_order_id: None,
+            seller_order_id: None,
+            market_pair: symbol.to_string(),
+            instrument: None,
+            price: self.price,
+            qty: self.amount.abs(),
+            fees: None,
+            side: match self.amount.is_sign_negative() {
+                true => crate::model::Side::Sell,
+                false => crate::model::Side::Buy,
+            },
+            liquidity: None,
+            created_at: self.mts,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use std::convert::TryFrom;
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde_json::Value;
+use rust_decimal::prelude::Decimal;
+use super::array_payload::{decimal, opt_decimal, string};
+
+/// This struct represents a wallet
+/// `[WALLET_TYPE, CURRENCY, BALANCE, UNSETTLED_INTEREST, AVAILABLE_BALANCE, ...]`.
+/// Bitfinex sends a null available balance when it hasn't been computed yet.
+#[derive(Debug, Serialize, Clone)]
+pub struct Wallet {
+    pub wallet_type: String,
+    pub currency: String,
+    pub balance: Decimal,
+    pub unsettled_interest: Decimal,
+    pub available_balance: Option<Decimal>,
+}
+
+impl TryFrom<&[Value]> for Wallet {
+    type Error = String;
+
+    fn try_from(row: &[Value]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            wallet_type: string(row, 0)?,
+            currency: string(row, 1)?,
+            balance: decimal(row, 2)?,
+            unsettled_interest: decimal(row, 3)?,
+            available_balance: opt_decimal(row, 4)?,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Wallet {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let row: Vec<Value> = Deserialize::deserialize(deserializer)?;
+        Wallet::try_from(row.as_slice()).map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct authenticates the connection and opens the account channel (channel 0)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthRequest {
+    pub event: String,
+    pub api_key: String,
+    pub auth_sig: String,
+    pub auth_nonce: String,
+    pub auth_payload: String,
+}
@@ -0,0 +1,29 @@
+use crate::model::websocket::Subscription;
+
+/// This enum represents a bitfinex subscription
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BitfinexSubscription {
+    /// Symbol, the raw order book validated with the published checksums
+    Book(String),
+    /// Symbol
+    Trades(String),
+    /// Symbol
+    Ticker(String),
+    /// Candle key, e.g. `trade:1m:tBTCUSD`
+    Candles(String),
+    /// Orders, wallets, positions and trades of the authenticated account
+    Account,
+}
+
+impl From<Subscription> for BitfinexSubscription {
+    fn from(subscription: Subscription) -> Self {
+        match subscription {
+            Subscription::OrderBookUpdates(symbol) => BitfinexSubscription::Book(symbol),
+            Subscription::Trades(symbol) => BitfinexSubscription::Trades(symbol),
+            Subscription::Ticker(symbol) => BitfinexSubscription::Ticker(symbol),
+            Subscription::AccountOrders(_)
+            | Subscription::AccountTrades(_)
+            | Subscription::AccountBalance(_) => BitfinexSubscription::Account,
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use serde::Serialize;
+use serde_json::Value;
+use crate::model::OrderBookResponse;
+use super::AccountTrade;
+use super::Candle;
+use super::Event;
+use super::Notification;
+use super::Order;
+use super::Position;
+use super::RawBookEntry;
+use super::Ticker;
+use super::Trade;
+use super::Wallet;
+
+/// This enum represents the types of websocket messages.
+/// Order book messages carry the aggregated levels computed from the local raw book,
+/// a checksum is only forwarded once the local book matched it.
+#[derive(Debug, Clone, Serialize)]
+pub enum BitfinexWebsocketMessage {
+    Event(Event),
+    Heartbeat,
+    BookSnapshot {
+        symbol: String,
+        book: OrderBookResponse,
+    },
+    BookUpdate {
+        symbol: String,
+        entry: RawBookEntry,
+        levels: OrderBookResponse,
+    },
+    Checksum {
+        symbol: String,
+        checksum: i32,
+    },
+    TradesSnapshot {
+        symbol: String,
+        trades: Vec<Trade>,
+    },
+    TradeExecuted {
+        symbol: String,
+        trade: Trade,
+    },
+    TradeUpdated {
+        symbol: String,
+        trade: Trade,
+    },
+    Ticker {
+        symbol: String,
+        ticker: Ticker,
+    },
+    CandlesSnapshot {
+        key: String,
+        candles: Vec<Candle>,
+    },
+    CandleUpdate {
+        key: String,
+        candle: Candle,
+    },
+    OrdersSnapshot(Vec<Order>),
+    OrderNew(Order),
+    OrderUpdate(Order),
+    OrderCancel(Order),
+    WalletsSnapshot(Vec<Wallet>),
+    WalletUpdate(Wallet),
+    PositionsSnapshot(Vec<Position>),
+    PositionNew(Position),
+    PositionUpdate(Position),
+    PositionClose(Position),
+    AccountTradeExecuted(AccountTrade),
+    AccountTradeUpdate(AccountTrade),
+    Notification(Notification<Value>),
+    Unknown(Value),
+    Ping,
+    Pong,
+    Close,
+    Binary(Vec<u8>), // Unexpected, unparsed
+}
@@ -0,0 +1,21 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Asks bitfinex to publish a checksum after every order book update
+pub const CONF_FLAG_CHECKSUM: u64 = 131_072;
+
+/// This struct configures the connection, it must be sent before subscribing
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfRequest {
+    pub event: String,
+    pub flags: u64,
+}
+
+impl ConfRequest {
+    pub fn new(flags: u64) -> Self {
+        Self {
+            event: String::from("conf"),
+            flags,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This enum represents the events bitfinex sends as json objects,
+/// everything else on the connection is a channel message sent as an array
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum Event {
+    Info {
+        #[serde(default)]
+        version: Option<u64>,
+        #[serde(default)]
+        code: Option<i64>,
+        #[serde(default)]
+        msg: Option<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Subscribed {
+        channel: String,
+        chan_id: u64,
+        #[serde(default)]
+        symbol: Option<String>,
+        #[serde(default)]
+        key: Option<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Unsubscribed { status: String, chan_id: u64 },
+    #[serde(rename_all = "camelCase")]
+    Auth {
+        status: String,
+        #[serde(default)]
+        user_id: Option<u64>,
+        #[serde(default)]
+        code: Option<i64>,
+        #[serde(default)]
+        msg: Option<String>,
+    },
+    Conf {
+        #[serde(default)]
+        status: Option<String>,
+    },
+    Error { code: i64, msg: String },
+}
@@ -0,0 +1,27 @@
+//! Websocket model
+
+use super::AccountTrade;
+use super::Candle;
+use super::Notification;
+use super::Order;
+use super::Position;
+use super::RawBookEntry;
+use super::Ticker;
+use super::Trade;
+use super::Wallet;
+
+mod auth_request;
+mod bitfinex_subscription;
+mod bitfinex_websocket_message;
+mod conf_request;
+mod event;
+mod subscribe_request;
+
+pub use auth_request::AuthRequest;
+pub use bitfinex_subscription::BitfinexSubscription;
+pub use bitfinex_websocket_message::BitfinexWebsocketMessage;
+pub use conf_request::ConfRequest;
+pub use conf_request::CONF_FLAG_CHECKSUM;
+pub use event::Event;
+pub use subscribe_request::SubscribeRequest;
+pub use super::shared;
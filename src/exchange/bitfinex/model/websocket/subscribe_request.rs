@@ -0,0 +1,61 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::BitfinexSubscription;
+
+/// Precision of the order book channel, `R0` streams every single order
+pub const BOOK_PRECISION_RAW: &str = "R0";
+/// Number of orders per side, bitfinex accepts 1, 25, 100 or 250
+pub const BOOK_LENGTH: &str = "25";
+
+/// This struct represents a subscription to a public channel
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SubscribeRequest {
+    pub event: String,
+    pub channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prec: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub len: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+}
+
+impl SubscribeRequest {
+    fn channel(channel: &str) -> Self {
+        Self {
+            event: String::from("subscribe"),
+            channel: String::from(channel),
+            symbol: None,
+            prec: None,
+            len: None,
+            key: None,
+        }
+    }
+
+    /// The account channel isn't subscribed to, it is opened by the authentication.
+    pub fn from_subscription(subscription: &BitfinexSubscription) -> Option<Self> {
+        match subscription {
+            BitfinexSubscription::Book(symbol) => Some(Self {
+                symbol: Some(symbol.clone()),
+                prec: Some(String::from(BOOK_PRECISION_RAW)),
+                len: Some(String::from(BOOK_LENGTH)),
+                ..Self::channel("book")
+            }),
+            BitfinexSubscription::Trades(symbol) => Some(Self {
+                symbol: Some(symbol.clone()),
+                ..Self::channel("trades")
+            }),
+            BitfinexSubscription::Ticker(symbol) => Some(Self {
+                symbol: Some(symbol.clone()),
+                ..Self::channel("ticker")
+            }),
+            BitfinexSubscription::Candles(key) => Some(Self {
+                key: Some(key.clone()),
+                ..Self::channel("candles")
+            }),
+            BitfinexSubscription::Account => None,
+        }
+    }
+}
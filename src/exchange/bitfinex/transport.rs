@@ -0,0 +1,189 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use chrono::Utc;
+use hex::encode as hexify;
+use hmac::{Hmac, Mac, NewMac};
+use reqwest::header;
+use reqwest::Response;
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha2::Sha384;
+use url::Url;
+use crate::exchange::bitfinex::BitfinexContentError;
+use crate::errors::OpenLimitsError;
+use super::shared::Result;
+
+type HmacSha384 = Hmac<Sha384>;
+
+const PUBLIC_URL: &str = "https://api-pub.bitfinex.com";
+const AUTHENTICATED_URL: &str = "https://api.bitfinex.com";
+
+#[derive(Clone, Debug)]
+pub struct Transport {
+    credential: Option<(String, String)>,
+    client: reqwest::Client,
+    last_nonce: Arc<AtomicU64>,
+    public_url: String,
+    authenticated_url: String,
+}
+
+impl Transport {
+    pub fn new() -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .default_headers(Transport::default_headers())
+            .build()?;
+
+        Ok(Transport {
+            credential: None,
+            client,
+            last_nonce: Arc::new(AtomicU64::new(0)),
+            public_url: String::from(PUBLIC_URL),
+            authenticated_url: String::from(AUTHENTICATED_URL),
+        })
+    }
+
+    pub fn with_credential(api_key: &str, api_secret: &str) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .default_headers(Transport::default_headers())
+            .build()?;
+
+        Ok(Transport {
+            credential: Some((api_key.into(), api_secret.into())),
+            client,
+            last_nonce: Arc::new(AtomicU64::new(0)),
+            public_url: String::from(PUBLIC_URL),
+            authenticated_url: String::from(AUTHENTICATED_URL),
+        })
+    }
+
+    pub fn default_headers() -> header::HeaderMap<header::HeaderValue> {
+        let mut headers = header::HeaderMap::new();
+        headers.insert(
+            header::USER_AGENT,
+            header::HeaderValue::from_static("open_limit"),
+        );
+        headers.insert(
+            header::CONTENT_TYPE,
+            header::HeaderValue::from_static("application/json"),
+        );
+
+        headers
+    }
+
+    pub async fn get<O, S>(&self, endpoint: &str, params: Option<&S>) -> Result<O>
+    where
+        O: DeserializeOwned,
+        S: Serialize,
+    {
+        let url = self.get_url(&self.public_url, endpoint, params)?;
+        let response = self.client.get(url).send().await?;
+
+        self.response_handler(response).await
+    }
+
+    pub async fn signed_post<O, D>(&self, endpoint: &str, data: Option<&D>) -> Result<O>
+    where
+        O: DeserializeOwned,
+        D: Serialize,
+    {
+        let url = self.get_url::<()>(&self.authenticated_url, endpoint, None)?;
+        let body = match data {
+            Some(data) => serde_json::to_string(data)?,
+            None => String::from("{}"),
+        };
+        let nonce = self.nonce().to_string();
+        let (key, signature) = self.signature(endpoint, &nonce, &body)?;
+
+        let response = self
+            .client
+            .post(url)
+            .header("bfx-nonce", nonce.as_str())
+            .header("bfx-apikey", key)
+            .header("bfx-signature", signature)
+            .body(body)
+            .send()
+            .await?;
+
+        self.response_handler(response).await
+    }
+
+    pub fn get_url<Q>(&self, base_url: &str, endpoint: &str, params: Option<&Q>) -> Result<Url>
+    where
+        Q: Serialize,
+    {
+        let url = format!("{}{}", base_url, endpoint);
+
+        let mut url = Url::parse(&url)?;
+
+        if params.is_some() {
+            let query = serde_urlencoded::to_string(params)?;
+            url.set_query(Some(&query));
+        };
+
+        Ok(url)
+    }
+
+    /// Bitfinex requires every authenticated call to carry a strictly increasing nonce,
+    /// concurrent requests issued in the same microsecond are bumped by one.
+    pub fn nonce(&self) -> u64 {
+        let now = (Utc::now().timestamp_nanos() / 1000) as u64;
+        let mut last = self.last_nonce.load(Ordering::SeqCst);
+        loop {
+            let next = if now > last { now } else { last + 1 };
+            match self.last_nonce.compare_exchange(last, next, Ordering::SeqCst, Ordering::SeqCst) {
+                Ok(_) => return next,
+                Err(current) => last = current,
+            }
+        }
+    }
+
+    fn check_key(&self) -> Result<(&str, &str)> {
+        match self.credential.as_ref() {
+            None => Err(OpenLimitsError::NoApiKeySet()),
+            Some((k, s)) => Ok((k, s)),
+        }
+    }
+
+    pub fn signature(&self, endpoint: &str, nonce: &str, body: &str) -> Result<(&str, String)> {
+        let (key, secret) = self.check_key()?;
+        let sign_message = format!("/api{}{}{}", endpoint, nonce, body);
+        Ok((key, sign(secret, &sign_message)))
+    }
+
+    async fn response_handler<O>(&self, response: Response) -> Result<O>
+    where
+        O: DeserializeOwned,
+    {
+        let status = response.status();
+        let text = response.text().await?;
+
+        if status == StatusCode::OK {
+            return serde_json::from_str::<O>(&text).map_err(|err| {
+                OpenLimitsError::NotParsableResponse(format!("Error:{} Payload: {}", err, text))
+            });
+        }
+
+        if let Ok(error) = serde_json::from_str::<BitfinexContentError>(&text) {
+            return Err(OpenLimitsError::BitfinexError(error));
+        }
+
+        match status {
+            StatusCode::INTERNAL_SERVER_ERROR => Err(OpenLimitsError::InternalServerError()),
+            StatusCode::SERVICE_UNAVAILABLE => Err(OpenLimitsError::ServiceUnavailable()),
+            StatusCode::UNAUTHORIZED => Err(OpenLimitsError::Unauthorized()),
+            s => Err(OpenLimitsError::UnkownResponse(format!(
+                "Received response: {:?}, value: {}",
+                s, text
+            ))),
+        }
+    }
+}
+
+/// Hex encoded HMAC-SHA384, used by both the REST and the websocket authentication.
+pub fn sign(secret: &str, message: &str) -> String {
+    let mut mac =
+        HmacSha384::new_varkey(secret.as_bytes()).expect("Couldn't construct hmac from bytes.");
+    mac.update(message.as_bytes());
+    hexify(mac.finalize().into_bytes())
+}
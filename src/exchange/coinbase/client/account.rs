@@ -3,7 +3,7 @@ use crate::exchange::{
     coinbase::model::{
         Account, CancelAllOrders, CancelOrder, Fill, GetFillsReq, GetOrderRequest, Order,
         OrderRequest, OrderRequestMarketType, OrderRequestType, OrderSide, OrderTimeInForce,
-        Paginator,
+        Paged, Paginator,
     },
 };
 use crate::exchange::traits::info::MarketPair;
@@ -19,6 +19,10 @@ impl BaseClient {
         self.transport.signed_get::<_, _>("/orders", params).await
     }
 
+    pub async fn get_orders_paged(&self, params: Option<&GetOrderRequest>) -> Result<Paged<Vec<Order>>> {
+        self.transport.signed_get_paged("/orders", params).await
+    }
+
     pub async fn get_order(&self, order_id: String) -> Result<Order> {
         self.transport
             .signed_get::<_, ()>(&format!("/orders/{}", order_id), None)
@@ -172,4 +176,8 @@ impl BaseClient {
 
         Ok(resp)
     }
+
+    pub async fn get_fills_paged(&self, params: Option<&GetFillsReq>) -> Result<Paged<Vec<Fill>>> {
+        self.transport.signed_get_paged("/fills", params).await
+    }
 }
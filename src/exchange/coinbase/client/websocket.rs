@@ -1,16 +1,18 @@
 use async_trait::async_trait;
-use std::{collections::HashMap, pin::Pin, task::Poll};
+use std::{collections::HashMap, pin::Pin, task::Poll, time::SystemTime};
 use futures::{
     stream::{SplitStream, Stream},
     SinkExt, StreamExt,
 };
 use serde::{Deserialize, Serialize};
+use reqwest::Method;
 use tokio::net::TcpStream;
 use tokio_tungstenite::tungstenite::Message;
 use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
 use crate::{
     exchange::coinbase::model::websocket::{
-        Channel, CoinbaseSubscription, CoinbaseWebsocketMessage, Subscribe, SubscribeCmd,
+        Auth, Channel, CoinbaseSubscription, CoinbaseWebsocketMessage, OrderTracker, Subscribe,
+        SubscribeCmd,
     },
     errors::OpenLimitsError,
 };
@@ -21,9 +23,11 @@ use futures::stream::BoxStream;
 use std::sync::Mutex;
 use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
 use super::shared::Result;
+use super::Transport;
 
 const WS_URL_PROD: &str = "wss://ws-feed.pro.coinbase.com";
 const WS_URL_SANDBOX: &str = "wss://ws-feed-public.sandbox.pro.coinbase.com";
+const VERIFY_URL: &str = "https://api.pro.coinbase.com/users/self/verify";
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
@@ -51,27 +55,73 @@ impl CoinbaseWebsocket {
     }
 
     pub async fn subscribe_(&mut self, subscription: CoinbaseSubscription) -> Result<()> {
-        let (channels, product_ids) = match &subscription {
-            CoinbaseSubscription::Level2(product_id) => (
-                vec![Channel::Name(ChannelType::Level2)],
-                vec![product_id.clone()],
-            ),
-            CoinbaseSubscription::Heartbeat(product_id) => (
-                vec![Channel::Name(ChannelType::Heartbeat)],
-                vec![product_id.clone()],
-            ),
-            _ => unimplemented!(),
+        let subscribe = self.subscribe_message(std::slice::from_ref(&subscription))?;
+        let stream = self.connect(subscribe).await?;
+        self.subscriptions.insert(subscription, stream);
+        Ok(())
+    }
+
+    /// The subscribe message of the channels and products of `subscriptions`, it's
+    /// authenticated when subscribing to the user channel
+    pub fn subscribe_message(&self, subscriptions: &[CoinbaseSubscription]) -> Result<Subscribe> {
+        let mut channels = Vec::new();
+        let mut product_ids = Vec::new();
+        for subscription in subscriptions {
+            let (channel, product_id) = match subscription {
+                CoinbaseSubscription::Level2(product_id) => (ChannelType::Level2, product_id),
+                CoinbaseSubscription::Heartbeat(product_id) => {
+                    (ChannelType::Heartbeat, product_id)
+                }
+                CoinbaseSubscription::User(product_id) => (ChannelType::User, product_id),
+                _ => return Err(OpenLimitsError::WebSocketMessageNotSupported()),
+            };
+            let channel = Channel::Name(channel);
+            if !channels.contains(&channel) {
+                channels.push(channel);
+            }
+            if !product_ids.contains(product_id) {
+                product_ids.push(product_id.clone());
+            }
+        }
+        let auth = if channels.contains(&Channel::Name(ChannelType::User)) {
+            Some(self.auth()?)
+        } else {
+            None
         };
-        let subscribe = Subscribe {
+        Ok(Subscribe {
             _type: SubscribeCmd::Subscribe,
-            auth: None,
+            auth,
             channels,
             product_ids,
-        };
+        })
+    }
 
-        let stream = self.connect(subscribe).await?;
-        self.subscriptions.insert(subscription, stream);
-        Ok(())
+    /// Signs the subscription like a request verifying the user
+    fn auth(&self) -> Result<Auth> {
+        let credentials = self
+            .parameters
+            .credentials
+            .as_ref()
+            .ok_or(OpenLimitsError::NoApiKeySet())?;
+        let transport = Transport::with_credential(
+            &credentials.api_key,
+            &credentials.api_secret,
+            &credentials.passphrase,
+            self.parameters.sandbox,
+            &self.parameters.http,
+        )?;
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("Invalid SystemTime.")
+            .as_secs();
+        let url = url::Url::parse(VERIFY_URL).expect("Couldn't parse url.");
+        let signature = transport.signature(&url, timestamp, &Method::GET, None::<&()>)?;
+        Ok(Auth {
+            signature,
+            key: credentials.api_key.clone(),
+            passphrase: credentials.passphrase.clone(),
+            timestamp: timestamp.to_string(),
+        })
     }
 
     pub async fn connect(&self, subscribe: Subscribe) -> Result<SplitStream<WSStream>> {
@@ -144,26 +194,10 @@ impl ExchangeWs for CoinbaseWebsocket {
         } else {
             WS_URL_PROD
         };
+        let subscribe = self.subscribe_message(subscription.as_slice())?;
         let endpoint = url::Url::parse(ws_url).expect("Couldn't parse url.");
         let (ws_stream, _) = connect_async(endpoint).await?;
 
-        let (channels, product_ids) = match &subscription.as_slice()[0] {
-            CoinbaseSubscription::Level2(product_id) => (
-                vec![Channel::Name(ChannelType::Level2)],
-                vec![product_id.clone()],
-            ),
-            CoinbaseSubscription::Heartbeat(product_id) => (
-                vec![Channel::Name(ChannelType::Heartbeat)],
-                vec![product_id.clone()],
-            ),
-            _ => unimplemented!(),
-        };
-        let subscribe = Subscribe {
-            _type: SubscribeCmd::Subscribe,
-            auth: None,
-            channels,
-            product_ids,
-        };
         let subscribe = serde_json::to_string(&subscribe)?;
         let (mut sink, stream) = ws_stream.split();
         let (disconnection_sender, mut disconnection_receiver) = unbounded_channel();
@@ -177,7 +211,17 @@ impl ExchangeWs for CoinbaseWebsocket {
         if let Ok(mut senders) = self.disconnection_senders.lock() {
             senders.push(disconnection_sender);
         }
-        let s = stream.map(|message| parse_message(message?));
+        let mut tracker = OrderTracker::new();
+        let s = stream.map(move |message| {
+            let message = parse_message(message?)?;
+            if let CoinbaseWebsocketMessage::Full(full) = &message {
+                let orders = tracker.apply(full);
+                if !orders.is_empty() {
+                    return Ok(CoinbaseWebsocketMessage::Orders(orders));
+                }
+            }
+            Ok(message)
+        });
 
         Ok(s.boxed())
     }
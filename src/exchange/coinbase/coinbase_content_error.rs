@@ -2,15 +2,52 @@ use serde::Deserialize;
 use serde::Serialize;
 use thiserror::Error;
 use std::fmt;
+use crate::errors::ErrorKind;
 
-/// This struct represents the coinbase content errors
+/// This struct represents the coinbase content errors, `status` is the HTTP status it came
+/// with and `path` the path of the request
 #[derive(Serialize, Deserialize, Debug, Error)]
 pub struct CoinbaseContentError {
     pub message: String,
+    #[serde(skip)]
+    pub status: Option<u16>,
+    #[serde(skip)]
+    pub path: Option<String>,
+}
+
+impl CoinbaseContentError {
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn with_path(mut self, path: &str) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    fn is_order_request(&self) -> bool {
+        self.path
+            .as_deref()
+            .map_or(false, |path| path.starts_with("/orders"))
+    }
+
+    /// Coinbase has no error codes, the kind is found from the message and then from the HTTP
+    /// status
+    pub fn kind(&self) -> ErrorKind {
+        match (ErrorKind::from_message(&self.message), self.status) {
+            // Coinbase answers `NotFound` to the requests about the orders it doesn't know
+            (ErrorKind::Unknown, Some(404)) if self.is_order_request() => {
+                ErrorKind::OrderNotFound
+            }
+            (ErrorKind::Unknown, Some(status)) => ErrorKind::from_status(status),
+            (kind, _) => kind,
+        }
+    }
 }
 
 impl fmt::Display for CoinbaseContentError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "error message: {}", self.message)
     }
-}
\ No newline at end of file
+}
@@ -1,10 +1,15 @@
+use crate::exchange::traits::HttpConfig;
+use crate::exchange::traits::info::ExchangeInfoCache;
 use super::CoinbaseCredentials;
 
-/// This struct represents the coinbase parameters
+/// This struct represents the coinbase parameters.
+/// The exchange information is read from `exchange_info_cache` when there's one.
 #[derive(Default, Clone)]
 pub struct CoinbaseParameters {
     pub sandbox: bool,
     pub credentials: Option<CoinbaseCredentials>,
+    pub http: HttpConfig,
+    pub exchange_info_cache: Option<ExchangeInfoCache>,
 }
 
 impl CoinbaseParameters {
@@ -21,4 +26,9 @@ impl CoinbaseParameters {
             ..Default::default()
         }
     }
+
+    pub fn with_exchange_info_cache(mut self, cache: ExchangeInfoCache) -> Self {
+        self.exchange_info_cache = Some(cache);
+        self
+    }
 }
\ No newline at end of file
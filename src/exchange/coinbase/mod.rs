@@ -21,12 +21,15 @@
 
 
 use std::convert::TryFrom;
+use std::str::FromStr;
 use async_trait::async_trait;
 use chrono::Duration;
+use futures::stream::BoxStream;
+use rust_decimal::Decimal;
 use client::BaseClient;
 use transport::Transport;
 use crate::{
-    errors::OpenLimitsError,
+    errors::{MissingImplementationContent, OpenLimitsError},
     model::{
         AskBid, Balance, CancelAllOrdersRequest, CancelOrderRequest, Candle,
         GetHistoricRatesRequest, GetHistoricTradesRequest, GetOrderHistoryRequest, GetOrderRequest,
@@ -35,8 +38,11 @@ use crate::{
         Paginator, Side, Ticker, TimeInForce, Trade, TradeHistoryRequest,
     },
 };
-use crate::exchange::traits::info::{ExchangeInfoRetrieval, MarketPair, MarketPairHandle};
+use crate::exchange::traits::info::{
+    ExchangeInfoRetrieval, MarketPair, MarketPairHandle, MarketStatus,
+};
 use crate::exchange::traits::Exchange;
+use crate::exchange::traits::pagination::{paginate_pages, Page, PaginationConfig};
 use crate::prelude::*;
 use super::shared::Result;
 use super::shared::timestamp_to_naive_datetime;
@@ -74,18 +80,24 @@ impl Exchange for Coinbase {
                         &credentials.api_secret,
                         &credentials.passphrase,
                         parameters.sandbox,
+                        &parameters.http,
                     )?,
                 },
             },
             None => Coinbase {
                 exchange_info: ExchangeInfo::new(),
                 client: BaseClient {
-                    transport: Transport::new(parameters.sandbox)?,
+                    transport: Transport::new(parameters.sandbox, &parameters.http)?,
                 },
             },
         };
 
-        coinbase.refresh_market_info().await?;
+        match &parameters.exchange_info_cache {
+            Some(cache) => cache.initialize(&coinbase, &coinbase.exchange_info).await?,
+            None => {
+                coinbase.refresh_market_info().await?;
+            }
+        }
         Ok(coinbase)
     }
 
@@ -94,21 +106,68 @@ impl Exchange for Coinbase {
     }
 }
 
+impl Coinbase {
+    /// Streams the order history following the CB-AFTER cursors of the responses
+    pub fn order_history_stream(
+        &self,
+        req: &GetOrderHistoryRequest,
+        config: PaginationConfig,
+    ) -> BoxStream<'_, Result<Order>> {
+        let req: model::GetOrderRequest = req.into();
+        let paginator = req.paginator.clone().map(Into::into).unwrap_or_default();
+        paginate_pages(paginator, config, move |paginator: Paginator| {
+            let req = model::GetOrderRequest {
+                paginator: Some((&paginator).into()),
+                ..req.clone()
+            };
+            async move {
+                let page = self.client.get_orders_paged(Some(&req)).await?;
+                Ok(Page::new(
+                    page.data.into_iter().map(Into::into).collect(),
+                    next_page(&paginator, page.after),
+                ))
+            }
+        })
+    }
+
+    /// Streams the trade history following the CB-AFTER cursors of the responses
+    pub fn trade_history_stream(
+        &self,
+        req: &TradeHistoryRequest,
+        config: PaginationConfig,
+    ) -> BoxStream<'_, Result<Trade>> {
+        let req: model::GetFillsReq = req.into();
+        let paginator = req.paginator.clone().map(Into::into).unwrap_or_default();
+        paginate_pages(paginator, config, move |paginator: Paginator| {
+            let req = model::GetFillsReq {
+                paginator: Some((&paginator).into()),
+                ..req.clone()
+            };
+            async move {
+                let page = self.client.get_fills_paged(Some(&req)).await?;
+                Ok(Page::new(
+                    page.data.into_iter().map(Into::into).collect(),
+                    next_page(&paginator, page.after),
+                ))
+            }
+        })
+    }
+}
+
+/// Paginator of the page older than the one requested with `paginator`
+fn next_page(paginator: &Paginator, after: Option<String>) -> Option<Paginator> {
+    after.map(|after| Paginator {
+        after: Some(after),
+        before: None,
+        ..paginator.clone()
+    })
+}
+
 #[async_trait]
 impl ExchangeInfoRetrieval for Coinbase {
     async fn retrieve_pairs(&self) -> Result<Vec<MarketPair>> {
         self.client.products().await.map(|v| {
-            v.into_iter()
-                .map(|product| MarketPair {
-                    symbol: product.id,
-                    base: product.base_currency,
-                    quote: product.quote_currency,
-                    base_increment: product.base_increment,
-                    quote_increment: product.quote_increment,
-                    min_base_trade_size: None,
-                    min_quote_trade_size: None,
-                })
-                .collect()
+            v.into_iter().map(Into::into).collect()
         })
     }
 
@@ -129,11 +188,16 @@ impl ExchangeMarketData for Coinbase {
         self.client
             .book::<model::BookRecordL2>(&req.market_pair)
             .await
-            .map(Into::into)
+            .map(|book: OrderBookResponse| {
+                self.exchange_info.translate_market(&req.market_pair, book)
+            })
     }
 
     async fn get_price_ticker(&self, req: &GetPriceTickerRequest) -> Result<Ticker> {
-        self.client.ticker(&req.market_pair).await.map(Into::into)
+        self.client
+            .ticker(&req.market_pair)
+            .await
+            .map(|ticker: Ticker| self.exchange_info.translate_market(&req.market_pair, ticker))
     }
 
     async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
@@ -141,17 +205,67 @@ impl ExchangeMarketData for Coinbase {
         self.client
             .candles(&req.market_pair, Some(&params))
             .await
-            .map(|v| v.into_iter().map(Into::into).collect())
+            .map(|v| {
+                let candles: Vec<Candle> = v.into_iter().map(Into::into).collect();
+                self.exchange_info.translate_market(&req.market_pair, candles)
+            })
     }
 
     async fn get_historic_trades(&self, _req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
-        unimplemented!("Only implemented for Nash right now");
+        Err(OpenLimitsError::MissingImplementation(MissingImplementationContent {
+            message: String::from("Coinbase doesn't serve historic trades yet"),
+        }))
+    }
+}
+
+impl From<model::Product> for MarketPair {
+    fn from(product: model::Product) -> Self {
+        let status = if product.trading_disabled || product.status != "online" {
+            MarketStatus::Halted
+        } else if product.cancel_only {
+            MarketStatus::CancelOnly
+        } else if product.post_only {
+            MarketStatus::PostOnly
+        } else {
+            MarketStatus::Trading
+        };
+        let order_types = match product.limit_only {
+            true => vec![OrderType::Limit],
+            false => vec![
+                OrderType::Limit,
+                OrderType::Market,
+                OrderType::StopLimit,
+                OrderType::StopMarket,
+            ],
+        };
+        // The funds of market orders were dropped from newer products, they're empty then
+        let funds = |funds: &str| Decimal::from_str(funds).ok().filter(|funds| !funds.is_zero());
+
+        Self {
+            status,
+            order_types,
+            min_quote_trade_size: funds(&product.min_market_funds),
+            max_quote_trade_size: funds(&product.max_market_funds),
+            min_base_trade_size: Some(product.base_min_size).filter(|size| !size.is_zero()),
+            max_base_trade_size: Some(product.base_max_size).filter(|size| !size.is_zero()),
+            min_price: None,
+            max_price: None,
+            base_precision: Some(product.base_increment.normalize().scale()),
+            quote_precision: Some(product.quote_increment.normalize().scale()),
+            symbol: product.id,
+            base: product.base_currency,
+            quote: product.quote_currency,
+            base_increment: product.base_increment,
+            quote_increment: product.quote_increment,
+        }
     }
 }
 
 impl From<model::Book<model::BookRecordL2>> for OrderBookResponse {
     fn from(book: model::Book<model::BookRecordL2>) -> Self {
         Self {
+            market_pair: None,
+            instrument: None,
             update_id: Some(book.sequence as u64),
             last_update_id: None,
             bids: book.bids.into_iter().map(Into::into).collect(),
@@ -183,6 +297,7 @@ impl From<model::Order> for Order {
         Self {
             id: order.id,
             market_pair: order.product_id,
+            instrument: None,
             client_order_id: None,
             created_at: Some((order.created_at.timestamp_millis()) as u64),
             order_type,
@@ -209,7 +324,7 @@ impl ExchangeAccount for Coinbase {
                 req.post_only,
             )
             .await
-            .map(Into::into)
+            .map(|order: Order| self.exchange_info.translate(order))
     }
 
     async fn limit_sell(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
@@ -223,12 +338,15 @@ impl ExchangeAccount for Coinbase {
                 req.post_only,
             )
             .await
-            .map(Into::into)
+            .map(|order: Order| self.exchange_info.translate(order))
     }
 
     async fn market_buy(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
         let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
-        self.client.market_buy(pair, req.size).await.map(Into::into)
+        self.client
+            .market_buy(pair, req.size)
+            .await
+            .map(|order: Order| self.exchange_info.translate(order))
     }
 
     async fn market_sell(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
@@ -236,7 +354,7 @@ impl ExchangeAccount for Coinbase {
         self.client
             .market_sell(pair, req.size)
             .await
-            .map(Into::into)
+            .map(|order: Order| self.exchange_info.translate(order))
     }
 
     async fn cancel_order(&self, req: &CancelOrderRequest) -> Result<OrderCanceled> {
@@ -263,7 +381,7 @@ impl ExchangeAccount for Coinbase {
         self.client
             .get_orders(Some(&params))
             .await
-            .map(|v| v.into_iter().map(Into::into).collect())
+            .map(|v| self.exchange_info.translate(v.into_iter().map(Into::into).collect()))
     }
 
     async fn get_order_history(&self, req: &GetOrderHistoryRequest) -> Result<Vec<Order>> {
@@ -272,7 +390,7 @@ impl ExchangeAccount for Coinbase {
         self.client
             .get_orders(Some(&req))
             .await
-            .map(|v| v.into_iter().map(Into::into).collect())
+            .map(|v| self.exchange_info.translate(v.into_iter().map(Into::into).collect()))
     }
 
     async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>> {
@@ -281,7 +399,7 @@ impl ExchangeAccount for Coinbase {
         self.client
             .get_fills(Some(&req))
             .await
-            .map(|v| v.into_iter().map(Into::into).collect())
+            .map(|v| self.exchange_info.translate(v.into_iter().map(Into::into).collect()))
     }
 
     async fn get_account_balances(&self, paginator: Option<Paginator>) -> Result<Vec<Balance>> {
@@ -296,7 +414,10 @@ impl ExchangeAccount for Coinbase {
     async fn get_order(&self, req: &GetOrderRequest) -> Result<Order> {
         let id = req.id.clone();
 
-        self.client.get_order(id).await.map(Into::into)
+        self.client
+            .get_order(id)
+            .await
+            .map(|order: Order| self.exchange_info.translate(order))
     }
 }
 
@@ -312,6 +433,9 @@ impl From<model::Account> for Balance {
             asset: account.currency,
             free: account.available,
             total: account.balance,
+            locked: account.hold,
+            wallet: Some(account.id),
+            ..Default::default()
         }
     }
 }
@@ -328,6 +452,7 @@ impl From<model::Fill> for Trade {
             buyer_order_id,
             seller_order_id,
             market_pair: fill.product_id,
+            instrument: None,
             price: fill.price,
             qty: fill.size,
             fees: Some(fill.fee),
@@ -348,6 +473,8 @@ impl From<model::Fill> for Trade {
 impl From<model::Ticker> for Ticker {
     fn from(ticker: model::Ticker) -> Self {
         Self {
+            market_pair: None,
+            instrument: None,
             price: Some(ticker.price),
             price_24h: None,
         }
@@ -357,6 +484,8 @@ impl From<model::Ticker> for Ticker {
 impl From<model::Candle> for Candle {
     fn from(candle: model::Candle) -> Self {
         Self {
+            market_pair: None,
+            instrument: None,
             time: candle.time * 1000,
             low: candle.low,
             high: candle.high,
@@ -409,12 +538,8 @@ impl From<&GetOrderHistoryRequest> for model::GetOrderRequest {
 impl From<Paginator> for model::Paginator {
     fn from(paginator: Paginator) -> Self {
         Self {
-            after: paginator
-                .after
-                .map(|s| s.parse::<u64>().expect("Couldn't parse paginator.")),
-            before: paginator
-                .before
-                .map(|s| s.parse::<u64>().expect("Couldn't parse paginator.")),
+            after: paginator.after,
+            before: paginator.before,
             limit: paginator.limit,
         }
     }
@@ -423,15 +548,20 @@ impl From<Paginator> for model::Paginator {
 impl From<&Paginator> for model::Paginator {
     fn from(paginator: &Paginator) -> Self {
         Self {
-            after: paginator
-                .after
-                .as_ref()
-                .map(|s| s.parse().expect("coinbase page id did not parse as u64")),
-            before: paginator
-                .before
-                .as_ref()
-                .map(|s| s.parse().expect("coinbase page id did not parse as u64")),
+            after: paginator.after.clone(),
+            before: paginator.before.clone(),
+            limit: paginator.limit,
+        }
+    }
+}
+
+impl From<model::Paginator> for Paginator {
+    fn from(paginator: model::Paginator) -> Self {
+        Self {
+            after: paginator.after,
+            before: paginator.before,
             limit: paginator.limit,
+            ..Default::default()
         }
     }
 }
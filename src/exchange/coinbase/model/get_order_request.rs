@@ -9,6 +9,6 @@ pub struct GetOrderRequest {
     pub status: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub product_id: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
     pub paginator: Option<Paginator>,
 }
\ No newline at end of file
@@ -25,6 +25,7 @@ mod order_time_in_force_response;
 mod order_time_in_force;
 mod order_type;
 mod order;
+mod paged;
 mod paginator;
 mod product;
 mod ticker;
@@ -57,6 +58,7 @@ pub use order_time_in_force_response::OrderTimeInForceResponse;
 pub use order_time_in_force::OrderTimeInForce;
 pub use order_type::OrderType;
 pub use order::Order;
+pub use paged::Paged;
 pub use paginator::Paginator;
 pub use product::Product;
 pub use ticker::Ticker;
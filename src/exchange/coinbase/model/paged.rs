@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents a page of a response with the cursors of its CB-BEFORE and CB-AFTER
+/// headers
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Paged<T> {
+    pub data: T,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
@@ -1,13 +1,14 @@
 use serde::Deserialize;
 use serde::Serialize;
 
-/// This struct represents a paginator
+/// This struct represents a paginator, `before` and `after` are the cursors of the CB-BEFORE and
+/// CB-AFTER headers
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Paginator {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub before: Option<u64>,
+    pub before: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub after: Option<u64>,
+    pub after: Option<String>,
 }
\ No newline at end of file
@@ -1,9 +1,10 @@
 use rust_decimal::prelude::Decimal;
 use serde::Deserialize;
+use serde::Serialize;
 use super::StopType;
 use super::shared::string_to_decimal;
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Activate {
     pub product_id: String,
     #[serde(with = "string_to_decimal")]
@@ -1,10 +1,11 @@
 use rust_decimal::prelude::Decimal;
 use serde::Deserialize;
+use serde::Serialize;
 use super::OrderSide;
 use super::shared::string_to_decimal;
 use super::shared::string_to_opt_decimal;
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Change {
     pub time: String,
     pub sequence: usize,
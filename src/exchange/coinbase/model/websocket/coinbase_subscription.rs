@@ -1,4 +1,4 @@
-use crate::model::websocket::Subscription;
+use crate::model::websocket::{AccountOrders, Subscription};
 
 /// This enum represents a coinbase subscription
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -7,16 +7,24 @@ pub enum CoinbaseSubscription {
     Status,
     // Ticker(String),
     Level2(String),
-    // User,
+    /// The authenticated channel of the orders of the account in a product
+    User(String),
     // Matches,
     // FullChannel
+    /// Subscriptions without a coinbase channel
+    Unsupported(Subscription),
 }
 
 impl From<Subscription> for CoinbaseSubscription {
     fn from(subscription: Subscription) -> Self {
         match subscription {
             Subscription::OrderBookUpdates(symbol) => CoinbaseSubscription::Level2(symbol),
-            _ => unimplemented!(),
+            Subscription::AccountTrades(symbol) => CoinbaseSubscription::User(symbol),
+            Subscription::AccountOrders(AccountOrders {
+                market: Some(symbol),
+                ..
+            }) => CoinbaseSubscription::User(symbol),
+            subscription => CoinbaseSubscription::Unsupported(subscription),
         }
     }
-}
\ No newline at end of file
+}
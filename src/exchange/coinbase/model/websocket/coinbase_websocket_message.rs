@@ -1,14 +1,16 @@
 use serde::Deserialize;
 use serde::Deserializer;
+use serde::Serialize;
 use super::Channel;
 use super::Ticker;
 use super::Level2;
 use super::Match;
 use super::InputMessage;
 use super::Full;
+use crate::model::Order;
 
 /// This enum represents a coinbase websocket message
-#[derive(Debug, Clone)]
+#[derive(Serialize, Debug, Clone)]
 pub enum CoinbaseWebsocketMessage {
     Subscriptions {
         channels: Vec<Channel>,
@@ -23,6 +25,8 @@ pub enum CoinbaseWebsocketMessage {
     Level2(Level2),
     Match(Match),
     Full(Full),
+    /// The orders of the user channel updated by a full message, see `OrderTracker`
+    Orders(Vec<Order>),
     Error {
         message: String,
     },
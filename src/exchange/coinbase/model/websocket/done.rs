@@ -1,10 +1,11 @@
 use rust_decimal::prelude::Decimal;
 use serde::Deserialize;
+use serde::Serialize;
 use super::OrderSide;
 use super::Reason;
 use super::shared::string_to_decimal;
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum Done {
     Limit {
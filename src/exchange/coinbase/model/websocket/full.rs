@@ -1,5 +1,6 @@
 use rust_decimal::prelude::Decimal;
 use serde::Deserialize;
+use serde::Serialize;
 use super::Activate;
 use super::Change;
 use super::Done;
@@ -8,7 +9,7 @@ use super::Match;
 use super::Received;
 
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum Full {
     Received(Received),
     Open(Open),
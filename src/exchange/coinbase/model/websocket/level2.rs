@@ -1,9 +1,10 @@
 use serde::Deserialize;
+use serde::Serialize;
 use super::Level2SnapshotRecord;
 use super::Level2UpdateRecord;
 
 /// This enum represents the level 2
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum Level2 {
     Snapshot {
         product_id: String,
@@ -1,9 +1,10 @@
 use rust_decimal::prelude::Decimal;
 use serde::Deserialize;
+use serde::Serialize;
 use super::shared::string_to_decimal;
 
 /// This struct represents a level 2 snapshot record
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Level2SnapshotRecord {
     #[serde(with = "string_to_decimal")]
     pub price: Decimal,
@@ -1,10 +1,11 @@
 use rust_decimal::prelude::Decimal;
 use serde::Deserialize;
+use serde::Serialize;
 use super::OrderSide;
 use super::shared::string_to_decimal;
 
 /// This struct represents the level 2 update record
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Level2UpdateRecord {
     pub side: OrderSide,
     #[serde(with = "string_to_decimal")]
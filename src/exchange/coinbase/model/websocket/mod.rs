@@ -23,6 +23,7 @@ mod level2_update_record;
 mod level2;
 mod match_;
 mod open;
+mod order_tracker;
 mod reason;
 mod received;
 mod stop_type;
@@ -45,6 +46,7 @@ pub use level2_update_record::Level2UpdateRecord;
 pub use level2::Level2;
 pub use match_::Match;
 pub use open::Open;
+pub use order_tracker::OrderTracker;
 pub use reason::Reason;
 pub use received::Received;
 pub use stop_type::StopType;
@@ -61,6 +63,9 @@ impl TryFrom<CoinbaseWebsocketMessage> for WebSocketResponse<CoinbaseWebsocketMe
             CoinbaseWebsocketMessage::Level2(level2) => {
                 Ok(WebSocketResponse::Generic(level2.try_into()?))
             }
+            CoinbaseWebsocketMessage::Orders(orders) => Ok(WebSocketResponse::Generic(
+                OpenLimitsWebSocketMessage::Orders(orders),
+            )),
             _ => Ok(WebSocketResponse::Raw(value)),
         }
     }
@@ -74,10 +79,16 @@ impl TryFrom<Level2> for OpenLimitsWebSocketMessage {
         let last_update_id = None;
         let update_id = None;
         Ok(match level2 {
-            Level2::Snapshot { asks, bids, .. } => {
+            Level2::Snapshot {
+                product_id,
+                asks,
+                bids,
+            } => {
                 let bids = bids.iter().map(|bid| bid.into()).collect();
                 let asks = asks.iter().map(|ask| ask.into()).collect();
                 let order_book_response = OrderBookResponse {
+                    market_pair: Some(product_id),
+                    instrument: None,
                     bids,
                     asks,
                     update_id,
@@ -85,7 +96,10 @@ impl TryFrom<Level2> for OpenLimitsWebSocketMessage {
                 };
                 OpenLimitsWebSocketMessage::OrderBook(order_book_response)
             }
-            Level2::L2update { changes, .. } => {
+            Level2::L2update {
+                product_id,
+                changes,
+            } => {
                 let bids = changes
                     .iter()
                     .filter(|change| change.side == OrderSide::Buy)
@@ -97,6 +111,8 @@ impl TryFrom<Level2> for OpenLimitsWebSocketMessage {
                     .map(|change| change.into())
                     .collect();
                 let order_book_response = OrderBookResponse {
+                    market_pair: Some(product_id),
+                    instrument: None,
                     bids,
                     asks,
                     update_id,
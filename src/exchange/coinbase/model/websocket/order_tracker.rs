@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+use chrono::DateTime;
+use rust_decimal::prelude::{Decimal, Zero};
+use crate::model::{Liquidity, Order, OrderStatus, OrderType, Trade};
+use super::Change;
+use super::Done;
+use super::Full;
+use super::Match;
+use super::Open;
+use super::OrderSide;
+use super::Reason;
+use super::Received;
+
+/// This struct rebuilds the orders of the user channel from its full channel messages.
+/// The messages only carry the fields that changed, so the orders are tracked from the
+/// message receiving them; orders received before the subscription are left out.
+#[derive(Debug, Default, Clone)]
+pub struct OrderTracker {
+    orders: HashMap<String, Order>,
+}
+
+impl OrderTracker {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Applies a message of the user channel and returns the orders it updated
+    pub fn apply(&mut self, full: &Full) -> Vec<Order> {
+        match full {
+            Full::Received(Received::Limit {
+                time,
+                product_id,
+                order_id,
+                client_oid,
+                size,
+                price,
+                side,
+                user_id: Some(_),
+                ..
+            }) => {
+                let order = Order {
+                    id: order_id.clone(),
+                    market_pair: product_id.clone(),
+                    instrument: None,
+                    client_order_id: client_oid.clone(),
+                    created_at: Some(timestamp(time)),
+                    order_type: OrderType::Limit,
+                    side: side.clone().into(),
+                    status: OrderStatus::New,
+                    size: *size,
+                    price: Some(*price),
+                    remaining: Some(*size),
+                    trades: Vec::new(),
+                };
+                self.orders.insert(order_id.clone(), order.clone());
+                vec![order]
+            }
+            Full::Open(Open {
+                order_id,
+                remaining_size,
+                ..
+            }) => self.update(order_id, |order| {
+                // A taker order partially filled when received keeps its status on the book
+                if order.trades.is_empty() {
+                    order.status = OrderStatus::Open;
+                }
+                order.remaining = Some(*remaining_size);
+            }),
+            Full::Change(Change {
+                order_id, new_size, ..
+            }) => self.update(order_id, |order| {
+                let filled = order.size - order.remaining.unwrap_or(order.size);
+                order.size = *new_size + filled;
+                order.remaining = Some(*new_size);
+            }),
+            Full::Match(fill) => {
+                let mut orders = self.update(&fill.maker_order_id, |order| {
+                    apply_match(order, fill, Liquidity::Maker)
+                });
+                orders.extend(self.update(&fill.taker_order_id, |order| {
+                    apply_match(order, fill, Liquidity::Taker)
+                }));
+                orders
+            }
+            Full::Done(done) => {
+                let (order_id, reason, remaining_size) = match done {
+                    Done::Limit {
+                        order_id,
+                        reason,
+                        remaining_size,
+                        ..
+                    } => (order_id, reason, Some(*remaining_size)),
+                    Done::Market {
+                        order_id, reason, ..
+                    } => (order_id, reason, None),
+                };
+                let orders = self.update(order_id, |order| {
+                    order.status = match reason {
+                        Reason::Filled => OrderStatus::Filled,
+                        Reason::Canceled => OrderStatus::Canceled,
+                    };
+                    if let Some(remaining_size) = remaining_size {
+                        order.remaining = Some(remaining_size);
+                    }
+                });
+                self.orders.remove(order_id);
+                orders
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn update<F: FnOnce(&mut Order)>(&mut self, order_id: &str, update: F) -> Vec<Order> {
+        match self.orders.get_mut(order_id) {
+            Some(order) => {
+                update(order);
+                vec![order.clone()]
+            }
+            None => Vec::new(),
+        }
+    }
+}
+
+fn apply_match(order: &mut Order, fill: &Match, liquidity: Liquidity) {
+    // The side of a match is the side of its maker order
+    let (buyer_order_id, seller_order_id) = match fill.side {
+        OrderSide::Buy => (&fill.maker_order_id, &fill.taker_order_id),
+        OrderSide::Sell => (&fill.taker_order_id, &fill.maker_order_id),
+    };
+    let remaining = (order.remaining.unwrap_or(order.size) - fill.size).max(Decimal::zero());
+    order.remaining = Some(remaining);
+    order.status = if remaining.is_zero() {
+        OrderStatus::Filled
+    } else {
+        OrderStatus::PartiallyFilled
+    };
+    order.trades.push(Trade {
+        id: fill.trade_id.to_string(),
+        buyer_order_id: Some(buyer_order_id.clone()),
+        seller_order_id: Some(seller_order_id.clone()),
+        market_pair: fill.product_id.clone(),
+        instrument: None,
+        price: fill.price,
+        qty: fill.size,
+        fees: None,
+        side: order.side.clone(),
+        liquidity: Some(liquidity),
+        created_at: timestamp(&fill.time),
+    });
+}
+
+fn timestamp(time: &str) -> u64 {
+    DateTime::parse_from_rfc3339(time)
+        .map(|time| time.timestamp_millis() as u64)
+        .unwrap_or_default()
+}
@@ -1,7 +1,8 @@
 use serde::Deserialize;
+use serde::Serialize;
 
 /// This enum represents the reason why an order was rejected
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub enum Reason {
     Filled,
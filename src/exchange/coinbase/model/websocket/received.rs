@@ -2,8 +2,9 @@ use super::shared::string_to_decimal;
 use super::shared::string_to_opt_decimal;
 use rust_decimal::prelude::Decimal;
 use serde::Deserialize;
+use serde::Serialize;
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(tag = "order_type")]
 #[serde(rename_all = "camelCase")]
 pub enum Received {
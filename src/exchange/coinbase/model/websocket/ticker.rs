@@ -1,11 +1,12 @@
 use rust_decimal::prelude::Decimal;
 use serde::Deserialize;
+use serde::Serialize;
 use super::OrderSide;
 use super::shared::string_to_decimal;
 use super::shared::string_to_opt_decimal;
 
 /// This enum represents a ticker
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 #[serde(rename_all = "camelCase")]
 pub enum Ticker {
@@ -1,36 +1,38 @@
 use hmac::{Hmac, Mac, NewMac};
 use reqwest::header;
-use reqwest::{Method, RequestBuilder, Response, StatusCode};
+use reqwest::{Method, Request, Response, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use sha2::Sha256;
-use std::time::SystemTime;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 use url::Url;
 use crate::exchange::coinbase::CoinbaseContentError;
+use crate::exchange::coinbase::model::Paged;
+use crate::exchange::traits::{HttpConfig, HttpTransport};
 use crate::errors::OpenLimitsError;
 use super::shared::Result;
 
 type HmacSha256 = Hmac<Sha256>;
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Transport {
     api_secret: Option<String>,
-    client: reqwest::Client,
+    client: Arc<dyn HttpTransport>,
+    headers: header::HeaderMap<header::HeaderValue>,
+    timeout: Option<Duration>,
     base_url: String,
 }
 
 impl Transport {
-    pub fn new(sandbox: bool) -> Result<Self> {
-        let default_headers = Transport::default_headers();
-
-        let client = reqwest::Client::builder()
-            .default_headers(default_headers)
-            .build()?;
-
+    pub fn new(sandbox: bool, http: &HttpConfig) -> Result<Self> {
         Ok(Transport {
-            client,
+            client: http.build_transport()?,
             api_secret: None,
-            base_url: Transport::get_base_url(sandbox),
+            headers: Transport::default_headers(),
+            timeout: http.timeout,
+            base_url: http.base_url_or(&Transport::get_base_url(sandbox)),
         })
     }
 
@@ -39,16 +41,14 @@ impl Transport {
         api_secret: &str,
         passphrase: &str,
         sandbox: bool,
+        http: &HttpConfig,
     ) -> Result<Self> {
-        let default_headers = Transport::default_headers_with_auth(&api_key, &passphrase);
-        let client = reqwest::Client::builder()
-            .default_headers(default_headers)
-            .build()?;
-
         Ok(Transport {
             api_secret: Some(String::from(api_secret)),
-            client,
-            base_url: Transport::get_base_url(sandbox),
+            client: http.build_transport()?,
+            headers: Transport::default_headers_with_auth(&api_key, &passphrase),
+            timeout: http.timeout,
+            base_url: http.base_url_or(&Transport::get_base_url(sandbox)),
         })
     }
 
@@ -103,7 +103,8 @@ impl Transport {
         S: Serialize,
     {
         let url = self.get_url(endpoint, params)?;
-        let request = self.client.get(url).send().await?;
+        let request = self.build_unsigned_request(Method::GET, url);
+        let request = self.client.execute(request).await?;
 
         Ok(self.response_handler(request).await?)
     }
@@ -117,11 +118,40 @@ impl Transport {
 
         let request = self.build_request::<()>(url, Method::GET, None)?;
 
-        let resp = request.send().await?;
+        let resp = self.client.execute(request).await?;
 
         Ok(self.response_handler(resp).await?)
     }
 
+    /// Signed get keeping the pagination cursors of the response headers
+    pub async fn signed_get_paged<O, S>(&self, endpoint: &str, params: Option<&S>) -> Result<Paged<O>>
+    where
+        O: DeserializeOwned,
+        S: Serialize,
+    {
+        let url = self.get_url(endpoint, params)?;
+
+        let request = self.build_request::<()>(url, Method::GET, None)?;
+
+        let resp = self.client.execute(request).await?;
+        let before = Transport::header(&resp, "CB-BEFORE");
+        let after = Transport::header(&resp, "CB-AFTER");
+
+        Ok(Paged {
+            data: self.response_handler(resp).await?,
+            before,
+            after,
+        })
+    }
+
+    fn header(response: &Response, name: &str) -> Option<String> {
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from)
+    }
+
     pub async fn signed_post<O, P, D>(
         &self,
         endpoint: &str,
@@ -135,7 +165,7 @@ impl Transport {
     {
         let url = self.get_url(endpoint, params)?;
         let request = self.build_request(url, Method::POST, data)?;
-        let resp = request.send().await?;
+        let resp = self.client.execute(request).await?;
 
         Ok(self.response_handler(resp).await?)
     }
@@ -153,7 +183,7 @@ impl Transport {
     {
         let url = self.get_url(endpoint, params)?;
         let request = self.build_request(url, Method::DELETE, data)?;
-        let request = request.send().await?;
+        let request = self.client.execute(request).await?;
 
         Ok(self.response_handler(request).await?)
     }
@@ -163,7 +193,7 @@ impl Transport {
         url: Url,
         method: Method,
         data: Option<&D>,
-    ) -> Result<RequestBuilder>
+    ) -> Result<Request>
     where
         D: Serialize,
     {
@@ -174,21 +204,33 @@ impl Transport {
 
         let signature = self.signature(&url, since_epoch_seconds, &method, data)?;
 
-        let mut request = self
-            .client
-            .request(method, url)
-            .header("CB-ACCESS-SIGN", signature)
-            .header("CB-ACCESS-TIMESTAMP", since_epoch_seconds.to_string());
+        let mut request = self.build_unsigned_request(method, url);
+        let headers = request.headers_mut();
+        headers.insert("CB-ACCESS-SIGN", header::HeaderValue::from_str(&signature)?);
+        headers.insert(
+            "CB-ACCESS-TIMESTAMP",
+            header::HeaderValue::from_str(&since_epoch_seconds.to_string())?,
+        );
 
-        request = if data.is_some() {
-            request.json(&data)
-        } else {
-            request
-        };
+        if data.is_some() {
+            headers.insert(
+                header::CONTENT_TYPE,
+                header::HeaderValue::from_static("application/json"),
+            );
+            *request.body_mut() = Some(serde_json::to_string(&data)?.into());
+        }
 
         Ok(request)
     }
 
+    /// Requests are built here and sent by the http transport
+    fn build_unsigned_request(&self, method: Method, url: Url) -> Request {
+        let mut request = Request::new(method, url);
+        *request.headers_mut() = self.headers.clone();
+        *request.timeout_mut() = self.timeout;
+        request
+    }
+
     pub fn get_url<Q>(&self, endpoint: &str, params: Option<&Q>) -> Result<Url>
     where
         Q: Serialize,
@@ -254,26 +296,28 @@ impl Transport {
                     OpenLimitsError::NotParsableResponse(format!("Error:{} Payload: {}", err, text))
                 })
             }
-
-            StatusCode::INTERNAL_SERVER_ERROR => Err(OpenLimitsError::InternalServerError()),
-            StatusCode::SERVICE_UNAVAILABLE => Err(OpenLimitsError::ServiceUnavailable()),
-            StatusCode::UNAUTHORIZED => {
-                let text = response.text().await?;
-                println!("{}", text);
-                Err(OpenLimitsError::Unauthorized())
-            }
-            StatusCode::BAD_REQUEST => {
-                let error: CoinbaseContentError = response.json().await?;
-
-                Err(OpenLimitsError::CoinbaseError(error))
-            }
-            s => {
-                let text = response.text().await?;
-                Err(OpenLimitsError::UnkownResponse(format!(
-                    "Received response: {:?}, value: {}",
-                    s, text
-                )))
+            status => {
+                let path = response.url().path().to_string();
+                let body = response.text().await?;
+                match serde_json::from_str::<CoinbaseContentError>(&body) {
+                    Ok(error) => Err(OpenLimitsError::CoinbaseError(
+                        error.with_status(status.as_u16()).with_path(&path),
+                    )),
+                    Err(_) => Err(OpenLimitsError::HttpError {
+                        status: status.as_u16(),
+                        body,
+                    }),
+                }
             }
         }
     }
 }
+
+impl fmt::Debug for Transport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Transport")
+            .field("base_url", &self.base_url)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
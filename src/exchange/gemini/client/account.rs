@@ -0,0 +1,104 @@
+use chrono::Utc;
+use rust_decimal::prelude::*;
+use crate::exchange::gemini::model::{
+    AccountTrade, Balance, CancelAllResponse, HistoryRequest, NewOrderRequest, Order,
+    OrderIdRequest, ORDER_SIDE_BUY, ORDER_SIDE_SELL, ORDER_TYPE_EXCHANGE_LIMIT,
+};
+use crate::exchange::traits::info::MarketPair;
+use super::BaseClient;
+use super::shared::Result;
+
+impl BaseClient {
+    pub async fn balances(&self) -> Result<Vec<Balance>> {
+        self.transport
+            .signed_post::<_, ()>("/v1/balances", None)
+            .await
+    }
+
+    pub async fn order_status(&self, order_id: u64) -> Result<Order> {
+        self.transport
+            .signed_post("/v1/order/status", Some(&OrderIdRequest { order_id }))
+            .await
+    }
+
+    pub async fn active_orders(&self) -> Result<Vec<Order>> {
+        self.transport.signed_post::<_, ()>("/v1/orders", None).await
+    }
+
+    pub async fn orders_history(&self, request: &HistoryRequest) -> Result<Vec<Order>> {
+        self.transport
+            .signed_post("/v1/orders/history", Some(request))
+            .await
+    }
+
+    pub async fn past_trades(&self, request: &HistoryRequest) -> Result<Vec<AccountTrade>> {
+        self.transport
+            .signed_post("/v1/mytrades", Some(request))
+            .await
+    }
+
+    pub async fn cancel_order(&self, order_id: u64) -> Result<Order> {
+        self.transport
+            .signed_post("/v1/order/cancel", Some(&OrderIdRequest { order_id }))
+            .await
+    }
+
+    // Cancels the orders of every session of the account
+    pub async fn cancel_all_orders(&self) -> Result<CancelAllResponse> {
+        self.transport
+            .signed_post::<_, ()>("/v1/order/cancel/all", None)
+            .await
+    }
+
+    // Place a LIMIT order - BUY
+    pub async fn limit_buy(
+        &self,
+        pair: MarketPair,
+        size: Decimal,
+        price: Decimal,
+        options: Vec<String>,
+    ) -> Result<Order> {
+        let data = NewOrderRequest {
+            client_order_id: client_order_id(),
+            symbol: pair.symbol,
+            amount: size.round_dp(pair.base_increment.normalize().scale()),
+            price: price.round_dp_with_strategy(
+                pair.quote_increment.normalize().scale(),
+                RoundingStrategy::ToZero,
+            ),
+            side: ORDER_SIDE_BUY.to_string(),
+            order_type: ORDER_TYPE_EXCHANGE_LIMIT.to_string(),
+            options,
+        };
+
+        self.transport.signed_post("/v1/order/new", Some(&data)).await
+    }
+
+    // Place a LIMIT order - SELL
+    pub async fn limit_sell(
+        &self,
+        pair: MarketPair,
+        size: Decimal,
+        price: Decimal,
+        options: Vec<String>,
+    ) -> Result<Order> {
+        let data = NewOrderRequest {
+            client_order_id: client_order_id(),
+            symbol: pair.symbol,
+            amount: size.round_dp(pair.base_increment.normalize().scale()),
+            price: price.round_dp_with_strategy(
+                pair.quote_increment.normalize().scale(),
+                RoundingStrategy::AwayFromZero,
+            ),
+            side: ORDER_SIDE_SELL.to_string(),
+            order_type: ORDER_TYPE_EXCHANGE_LIMIT.to_string(),
+            options,
+        };
+
+        self.transport.signed_post("/v1/order/new", Some(&data)).await
+    }
+}
+
+fn client_order_id() -> String {
+    Utc::now().timestamp_nanos().to_string()
+}
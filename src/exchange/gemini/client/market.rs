@@ -0,0 +1,43 @@
+use serde_json::json;
+use crate::exchange::gemini::model::{Book, Candle, SymbolDetails, Ticker, TimeFrame, Trade};
+use super::shared::Result;
+use super::BaseClient;
+
+impl BaseClient {
+    pub async fn symbols(&self) -> Result<Vec<String>> {
+        self.transport.get::<_, ()>("/v1/symbols", None).await
+    }
+
+    pub async fn symbol_details(&self, symbol: &str) -> Result<SymbolDetails> {
+        self.transport
+            .get::<_, ()>(&format!("/v1/symbols/details/{}", symbol), None)
+            .await
+    }
+
+    // Order book with every level, a limit of zero returns the full book
+    pub async fn book(&self, symbol: &str) -> Result<Book> {
+        let params = json! {{"limit_bids": 0, "limit_asks": 0}};
+        self.transport
+            .get(&format!("/v1/book/{}", symbol), Some(&params))
+            .await
+    }
+
+    pub async fn ticker(&self, symbol: &str) -> Result<Ticker> {
+        self.transport
+            .get::<_, ()>(&format!("/v2/ticker/{}", symbol), None)
+            .await
+    }
+
+    pub async fn candles(&self, symbol: &str, time_frame: TimeFrame) -> Result<Vec<Candle>> {
+        self.transport
+            .get::<_, ()>(&format!("/v2/candles/{}/{}", symbol, time_frame), None)
+            .await
+    }
+
+    pub async fn trades(&self, symbol: &str, since: Option<u64>, limit: Option<u64>) -> Result<Vec<Trade>> {
+        let params = json! {{"timestamp": since, "limit_trades": limit}};
+        self.transport
+            .get(&format!("/v1/trades/{}", symbol), Some(&params))
+            .await
+    }
+}
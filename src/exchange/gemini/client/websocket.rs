@@ -0,0 +1,389 @@
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::sync::Mutex;
+use std::time::Duration;
+use async_trait::async_trait;
+use futures::{SinkExt, stream::BoxStream, StreamExt};
+use rust_decimal::prelude::Zero;
+use serde::{de, Deserialize};
+use serde_json::Value;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use crate::{
+    errors::{MissingImplementationContent, OpenLimitsError},
+    exchange::gemini::{
+        GeminiParameters,
+        client::Transport,
+        model::websocket::{
+            GeminiSubscription, GeminiWebsocketMessage, L2Change, L2Update, OrderEvent,
+            SubscribeRequest,
+        },
+    },
+    model::websocket::OpenLimitsWebSocketMessage,
+    model::websocket::WebSocketResponse,
+    model::{AskBid, Liquidity, Order, OrderBookResponse, OrderStatus, OrderType, Side, Trade},
+};
+use crate::exchange::traits::stream::{ExchangeWs, Subscriptions};
+use super::shared::Result;
+
+const WS_URL_MARKET_DATA: &str = "wss://api.gemini.com/v2/marketdata";
+const WS_URL_ORDER_EVENTS: &str = "wss://api.gemini.com/v1/order/events";
+const WS_URL_SANDBOX_MARKET_DATA: &str = "wss://api.sandbox.gemini.com/v2/marketdata";
+const WS_URL_SANDBOX_ORDER_EVENTS: &str = "wss://api.sandbox.gemini.com/v1/order/events";
+
+const ORDER_EVENTS_ENDPOINT: &str = "/v1/order/events";
+const PING_INTERVAL: Duration = Duration::from_secs(30);
+
+/// This struct is used for websocket communications with gemini exchange.
+/// Market data and order events are served by different endpoints, the order events
+/// connection is authenticated with the signed payload headers of the REST api.
+pub struct GeminiWebsocket {
+    transport: Transport,
+    sandbox: bool,
+    disconnection_senders: Mutex<Vec<UnboundedSender<()>>>,
+}
+
+impl GeminiWebsocket {
+    async fn connect_market_data(
+        &self,
+        symbols: Vec<String>,
+    ) -> Result<BoxStream<'static, Result<GeminiWebsocketMessage>>> {
+        let url = match self.sandbox {
+            true => WS_URL_SANDBOX_MARKET_DATA,
+            false => WS_URL_MARKET_DATA,
+        };
+        let (ws_stream, _) = connect_async(url).await?;
+        let (mut sink, stream) = ws_stream.split();
+
+        let request = SubscribeRequest::l2(symbols);
+        sink.send(Message::Text(serde_json::to_string(&request)?))
+            .await?;
+        self.keep_alive(sink);
+
+        // The first update of every symbol carries the whole book
+        let mut snapshots = HashSet::new();
+        let s = stream.map(move |message| match message {
+            Ok(msg) => parse_message(msg).map(|message| match message {
+                GeminiWebsocketMessage::L2Update(update)
+                    if snapshots.insert(update.symbol.clone()) =>
+                {
+                    GeminiWebsocketMessage::L2Snapshot(update)
+                }
+                message => message,
+            }),
+            Err(_) => Err(OpenLimitsError::SocketError()),
+        });
+
+        Ok(s.boxed())
+    }
+
+    async fn connect_order_events(&self) -> Result<BoxStream<'static, Result<GeminiWebsocketMessage>>> {
+        let url = match self.sandbox {
+            true => WS_URL_SANDBOX_ORDER_EVENTS,
+            false => WS_URL_ORDER_EVENTS,
+        };
+        let (key, payload, signature) = self
+            .transport
+            .signed_payload::<()>(ORDER_EVENTS_ENDPOINT, None)?;
+
+        let mut request = url.into_client_request()?;
+        let headers = request.headers_mut();
+        headers.insert("X-GEMINI-APIKEY", HeaderValue::from_str(key)?);
+        headers.insert("X-GEMINI-PAYLOAD", HeaderValue::from_str(&payload)?);
+        headers.insert("X-GEMINI-SIGNATURE", HeaderValue::from_str(&signature)?);
+
+        let (ws_stream, _) = connect_async(request).await?;
+        let (sink, stream) = ws_stream.split();
+        self.keep_alive(sink);
+
+        let s = stream.map(|message| match message {
+            Ok(msg) => parse_message(msg),
+            Err(_) => Err(OpenLimitsError::SocketError()),
+        });
+
+        Ok(s.boxed())
+    }
+
+    fn keep_alive<S>(&self, mut sink: S)
+    where
+        S: futures::Sink<Message> + Unpin + Send + 'static,
+    {
+        let (disconnection_sender, mut disconnection_receiver) = unbounded_channel();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(PING_INTERVAL);
+            loop {
+                tokio::select! {
+                    _ = disconnection_receiver.recv() => {
+                        sink.close().await.ok();
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        if sink.send(Message::Ping(Vec::new())).await.is_err() {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+
+        if let Ok(mut senders) = self.disconnection_senders.lock() {
+            senders.push(disconnection_sender);
+        }
+    }
+}
+
+#[async_trait]
+impl ExchangeWs for GeminiWebsocket {
+    type InitParams = GeminiParameters;
+    type Subscription = GeminiSubscription;
+    type Response = GeminiWebsocketMessage;
+
+    async fn new(parameters: Self::InitParams) -> Result<Self> {
+        let transport = match parameters.credentials {
+            Some(credentials) => Transport::with_credential(
+                &credentials.api_key,
+                &credentials.api_secret,
+                parameters.sandbox,
+            )?,
+            None => Transport::new(parameters.sandbox)?,
+        };
+
+        Ok(GeminiWebsocket {
+            transport,
+            sandbox: parameters.sandbox,
+            disconnection_senders: Default::default(),
+        })
+    }
+
+    async fn disconnect(&self) {
+        if let Ok(mut senders) = self.disconnection_senders.lock() {
+            for sender in senders.iter() {
+                sender.send(()).ok();
+            }
+            senders.clear();
+        }
+    }
+
+    async fn create_stream_specific(
+        &self,
+        subscriptions: Subscriptions<Self::Subscription>,
+    ) -> Result<BoxStream<'static, Result<Self::Response>>> {
+        let mut symbols = Vec::new();
+        let mut order_events = false;
+        for subscription in subscriptions.into_iter() {
+            match subscription {
+                GeminiSubscription::L2(symbol) => {
+                    if !symbols.contains(&symbol) {
+                        symbols.push(symbol);
+                    }
+                }
+                GeminiSubscription::OrderEvents => order_events = true,
+                GeminiSubscription::Unsupported(subscription) => {
+                    return Err(OpenLimitsError::MissingImplementation(
+                        MissingImplementationContent {
+                            message: format!("Gemini doesn't support {:?}", subscription),
+                        },
+                    ))
+                }
+            }
+        }
+
+        let mut streams = Vec::new();
+        if !symbols.is_empty() {
+            streams.push(self.connect_market_data(symbols).await?);
+        }
+        if order_events {
+            streams.push(self.connect_order_events().await?);
+        }
+
+        Ok(futures::stream::select_all(streams).boxed())
+    }
+}
+
+#[derive(Deserialize)]
+struct GeminiWebsocketEvent {
+    #[serde(rename = "type")]
+    pub event_type: String,
+}
+
+impl<'de> Deserialize<'de> for GeminiWebsocketMessage {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+
+        // Order events are always sent in batches
+        if value.is_array() {
+            return Ok(GeminiWebsocketMessage::OrderEvents(
+                serde_json::from_value(value).map_err(de::Error::custom)?,
+            ));
+        }
+
+        let event: GeminiWebsocketEvent = match serde_json::from_value(value.clone()) {
+            Ok(event) => event,
+            Err(_) => return Ok(GeminiWebsocketMessage::Unknown(value)),
+        };
+        match event.event_type.as_str() {
+            "l2_updates" => Ok(GeminiWebsocketMessage::L2Update(
+                serde_json::from_value(value).map_err(de::Error::custom)?,
+            )),
+            "trade" => Ok(GeminiWebsocketMessage::Trade(
+                serde_json::from_value(value).map_err(de::Error::custom)?,
+            )),
+            "heartbeat" => Ok(GeminiWebsocketMessage::Heartbeat),
+            "subscription_ack" => Ok(GeminiWebsocketMessage::SubscriptionAck(value)),
+            _ => Ok(GeminiWebsocketMessage::Unknown(value)),
+        }
+    }
+}
+
+impl TryFrom<GeminiWebsocketMessage> for WebSocketResponse<GeminiWebsocketMessage> {
+    type Error = OpenLimitsError;
+
+    fn try_from(value: GeminiWebsocketMessage) -> Result<Self> {
+        match value {
+            GeminiWebsocketMessage::L2Snapshot(update) => Ok(WebSocketResponse::Generic(
+                OpenLimitsWebSocketMessage::OrderBook(order_book_response(update)),
+            )),
+            GeminiWebsocketMessage::L2Update(update) => Ok(WebSocketResponse::Generic(
+                OpenLimitsWebSocketMessage::OrderBookDiff(order_book_response(update)),
+            )),
+            GeminiWebsocketMessage::Trade(trade) => Ok(WebSocketResponse::Generic(
+                OpenLimitsWebSocketMessage::Trades(vec![Trade {
+                    id: trade.event_id.to_string(),
+                    buyer_order_id: None,
+                    seller_order_id: None,
+                    market_pair: trade.symbol,
+                    instrument: None,
+                    price: trade.price,
+                    qty: trade.quantity,
+                    fees: None,
+                    side: Side::from(trade.side),
+                    liquidity: None,
+                    created_at: trade.timestamp,
+                }]),
+            )),
+            GeminiWebsocketMessage::OrderEvents(events) => Ok(WebSocketResponse::Generic(
+                OpenLimitsWebSocketMessage::Orders(events.into_iter().map(Order::from).collect()),
+            )),
+            GeminiWebsocketMessage::Heartbeat
+            | GeminiWebsocketMessage::Ping
+            | GeminiWebsocketMessage::Pong => {
+                Ok(WebSocketResponse::Generic(OpenLimitsWebSocketMessage::Ping))
+            }
+            GeminiWebsocketMessage::Close => Err(OpenLimitsError::SocketError()),
+            _ => Ok(WebSocketResponse::Raw(value)),
+        }
+    }
+}
+
+/// Bids are the `buy` changes and asks the `sell` ones
+fn order_book_response(update: L2Update) -> OrderBookResponse {
+    let (bids, asks): (Vec<L2Change>, Vec<L2Change>) = update
+        .changes
+        .into_iter()
+        .partition(|change| change.side == "buy");
+
+    OrderBookResponse {
+        market_pair: Some(update.symbol),
+        instrument: None,
+        update_id: None,
+        last_update_id: None,
+        bids: bids.into_iter().map(Into::into).collect(),
+        asks: asks.into_iter().map(Into::into).collect(),
+    }
+}
+
+impl From<L2Change> for AskBid {
+    fn from(change: L2Change) -> Self {
+        Self {
+            price: change.price,
+            qty: change.quantity,
+        }
+    }
+}
+
+/// A `fill` event carries the trade that filled the order
+impl From<OrderEvent> for Order {
+    fn from(event: OrderEvent) -> Self {
+        let order_type = if event.order_type.contains("limit") {
+            OrderType::Limit
+        } else if event.order_type.contains("market") {
+            OrderType::Market
+        } else {
+            OrderType::Unknown
+        };
+        let executed_amount = event.executed_amount.unwrap_or_default();
+        let status = if event.event_type == "rejected" {
+            OrderStatus::Rejected
+        } else if event.is_live {
+            match executed_amount.is_zero() {
+                true => OrderStatus::Open,
+                false => OrderStatus::PartiallyFilled,
+            }
+        } else if event.is_cancelled {
+            OrderStatus::Canceled
+        } else {
+            OrderStatus::Filled
+        };
+        let size = event
+            .original_amount
+            .unwrap_or_else(|| executed_amount + event.remaining_amount.unwrap_or_default());
+        let side = Side::from(event.side);
+        let trades = match event.fill {
+            Some(fill) => {
+                let (buyer_order_id, seller_order_id) = match side {
+                    Side::Buy => (Some(event.order_id.clone()), None),
+                    Side::Sell => (None, Some(event.order_id.clone())),
+                };
+                vec![Trade {
+                    id: fill.trade_id,
+                    buyer_order_id,
+                    seller_order_id,
+                    market_pair: event.symbol.clone(),
+                    instrument: None,
+                    price: fill.price,
+                    qty: fill.amount,
+                    fees: Some(fill.fee),
+                    side: side.clone(),
+                    liquidity: match fill.liquidity.as_str() {
+                        "Maker" => Some(Liquidity::Maker),
+                        _ => Some(Liquidity::Taker),
+                    },
+                    created_at: event.timestampms,
+                }]
+            }
+            None => Vec::new(),
+        };
+
+        Self {
+            id: event.order_id,
+            market_pair: event.symbol,
+            instrument: None,
+            client_order_id: event.client_order_id,
+            created_at: Some(event.timestampms),
+            order_type,
+            side,
+            status,
+            size,
+            price: event.price,
+            remaining: event.remaining_amount,
+            trades,
+        }
+    }
+}
+
+fn parse_message(ws_message: Message) -> Result<GeminiWebsocketMessage> {
+    let msg = match ws_message {
+        Message::Text(m) => m,
+        Message::Binary(b) => return Ok(GeminiWebsocketMessage::Binary(b)),
+        Message::Pong(..) => return Ok(GeminiWebsocketMessage::Pong),
+        Message::Ping(..) => return Ok(GeminiWebsocketMessage::Ping),
+        Message::Close(..) => return Ok(GeminiWebsocketMessage::Close),
+    };
+
+    serde_json::from_str(&msg).map_err(OpenLimitsError::JsonError)
+}
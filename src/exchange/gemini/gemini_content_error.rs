@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thiserror::Error;
+use std::fmt;
+
+/// This struct represents the gemini content errors,
+/// `{"result": "error", "reason": "InvalidSignature", "message": "..."}`
+#[derive(Serialize, Deserialize, Debug, Error)]
+pub struct GeminiContentError {
+    pub result: String,
+    pub reason: String,
+    #[serde(default)]
+    pub message: String,
+}
+
+impl fmt::Display for GeminiContentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "error reason: {} message: {}", self.reason, self.message)
+    }
+}
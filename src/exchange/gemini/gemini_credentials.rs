@@ -0,0 +1,6 @@
+/// This struct represents the gemini credentials
+#[derive(Clone)]
+pub struct GeminiCredentials {
+    pub api_key: String,
+    pub api_secret: String,
+}
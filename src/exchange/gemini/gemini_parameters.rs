@@ -0,0 +1,32 @@
+use crate::exchange::traits::info::ExchangeInfoCache;
+use super::GeminiCredentials;
+
+/// This struct represents the gemini parameters.
+/// The exchange information is read from `exchange_info_cache` when there's one.
+#[derive(Default, Clone)]
+pub struct GeminiParameters {
+    pub sandbox: bool,
+    pub credentials: Option<GeminiCredentials>,
+    pub exchange_info_cache: Option<ExchangeInfoCache>,
+}
+
+impl GeminiParameters {
+    pub fn sandbox() -> Self {
+        Self {
+            sandbox: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn prod() -> Self {
+        Self {
+            sandbox: false,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_exchange_info_cache(mut self, cache: ExchangeInfoCache) -> Self {
+        self.exchange_info_cache = Some(cache);
+        self
+    }
+}
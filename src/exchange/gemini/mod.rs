@@ -0,0 +1,523 @@
+//! This module provides functionality for communicating with the gemini API.
+//! # Example
+//! ```
+//! use openlimits::exchange::gemini::Gemini;
+//! use openlimits::exchange::gemini::GeminiParameters;
+//! use openlimits::prelude::*;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let gemini = Gemini::new(GeminiParameters::prod())
+//!                         .await
+//!                         .expect("Couldn't create gemini client");
+
+//!     let order_book = gemini.order_book(&OrderBookRequest {market_pair: "btcusd".to_string()})
+//!                         .await
+//!                         .expect("Couldn't get order book");
+
+//!     println!("{:?}", order_book);
+//! }
+//! ```
+
+use std::convert::TryFrom;
+use async_trait::async_trait;
+use futures::future::try_join_all;
+use client::BaseClient;
+use transport::Transport;
+use crate::{
+    errors::{MissingImplementationContent, OpenLimitsError},
+    model::{
+        AskBid, Balance, CancelAllOrdersRequest, CancelOrderRequest, Candle,
+        GetHistoricRatesRequest, GetHistoricTradesRequest, GetOrderHistoryRequest, GetOrderRequest,
+        GetPriceTickerRequest, Interval, Liquidity, OpenLimitOrderRequest, OpenMarketOrderRequest,
+        Order, OrderBookRequest, OrderBookResponse, OrderCanceled, OrderStatus, OrderType,
+        Paginator, Side, Ticker, TimeInForce, Trade, TradeHistoryRequest,
+    },
+};
+use crate::exchange::traits::info::{
+    ExchangeInfoRetrieval, MarketPair, MarketPairHandle, MarketStatus,
+};
+use crate::exchange::traits::Exchange;
+use crate::prelude::*;
+use super::shared::Result;
+
+pub mod client;
+pub mod model;
+mod transport;
+mod gemini_content_error;
+mod gemini_credentials;
+mod gemini_parameters;
+
+pub use client::websocket::GeminiWebsocket;
+pub use gemini_content_error::GeminiContentError;
+pub use gemini_credentials::GeminiCredentials;
+pub use gemini_parameters::GeminiParameters;
+pub use super::shared;
+
+/// The main struct of the gemini module
+#[derive(Clone)]
+pub struct Gemini {
+    pub exchange_info: ExchangeInfo,
+    pub client: BaseClient,
+}
+
+#[async_trait]
+impl Exchange for Gemini {
+    type InitParams = GeminiParameters;
+    type InnerClient = BaseClient;
+
+    async fn new(parameters: Self::InitParams) -> Result<Self> {
+        let gemini = match parameters.credentials {
+            Some(credentials) => Gemini {
+                exchange_info: ExchangeInfo::new(),
+                client: BaseClient {
+                    transport: Transport::with_credential(
+                        &credentials.api_key,
+                        &credentials.api_secret,
+                        parameters.sandbox,
+                    )?,
+                },
+            },
+            None => Gemini {
+                exchange_info: ExchangeInfo::new(),
+                client: BaseClient {
+                    transport: Transport::new(parameters.sandbox)?,
+                },
+            },
+        };
+
+        match &parameters.exchange_info_cache {
+            Some(cache) => cache.initialize(&gemini, &gemini.exchange_info).await?,
+            None => {
+                gemini.refresh_market_info().await?;
+            }
+        }
+        Ok(gemini)
+    }
+
+    fn inner_client(&self) -> Option<&Self::InnerClient> {
+        Some(&self.client)
+    }
+}
+
+#[async_trait]
+impl ExchangeInfoRetrieval for Gemini {
+    async fn retrieve_pairs(&self) -> Result<Vec<MarketPair>> {
+        // The symbol list doesn't carry the trading rules, they are fetched for every symbol
+        let symbols = self.client.symbols().await?;
+        let details = try_join_all(
+            symbols
+                .iter()
+                .map(|symbol| self.client.symbol_details(symbol)),
+        )
+        .await?;
+
+        Ok(details.into_iter().map(Into::into).collect())
+    }
+
+    async fn refresh_market_info(&self) -> Result<Vec<MarketPairHandle>> {
+        self.exchange_info
+            .refresh(self as &dyn ExchangeInfoRetrieval)
+            .await
+    }
+
+    async fn get_pair(&self, name: &str) -> Result<MarketPairHandle> {
+        self.exchange_info.get_pair(name)
+    }
+}
+
+#[async_trait]
+impl ExchangeMarketData for Gemini {
+    async fn order_book(&self, req: &OrderBookRequest) -> Result<OrderBookResponse> {
+        self.client
+            .book(&req.market_pair)
+            .await
+            .map(|book: OrderBookResponse| {
+                self.exchange_info.translate_market(&req.market_pair, book)
+            })
+    }
+
+    async fn get_price_ticker(&self, req: &GetPriceTickerRequest) -> Result<Ticker> {
+        self.client
+            .ticker(&req.market_pair)
+            .await
+            .map(|ticker: Ticker| self.exchange_info.translate_market(&req.market_pair, ticker))
+    }
+
+    async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
+        let time_frame = model::TimeFrame::try_from(req.interval)?;
+        self.client
+            .candles(&req.market_pair, time_frame)
+            .await
+            .map(|v| {
+                let candles: Vec<Candle> = v.into_iter().map(Into::into).collect();
+                self.exchange_info.translate_market(&req.market_pair, candles)
+            })
+    }
+
+    async fn get_historic_trades(&self, req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
+        let paginator = req.paginator.clone().unwrap_or_default();
+        let market_pair = req.market_pair.clone();
+        self.client
+            .trades(&req.market_pair, paginator.start_time, paginator.limit)
+            .await
+            .map(|v| {
+                self.exchange_info.translate(
+                    v.into_iter()
+                        .map(|trade| public_trade(market_pair.clone(), trade))
+                        .collect(),
+                )
+            })
+    }
+}
+
+#[async_trait]
+impl ExchangeAccount for Gemini {
+    async fn limit_buy(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
+        let options = order_options(req.time_in_force, req.post_only)?;
+        self.client
+            .limit_buy(pair, req.size, req.price, options)
+            .await
+            .map(|order: Order| self.exchange_info.translate(order))
+    }
+
+    async fn limit_sell(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
+        let options = order_options(req.time_in_force, req.post_only)?;
+        self.client
+            .limit_sell(pair, req.size, req.price, options)
+            .await
+            .map(|order: Order| self.exchange_info.translate(order))
+    }
+
+    async fn market_buy(&self, _req: &OpenMarketOrderRequest) -> Result<Order> {
+        Err(market_orders_unsupported())
+    }
+
+    async fn market_sell(&self, _req: &OpenMarketOrderRequest) -> Result<Order> {
+        Err(market_orders_unsupported())
+    }
+
+    async fn cancel_order(&self, req: &CancelOrderRequest) -> Result<OrderCanceled> {
+        let order_id = parse_order_id(&req.id)?;
+        self.client
+            .cancel_order(order_id)
+            .await
+            .map(Into::into)
+    }
+
+    async fn cancel_all_orders(&self, req: &CancelAllOrdersRequest) -> Result<Vec<OrderCanceled>> {
+        match req.market_pair.as_ref() {
+            // Gemini only cancels everything at once, the orders of a symbol are canceled
+            // one by one
+            Some(market_pair) => {
+                let orders = self.client.active_orders().await?;
+                let mut canceled = Vec::new();
+                for order in orders.iter().filter(|order| &order.symbol == market_pair) {
+                    let order = self
+                        .client
+                        .cancel_order(parse_order_id(&order.order_id)?)
+                        .await?;
+                    canceled.push(order.into());
+                }
+                Ok(canceled)
+            }
+            None => self.client.cancel_all_orders().await.map(|response| {
+                response
+                    .details
+                    .cancelled_orders
+                    .into_iter()
+                    .map(|id| OrderCanceled { id: id.to_string() })
+                    .collect()
+            }),
+        }
+    }
+
+    async fn get_all_open_orders(&self) -> Result<Vec<Order>> {
+        self.client
+            .active_orders()
+            .await
+            .map(|v| self.exchange_info.translate(v.into_iter().map(Into::into).collect()))
+    }
+
+    async fn get_order_history(&self, req: &GetOrderHistoryRequest) -> Result<Vec<Order>> {
+        let paginator = req.paginator.clone().unwrap_or_default();
+        let request = model::HistoryRequest {
+            symbol: req.market_pair.clone(),
+            limit_orders: paginator.limit,
+            limit_trades: None,
+            timestamp: paginator.start_time,
+        };
+        self.client
+            .orders_history(&request)
+            .await
+            .map(|v| self.exchange_info.translate(v.into_iter().map(Into::into).collect()))
+    }
+
+    async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>> {
+        let market_pair = required_market_pair(req.market_pair.as_ref())?;
+        let paginator = req.paginator.clone().unwrap_or_default();
+        let request = model::HistoryRequest {
+            symbol: Some(market_pair.clone()),
+            limit_orders: None,
+            limit_trades: paginator.limit,
+            timestamp: paginator.start_time,
+        };
+        let trades = self.client.past_trades(&request).await?;
+
+        let trades: Vec<Trade> = trades
+            .into_iter()
+            .filter(|trade| match req.order_id.as_ref() {
+                Some(order_id) => &trade.order_id == order_id,
+                None => true,
+            })
+            .map(|trade| account_trade(market_pair.clone(), trade))
+            .collect();
+        Ok(self.exchange_info.translate(trades))
+    }
+
+    async fn get_account_balances(&self, _paginator: Option<Paginator>) -> Result<Vec<Balance>> {
+        self.client
+            .balances()
+            .await
+            .map(|v| v.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_order(&self, req: &GetOrderRequest) -> Result<Order> {
+        let order_id = parse_order_id(&req.id)?;
+        self.client
+            .order_status(order_id)
+            .await
+            .map(|order: Order| self.exchange_info.translate(order))
+    }
+}
+
+fn market_orders_unsupported() -> OpenLimitsError {
+    OpenLimitsError::MissingImplementation(MissingImplementationContent {
+        message: String::from(
+            "Gemini doesn't support market orders, use an immediate or cancel limit order",
+        ),
+    })
+}
+
+/// Gemini order ids are numbers
+fn parse_order_id(id: &str) -> Result<u64> {
+    id.parse::<u64>()
+        .map_err(|_| OpenLimitsError::InvalidParameter(format!("{} is not a gemini order id", id)))
+}
+
+/// The past trades are only returned for one symbol
+fn required_market_pair(market_pair: Option<&String>) -> Result<String> {
+    market_pair.cloned().ok_or_else(|| {
+        OpenLimitsError::MissingParameter("market_pair parameter is required.".to_string())
+    })
+}
+
+fn order_options(tif: TimeInForce, post_only: bool) -> Result<Vec<String>> {
+    match tif {
+        TimeInForce::GoodTillCancelled if post_only => {
+            Ok(vec![model::ORDER_OPTION_MAKER_OR_CANCEL.to_string()])
+        }
+        TimeInForce::GoodTillCancelled => Ok(Vec::new()),
+        TimeInForce::ImmediateOrCancelled => {
+            Ok(vec![model::ORDER_OPTION_IMMEDIATE_OR_CANCEL.to_string()])
+        }
+        TimeInForce::FillOrKill => Ok(vec![model::ORDER_OPTION_FILL_OR_KILL.to_string()]),
+        TimeInForce::GoodTillTime(_) => Err(OpenLimitsError::InvalidParameter(
+            "GoodTillTime is not supported in Gemini".to_string(),
+        )),
+    }
+}
+
+fn public_trade(market_pair: String, trade: model::Trade) -> Trade {
+    Trade {
+        id: trade.tid.to_string(),
+        buyer_order_id: None,
+        seller_order_id: None,
+        market_pair,
+        instrument: None,
+        price: trade.price,
+        qty: trade.amount,
+        fees: None,
+        side: Side::from(trade.trade_type),
+        liquidity: None,
+        created_at: trade.timestampms,
+    }
+}
+
+fn account_trade(market_pair: String, trade: model::AccountTrade) -> Trade {
+    // The side of the account trades is capitalized
+    let side = Side::from(trade.trade_type.to_lowercase());
+    let (buyer_order_id, seller_order_id) = match side {
+        Side::Buy => (Some(trade.order_id), None),
+        Side::Sell => (None, Some(trade.order_id)),
+    };
+
+    Trade {
+        id: trade.tid.to_string(),
+        buyer_order_id,
+        seller_order_id,
+        market_pair: trade.symbol.unwrap_or(market_pair),
+        instrument: None,
+        price: trade.price,
+        qty: trade.amount,
+        fees: Some(trade.fee_amount),
+        side,
+        liquidity: match trade.aggressor {
+            true => Some(Liquidity::Taker),
+            false => Some(Liquidity::Maker),
+        },
+        created_at: trade.timestampms,
+    }
+}
+
+impl From<model::SymbolDetails> for MarketPair {
+    fn from(details: model::SymbolDetails) -> Self {
+        Self {
+            symbol: details.symbol,
+            base: details.base_currency,
+            quote: details.quote_currency,
+            base_increment: details.tick_size,
+            quote_increment: details.quote_increment,
+            min_base_trade_size: Some(details.min_order_size),
+            status: match details.status.as_str() {
+                "open" | "limit_only" => MarketStatus::Trading,
+                "post_only" => MarketStatus::PostOnly,
+                "cancel_only" => MarketStatus::CancelOnly,
+                _ => MarketStatus::Halted,
+            },
+            order_types: match details.status.as_str() {
+                "limit_only" | "post_only" => vec![OrderType::Limit],
+                _ => Vec::new(),
+            },
+            ..Default::default()
+        }
+    }
+}
+
+impl From<model::BookEntry> for AskBid {
+    fn from(entry: model::BookEntry) -> Self {
+        Self {
+            price: entry.price,
+            qty: entry.amount,
+        }
+    }
+}
+
+impl From<model::Book> for OrderBookResponse {
+    fn from(book: model::Book) -> Self {
+        Self {
+            market_pair: None,
+            instrument: None,
+            update_id: None,
+            last_update_id: None,
+            bids: book.bids.into_iter().map(Into::into).collect(),
+            asks: book.asks.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<model::Ticker> for Ticker {
+    fn from(ticker: model::Ticker) -> Self {
+        Self {
+            market_pair: None,
+            instrument: None,
+            price: ticker.close,
+            price_24h: ticker.open,
+        }
+    }
+}
+
+impl From<model::Candle> for Candle {
+    fn from(candle: model::Candle) -> Self {
+        Self {
+            market_pair: None,
+            instrument: None,
+            time: candle.time,
+            low: candle.low,
+            high: candle.high,
+            open: candle.open,
+            close: candle.close,
+            volume: candle.volume,
+        }
+    }
+}
+
+impl From<model::Order> for Order {
+    fn from(order: model::Order) -> Self {
+        let order_type = if order.order_type.contains("limit") {
+            OrderType::Limit
+        } else if order.order_type.contains("market") {
+            OrderType::Market
+        } else {
+            OrderType::Unknown
+        };
+        let status = if order.is_live {
+            match order.executed_amount.is_zero() {
+                true => OrderStatus::Open,
+                false => OrderStatus::PartiallyFilled,
+            }
+        } else if order.is_cancelled {
+            OrderStatus::Canceled
+        } else {
+            OrderStatus::Filled
+        };
+        let size = order
+            .original_amount
+            .unwrap_or_else(|| order.executed_amount + order.remaining_amount.unwrap_or_default());
+
+        Self {
+            id: order.order_id,
+            market_pair: order.symbol,
+            instrument: None,
+            client_order_id: order.client_order_id,
+            created_at: Some(order.timestampms),
+            order_type,
+            side: Side::from(order.side),
+            status,
+            size,
+            price: order.price,
+            remaining: order.remaining_amount,
+            trades: Vec::new(),
+        }
+    }
+}
+
+impl From<model::Order> for OrderCanceled {
+    fn from(order: model::Order) -> Self {
+        Self { id: order.order_id }
+    }
+}
+
+impl From<model::Balance> for Balance {
+    fn from(balance: model::Balance) -> Self {
+        Self {
+            asset: balance.currency,
+            free: balance.available,
+            total: balance.amount,
+            locked: balance.amount - balance.available,
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<Interval> for model::TimeFrame {
+    type Error = OpenLimitsError;
+
+    fn try_from(interval: Interval) -> Result<Self> {
+        match interval {
+            Interval::OneMinute => Ok(model::TimeFrame::OneMinute),
+            Interval::FiveMinutes => Ok(model::TimeFrame::FiveMinutes),
+            Interval::FifteenMinutes => Ok(model::TimeFrame::FifteenMinutes),
+            Interval::ThirtyMinutes => Ok(model::TimeFrame::ThirtyMinutes),
+            Interval::OneHour => Ok(model::TimeFrame::OneHour),
+            Interval::SixHours => Ok(model::TimeFrame::SixHours),
+            Interval::OneDay => Ok(model::TimeFrame::OneDay),
+            _ => Err(OpenLimitsError::InvalidParameter(format!(
+                "{:?} is not supported in Gemini",
+                interval,
+            ))),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents a trade of the account, `trade_type` is either `Buy` or `Sell`
+/// and `aggressor` is set when the order took liquidity
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccountTrade {
+    pub tid: u64,
+    pub order_id: String,
+    #[serde(default)]
+    pub symbol: Option<String>,
+    #[serde(with = "string_to_decimal")]
+    pub price: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub amount: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub fee_amount: Decimal,
+    pub fee_currency: String,
+    #[serde(rename = "type")]
+    pub trade_type: String,
+    pub aggressor: bool,
+    pub timestampms: u64,
+}
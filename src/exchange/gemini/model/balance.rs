@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents the balance of one currency
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Balance {
+    pub currency: String,
+    #[serde(with = "string_to_decimal")]
+    pub amount: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub available: Decimal,
+}
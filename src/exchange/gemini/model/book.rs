@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::BookEntry;
+
+/// This struct represents the order book
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Book {
+    pub bids: Vec<BookEntry>,
+    pub asks: Vec<BookEntry>,
+}
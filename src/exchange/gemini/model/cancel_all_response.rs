@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents the result of canceling every order
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CancelAllResponse {
+    pub result: String,
+    pub details: CancelAllDetails,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllDetails {
+    pub cancelled_orders: Vec<u64>,
+    pub cancel_rejects: Vec<u64>,
+}
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+
+/// This struct represents a candle `[time, open, high, low, close, volume]`,
+/// the time is in milliseconds.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Candle {
+    pub time: u64,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    pub volume: Decimal,
+}
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct filters the past orders and trades, `timestamp` is the time in milliseconds
+/// from which the records are returned. Orders are limited by `limit_orders` and trades by
+/// `limit_trades`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct HistoryRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_orders: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_trades: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
+}
@@ -0,0 +1,40 @@
+//! This module provides models that are used in the gemini module
+
+pub const ORDER_SIDE_BUY: &str = "buy";
+pub const ORDER_SIDE_SELL: &str = "sell";
+pub const ORDER_TYPE_EXCHANGE_LIMIT: &str = "exchange limit";
+pub const ORDER_OPTION_MAKER_OR_CANCEL: &str = "maker-or-cancel";
+pub const ORDER_OPTION_IMMEDIATE_OR_CANCEL: &str = "immediate-or-cancel";
+pub const ORDER_OPTION_FILL_OR_KILL: &str = "fill-or-kill";
+
+mod account_trade;
+mod balance;
+mod book;
+mod book_entry;
+mod cancel_all_response;
+mod candle;
+mod history_request;
+mod new_order_request;
+mod order;
+mod order_id_request;
+mod symbol_details;
+mod ticker;
+mod time_frame;
+mod trade;
+pub mod websocket;
+
+pub use account_trade::AccountTrade;
+pub use balance::Balance;
+pub use book::Book;
+pub use book_entry::BookEntry;
+pub use cancel_all_response::{CancelAllDetails, CancelAllResponse};
+pub use candle::Candle;
+pub use history_request::HistoryRequest;
+pub use new_order_request::NewOrderRequest;
+pub use order::Order;
+pub use order_id_request::OrderIdRequest;
+pub use symbol_details::SymbolDetails;
+pub use ticker::Ticker;
+pub use time_frame::TimeFrame;
+pub use trade::Trade;
+pub use super::shared;
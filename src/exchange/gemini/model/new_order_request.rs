@@ -0,0 +1,22 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents an order submission, gemini only accepts limit orders.
+/// The time in force is set with `options`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NewOrderRequest {
+    pub client_order_id: String,
+    pub symbol: String,
+    #[serde(with = "string_to_decimal")]
+    pub amount: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub price: Decimal,
+    pub side: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub options: Vec<String>,
+}
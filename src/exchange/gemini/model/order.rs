@@ -0,0 +1,35 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+use super::shared::string_to_opt_decimal;
+
+/// This struct represents the status of an order
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Order {
+    pub order_id: String,
+    #[serde(default)]
+    pub client_order_id: Option<String>,
+    pub symbol: String,
+    pub side: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub timestampms: u64,
+    pub is_live: bool,
+    pub is_cancelled: bool,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub price: Option<Decimal>,
+    #[serde(with = "string_to_decimal")]
+    pub avg_execution_price: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub executed_amount: Decimal,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub remaining_amount: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub original_amount: Option<Decimal>,
+    #[serde(default)]
+    pub options: Vec<String>,
+}
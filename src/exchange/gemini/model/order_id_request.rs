@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct identifies an order, gemini order ids are numbers
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderIdRequest {
+    pub order_id: u64,
+}
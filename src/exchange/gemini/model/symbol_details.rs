@@ -0,0 +1,18 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents the details of a symbol. `tick_size` is the increment of the
+/// amounts and `quote_increment` the increment of the prices.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SymbolDetails {
+    pub symbol: String,
+    pub base_currency: String,
+    pub quote_currency: String,
+    pub tick_size: Decimal,
+    pub quote_increment: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub min_order_size: Decimal,
+    pub status: String,
+}
@@ -0,0 +1,28 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_opt_decimal;
+
+/// This struct represents the v2 ticker, `open` is the price 24 hours ago
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Ticker {
+    pub symbol: String,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub open: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub high: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub low: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub close: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub bid: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub ask: Option<Decimal>,
+}
@@ -0,0 +1,37 @@
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt;
+
+/// This enum represents the candle time frames supported by gemini
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeFrame {
+    #[serde(rename = "1m")]
+    OneMinute,
+    #[serde(rename = "5m")]
+    FiveMinutes,
+    #[serde(rename = "15m")]
+    FifteenMinutes,
+    #[serde(rename = "30m")]
+    ThirtyMinutes,
+    #[serde(rename = "1hr")]
+    OneHour,
+    #[serde(rename = "6hr")]
+    SixHours,
+    #[serde(rename = "1day")]
+    OneDay,
+}
+
+impl fmt::Display for TimeFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            TimeFrame::OneMinute => "1m",
+            TimeFrame::FiveMinutes => "5m",
+            TimeFrame::FifteenMinutes => "15m",
+            TimeFrame::ThirtyMinutes => "30m",
+            TimeFrame::OneHour => "1hr",
+            TimeFrame::SixHours => "6hr",
+            TimeFrame::OneDay => "1day",
+        };
+        write!(f, "{}", s)
+    }
+}
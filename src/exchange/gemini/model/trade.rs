@@ -0,0 +1,17 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents a public trade, `trade_type` is the taker side
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Trade {
+    pub timestampms: u64,
+    pub tid: u64,
+    #[serde(with = "string_to_decimal")]
+    pub price: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub amount: Decimal,
+    #[serde(rename = "type")]
+    pub trade_type: String,
+}
@@ -0,0 +1,18 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents the fill of an order event
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FillEvent {
+    pub trade_id: String,
+    pub liquidity: String,
+    #[serde(with = "string_to_decimal")]
+    pub price: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub amount: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub fee: Decimal,
+    pub fee_currency: String,
+}
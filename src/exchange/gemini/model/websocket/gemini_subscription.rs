@@ -0,0 +1,27 @@
+use crate::model::websocket::Subscription;
+
+/// This enum represents a gemini subscription
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GeminiSubscription {
+    /// Symbol, order book snapshot followed by its updates and the trades
+    L2(String),
+    /// Order events of the account, served by their own authenticated endpoint
+    OrderEvents,
+    /// A subscription gemini has no stream for, creating a stream with it fails
+    Unsupported(Subscription),
+}
+
+impl From<Subscription> for GeminiSubscription {
+    fn from(subscription: Subscription) -> Self {
+        match subscription {
+            // The market data api wants the symbols upper case, the REST api lower case
+            Subscription::OrderBookUpdates(symbol) | Subscription::Trades(symbol) => {
+                GeminiSubscription::L2(symbol.to_uppercase())
+            }
+            Subscription::AccountOrders(_) | Subscription::AccountTrades(_) => {
+                GeminiSubscription::OrderEvents
+            }
+            subscription => GeminiSubscription::Unsupported(subscription),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use serde::Serialize;
+use serde_json::Value;
+use super::L2Update;
+use super::OrderEvent;
+use super::TradeEvent;
+
+/// This enum represents the types of websocket messages
+#[derive(Debug, Clone, Serialize)]
+pub enum GeminiWebsocketMessage {
+    /// The first update of a symbol, it holds the whole book
+    L2Snapshot(L2Update),
+    L2Update(L2Update),
+    Trade(TradeEvent),
+    OrderEvents(Vec<OrderEvent>),
+    SubscriptionAck(Value),
+    Heartbeat,
+    Unknown(Value),
+    Ping,
+    Pong,
+    Close,
+    Binary(Vec<u8>), // Unexpected, unparsed
+}
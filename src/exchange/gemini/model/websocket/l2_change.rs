@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents an order book change `[side, price, quantity]`,
+/// a zero quantity removes the level
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct L2Change {
+    pub side: String,
+    #[serde(with = "string_to_decimal")]
+    pub price: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub quantity: Decimal,
+}
@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::L2Change;
+use super::TradeEvent;
+
+/// This struct represents the changes of an order book, the first update of every symbol
+/// is the whole book along with the last trades
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct L2Update {
+    pub symbol: String,
+    pub changes: Vec<L2Change>,
+    #[serde(default)]
+    pub trades: Vec<TradeEvent>,
+}
@@ -0,0 +1,20 @@
+//! Websocket model
+
+mod fill_event;
+mod gemini_subscription;
+mod gemini_websocket_message;
+mod l2_change;
+mod l2_update;
+mod order_event;
+mod subscribe_request;
+mod trade_event;
+
+pub use fill_event::FillEvent;
+pub use gemini_subscription::GeminiSubscription;
+pub use gemini_websocket_message::GeminiWebsocketMessage;
+pub use l2_change::L2Change;
+pub use l2_update::L2Update;
+pub use order_event::OrderEvent;
+pub use subscribe_request::{SubscribeRequest, SubscriptionItem};
+pub use trade_event::TradeEvent;
+pub use super::shared;
@@ -0,0 +1,37 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_opt_decimal;
+use super::FillEvent;
+
+/// This struct represents an event of one of the account orders,
+/// `event_type` is one of `initial`, `accepted`, `rejected`, `booked`, `fill`, `cancelled`,
+/// `cancel_rejected` or `closed`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderEvent {
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub order_id: String,
+    #[serde(default)]
+    pub client_order_id: Option<String>,
+    pub symbol: String,
+    pub side: String,
+    pub order_type: String,
+    pub timestampms: u64,
+    pub is_live: bool,
+    pub is_cancelled: bool,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub price: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub executed_amount: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub remaining_amount: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub original_amount: Option<Decimal>,
+    #[serde(default)]
+    pub fill: Option<FillEvent>,
+}
@@ -0,0 +1,28 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents a market data v2 subscription request
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SubscribeRequest {
+    #[serde(rename = "type")]
+    pub request_type: String,
+    pub subscriptions: Vec<SubscriptionItem>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SubscriptionItem {
+    pub name: String,
+    pub symbols: Vec<String>,
+}
+
+impl SubscribeRequest {
+    pub fn l2(symbols: Vec<String>) -> Self {
+        Self {
+            request_type: String::from("subscribe"),
+            subscriptions: vec![SubscriptionItem {
+                name: String::from("l2"),
+                symbols,
+            }],
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents a public trade, the side is the taker side
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TradeEvent {
+    pub symbol: String,
+    pub event_id: u64,
+    pub timestamp: u64,
+    #[serde(with = "string_to_decimal")]
+    pub price: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub quantity: Decimal,
+    pub side: String,
+}
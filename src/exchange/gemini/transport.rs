@@ -0,0 +1,210 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use chrono::Utc;
+use hex::encode as hexify;
+use hmac::{Hmac, Mac, NewMac};
+use reqwest::header;
+use reqwest::Response;
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+use sha2::Sha384;
+use url::Url;
+use crate::exchange::gemini::GeminiContentError;
+use crate::errors::OpenLimitsError;
+use super::shared::Result;
+
+type HmacSha384 = Hmac<Sha384>;
+
+#[derive(Clone, Debug)]
+pub struct Transport {
+    credential: Option<(String, String)>,
+    client: reqwest::Client,
+    last_nonce: Arc<AtomicU64>,
+    base_url: String,
+}
+
+impl Transport {
+    pub fn new(sandbox: bool) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .default_headers(Transport::default_headers())
+            .build()?;
+
+        Ok(Transport {
+            credential: None,
+            client,
+            last_nonce: Arc::new(AtomicU64::new(0)),
+            base_url: Transport::get_base_url(sandbox),
+        })
+    }
+
+    pub fn with_credential(api_key: &str, api_secret: &str, sandbox: bool) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .default_headers(Transport::default_headers())
+            .build()?;
+
+        Ok(Transport {
+            credential: Some((api_key.into(), api_secret.into())),
+            client,
+            last_nonce: Arc::new(AtomicU64::new(0)),
+            base_url: Transport::get_base_url(sandbox),
+        })
+    }
+
+    pub fn default_headers() -> header::HeaderMap<header::HeaderValue> {
+        let mut headers = header::HeaderMap::new();
+        headers.insert(
+            header::USER_AGENT,
+            header::HeaderValue::from_static("openlimit"),
+        );
+        headers.insert(
+            header::CONTENT_TYPE,
+            header::HeaderValue::from_static("text/plain"),
+        );
+        headers.insert(
+            header::CACHE_CONTROL,
+            header::HeaderValue::from_static("no-cache"),
+        );
+
+        headers
+    }
+
+    fn get_base_url(sandbox: bool) -> String {
+        if sandbox {
+            String::from("https://api.sandbox.gemini.com")
+        } else {
+            String::from("https://api.gemini.com")
+        }
+    }
+
+    pub async fn get<O, S>(&self, endpoint: &str, params: Option<&S>) -> Result<O>
+    where
+        O: DeserializeOwned,
+        S: Serialize,
+    {
+        let url = self.get_url(endpoint, params)?;
+        let response = self.client.get(url).send().await?;
+
+        self.response_handler(response).await
+    }
+
+    /// The parameters of the private endpoints are sent in the signed payload header, the body
+    /// is always empty.
+    pub async fn signed_post<O, D>(&self, endpoint: &str, data: Option<&D>) -> Result<O>
+    where
+        O: DeserializeOwned,
+        D: Serialize,
+    {
+        let url = self.get_url::<()>(endpoint, None)?;
+        let (key, payload, signature) = self.signed_payload(endpoint, data)?;
+
+        let response = self
+            .client
+            .post(url)
+            .header(header::CONTENT_LENGTH, 0)
+            .header("X-GEMINI-APIKEY", key)
+            .header("X-GEMINI-PAYLOAD", payload)
+            .header("X-GEMINI-SIGNATURE", signature)
+            .send()
+            .await?;
+
+        self.response_handler(response).await
+    }
+
+    /// Returns the api key, the base64 encoded payload and its signature.
+    /// The payload is the JSON of the parameters with the `request` path and a `nonce`.
+    pub fn signed_payload<D>(&self, endpoint: &str, data: Option<&D>) -> Result<(&str, String, String)>
+    where
+        D: Serialize,
+    {
+        let (key, secret) = self.check_key()?;
+        let mut payload = match data {
+            Some(data) => serde_json::to_value(data)?,
+            None => json!({}),
+        };
+        if let Value::Object(ref mut map) = payload {
+            map.insert(String::from("request"), Value::from(endpoint));
+            map.insert(String::from("nonce"), Value::from(self.nonce()));
+        }
+        let payload = base64::encode(serde_json::to_string(&payload)?);
+        let signature = sign(secret, &payload);
+
+        Ok((key, payload, signature))
+    }
+
+    pub fn get_url<Q>(&self, endpoint: &str, params: Option<&Q>) -> Result<Url>
+    where
+        Q: Serialize,
+    {
+        let url = format!("{}{}", self.base_url, endpoint);
+
+        let mut url = Url::parse(&url)?;
+
+        if params.is_some() {
+            let query = serde_urlencoded::to_string(params)?;
+            if !query.is_empty() {
+                url.set_query(Some(&query));
+            }
+        };
+
+        Ok(url)
+    }
+
+    /// Gemini requires every private call to carry an increasing nonce,
+    /// concurrent requests issued in the same millisecond are bumped by one.
+    pub fn nonce(&self) -> u64 {
+        let now = Utc::now().timestamp_millis() as u64;
+        let mut last = self.last_nonce.load(Ordering::SeqCst);
+        loop {
+            let next = if now > last { now } else { last + 1 };
+            match self.last_nonce.compare_exchange(last, next, Ordering::SeqCst, Ordering::SeqCst) {
+                Ok(_) => return next,
+                Err(current) => last = current,
+            }
+        }
+    }
+
+    fn check_key(&self) -> Result<(&str, &str)> {
+        match self.credential.as_ref() {
+            None => Err(OpenLimitsError::NoApiKeySet()),
+            Some((k, s)) => Ok((k, s)),
+        }
+    }
+
+    async fn response_handler<O>(&self, response: Response) -> Result<O>
+    where
+        O: DeserializeOwned,
+    {
+        let status = response.status();
+        let text = response.text().await?;
+
+        if status == StatusCode::OK {
+            return serde_json::from_str::<O>(&text).map_err(|err| {
+                OpenLimitsError::NotParsableResponse(format!("Error:{} Payload: {}", err, text))
+            });
+        }
+
+        if let Ok(error) = serde_json::from_str::<GeminiContentError>(&text) {
+            return Err(OpenLimitsError::GeminiError(error));
+        }
+
+        match status {
+            StatusCode::INTERNAL_SERVER_ERROR => Err(OpenLimitsError::InternalServerError()),
+            StatusCode::SERVICE_UNAVAILABLE => Err(OpenLimitsError::ServiceUnavailable()),
+            StatusCode::UNAUTHORIZED => Err(OpenLimitsError::Unauthorized()),
+            s => Err(OpenLimitsError::UnkownResponse(format!(
+                "Received response: {:?}, value: {}",
+                s, text
+            ))),
+        }
+    }
+}
+
+/// Hex encoded HMAC-SHA384 of the base64 payload.
+pub fn sign(secret: &str, payload: &str) -> String {
+    let mut mac =
+        HmacSha384::new_varkey(secret.as_bytes()).expect("Couldn't construct hmac from bytes.");
+    mac.update(payload.as_bytes());
+    hexify(mac.finalize().into_bytes())
+}
@@ -0,0 +1,150 @@
+use chrono::Utc;
+use rust_decimal::prelude::*;
+use serde_json::json;
+use crate::exchange::kucoin::model::{
+    Account, BulletToken, CancelledOrders, Fill, FillFilter, Order, OrderFilter, OrderPlaced,
+    OrderRequest, Page, TimeInForce, ORDER_SIDE_BUY, ORDER_SIDE_SELL, ORDER_TYPE_LIMIT,
+    ORDER_TYPE_MARKET,
+};
+use crate::exchange::traits::info::MarketPair;
+use super::BaseClient;
+use super::shared::Result;
+
+impl BaseClient {
+    pub async fn accounts(&self, account_type: Option<&str>) -> Result<Vec<Account>> {
+        let params = account_type.map(|account_type| json! {{"type": account_type}});
+        self.transport
+            .signed_get("/api/v1/accounts", params.as_ref())
+            .await
+    }
+
+    pub async fn orders(&self, filter: &OrderFilter) -> Result<Page<Order>> {
+        self.transport.signed_get("/api/v1/orders", Some(filter)).await
+    }
+
+    pub async fn order(&self, order_id: &str) -> Result<Order> {
+        self.transport
+            .signed_get::<_, ()>(&format!("/api/v1/orders/{}", order_id), None)
+            .await
+    }
+
+    pub async fn fills(&self, filter: &FillFilter) -> Result<Page<Fill>> {
+        self.transport.signed_get("/api/v1/fills", Some(filter)).await
+    }
+
+    pub async fn cancel_order(&self, order_id: &str) -> Result<CancelledOrders> {
+        self.transport
+            .signed_delete::<_, ()>(&format!("/api/v1/orders/{}", order_id), None)
+            .await
+    }
+
+    // Cancels the open orders of ONE symbol, or of all of them
+    pub async fn cancel_all_orders(&self, symbol: Option<&str>) -> Result<CancelledOrders> {
+        let params = symbol.map(|symbol| json! {{"symbol": symbol}});
+        self.transport
+            .signed_delete("/api/v1/orders", params.as_ref())
+            .await
+    }
+
+    // Token and servers for a private websocket connection
+    pub async fn bullet_private(&self) -> Result<BulletToken> {
+        self.transport
+            .signed_post::<_, ()>("/api/v1/bullet-private", None)
+            .await
+    }
+
+    // Place a LIMIT order - BUY
+    pub async fn limit_buy(
+        &self,
+        pair: MarketPair,
+        size: Decimal,
+        price: Decimal,
+        time_in_force: TimeInForce,
+        cancel_after: Option<u64>,
+        post_only: bool,
+    ) -> Result<OrderPlaced> {
+        let data = OrderRequest {
+            client_oid: client_oid(),
+            side: ORDER_SIDE_BUY.to_string(),
+            symbol: pair.symbol,
+            order_type: ORDER_TYPE_LIMIT.to_string(),
+            size: size.round_dp(pair.base_increment.normalize().scale()),
+            price: Some(price.round_dp_with_strategy(
+                pair.quote_increment.normalize().scale(),
+                RoundingStrategy::ToZero,
+            )),
+            time_in_force: Some(time_in_force),
+            cancel_after,
+            post_only: Some(post_only),
+        };
+
+        self.transport.signed_post("/api/v1/orders", Some(&data)).await
+    }
+
+    // Place a LIMIT order - SELL
+    pub async fn limit_sell(
+        &self,
+        pair: MarketPair,
+        size: Decimal,
+        price: Decimal,
+        time_in_force: TimeInForce,
+        cancel_after: Option<u64>,
+        post_only: bool,
+    ) -> Result<OrderPlaced> {
+        let data = OrderRequest {
+            client_oid: client_oid(),
+            side: ORDER_SIDE_SELL.to_string(),
+            symbol: pair.symbol,
+            order_type: ORDER_TYPE_LIMIT.to_string(),
+            size: size.round_dp(pair.base_increment.normalize().scale()),
+            price: Some(price.round_dp_with_strategy(
+                pair.quote_increment.normalize().scale(),
+                RoundingStrategy::AwayFromZero,
+            )),
+            time_in_force: Some(time_in_force),
+            cancel_after,
+            post_only: Some(post_only),
+        };
+
+        self.transport.signed_post("/api/v1/orders", Some(&data)).await
+    }
+
+    // Place a MARKET order - BUY
+    pub async fn market_buy(&self, pair: MarketPair, size: Decimal) -> Result<OrderPlaced> {
+        let data = OrderRequest {
+            client_oid: client_oid(),
+            side: ORDER_SIDE_BUY.to_string(),
+            symbol: pair.symbol,
+            order_type: ORDER_TYPE_MARKET.to_string(),
+            size: size.round_dp(pair.base_increment.normalize().scale()),
+            price: None,
+            time_in_force: None,
+            cancel_after: None,
+            post_only: None,
+        };
+
+        self.transport.signed_post("/api/v1/orders", Some(&data)).await
+    }
+
+    // Place a MARKET order - SELL
+    pub async fn market_sell(&self, pair: MarketPair, size: Decimal) -> Result<OrderPlaced> {
+        let data = OrderRequest {
+            client_oid: client_oid(),
+            side: ORDER_SIDE_SELL.to_string(),
+            symbol: pair.symbol,
+            order_type: ORDER_TYPE_MARKET.to_string(),
+            size: size.round_dp(pair.base_increment.normalize().scale()),
+            price: None,
+            time_in_force: None,
+            cancel_after: None,
+            post_only: None,
+        };
+
+        self.transport.signed_post("/api/v1/orders", Some(&data)).await
+    }
+}
+
+/// Kucoin requires a unique client order id on every order
+fn client_oid() -> String {
+    Utc::now().timestamp_nanos().to_string()
+}
@@ -0,0 +1,7 @@
+use super::Transport;
+
+/// The kucoin client
+#[derive(Clone)]
+pub struct BaseClient {
+    pub transport: Transport,
+}
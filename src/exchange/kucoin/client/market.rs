@@ -0,0 +1,52 @@
+use serde_json::json;
+use crate::exchange::kucoin::model::{
+    BulletToken, Candle, CandleRequestParams, OrderBook, Stats, Symbol, Ticker, Trade,
+};
+use super::shared::Result;
+use super::BaseClient;
+
+impl BaseClient {
+    pub async fn symbols(&self) -> Result<Vec<Symbol>> {
+        self.transport.get::<_, ()>("/api/v1/symbols", None).await
+    }
+
+    // Order book aggregated by price, the 100 best levels of each side
+    pub async fn order_book(&self, symbol: &str) -> Result<OrderBook> {
+        let params = json! {{"symbol": symbol}};
+        self.transport
+            .get("/api/v1/market/orderbook/level2_100", Some(&params))
+            .await
+    }
+
+    pub async fn ticker(&self, symbol: &str) -> Result<Ticker> {
+        let params = json! {{"symbol": symbol}};
+        self.transport
+            .get("/api/v1/market/orderbook/level1", Some(&params))
+            .await
+    }
+
+    // 24h statistics
+    pub async fn stats(&self, symbol: &str) -> Result<Stats> {
+        let params = json! {{"symbol": symbol}};
+        self.transport.get("/api/v1/market/stats", Some(&params)).await
+    }
+
+    pub async fn candles(&self, params: &CandleRequestParams) -> Result<Vec<Candle>> {
+        self.transport.get("/api/v1/market/candles", Some(params)).await
+    }
+
+    // The 100 last trades
+    pub async fn trades(&self, symbol: &str) -> Result<Vec<Trade>> {
+        let params = json! {{"symbol": symbol}};
+        self.transport
+            .get("/api/v1/market/histories", Some(&params))
+            .await
+    }
+
+    // Token and servers for a public websocket connection
+    pub async fn bullet_public(&self) -> Result<BulletToken> {
+        self.transport
+            .post::<_, ()>("/api/v1/bullet-public", None)
+            .await
+    }
+}
@@ -0,0 +1,344 @@
+use std::convert::TryFrom;
+use std::sync::Mutex;
+use std::time::Duration;
+use async_trait::async_trait;
+use chrono::Utc;
+use futures::{SinkExt, stream::BoxStream, StreamExt};
+use rust_decimal::prelude::Zero;
+use serde::{de, Deserialize};
+use serde_json::Value;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
+use crate::{
+    errors::OpenLimitsError,
+    exchange::kucoin::{
+        KucoinParameters,
+        client::BaseClient,
+        client::Transport,
+        model::websocket::{
+            KucoinSubscription, KucoinWebsocketMessage, Level2Change, OrderChange, Request,
+        },
+        model::{LIQUIDITY_MAKER, ORDER_TYPE_LIMIT, ORDER_TYPE_MARKET},
+    },
+    model::websocket::OpenLimitsWebSocketMessage,
+    model::websocket::WebSocketResponse,
+    model::{AskBid, Liquidity, Order, OrderBookResponse, OrderStatus, OrderType, Side, Trade},
+};
+use crate::exchange::traits::stream::{ExchangeWs, Subscriptions};
+use super::shared::Result;
+
+/// This struct is used for websocket communications with kucoin exchange.
+/// Kucoin doesn't have a fixed websocket endpoint, a bullet token is requested over REST
+/// before every connection and it comes with the server to connect to and the ping interval
+/// that keeps the connection alive.
+pub struct KucoinWebsocket {
+    client: BaseClient,
+    disconnection_senders: Mutex<Vec<UnboundedSender<()>>>,
+}
+
+#[async_trait]
+impl ExchangeWs for KucoinWebsocket {
+    type InitParams = KucoinParameters;
+    type Subscription = KucoinSubscription;
+    type Response = KucoinWebsocketMessage;
+
+    async fn new(parameters: Self::InitParams) -> Result<Self> {
+        let transport = match parameters.credentials {
+            Some(credentials) => Transport::with_credential(
+                &credentials.api_key,
+                &credentials.api_secret,
+                &credentials.passphrase,
+                parameters.sandbox,
+            )?,
+            None => Transport::new(parameters.sandbox)?,
+        };
+
+        Ok(KucoinWebsocket {
+            client: BaseClient { transport },
+            disconnection_senders: Default::default(),
+        })
+    }
+
+    async fn disconnect(&self) {
+        if let Ok(mut senders) = self.disconnection_senders.lock() {
+            for sender in senders.iter() {
+                sender.send(()).ok();
+            }
+            senders.clear();
+        }
+    }
+
+    async fn create_stream_specific(
+        &self,
+        subscriptions: Subscriptions<Self::Subscription>,
+    ) -> Result<BoxStream<'static, Result<Self::Response>>> {
+        let subscriptions: Vec<KucoinSubscription> = subscriptions.into_iter().collect();
+        let bullet = match subscriptions.iter().any(KucoinSubscription::is_private) {
+            true => self.client.bullet_private().await?,
+            false => self.client.bullet_public().await?,
+        };
+        let server = bullet.instance_servers.first().ok_or_else(|| {
+            OpenLimitsError::UnkownResponse("kucoin didn't return any websocket server".to_string())
+        })?;
+
+        let endpoint = url::Url::parse_with_params(
+            &server.endpoint,
+            &[("token", bullet.token.as_str()), ("connectId", &request_id())],
+        )
+        .map_err(OpenLimitsError::UrlParserError)?;
+        let (ws_stream, _) = connect_async(endpoint).await?;
+
+        let (mut sink, mut stream) = ws_stream.split();
+
+        // The server greets every connection, subscribing before the welcome message is
+        // received can be dropped silently.
+        loop {
+            match stream.next().await {
+                Some(Ok(message)) => {
+                    if let KucoinWebsocketMessage::Welcome = parse_message(message)? {
+                        break;
+                    }
+                }
+                _ => return Err(OpenLimitsError::SocketError()),
+            }
+        }
+
+        for subscription in subscriptions.iter() {
+            let request = Request::subscribe(request_id(), subscription);
+            sink.send(Message::Text(serde_json::to_string(&request)?))
+                .await?;
+        }
+
+        let ping_interval = Duration::from_millis(server.ping_interval);
+        let (disconnection_sender, mut disconnection_receiver) = unbounded_channel();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(ping_interval);
+            loop {
+                tokio::select! {
+                    _ = disconnection_receiver.recv() => {
+                        sink.close().await.ok();
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        let ping = match serde_json::to_string(&Request::ping(request_id())) {
+                            Ok(ping) => ping,
+                            Err(_) => break,
+                        };
+                        if sink.send(Message::Text(ping)).await.is_err() {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+
+        if let Ok(mut senders) = self.disconnection_senders.lock() {
+            senders.push(disconnection_sender);
+        }
+
+        let s = stream.map(|message| match message {
+            Ok(msg) => parse_message(msg),
+            Err(_) => Err(OpenLimitsError::SocketError()),
+        });
+
+        Ok(s.boxed())
+    }
+}
+
+#[derive(Deserialize)]
+struct KucoinWebsocketEvent {
+    #[serde(default)]
+    pub id: String,
+    #[serde(rename = "type")]
+    pub event_type: String,
+    #[serde(default)]
+    pub topic: String,
+    #[serde(default)]
+    pub subject: String,
+    #[serde(default)]
+    pub data: Value,
+}
+
+impl<'de> Deserialize<'de> for KucoinWebsocketMessage {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let event: KucoinWebsocketEvent =
+            serde_json::from_value(value.clone()).map_err(de::Error::custom)?;
+
+        match event.event_type.as_str() {
+            "welcome" => return Ok(KucoinWebsocketMessage::Welcome),
+            "ack" => return Ok(KucoinWebsocketMessage::Ack(event.id)),
+            "pong" => return Ok(KucoinWebsocketMessage::Pong),
+            "error" => return Ok(KucoinWebsocketMessage::Error(event.data.to_string())),
+            "message" => (),
+            _ => return Ok(KucoinWebsocketMessage::Unknown(value)),
+        }
+
+        if event.topic.starts_with("/market/level2:") {
+            Ok(KucoinWebsocketMessage::Level2(
+                serde_json::from_value(event.data).map_err(de::Error::custom)?,
+            ))
+        } else if event.topic.starts_with("/market/match:") {
+            Ok(KucoinWebsocketMessage::Match(
+                serde_json::from_value(event.data).map_err(de::Error::custom)?,
+            ))
+        } else if event.topic.starts_with("/market/ticker:") {
+            Ok(KucoinWebsocketMessage::Ticker {
+                symbol: event.topic.trim_start_matches("/market/ticker:").to_string(),
+                ticker: serde_json::from_value(event.data).map_err(de::Error::custom)?,
+            })
+        } else if event.topic.starts_with("/market/candles:") {
+            Ok(KucoinWebsocketMessage::Candle(
+                serde_json::from_value(event.data).map_err(de::Error::custom)?,
+            ))
+        } else if event.subject == "orderChange" {
+            Ok(KucoinWebsocketMessage::OrderChange(
+                serde_json::from_value(event.data).map_err(de::Error::custom)?,
+            ))
+        } else if event.subject == "account.balance" {
+            Ok(KucoinWebsocketMessage::BalanceChange(
+                serde_json::from_value(event.data).map_err(de::Error::custom)?,
+            ))
+        } else {
+            Ok(KucoinWebsocketMessage::Unknown(value))
+        }
+    }
+}
+
+impl TryFrom<KucoinWebsocketMessage> for WebSocketResponse<KucoinWebsocketMessage> {
+    type Error = OpenLimitsError;
+
+    fn try_from(value: KucoinWebsocketMessage) -> Result<Self> {
+        match value {
+            KucoinWebsocketMessage::Level2(update) => Ok(WebSocketResponse::Generic(
+                OpenLimitsWebSocketMessage::OrderBookDiff(OrderBookResponse {
+                    market_pair: Some(update.symbol),
+                    instrument: None,
+                    update_id: Some(update.sequence_end),
+                    last_update_id: Some(update.sequence_start),
+                    bids: update.changes.bids.into_iter().map(Into::into).collect(),
+                    asks: update.changes.asks.into_iter().map(Into::into).collect(),
+                }),
+            )),
+            KucoinWebsocketMessage::Match(trade) => {
+                let side = Side::from(trade.side);
+                let (buyer_order_id, seller_order_id) = match side {
+                    Side::Buy => (trade.taker_order_id, trade.maker_order_id),
+                    Side::Sell => (trade.maker_order_id, trade.taker_order_id),
+                };
+                Ok(WebSocketResponse::Generic(OpenLimitsWebSocketMessage::Trades(
+                    vec![Trade {
+                        id: trade.trade_id,
+                        buyer_order_id: Some(buyer_order_id),
+                        seller_order_id: Some(seller_order_id),
+                        market_pair: trade.symbol,
+                        instrument: None,
+                        price: trade.price,
+                        qty: trade.size,
+                        fees: None,
+                        side,
+                        liquidity: None,
+                        created_at: trade.time / 1_000_000,
+                    }],
+                )))
+            }
+            KucoinWebsocketMessage::OrderChange(change) => Ok(WebSocketResponse::Generic(
+                OpenLimitsWebSocketMessage::Orders(vec![Order::from(change)]),
+            )),
+            KucoinWebsocketMessage::Ping | KucoinWebsocketMessage::Pong => {
+                Ok(WebSocketResponse::Generic(OpenLimitsWebSocketMessage::Ping))
+            }
+            KucoinWebsocketMessage::Close => Err(OpenLimitsError::SocketError()),
+            _ => Ok(WebSocketResponse::Raw(value)),
+        }
+    }
+}
+
+impl From<Level2Change> for AskBid {
+    fn from(change: Level2Change) -> Self {
+        Self {
+            price: change.price,
+            qty: change.size,
+        }
+    }
+}
+
+/// A `match` change carries the trade that filled the order
+impl From<OrderChange> for Order {
+    fn from(change: OrderChange) -> Self {
+        let order_type = match change.order_type.as_str() {
+            ORDER_TYPE_LIMIT => OrderType::Limit,
+            ORDER_TYPE_MARKET => OrderType::Market,
+            _ => OrderType::Unknown,
+        };
+        let status = match change.change_type.as_str() {
+            "canceled" => OrderStatus::Canceled,
+            "filled" => OrderStatus::Filled,
+            _ if change.remain_size.is_zero() && !change.filled_size.is_zero() => {
+                OrderStatus::Filled
+            }
+            _ if change.filled_size.is_zero() => OrderStatus::Open,
+            _ => OrderStatus::PartiallyFilled,
+        };
+        let side = Side::from(change.side);
+        let trade = match (change.trade_id, change.match_price, change.match_size) {
+            (Some(id), Some(price), Some(qty)) => {
+                let (buyer_order_id, seller_order_id) = match side {
+                    Side::Buy => (Some(change.order_id.clone()), None),
+                    Side::Sell => (None, Some(change.order_id.clone())),
+                };
+                Some(Trade {
+                    id,
+                    buyer_order_id,
+                    seller_order_id,
+                    market_pair: change.symbol.clone(),
+                    instrument: None,
+                    price,
+                    qty,
+                    fees: None,
+                    side: side.clone(),
+                    liquidity: change.liquidity.map(|liquidity| match liquidity.as_str() {
+                        LIQUIDITY_MAKER => Liquidity::Maker,
+                        _ => Liquidity::Taker,
+                    }),
+                    created_at: change.ts / 1_000_000,
+                })
+            }
+            _ => None,
+        };
+
+        Self {
+            id: change.order_id,
+            market_pair: change.symbol,
+            instrument: None,
+            client_order_id: change.client_oid,
+            created_at: Some(change.order_time / 1_000_000),
+            order_type,
+            side,
+            status,
+            size: change.size,
+            price: change.price,
+            remaining: Some(change.remain_size),
+            trades: trade.into_iter().collect(),
+        }
+    }
+}
+
+fn request_id() -> String {
+    Utc::now().timestamp_nanos().to_string()
+}
+
+fn parse_message(ws_message: Message) -> Result<KucoinWebsocketMessage> {
+    let msg = match ws_message {
+        Message::Text(m) => m,
+        Message::Binary(b) => return Ok(KucoinWebsocketMessage::Binary(b)),
+        Message::Pong(..) => return Ok(KucoinWebsocketMessage::Pong),
+        Message::Ping(..) => return Ok(KucoinWebsocketMessage::Ping),
+        Message::Close(..) => return Ok(KucoinWebsocketMessage::Close),
+    };
+
+    serde_json::from_str(&msg).map_err(OpenLimitsError::JsonError)
+}
@@ -0,0 +1,18 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thiserror::Error;
+use std::fmt;
+
+/// This struct represents the kucoin content errors
+#[derive(Serialize, Deserialize, Debug, Error)]
+pub struct KucoinContentError {
+    pub code: String,
+    #[serde(default)]
+    pub msg: String,
+}
+
+impl fmt::Display for KucoinContentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "error code: {} msg: {}", self.code, self.msg)
+    }
+}
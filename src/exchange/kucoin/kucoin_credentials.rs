@@ -0,0 +1,7 @@
+/// This struct represents the kucoin credentials
+#[derive(Clone)]
+pub struct KucoinCredentials {
+    pub api_key: String,
+    pub api_secret: String,
+    pub passphrase: String,
+}
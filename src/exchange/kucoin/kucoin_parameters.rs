@@ -0,0 +1,32 @@
+use crate::exchange::traits::info::ExchangeInfoCache;
+use super::KucoinCredentials;
+
+/// This struct represents the kucoin parameters.
+/// The exchange information is read from `exchange_info_cache` when there's one.
+#[derive(Default, Clone)]
+pub struct KucoinParameters {
+    pub sandbox: bool,
+    pub credentials: Option<KucoinCredentials>,
+    pub exchange_info_cache: Option<ExchangeInfoCache>,
+}
+
+impl KucoinParameters {
+    pub fn sandbox() -> Self {
+        Self {
+            sandbox: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn prod() -> Self {
+        Self {
+            sandbox: false,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_exchange_info_cache(mut self, cache: ExchangeInfoCache) -> Self {
+        self.exchange_info_cache = Some(cache);
+        self
+    }
+}
@@ -0,0 +1,508 @@
+//! This module provides functionality for communicating with the kucoin API.
+//! # Example
+//! ```
+//! use openlimits::exchange::kucoin::Kucoin;
+//! use openlimits::exchange::kucoin::KucoinParameters;
+//! use openlimits::prelude::*;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let kucoin = Kucoin::new(KucoinParameters::prod())
+//!                         .await
+//!                         .expect("Couldn't create kucoin client");
+
+//!     let order_book = kucoin.order_book(&OrderBookRequest {market_pair: "BTC-USDT".to_string()})
+//!                         .await
+//!                         .expect("Couldn't get order book");
+
+//!     println!("{:?}", order_book);
+//! }
+//! ```
+
+use std::convert::TryFrom;
+use async_trait::async_trait;
+use client::BaseClient;
+use transport::Transport;
+use rust_decimal::prelude::*;
+use crate::{
+    errors::OpenLimitsError,
+    model::{
+        AskBid, Balance, CancelAllOrdersRequest, CancelOrderRequest, Candle,
+        GetHistoricRatesRequest, GetHistoricTradesRequest, GetOrderHistoryRequest, GetOrderRequest,
+        GetPriceTickerRequest, Interval, Liquidity, OpenLimitOrderRequest, OpenMarketOrderRequest,
+        Order, OrderBookRequest, OrderBookResponse, OrderCanceled, OrderStatus, OrderType,
+        Paginator, Side, Ticker, TimeInForce, Trade, TradeHistoryRequest,
+    },
+};
+use crate::exchange::traits::info::{
+    ExchangeInfoRetrieval, MarketPair, MarketPairHandle, MarketStatus,
+};
+use crate::exchange::traits::Exchange;
+use crate::prelude::*;
+use super::shared::Result;
+
+pub mod client;
+pub mod model;
+mod transport;
+mod kucoin_content_error;
+mod kucoin_credentials;
+mod kucoin_parameters;
+
+pub use client::websocket::KucoinWebsocket;
+pub use kucoin_content_error::KucoinContentError;
+pub use kucoin_credentials::KucoinCredentials;
+pub use kucoin_parameters::KucoinParameters;
+pub use super::shared;
+
+/// The main struct of the kucoin module
+#[derive(Clone)]
+pub struct Kucoin {
+    pub exchange_info: ExchangeInfo,
+    pub client: BaseClient,
+}
+
+#[async_trait]
+impl Exchange for Kucoin {
+    type InitParams = KucoinParameters;
+    type InnerClient = BaseClient;
+
+    async fn new(parameters: Self::InitParams) -> Result<Self> {
+        let kucoin = match parameters.credentials {
+            Some(credentials) => Kucoin {
+                exchange_info: ExchangeInfo::new(),
+                client: BaseClient {
+                    transport: Transport::with_credential(
+                        &credentials.api_key,
+                        &credentials.api_secret,
+                        &credentials.passphrase,
+                        parameters.sandbox,
+                    )?,
+                },
+            },
+            None => Kucoin {
+                exchange_info: ExchangeInfo::new(),
+                client: BaseClient {
+                    transport: Transport::new(parameters.sandbox)?,
+                },
+            },
+        };
+
+        match &parameters.exchange_info_cache {
+            Some(cache) => cache.initialize(&kucoin, &kucoin.exchange_info).await?,
+            None => {
+                kucoin.refresh_market_info().await?;
+            }
+        }
+        Ok(kucoin)
+    }
+
+    fn inner_client(&self) -> Option<&Self::InnerClient> {
+        Some(&self.client)
+    }
+}
+
+#[async_trait]
+impl ExchangeInfoRetrieval for Kucoin {
+    async fn retrieve_pairs(&self) -> Result<Vec<MarketPair>> {
+        self.client
+            .symbols()
+            .await
+            .map(|v| v.into_iter().map(Into::into).collect())
+    }
+
+    async fn refresh_market_info(&self) -> Result<Vec<MarketPairHandle>> {
+        self.exchange_info
+            .refresh(self as &dyn ExchangeInfoRetrieval)
+            .await
+    }
+
+    async fn get_pair(&self, name: &str) -> Result<MarketPairHandle> {
+        self.exchange_info.get_pair(name)
+    }
+}
+
+#[async_trait]
+impl ExchangeMarketData for Kucoin {
+    async fn order_book(&self, req: &OrderBookRequest) -> Result<OrderBookResponse> {
+        self.client
+            .order_book(&req.market_pair)
+            .await
+            .map(|book: OrderBookResponse| {
+                self.exchange_info.translate_market(&req.market_pair, book)
+            })
+    }
+
+    async fn get_price_ticker(&self, req: &GetPriceTickerRequest) -> Result<Ticker> {
+        self.client
+            .stats(&req.market_pair)
+            .await
+            .map(|ticker: Ticker| self.exchange_info.translate_market(&req.market_pair, ticker))
+    }
+
+    async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
+        // The candles endpoint takes its range in seconds
+        let paginator = req.paginator.clone().unwrap_or_default();
+        let params = model::CandleRequestParams {
+            symbol: req.market_pair.clone(),
+            candle_type: model::CandleType::try_from(req.interval)?,
+            start_at: paginator.start_time.map(|t| t / 1000),
+            end_at: paginator.end_time.map(|t| t / 1000),
+        };
+        self.client
+            .candles(&params)
+            .await
+            .map(|v| {
+                let candles: Vec<Candle> = v.into_iter().map(Into::into).collect();
+                self.exchange_info.translate_market(&req.market_pair, candles)
+            })
+    }
+
+    async fn get_historic_trades(&self, req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
+        self.client.trades(&req.market_pair).await.map(|v| {
+            let trades: Vec<Trade> = v
+                .into_iter()
+                .map(|trade| Trade {
+                    id: trade.sequence,
+                    buyer_order_id: None,
+                    seller_order_id: None,
+                    market_pair: req.market_pair.clone(),
+                    instrument: None,
+                    price: trade.price,
+                    qty: trade.size,
+                    fees: None,
+                    side: Side::from(trade.side),
+                    liquidity: None,
+                    created_at: trade.time / 1_000_000,
+                })
+                .collect();
+            self.exchange_info.translate(trades)
+        })
+    }
+}
+
+#[async_trait]
+impl ExchangeAccount for Kucoin {
+    async fn limit_buy(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
+        let (tif, cancel_after) = limit_time_in_force(req.time_in_force);
+        let placed = self
+            .client
+            .limit_buy(pair, req.size, req.price, tif, cancel_after, req.post_only)
+            .await?;
+        self.client
+            .order(&placed.order_id)
+            .await
+            .map(|order: Order| self.exchange_info.translate(order))
+    }
+
+    async fn limit_sell(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
+        let (tif, cancel_after) = limit_time_in_force(req.time_in_force);
+        let placed = self
+            .client
+            .limit_sell(pair, req.size, req.price, tif, cancel_after, req.post_only)
+            .await?;
+        self.client
+            .order(&placed.order_id)
+            .await
+            .map(|order: Order| self.exchange_info.translate(order))
+    }
+
+    async fn market_buy(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
+        let placed = self.client.market_buy(pair, req.size).await?;
+        self.client
+            .order(&placed.order_id)
+            .await
+            .map(|order: Order| self.exchange_info.translate(order))
+    }
+
+    async fn market_sell(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
+        let placed = self.client.market_sell(pair, req.size).await?;
+        self.client
+            .order(&placed.order_id)
+            .await
+            .map(|order: Order| self.exchange_info.translate(order))
+    }
+
+    async fn cancel_order(&self, req: &CancelOrderRequest) -> Result<OrderCanceled> {
+        self.client.cancel_order(&req.id).await?;
+        Ok(OrderCanceled { id: req.id.clone() })
+    }
+
+    async fn cancel_all_orders(&self, req: &CancelAllOrdersRequest) -> Result<Vec<OrderCanceled>> {
+        self.client
+            .cancel_all_orders(req.market_pair.as_deref())
+            .await
+            .map(|cancelled| {
+                cancelled
+                    .cancelled_order_ids
+                    .into_iter()
+                    .map(|id| OrderCanceled { id })
+                    .collect()
+            })
+    }
+
+    async fn get_all_open_orders(&self) -> Result<Vec<Order>> {
+        let filter = model::OrderFilter {
+            status: Some(model::ORDER_STATUS_ACTIVE.to_string()),
+            ..Default::default()
+        };
+        self.client
+            .orders(&filter)
+            .await
+            .map(|page| {
+                self.exchange_info
+                    .translate(page.items.into_iter().map(Into::into).collect())
+            })
+    }
+
+    async fn get_order_history(&self, req: &GetOrderHistoryRequest) -> Result<Vec<Order>> {
+        let filter = model::OrderFilter {
+            status: Some(model::ORDER_STATUS_DONE.to_string()),
+            symbol: req.market_pair.clone(),
+            paginator: req.paginator.clone().map(Into::into).unwrap_or_default(),
+        };
+        self.client
+            .orders(&filter)
+            .await
+            .map(|page| {
+                self.exchange_info
+                    .translate(page.items.into_iter().map(Into::into).collect())
+            })
+    }
+
+    async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>> {
+        let filter = model::FillFilter {
+            symbol: req.market_pair.clone(),
+            order_id: req.order_id.clone(),
+            paginator: req.paginator.clone().map(Into::into).unwrap_or_default(),
+        };
+        self.client
+            .fills(&filter)
+            .await
+            .map(|page| {
+                self.exchange_info
+                    .translate(page.items.into_iter().map(Into::into).collect())
+            })
+    }
+
+    async fn get_account_balances(&self, _paginator: Option<Paginator>) -> Result<Vec<Balance>> {
+        self.client
+            .accounts(Some(model::ACCOUNT_TYPE_TRADE))
+            .await
+            .map(|v| v.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_order(&self, req: &GetOrderRequest) -> Result<Order> {
+        self.client
+            .order(&req.id)
+            .await
+            .map(|order: Order| self.exchange_info.translate(order))
+    }
+}
+
+/// Good till time orders are sent as `GTT` with the number of seconds after which
+/// kucoin cancels them.
+fn limit_time_in_force(tif: TimeInForce) -> (model::TimeInForce, Option<u64>) {
+    match tif {
+        TimeInForce::GoodTillCancelled => (model::TimeInForce::GTC, None),
+        TimeInForce::ImmediateOrCancelled => (model::TimeInForce::IOC, None),
+        TimeInForce::FillOrKill => (model::TimeInForce::FOK, None),
+        TimeInForce::GoodTillTime(duration) => (
+            model::TimeInForce::GTT,
+            Some(duration.num_seconds().max(1) as u64),
+        ),
+    }
+}
+
+impl From<model::Symbol> for MarketPair {
+    fn from(symbol: model::Symbol) -> Self {
+        Self {
+            symbol: symbol.symbol,
+            base: symbol.base_currency,
+            quote: symbol.quote_currency,
+            base_increment: symbol.base_increment,
+            quote_increment: symbol.price_increment,
+            min_base_trade_size: Some(symbol.base_min_size),
+            min_quote_trade_size: Some(symbol.quote_min_size),
+            max_base_trade_size: Some(symbol.base_max_size),
+            max_quote_trade_size: Some(symbol.quote_max_size),
+            status: match symbol.enable_trading {
+                true => MarketStatus::Trading,
+                false => MarketStatus::Halted,
+            },
+            ..Default::default()
+        }
+    }
+}
+
+impl From<model::BookLevel> for AskBid {
+    fn from(level: model::BookLevel) -> Self {
+        Self {
+            price: level.price,
+            qty: level.size,
+        }
+    }
+}
+
+impl From<model::OrderBook> for OrderBookResponse {
+    fn from(book: model::OrderBook) -> Self {
+        Self {
+            market_pair: None,
+            instrument: None,
+            update_id: None,
+            last_update_id: book.sequence.parse::<u64>().ok(),
+            bids: book.bids.into_iter().map(Into::into).collect(),
+            asks: book.asks.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<model::Stats> for Ticker {
+    fn from(stats: model::Stats) -> Self {
+        let price_24h = match (stats.last, stats.change_price) {
+            (Some(last), Some(change)) => Some(last - change),
+            _ => None,
+        };
+        Self {
+            market_pair: None,
+            instrument: None,
+            price: stats.last,
+            price_24h,
+        }
+    }
+}
+
+impl From<model::Candle> for Candle {
+    fn from(candle: model::Candle) -> Self {
+        Self {
+            market_pair: None,
+            instrument: None,
+            time: candle.time * 1000,
+            low: candle.low,
+            high: candle.high,
+            open: candle.open,
+            close: candle.close,
+            volume: candle.volume,
+        }
+    }
+}
+
+impl From<model::Order> for Order {
+    fn from(order: model::Order) -> Self {
+        let order_type = match order.order_type.as_str() {
+            model::ORDER_TYPE_LIMIT => OrderType::Limit,
+            model::ORDER_TYPE_MARKET => OrderType::Market,
+            _ => OrderType::Unknown,
+        };
+        let status = if order.is_active {
+            match order.deal_size.is_zero() {
+                true => OrderStatus::Open,
+                false => OrderStatus::PartiallyFilled,
+            }
+        } else if order.cancel_exist {
+            OrderStatus::Canceled
+        } else {
+            OrderStatus::Filled
+        };
+        let price = match order_type {
+            OrderType::Market => None,
+            _ => Some(order.price),
+        };
+
+        Self {
+            id: order.id,
+            market_pair: order.symbol,
+            instrument: None,
+            client_order_id: order.client_oid,
+            created_at: Some(order.created_at),
+            order_type,
+            side: Side::from(order.side),
+            status,
+            remaining: Some(order.size - order.deal_size),
+            size: order.size,
+            price,
+            trades: Vec::new(),
+        }
+    }
+}
+
+impl From<model::Account> for Balance {
+    fn from(account: model::Account) -> Self {
+        Self {
+            asset: account.currency,
+            free: account.available,
+            total: account.balance,
+            locked: account.holds,
+            // Kucoin keeps one account per currency and type, e.g. main and trade
+            wallet: Some(account.account_type),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<model::Fill> for Trade {
+    fn from(fill: model::Fill) -> Self {
+        let side = Side::from(fill.side);
+        let (buyer_order_id, seller_order_id) = match side {
+            Side::Buy => (Some(fill.order_id), Some(fill.counter_order_id)),
+            Side::Sell => (Some(fill.counter_order_id), Some(fill.order_id)),
+        };
+
+        Self {
+            id: fill.trade_id,
+            buyer_order_id,
+            seller_order_id,
+            market_pair: fill.symbol,
+            instrument: None,
+            price: fill.price,
+            qty: fill.size,
+            fees: Some(fill.fee),
+            side,
+            liquidity: match fill.liquidity.as_str() {
+                model::LIQUIDITY_MAKER => Some(Liquidity::Maker),
+                _ => Some(Liquidity::Taker),
+            },
+            created_at: fill.created_at,
+        }
+    }
+}
+
+impl From<Paginator> for model::Paginator {
+    fn from(paginator: Paginator) -> Self {
+        Self {
+            current_page: paginator.after.and_then(|page| page.parse().ok()),
+            page_size: paginator.limit,
+            start_at: paginator.start_time,
+            end_at: paginator.end_time,
+        }
+    }
+}
+
+impl TryFrom<Interval> for model::CandleType {
+    type Error = OpenLimitsError;
+
+    fn try_from(interval: Interval) -> Result<Self> {
+        match interval {
+            Interval::OneMinute => Ok(model::CandleType::OneMinute),
+            Interval::ThreeMinutes => Ok(model::CandleType::ThreeMinutes),
+            Interval::FiveMinutes => Ok(model::CandleType::FiveMinutes),
+            Interval::FifteenMinutes => Ok(model::CandleType::FifteenMinutes),
+            Interval::ThirtyMinutes => Ok(model::CandleType::ThirtyMinutes),
+            Interval::OneHour => Ok(model::CandleType::OneHour),
+            Interval::TwoHours => Ok(model::CandleType::TwoHours),
+            Interval::FourHours => Ok(model::CandleType::FourHours),
+            Interval::SixHours => Ok(model::CandleType::SixHours),
+            Interval::EightHours => Ok(model::CandleType::EightHours),
+            Interval::TwelveHours => Ok(model::CandleType::TwelveHours),
+            Interval::OneDay => Ok(model::CandleType::OneDay),
+            Interval::OneWeek => Ok(model::CandleType::OneWeek),
+            _ => Err(OpenLimitsError::InvalidParameter(format!(
+                "{:?} is not supported in Kucoin",
+                interval,
+            ))),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents an account, kucoin keeps one account per currency and type
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Account {
+    pub id: String,
+    pub currency: String,
+    #[serde(rename = "type")]
+    pub account_type: String,
+    #[serde(with = "string_to_decimal")]
+    pub balance: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub available: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub holds: Decimal,
+}
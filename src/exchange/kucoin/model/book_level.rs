@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents an order book level `[price, size]`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BookLevel {
+    #[serde(with = "string_to_decimal")]
+    pub price: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub size: Decimal,
+}
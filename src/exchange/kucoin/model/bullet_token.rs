@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::InstanceServer;
+
+/// This struct represents the token required to open a websocket connection,
+/// private tokens also authenticate the connection
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BulletToken {
+    pub token: String,
+    pub instance_servers: Vec<InstanceServer>,
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents the orders canceled by a cancellation request
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelledOrders {
+    pub cancelled_order_ids: Vec<String>,
+}
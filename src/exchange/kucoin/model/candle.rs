@@ -0,0 +1,25 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+use super::shared::string_to_u64;
+
+/// This struct represents a candle
+/// `[time, open, close, high, low, volume, turnover]`, the time is in seconds.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Candle {
+    #[serde(with = "string_to_u64")]
+    pub time: u64,
+    #[serde(with = "string_to_decimal")]
+    pub open: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub close: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub high: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub low: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub volume: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub turnover: Decimal,
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::CandleType;
+
+/// This struct represents the candle request params, the times are in seconds
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CandleRequestParams {
+    pub symbol: String,
+    #[serde(rename = "type")]
+    pub candle_type: CandleType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_at: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_at: Option<u64>,
+}
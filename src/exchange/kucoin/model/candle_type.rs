@@ -0,0 +1,55 @@
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt;
+
+/// This enum represents the candle types supported by kucoin
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CandleType {
+    #[serde(rename = "1min")]
+    OneMinute,
+    #[serde(rename = "3min")]
+    ThreeMinutes,
+    #[serde(rename = "5min")]
+    FiveMinutes,
+    #[serde(rename = "15min")]
+    FifteenMinutes,
+    #[serde(rename = "30min")]
+    ThirtyMinutes,
+    #[serde(rename = "1hour")]
+    OneHour,
+    #[serde(rename = "2hour")]
+    TwoHours,
+    #[serde(rename = "4hour")]
+    FourHours,
+    #[serde(rename = "6hour")]
+    SixHours,
+    #[serde(rename = "8hour")]
+    EightHours,
+    #[serde(rename = "12hour")]
+    TwelveHours,
+    #[serde(rename = "1day")]
+    OneDay,
+    #[serde(rename = "1week")]
+    OneWeek,
+}
+
+impl fmt::Display for CandleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            CandleType::OneMinute => "1min",
+            CandleType::ThreeMinutes => "3min",
+            CandleType::FiveMinutes => "5min",
+            CandleType::FifteenMinutes => "15min",
+            CandleType::ThirtyMinutes => "30min",
+            CandleType::OneHour => "1hour",
+            CandleType::TwoHours => "2hour",
+            CandleType::FourHours => "4hour",
+            CandleType::SixHours => "6hour",
+            CandleType::EightHours => "8hour",
+            CandleType::TwelveHours => "12hour",
+            CandleType::OneDay => "1day",
+            CandleType::OneWeek => "1week",
+        };
+        write!(f, "{}", s)
+    }
+}
@@ -0,0 +1,28 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents a fill of one of the account orders
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Fill {
+    pub symbol: String,
+    pub trade_id: String,
+    pub order_id: String,
+    pub counter_order_id: String,
+    pub side: String,
+    pub liquidity: String,
+    #[serde(with = "string_to_decimal")]
+    pub price: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub size: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub funds: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub fee: Decimal,
+    pub fee_currency: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub created_at: u64,
+}
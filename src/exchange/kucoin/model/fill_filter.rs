@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::Paginator;
+
+/// This struct filters the fills
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FillFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    #[serde(flatten)]
+    pub paginator: Paginator,
+}
@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents a websocket server, the intervals are in milliseconds
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceServer {
+    pub endpoint: String,
+    pub encrypt: bool,
+    pub protocol: String,
+    pub ping_interval: u64,
+    pub ping_timeout: u64,
+}
@@ -0,0 +1,58 @@
+//! This module provides models that are used in the kucoin module
+
+pub const ORDER_TYPE_LIMIT: &str = "limit";
+pub const ORDER_TYPE_MARKET: &str = "market";
+pub const ORDER_SIDE_BUY: &str = "buy";
+pub const ORDER_SIDE_SELL: &str = "sell";
+pub const ORDER_STATUS_ACTIVE: &str = "active";
+pub const ORDER_STATUS_DONE: &str = "done";
+pub const ACCOUNT_TYPE_TRADE: &str = "trade";
+pub const LIQUIDITY_MAKER: &str = "maker";
+
+mod account;
+mod book_level;
+mod bullet_token;
+mod cancelled_orders;
+mod candle;
+mod candle_request_params;
+mod candle_type;
+mod fill;
+mod fill_filter;
+mod instance_server;
+mod order;
+mod order_book;
+mod order_filter;
+mod order_placed;
+mod order_request;
+mod page;
+mod paginator;
+mod stats;
+mod symbol;
+mod ticker;
+mod time_in_force;
+mod trade;
+pub mod websocket;
+
+pub use account::Account;
+pub use book_level::BookLevel;
+pub use bullet_token::BulletToken;
+pub use cancelled_orders::CancelledOrders;
+pub use candle::Candle;
+pub use candle_request_params::CandleRequestParams;
+pub use candle_type::CandleType;
+pub use fill::Fill;
+pub use fill_filter::FillFilter;
+pub use instance_server::InstanceServer;
+pub use order::Order;
+pub use order_book::OrderBook;
+pub use order_filter::OrderFilter;
+pub use order_placed::OrderPlaced;
+pub use order_request::OrderRequest;
+pub use page::Page;
+pub use paginator::Paginator;
+pub use stats::Stats;
+pub use symbol::Symbol;
+pub use ticker::Ticker;
+pub use time_in_force::TimeInForce;
+pub use trade::Trade;
+pub use super::shared;
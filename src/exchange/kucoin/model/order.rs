@@ -0,0 +1,39 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents an order, market orders have a zero price
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub id: String,
+    pub symbol: String,
+    pub op_type: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub side: String,
+    #[serde(with = "string_to_decimal")]
+    pub price: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub size: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub funds: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub deal_funds: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub deal_size: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub fee: Decimal,
+    pub fee_currency: String,
+    #[serde(default)]
+    pub stop: String,
+    pub time_in_force: String,
+    pub post_only: bool,
+    pub cancel_after: u64,
+    #[serde(default)]
+    pub client_oid: Option<String>,
+    pub is_active: bool,
+    pub cancel_exist: bool,
+    pub created_at: u64,
+}
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::BookLevel;
+
+/// This struct represents the aggregated order book
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderBook {
+    pub sequence: String,
+    pub time: u64,
+    pub bids: Vec<BookLevel>,
+    pub asks: Vec<BookLevel>,
+}
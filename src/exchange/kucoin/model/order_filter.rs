@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::Paginator;
+
+/// This struct filters the orders, `status` is either `active` or `done`
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(flatten)]
+    pub paginator: Paginator,
+}
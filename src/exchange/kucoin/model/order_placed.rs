@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents the answer to an order submission
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderPlaced {
+    pub order_id: String,
+}
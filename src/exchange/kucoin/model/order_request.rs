@@ -0,0 +1,30 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+use super::shared::string_to_opt_decimal;
+use super::TimeInForce;
+
+/// This struct represents an order submission
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderRequest {
+    pub client_oid: String,
+    pub side: String,
+    pub symbol: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    #[serde(with = "string_to_decimal")]
+    pub size: Decimal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub price: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    /// Seconds before a `GTT` order is canceled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_after: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_only: Option<bool>,
+}
@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents a page of a paginated endpoint
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<T> {
+    pub current_page: u64,
+    pub page_size: u64,
+    pub total_num: u64,
+    pub total_page: u64,
+    pub items: Vec<T>,
+}
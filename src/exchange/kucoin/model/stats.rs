@@ -0,0 +1,34 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_opt_decimal;
+
+/// This struct represents the 24h statistics of a symbol,
+/// the prices are missing when nothing was traded
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Stats {
+    pub symbol: String,
+    pub time: u64,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub last: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub change_price: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub change_rate: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub high: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub low: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub vol: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub vol_value: Option<Decimal>,
+}
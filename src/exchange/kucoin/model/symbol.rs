@@ -0,0 +1,31 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents a trading symbol
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Symbol {
+    pub symbol: String,
+    pub name: String,
+    pub base_currency: String,
+    pub quote_currency: String,
+    #[serde(with = "string_to_decimal")]
+    pub base_min_size: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub quote_min_size: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub base_max_size: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub quote_max_size: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub base_increment: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub quote_increment: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub price_increment: Decimal,
+    pub fee_currency: String,
+    pub enable_trading: bool,
+    pub is_margin_enabled: bool,
+}
@@ -0,0 +1,30 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_opt_decimal;
+
+/// This struct represents the level 1 ticker, the best bid and ask and the last trade
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker {
+    pub sequence: String,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub price: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub size: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub best_bid: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub best_bid_size: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub best_ask: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub best_ask_size: Option<Decimal>,
+    pub time: u64,
+}
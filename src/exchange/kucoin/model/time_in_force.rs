@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This enum represents the time in force of an order
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum TimeInForce {
+    GTC,
+    GTT,
+    IOC,
+    FOK,
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents a public trade, the time is in nanoseconds
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Trade {
+    pub sequence: String,
+    #[serde(with = "string_to_decimal")]
+    pub price: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub size: Decimal,
+    pub side: String,
+    pub time: u64,
+}
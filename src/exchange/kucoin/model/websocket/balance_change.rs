@@ -0,0 +1,27 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+use super::shared::string_to_u64;
+
+/// This struct represents a balance change of the account
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceChange {
+    pub account_id: String,
+    pub currency: String,
+    #[serde(with = "string_to_decimal")]
+    pub total: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub available: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub available_change: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub hold: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub hold_change: Decimal,
+    pub relation_event: String,
+    pub relation_event_id: String,
+    #[serde(with = "string_to_u64")]
+    pub time: u64,
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::Candle;
+
+/// This struct represents the candle being built, the time is in nanoseconds
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CandleUpdate {
+    pub symbol: String,
+    pub candles: Candle,
+    pub time: u64,
+}
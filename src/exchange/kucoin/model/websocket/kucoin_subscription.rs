@@ -0,0 +1,55 @@
+use crate::model::websocket::Subscription;
+use super::CandleType;
+
+/// This enum represents a kucoin subscription
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum KucoinSubscription {
+    /// Symbol, incremental order book updates
+    Level2(String),
+    /// Symbol
+    Match(String),
+    /// Symbol
+    Ticker(String),
+    Candles(String, CandleType),
+    /// Order changes of the authenticated account
+    TradeOrders,
+    /// Balance changes of the authenticated account
+    Balance,
+}
+
+impl KucoinSubscription {
+    pub fn topic(&self) -> String {
+        match self {
+            KucoinSubscription::Level2(symbol) => format!("/market/level2:{}", symbol),
+            KucoinSubscription::Match(symbol) => format!("/market/match:{}", symbol),
+            KucoinSubscription::Ticker(symbol) => format!("/market/ticker:{}", symbol),
+            KucoinSubscription::Candles(symbol, candle_type) => {
+                format!("/market/candles:{}_{}", symbol, candle_type)
+            }
+            KucoinSubscription::TradeOrders => String::from("/spotMarket/tradeOrders"),
+            KucoinSubscription::Balance => String::from("/account/balance"),
+        }
+    }
+
+    /// Private topics need a connection opened with a private bullet token
+    pub fn is_private(&self) -> bool {
+        matches!(
+            self,
+            KucoinSubscription::TradeOrders | KucoinSubscription::Balance
+        )
+    }
+}
+
+impl From<Subscription> for KucoinSubscription {
+    fn from(subscription: Subscription) -> Self {
+        match subscription {
+            Subscription::OrderBookUpdates(symbol) => KucoinSubscription::Level2(symbol),
+            Subscription::Trades(symbol) => KucoinSubscription::Match(symbol),
+            Subscription::Ticker(symbol) => KucoinSubscription::Ticker(symbol),
+            Subscription::AccountOrders(_) | Subscription::AccountTrades(_) => {
+                KucoinSubscription::TradeOrders
+            }
+            Subscription::AccountBalance(_) => KucoinSubscription::Balance,
+        }
+    }
+}
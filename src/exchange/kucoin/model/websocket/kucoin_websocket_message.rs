@@ -0,0 +1,27 @@
+use serde::Serialize;
+use serde_json::Value;
+use super::BalanceChange;
+use super::CandleUpdate;
+use super::Level2Update;
+use super::MatchMessage;
+use super::OrderChange;
+use super::TickerUpdate;
+
+/// This enum represents the types of websocket messages
+#[derive(Debug, Clone, Serialize)]
+pub enum KucoinWebsocketMessage {
+    Welcome,
+    Ack(String),
+    Error(String),
+    Level2(Level2Update),
+    Match(MatchMessage),
+    Ticker { symbol: String, ticker: TickerUpdate },
+    Candle(CandleUpdate),
+    OrderChange(OrderChange),
+    BalanceChange(BalanceChange),
+    Unknown(Value),
+    Ping,
+    Pong,
+    Close,
+    Binary(Vec<u8>), // Unexpected, unparsed
+}
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+use super::shared::string_to_u64;
+
+/// This struct represents an order book change `[price, size, sequence]`,
+/// a zero size removes the level
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Level2Change {
+    #[serde(with = "string_to_decimal")]
+    pub price: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub size: Decimal,
+    #[serde(with = "string_to_u64")]
+    pub sequence: u64,
+}
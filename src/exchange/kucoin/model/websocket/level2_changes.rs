@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::Level2Change;
+
+/// This struct represents the changes of both sides of the order book
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Level2Changes {
+    pub asks: Vec<Level2Change>,
+    pub bids: Vec<Level2Change>,
+}
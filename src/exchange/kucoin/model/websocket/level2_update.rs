@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::Level2Changes;
+
+/// This struct represents an incremental order book update
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Level2Update {
+    pub sequence_start: u64,
+    pub sequence_end: u64,
+    pub symbol: String,
+    pub changes: Level2Changes,
+}
@@ -0,0 +1,25 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+use super::shared::string_to_u64;
+
+/// This struct represents a public trade, the side is the taker side and the time is in
+/// nanoseconds
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchMessage {
+    #[serde(with = "string_to_u64")]
+    pub sequence: u64,
+    pub symbol: String,
+    pub side: String,
+    #[serde(with = "string_to_decimal")]
+    pub price: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub size: Decimal,
+    pub trade_id: String,
+    pub taker_order_id: String,
+    pub maker_order_id: String,
+    #[serde(with = "string_to_u64")]
+    pub time: u64,
+}
@@ -0,0 +1,29 @@
+//! Websocket model
+
+use super::Candle;
+use super::CandleType;
+
+mod balance_change;
+mod candle_update;
+mod kucoin_subscription;
+mod kucoin_websocket_message;
+mod level2_change;
+mod level2_changes;
+mod level2_update;
+mod match_message;
+mod order_change;
+mod request;
+mod ticker_update;
+
+pub use balance_change::BalanceChange;
+pub use candle_update::CandleUpdate;
+pub use kucoin_subscription::KucoinSubscription;
+pub use kucoin_websocket_message::KucoinWebsocketMessage;
+pub use level2_change::Level2Change;
+pub use level2_changes::Level2Changes;
+pub use level2_update::Level2Update;
+pub use match_message::MatchMessage;
+pub use order_change::OrderChange;
+pub use request::Request;
+pub use ticker_update::TickerUpdate;
+pub use super::shared;
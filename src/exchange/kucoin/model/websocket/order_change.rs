@@ -0,0 +1,42 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+use super::shared::string_to_opt_decimal;
+
+/// This struct represents a change of one of the account orders,
+/// `change_type` is one of `open`, `match`, `filled`, `canceled` or `update`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderChange {
+    pub symbol: String,
+    pub order_type: String,
+    pub side: String,
+    pub order_id: String,
+    #[serde(rename = "type")]
+    pub change_type: String,
+    pub order_time: u64,
+    #[serde(with = "string_to_decimal")]
+    pub size: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub filled_size: Decimal,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub price: Option<Decimal>,
+    #[serde(default)]
+    pub client_oid: Option<String>,
+    #[serde(with = "string_to_decimal")]
+    pub remain_size: Decimal,
+    pub status: String,
+    pub ts: u64,
+    #[serde(default)]
+    pub liquidity: Option<String>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub match_price: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub match_size: Option<Decimal>,
+    #[serde(default)]
+    pub trade_id: Option<String>,
+}
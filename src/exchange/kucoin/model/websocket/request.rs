@@ -0,0 +1,41 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::KucoinSubscription;
+
+/// This struct represents a message sent to the websocket server
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Request {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub request_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_channel: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<bool>,
+}
+
+impl Request {
+    pub fn subscribe(id: String, subscription: &KucoinSubscription) -> Self {
+        Self {
+            id,
+            request_type: String::from("subscribe"),
+            topic: Some(subscription.topic()),
+            private_channel: Some(subscription.is_private()),
+            response: Some(true),
+        }
+    }
+
+    /// The server closes connections that didn't ping within the interval of the bullet token
+    pub fn ping(id: String) -> Self {
+        Self {
+            id,
+            request_type: String::from("ping"),
+            topic: None,
+            private_channel: None,
+            response: None,
+        }
+    }
+}
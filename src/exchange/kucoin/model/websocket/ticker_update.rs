@@ -0,0 +1,25 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+use super::shared::string_to_u64;
+
+/// This struct represents a level 1 update, the best bid and ask and the last trade
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TickerUpdate {
+    #[serde(with = "string_to_u64")]
+    pub sequence: u64,
+    #[serde(with = "string_to_decimal")]
+    pub price: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub size: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub best_bid: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub best_bid_size: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub best_ask: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub best_ask_size: Decimal,
+}
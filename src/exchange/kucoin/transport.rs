@@ -0,0 +1,269 @@
+use chrono::Utc;
+use hmac::{Hmac, Mac, NewMac};
+use reqwest::header;
+use reqwest::{Method, RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use sha2::Sha256;
+use url::Url;
+use crate::exchange::kucoin::KucoinContentError;
+use crate::errors::OpenLimitsError;
+use super::shared::Result;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Code of the successful responses, every other code is an error
+const SUCCESS_CODE: &str = "200000";
+/// The passphrase is sent signed with the api secret
+const API_KEY_VERSION: &str = "2";
+
+/// Every kucoin response is wrapped as `{"code": "200000", "data": ...}`
+#[derive(Deserialize)]
+struct Envelope {
+    code: String,
+    #[serde(default)]
+    data: Value,
+    #[serde(default)]
+    msg: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct Transport {
+    credential: Option<(String, String, String)>,
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl Transport {
+    pub fn new(sandbox: bool) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .default_headers(Transport::default_headers())
+            .build()?;
+
+        Ok(Transport {
+            credential: None,
+            client,
+            base_url: Transport::get_base_url(sandbox),
+        })
+    }
+
+    pub fn with_credential(
+        api_key: &str,
+        api_secret: &str,
+        passphrase: &str,
+        sandbox: bool,
+    ) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .default_headers(Transport::default_headers())
+            .build()?;
+
+        Ok(Transport {
+            credential: Some((api_key.into(), api_secret.into(), passphrase.into())),
+            client,
+            base_url: Transport::get_base_url(sandbox),
+        })
+    }
+
+    pub fn default_headers() -> header::HeaderMap<header::HeaderValue> {
+        let mut headers = header::HeaderMap::new();
+        headers.insert(
+            header::USER_AGENT,
+            header::HeaderValue::from_static("openlimit"),
+        );
+        headers.insert(
+            header::CONTENT_TYPE,
+            header::HeaderValue::from_static("application/json"),
+        );
+
+        headers
+    }
+
+    fn get_base_url(sandbox: bool) -> String {
+        if sandbox {
+            String::from("https://openapi-sandbox.kucoin.com")
+        } else {
+            String::from("https://api.kucoin.com")
+        }
+    }
+
+    pub async fn get<O, S>(&self, endpoint: &str, params: Option<&S>) -> Result<O>
+    where
+        O: DeserializeOwned,
+        S: Serialize,
+    {
+        let url = self.get_url(endpoint, params)?;
+        let response = self.client.get(url).send().await?;
+
+        self.response_handler(response).await
+    }
+
+    pub async fn post<O, D>(&self, endpoint: &str, data: Option<&D>) -> Result<O>
+    where
+        O: DeserializeOwned,
+        D: Serialize,
+    {
+        let url = self.get_url::<()>(endpoint, None)?;
+        let mut request = self.client.post(url);
+        if let Some(data) = data {
+            request = request.json(data);
+        }
+        let response = request.send().await?;
+
+        self.response_handler(response).await
+    }
+
+    pub async fn signed_get<O, S>(&self, endpoint: &str, params: Option<&S>) -> Result<O>
+    where
+        O: DeserializeOwned,
+        S: Serialize,
+    {
+        let url = self.get_url(endpoint, params)?;
+        let request = self.build_request::<()>(url, Method::GET, None)?;
+        let response = request.send().await?;
+
+        self.response_handler(response).await
+    }
+
+    pub async fn signed_post<O, D>(&self, endpoint: &str, data: Option<&D>) -> Result<O>
+    where
+        O: DeserializeOwned,
+        D: Serialize,
+    {
+        let url = self.get_url::<()>(endpoint, None)?;
+        let request = self.build_request(url, Method::POST, data)?;
+        let response = request.send().await?;
+
+        self.response_handler(response).await
+    }
+
+    pub async fn signed_delete<O, S>(&self, endpoint: &str, params: Option<&S>) -> Result<O>
+    where
+        O: DeserializeOwned,
+        S: Serialize,
+    {
+        let url = self.get_url(endpoint, params)?;
+        let request = self.build_request::<()>(url, Method::DELETE, None)?;
+        let response = request.send().await?;
+
+        self.response_handler(response).await
+    }
+
+    pub fn build_request<D>(
+        &self,
+        url: Url,
+        method: Method,
+        data: Option<&D>,
+    ) -> Result<RequestBuilder>
+    where
+        D: Serialize,
+    {
+        let timestamp = Utc::now().timestamp_millis().to_string();
+        let body = match data {
+            Some(data) => serde_json::to_string(data)?,
+            None => String::new(),
+        };
+        let path = match url.query() {
+            Some(q) => format!("{}?{}", url.path(), q),
+            None => url.path().to_string(),
+        };
+        let headers = self.signed_headers(&timestamp, &method, &path, &body)?;
+
+        let mut request = self.client.request(method, url).headers(headers);
+        if data.is_some() {
+            request = request.body(body);
+        }
+
+        Ok(request)
+    }
+
+    /// The headers authenticating a request sent at `timestamp`, in milliseconds. The
+    /// signature covers the timestamp, the method, the path with its query and the body.
+    pub fn signed_headers(
+        &self,
+        timestamp: &str,
+        method: &Method,
+        path: &str,
+        body: &str,
+    ) -> Result<header::HeaderMap> {
+        let (api_key, api_secret, passphrase) = self.check_key()?;
+        let signature = sign(
+            api_secret,
+            &format!("{}{}{}{}", timestamp, method.as_str(), path, body),
+        );
+        let passphrase = sign(api_secret, passphrase);
+
+        let mut headers = header::HeaderMap::new();
+        headers.insert("KC-API-KEY", header::HeaderValue::from_str(api_key)?);
+        headers.insert("KC-API-SIGN", header::HeaderValue::from_str(&signature)?);
+        headers.insert("KC-API-TIMESTAMP", header::HeaderValue::from_str(timestamp)?);
+        headers.insert("KC-API-PASSPHRASE", header::HeaderValue::from_str(&passphrase)?);
+        headers.insert(
+            "KC-API-KEY-VERSION",
+            header::HeaderValue::from_static(API_KEY_VERSION),
+        );
+        Ok(headers)
+    }
+
+    pub fn get_url<Q>(&self, endpoint: &str, params: Option<&Q>) -> Result<Url>
+    where
+        Q: Serialize,
+    {
+        let url = format!("{}{}", self.base_url, endpoint);
+
+        let mut url = Url::parse(&url)?;
+
+        if params.is_some() {
+            let query = serde_urlencoded::to_string(params)?;
+            if !query.is_empty() {
+                url.set_query(Some(&query));
+            }
+        };
+
+        Ok(url)
+    }
+
+    fn check_key(&self) -> Result<(&str, &str, &str)> {
+        match self.credential.as_ref() {
+            None => Err(OpenLimitsError::NoApiKeySet()),
+            Some((k, s, p)) => Ok((k, s, p)),
+        }
+    }
+
+    async fn response_handler<O>(&self, response: Response) -> Result<O>
+    where
+        O: DeserializeOwned,
+    {
+        let status = response.status();
+        let text = response.text().await?;
+
+        match serde_json::from_str::<Envelope>(&text) {
+            Ok(envelope) if envelope.code == SUCCESS_CODE => serde_json::from_value(envelope.data)
+                .map_err(|err| {
+                    OpenLimitsError::NotParsableResponse(format!("Error:{} Payload: {}", err, text))
+                }),
+            Ok(envelope) => Err(OpenLimitsError::KucoinError(KucoinContentError {
+                code: envelope.code,
+                msg: envelope.msg,
+            })),
+            Err(_) => match status {
+                StatusCode::INTERNAL_SERVER_ERROR => Err(OpenLimitsError::InternalServerError()),
+                StatusCode::SERVICE_UNAVAILABLE => Err(OpenLimitsError::ServiceUnavailable()),
+                StatusCode::UNAUTHORIZED => Err(OpenLimitsError::Unauthorized()),
+                s => Err(OpenLimitsError::UnkownResponse(format!(
+                    "Received response: {:?}, value: {}",
+                    s, text
+                ))),
+            },
+        }
+    }
+}
+
+/// Base64 encoded HMAC-SHA256, used for the request signature and the passphrase.
+fn sign(secret: &str, message: &str) -> String {
+    let mut mac =
+        HmacSha256::new_varkey(secret.as_bytes()).expect("Couldn't construct hmac from bytes.");
+    mac.update(message.as_bytes());
+    base64::encode(mac.finalize().into_bytes())
+}
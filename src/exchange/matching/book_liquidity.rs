@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+use rust_decimal::prelude::{Decimal, Zero};
+use crate::model::{AskBid, OrderBookResponse, Side};
+
+/// The quantity of the levels of polled books already taken by resting orders, by market, side
+/// of the taking orders and price. Polls showing a level with the same quantity show the same
+/// liquidity, so a level is only available again once a book shows it with another quantity.
+#[derive(Debug, Default, Clone)]
+pub struct BookLiquidity {
+    levels: HashMap<(String, Side, Decimal), TakenLevel>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TakenLevel {
+    qty: Decimal,
+    taken: Decimal,
+}
+
+impl BookLiquidity {
+    /// Forgets what was taken from the levels of the market that changed or left `book`
+    pub fn update(&mut self, market_pair: &str, book: &OrderBookResponse) {
+        let market = market_pair.to_uppercase();
+        self.levels.retain(|(market_pair, side, price), level| {
+            let levels = match side {
+                Side::Buy => &book.asks,
+                Side::Sell => &book.bids,
+            };
+            *market_pair != market
+                || levels
+                    .iter()
+                    .any(|ask_bid| ask_bid.price == *price && ask_bid.qty == level.qty)
+        });
+    }
+
+    /// Takes up to `qty` from `levels`, the levels crossed by an order of `side`, and returns
+    /// the quantity taken
+    pub fn take(
+        &mut self,
+        market_pair: &str,
+        side: &Side,
+        levels: &[AskBid],
+        qty: Decimal,
+    ) -> Decimal {
+        let mut taken = Decimal::zero();
+        for level in levels {
+            if taken >= qty {
+                break;
+            }
+            let key = (market_pair.to_uppercase(), side.clone(), level.price);
+            let entry = self.levels.entry(key).or_insert(TakenLevel {
+                qty: level.qty,
+                taken: Decimal::zero(),
+            });
+            let available = (level.qty - entry.taken).max(Decimal::zero());
+            let take = available.min(qty - taken);
+            entry.taken += take;
+            taken += take;
+        }
+        taken
+    }
+}
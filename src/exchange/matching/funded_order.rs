@@ -0,0 +1,31 @@
+use rust_decimal::Decimal;
+use crate::model::{Order, OrderStatus, Side};
+
+/// This trait represents an order keeping funds locked until it's closed.
+/// Buy orders lock quote currency, sell orders lock base currency.
+pub trait FundedOrder {
+    fn order(&self) -> &Order;
+    fn order_mut(&mut self) -> &mut Order;
+    fn base(&self) -> &str;
+    fn quote(&self) -> &str;
+    fn locked(&self) -> Decimal;
+    fn set_locked(&mut self, locked: Decimal);
+
+    /// Whether the order rests on the market and can be filled
+    fn is_open(&self) -> bool {
+        self.order().status == OrderStatus::Open
+            || self.order().status == OrderStatus::PartiallyFilled
+    }
+
+    fn remaining(&self) -> Decimal {
+        self.order().remaining.unwrap_or(self.order().size)
+    }
+
+    /// Asset the order locks
+    fn locked_asset(&self) -> &str {
+        match self.order().side {
+            Side::Buy => self.quote(),
+            Side::Sell => self.base(),
+        }
+    }
+}
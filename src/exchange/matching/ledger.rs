@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+use rust_decimal::prelude::{Decimal, Zero};
+use crate::errors::OpenLimitsError;
+use crate::exchange::shared::Result;
+use crate::exchange::traits::info::MarketPair;
+use crate::model::{Balance, Liquidity, OrderStatus, Side, Trade};
+use super::FundedOrder;
+
+/// This struct represents the balances of an account and the fees it pays. The locked funds
+/// are kept by the orders, the ledger only knows the totals.
+#[derive(Clone, Debug, Default)]
+pub struct Ledger {
+    totals: HashMap<String, Decimal>,
+    pub maker_fee: Decimal,
+    pub taker_fee: Decimal,
+}
+
+impl Ledger {
+    pub fn new(balances: HashMap<String, Decimal>, maker_fee: Decimal, taker_fee: Decimal) -> Self {
+        Self {
+            totals: balances
+                .into_iter()
+                .map(|(asset, amount)| (asset.to_uppercase(), amount))
+                .collect(),
+            maker_fee,
+            taker_fee,
+        }
+    }
+
+    pub fn deposit(&mut self, asset: &str, amount: Decimal) {
+        *self
+            .totals
+            .entry(asset.to_uppercase())
+            .or_insert_with(Decimal::zero) += amount;
+    }
+
+    pub fn total(&self, asset: &str) -> Decimal {
+        self.totals
+            .get(&asset.to_uppercase())
+            .copied()
+            .unwrap_or_else(Decimal::zero)
+    }
+
+    pub fn totals(&self) -> &HashMap<String, Decimal> {
+        &self.totals
+    }
+
+    pub fn fee_rate(&self, liquidity: &Liquidity) -> Decimal {
+        match liquidity {
+            Liquidity::Maker => self.maker_fee,
+            Liquidity::Taker => self.taker_fee,
+        }
+    }
+
+    /// Balance not locked by `orders`, which are the orders holding funds
+    pub fn free<'a, O, I>(&self, asset: &str, orders: I) -> Decimal
+    where
+        O: FundedOrder + 'a,
+        I: IntoIterator<Item = &'a O>,
+    {
+        let asset = asset.to_uppercase();
+        let locked = orders
+            .into_iter()
+            .filter(|order| order.locked_asset() == asset)
+            .fold(Decimal::zero(), |acc, order| acc + order.locked());
+
+        self.total(&asset) - locked
+    }
+
+    /// Balances sorted by asset, given the free balance of every asset
+    pub fn balances(&self, free: impl Fn(&str) -> Decimal) -> Vec<Balance> {
+        let mut balances: Vec<Balance> = self
+            .totals
+            .iter()
+            .map(|(asset, total)| {
+                let free = free(asset);
+                Balance {
+                    asset: asset.clone(),
+                    total: *total,
+                    free,
+                    locked: *total - free,
+                    ..Default::default()
+                }
+            })
+            .collect();
+        balances.sort_by(|a, b| a.asset.cmp(&b.asset));
+        balances
+    }
+
+    /// An order of `side` on `pair` can only lock `required` if it's free beside `orders`
+    pub fn check_funds<'a, O, I>(
+        &self,
+        pair: &MarketPair,
+        side: &Side,
+        required: Decimal,
+        orders: I,
+    ) -> Result<()>
+    where
+        O: FundedOrder + 'a,
+        I: IntoIterator<Item = &'a O>,
+    {
+        let asset = match side {
+            Side::Buy => pair.quote.to_uppercase(),
+            Side::Sell => pair.base.to_uppercase(),
+        };
+        let available = self.free(&asset, orders);
+        if available < required {
+            return Err(OpenLimitsError::InsufficientFunds {
+                asset,
+                required,
+                available,
+            });
+        }
+        Ok(())
+    }
+
+    /// Fills `qty` of the order at `price` against the market rather than another order of
+    /// the venue, the trade is identified by the order id and the number of its fills.
+    pub fn fill<O: FundedOrder>(
+        &mut self,
+        order: &mut O,
+        price: Decimal,
+        qty: Decimal,
+        liquidity: Liquidity,
+        now: u64,
+    ) -> Trade {
+        let placed = order.order();
+        let (buyer_order_id, seller_order_id) = match placed.side {
+            Side::Buy => (Some(placed.id.clone()), None),
+            Side::Sell => (None, Some(placed.id.clone())),
+        };
+        let trade = Trade {
+            id: format!("{}-{}", placed.id, placed.trades.len() + 1),
+            buyer_order_id,
+            seller_order_id,
+            market_pair: placed.market_pair.clone(),
+            instrument: None,
+            price,
+            qty,
+            fees: None,
+            side: placed.side.clone(),
+            liquidity: None,
+            created_at: now,
+        };
+        self.settle(order, &trade, liquidity)
+    }
+
+    /// Moves the funds of the side of the order in `trade`, releases what it spent from its
+    /// locked funds and records the trade on it with its fee and liquidity. The order is filled
+    /// or partially filled, an order pending cancel stays so.
+    pub fn settle<O: FundedOrder>(
+        &mut self,
+        order: &mut O,
+        trade: &Trade,
+        liquidity: Liquidity,
+    ) -> Trade {
+        let notional = trade.price * trade.qty;
+        let fee = notional * self.fee_rate(&liquidity);
+        let side = order.order().side.clone();
+
+        let (spent_asset, spent, received_asset, received) = match side {
+            Side::Buy => (order.quote(), notional + fee, order.base(), trade.qty),
+            Side::Sell => (order.base(), trade.qty, order.quote(), notional - fee),
+        };
+        *self.totals.entry(spent_asset.to_string()).or_insert_with(Decimal::zero) -= spent;
+        *self.totals.entry(received_asset.to_string()).or_insert_with(Decimal::zero) += received;
+
+        let remaining = order.remaining() - trade.qty;
+        let locked = if remaining.is_zero() {
+            Decimal::zero()
+        } else {
+            (order.locked() - spent).max(Decimal::zero())
+        };
+        order.set_locked(locked);
+
+        let trade = Trade {
+            fees: Some(fee),
+            side,
+            liquidity: Some(liquidity),
+            ..trade.clone()
+        };
+        let placed = order.order_mut();
+        placed.remaining = Some(remaining);
+        if remaining.is_zero() {
+            placed.status = OrderStatus::Filled;
+        } else if placed.status != OrderStatus::PendingCancel {
+            placed.status = OrderStatus::PartiallyFilled;
+        }
+        placed.trades.push(trade.clone());
+        trade
+    }
+}
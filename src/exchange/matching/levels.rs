@@ -0,0 +1,71 @@
+use rust_decimal::prelude::{Decimal, Zero};
+use crate::model::{AskBid, OrderBookResponse, Side};
+use super::FundedOrder;
+
+/// Levels of the opposite side of the book an order can take, best price first.
+/// With a limit, only the levels at the limit or better are returned.
+pub fn crossing_levels(
+    book: &OrderBookResponse,
+    side: &Side,
+    limit: Option<Decimal>,
+) -> Vec<AskBid> {
+    let mut levels: Vec<AskBid> = match side {
+        Side::Buy => book
+            .asks
+            .iter()
+            .filter(|ask| limit.map_or(true, |limit| ask.price <= limit))
+            .copied()
+            .collect(),
+        Side::Sell => book
+            .bids
+            .iter()
+            .filter(|bid| limit.map_or(true, |limit| bid.price >= limit))
+            .copied()
+            .collect(),
+    };
+    match side {
+        Side::Buy => levels.sort_by(|a, b| a.price.cmp(&b.price)),
+        Side::Sell => levels.sort_by(|a, b| b.price.cmp(&a.price)),
+    }
+    levels.retain(|level| level.qty > Decimal::zero());
+    levels
+}
+
+/// Quantities taken from every level to fill `size`
+pub fn walk(levels: &[AskBid], size: Decimal) -> Vec<AskBid> {
+    let mut remaining = size;
+    let mut fills = Vec::new();
+    for level in levels {
+        if remaining.is_zero() {
+            break;
+        }
+        let qty = level.qty.min(remaining);
+        remaining -= qty;
+        fills.push(AskBid {
+            price: level.price,
+            qty,
+        });
+    }
+    fills
+}
+
+/// Indexes of the open orders of a market, best price first and then oldest first
+pub fn resting_orders<O: FundedOrder>(orders: &[O], market_pair: &str) -> Vec<usize> {
+    let mut buys = Vec::new();
+    let mut sells = Vec::new();
+    for (index, order) in orders.iter().enumerate() {
+        if !order.is_open() || !order.order().market_pair.eq_ignore_ascii_case(market_pair) {
+            continue;
+        }
+        match order.order().side {
+            Side::Buy => buys.push(index),
+            Side::Sell => sells.push(index),
+        }
+    }
+
+    let price = |index: &usize| orders[*index].order().price.unwrap_or_default();
+    buys.sort_by(|a, b| price(b).cmp(&price(a)).then(a.cmp(b)));
+    sells.sort_by(|a, b| price(a).cmp(&price(b)).then(a.cmp(b)));
+    buys.append(&mut sells);
+    buys
+}
@@ -0,0 +1,14 @@
+//! This module contains the matching and the accounting shared by the exchanges simulating
+//! orders in process: the paper exchange, the simulated exchange and the execution simulator
+//! of the backtester. Orders lock the funds they may spend, taking orders walk the levels of a
+//! book and fills are settled on a `Ledger`, fees being charged in the quote currency.
+
+mod book_liquidity;
+mod funded_order;
+mod ledger;
+mod levels;
+
+pub use book_liquidity::BookLiquidity;
+pub use funded_order::FundedOrder;
+pub use ledger::Ledger;
+pub use levels::{crossing_levels, resting_orders, walk};
@@ -1,8 +1,16 @@
 //! This module contains all the implemented exchanges.
 
 pub mod binance;
+pub mod bitfinex;
 pub mod coinbase;
+pub mod gemini;
+pub mod kucoin;
+pub mod matching;
 pub mod nash;
+pub mod okx;
+pub mod paper;
+pub mod replay;
+pub mod simulated;
 pub mod traits;
 pub mod any;
 pub mod shared;
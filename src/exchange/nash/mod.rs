@@ -3,12 +3,14 @@
 
 mod nash_credentials;
 mod nash_parameters;
+mod nash_subscription;
 mod nash_websocket;
 mod subscription_response_wrapper;
 mod utils;
 
 pub use nash_credentials::NashCredentials;
 pub use nash_parameters::NashParameters;
+pub use nash_subscription::NashSubscription;
 pub use nash_websocket::NashWebsocket;
 pub use subscription_response_wrapper::SubscriptionResponseWrapper;
 pub use utils::client_from_params_failable;
@@ -34,6 +36,7 @@ use crate::{
     },
     model::websocket::OpenLimitsWebSocketMessage,
 };
+use crate::candles::{CandleLimits, ResampledMarketData};
 use crate::model::websocket::AccountOrders;
 use crate::exchange::traits::info::ExchangeInfo;
 use crate::exchange::traits::info::ExchangeInfoRetrieval;
@@ -42,6 +45,7 @@ use crate::exchange::traits::ExchangeMarketData;
 use crate::exchange::traits::ExchangeAccount;
 use crate::exchange::traits::info::MarketPair;
 use crate::exchange::traits::info::MarketPairHandle;
+use crate::exchange::traits::info::MarketStatus;
 use utils::try_split_paginator;
 use super::shared::{Result, timestamp_to_utc_datetime};
 
@@ -57,10 +61,44 @@ impl Exchange for Nash {
     type InnerClient = Client;
 
     async fn new(params: Self::InitParams) -> Result<Self> {
-        Ok(Self {
+        let cache = params.exchange_info_cache.clone();
+        let public = NashParameters {
+            credentials: None,
+            sign_states_loop_interval: None,
+            fill_pool_loop_interval: None,
+            ..params.clone()
+        };
+        let nash = Self {
             exchange_info: ExchangeInfo::new(),
             transport: client_from_params_failable(params).await?,
-        })
+        };
+
+        match cache {
+            // The client can't be shared with a background task, the markets are refreshed by
+            // a public client of its own
+            Some(cache) if cache.lazy => {
+                cache.load(&nash.exchange_info);
+                let exchange_info = nash.exchange_info.clone();
+                tokio::spawn(async move {
+                    if let Ok(transport) = client_from_params_failable(public).await {
+                        let nash = Nash {
+                            exchange_info,
+                            transport,
+                        };
+                        cache.refresh(&nash, &nash.exchange_info).await.ok();
+                    }
+                });
+            }
+            Some(cache) => {
+                if !cache.load(&nash.exchange_info) {
+                    cache.refresh(&nash, &nash.exchange_info).await?;
+                }
+            }
+            None => {
+                nash.refresh_market_info().await?;
+            }
+        }
+        Ok(nash)
     }
 
     fn inner_client(&self) -> Option<&Self::InnerClient> {
@@ -71,16 +109,21 @@ impl Exchange for Nash {
 #[async_trait]
 impl ExchangeMarketData for Nash {
     async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
-        let req: nash_protocol::protocol::list_candles::ListCandlesRequest = req.try_into()?;
+        let limits = CandleLimits::nash();
+        if !limits.supports(req.interval) {
+            return ResampledMarketData::new(self, limits).get_historic_rates(req).await;
+        }
+        let request: nash_protocol::protocol::list_candles::ListCandlesRequest = req.try_into()?;
 
-        let resp = self.transport.run(req).await;
+        let resp = self.transport.run(request).await;
 
         let resp: nash_protocol::protocol::list_candles::ListCandlesResponse =
             Nash::unwrap_response::<nash_protocol::protocol::list_candles::ListCandlesResponse>(
                 resp,
             )?;
 
-        Ok(resp.candles.into_iter().map(Into::into).collect())
+        let candles: Vec<Candle> = resp.candles.into_iter().map(Into::into).collect();
+        Ok(self.exchange_info.translate_market(&req.market_pair, candles))
     }
 
     async fn get_historic_trades(&self, req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
@@ -91,25 +134,27 @@ impl ExchangeMarketData for Nash {
             nash_protocol::protocol::list_trades::ListTradesResponse,
         >(resp)?;
 
-        Ok(resp.trades.into_iter().map(Into::into).collect())
+        Ok(self
+            .exchange_info
+            .translate(resp.trades.into_iter().map(Into::into).collect()))
     }
 
     async fn get_price_ticker(&self, req: &GetPriceTickerRequest) -> Result<Ticker> {
-        let req: nash_protocol::protocol::get_ticker::TickerRequest = req.into();
-        let resp = self.transport.run(req).await;
-        Ok(
+        let request: nash_protocol::protocol::get_ticker::TickerRequest = req.into();
+        let resp = self.transport.run(request).await;
+        let ticker: Ticker =
             Nash::unwrap_response::<nash_protocol::protocol::get_ticker::TickerResponse>(resp)?
-                .into(),
-        )
+                .into();
+        Ok(self.exchange_info.translate_market(&req.market_pair, ticker))
     }
 
     async fn order_book(&self, req: &OrderBookRequest) -> Result<OrderBookResponse> {
-        let req: nash_protocol::protocol::orderbook::OrderbookRequest = req.into();
-        let resp = self.transport.run(req).await;
-        Ok(
+        let request: nash_protocol::protocol::orderbook::OrderbookRequest = req.into();
+        let resp = self.transport.run(request).await;
+        let book: OrderBookResponse =
             Nash::unwrap_response::<nash_protocol::protocol::orderbook::OrderbookResponse>(resp)?
-                .into(),
-        )
+                .into();
+        Ok(self.exchange_info.translate_market(&req.market_pair, book))
     }
 }
 
@@ -161,11 +206,23 @@ impl ExchangeAccount for Nash {
                     .to_string(),
             )
             .expect("Couldn't parse Decimal from string.");
+            // Deposits are pending until they're moved to the state channel
+            let pending = resp
+                .pending
+                .get(asset)
+                .map(|amount| {
+                    Decimal::from_str(&amount.to_string())
+                        .expect("Couldn't parse Decimal from string.")
+                })
+                .unwrap_or_default();
             let total = free + in_orders;
             balances.push(Balance {
                 asset: asset.name().to_string(),
                 total,
                 free,
+                locked: in_orders,
+                pending_deposit: pending,
+                ..Default::default()
             });
         }
 
@@ -190,7 +247,9 @@ impl ExchangeAccount for Nash {
                 nash_protocol::protocol::list_account_orders::ListAccountOrdersResponse,
             >(resp)?;
 
-        Ok(resp.orders.into_iter().map(Into::into).collect())
+        Ok(self
+            .exchange_info
+            .translate(resp.orders.into_iter().map(Into::into).collect()))
     }
 
     async fn get_order_history(&self, req: &GetOrderHistoryRequest) -> Result<Vec<Order>> {
@@ -204,7 +263,9 @@ impl ExchangeAccount for Nash {
                 nash_protocol::protocol::list_account_orders::ListAccountOrdersResponse,
             >(resp)?;
 
-        Ok(resp.orders.into_iter().map(Into::into).collect())
+        Ok(self
+            .exchange_info
+            .translate(resp.orders.into_iter().map(Into::into).collect()))
     }
 
     async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>> {
@@ -218,7 +279,9 @@ impl ExchangeAccount for Nash {
                 nash_protocol::protocol::list_account_trades::ListAccountTradesResponse,
             >(resp)?;
 
-        Ok(resp.trades.into_iter().map(Into::into).collect())
+        Ok(self
+            .exchange_info
+            .translate(resp.trades.into_iter().map(Into::into).collect()))
     }
 
     async fn limit_buy(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
@@ -227,12 +290,12 @@ impl ExchangeAccount for Nash {
 
         let resp = self.transport.run_http(req).await;
 
-        Ok(
+        let order: Order =
             Nash::unwrap_response::<nash_protocol::protocol::place_order::PlaceOrderResponse>(
                 resp,
             )?
-            .into(),
-        )
+            .into();
+        Ok(self.exchange_info.translate(order))
     }
 
     async fn limit_sell(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
@@ -240,12 +303,12 @@ impl ExchangeAccount for Nash {
             Nash::convert_limit_order(req, nash_protocol::types::BuyOrSell::Sell);
         let resp = self.transport.run_http(req).await;
 
-        Ok(
+        let order: Order =
             Nash::unwrap_response::<nash_protocol::protocol::place_order::PlaceOrderResponse>(
                 resp,
             )?
-            .into(),
-        )
+            .into();
+        Ok(self.exchange_info.translate(order))
     }
 
     async fn market_sell(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
@@ -253,12 +316,12 @@ impl ExchangeAccount for Nash {
             Nash::convert_market_request(req);
 
         let resp = self.transport.run_http(req).await;
-        Ok(
+        let order: Order =
             Nash::unwrap_response::<nash_protocol::protocol::place_order::PlaceOrderResponse>(
                 resp,
             )?
-            .into(),
-        )
+            .into();
+        Ok(self.exchange_info.translate(order))
     }
 
     async fn market_buy(&self, _: &OpenMarketOrderRequest) -> Result<Order> {
@@ -271,7 +334,7 @@ impl ExchangeAccount for Nash {
         let resp = Nash::unwrap_response::<
             nash_protocol::protocol::get_account_order::GetAccountOrderResponse,
         >(resp)?;
-        Ok(resp.order.into())
+        Ok(self.exchange_info.translate(resp.order.into()))
     }
 }
 
@@ -362,6 +425,20 @@ impl ExchangeInfoRetrieval for Nash {
                     Decimal::from_str(&format!("{}", &v.min_trade_size_b.amount.value))
                         .expect("Couldn't create Decimal from string."),
                 ),
+                max_base_trade_size: None,
+                max_quote_trade_size: None,
+                min_price: None,
+                max_price: None,
+                // The markets listed are the ones open for trading
+                status: MarketStatus::Trading,
+                order_types: vec![
+                    OrderType::Limit,
+                    OrderType::Market,
+                    OrderType::StopLimit,
+                    OrderType::StopMarket,
+                ],
+                base_precision: Some(v.asset_a.precision),
+                quote_precision: Some(v.asset_b.precision),
             })
             .collect())
     }
@@ -387,6 +464,8 @@ impl From<&OrderBookRequest> for nash_protocol::protocol::orderbook::OrderbookRe
 impl From<nash_protocol::protocol::orderbook::OrderbookResponse> for OrderBookResponse {
     fn from(book: nash_protocol::protocol::orderbook::OrderbookResponse) -> Self {
         Self {
+            market_pair: None,
+            instrument: None,
             update_id: Some(book.update_id as u64),
             last_update_id: Some(book.last_update_id as u64),
             bids: book.bids.into_iter().map(Into::into).collect(),
@@ -449,6 +528,7 @@ impl From<nash_protocol::protocol::place_order::PlaceOrderResponse> for Order {
         Self {
             id: resp.order_id,
             market_pair: resp.market.name,
+            instrument: None,
             client_order_id: None,
             created_at: Some(resp.placed_at.timestamp_millis() as u64),
             order_type: resp.order_type.into(),
@@ -504,6 +584,7 @@ impl From<nash_protocol::types::Trade> for Trade {
             fees: Some(fees),
             liquidity: Some(resp.account_side.into()),
             market_pair: resp.market.clone(),
+            instrument: None,
             buyer_order_id: Some(buyer_order_id),
             seller_order_id: Some(seller_order_id),
             price,
@@ -605,6 +686,8 @@ impl From<nash_protocol::types::Candle> for Candle {
             .expect("Couldn't parse Decimal from string.");
 
         Self {
+            market_pair: None,
+            instrument: None,
             close,
             high,
             low,
@@ -656,6 +739,7 @@ impl From<nash_protocol::types::Order> for Order {
         Self {
             id: order.id,
             market_pair: order.market.clone(),
+            instrument: None,
             client_order_id: None,
             created_at: Some(order.placed_at.timestamp_millis() as u64),
             order_type: order.order_type.into(),
@@ -733,7 +817,12 @@ impl From<nash_protocol::protocol::get_ticker::TickerResponse> for Ticker {
             .expect("Couldn't parse Decimal from string.");
             price_24h = Some((day_high + day_low) / Decimal::from(2));
         }
-        Self { price, price_24h }
+        Self {
+            market_pair: None,
+            instrument: None,
+            price,
+            price_24h,
+        }
     }
 }
 
@@ -798,31 +887,35 @@ impl From<AccountOrders> for SubscribeAccountOrders {
     }
 }
 
-impl From<Subscription> for nash_protocol::protocol::subscriptions::SubscriptionRequest {
+impl From<Subscription> for NashSubscription {
     fn from(sub: Subscription) -> Self {
+        use nash_protocol::protocol::subscriptions::{
+            new_account_trades::SubscribeAccountTrades,
+            updated_account_balances::SubscribeAccountBalances, SubscriptionRequest,
+        };
         match sub {
-            Subscription::OrderBookUpdates(market) => Self::Orderbook(
+            Subscription::OrderBookUpdates(market) => Self::Request(SubscriptionRequest::Orderbook(
                 nash_protocol::protocol::subscriptions::updated_orderbook::SubscribeOrderbook {
                     market,
                 },
-            ),
-            Subscription::Trades(market) => Self::Trades(
+            )),
+            Subscription::Trades(market) => Self::Request(SubscriptionRequest::Trades(
                 nash_protocol::protocol::subscriptions::trades::SubscribeTrades { market },
+            )),
+            Subscription::AccountOrders(account_orders) => Self::Request(
+                SubscriptionRequest::AccountOrders(account_orders.into())
             ),
-            Subscription::AccountOrders(account_orders) => Self::AccountOrders(
-                account_orders.into()
-            ),
-            Subscription::AccountTrades(market_name) => Self::AccountTrades(
-                nash_protocol::protocol::subscriptions::new_account_trades::SubscribeAccountTrades {
-                    market_name: Some(market_name)
-                }
+            Subscription::AccountTrades(market_name) => Self::Request(
+                SubscriptionRequest::AccountTrades(SubscribeAccountTrades {
+                    market_name: Some(market_name),
+                }),
             ),
-            Subscription::AccountBalance(symbol) => Self::AccountBalances(
-                nash_protocol::protocol::subscriptions::updated_account_balances::SubscribeAccountBalances {
-                    symbol: Some(symbol)
-                }
+            Subscription::AccountBalance(symbol) => Self::Request(
+                SubscriptionRequest::AccountBalances(SubscribeAccountBalances {
+                    symbol: Some(symbol),
+                }),
             ),
-            _ => panic!("Not supported Subscription"),
+            subscription => Self::Unsupported(subscription),
         }
     }
 }
@@ -834,6 +927,8 @@ impl TryFrom<SubscriptionResponseWrapper> for WebSocketResponse<SubscriptionResp
         match value.0 {
             SubscriptionResponse::Orderbook(resp) => Ok(WebSocketResponse::Generic(
                 OpenLimitsWebSocketMessage::OrderBook(OrderBookResponse {
+                    market_pair: None,
+                    instrument: None,
                     update_id: Some(resp.update_id as u64),
                     last_update_id: Some(resp.last_update_id as u64),
                     asks: resp.asks.into_iter().map(Into::into).collect(),
@@ -852,9 +947,12 @@ impl TryFrom<SubscriptionResponseWrapper> for WebSocketResponse<SubscriptionResp
             SubscriptionResponse::AccountTrades(resp) => Ok(WebSocketResponse::Raw(
                 SubscriptionResponseWrapper(SubscriptionResponse::AccountTrades(resp)),
             )),
-            SubscriptionResponse::AccountOrders(resp) => Ok(WebSocketResponse::Raw(
-                SubscriptionResponseWrapper(SubscriptionResponse::AccountOrders(resp)),
-            )),
+            SubscriptionResponse::AccountOrders(resp) => {
+                let orders = resp.orders.into_iter().map(Into::into).collect();
+                Ok(WebSocketResponse::Generic(
+                    OpenLimitsWebSocketMessage::Orders(orders),
+                ))
+            }
             SubscriptionResponse::AccountBalances(resp) => Ok(WebSocketResponse::Raw(
                 SubscriptionResponseWrapper(SubscriptionResponse::AccountBalances(resp)),
             )),
@@ -1,9 +1,12 @@
 use tokio::time::Duration;
 pub use nash_native_client::{Client, Environment};
+use crate::exchange::traits::info::ExchangeInfoCache;
 use super::NashCredentials;
 use nash_protocol::types::Blockchain;
 
-/// This struct represents the parameters
+/// This struct represents the parameters.
+/// The exchange information is read from `exchange_info_cache` when there's one, a lazy cache
+/// being refreshed by a public client of its own.
 #[derive(Clone)]
 pub struct NashParameters {
     pub credentials: Option<NashCredentials>,
@@ -15,4 +18,12 @@ pub struct NashParameters {
     pub client_id: u64,
     pub environment: Environment,
     pub timeout: Duration,
+    pub exchange_info_cache: Option<ExchangeInfoCache>,
+}
+
+impl NashParameters {
+    pub fn with_exchange_info_cache(mut self, cache: ExchangeInfoCache) -> Self {
+        self.exchange_info_cache = Some(cache);
+        self
+    }
 }
@@ -0,0 +1,11 @@
+use nash_protocol::protocol::subscriptions::SubscriptionRequest;
+use crate::model::websocket::Subscription;
+
+/// This enum represents a subscription of the nash websocket
+#[derive(Clone)]
+pub enum NashSubscription {
+    /// A subscription request of the nash protocol
+    Request(SubscriptionRequest),
+    /// Subscriptions without a nash subscription request
+    Unsupported(Subscription),
+}
@@ -2,11 +2,11 @@ use std::{pin::Pin, task::Context, task::Poll};
 use async_trait::async_trait;
 use futures::stream::{BoxStream, SelectAll, Stream, StreamExt};
 pub use nash_native_client::{Client, Environment};
-use nash_protocol::protocol::subscriptions::SubscriptionRequest;
 use nash_protocol::protocol::ResponseOrError;
 use crate::errors::OpenLimitsError;
 use crate::exchange::traits::stream::{ExchangeWs, Subscriptions};
 use super::NashParameters;
+use super::NashSubscription;
 use super::SubscriptionResponseWrapper;
 use super::utils::*;
 use super::shared::Result;
@@ -31,7 +31,7 @@ impl Stream for NashWebsocket {
 impl ExchangeWs for NashWebsocket {
     type InitParams = NashParameters;
 
-    type Subscription = SubscriptionRequest;
+    type Subscription = NashSubscription;
     type Response = SubscriptionResponseWrapper;
 
     async fn new(params: Self::InitParams) -> Result<Self> {
@@ -51,6 +51,12 @@ impl ExchangeWs for NashWebsocket {
         let mut streams = SelectAll::new();
 
         for subscription in subscriptions.into_iter() {
+            let subscription = match subscription {
+                NashSubscription::Request(request) => request,
+                NashSubscription::Unsupported(_) => {
+                    return Err(OpenLimitsError::WebSocketMessageNotSupported())
+                }
+            };
             let stream = self.client.subscribe_protocol(subscription).await?;
             streams.push(tokio_stream::wrappers::UnboundedReceiverStream::new(stream));
         }
@@ -0,0 +1,177 @@
+use chrono::Utc;
+use rust_decimal::prelude::*;
+use crate::exchange::okx::model::{
+    AccountBalance, CancelOrderRequest, Fill, InstrumentType, Order, OrderAck, OrderFilter,
+    OrderRequest, ORDER_SIDE_BUY, ORDER_SIDE_SELL, ORDER_TYPE_MARKET, TARGET_CURRENCY_BASE,
+};
+use crate::exchange::traits::info::MarketPair;
+use serde_json::json;
+use super::BaseClient;
+use super::shared::Result;
+
+impl BaseClient {
+    pub async fn balance(&self) -> Result<Vec<AccountBalance>> {
+        self.transport
+            .signed_get::<_, ()>("/api/v5/account/balance", None)
+            .await
+    }
+
+    pub async fn order(&self, inst_id: &str, ord_id: &str) -> Result<Vec<Order>> {
+        let params = json! {{"instId": inst_id, "ordId": ord_id}};
+        self.transport
+            .signed_get("/api/v5/trade/order", Some(&params))
+            .await
+    }
+
+    pub async fn pending_orders(&self, filter: &OrderFilter) -> Result<Vec<Order>> {
+        self.transport
+            .signed_get("/api/v5/trade/orders-pending", Some(filter))
+            .await
+    }
+
+    // Orders completed in the last 7 days
+    pub async fn orders_history(&self, filter: &OrderFilter) -> Result<Vec<Order>> {
+        self.transport
+            .signed_get("/api/v5/trade/orders-history", Some(filter))
+            .await
+    }
+
+    // Fills of the last 3 days
+    pub async fn fills(&self, filter: &OrderFilter) -> Result<Vec<Fill>> {
+        self.transport
+            .signed_get("/api/v5/trade/fills", Some(filter))
+            .await
+    }
+
+    pub async fn place_order(&self, order: &OrderRequest) -> Result<Vec<OrderAck>> {
+        self.transport.signed_post("/api/v5/trade/order", order).await
+    }
+
+    // Places up to 20 orders at once
+    pub async fn place_orders(&self, orders: &[OrderRequest]) -> Result<Vec<OrderAck>> {
+        self.transport
+            .signed_post("/api/v5/trade/batch-orders", &orders)
+            .await
+    }
+
+    pub async fn cancel_order(&self, order: &CancelOrderRequest) -> Result<Vec<OrderAck>> {
+        self.transport
+            .signed_post("/api/v5/trade/cancel-order", order)
+            .await
+    }
+
+    // Cancels up to 20 orders at once
+    pub async fn cancel_orders(&self, orders: &[CancelOrderRequest]) -> Result<Vec<OrderAck>> {
+        self.transport
+            .signed_post("/api/v5/trade/cancel-batch-orders", &orders)
+            .await
+    }
+
+    // Place a LIMIT order - BUY
+    pub async fn limit_buy(
+        &self,
+        pair: MarketPair,
+        inst_type: InstrumentType,
+        size: Decimal,
+        price: Decimal,
+        ord_type: &str,
+    ) -> Result<Vec<OrderAck>> {
+        let order = OrderRequest {
+            inst_id: pair.symbol,
+            td_mode: inst_type.trade_mode().to_string(),
+            cl_ord_id: client_order_id(),
+            side: ORDER_SIDE_BUY.to_string(),
+            ord_type: ord_type.to_string(),
+            sz: size.round_dp(pair.base_increment.normalize().scale()),
+            px: Some(price.round_dp_with_strategy(
+                pair.quote_increment.normalize().scale(),
+                RoundingStrategy::ToZero,
+            )),
+            tgt_ccy: None,
+        };
+
+        self.place_order(&order).await
+    }
+
+    // Place a LIMIT order - SELL
+    pub async fn limit_sell(
+        &self,
+        pair: MarketPair,
+        inst_type: InstrumentType,
+        size: Decimal,
+        price: Decimal,
+        ord_type: &str,
+    ) -> Result<Vec<OrderAck>> {
+        let order = OrderRequest {
+            inst_id: pair.symbol,
+            td_mode: inst_type.trade_mode().to_string(),
+            cl_ord_id: client_order_id(),
+            side: ORDER_SIDE_SELL.to_string(),
+            ord_type: ord_type.to_string(),
+            sz: size.round_dp(pair.base_increment.normalize().scale()),
+            px: Some(price.round_dp_with_strategy(
+                pair.quote_increment.normalize().scale(),
+                RoundingStrategy::AwayFromZero,
+            )),
+            tgt_ccy: None,
+        };
+
+        self.place_order(&order).await
+    }
+
+    // Place a MARKET order - BUY
+    pub async fn market_buy(
+        &self,
+        pair: MarketPair,
+        inst_type: InstrumentType,
+        size: Decimal,
+    ) -> Result<Vec<OrderAck>> {
+        let order = OrderRequest {
+            inst_id: pair.symbol,
+            td_mode: inst_type.trade_mode().to_string(),
+            cl_ord_id: client_order_id(),
+            side: ORDER_SIDE_BUY.to_string(),
+            ord_type: ORDER_TYPE_MARKET.to_string(),
+            sz: size.round_dp(pair.base_increment.normalize().scale()),
+            px: None,
+            tgt_ccy: target_currency(inst_type),
+        };
+
+        self.place_order(&order).await
+    }
+
+    // Place a MARKET order - SELL
+    pub async fn market_sell(
+        &self,
+        pair: MarketPair,
+        inst_type: InstrumentType,
+        size: Decimal,
+    ) -> Result<Vec<OrderAck>> {
+        let order = OrderRequest {
+            inst_id: pair.symbol,
+            td_mode: inst_type.trade_mode().to_string(),
+            cl_ord_id: client_order_id(),
+            side: ORDER_SIDE_SELL.to_string(),
+            ord_type: ORDER_TYPE_MARKET.to_string(),
+            sz: size.round_dp(pair.base_increment.normalize().scale()),
+            px: None,
+            tgt_ccy: target_currency(inst_type),
+        };
+
+        self.place_order(&order).await
+    }
+}
+
+/// Spot market buys are sized in the quote currency by default, the size of the
+/// requests is always in the base currency.
+fn target_currency(inst_type: InstrumentType) -> Option<String> {
+    match inst_type {
+        InstrumentType::Spot => Some(TARGET_CURRENCY_BASE.to_string()),
+        _ => None,
+    }
+}
+
+/// Client order ids are alphanumeric, up to 32 characters
+fn client_order_id() -> String {
+    format!("ol{}", Utc::now().timestamp_nanos())
+}
@@ -0,0 +1,36 @@
+use serde_json::json;
+use crate::exchange::okx::model::{
+    CandleRequestParams, Candle, Instrument, InstrumentType, OrderBook, Ticker, Trade,
+};
+use super::shared::Result;
+use super::BaseClient;
+
+impl BaseClient {
+    pub async fn instruments(&self, inst_type: InstrumentType) -> Result<Vec<Instrument>> {
+        let params = json! {{"instType": inst_type}};
+        self.transport
+            .get("/api/v5/public/instruments", Some(&params))
+            .await
+    }
+
+    // Order book of the 400 best levels of each side
+    pub async fn order_book(&self, inst_id: &str) -> Result<Vec<OrderBook>> {
+        let params = json! {{"instId": inst_id, "sz": 400}};
+        self.transport.get("/api/v5/market/books", Some(&params)).await
+    }
+
+    pub async fn ticker(&self, inst_id: &str) -> Result<Vec<Ticker>> {
+        let params = json! {{"instId": inst_id}};
+        self.transport.get("/api/v5/market/ticker", Some(&params)).await
+    }
+
+    pub async fn candles(&self, params: &CandleRequestParams) -> Result<Vec<Candle>> {
+        self.transport.get("/api/v5/market/candles", Some(params)).await
+    }
+
+    // The 100 last trades
+    pub async fn trades(&self, inst_id: &str) -> Result<Vec<Trade>> {
+        let params = json! {{"instId": inst_id, "limit": 100}};
+        self.transport.get("/api/v5/market/trades", Some(&params)).await
+    }
+}
@@ -0,0 +1,9 @@
+//! This module is used to make calls to api and connect to the websockets
+mod account;
+mod market;
+mod base_client;
+pub mod websocket;
+
+pub use base_client::BaseClient;
+pub (crate) use super::transport::Transport;
+pub use super::shared;
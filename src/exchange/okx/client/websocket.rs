@@ -0,0 +1,338 @@
+use std::convert::TryFrom;
+use std::sync::Mutex;
+use std::time::Duration;
+use async_trait::async_trait;
+use chrono::Utc;
+use futures::{SinkExt, stream::BoxStream, StreamExt};
+use rust_decimal::prelude::Zero;
+use serde::{de, Deserialize};
+use serde_json::Value;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
+use crate::{
+    errors::OpenLimitsError,
+    exchange::okx::{
+        OkxContentError,
+        OkxParameters,
+        model::websocket::{
+            LoginArg, OkxSubscription, OkxWebsocketMessage, Request, SubscriptionArg,
+            CHANNEL_ACCOUNT, CHANNEL_BOOKS, CHANNEL_ORDERS, CHANNEL_TICKERS, CHANNEL_TRADES,
+        },
+        model::{self, OrderBook},
+        transport::sign,
+    },
+    model::websocket::OpenLimitsWebSocketMessage,
+    model::websocket::WebSocketResponse,
+    model::{Liquidity, MarketResponse, Order, OrderBookResponse, Side, Trade},
+};
+use crate::exchange::traits::stream::{ExchangeWs, Subscriptions};
+use super::shared::Result;
+
+const WS_URL_PUBLIC: &str = "wss://ws.okx.com:8443/ws/v5/public";
+const WS_URL_PRIVATE: &str = "wss://ws.okx.com:8443/ws/v5/private";
+const WS_URL_DEMO_PUBLIC: &str = "wss://wspap.okx.com:8443/ws/v5/public?brokerId=9999";
+const WS_URL_DEMO_PRIVATE: &str = "wss://wspap.okx.com:8443/ws/v5/private?brokerId=9999";
+
+/// The server closes connections that are silent for 30 seconds
+const PING_INTERVAL: Duration = Duration::from_secs(25);
+
+/// This struct is used for websocket communications with okx exchange.
+/// Public and private channels are served by different endpoints, a stream mixing both opens
+/// one connection to each of them.
+pub struct OkxWebsocket {
+    parameters: OkxParameters,
+    disconnection_senders: Mutex<Vec<UnboundedSender<()>>>,
+}
+
+impl OkxWebsocket {
+    async fn connect(
+        &self,
+        url: &str,
+        login: Option<LoginArg>,
+        args: Vec<SubscriptionArg>,
+    ) -> Result<BoxStream<'static, Result<OkxWebsocketMessage>>> {
+        let (ws_stream, _) = connect_async(url).await?;
+        let (mut sink, mut stream) = ws_stream.split();
+
+        // Private channels can only be subscribed once the login is confirmed
+        if let Some(login) = login {
+            sink.send(Message::Text(serde_json::to_string(&Request::login(login))?))
+                .await?;
+            loop {
+                match stream.next().await {
+                    Some(Ok(message)) => match parse_message(message)? {
+                        OkxWebsocketMessage::Login => break,
+                        OkxWebsocketMessage::Error(err) => {
+                            return Err(OpenLimitsError::OkxError(err))
+                        }
+                        _ => (),
+                    },
+                    _ => return Err(OpenLimitsError::SocketError()),
+                }
+            }
+        }
+
+        sink.send(Message::Text(serde_json::to_string(&Request::subscribe(args))?))
+            .await?;
+
+        let (disconnection_sender, mut disconnection_receiver) = unbounded_channel();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(PING_INTERVAL);
+            loop {
+                tokio::select! {
+                    _ = disconnection_receiver.recv() => {
+                        sink.close().await.ok();
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        if sink.send(Message::Text(String::from("ping"))).await.is_err() {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+
+        if let Ok(mut senders) = self.disconnection_senders.lock() {
+            senders.push(disconnection_sender);
+        }
+
+        let s = stream.map(|message| match message {
+            Ok(msg) => parse_message(msg),
+            Err(_) => Err(OpenLimitsError::SocketError()),
+        });
+
+        Ok(s.boxed())
+    }
+
+    fn login(&self) -> Result<LoginArg> {
+        let credentials = self
+            .parameters
+            .credentials
+            .as_ref()
+            .ok_or(OpenLimitsError::NoApiKeySet())?;
+        let timestamp = Utc::now().timestamp().to_string();
+        let sign = sign(
+            &credentials.api_secret,
+            &format!("{}GET/users/self/verify", timestamp),
+        );
+
+        Ok(LoginArg {
+            api_key: credentials.api_key.clone(),
+            passphrase: credentials.passphrase.clone(),
+            timestamp,
+            sign,
+        })
+    }
+}
+
+#[async_trait]
+impl ExchangeWs for OkxWebsocket {
+    type InitParams = OkxParameters;
+    type Subscription = OkxSubscription;
+    type Response = OkxWebsocketMessage;
+
+    async fn new(parameters: Self::InitParams) -> Result<Self> {
+        Ok(OkxWebsocket {
+            parameters,
+            disconnection_senders: Default::default(),
+        })
+    }
+
+    async fn disconnect(&self) {
+        if let Ok(mut senders) = self.disconnection_senders.lock() {
+            for sender in senders.iter() {
+                sender.send(()).ok();
+            }
+            senders.clear();
+        }
+    }
+
+    async fn create_stream_specific(
+        &self,
+        subscriptions: Subscriptions<Self::Subscription>,
+    ) -> Result<BoxStream<'static, Result<Self::Response>>> {
+        let (private, public): (Vec<OkxSubscription>, Vec<OkxSubscription>) = subscriptions
+            .into_iter()
+            .partition(OkxSubscription::is_private);
+        let (public_url, private_url) = match self.parameters.demo {
+            true => (WS_URL_DEMO_PUBLIC, WS_URL_DEMO_PRIVATE),
+            false => (WS_URL_PUBLIC, WS_URL_PRIVATE),
+        };
+
+        let mut streams = Vec::new();
+        if !public.is_empty() {
+            let args = public.iter().map(OkxSubscription::arg).collect();
+            streams.push(self.connect(public_url, None, args).await?);
+        }
+        if !private.is_empty() {
+            let args = private.iter().map(OkxSubscription::arg).collect();
+            streams.push(self.connect(private_url, Some(self.login()?), args).await?);
+        }
+
+        Ok(futures::stream::select_all(streams).boxed())
+    }
+}
+
+#[derive(Deserialize)]
+struct OkxWebsocketEvent {
+    pub event: String,
+    #[serde(default)]
+    pub code: String,
+    #[serde(default)]
+    pub msg: String,
+    #[serde(default)]
+    pub arg: Option<SubscriptionArg>,
+}
+
+#[derive(Deserialize)]
+struct OkxWebsocketPush {
+    pub arg: SubscriptionArg,
+    #[serde(default)]
+    pub action: String,
+    pub data: Value,
+}
+
+impl<'de> Deserialize<'de> for OkxWebsocketMessage {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+
+        if value.get("event").is_some() {
+            let event: OkxWebsocketEvent =
+                serde_json::from_value(value.clone()).map_err(de::Error::custom)?;
+            return Ok(match event.event.as_str() {
+                "login" if event.code == "0" => OkxWebsocketMessage::Login,
+                "login" | "error" => OkxWebsocketMessage::Error(OkxContentError {
+                    code: event.code,
+                    msg: event.msg,
+                }),
+                "subscribe" => match event.arg {
+                    Some(arg) => OkxWebsocketMessage::Subscribed(arg),
+                    None => OkxWebsocketMessage::Unknown(value),
+                },
+                _ => OkxWebsocketMessage::Unknown(value),
+            });
+        }
+
+        let push: OkxWebsocketPush = match serde_json::from_value(value.clone()) {
+            Ok(push) => push,
+            Err(_) => return Ok(OkxWebsocketMessage::Unknown(value)),
+        };
+        match push.arg.channel.as_str() {
+            CHANNEL_BOOKS => {
+                let inst_id = push.arg.inst_id.unwrap_or_default();
+                let book = serde_json::from_value::<Vec<OrderBook>>(push.data)
+                    .map_err(de::Error::custom)?
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| de::Error::custom("empty order book message"))?;
+                match push.action.as_str() {
+                    "snapshot" => Ok(OkxWebsocketMessage::OrderBookSnapshot(inst_id, book)),
+                    _ => Ok(OkxWebsocketMessage::OrderBookUpdate(inst_id, book)),
+                }
+            }
+            CHANNEL_TRADES => Ok(OkxWebsocketMessage::Trades(
+                serde_json::from_value(push.data).map_err(de::Error::custom)?,
+            )),
+            CHANNEL_TICKERS => Ok(OkxWebsocketMessage::Tickers(
+                serde_json::from_value(push.data).map_err(de::Error::custom)?,
+            )),
+            CHANNEL_ORDERS => Ok(OkxWebsocketMessage::Orders(
+                serde_json::from_value(push.data).map_err(de::Error::custom)?,
+            )),
+            CHANNEL_ACCOUNT => Ok(OkxWebsocketMessage::Account(
+                serde_json::from_value(push.data).map_err(de::Error::custom)?,
+            )),
+            _ => Ok(OkxWebsocketMessage::Unknown(value)),
+        }
+    }
+}
+
+impl TryFrom<OkxWebsocketMessage> for WebSocketResponse<OkxWebsocketMessage> {
+    type Error = OpenLimitsError;
+
+    fn try_from(value: OkxWebsocketMessage) -> Result<Self> {
+        match value {
+            OkxWebsocketMessage::OrderBookSnapshot(inst_id, book) => {
+                let book = OrderBookResponse::from(book).with_market_pair(&inst_id);
+                Ok(WebSocketResponse::Generic(OpenLimitsWebSocketMessage::OrderBook(book)))
+            }
+            OkxWebsocketMessage::OrderBookUpdate(inst_id, book) => {
+                let book = OrderBookResponse::from(book).with_market_pair(&inst_id);
+                Ok(WebSocketResponse::Generic(OpenLimitsWebSocketMessage::OrderBookDiff(book)))
+            }
+            OkxWebsocketMessage::Trades(trades) => Ok(WebSocketResponse::Generic(
+                OpenLimitsWebSocketMessage::Trades(trades.into_iter().map(Into::into).collect()),
+            )),
+            OkxWebsocketMessage::Orders(orders) => Ok(WebSocketResponse::Generic(
+                OpenLimitsWebSocketMessage::Orders(orders.into_iter().map(order_update).collect()),
+            )),
+            OkxWebsocketMessage::Ping | OkxWebsocketMessage::Pong => {
+                Ok(WebSocketResponse::Generic(OpenLimitsWebSocketMessage::Ping))
+            }
+            OkxWebsocketMessage::Error(err) => Err(OpenLimitsError::OkxError(err)),
+            OkxWebsocketMessage::Close => Err(OpenLimitsError::SocketError()),
+            _ => Ok(WebSocketResponse::Raw(value)),
+        }
+    }
+}
+
+/// The updates of the orders channel carry the last fill of the order
+fn order_update(order: model::Order) -> Order {
+    let fill = match (order.fill_px, order.fill_sz) {
+        (Some(price), Some(qty)) if !order.trade_id.is_empty() && !qty.is_zero() => {
+            Some((order.trade_id.clone(), price, qty))
+        }
+        _ => None,
+    };
+    let fees = order.fill_fee;
+    let liquidity = match order.exec_type.as_str() {
+        model::EXEC_TYPE_MAKER => Some(Liquidity::Maker),
+        _ => Some(Liquidity::Taker),
+    };
+    let time = order.u_time;
+    let mut update = Order::from(order);
+
+    if let Some((id, price, qty)) = fill {
+        let (buyer_order_id, seller_order_id) = match update.side {
+            Side::Buy => (Some(update.id.clone()), None),
+            Side::Sell => (None, Some(update.id.clone())),
+        };
+        update.trades.push(Trade {
+            id,
+            buyer_order_id,
+            seller_order_id,
+            market_pair: update.market_pair.clone(),
+            instrument: None,
+            price,
+            qty,
+            // Okx reports fees as negative amounts
+            fees: fees.map(|fee| -fee),
+            side: update.side.clone(),
+            liquidity,
+            created_at: time,
+        });
+    }
+
+    update
+}
+
+fn parse_message(ws_message: Message) -> Result<OkxWebsocketMessage> {
+    let msg = match ws_message {
+        Message::Text(m) => m,
+        Message::Binary(b) => return Ok(OkxWebsocketMessage::Binary(b)),
+        Message::Pong(..) => return Ok(OkxWebsocketMessage::Pong),
+        Message::Ping(..) => return Ok(OkxWebsocketMessage::Ping),
+        Message::Close(..) => return Ok(OkxWebsocketMessage::Close),
+    };
+
+    // The keepalive is answered with a bare "pong"
+    if msg == "pong" {
+        return Ok(OkxWebsocketMessage::Pong);
+    }
+
+    serde_json::from_str(&msg).map_err(OpenLimitsError::JsonError)
+}
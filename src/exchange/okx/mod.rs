@@ -0,0 +1,558 @@
+//! This module provides functionality for communicating with the okx v5 API.
+//! # Example
+//! ```
+//! use openlimits::exchange::okx::Okx;
+//! use openlimits::exchange::okx::OkxParameters;
+//! use openlimits::prelude::*;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let okx = Okx::new(OkxParameters::prod())
+//!                         .await
+//!                         .expect("Couldn't create okx client");
+
+//!     let order_book = okx.order_book(&OrderBookRequest {market_pair: "BTC-USDT".to_string()})
+//!                         .await
+//!                         .expect("Couldn't get order book");
+
+//!     println!("{:?}", order_book);
+//! }
+//! ```
+
+use std::convert::TryFrom;
+use async_trait::async_trait;
+use client::BaseClient;
+use transport::Transport;
+use crate::{
+    errors::OpenLimitsError,
+    model::{
+        AskBid, Balance, CancelAllOrdersRequest, CancelOrderRequest, Candle,
+        GetHistoricRatesRequest, GetHistoricTradesRequest, GetOrderHistoryRequest, GetOrderRequest,
+        GetPriceTickerRequest, Interval, Liquidity, OpenLimitOrderRequest, OpenMarketOrderRequest,
+        Order, OrderBookRequest, OrderBookResponse, OrderCanceled, OrderStatus, OrderType,
+        Paginator, Side, Ticker, TimeInForce, Trade, TradeHistoryRequest,
+    },
+};
+use crate::exchange::traits::info::{
+    ExchangeInfoRetrieval, MarketPair, MarketPairHandle, MarketStatus,
+};
+use crate::exchange::traits::Exchange;
+use crate::prelude::*;
+use super::shared::Result;
+
+pub mod client;
+pub mod model;
+mod transport;
+mod okx_content_error;
+mod okx_credentials;
+mod okx_parameters;
+
+pub use client::websocket::OkxWebsocket;
+pub use okx_content_error::OkxContentError;
+pub use okx_credentials::OkxCredentials;
+pub use okx_parameters::OkxParameters;
+pub use super::shared;
+
+/// The main struct of the okx module, it trades the instruments of one type
+#[derive(Clone)]
+pub struct Okx {
+    pub exchange_info: ExchangeInfo,
+    pub client: BaseClient,
+    pub instrument_type: model::InstrumentType,
+}
+
+#[async_trait]
+impl Exchange for Okx {
+    type InitParams = OkxParameters;
+    type InnerClient = BaseClient;
+
+    async fn new(parameters: Self::InitParams) -> Result<Self> {
+        let okx = match parameters.credentials {
+            Some(credentials) => Okx {
+                exchange_info: ExchangeInfo::new(),
+                client: BaseClient {
+                    transport: Transport::with_credential(
+                        &credentials.api_key,
+                        &credentials.api_secret,
+                        &credentials.passphrase,
+                        parameters.demo,
+                    )?,
+                },
+                instrument_type: parameters.instrument_type,
+            },
+            None => Okx {
+                exchange_info: ExchangeInfo::new(),
+                client: BaseClient {
+                    transport: Transport::new(parameters.demo)?,
+                },
+                instrument_type: parameters.instrument_type,
+            },
+        };
+
+        match &parameters.exchange_info_cache {
+            Some(cache) => cache.initialize(&okx, &okx.exchange_info).await?,
+            None => {
+                okx.refresh_market_info().await?;
+            }
+        }
+        Ok(okx)
+    }
+
+    fn inner_client(&self) -> Option<&Self::InnerClient> {
+        Some(&self.client)
+    }
+}
+
+#[async_trait]
+impl ExchangeInfoRetrieval for Okx {
+    async fn retrieve_pairs(&self) -> Result<Vec<MarketPair>> {
+        self.client
+            .instruments(self.instrument_type)
+            .await
+            .map(|v| v.into_iter().map(Into::into).collect())
+    }
+
+    async fn refresh_market_info(&self) -> Result<Vec<MarketPairHandle>> {
+        self.exchange_info
+            .refresh(self as &dyn ExchangeInfoRetrieval)
+            .await
+    }
+
+    async fn get_pair(&self, name: &str) -> Result<MarketPairHandle> {
+        self.exchange_info.get_pair(name)
+    }
+}
+
+#[async_trait]
+impl ExchangeMarketData for Okx {
+    async fn order_book(&self, req: &OrderBookRequest) -> Result<OrderBookResponse> {
+        self.client
+            .order_book(&req.market_pair)
+            .await
+            .and_then(first)
+            .map(|book: OrderBookResponse| {
+                self.exchange_info.translate_market(&req.market_pair, book)
+            })
+    }
+
+    async fn get_price_ticker(&self, req: &GetPriceTickerRequest) -> Result<Ticker> {
+        self.client
+            .ticker(&req.market_pair)
+            .await
+            .and_then(first)
+            .map(|ticker: Ticker| self.exchange_info.translate_market(&req.market_pair, ticker))
+    }
+
+    async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
+        // Candles are paginated by timestamp, `after` returns the older ones
+        let paginator = req.paginator.clone().unwrap_or_default();
+        let params = model::CandleRequestParams {
+            inst_id: req.market_pair.clone(),
+            bar: model::Bar::try_from(req.interval)?,
+            paginator: model::Paginator {
+                after: paginator.after.or_else(|| paginator.end_time.map(|t| t.to_string())),
+                before: paginator
+                    .before
+                    .or_else(|| paginator.start_time.map(|t| t.to_string())),
+                limit: paginator.limit,
+            },
+        };
+        self.client
+            .candles(&params)
+            .await
+            .map(|v| {
+                let candles: Vec<Candle> = v.into_iter().map(Into::into).collect();
+                self.exchange_info.translate_market(&req.market_pair, candles)
+            })
+    }
+
+    async fn get_historic_trades(&self, req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
+        self.client
+            .trades(&req.market_pair)
+            .await
+            .map(|v| self.exchange_info.translate(v.into_iter().map(Into::into).collect()))
+    }
+}
+
+#[async_trait]
+impl ExchangeAccount for Okx {
+    async fn limit_buy(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
+        let ord_type = limit_order_type(req.time_in_force, req.post_only)?;
+        let acks = self
+            .client
+            .limit_buy(pair, self.instrument_type, req.size, req.price, ord_type)
+            .await?;
+        self.placed_order(&req.market_pair, acks).await
+    }
+
+    async fn limit_sell(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
+        let ord_type = limit_order_type(req.time_in_force, req.post_only)?;
+        let acks = self
+            .client
+            .limit_sell(pair, self.instrument_type, req.size, req.price, ord_type)
+            .await?;
+        self.placed_order(&req.market_pair, acks).await
+    }
+
+    async fn market_buy(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
+        let acks = self
+            .client
+            .market_buy(pair, self.instrument_type, req.size)
+            .await?;
+        self.placed_order(&req.market_pair, acks).await
+    }
+
+    async fn market_sell(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        let pair = self.exchange_info.get_pair(&req.market_pair)?.read()?;
+        let acks = self
+            .client
+            .market_sell(pair, self.instrument_type, req.size)
+            .await?;
+        self.placed_order(&req.market_pair, acks).await
+    }
+
+    async fn cancel_order(&self, req: &CancelOrderRequest) -> Result<OrderCanceled> {
+        let request = model::CancelOrderRequest {
+            inst_id: required_market_pair(req.market_pair.as_ref())?,
+            ord_id: req.id.clone(),
+        };
+        self.client
+            .cancel_order(&request)
+            .await
+            .and_then(first)
+            .map(Into::into)
+    }
+
+    async fn cancel_all_orders(&self, req: &CancelAllOrdersRequest) -> Result<Vec<OrderCanceled>> {
+        // There is no endpoint canceling everything, the open orders are canceled in batches
+        let filter = model::OrderFilter {
+            inst_type: Some(self.instrument_type),
+            inst_id: req.market_pair.clone(),
+            ..Default::default()
+        };
+        let requests: Vec<model::CancelOrderRequest> = self
+            .client
+            .pending_orders(&filter)
+            .await?
+            .into_iter()
+            .map(|order| model::CancelOrderRequest {
+                inst_id: order.inst_id,
+                ord_id: order.ord_id,
+            })
+            .collect();
+
+        let mut canceled = Vec::with_capacity(requests.len());
+        for batch in requests.chunks(model::BATCH_LIMIT) {
+            let acks = self.client.cancel_orders(batch).await?;
+            canceled.extend(acks.into_iter().map(OrderCanceled::from));
+        }
+
+        Ok(canceled)
+    }
+
+    async fn get_all_open_orders(&self) -> Result<Vec<Order>> {
+        let filter = model::OrderFilter {
+            inst_type: Some(self.instrument_type),
+            ..Default::default()
+        };
+        self.client
+            .pending_orders(&filter)
+            .await
+            .map(|v| self.exchange_info.translate(v.into_iter().map(Into::into).collect()))
+    }
+
+    async fn get_order_history(&self, req: &GetOrderHistoryRequest) -> Result<Vec<Order>> {
+        let filter = model::OrderFilter {
+            inst_type: Some(self.instrument_type),
+            inst_id: req.market_pair.clone(),
+            ord_id: None,
+            paginator: req.paginator.clone().map(Into::into).unwrap_or_default(),
+        };
+        self.client
+            .orders_history(&filter)
+            .await
+            .map(|v| self.exchange_info.translate(v.into_iter().map(Into::into).collect()))
+    }
+
+    async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>> {
+        let filter = model::OrderFilter {
+            inst_type: Some(self.instrument_type),
+            inst_id: req.market_pair.clone(),
+            ord_id: req.order_id.clone(),
+            paginator: req.paginator.clone().map(Into::into).unwrap_or_default(),
+        };
+        self.client
+            .fills(&filter)
+            .await
+            .map(|v| self.exchange_info.translate(v.into_iter().map(Into::into).collect()))
+    }
+
+    async fn get_account_balances(&self, _paginator: Option<Paginator>) -> Result<Vec<Balance>> {
+        self.client.balance().await.map(|v| {
+            v.into_iter()
+                .flat_map(|balance| balance.details)
+                .map(Into::into)
+                .collect()
+        })
+    }
+
+    async fn get_order(&self, req: &GetOrderRequest) -> Result<Order> {
+        let inst_id = required_market_pair(req.market_pair.as_ref())?;
+        self.client
+            .order(&inst_id, &req.id)
+            .await
+            .and_then(first)
+            .map(|order: Order| self.exchange_info.translate(order))
+    }
+}
+
+impl Okx {
+    /// The order endpoints only acknowledge the order, it is fetched to return its state
+    async fn placed_order(&self, inst_id: &str, acks: Vec<model::OrderAck>) -> Result<Order> {
+        let ack = first(acks)?;
+        self.client
+            .order(inst_id, &ack.ord_id)
+            .await
+            .and_then(first)
+            .map(|order: Order| self.exchange_info.translate(order))
+    }
+}
+
+/// Okx wraps single objects in an array
+fn first<T>(items: Vec<T>) -> Result<T> {
+    items
+        .into_iter()
+        .next()
+        .ok_or_else(|| OpenLimitsError::UnkownResponse("okx returned no data".to_string()))
+}
+
+/// Orders are looked up and canceled by instrument and id
+fn required_market_pair(market_pair: Option<&String>) -> Result<String> {
+    market_pair.cloned().ok_or_else(|| {
+        OpenLimitsError::MissingParameter("market_pair parameter is required.".to_string())
+    })
+}
+
+fn limit_order_type(tif: TimeInForce, post_only: bool) -> Result<&'static str> {
+    match tif {
+        TimeInForce::GoodTillCancelled if post_only => Ok(model::ORDER_TYPE_POST_ONLY),
+        TimeInForce::GoodTillCancelled => Ok(model::ORDER_TYPE_LIMIT),
+        TimeInForce::ImmediateOrCancelled => Ok(model::ORDER_TYPE_IOC),
+        TimeInForce::FillOrKill => Ok(model::ORDER_TYPE_FOK),
+        TimeInForce::GoodTillTime(_) => Err(OpenLimitsError::InvalidParameter(
+            "GoodTillTime is not supported in Okx".to_string(),
+        )),
+    }
+}
+
+impl From<model::Instrument> for MarketPair {
+    fn from(instrument: model::Instrument) -> Self {
+        let (base, quote) = instrument.base_and_quote();
+        Self {
+            symbol: instrument.inst_id,
+            base,
+            quote,
+            base_increment: instrument.lot_sz,
+            quote_increment: instrument.tick_sz,
+            min_base_trade_size: Some(instrument.min_sz),
+            status: match instrument.state.as_str() {
+                "live" => MarketStatus::Trading,
+                _ => MarketStatus::Halted,
+            },
+            ..Default::default()
+        }
+    }
+}
+
+impl From<model::BookLevel> for AskBid {
+    fn from(level: model::BookLevel) -> Self {
+        Self {
+            price: level.price,
+            qty: level.size,
+        }
+    }
+}
+
+impl From<model::OrderBook> for OrderBookResponse {
+    fn from(book: model::OrderBook) -> Self {
+        Self {
+            market_pair: None,
+            instrument: None,
+            update_id: book.seq_id,
+            // Snapshots have a previous sequence id of -1
+            last_update_id: book.prev_seq_id.and_then(|id| u64::try_from(id).ok()),
+            bids: book.bids.into_iter().map(Into::into).collect(),
+            asks: book.asks.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<model::Ticker> for Ticker {
+    fn from(ticker: model::Ticker) -> Self {
+        Self {
+            market_pair: Some(ticker.inst_id),
+            instrument: None,
+            price: ticker.last,
+            price_24h: ticker.open24h,
+        }
+    }
+}
+
+impl From<model::Candle> for Candle {
+    fn from(candle: model::Candle) -> Self {
+        Self {
+            market_pair: None,
+            instrument: None,
+            time: candle.ts,
+            low: candle.low,
+            high: candle.high,
+            open: candle.open,
+            close: candle.close,
+            volume: candle.volume,
+        }
+    }
+}
+
+impl From<model::Trade> for Trade {
+    fn from(trade: model::Trade) -> Self {
+        Self {
+            id: trade.trade_id,
+            buyer_order_id: None,
+            seller_order_id: None,
+            market_pair: trade.inst_id,
+            instrument: None,
+            price: trade.px,
+            qty: trade.sz,
+            fees: None,
+            side: Side::from(trade.side),
+            liquidity: None,
+            created_at: trade.ts,
+        }
+    }
+}
+
+impl From<model::Order> for Order {
+    fn from(order: model::Order) -> Self {
+        let order_type = match order.ord_type.as_str() {
+            model::ORDER_TYPE_MARKET => OrderType::Market,
+            model::ORDER_TYPE_LIMIT
+            | model::ORDER_TYPE_POST_ONLY
+            | model::ORDER_TYPE_FOK
+            | model::ORDER_TYPE_IOC => OrderType::Limit,
+            _ => OrderType::Unknown,
+        };
+        let status = match order.state.as_str() {
+            model::ORDER_STATE_LIVE => OrderStatus::Open,
+            model::ORDER_STATE_PARTIALLY_FILLED => OrderStatus::PartiallyFilled,
+            model::ORDER_STATE_FILLED => OrderStatus::Filled,
+            // `canceled` and `mmp_canceled`
+            _ => OrderStatus::Canceled,
+        };
+        let client_order_id = match order.cl_ord_id.is_empty() {
+            true => None,
+            false => Some(order.cl_ord_id),
+        };
+
+        Self {
+            id: order.ord_id,
+            market_pair: order.inst_id,
+            instrument: None,
+            client_order_id,
+            created_at: Some(order.c_time),
+            order_type,
+            side: Side::from(order.side),
+            status,
+            remaining: Some(order.sz - order.acc_fill_sz),
+            size: order.sz,
+            price: order.px,
+            trades: Vec::new(),
+        }
+    }
+}
+
+impl From<model::OrderAck> for OrderCanceled {
+    fn from(ack: model::OrderAck) -> Self {
+        Self { id: ack.ord_id }
+    }
+}
+
+impl From<model::BalanceDetail> for Balance {
+    fn from(detail: model::BalanceDetail) -> Self {
+        Self {
+            asset: detail.ccy,
+            free: detail.avail_bal.unwrap_or(detail.cash_bal),
+            total: detail.cash_bal,
+            locked: detail.frozen_bal.unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<model::Fill> for Trade {
+    fn from(fill: model::Fill) -> Self {
+        let side = Side::from(fill.side);
+        let (buyer_order_id, seller_order_id) = match side {
+            Side::Buy => (Some(fill.ord_id), None),
+            Side::Sell => (None, Some(fill.ord_id)),
+        };
+
+        Self {
+            id: fill.trade_id,
+            buyer_order_id,
+            seller_order_id,
+            market_pair: fill.inst_id,
+            instrument: None,
+            price: fill.fill_px,
+            qty: fill.fill_sz,
+            // Okx reports fees as negative amounts
+            fees: Some(-fill.fee),
+            side,
+            liquidity: match fill.exec_type.as_str() {
+                model::EXEC_TYPE_MAKER => Some(Liquidity::Maker),
+                _ => Some(Liquidity::Taker),
+            },
+            created_at: fill.ts,
+        }
+    }
+}
+
+/// The account endpoints are paginated by id, `after` returns the older records
+impl From<Paginator> for model::Paginator {
+    fn from(paginator: Paginator) -> Self {
+        Self {
+            after: paginator.after,
+            before: paginator.before,
+            limit: paginator.limit,
+        }
+    }
+}
+
+impl TryFrom<Interval> for model::Bar {
+    type Error = OpenLimitsError;
+
+    fn try_from(interval: Interval) -> Result<Self> {
+        match interval {
+            Interval::OneMinute => Ok(model::Bar::OneMinute),
+            Interval::ThreeMinutes => Ok(model::Bar::ThreeMinutes),
+            Interval::FiveMinutes => Ok(model::Bar::FiveMinutes),
+            Interval::FifteenMinutes => Ok(model::Bar::FifteenMinutes),
+            Interval::ThirtyMinutes => Ok(model::Bar::ThirtyMinutes),
+            Interval::OneHour => Ok(model::Bar::OneHour),
+            Interval::TwoHours => Ok(model::Bar::TwoHours),
+            Interval::FourHours => Ok(model::Bar::FourHours),
+            Interval::SixHours => Ok(model::Bar::SixHours),
+            Interval::TwelveHours => Ok(model::Bar::TwelveHours),
+            Interval::OneDay => Ok(model::Bar::OneDay),
+            Interval::ThreeDays => Ok(model::Bar::ThreeDays),
+            Interval::OneWeek => Ok(model::Bar::OneWeek),
+            Interval::OneMonth => Ok(model::Bar::OneMonth),
+            _ => Err(OpenLimitsError::InvalidParameter(format!(
+                "{:?} is not supported in Okx",
+                interval,
+            ))),
+        }
+    }
+}
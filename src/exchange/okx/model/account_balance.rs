@@ -0,0 +1,18 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_opt_decimal;
+use super::shared::string_to_u64;
+use super::BalanceDetail;
+
+/// This struct represents the balance of the unified account
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalance {
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub total_eq: Option<Decimal>,
+    pub details: Vec<BalanceDetail>,
+    #[serde(with = "string_to_u64")]
+    pub u_time: u64,
+}
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+use super::shared::string_to_opt_decimal;
+
+/// This struct represents the balance of one currency
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceDetail {
+    pub ccy: String,
+    #[serde(with = "string_to_decimal")]
+    pub eq: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub cash_bal: Decimal,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub avail_bal: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub frozen_bal: Option<Decimal>,
+}
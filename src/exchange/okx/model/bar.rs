@@ -0,0 +1,35 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This enum represents the candle sizes, the ones above four hours are aligned to UTC
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bar {
+    #[serde(rename = "1m")]
+    OneMinute,
+    #[serde(rename = "3m")]
+    ThreeMinutes,
+    #[serde(rename = "5m")]
+    FiveMinutes,
+    #[serde(rename = "15m")]
+    FifteenMinutes,
+    #[serde(rename = "30m")]
+    ThirtyMinutes,
+    #[serde(rename = "1H")]
+    OneHour,
+    #[serde(rename = "2H")]
+    TwoHours,
+    #[serde(rename = "4H")]
+    FourHours,
+    #[serde(rename = "6Hutc")]
+    SixHours,
+    #[serde(rename = "12Hutc")]
+    TwelveHours,
+    #[serde(rename = "1Dutc")]
+    OneDay,
+    #[serde(rename = "3Dutc")]
+    ThreeDays,
+    #[serde(rename = "1Wutc")]
+    OneWeek,
+    #[serde(rename = "1Mutc")]
+    OneMonth,
+}
@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents an order cancelation, it is also an item of the batch endpoint
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderRequest {
+    pub inst_id: String,
+    pub ord_id: String,
+}
@@ -0,0 +1,30 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+use super::shared::string_to_u64;
+
+/// This struct represents a candle
+/// `[ts, open, high, low, close, volume, volume currency, volume quote, confirm]`,
+/// the time is in milliseconds.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Candle {
+    #[serde(with = "string_to_u64")]
+    pub ts: u64,
+    #[serde(with = "string_to_decimal")]
+    pub open: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub high: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub low: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub close: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub volume: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub volume_currency: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub volume_quote: Decimal,
+    /// "1" once the candle is closed
+    pub confirm: String,
+}
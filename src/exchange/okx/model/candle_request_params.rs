@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::Bar;
+use super::Paginator;
+
+/// This struct represents the candle request params, `after` and `before` are
+/// timestamps in milliseconds
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CandleRequestParams {
+    pub inst_id: String,
+    pub bar: Bar,
+    #[serde(flatten)]
+    pub paginator: Paginator,
+}
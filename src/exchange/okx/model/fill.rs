@@ -0,0 +1,29 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+use super::shared::string_to_u64;
+use super::InstrumentType;
+
+/// This struct represents a fill of one of the account orders,
+/// fees are negative and rebates positive
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Fill {
+    pub inst_type: InstrumentType,
+    pub inst_id: String,
+    pub trade_id: String,
+    pub ord_id: String,
+    pub bill_id: String,
+    #[serde(with = "string_to_decimal")]
+    pub fill_px: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub fill_sz: Decimal,
+    pub side: String,
+    pub exec_type: String,
+    #[serde(with = "string_to_decimal")]
+    pub fee: Decimal,
+    pub fee_ccy: String,
+    #[serde(with = "string_to_u64")]
+    pub ts: u64,
+}
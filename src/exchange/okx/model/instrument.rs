@@ -0,0 +1,51 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+use super::shared::string_to_opt_decimal;
+use super::InstrumentType;
+
+/// This struct represents an instrument of any type. Spot and margin instruments have
+/// base and quote currencies, derivatives are described by their underlying
+/// (e.g. `BTC-USD`) and contract value.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Instrument {
+    pub inst_type: InstrumentType,
+    pub inst_id: String,
+    #[serde(default)]
+    pub uly: String,
+    #[serde(default)]
+    pub base_ccy: String,
+    #[serde(default)]
+    pub quote_ccy: String,
+    #[serde(default)]
+    pub settle_ccy: String,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub ct_val: Option<Decimal>,
+    #[serde(default)]
+    pub ct_val_ccy: String,
+    #[serde(with = "string_to_decimal")]
+    pub tick_sz: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub lot_sz: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub min_sz: Decimal,
+    #[serde(default)]
+    pub exp_time: String,
+    pub state: String,
+}
+
+impl Instrument {
+    pub fn base_and_quote(&self) -> (String, String) {
+        if !self.base_ccy.is_empty() {
+            return (self.base_ccy.clone(), self.quote_ccy.clone());
+        }
+
+        match self.uly.find('-') {
+            Some(i) => (self.uly[..i].to_string(), self.uly[i + 1..].to_string()),
+            None => (self.uly.clone(), self.settle_ccy.clone()),
+        }
+    }
+}
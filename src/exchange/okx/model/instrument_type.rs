@@ -0,0 +1,40 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This enum represents the instrument types of the unified account
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum InstrumentType {
+    Spot,
+    Margin,
+    Swap,
+    Futures,
+    Option,
+}
+
+impl Default for InstrumentType {
+    fn default() -> Self {
+        InstrumentType::Spot
+    }
+}
+
+impl InstrumentType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InstrumentType::Spot => "SPOT",
+            InstrumentType::Margin => "MARGIN",
+            InstrumentType::Swap => "SWAP",
+            InstrumentType::Futures => "FUTURES",
+            InstrumentType::Option => "OPTION",
+        }
+    }
+
+    /// Spot orders are paid with the available balance, every other instrument is traded
+    /// on cross margin.
+    pub fn trade_mode(&self) -> &'static str {
+        match self {
+            InstrumentType::Spot => "cash",
+            _ => "cross",
+        }
+    }
+}
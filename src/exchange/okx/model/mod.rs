@@ -0,0 +1,56 @@
+//! This module provides models that are used in the okx module
+
+pub const ORDER_SIDE_BUY: &str = "buy";
+pub const ORDER_SIDE_SELL: &str = "sell";
+pub const ORDER_TYPE_LIMIT: &str = "limit";
+pub const ORDER_TYPE_MARKET: &str = "market";
+pub const ORDER_TYPE_POST_ONLY: &str = "post_only";
+pub const ORDER_TYPE_FOK: &str = "fok";
+pub const ORDER_TYPE_IOC: &str = "ioc";
+pub const ORDER_STATE_LIVE: &str = "live";
+pub const ORDER_STATE_PARTIALLY_FILLED: &str = "partially_filled";
+pub const ORDER_STATE_FILLED: &str = "filled";
+pub const EXEC_TYPE_MAKER: &str = "M";
+pub const TARGET_CURRENCY_BASE: &str = "base_ccy";
+/// Maximum number of orders of the batch endpoints
+pub const BATCH_LIMIT: usize = 20;
+
+mod account_balance;
+mod balance_detail;
+mod bar;
+mod book_level;
+mod cancel_order_request;
+mod candle;
+mod candle_request_params;
+mod fill;
+mod instrument;
+mod instrument_type;
+mod order;
+mod order_ack;
+mod order_book;
+mod order_filter;
+mod order_request;
+mod paginator;
+mod ticker;
+mod trade;
+pub mod websocket;
+
+pub use account_balance::AccountBalance;
+pub use balance_detail::BalanceDetail;
+pub use bar::Bar;
+pub use book_level::BookLevel;
+pub use cancel_order_request::CancelOrderRequest;
+pub use candle::Candle;
+pub use candle_request_params::CandleRequestParams;
+pub use fill::Fill;
+pub use instrument::Instrument;
+pub use instrument_type::InstrumentType;
+pub use order::Order;
+pub use order_ack::OrderAck;
+pub use order_book::OrderBook;
+pub use order_filter::OrderFilter;
+pub use order_request::OrderRequest;
+pub use paginator::Paginator;
+pub use ticker::Ticker;
+pub use trade::Trade;
+pub use super::shared;
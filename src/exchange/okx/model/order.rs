@@ -0,0 +1,54 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+use super::shared::string_to_opt_decimal;
+use super::shared::string_to_u64;
+use super::InstrumentType;
+
+/// This struct represents an order, market orders have an empty price.
+/// The `fill` fields describe the last fill and are only sent by the orders channel
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub inst_type: InstrumentType,
+    pub inst_id: String,
+    pub ord_id: String,
+    #[serde(default)]
+    pub cl_ord_id: String,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub px: Option<Decimal>,
+    #[serde(with = "string_to_decimal")]
+    pub sz: Decimal,
+    pub ord_type: String,
+    pub side: String,
+    pub state: String,
+    #[serde(with = "string_to_decimal")]
+    pub acc_fill_sz: Decimal,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub avg_px: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub fee: Option<Decimal>,
+    #[serde(default)]
+    pub fee_ccy: String,
+    #[serde(with = "string_to_u64")]
+    pub c_time: u64,
+    #[serde(with = "string_to_u64")]
+    pub u_time: u64,
+    #[serde(default)]
+    pub trade_id: String,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub fill_px: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub fill_sz: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub fill_fee: Option<Decimal>,
+    #[serde(default)]
+    pub exec_type: String,
+}
@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents the result of an order placement or cancelation
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAck {
+    pub ord_id: String,
+    #[serde(default)]
+    pub cl_ord_id: String,
+    pub s_code: String,
+    #[serde(default)]
+    pub s_msg: String,
+}
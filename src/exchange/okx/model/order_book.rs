@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::shared::string_to_u64;
+use super::BookLevel;
+
+/// This struct represents the order book, the websocket updates also carry the sequence ids
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderBook {
+    pub asks: Vec<BookLevel>,
+    pub bids: Vec<BookLevel>,
+    #[serde(with = "string_to_u64")]
+    pub ts: u64,
+    #[serde(default)]
+    pub checksum: Option<i64>,
+    #[serde(default)]
+    pub seq_id: Option<u64>,
+    #[serde(default)]
+    pub prev_seq_id: Option<i64>,
+}
@@ -0,0 +1,18 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::InstrumentType;
+use super::Paginator;
+
+/// This struct filters the orders and the fills, only the fills accept `ord_id`
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_type: Option<InstrumentType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ord_id: Option<String>,
+    #[serde(flatten)]
+    pub paginator: Paginator,
+}
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+use super::shared::string_to_opt_decimal;
+
+/// This struct represents an order submission, it is also an item of the batch endpoint.
+/// `ord_type` carries the time in force (`limit`, `post_only`, `fok`, `ioc` or `market`).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderRequest {
+    pub inst_id: String,
+    pub td_mode: String,
+    pub cl_ord_id: String,
+    pub side: String,
+    pub ord_type: String,
+    #[serde(with = "string_to_decimal")]
+    pub sz: Decimal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "string_to_opt_decimal")]
+    #[serde(default)]
+    pub px: Option<Decimal>,
+    /// Spot market orders are sized in the quote currency unless this is `base_ccy`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tgt_ccy: Option<String>,
+}
@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents a paginator, `after` and `before` are the ids (or timestamps for
+/// candles) the page is older or newer than
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Paginator {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+}
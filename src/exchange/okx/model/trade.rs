@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+use super::shared::string_to_u64;
+
+/// This struct represents a public trade, the side is the taker side
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub inst_id: String,
+    pub trade_id: String,
+    #[serde(with = "string_to_decimal")]
+    pub px: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub sz: Decimal,
+    pub side: String,
+    #[serde(with = "string_to_u64")]
+    pub ts: u64,
+}
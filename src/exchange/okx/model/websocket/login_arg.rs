@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents the login of a private connection, the timestamp is in seconds
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginArg {
+    pub api_key: String,
+    pub passphrase: String,
+    pub timestamp: String,
+    pub sign: String,
+}
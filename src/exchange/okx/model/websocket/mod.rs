@@ -0,0 +1,24 @@
+//! Websocket model
+
+use super::AccountBalance;
+use super::InstrumentType;
+use super::Order;
+use super::OrderBook;
+use super::Ticker;
+use super::Trade;
+use super::super::OkxContentError;
+
+mod login_arg;
+mod okx_subscription;
+mod okx_websocket_message;
+mod request;
+mod subscription_arg;
+
+pub use login_arg::LoginArg;
+pub use okx_subscription::{
+    OkxSubscription, CHANNEL_ACCOUNT, CHANNEL_BOOKS, CHANNEL_ORDERS, CHANNEL_TICKERS,
+    CHANNEL_TRADES,
+};
+pub use okx_websocket_message::OkxWebsocketMessage;
+pub use request::Request;
+pub use subscription_arg::SubscriptionArg;
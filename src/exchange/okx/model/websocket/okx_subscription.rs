@@ -0,0 +1,65 @@
+use crate::model::websocket::Subscription;
+use super::InstrumentType;
+use super::SubscriptionArg;
+
+pub const CHANNEL_BOOKS: &str = "books";
+pub const CHANNEL_TRADES: &str = "trades";
+pub const CHANNEL_TICKERS: &str = "tickers";
+pub const CHANNEL_ORDERS: &str = "orders";
+pub const CHANNEL_ACCOUNT: &str = "account";
+
+/// This enum represents an okx subscription
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OkxSubscription {
+    /// Instrument id, snapshot followed by incremental updates of 400 levels
+    Books(String),
+    /// Instrument id
+    Trades(String),
+    /// Instrument id
+    Tickers(String),
+    /// Order changes of the account, of every instrument type when `None`
+    Orders(Option<InstrumentType>),
+    /// Balance changes of the account
+    Account,
+}
+
+impl OkxSubscription {
+    pub fn arg(&self) -> SubscriptionArg {
+        let (channel, inst_id, inst_type) = match self {
+            OkxSubscription::Books(inst_id) => (CHANNEL_BOOKS, Some(inst_id.clone()), None),
+            OkxSubscription::Trades(inst_id) => (CHANNEL_TRADES, Some(inst_id.clone()), None),
+            OkxSubscription::Tickers(inst_id) => (CHANNEL_TICKERS, Some(inst_id.clone()), None),
+            OkxSubscription::Orders(inst_type) => (
+                CHANNEL_ORDERS,
+                None,
+                Some(inst_type.map_or("ANY", |inst_type| inst_type.as_str()).to_string()),
+            ),
+            OkxSubscription::Account => (CHANNEL_ACCOUNT, None, None),
+        };
+
+        SubscriptionArg {
+            channel: channel.to_string(),
+            inst_id,
+            inst_type,
+        }
+    }
+
+    /// Private channels are served by a different endpoint and need a login
+    pub fn is_private(&self) -> bool {
+        matches!(self, OkxSubscription::Orders(_) | OkxSubscription::Account)
+    }
+}
+
+impl From<Subscription> for OkxSubscription {
+    fn from(subscription: Subscription) -> Self {
+        match subscription {
+            Subscription::OrderBookUpdates(inst_id) => OkxSubscription::Books(inst_id),
+            Subscription::Trades(inst_id) => OkxSubscription::Trades(inst_id),
+            Subscription::Ticker(inst_id) => OkxSubscription::Tickers(inst_id),
+            Subscription::AccountOrders(_) | Subscription::AccountTrades(_) => {
+                OkxSubscription::Orders(None)
+            }
+            Subscription::AccountBalance(_) => OkxSubscription::Account,
+        }
+    }
+}
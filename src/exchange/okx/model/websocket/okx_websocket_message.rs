@@ -0,0 +1,29 @@
+use serde::Serialize;
+use serde_json::Value;
+use super::AccountBalance;
+use super::OkxContentError;
+use super::Order;
+use super::OrderBook;
+use super::SubscriptionArg;
+use super::Ticker;
+use super::Trade;
+
+/// This enum represents the types of websocket messages
+#[derive(Debug, Clone, Serialize)]
+pub enum OkxWebsocketMessage {
+    Login,
+    Subscribed(SubscriptionArg),
+    Error(OkxContentError),
+    /// The whole book, the following messages of the channel are incremental
+    OrderBookSnapshot(String, OrderBook),
+    OrderBookUpdate(String, OrderBook),
+    Trades(Vec<Trade>),
+    Tickers(Vec<Ticker>),
+    Orders(Vec<Order>),
+    Account(Vec<AccountBalance>),
+    Unknown(Value),
+    Ping,
+    Pong,
+    Close,
+    Binary(Vec<u8>), // Unexpected, unparsed
+}
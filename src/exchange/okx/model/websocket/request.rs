@@ -0,0 +1,29 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::LoginArg;
+use super::SubscriptionArg;
+
+/// This struct represents an operation sent to the websocket server
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Request<T> {
+    pub op: String,
+    pub args: Vec<T>,
+}
+
+impl Request<SubscriptionArg> {
+    pub fn subscribe(args: Vec<SubscriptionArg>) -> Self {
+        Self {
+            op: String::from("subscribe"),
+            args,
+        }
+    }
+}
+
+impl Request<LoginArg> {
+    pub fn login(arg: LoginArg) -> Self {
+        Self {
+            op: String::from("login"),
+            args: vec![arg],
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents a channel, the pushed messages carry it back
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionArg {
+    pub channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub inst_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub inst_type: Option<String>,
+}
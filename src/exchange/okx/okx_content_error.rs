@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thiserror::Error;
+use std::fmt;
+
+/// This struct represents the okx content errors, the code and message of the
+/// batch items (`sCode` and `sMsg`) are reported the same way
+#[derive(Serialize, Deserialize, Clone, Debug, Error)]
+pub struct OkxContentError {
+    pub code: String,
+    #[serde(default)]
+    pub msg: String,
+}
+
+impl fmt::Display for OkxContentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "error code: {} msg: {}", self.code, self.msg)
+    }
+}
@@ -0,0 +1,7 @@
+/// This struct represents the okx credentials
+#[derive(Clone)]
+pub struct OkxCredentials {
+    pub api_key: String,
+    pub api_secret: String,
+    pub passphrase: String,
+}
@@ -0,0 +1,36 @@
+use crate::exchange::traits::info::ExchangeInfoCache;
+use super::OkxCredentials;
+use super::model::InstrumentType;
+
+/// This struct represents the okx parameters.
+/// `demo` routes every request to the demo trading environment and `instrument_type`
+/// selects the instruments the client discovers and trades. The exchange information is read
+/// from `exchange_info_cache` when there's one.
+#[derive(Default, Clone)]
+pub struct OkxParameters {
+    pub demo: bool,
+    pub instrument_type: InstrumentType,
+    pub credentials: Option<OkxCredentials>,
+    pub exchange_info_cache: Option<ExchangeInfoCache>,
+}
+
+impl OkxParameters {
+    pub fn demo() -> Self {
+        Self {
+            demo: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn prod() -> Self {
+        Self {
+            demo: false,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_exchange_info_cache(mut self, cache: ExchangeInfoCache) -> Self {
+        self.exchange_info_cache = Some(cache);
+        self
+    }
+}
@@ -0,0 +1,248 @@
+use chrono::Utc;
+use hmac::{Hmac, Mac, NewMac};
+use reqwest::header;
+use reqwest::{Method, RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use sha2::Sha256;
+use url::Url;
+use crate::exchange::okx::OkxContentError;
+use crate::errors::OpenLimitsError;
+use super::shared::Result;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const BASE_URL: &str = "https://www.okx.com";
+/// Code of the successful responses, every other code is an error
+const SUCCESS_CODE: &str = "0";
+
+/// Every okx response is wrapped as `{"code": "0", "msg": "", "data": [...]}`
+#[derive(Deserialize)]
+struct Envelope {
+    code: String,
+    #[serde(default)]
+    msg: String,
+    #[serde(default)]
+    data: Value,
+}
+
+/// Order endpoints report the result of every item of the request
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ItemError {
+    s_code: String,
+    #[serde(default)]
+    s_msg: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct Transport {
+    credential: Option<(String, String, String)>,
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl Transport {
+    pub fn new(demo: bool) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .default_headers(Transport::default_headers(demo))
+            .build()?;
+
+        Ok(Transport {
+            credential: None,
+            client,
+            base_url: BASE_URL.to_string(),
+        })
+    }
+
+    pub fn with_credential(
+        api_key: &str,
+        api_secret: &str,
+        passphrase: &str,
+        demo: bool,
+    ) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .default_headers(Transport::default_headers(demo))
+            .build()?;
+
+        Ok(Transport {
+            credential: Some((api_key.into(), api_secret.into(), passphrase.into())),
+            client,
+            base_url: BASE_URL.to_string(),
+        })
+    }
+
+    /// The demo trading environment shares the production host, requests are routed by the
+    /// `x-simulated-trading` header.
+    pub fn default_headers(demo: bool) -> header::HeaderMap<header::HeaderValue> {
+        let mut headers = header::HeaderMap::new();
+        headers.insert(
+            header::USER_AGENT,
+            header::HeaderValue::from_static("openlimit"),
+        );
+        headers.insert(
+            header::CONTENT_TYPE,
+            header::HeaderValue::from_static("application/json"),
+        );
+        if demo {
+            headers.insert(
+                "x-simulated-trading",
+                header::HeaderValue::from_static("1"),
+            );
+        }
+
+        headers
+    }
+
+    pub async fn get<O, S>(&self, endpoint: &str, params: Option<&S>) -> Result<O>
+    where
+        O: DeserializeOwned,
+        S: Serialize,
+    {
+        let url = self.get_url(endpoint, params)?;
+        let response = self.client.get(url).send().await?;
+
+        self.response_handler(response).await
+    }
+
+    pub async fn signed_get<O, S>(&self, endpoint: &str, params: Option<&S>) -> Result<O>
+    where
+        O: DeserializeOwned,
+        S: Serialize,
+    {
+        let url = self.get_url(endpoint, params)?;
+        let request = self.build_request::<()>(url, Method::GET, None)?;
+        let response = request.send().await?;
+
+        self.response_handler(response).await
+    }
+
+    pub async fn signed_post<O, D>(&self, endpoint: &str, data: &D) -> Result<O>
+    where
+        O: DeserializeOwned,
+        D: Serialize,
+    {
+        let url = self.get_url::<()>(endpoint, None)?;
+        let request = self.build_request(url, Method::POST, Some(data))?;
+        let response = request.send().await?;
+
+        self.response_handler(response).await
+    }
+
+    pub fn build_request<D>(
+        &self,
+        url: Url,
+        method: Method,
+        data: Option<&D>,
+    ) -> Result<RequestBuilder>
+    where
+        D: Serialize,
+    {
+        let (api_key, api_secret, passphrase) = self.check_key()?;
+        let timestamp = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+        let body = match data {
+            Some(data) => serde_json::to_string(data)?,
+            None => String::new(),
+        };
+        let path = match url.query() {
+            Some(q) => format!("{}?{}", url.path(), q),
+            None => url.path().to_string(),
+        };
+
+        let signature = sign(
+            api_secret,
+            &format!("{}{}{}{}", timestamp, method.as_str(), path, body),
+        );
+
+        let mut request = self
+            .client
+            .request(method, url)
+            .header("OK-ACCESS-KEY", api_key)
+            .header("OK-ACCESS-SIGN", signature)
+            .header("OK-ACCESS-TIMESTAMP", timestamp)
+            .header("OK-ACCESS-PASSPHRASE", passphrase);
+
+        if data.is_some() {
+            request = request.body(body);
+        }
+
+        Ok(request)
+    }
+
+    pub fn get_url<Q>(&self, endpoint: &str, params: Option<&Q>) -> Result<Url>
+    where
+        Q: Serialize,
+    {
+        let url = format!("{}{}", self.base_url, endpoint);
+
+        let mut url = Url::parse(&url)?;
+
+        if params.is_some() {
+            let query = serde_urlencoded::to_string(params)?;
+            if !query.is_empty() {
+                url.set_query(Some(&query));
+            }
+        };
+
+        Ok(url)
+    }
+
+    fn check_key(&self) -> Result<(&str, &str, &str)> {
+        match self.credential.as_ref() {
+            None => Err(OpenLimitsError::NoApiKeySet()),
+            Some((k, s, p)) => Ok((k, s, p)),
+        }
+    }
+
+    async fn response_handler<O>(&self, response: Response) -> Result<O>
+    where
+        O: DeserializeOwned,
+    {
+        let status = response.status();
+        let text = response.text().await?;
+
+        match serde_json::from_str::<Envelope>(&text) {
+            Ok(envelope) if envelope.code == SUCCESS_CODE => serde_json::from_value(envelope.data)
+                .map_err(|err| {
+                    OpenLimitsError::NotParsableResponse(format!("Error:{} Payload: {}", err, text))
+                }),
+            Ok(envelope) => {
+                // A failed order request carries the reason in its items, the envelope
+                // only says that something went wrong.
+                let item = serde_json::from_value::<Vec<ItemError>>(envelope.data)
+                    .ok()
+                    .and_then(|items| items.into_iter().find(|item| item.s_code != SUCCESS_CODE));
+                let error = match item {
+                    Some(item) => OkxContentError {
+                        code: item.s_code,
+                        msg: item.s_msg,
+                    },
+                    None => OkxContentError {
+                        code: envelope.code,
+                        msg: envelope.msg,
+                    },
+                };
+                Err(OpenLimitsError::OkxError(error))
+            }
+            Err(_) => match status {
+                StatusCode::INTERNAL_SERVER_ERROR => Err(OpenLimitsError::InternalServerError()),
+                StatusCode::SERVICE_UNAVAILABLE => Err(OpenLimitsError::ServiceUnavailable()),
+                StatusCode::UNAUTHORIZED => Err(OpenLimitsError::Unauthorized()),
+                s => Err(OpenLimitsError::UnkownResponse(format!(
+                    "Received response: {:?}, value: {}",
+                    s, text
+                ))),
+            },
+        }
+    }
+}
+
+/// Base64 encoded HMAC-SHA256, used for the request signature and the websocket login.
+pub fn sign(secret: &str, message: &str) -> String {
+    let mut mac =
+        HmacSha256::new_varkey(secret.as_bytes()).expect("Couldn't construct hmac from bytes.");
+    mac.update(message.as_bytes());
+    base64::encode(mac.finalize().into_bytes())
+}
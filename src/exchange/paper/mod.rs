@@ -0,0 +1,298 @@
+//! This module provides a paper trading exchange. Orders are simulated on a virtual account
+//! against the market data of a real exchange, nothing is ever sent to it.
+//! # Example
+//! ```
+//! use openlimits::exchange::binance::Binance;
+//! use openlimits::exchange::binance::BinanceParameters;
+//! use openlimits::exchange::paper::PaperExchange;
+//! use openlimits::exchange::paper::PaperParameters;
+//! use openlimits::prelude::*;
+//! use rust_decimal::Decimal;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let parameters = PaperParameters::new(BinanceParameters::prod())
+//!                         .with_balance("USDT", Decimal::new(10000, 0))
+//!                         .with_fees(Decimal::new(1, 3), Decimal::new(1, 3));
+//!     let paper = PaperExchange::<Binance>::new(parameters)
+//!                         .await
+//!                         .expect("Couldn't create paper exchange");
+
+//!     let order = paper.market_buy(&OpenMarketOrderRequest {
+//!                         market_pair: "BTCUSDT".to_string(),
+//!                         size: Decimal::new(1, 2),
+//!                     })
+//!                     .await
+//!                     .expect("Couldn't buy");
+
+//!     println!("{:?}", order);
+//! }
+//! ```
+
+use std::sync::{Arc, Mutex, MutexGuard};
+use async_trait::async_trait;
+use chrono::Utc;
+use futures::{Stream, StreamExt};
+use crate::{
+    errors::OpenLimitsError,
+    model::{
+        Balance, CancelAllOrdersRequest, CancelOrderRequest, Candle, GetHistoricRatesRequest,
+        GetHistoricTradesRequest, GetOrderHistoryRequest, GetOrderRequest, GetPriceTickerRequest,
+        OpenLimitOrderRequest, OpenMarketOrderRequest, Order, OrderBookRequest,
+        OrderBookResponse, OrderCanceled, Paginator, Side, Ticker, Trade, TradeHistoryRequest,
+        websocket::{OpenLimitsWebSocketMessage, WebSocketResponse},
+    },
+};
+use crate::exchange::traits::info::{ExchangeInfoRetrieval, MarketPair, MarketPairHandle};
+use crate::exchange::traits::{Exchange, ExchangeAccount, ExchangeMarketData};
+use super::shared::Result;
+
+mod paper_account;
+mod paper_order;
+mod paper_parameters;
+
+pub use paper_account::PaperAccount;
+pub use paper_order::PaperOrder;
+pub use paper_parameters::PaperParameters;
+pub use super::shared;
+
+/// The main struct of the paper module.
+/// Market data and market information are read from `market`, orders only exist in `account`.
+/// Resting orders are filled by the public trades given to `process_message` or `follow`,
+/// or by the order books read by `poll`.
+#[derive(Clone)]
+pub struct PaperExchange<E> {
+    pub market: E,
+    pub account: Arc<Mutex<PaperAccount>>,
+}
+
+impl<E> PaperExchange<E>
+where
+    E: ExchangeInfoRetrieval + ExchangeMarketData + Send + Sync,
+{
+    pub fn with_account(market: E, account: PaperAccount) -> Self {
+        Self {
+            market,
+            account: Arc::new(Mutex::new(account)),
+        }
+    }
+
+    /// Fills the resting orders with the trades of a websocket message
+    pub fn process_message(&self, message: &OpenLimitsWebSocketMessage) -> Result<Vec<Trade>> {
+        match message {
+            OpenLimitsWebSocketMessage::Trades(trades) => {
+                let mut account = self.account()?;
+                Ok(trades
+                    .iter()
+                    .flat_map(|trade| account.on_trade(trade, timestamp()))
+                    .collect())
+            }
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// Processes the messages of a websocket stream until it ends or fails
+    pub async fn follow<S, R>(&self, mut stream: S) -> Result<()>
+    where
+        S: Stream<Item = Result<WebSocketResponse<R>>> + Unpin,
+    {
+        while let Some(message) = stream.next().await {
+            if let WebSocketResponse::Generic(message) = message? {
+                self.process_message(&message)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Fills the resting orders with the current order book of their markets
+    pub async fn poll(&self) -> Result<Vec<Trade>> {
+        let markets = self.account()?.open_markets();
+
+        let mut fills = Vec::new();
+        for market_pair in markets {
+            let book = self
+                .market
+                .order_book(&OrderBookRequest {
+                    market_pair: market_pair.clone(),
+                })
+                .await?;
+            fills.extend(self.account()?.on_order_book(&market_pair, &book, timestamp()));
+        }
+        Ok(fills)
+    }
+
+    async fn book(&self, market_pair: &str) -> Result<(MarketPair, OrderBookResponse)> {
+        let pair = self.market.get_pair(market_pair).await?.read()?;
+        let book = self
+            .market
+            .order_book(&OrderBookRequest {
+                market_pair: market_pair.to_string(),
+            })
+            .await?;
+        Ok((pair, book))
+    }
+
+    /// The good till time orders are expired every time the account is read
+    fn account(&self) -> Result<MutexGuard<PaperAccount>> {
+        let mut account = self
+            .account
+            .lock()
+            .map_err(|_| OpenLimitsError::PoisonError())?;
+        account.expire(timestamp());
+        Ok(account)
+    }
+}
+
+#[async_trait]
+impl<E> Exchange for PaperExchange<E>
+where
+    E: Exchange + Send + Sync,
+    E::InitParams: Send,
+{
+    type InitParams = PaperParameters<E::InitParams>;
+    type InnerClient = E;
+
+    async fn new(parameters: Self::InitParams) -> Result<Self> {
+        let market = E::new(parameters.market).await?;
+        let account = PaperAccount::new(
+            parameters.balances,
+            parameters.maker_fee,
+            parameters.taker_fee,
+        );
+
+        Ok(PaperExchange::with_account(market, account))
+    }
+
+    fn inner_client(&self) -> Option<&Self::InnerClient> {
+        Some(&self.market)
+    }
+}
+
+#[async_trait]
+impl<E> ExchangeInfoRetrieval for PaperExchange<E>
+where
+    E: ExchangeInfoRetrieval + ExchangeMarketData + Send + Sync,
+{
+    async fn get_pair(&self, name: &str) -> Result<MarketPairHandle> {
+        self.market.get_pair(name).await
+    }
+
+    async fn retrieve_pairs(&self) -> Result<Vec<MarketPair>> {
+        self.market.retrieve_pairs().await
+    }
+
+    async fn refresh_market_info(&self) -> Result<Vec<MarketPairHandle>> {
+        self.market.refresh_market_info().await
+    }
+}
+
+#[async_trait]
+impl<E> ExchangeMarketData for PaperExchange<E>
+where
+    E: ExchangeInfoRetrieval + ExchangeMarketData + Send + Sync,
+{
+    async fn order_book(&self, req: &OrderBookRequest) -> Result<OrderBookResponse> {
+        self.market.order_book(req).await
+    }
+
+    async fn get_price_ticker(&self, req: &GetPriceTickerRequest) -> Result<Ticker> {
+        self.market.get_price_ticker(req).await
+    }
+
+    async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
+        self.market.get_historic_rates(req).await
+    }
+
+    async fn get_historic_trades(&self, req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
+        self.market.get_historic_trades(req).await
+    }
+}
+
+#[async_trait]
+impl<E> ExchangeAccount for PaperExchange<E>
+where
+    E: ExchangeInfoRetrieval + ExchangeMarketData + Send + Sync,
+{
+    async fn limit_buy(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        let (pair, book) = self.book(&req.market_pair).await?;
+        self.account()?
+            .limit_order(&pair, Side::Buy, req, &book, timestamp())
+    }
+
+    async fn limit_sell(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        let (pair, book) = self.book(&req.market_pair).await?;
+        self.account()?
+            .limit_order(&pair, Side::Sell, req, &book, timestamp())
+    }
+
+    async fn market_buy(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        let (pair, book) = self.book(&req.market_pair).await?;
+        self.account()?
+            .market_order(&pair, Side::Buy, req.size, &book, timestamp())
+    }
+
+    async fn market_sell(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        let (pair, book) = self.book(&req.market_pair).await?;
+        self.account()?
+            .market_order(&pair, Side::Sell, req.size, &book, timestamp())
+    }
+
+    async fn cancel_order(&self, req: &CancelOrderRequest) -> Result<OrderCanceled> {
+        self.account()?.cancel(&req.id)
+    }
+
+    async fn cancel_all_orders(&self, req: &CancelAllOrdersRequest) -> Result<Vec<OrderCanceled>> {
+        Ok(self.account()?.cancel_all(req.market_pair.as_deref()))
+    }
+
+    async fn get_all_open_orders(&self) -> Result<Vec<Order>> {
+        Ok(self.account()?.open_orders())
+    }
+
+    async fn get_order_history(&self, req: &GetOrderHistoryRequest) -> Result<Vec<Order>> {
+        let orders = self.account()?.orders();
+        Ok(orders
+            .into_iter()
+            .filter(|order| {
+                req.market_pair
+                    .as_ref()
+                    .map_or(true, |market_pair| order.market_pair.eq_ignore_ascii_case(market_pair))
+            })
+            .filter(|order| {
+                req.order_status
+                    .as_ref()
+                    .map_or(true, |statuses| statuses.contains(&order.status))
+            })
+            .collect())
+    }
+
+    async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>> {
+        let trades = self.account()?.trades();
+        Ok(trades
+            .into_iter()
+            .filter(|trade| {
+                req.market_pair
+                    .as_ref()
+                    .map_or(true, |market_pair| trade.market_pair.eq_ignore_ascii_case(market_pair))
+            })
+            .filter(|trade| {
+                req.order_id.as_ref().map_or(true, |order_id| {
+                    trade.buyer_order_id.as_ref() == Some(order_id)
+                        || trade.seller_order_id.as_ref() == Some(order_id)
+                })
+            })
+            .collect())
+    }
+
+    async fn get_account_balances(&self, _paginator: Option<Paginator>) -> Result<Vec<Balance>> {
+        Ok(self.account()?.balances())
+    }
+
+    async fn get_order(&self, req: &GetOrderRequest) -> Result<Order> {
+        self.account()?.order(&req.id)
+    }
+}
+
+fn timestamp() -> u64 {
+    Utc::now().timestamp_millis() as u64
+}
@@ -0,0 +1,317 @@
+use std::collections::HashMap;
+use chrono::Duration;
+use rust_decimal::prelude::{Decimal, One, Zero};
+use crate::errors::OpenLimitsError;
+use crate::exchange::matching::{
+    crossing_levels, resting_orders, walk, BookLiquidity, FundedOrder, Ledger,
+};
+use crate::exchange::traits::info::MarketPair;
+use crate::model::{
+    Balance, Liquidity, OpenLimitOrderRequest, Order, OrderBookResponse, OrderCanceled,
+    OrderStatus, OrderType, Side, TimeInForce, Trade,
+};
+use super::PaperOrder;
+use super::shared::Result;
+
+/// This struct represents the virtual account of the paper exchange: the balances, the orders
+/// and their fills. Fees are charged in the quote currency of the market.
+/// The time is always given by the caller so the same fills can be replayed.
+#[derive(Debug, Default)]
+pub struct PaperAccount {
+    ledger: Ledger,
+    orders: Vec<PaperOrder>,
+    liquidity: BookLiquidity,
+    next_id: u64,
+}
+
+impl PaperAccount {
+    pub fn new(balances: HashMap<String, Decimal>, maker_fee: Decimal, taker_fee: Decimal) -> Self {
+        Self {
+            ledger: Ledger::new(balances, maker_fee, taker_fee),
+            orders: Vec::new(),
+            liquidity: BookLiquidity::default(),
+            next_id: 1,
+        }
+    }
+
+    pub fn balances(&self) -> Vec<Balance> {
+        self.ledger.balances(|asset| self.free(asset))
+    }
+
+    /// Balance not locked by open orders
+    pub fn free(&self, asset: &str) -> Decimal {
+        self.ledger.free(asset, self.open())
+    }
+
+    fn open(&self) -> impl Iterator<Item = &PaperOrder> {
+        self.orders.iter().filter(|order| order.is_open())
+    }
+
+    pub fn orders(&self) -> Vec<Order> {
+        self.orders.iter().map(|order| order.order.clone()).collect()
+    }
+
+    pub fn open_orders(&self) -> Vec<Order> {
+        self.orders
+            .iter()
+            .filter(|order| order.is_open())
+            .map(|order| order.order.clone())
+            .collect()
+    }
+
+    pub fn order(&self, id: &str) -> Result<Order> {
+        self.orders
+            .iter()
+            .find(|order| order.order.id == id)
+            .map(|order| order.order.clone())
+            .ok_or_else(|| OpenLimitsError::InvalidParameter(format!("Order {} not found", id)))
+    }
+
+    pub fn trades(&self) -> Vec<Trade> {
+        let mut trades: Vec<Trade> = self
+            .orders
+            .iter()
+            .flat_map(|order| order.order.trades.iter().cloned())
+            .collect();
+        trades.sort_by_key(|trade| trade.created_at);
+        trades
+    }
+
+    /// Market orders take the liquidity of the book at the price of every level, what the book
+    /// can't fill expires.
+    pub fn market_order(
+        &mut self,
+        pair: &MarketPair,
+        side: Side,
+        size: Decimal,
+        book: &OrderBookResponse,
+        now: u64,
+    ) -> Result<Order> {
+        let fills = walk(&crossing_levels(book, &side, None), size);
+        let required = match side {
+            Side::Buy => {
+                fills
+                    .iter()
+                    .fold(Decimal::zero(), |acc, fill| acc + fill.price * fill.qty)
+                    * (Decimal::one() + self.ledger.taker_fee)
+            }
+            Side::Sell => fills.iter().fold(Decimal::zero(), |acc, fill| acc + fill.qty),
+        };
+        self.ledger.check_funds(pair, &side, required, self.open())?;
+
+        let index = self.insert(pair, side, OrderType::Market, size, None, now);
+        for fill in fills {
+            self.fill(index, fill.price, fill.qty, Liquidity::Taker, now);
+        }
+        if self.orders[index].is_open() {
+            self.close(index, OrderStatus::Expired);
+        }
+
+        Ok(self.orders[index].order.clone())
+    }
+
+    /// Limit orders first take the levels of the book crossing their price, the remaining size
+    /// rests on the account until it's filled by the market, canceled or expired.
+    pub fn limit_order(
+        &mut self,
+        pair: &MarketPair,
+        side: Side,
+        req: &OpenLimitOrderRequest,
+        book: &OrderBookResponse,
+        now: u64,
+    ) -> Result<Order> {
+        if let TimeInForce::GoodTillTime(duration) = req.time_in_force {
+            if duration <= Duration::zero() {
+                return Err(OpenLimitsError::InvalidParameter(format!(
+                    "Good till time duration of {}ms must be positive",
+                    duration.num_milliseconds()
+                )));
+            }
+        }
+        let levels = crossing_levels(book, &side, Some(req.price));
+        let fills = walk(&levels, req.size);
+        let filled = fills.iter().fold(Decimal::zero(), |acc, fill| acc + fill.qty);
+
+        if req.post_only && !levels.is_empty() {
+            let index = self.insert(pair, side, OrderType::Limit, req.size, Some(req.price), now);
+            self.close(index, OrderStatus::Rejected);
+            return Ok(self.orders[index].order.clone());
+        }
+        if req.time_in_force == TimeInForce::FillOrKill && filled < req.size {
+            let index = self.insert(pair, side, OrderType::Limit, req.size, Some(req.price), now);
+            self.close(index, OrderStatus::Expired);
+            return Ok(self.orders[index].order.clone());
+        }
+
+        let locked = match side {
+            Side::Buy => req.size * req.price * (Decimal::one() + self.ledger.taker_fee),
+            Side::Sell => req.size,
+        };
+        self.ledger.check_funds(pair, &side, locked, self.open())?;
+
+        let index = self.insert(pair, side, OrderType::Limit, req.size, Some(req.price), now);
+        self.orders[index].locked = locked;
+        if let TimeInForce::GoodTillTime(duration) = req.time_in_force {
+            self.orders[index].expires_at = Some(now + duration.num_milliseconds() as u64);
+        }
+        for fill in fills {
+            self.fill(index, fill.price, fill.qty, Liquidity::Taker, now);
+        }
+        if req.time_in_force == TimeInForce::ImmediateOrCancelled && self.orders[index].is_open() {
+            self.close(index, OrderStatus::Expired);
+        }
+
+        Ok(self.orders[index].order.clone())
+    }
+
+    pub fn cancel(&mut self, id: &str) -> Result<OrderCanceled> {
+        let index = self
+            .orders
+            .iter()
+            .position(|order| order.order.id == id && order.is_open())
+            .ok_or_else(|| OpenLimitsError::InvalidParameter(format!("Order {} is not open", id)))?;
+        self.close(index, OrderStatus::Canceled);
+
+        Ok(OrderCanceled { id: id.to_string() })
+    }
+
+    pub fn cancel_all(&mut self, market_pair: Option<&str>) -> Vec<OrderCanceled> {
+        let indexes: Vec<usize> = self
+            .orders
+            .iter()
+            .enumerate()
+            .filter(|(_, order)| order.is_open())
+            .filter(|(_, order)| {
+                market_pair.map_or(true, |market_pair| {
+                    order.order.market_pair.eq_ignore_ascii_case(market_pair)
+                })
+            })
+            .map(|(index, _)| index)
+            .collect();
+
+        indexes
+            .into_iter()
+            .map(|index| {
+                self.close(index, OrderStatus::Canceled);
+                OrderCanceled {
+                    id: self.orders[index].order.id.clone(),
+                }
+            })
+            .collect()
+    }
+
+    /// Expires the good till time orders whose deadline has passed
+    pub fn expire(&mut self, now: u64) {
+        let indexes: Vec<usize> = self
+            .orders
+            .iter()
+            .enumerate()
+            .filter(|(_, order)| order.is_open())
+            .filter(|(_, order)| order.expires_at.map_or(false, |expires_at| expires_at <= now))
+            .map(|(index, _)| index)
+            .collect();
+
+        for index in indexes {
+            self.close(index, OrderStatus::Expired);
+        }
+    }
+
+    /// Markets the open orders are resting on
+    pub fn open_markets(&self) -> Vec<String> {
+        let mut markets: Vec<String> = Vec::new();
+        for order in self.orders.iter().filter(|order| order.is_open()) {
+            if !markets.contains(&order.order.market_pair) {
+                markets.push(order.order.market_pair.clone());
+            }
+        }
+        markets
+    }
+
+    /// A public trade fills the resting orders it reaches or goes through, as maker and at the
+    /// price of the order. Its quantity is shared by the orders following price-time priority.
+    pub fn on_trade(&mut self, trade: &Trade, now: u64) -> Vec<Trade> {
+        let mut available = trade.qty;
+        let mut fills = Vec::new();
+        for index in resting_orders(&self.orders, &trade.market_pair) {
+            let price = self.orders[index].order.price.unwrap_or_default();
+            let crosses = match self.orders[index].order.side {
+                Side::Buy => trade.price <= price,
+                Side::Sell => trade.price >= price,
+            };
+            if !crosses || available.is_zero() {
+                continue;
+            }
+
+            let qty = available.min(self.orders[index].remaining());
+            available -= qty;
+            fills.push(self.fill(index, price, qty, Liquidity::Maker, now));
+        }
+        fills
+    }
+
+    /// Polled books fill the resting orders with the quantity of the levels crossing them, as
+    /// maker and at the price of the order. The quantity of a level fills the orders once until
+    /// a book shows it with another quantity.
+    pub fn on_order_book(&mut self, market_pair: &str, book: &OrderBookResponse, now: u64) -> Vec<Trade> {
+        self.liquidity.update(market_pair, book);
+        let mut fills = Vec::new();
+        for index in resting_orders(&self.orders, market_pair) {
+            let order = &self.orders[index];
+            let side = order.order.side.clone();
+            let price = order.order.price.unwrap_or_default();
+            let levels = crossing_levels(book, &side, order.order.price);
+            let qty = self
+                .liquidity
+                .take(market_pair, &side, &levels, order.remaining());
+            if qty.is_zero() {
+                continue;
+            }
+            fills.push(self.fill(index, price, qty, Liquidity::Maker, now));
+        }
+        fills
+    }
+
+    fn insert(
+        &mut self,
+        pair: &MarketPair,
+        side: Side,
+        order_type: OrderType,
+        size: Decimal,
+        price: Option<Decimal>,
+        now: u64,
+    ) -> usize {
+        let id = self.next_id.to_string();
+        self.next_id += 1;
+        self.orders.push(PaperOrder {
+            order: Order {
+                id,
+                market_pair: pair.symbol.clone(),
+                instrument: None,
+                client_order_id: None,
+                created_at: Some(now),
+                order_type,
+                side,
+                status: OrderStatus::Open,
+                size,
+                price,
+                remaining: Some(size),
+                trades: Vec::new(),
+            },
+            base: pair.base.to_uppercase(),
+            quote: pair.quote.to_uppercase(),
+            locked: Decimal::zero(),
+            expires_at: None,
+        });
+        self.orders.len() - 1
+    }
+
+    fn close(&mut self, index: usize, status: OrderStatus) {
+        let order = &mut self.orders[index];
+        order.order.status = status;
+        order.locked = Decimal::zero();
+    }
+
+    fn fill(&mut self, index: usize, price: Decimal, qty: Decimal, liquidity: Liquidity, now: u64) -> Trade {
+        self.ledger.fill(&mut self.orders[index], price, qty, liquidity, now)
+    }
+}
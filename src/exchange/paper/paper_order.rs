@@ -0,0 +1,40 @@
+use rust_decimal::Decimal;
+use crate::exchange::matching::FundedOrder;
+use crate::model::Order;
+
+/// This struct represents an order of the paper account with the funds it keeps locked.
+/// Buy orders lock quote currency, sell orders lock base currency.
+#[derive(Clone, Debug)]
+pub struct PaperOrder {
+    pub order: Order,
+    pub base: String,
+    pub quote: String,
+    pub locked: Decimal,
+    pub expires_at: Option<u64>,
+}
+
+impl FundedOrder for PaperOrder {
+    fn order(&self) -> &Order {
+        &self.order
+    }
+
+    fn order_mut(&mut self) -> &mut Order {
+        &mut self.order
+    }
+
+    fn base(&self) -> &str {
+        &self.base
+    }
+
+    fn quote(&self) -> &str {
+        &self.quote
+    }
+
+    fn locked(&self) -> Decimal {
+        self.locked
+    }
+
+    fn set_locked(&mut self, locked: Decimal) {
+        self.locked = locked;
+    }
+}
@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+use rust_decimal::Decimal;
+
+/// This struct represents the paper exchange parameters.
+/// `market` are the parameters of the exchange the market data is read from, the fees are
+/// rates applied to the notional of every fill (0.001 is 0.1%).
+#[derive(Default, Clone)]
+pub struct PaperParameters<P> {
+    pub market: P,
+    pub balances: HashMap<String, Decimal>,
+    pub maker_fee: Decimal,
+    pub taker_fee: Decimal,
+}
+
+impl<P> PaperParameters<P> {
+    pub fn new(market: P) -> Self {
+        Self {
+            market,
+            balances: HashMap::new(),
+            maker_fee: Decimal::default(),
+            taker_fee: Decimal::default(),
+        }
+    }
+
+    pub fn with_balance(mut self, asset: &str, amount: Decimal) -> Self {
+        self.balances.insert(asset.to_uppercase(), amount);
+        self
+    }
+
+    pub fn with_fees(mut self, maker_fee: Decimal, taker_fee: Decimal) -> Self {
+        self.maker_fee = maker_fee;
+        self.taker_fee = taker_fee;
+        self
+    }
+}
@@ -0,0 +1,206 @@
+//! This module provides a websocket client that replays recorded market data, so strategies
+//! can run unchanged against historical sessions.
+//!
+//! Recordings are JSON lines files, optionally gzip compressed with a `.gz` extension, as
+//! written by the `recorder` module. Every line is a `RecordedMessage`: the millisecond
+//! timestamp it was received at, the exchange and symbol it came from and its payload, which
+//! is either an `OpenLimitsWebSocketMessage`, an order book snapshot, the JSON of a raw
+//! exchange message or an error of the stream, which is replayed as an error.
+//! ```json
+//! {"timestamp":1620000000000,"exchange":"binance","symbol":"bnbbtc","payload":{"message":{"Trades":[]}}}
+//! {"timestamp":1620000000500,"exchange":"binance","symbol":"bnbbtc","payload":{"snapshot":{"update_id":null,"last_update_id":1,"bids":[{"price":"0.01","qty":"1"}],"asks":[]}}}
+//! {"timestamp":1620000001000,"exchange":"binance","symbol":"bnbbtc","payload":{"raw":{"OrderBook":{"lastUpdateId":1,"bids":[],"asks":[]}}}}
+//! ```
+//! # Example
+//! ```no_run
+//! use openlimits::exchange::replay::{ReplayParameters, ReplaySpeed, ReplayWs};
+//! use openlimits::exchange::traits::stream::OpenLimitsWs;
+//! use openlimits::model::websocket::Subscription;
+//! use openlimits::prelude::*;
+//! use futures::stream::StreamExt;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let parameters = ReplayParameters::from_directory("recordings")
+//!                         .expect("Couldn't read the recordings index")
+//!                         .with_speed(ReplaySpeed::Accelerated(10.0));
+//!     let ws = OpenLimitsWs::new(
+//!         ReplayWs::new(parameters).await.expect("Couldn't create replay client"),
+//!     );
+//!
+//!     let mut stream = ws.create_stream(&[Subscription::Trades("bnbbtc".to_string())])
+//!                         .await
+//!                         .expect("Couldn't create stream");
+//!     while let Some(message) = stream.next().await {
+//!         println!("{:?}", message);
+//!     }
+//! }
+//! ```
+
+use std::collections::VecDeque;
+use std::io;
+use std::iter;
+use std::sync::Mutex;
+use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+use tokio::time::Instant;
+use crate::errors::OpenLimitsError;
+use crate::exchange::traits::stream::{ExchangeWs, Subscriptions};
+use crate::model::websocket::{OpenLimitsWebSocketMessage, Subscription};
+use crate::recorder::{subscription_symbol, RecordReader, RecordedMessage, RecordedPayload};
+
+mod replay_message;
+mod replay_parameters;
+
+pub use replay_message::ReplayMessage;
+pub use replay_parameters::{ReplayParameters, ReplaySpeed};
+pub use super::shared;
+use super::shared::Result;
+
+/// Records read at once by a blocking task
+const BATCH_SIZE: usize = 256;
+
+type Records = Box<dyn Iterator<Item = Result<RecordedMessage>> + Send>;
+
+/// This struct is used to replay recordings as websocket streams.
+/// Every stream reads the recording from the start position and ends with it. The files are
+/// read in blocking tasks, a batch of records at a time.
+pub struct ReplayWs {
+    parameters: ReplayParameters,
+    disconnection_senders: Mutex<Vec<UnboundedSender<()>>>,
+}
+
+impl ReplayWs {
+    /// Streams created afterwards start at `timestamp`, an indexed recording being read from
+    /// the files and offsets of its first records
+    pub fn seek(&mut self, timestamp: u64) {
+        self.parameters.start = Some(timestamp);
+    }
+
+    /// Records of the files with records of `symbols`, which are opened when they're reached
+    fn records(&self, symbols: Option<&[String]>) -> Records {
+        let records = self
+            .parameters
+            .positions(symbols)
+            .into_iter()
+            .flat_map(|(file, offset)| -> Records {
+                match RecordReader::open_at(file, offset) {
+                    Ok(reader) => Box::new(reader),
+                    Err(err) => Box::new(iter::once(Err(err))),
+                }
+            });
+        Box::new(records)
+    }
+}
+
+/// Reads the next batch of records in a blocking task
+async fn read_batch(mut records: Records) -> Result<(Records, VecDeque<Result<RecordedMessage>>)> {
+    tokio::task::spawn_blocking(move || {
+        let batch = records.by_ref().take(BATCH_SIZE).collect();
+        (records, batch)
+    })
+    .await
+    .map_err(|err| OpenLimitsError::IoError(io::Error::new(io::ErrorKind::Other, err)))
+}
+
+/// Whether a record belongs to the stream of a subscription
+fn subscribed(subscription: &Subscription, record: &RecordedMessage) -> bool {
+    let kind = match (subscription, &record.payload) {
+        (_, RecordedPayload::Message(OpenLimitsWebSocketMessage::Ping)) => return true,
+        (Subscription::OrderBookUpdates(_), RecordedPayload::Snapshot(_))
+        | (
+            Subscription::OrderBookUpdates(_),
+            RecordedPayload::Message(OpenLimitsWebSocketMessage::OrderBook(_)),
+        )
+        | (
+            Subscription::OrderBookUpdates(_),
+            RecordedPayload::Message(OpenLimitsWebSocketMessage::OrderBookDiff(_)),
+        )
+        | (Subscription::Trades(_), RecordedPayload::Message(OpenLimitsWebSocketMessage::Trades(_)))
+        | (_, RecordedPayload::Raw(_))
+        | (_, RecordedPayload::Error(_)) => true,
+        _ => false,
+    };
+    kind && subscription_symbol(subscription) == record.symbol
+}
+
+#[async_trait]
+impl ExchangeWs for ReplayWs {
+    type InitParams = ReplayParameters;
+    type Subscription = Subscription;
+    type Response = ReplayMessage;
+
+    async fn new(parameters: Self::InitParams) -> Result<Self> {
+        Ok(ReplayWs {
+            parameters,
+            disconnection_senders: Default::default(),
+        })
+    }
+
+    async fn disconnect(&self) {
+        if let Ok(mut senders) = self.disconnection_senders.lock() {
+            for sender in senders.iter() {
+                sender.send(()).ok();
+            }
+            senders.clear();
+        }
+    }
+
+    async fn create_stream_specific(
+        &self,
+        subscriptions: Subscriptions<Self::Subscription>,
+    ) -> Result<BoxStream<'static, Result<Self::Response>>> {
+        let subscriptions: Vec<Subscription> = subscriptions.into_iter().collect();
+        let symbols: Option<Vec<String>> = subscriptions.iter().map(subscription_symbol).collect();
+        let parameters = self.parameters.clone();
+        let records = self.records(symbols.as_deref()).filter(move |record| match record {
+            Ok(record) => {
+                parameters.includes(record)
+                    && subscriptions
+                        .iter()
+                        .any(|subscription| subscribed(subscription, record))
+            }
+            Err(_) => true,
+        });
+        let records: Records = Box::new(records);
+
+        let speed = self.parameters.speed;
+        let s = stream::unfold(
+            (Some(records), VecDeque::<Result<RecordedMessage>>::new(), None),
+            move |(mut records, mut batch, mut origin): (_, _, Option<(u64, Instant)>)| async move {
+                if batch.is_empty() {
+                    match read_batch(records?).await {
+                        Ok((rest, next)) => {
+                            records = Some(rest);
+                            batch = next;
+                        }
+                        Err(err) => return Some((Err(err), (None, batch, origin))),
+                    }
+                }
+                let record = batch.pop_front()?;
+                if let Ok(record) = &record {
+                    match origin {
+                        Some((first, started)) => {
+                            if let Some(delay) = speed.delay(record.timestamp.saturating_sub(first)) {
+                                tokio::time::sleep_until(started + delay).await;
+                            }
+                        }
+                        None => origin = Some((record.timestamp, Instant::now())),
+                    }
+                }
+                let message = record.map(|record| ReplayMessage::from(record.payload));
+                Some((message, (records, batch, origin)))
+            },
+        );
+
+        let (disconnection_sender, mut disconnection_receiver) = unbounded_channel::<()>();
+        if let Ok(mut senders) = self.disconnection_senders.lock() {
+            senders.push(disconnection_sender);
+        }
+
+        let s = s.take_until(async move { disconnection_receiver.recv().await });
+
+        Ok(s.boxed())
+    }
+}
@@ -0,0 +1,41 @@
+use std::convert::TryFrom;
+use serde::Serialize;
+use serde_json::Value;
+use crate::errors::OpenLimitsError;
+use crate::model::websocket::{OpenLimitsWebSocketMessage, WebSocketResponse};
+use crate::recorder::RecordedPayload;
+
+/// This enum represents a message replayed from a recording.
+/// Order book snapshots are replayed as `OpenLimitsWebSocketMessage::OrderBook`, and the
+/// recorded errors as errors of the stream.
+#[derive(Serialize, Debug, Clone)]
+pub enum ReplayMessage {
+    Message(OpenLimitsWebSocketMessage),
+    Raw(Value),
+    Error(String),
+}
+
+impl From<RecordedPayload> for ReplayMessage {
+    fn from(payload: RecordedPayload) -> Self {
+        match payload {
+            RecordedPayload::Message(message) => ReplayMessage::Message(message),
+            RecordedPayload::Snapshot(book) => {
+                ReplayMessage::Message(OpenLimitsWebSocketMessage::OrderBook(book))
+            }
+            RecordedPayload::Raw(raw) => ReplayMessage::Raw(raw),
+            RecordedPayload::Error(error) => ReplayMessage::Error(error),
+        }
+    }
+}
+
+impl TryFrom<ReplayMessage> for WebSocketResponse<ReplayMessage> {
+    type Error = OpenLimitsError;
+
+    fn try_from(value: ReplayMessage) -> Result<Self, Self::Error> {
+        match value {
+            ReplayMessage::Message(message) => Ok(WebSocketResponse::Generic(message)),
+            ReplayMessage::Error(error) => Err(OpenLimitsError::UnkownResponse(error)),
+            raw => Ok(WebSocketResponse::Raw(raw)),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use crate::exchange::shared::Result;
+use crate::recorder::{RecordedMessage, RecordingIndex};
+
+/// This enum represents how fast a recording is replayed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReplaySpeed {
+    /// Messages are spaced as they were received
+    Original,
+    /// Messages are spaced as they were received, divided by the factor
+    Accelerated(f64),
+    /// Messages are emitted as soon as they are read
+    AsFastAsPossible,
+}
+
+impl ReplaySpeed {
+    /// Time to wait between the first message and one received `elapsed` milliseconds later
+    pub fn delay(&self, elapsed: u64) -> Option<Duration> {
+        match self {
+            ReplaySpeed::Original => Some(Duration::from_millis(elapsed)),
+            ReplaySpeed::Accelerated(factor) if *factor > 0.0 && factor.is_finite() => {
+                Some(Duration::from_secs_f64(elapsed as f64 / 1000.0 / factor))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Default for ReplaySpeed {
+    fn default() -> Self {
+        ReplaySpeed::Original
+    }
+}
+
+/// This struct represents the replay parameters.
+/// `start` and `end` are millisecond timestamps, records outside of them are skipped.
+/// When `symbols` is set, only the records of these symbols, or without a symbol, are replayed.
+/// When the recording is indexed, only its files with records of the replayed symbols and time
+/// range are read, from the first of these records.
+#[derive(Clone, Debug, Default)]
+pub struct ReplayParameters {
+    pub files: Vec<PathBuf>,
+    pub index: Option<RecordingIndex>,
+    pub speed: ReplaySpeed,
+    pub start: Option<u64>,
+    pub end: Option<u64>,
+    pub symbols: Option<Vec<String>>,
+}
+
+impl ReplayParameters {
+    pub fn new(file: impl Into<PathBuf>) -> Self {
+        Self::from_files(vec![file.into()])
+    }
+
+    pub fn from_files(files: Vec<PathBuf>) -> Self {
+        Self {
+            files,
+            ..Default::default()
+        }
+    }
+
+    /// Replays all the indexed files of a recording directory, in the order they were written
+    pub fn from_directory(directory: impl AsRef<Path>) -> Result<Self> {
+        let index = RecordingIndex::load(directory)?;
+        Ok(Self {
+            files: index.files(None, 0, u64::MAX),
+            index: Some(index),
+            ..Default::default()
+        })
+    }
+
+    pub fn with_speed(mut self, speed: ReplaySpeed) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    pub fn seek(mut self, start: u64) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    pub fn until(mut self, end: u64) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    pub fn with_symbols(mut self, symbols: &[&str]) -> Self {
+        self.symbols = Some(symbols.iter().map(|symbol| symbol.to_string()).collect());
+        self
+    }
+
+    /// Files to read the records of `symbols`, or of the replayed symbols, from, with the offset
+    /// reading them begins at
+    pub fn positions(&self, symbols: Option<&[String]>) -> Vec<(PathBuf, u64)> {
+        let symbols = symbols.or_else(|| self.symbols.as_deref());
+        match &self.index {
+            Some(index) => index.positions(
+                symbols,
+                self.start.unwrap_or(0),
+                self.end.unwrap_or(u64::MAX),
+            ),
+            None => self.files.iter().map(|file| (file.clone(), 0)).collect(),
+        }
+    }
+
+    /// Whether a record is in the replayed time range and symbols
+    pub fn includes(&self, record: &RecordedMessage) -> bool {
+        self.start.map_or(true, |start| record.timestamp >= start)
+            && self.end.map_or(true, |end| record.timestamp <= end)
+            && match (&self.symbols, &record.symbol) {
+                (Some(symbols), Some(symbol)) => symbols.contains(symbol),
+                _ => true,
+            }
+    }
+}
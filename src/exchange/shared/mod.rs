@@ -2,6 +2,10 @@
 
 pub type Result<T> = std::result::Result<T, crate::errors::OpenLimitsError>;
 
+mod subscribers;
+
+pub use subscribers::Subscribers;
+
 pub mod string_to_decimal {
     use std::fmt;
 
@@ -62,6 +66,37 @@ pub mod string_to_opt_decimal {
     }
 }
 
+pub mod string_to_u64 {
+    use std::fmt;
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringToU64 {
+            String(String),
+            Number(u64),
+        }
+
+        match StringToU64::deserialize(deserializer)? {
+            StringToU64::String(s) => s.parse::<u64>().map_err(de::Error::custom),
+            StringToU64::Number(n) => Ok(n),
+        }
+    }
+}
+
 pub mod naive_datetime_from_string {
     use chrono::naive::NaiveDateTime;
     use serde::{Deserialize, Deserializer, Serializer};
@@ -0,0 +1,48 @@
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+/// This struct represents the subscribers of a stream of events, it's shared by its clones.
+/// Subscribers that dropped their receiver are removed when events are published.
+pub struct Subscribers<T> {
+    senders: Arc<Mutex<Vec<UnboundedSender<T>>>>,
+}
+
+impl<T> Clone for Subscribers<T> {
+    fn clone(&self) -> Self {
+        Self {
+            senders: self.senders.clone(),
+        }
+    }
+}
+
+impl<T> Default for Subscribers<T> {
+    fn default() -> Self {
+        Self {
+            senders: Default::default(),
+        }
+    }
+}
+
+impl<T: Clone> Subscribers<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Receives the events published from now on
+    pub fn subscribe(&self) -> UnboundedReceiver<T> {
+        let (sender, receiver) = unbounded_channel();
+        if let Ok(mut senders) = self.senders.lock() {
+            senders.push(sender);
+        }
+        receiver
+    }
+
+    pub fn publish(&self, events: &[T]) {
+        if events.is_empty() {
+            return;
+        }
+        if let Ok(mut senders) = self.senders.lock() {
+            senders.retain(|sender| events.iter().all(|event| sender.send(event.clone()).is_ok()));
+        }
+    }
+}
@@ -0,0 +1,717 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use chrono::Duration;
+use rust_decimal::prelude::{Decimal, One, Zero};
+use tokio::sync::mpsc::UnboundedSender;
+use crate::errors::OpenLimitsError;
+use crate::exchange::matching::{walk, FundedOrder, Ledger};
+use crate::exchange::traits::info::MarketPair;
+use crate::model::{
+    AskBid, Balance, Candle, Interval, Liquidity, OpenLimitOrderRequest, OpenMarketOrderRequest,
+    Order, OrderBookResponse, OrderCanceled, OrderStatus, OrderType, Paginator, Side, Ticker,
+    TimeInForce, Trade,
+};
+use crate::model::websocket::OpenLimitsWebSocketMessage;
+use super::SimulatedOrder;
+use super::shared::Result;
+
+const DAY: u64 = 24 * 60 * 60 * 1000;
+
+/// Resting orders of a market by price, every level keeps its orders by arrival
+#[derive(Debug, Default)]
+struct Book {
+    bids: BTreeMap<Decimal, VecDeque<usize>>,
+    asks: BTreeMap<Decimal, VecDeque<usize>>,
+}
+
+#[derive(Debug)]
+struct Market {
+    pair: MarketPair,
+    book: Book,
+    tape: Vec<Trade>,
+}
+
+/// Websocket connection and the markets it's subscribed to
+struct Subscriber {
+    order_books: Vec<String>,
+    trades: Vec<String>,
+    sender: UnboundedSender<OpenLimitsWebSocketMessage>,
+}
+
+/// This struct represents the price-time priority matching engine of the simulated exchange.
+/// Order ids are sequence numbers and every method is given the current time, the same
+/// calls always produce the same orders, trades and balances.
+pub struct MatchingEngine {
+    markets: HashMap<String, Market>,
+    accounts: HashMap<String, Ledger>,
+    orders: Vec<SimulatedOrder>,
+    open: BTreeSet<usize>,
+    next_trade_id: u64,
+    maker_fee: Decimal,
+    taker_fee: Decimal,
+    subscribers: Vec<Subscriber>,
+}
+
+impl MatchingEngine {
+    pub fn new(pairs: Vec<MarketPair>) -> Self {
+        Self {
+            markets: pairs
+                .into_iter()
+                .map(|pair| {
+                    (
+                        pair.symbol.clone(),
+                        Market {
+                            pair,
+                            book: Book::default(),
+                            tape: Vec::new(),
+                        },
+                    )
+                })
+                .collect(),
+            accounts: HashMap::new(),
+            orders: Vec::new(),
+            open: BTreeSet::new(),
+            next_trade_id: 1,
+            maker_fee: Decimal::zero(),
+            taker_fee: Decimal::zero(),
+            subscribers: Vec::new(),
+        }
+    }
+
+    pub fn set_fees(&mut self, maker_fee: Decimal, taker_fee: Decimal) {
+        self.maker_fee = maker_fee;
+        self.taker_fee = taker_fee;
+        for ledger in self.accounts.values_mut() {
+            ledger.maker_fee = maker_fee;
+            ledger.taker_fee = taker_fee;
+        }
+    }
+
+    pub fn pairs(&self) -> Vec<MarketPair> {
+        let mut pairs: Vec<MarketPair> = self
+            .markets
+            .values()
+            .map(|market| market.pair.clone())
+            .collect();
+        pairs.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        pairs
+    }
+
+    pub fn deposit(&mut self, account: &str, asset: &str, amount: Decimal) {
+        self.ledger_mut(account).deposit(asset, amount);
+    }
+
+    pub fn balances(&self, account: &str) -> Vec<Balance> {
+        self.accounts
+            .get(account)
+            .map(|ledger| ledger.balances(|asset| self.free(account, asset)))
+            .unwrap_or_default()
+    }
+
+    /// Balance not locked by open orders
+    pub fn free(&self, account: &str, asset: &str) -> Decimal {
+        self.accounts
+            .get(account)
+            .map_or_else(Decimal::zero, |ledger| ledger.free(asset, self.funded(account)))
+    }
+
+    /// Open orders of an account, which hold its locked funds
+    fn funded<'a>(&'a self, account: &'a str) -> impl Iterator<Item = &'a SimulatedOrder> + 'a {
+        self.open
+            .iter()
+            .map(move |index| &self.orders[*index])
+            .filter(move |order| order.account == account)
+    }
+
+    fn ledger_mut(&mut self, account: &str) -> &mut Ledger {
+        let (maker_fee, taker_fee) = (self.maker_fee, self.taker_fee);
+        self.accounts
+            .entry(account.to_string())
+            .or_insert_with(|| Ledger::new(HashMap::new(), maker_fee, taker_fee))
+    }
+
+    pub fn orders(&self, account: &str) -> Vec<Order> {
+        self.orders
+            .iter()
+            .filter(|order| order.account == account)
+            .map(|order| order.order.clone())
+            .collect()
+    }
+
+    pub fn open_orders(&self, account: &str) -> Vec<Order> {
+        self.open
+            .iter()
+            .map(|index| &self.orders[*index])
+            .filter(|order| order.account == account)
+            .map(|order| order.order.clone())
+            .collect()
+    }
+
+    pub fn order(&self, account: &str, id: &str) -> Result<Order> {
+        self.find(account, id)
+            .map(|index| self.orders[index].order.clone())
+            .ok_or_else(|| OpenLimitsError::InvalidParameter(format!("Order {} not found", id)))
+    }
+
+    pub fn trades(&self, account: &str) -> Vec<Trade> {
+        let mut trades: Vec<Trade> = self
+            .orders
+            .iter()
+            .filter(|order| order.account == account)
+            .flat_map(|order| order.order.trades.iter().cloned())
+            .collect();
+        trades.sort_by_key(|trade| trade.created_at);
+        trades
+    }
+
+    pub fn limit_order(
+        &mut self,
+        account: &str,
+        side: Side,
+        req: &OpenLimitOrderRequest,
+        now: u64,
+    ) -> Result<Order> {
+        self.expire(now);
+        let pair = self.market(&req.market_pair)?.pair.clone();
+        validate(&pair, req.size, Some(req.price))?;
+        if let TimeInForce::GoodTillTime(duration) = req.time_in_force {
+            if duration <= Duration::zero() {
+                return Err(OpenLimitsError::InvalidParameter(format!(
+                    "Good till time duration of {}ms must be positive",
+                    duration.num_milliseconds()
+                )));
+            }
+        }
+
+        let available = self
+            .levels(&pair.symbol, &side, Some(req.price))
+            .iter()
+            .fold(Decimal::zero(), |acc, level| acc + level.qty);
+        if req.post_only && !available.is_zero() {
+            let index = self.insert(account, &pair, side, OrderType::Limit, req.size, Some(req.price), now);
+            self.close(index, OrderStatus::Rejected);
+            return Ok(self.orders[index].order.clone());
+        }
+        if req.time_in_force == TimeInForce::FillOrKill && available < req.size {
+            let index = self.insert(account, &pair, side, OrderType::Limit, req.size, Some(req.price), now);
+            self.close(index, OrderStatus::Expired);
+            return Ok(self.orders[index].order.clone());
+        }
+
+        let locked = match side {
+            Side::Buy => req.size * req.price * (Decimal::one() + self.taker_fee),
+            Side::Sell => req.size,
+        };
+        self.check_funds(account, &pair, &side, locked)?;
+
+        let index = self.insert(account, &pair, side, OrderType::Limit, req.size, Some(req.price), now);
+        self.orders[index].locked = locked;
+        if let TimeInForce::GoodTillTime(duration) = req.time_in_force {
+            self.orders[index].expires_at = Some(now + duration.num_milliseconds() as u64);
+        }
+
+        let trades = self.take(index, Some(req.price), now);
+        if self.orders[index].is_open() {
+            if req.time_in_force == TimeInForce::ImmediateOrCancelled {
+                self.close(index, OrderStatus::Expired);
+            } else {
+                self.rest(index);
+            }
+        }
+        self.publish(&pair.symbol, trades);
+
+        Ok(self.orders[index].order.clone())
+    }
+
+    /// Market orders walk the book, what it can't fill expires
+    pub fn market_order(
+        &mut self,
+        account: &str,
+        side: Side,
+        req: &OpenMarketOrderRequest,
+        now: u64,
+    ) -> Result<Order> {
+        self.expire(now);
+        let pair = self.market(&req.market_pair)?.pair.clone();
+        validate(&pair, req.size, None)?;
+
+        let required = walk(&self.levels(&pair.symbol, &side, None), req.size)
+            .iter()
+            .fold(Decimal::zero(), |acc, fill| match side {
+                Side::Buy => acc + fill.qty * fill.price * (Decimal::one() + self.taker_fee),
+                Side::Sell => acc + fill.qty,
+            });
+        self.check_funds(account, &pair, &side, required)?;
+
+        let index = self.insert(account, &pair, side, OrderType::Market, req.size, None, now);
+        self.orders[index].locked = required;
+        let trades = self.take(index, None, now);
+        if self.orders[index].is_open() {
+            self.close(index, OrderStatus::Expired);
+        }
+        self.publish(&pair.symbol, trades);
+
+        Ok(self.orders[index].order.clone())
+    }
+
+    pub fn cancel(&mut self, account: &str, id: &str, now: u64) -> Result<OrderCanceled> {
+        self.expire(now);
+        let index = self
+            .find(account, id)
+            .filter(|index| self.orders[*index].is_open())
+            .ok_or_else(|| OpenLimitsError::InvalidParameter(format!("Order {} is not open", id)))?;
+        self.close(index, OrderStatus::Canceled);
+        let symbol = self.orders[index].order.market_pair.clone();
+        self.publish(&symbol, Vec::new());
+
+        Ok(OrderCanceled { id: id.to_string() })
+    }
+
+    pub fn cancel_all(&mut self, account: &str, market_pair: Option<&str>, now: u64) -> Vec<OrderCanceled> {
+        self.expire(now);
+        let indexes: Vec<usize> = self
+            .open
+            .iter()
+            .copied()
+            .filter(|index| self.orders[*index].account == account)
+            .filter(|index| {
+                market_pair.map_or(true, |market_pair| self.orders[*index].order.market_pair == market_pair)
+            })
+            .collect();
+
+        let mut symbols = Vec::new();
+        let canceled = indexes
+            .into_iter()
+            .map(|index| {
+                self.close(index, OrderStatus::Canceled);
+                let order = &self.orders[index].order;
+                if !symbols.contains(&order.market_pair) {
+                    symbols.push(order.market_pair.clone());
+                }
+                OrderCanceled {
+                    id: order.id.clone(),
+                }
+            })
+            .collect();
+        for symbol in symbols {
+            self.publish(&symbol, Vec::new());
+        }
+        canceled
+    }
+
+    /// Expires the good till time orders whose deadline has passed
+    pub fn expire(&mut self, now: u64) {
+        let indexes: Vec<usize> = self
+            .open
+            .iter()
+            .copied()
+            .filter(|index| {
+                self.orders[*index]
+                    .expires_at
+                    .map_or(false, |expires_at| expires_at <= now)
+            })
+            .collect();
+
+        for index in indexes {
+            self.close(index, OrderStatus::Expired);
+            let symbol = self.orders[index].order.market_pair.clone();
+            self.publish(&symbol, Vec::new());
+        }
+    }
+
+    pub fn order_book(&self, market_pair: &str) -> Result<OrderBookResponse> {
+        self.market(market_pair)?;
+        Ok(OrderBookResponse {
+            market_pair: Some(market_pair.to_string()),
+            instrument: None,
+            update_id: None,
+            last_update_id: None,
+            bids: self.levels(market_pair, &Side::Sell, None),
+            asks: self.levels(market_pair, &Side::Buy, None),
+        })
+    }
+
+    /// Last price and the price of the first trade of the last 24 hours
+    pub fn ticker(&self, market_pair: &str, now: u64) -> Result<Ticker> {
+        let tape = &self.market(market_pair)?.tape;
+        Ok(Ticker {
+            market_pair: Some(market_pair.to_string()),
+            instrument: None,
+            price: tape.last().map(|trade| trade.price),
+            price_24h: tape
+                .iter()
+                .find(|trade| trade.created_at + DAY >= now)
+                .map(|trade| trade.price),
+        })
+    }
+
+    pub fn historic_trades(&self, market_pair: &str, paginator: Option<&Paginator>) -> Result<Vec<Trade>> {
+        let trades = self
+            .market(market_pair)?
+            .tape
+            .iter()
+            .filter(|trade| in_range(trade.created_at, paginator))
+            .cloned()
+            .collect();
+
+        Ok(last(trades, paginator))
+    }
+
+    pub fn candles(
+        &self,
+        market_pair: &str,
+        interval: Interval,
+        paginator: Option<&Paginator>,
+    ) -> Result<Vec<Candle>> {
+        let width = interval.to_duration().num_milliseconds() as u64;
+        let mut candles: BTreeMap<u64, Candle> = BTreeMap::new();
+        for trade in self.market(market_pair)?.tape.iter() {
+            let time = trade.created_at - trade.created_at % width;
+            if !in_range(time, paginator) {
+                continue;
+            }
+            let candle = candles.entry(time).or_insert_with(|| Candle {
+                market_pair: Some(market_pair.to_string()),
+                instrument: None,
+                time,
+                low: trade.price,
+                high: trade.price,
+                open: trade.price,
+                close: trade.price,
+                volume: Decimal::zero(),
+            });
+            candle.low = candle.low.min(trade.price);
+            candle.high = candle.high.max(trade.price);
+            candle.close = trade.price;
+            candle.volume += trade.qty;
+        }
+
+        Ok(last(candles.into_iter().map(|(_, candle)| candle).collect(), paginator))
+    }
+
+    /// The order books are sent on subscription and after every change of their market
+    pub fn subscribe(
+        &mut self,
+        order_books: Vec<String>,
+        trades: Vec<String>,
+        sender: UnboundedSender<OpenLimitsWebSocketMessage>,
+    ) -> Result<()> {
+        for market_pair in order_books.iter().chain(trades.iter()) {
+            self.market(market_pair)?;
+        }
+        for market_pair in order_books.iter() {
+            sender
+                .send(OpenLimitsWebSocketMessage::OrderBook(self.order_book(market_pair)?))
+                .map_err(|_| OpenLimitsError::SocketError())?;
+        }
+
+        self.subscribers.push(Subscriber {
+            order_books,
+            trades,
+            sender,
+        });
+        Ok(())
+    }
+
+    fn publish(&mut self, market_pair: &str, trades: Vec<Trade>) {
+        let book = match self.order_book(market_pair) {
+            Ok(book) => book,
+            Err(_) => return,
+        };
+
+        self.subscribers.retain(|subscriber| {
+            let mut connected = true;
+            if !trades.is_empty() && subscriber.trades.iter().any(|s| s == market_pair) {
+                connected &= subscriber
+                    .sender
+                    .send(OpenLimitsWebSocketMessage::Trades(trades.clone()))
+                    .is_ok();
+            }
+            if subscriber.order_books.iter().any(|s| s == market_pair) {
+                connected &= subscriber
+                    .sender
+                    .send(OpenLimitsWebSocketMessage::OrderBook(book.clone()))
+                    .is_ok();
+            }
+            connected
+        });
+    }
+
+    fn market(&self, market_pair: &str) -> Result<&Market> {
+        self.markets
+            .get(market_pair)
+            .ok_or(OpenLimitsError::SymbolNotFound())
+    }
+
+    fn find(&self, account: &str, id: &str) -> Option<usize> {
+        id.parse::<usize>()
+            .ok()
+            .and_then(|id| id.checked_sub(1))
+            .filter(|index| {
+                self.orders
+                    .get(*index)
+                    .map_or(false, |order| order.account == account)
+            })
+    }
+
+    /// Levels an order of `side` can take, best price first
+    fn levels(&self, market_pair: &str, side: &Side, limit: Option<Decimal>) -> Vec<AskBid> {
+        let book = match self.markets.get(market_pair) {
+            Some(market) => &market.book,
+            None => return Vec::new(),
+        };
+        let level = |(price, queue): (&Decimal, &VecDeque<usize>)| AskBid {
+            price: *price,
+            qty: queue
+                .iter()
+                .fold(Decimal::zero(), |acc, index| acc + self.orders[*index].remaining()),
+        };
+
+        match side {
+            Side::Buy => book
+                .asks
+                .iter()
+                .take_while(|(price, _)| limit.map_or(true, |limit| **price <= limit))
+                .map(level)
+                .collect(),
+            Side::Sell => book
+                .bids
+                .iter()
+                .rev()
+                .take_while(|(price, _)| limit.map_or(true, |limit| **price >= limit))
+                .map(level)
+                .collect(),
+        }
+    }
+
+    fn check_funds(&self, account: &str, pair: &MarketPair, side: &Side, required: Decimal) -> Result<()> {
+        let empty = Ledger::default();
+        let ledger = self.accounts.get(account).unwrap_or(&empty);
+        ledger.check_funds(pair, side, required, self.funded(account))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn insert(
+        &mut self,
+        account: &str,
+        pair: &MarketPair,
+        side: Side,
+        order_type: OrderType,
+        size: Decimal,
+        price: Option<Decimal>,
+        now: u64,
+    ) -> usize {
+        let index = self.orders.len();
+        self.orders.push(SimulatedOrder {
+            account: account.to_string(),
+            order: Order {
+                id: (index + 1).to_string(),
+                market_pair: pair.symbol.clone(),
+                instrument: None,
+                client_order_id: None,
+                created_at: Some(now),
+                order_type,
+                side,
+                status: OrderStatus::Open,
+                size,
+                price,
+                remaining: Some(size),
+                trades: Vec::new(),
+            },
+            base: pair.base.to_uppercase(),
+            quote: pair.quote.to_uppercase(),
+            locked: Decimal::zero(),
+            expires_at: None,
+        });
+        self.open.insert(index);
+        index
+    }
+
+    /// Takes the liquidity of the book until the order is filled or the book stops crossing it
+    fn take(&mut self, index: usize, limit: Option<Decimal>, now: u64) -> Vec<Trade> {
+        let symbol = self.orders[index].order.market_pair.clone();
+        let side = self.orders[index].order.side.clone();
+
+        let mut trades = Vec::new();
+        while self.orders[index].is_open() {
+            let best = match self.markets.get(&symbol) {
+                Some(market) => match side {
+                    Side::Buy => market.book.asks.iter().next(),
+                    Side::Sell => market.book.bids.iter().next_back(),
+                }
+                .and_then(|(price, queue)| queue.front().map(|maker| (*price, *maker))),
+                None => None,
+            };
+            let (price, maker) = match best {
+                Some(best) => best,
+                None => break,
+            };
+            let crosses = match side {
+                Side::Buy => limit.map_or(true, |limit| price <= limit),
+                Side::Sell => limit.map_or(true, |limit| price >= limit),
+            };
+            if !crosses {
+                break;
+            }
+
+            let qty = self.orders[index]
+                .remaining()
+                .min(self.orders[maker].remaining());
+            trades.push(self.execute(index, maker, price, qty, now));
+            if !self.orders[maker].is_open() {
+                self.unrest(maker);
+            }
+        }
+        trades
+    }
+
+    fn execute(&mut self, taker: usize, maker: usize, price: Decimal, qty: Decimal, now: u64) -> Trade {
+        let id = self.next_trade_id.to_string();
+        self.next_trade_id += 1;
+
+        let (buyer, seller) = match self.orders[taker].order.side {
+            Side::Buy => (taker, maker),
+            Side::Sell => (maker, taker),
+        };
+        let trade = Trade {
+            id,
+            buyer_order_id: Some(self.orders[buyer].order.id.clone()),
+            seller_order_id: Some(self.orders[seller].order.id.clone()),
+            market_pair: self.orders[taker].order.market_pair.clone(),
+            instrument: None,
+            price,
+            qty,
+            fees: None,
+            side: self.orders[taker].order.side.clone(),
+            liquidity: None,
+            created_at: now,
+        };
+
+        self.settle(taker, &trade, Liquidity::Taker);
+        self.settle(maker, &trade, Liquidity::Maker);
+        if let Some(market) = self.markets.get_mut(&trade.market_pair) {
+            market.tape.push(trade.clone());
+        }
+        trade
+    }
+
+    /// Moves the funds of one side of a trade and records it on the order
+    fn settle(&mut self, index: usize, trade: &Trade, liquidity: Liquidity) {
+        let account = self.orders[index].account.clone();
+        self.ledger_mut(&account);
+        if let Some(ledger) = self.accounts.get_mut(&account) {
+            ledger.settle(&mut self.orders[index], trade, liquidity);
+        }
+        if !self.orders[index].is_open() {
+            self.open.remove(&index);
+        }
+    }
+
+    fn rest(&mut self, index: usize) {
+        let order = &self.orders[index].order;
+        let price = order.price.unwrap_or_default();
+        if let Some(market) = self.markets.get_mut(&order.market_pair) {
+            let levels = match order.side {
+                Side::Buy => &mut market.book.bids,
+                Side::Sell => &mut market.book.asks,
+            };
+            levels.entry(price).or_default().push_back(index);
+        }
+    }
+
+    fn unrest(&mut self, index: usize) {
+        let order = &self.orders[index].order;
+        let price = match order.price {
+            Some(price) => price,
+            None => return,
+        };
+        if let Some(market) = self.markets.get_mut(&order.market_pair) {
+            let levels = match order.side {
+                Side::Buy => &mut market.book.bids,
+                Side::Sell => &mut market.book.asks,
+            };
+            if let Some(queue) = levels.get_mut(&price) {
+                queue.retain(|resting| *resting != index);
+                if queue.is_empty() {
+                    levels.remove(&price);
+                }
+            }
+        }
+    }
+
+    fn close(&mut self, index: usize, status: OrderStatus) {
+        self.unrest(index);
+        let order = &mut self.orders[index];
+        order.order.status = status;
+        order.locked = Decimal::zero();
+        self.open.remove(&index);
+    }
+}
+
+/// Orders must respect the increments and minimums of their market
+fn validate(pair: &MarketPair, size: Decimal, price: Option<Decimal>) -> Result<()> {
+    if size <= Decimal::zero() {
+        return Err(OpenLimitsError::InvalidParameter(format!(
+            "Size {} must be positive",
+            size
+        )));
+    }
+    if !pair.base_increment.is_zero() && !(size % pair.base_increment).is_zero() {
+        return Err(OpenLimitsError::InvalidParameter(format!(
+            "Size {} is not a multiple of {}",
+            size, pair.base_increment
+        )));
+    }
+    if let Some(min) = pair.min_base_trade_size {
+        if size < min {
+            return Err(OpenLimitsError::InvalidParameter(format!(
+                "Size {} is below the minimum of {}",
+                size, min
+            )));
+        }
+    }
+
+    if let Some(price) = price {
+        if price <= Decimal::zero() {
+            return Err(OpenLimitsError::InvalidParameter(format!(
+                "Price {} must be positive",
+                price
+            )));
+        }
+        if !pair.quote_increment.is_zero() && !(price % pair.quote_increment).is_zero() {
+            return Err(OpenLimitsError::InvalidParameter(format!(
+                "Price {} is not a multiple of {}",
+                price, pair.quote_increment
+            )));
+        }
+        if let Some(min) = pair.min_quote_trade_size {
+            if size * price < min {
+                return Err(OpenLimitsError::InvalidParameter(format!(
+                    "Notional {} is below the minimum of {}",
+                    size * price,
+                    min
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn in_range(time: u64, paginator: Option<&Paginator>) -> bool {
+    let paginator = match paginator {
+        Some(paginator) => paginator,
+        None => return true,
+    };
+    paginator.start_time.map_or(true, |start| time >= start)
+        && paginator.end_time.map_or(true, |end| time <= end)
+}
+
+/// The most recent `limit` items
+fn last<T>(mut items: Vec<T>, paginator: Option<&Paginator>) -> Vec<T> {
+    if let Some(limit) = paginator.and_then(|paginator| paginator.limit) {
+        let limit = limit as usize;
+        if items.len() > limit {
+            items.drain(..items.len() - limit);
+        }
+    }
+    items
+}
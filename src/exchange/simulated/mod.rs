@@ -0,0 +1,266 @@
+//! This module provides a deterministic exchange that runs in process. Orders are matched by
+//! a price-time priority engine on markets configured by the caller, and the time is read from
+//! an injectable clock, so tests and strategies can run offline.
+//! # Example
+//! ```
+//! use std::sync::Arc;
+//! use openlimits::exchange::simulated::{ManualClock, SimulatedExchange, SimulatedParameters, SimulatedVenue};
+//! use openlimits::prelude::*;
+//! use openlimits::model::{OpenLimitOrderRequest, TimeInForce};
+//! use rust_decimal::Decimal;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let venue = SimulatedVenue::new(vec![MarketPair {
+//!                         base: String::from("BTC"),
+//!                         quote: String::from("USD"),
+//!                         symbol: String::from("BTCUSD"),
+//!                         base_increment: Decimal::new(1, 8),
+//!                         quote_increment: Decimal::new(1, 2),
+//!                         min_base_trade_size: None,
+//!                         min_quote_trade_size: None,
+//!                         ..Default::default()
+//!                     }])
+//!                     .with_clock(Arc::new(ManualClock::new(0)));
+//!     let exchange = SimulatedExchange::new(
+//!                         SimulatedParameters::new(venue).with_balance("USD", Decimal::new(1000, 0)),
+//!                     )
+//!                     .await
+//!                     .expect("Couldn't create simulated exchange");
+
+//!     let order = exchange.limit_buy(&OpenLimitOrderRequest {
+//!                         market_pair: String::from("BTCUSD"),
+//!                         size: Decimal::new(1, 0),
+//!                         price: Decimal::new(100, 0),
+//!                         time_in_force: TimeInForce::GoodTillCancelled,
+//!                         post_only: false,
+//!                     })
+//!                     .await
+//!                     .expect("Couldn't limit buy");
+
+//!     println!("{:?}", order);
+//! }
+//! ```
+
+use async_trait::async_trait;
+use crate::{
+    model::{
+        Balance, CancelAllOrdersRequest, CancelOrderRequest, Candle, GetHistoricRatesRequest,
+        GetHistoricTradesRequest, GetOrderHistoryRequest, GetOrderRequest, GetPriceTickerRequest,
+        OpenLimitOrderRequest, OpenMarketOrderRequest, Order, OrderBookRequest,
+        OrderBookResponse, OrderCanceled, Paginator, Side, Ticker, Trade, TradeHistoryRequest,
+    },
+};
+use crate::exchange::traits::info::{ExchangeInfo, ExchangeInfoRetrieval, MarketPair, MarketPairHandle};
+use crate::exchange::traits::{Exchange, ExchangeAccount, ExchangeMarketData};
+use super::shared::Result;
+
+mod clock;
+mod matching_engine;
+mod simulated_order;
+mod simulated_parameters;
+mod simulated_venue;
+mod simulated_websocket;
+
+pub use clock::{Clock, ManualClock, SystemClock};
+pub use matching_engine::MatchingEngine;
+pub use simulated_order::SimulatedOrder;
+pub use simulated_parameters::SimulatedParameters;
+pub use simulated_venue::SimulatedVenue;
+pub use simulated_websocket::SimulatedWebsocket;
+pub use super::shared;
+
+/// The main struct of the simulated module, a client of one account of a `SimulatedVenue`.
+#[derive(Clone)]
+pub struct SimulatedExchange {
+    pub exchange_info: ExchangeInfo,
+    pub venue: SimulatedVenue,
+    pub account: String,
+}
+
+#[async_trait]
+impl Exchange for SimulatedExchange {
+    type InitParams = SimulatedParameters;
+    type InnerClient = SimulatedVenue;
+
+    async fn new(parameters: Self::InitParams) -> Result<Self> {
+        {
+            let mut engine = parameters.venue.engine()?;
+            for (asset, amount) in parameters.balances.iter() {
+                engine.deposit(&parameters.account, asset, *amount);
+            }
+        }
+
+        let simulated = SimulatedExchange {
+            exchange_info: ExchangeInfo::new(),
+            venue: parameters.venue,
+            account: parameters.account,
+        };
+        simulated.refresh_market_info().await?;
+        Ok(simulated)
+    }
+
+    fn inner_client(&self) -> Option<&Self::InnerClient> {
+        Some(&self.venue)
+    }
+}
+
+#[async_trait]
+impl ExchangeInfoRetrieval for SimulatedExchange {
+    async fn retrieve_pairs(&self) -> Result<Vec<MarketPair>> {
+        Ok(self.venue.engine()?.pairs())
+    }
+
+    async fn refresh_market_info(&self) -> Result<Vec<MarketPairHandle>> {
+        self.exchange_info
+            .refresh(self as &dyn ExchangeInfoRetrieval)
+            .await
+    }
+
+    async fn get_pair(&self, name: &str) -> Result<MarketPairHandle> {
+        self.exchange_info.get_pair(name)
+    }
+}
+
+#[async_trait]
+impl ExchangeMarketData for SimulatedExchange {
+    async fn order_book(&self, req: &OrderBookRequest) -> Result<OrderBookResponse> {
+        let book = self.venue.engine()?.order_book(&req.market_pair)?;
+        Ok(self.exchange_info.translate(book))
+    }
+
+    async fn get_price_ticker(&self, req: &GetPriceTickerRequest) -> Result<Ticker> {
+        let ticker = self
+            .venue
+            .engine()?
+            .ticker(&req.market_pair, self.venue.now())?;
+        Ok(self.exchange_info.translate(ticker))
+    }
+
+    async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
+        let candles = self
+            .venue
+            .engine()?
+            .candles(&req.market_pair, req.interval, req.paginator.as_ref())?;
+        Ok(self.exchange_info.translate(candles))
+    }
+
+    async fn get_historic_trades(&self, req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
+        let trades = self
+            .venue
+            .engine()?
+            .historic_trades(&req.market_pair, req.paginator.as_ref())?;
+        Ok(self.exchange_info.translate(trades))
+    }
+}
+
+#[async_trait]
+impl ExchangeAccount for SimulatedExchange {
+    async fn limit_buy(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        let order = self
+            .venue
+            .engine()?
+            .limit_order(&self.account, Side::Buy, req, self.venue.now())?;
+        Ok(self.exchange_info.translate(order))
+    }
+
+    async fn limit_sell(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        let order = self
+            .venue
+            .engine()?
+            .limit_order(&self.account, Side::Sell, req, self.venue.now())?;
+        Ok(self.exchange_info.translate(order))
+    }
+
+    async fn market_buy(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        let order = self
+            .venue
+            .engine()?
+            .market_order(&self.account, Side::Buy, req, self.venue.now())?;
+        Ok(self.exchange_info.translate(order))
+    }
+
+    async fn market_sell(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        let order = self
+            .venue
+            .engine()?
+            .market_order(&self.account, Side::Sell, req, self.venue.now())?;
+        Ok(self.exchange_info.translate(order))
+    }
+
+    async fn cancel_order(&self, req: &CancelOrderRequest) -> Result<OrderCanceled> {
+        self.venue
+            .engine()?
+            .cancel(&self.account, &req.id, self.venue.now())
+    }
+
+    async fn cancel_all_orders(&self, req: &CancelAllOrdersRequest) -> Result<Vec<OrderCanceled>> {
+        Ok(self.venue.engine()?.cancel_all(
+            &self.account,
+            req.market_pair.as_deref(),
+            self.venue.now(),
+        ))
+    }
+
+    async fn get_all_open_orders(&self) -> Result<Vec<Order>> {
+        let mut engine = self.venue.engine()?;
+        engine.expire(self.venue.now());
+        Ok(self.exchange_info.translate(engine.open_orders(&self.account)))
+    }
+
+    async fn get_order_history(&self, req: &GetOrderHistoryRequest) -> Result<Vec<Order>> {
+        let mut engine = self.venue.engine()?;
+        engine.expire(self.venue.now());
+        let orders: Vec<Order> = engine
+            .orders(&self.account)
+            .into_iter()
+            .filter(|order| {
+                req.market_pair
+                    .as_ref()
+                    .map_or(true, |market_pair| &order.market_pair == market_pair)
+            })
+            .filter(|order| {
+                req.order_status
+                    .as_ref()
+                    .map_or(true, |statuses| statuses.contains(&order.status))
+            })
+            .collect();
+        Ok(self.exchange_info.translate(orders))
+    }
+
+    async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>> {
+        let trades: Vec<Trade> = self
+            .venue
+            .engine()?
+            .trades(&self.account)
+            .into_iter()
+            .filter(|trade| {
+                req.market_pair
+                    .as_ref()
+                    .map_or(true, |market_pair| &trade.market_pair == market_pair)
+            })
+            .filter(|trade| {
+                req.order_id.as_ref().map_or(true, |order_id| {
+                    let own_order_id = match trade.side {
+                        Side::Buy => trade.buyer_order_id.as_ref(),
+                        Side::Sell => trade.seller_order_id.as_ref(),
+                    };
+                    own_order_id == Some(order_id)
+                })
+            })
+            .collect();
+        Ok(self.exchange_info.translate(trades))
+    }
+
+    async fn get_account_balances(&self, _paginator: Option<Paginator>) -> Result<Vec<Balance>> {
+        Ok(self.venue.engine()?.balances(&self.account))
+    }
+
+    async fn get_order(&self, req: &GetOrderRequest) -> Result<Order> {
+        let mut engine = self.venue.engine()?;
+        engine.expire(self.venue.now());
+        engine
+            .order(&self.account, &req.id)
+            .map(|order| self.exchange_info.translate(order))
+    }
+}
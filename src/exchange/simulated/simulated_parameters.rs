@@ -0,0 +1,33 @@
+use std::collections::HashMap;
+use rust_decimal::Decimal;
+use super::SimulatedVenue;
+
+/// This struct represents the simulated exchange parameters.
+/// `account` names the account of the venue the client trades with, `balances` are deposited
+/// into it when the exchange client is created.
+#[derive(Clone)]
+pub struct SimulatedParameters {
+    pub venue: SimulatedVenue,
+    pub account: String,
+    pub balances: HashMap<String, Decimal>,
+}
+
+impl SimulatedParameters {
+    pub fn new(venue: SimulatedVenue) -> Self {
+        Self {
+            venue,
+            account: String::from("default"),
+            balances: HashMap::new(),
+        }
+    }
+
+    pub fn with_account(mut self, account: &str) -> Self {
+        self.account = account.to_string();
+        self
+    }
+
+    pub fn with_balance(mut self, asset: &str, amount: Decimal) -> Self {
+        self.balances.insert(asset.to_uppercase(), amount);
+        self
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+use rust_decimal::Decimal;
+use crate::errors::OpenLimitsError;
+use crate::exchange::traits::info::MarketPair;
+use super::{Clock, MatchingEngine, SystemClock};
+use super::shared::Result;
+
+/// This struct represents a simulated exchange: its markets, matching engine and clock.
+/// Clones share the same engine, so every client created from it trades on the same books.
+#[derive(Clone)]
+pub struct SimulatedVenue {
+    engine: Arc<Mutex<MatchingEngine>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl SimulatedVenue {
+    pub fn new(pairs: Vec<MarketPair>) -> Self {
+        Self {
+            engine: Arc::new(Mutex::new(MatchingEngine::new(pairs))),
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub fn with_fees(self, maker_fee: Decimal, taker_fee: Decimal) -> Self {
+        if let Ok(mut engine) = self.engine.lock() {
+            engine.set_fees(maker_fee, taker_fee);
+        }
+        self
+    }
+
+    pub fn now(&self) -> u64 {
+        self.clock.now()
+    }
+
+    pub fn engine(&self) -> Result<MutexGuard<MatchingEngine>> {
+        self.engine
+            .lock()
+            .map_err(|_| OpenLimitsError::PoisonError())
+    }
+}
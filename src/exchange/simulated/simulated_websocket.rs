@@ -0,0 +1,69 @@
+use std::sync::Mutex;
+use async_trait::async_trait;
+use futures::stream::{BoxStream, StreamExt};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use crate::errors::OpenLimitsError;
+use crate::exchange::traits::stream::{ExchangeWs, Subscriptions};
+use crate::model::websocket::{OpenLimitsWebSocketMessage, Subscription};
+use super::{SimulatedParameters, SimulatedVenue};
+use super::shared::Result;
+
+/// This struct is used for websocket communications with the simulated exchange.
+/// Order books are sent on subscription and after every change, trades as they happen.
+pub struct SimulatedWebsocket {
+    venue: SimulatedVenue,
+    disconnection_senders: Mutex<Vec<UnboundedSender<()>>>,
+}
+
+#[async_trait]
+impl ExchangeWs for SimulatedWebsocket {
+    type InitParams = SimulatedParameters;
+    type Subscription = Subscription;
+    type Response = OpenLimitsWebSocketMessage;
+
+    async fn new(parameters: Self::InitParams) -> Result<Self> {
+        Ok(SimulatedWebsocket {
+            venue: parameters.venue,
+            disconnection_senders: Default::default(),
+        })
+    }
+
+    async fn disconnect(&self) {
+        if let Ok(mut senders) = self.disconnection_senders.lock() {
+            for sender in senders.iter() {
+                sender.send(()).ok();
+            }
+            senders.clear();
+        }
+    }
+
+    async fn create_stream_specific(
+        &self,
+        subscriptions: Subscriptions<Self::Subscription>,
+    ) -> Result<BoxStream<'static, Result<Self::Response>>> {
+        let mut order_books = Vec::new();
+        let mut trades = Vec::new();
+        for subscription in subscriptions.into_iter() {
+            match subscription {
+                Subscription::OrderBookUpdates(symbol) => order_books.push(symbol),
+                Subscription::Trades(symbol) => trades.push(symbol),
+                _ => return Err(OpenLimitsError::WebSocketMessageNotSupported()),
+            }
+        }
+
+        let (sender, receiver) = unbounded_channel();
+        self.venue.engine()?.subscribe(order_books, trades, sender)?;
+
+        let (disconnection_sender, mut disconnection_receiver) = unbounded_channel::<()>();
+        if let Ok(mut senders) = self.disconnection_senders.lock() {
+            senders.push(disconnection_sender);
+        }
+
+        let s = UnboundedReceiverStream::new(receiver)
+            .take_until(async move { disconnection_receiver.recv().await })
+            .map(Ok);
+
+        Ok(s.boxed())
+    }
+}
@@ -22,4 +22,23 @@ pub trait ExchangeAccount {
     async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>>;
     async fn get_account_balances(&self, paginator: Option<Paginator>) -> Result<Vec<Balance>>;
     async fn get_order(&self, req: &GetOrderRequest) -> Result<Order>;
+
+    /// Balance of one asset, zero when the account doesn't hold it. The balances of the asset
+    /// held in several wallets are merged.
+    async fn get_balance(&self, asset: &str) -> Result<Balance> {
+        let empty = Balance {
+            asset: asset.to_string(),
+            ..Default::default()
+        };
+        Ok(self
+            .get_account_balances(None)
+            .await?
+            .into_iter()
+            .filter(|balance| balance.asset.eq_ignore_ascii_case(asset))
+            .fold(None, |merged: Option<Balance>, balance| match merged {
+                Some(merged) => Some(merged.merge(balance)),
+                None => Some(balance),
+            })
+            .unwrap_or(empty))
+    }
 }
\ No newline at end of file
@@ -0,0 +1,58 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use super::shared::Result;
+use super::HttpTransport;
+
+/// This struct represents the networking options of the REST api transports
+#[derive(Clone, Default)]
+pub struct HttpConfig {
+    /// Sends the requests, a default `reqwest::Client` is used when it isn't set
+    pub transport: Option<Arc<dyn HttpTransport>>,
+    /// Replaces the base url of the exchange environment
+    pub base_url: Option<String>,
+    /// Timeout of every request
+    pub timeout: Option<Duration>,
+}
+
+impl HttpConfig {
+    pub fn with_transport<T: HttpTransport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = Some(base_url.to_string());
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// The injected transport or a new default client
+    pub fn build_transport(&self) -> Result<Arc<dyn HttpTransport>> {
+        match self.transport.as_ref() {
+            Some(transport) => Ok(transport.clone()),
+            None => Ok(Arc::new(reqwest::Client::builder().build()?)),
+        }
+    }
+
+    /// The overridden base url or the default one
+    pub fn base_url_or(&self, default: &str) -> String {
+        self.base_url
+            .clone()
+            .unwrap_or_else(|| default.to_string())
+    }
+}
+
+impl fmt::Debug for HttpConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpConfig")
+            .field("transport", &self.transport.as_ref().map(|_| "custom"))
+            .field("base_url", &self.base_url)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use reqwest::{Request, Response};
+use super::shared::Result;
+
+/// This trait sends the requests of the REST api transports.
+/// It is implemented for `reqwest::Client`, a client built with a proxy, custom TLS roots,
+/// a bound local address or a tuned connection pool can be injected as it is.
+#[async_trait]
+pub trait HttpTransport: Send + Sync {
+    async fn execute(&self, request: Request) -> Result<Response>;
+}
+
+#[async_trait]
+impl HttpTransport for reqwest::Client {
+    async fn execute(&self, request: Request) -> Result<Response> {
+        Ok(reqwest::Client::execute(self, request).await?)
+    }
+}
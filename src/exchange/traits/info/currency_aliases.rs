@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+use crate::model::Instrument;
+
+/// This struct represents the currencies that are considered the same when translating the
+/// symbols of an exchange to instruments, e.g. `XBT` is `BTC`. Aliases map to their canonical
+/// currency and are case insensitive.
+#[derive(Debug, Clone)]
+pub struct CurrencyAliases {
+    aliases: HashMap<String, String>,
+}
+
+impl CurrencyAliases {
+    /// No currency is an alias of another
+    pub fn empty() -> Self {
+        Self {
+            aliases: HashMap::new(),
+        }
+    }
+
+    /// `alias` is translated to `currency`, e.g. `with_alias("USDC", "USD")`
+    pub fn with_alias(mut self, alias: &str, currency: &str) -> Self {
+        self.aliases
+            .insert(alias.to_uppercase(), currency.to_uppercase());
+        self
+    }
+
+    pub fn canonical(&self, currency: &str) -> String {
+        let currency = currency.to_uppercase();
+        self.aliases.get(&currency).cloned().unwrap_or(currency)
+    }
+
+    /// The instrument whose currencies are the canonical ones of `instrument`
+    pub fn instrument(&self, instrument: &Instrument) -> Instrument {
+        Instrument::new(
+            &self.canonical(&instrument.base),
+            &self.canonical(&instrument.quote),
+        )
+    }
+}
+
+impl Default for CurrencyAliases {
+    fn default() -> Self {
+        Self::empty().with_alias("XBT", "BTC")
+    }
+}
@@ -1,33 +1,179 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+use std::process;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::sync::RwLock;
+use std::time::Duration;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use tokio::sync::mpsc::UnboundedReceiver;
 use crate::errors::OpenLimitsError;
-use super::shared::Result;
+use crate::model::{Instrument, MarketResponse, Translate};
+use super::shared::{Result, Subscribers};
+use super::CurrencyAliases;
 use super::ExchangeInfoRetrieval;
 use super::MarketPairHandle;
 use super::MarketPair;
+use super::MarketPairEvent;
+use super::MarketStatus;
 
-/// This struct represents informations about the exchange
+/// Saves of the process, numbering their temporary files
+static SAVES: AtomicU64 = AtomicU64::new(0);
+
+/// Content of a cache file, `saved_at` is in milliseconds
+#[derive(Serialize, Deserialize)]
+struct CachedPairs {
+    saved_at: u64,
+    pairs: Vec<MarketPair>,
+}
+
+/// The symbols of the markets by instrument, both as listed and with canonical currencies, and
+/// the canonical instruments by symbol. When several markets have the same instrument the
+/// smallest symbol is used.
+#[derive(Default)]
+struct SymbolIndex {
+    instruments: HashMap<String, Instrument>,
+    listed: HashMap<Instrument, String>,
+    canonical: HashMap<Instrument, String>,
+}
+
+impl SymbolIndex {
+    fn new(pairs: &[MarketPair], aliases: &CurrencyAliases) -> Self {
+        let mut index = Self::default();
+        for pair in pairs {
+            let listed = Instrument::new(&pair.base, &pair.quote);
+            let canonical = aliases.instrument(&listed);
+            Self::insert(&mut index.listed, listed, &pair.symbol);
+            Self::insert(&mut index.canonical, canonical.clone(), &pair.symbol);
+            index.instruments.insert(pair.symbol.clone(), canonical);
+        }
+        index
+    }
+
+    fn insert(symbols: &mut HashMap<Instrument, String>, instrument: Instrument, symbol: &str) {
+        let current = symbols
+            .entry(instrument)
+            .or_insert_with(|| symbol.to_string());
+        if symbol < current.as_str() {
+            *current = symbol.to_string();
+        }
+    }
+}
+
+/// This struct represents informations about the exchange.
+/// It translates the symbols of the exchange, e.g. `XBTUSD`, from and to instruments, e.g.
+/// `BTC/USD`, the currencies being made canonical by its `CurrencyAliases`.
+/// The handles of the markets are updated in place when the markets are refreshed.
 #[derive(Clone)]
 pub struct ExchangeInfo {
     pairs: Arc<RwLock<HashMap<String, Arc<RwLock<MarketPair>>>>>,
+    aliases: Arc<RwLock<CurrencyAliases>>,
+    index: Arc<RwLock<SymbolIndex>>,
+    subscribers: Subscribers<MarketPairEvent>,
 }
 
 impl ExchangeInfo {
     pub fn new() -> Self {
         Self {
             pairs: Arc::new(RwLock::new(HashMap::default())),
+            aliases: Default::default(),
+            index: Default::default(),
+            subscribers: Default::default(),
         }
     }
 
+    /// Receives the changes of the markets found from now on
+    pub fn subscribe(&self) -> UnboundedReceiver<MarketPairEvent> {
+        self.subscribers.subscribe()
+    }
+
+    /// `name` is either a symbol of the exchange or an instrument, e.g. `BTC/USD`
     pub fn get_pair(&self, name: &str) -> Result<MarketPairHandle> {
-        let market_map = self.pairs.read().expect("Couldn't read pairs.");
-        let market_pair = market_map.get(name);
+        let symbol = self.symbol(name)?;
+        let market_map = self
+            .pairs
+            .read()
+            .map_err(|_| OpenLimitsError::PoisonError())?;
+        let market_pair = market_map.get(&symbol);
         market_pair.map_or(Err(OpenLimitsError::SymbolNotFound()), |inner| {
             Ok(MarketPairHandle::new(inner.clone()))
         })
     }
 
+    pub fn aliases(&self) -> Result<CurrencyAliases> {
+        self.aliases
+            .read()
+            .map(|aliases| aliases.clone())
+            .map_err(|_| OpenLimitsError::PoisonError())
+    }
+
+    /// The aliases are shared by the clones of this `ExchangeInfo`
+    pub fn set_aliases(&self, aliases: CurrencyAliases) -> Result<()> {
+        *self
+            .aliases
+            .write()
+            .map_err(|_| OpenLimitsError::PoisonError())? = aliases;
+        self.reindex()
+    }
+
+    /// The instrument of `market_pair`, which is either a symbol of the exchange or an
+    /// instrument
+    pub fn instrument(&self, market_pair: &str) -> Result<Instrument> {
+        if let Some(instrument) = Instrument::parse(market_pair) {
+            return Ok(self.aliases()?.instrument(&instrument));
+        }
+        self.index
+            .read()
+            .map_err(|_| OpenLimitsError::PoisonError())?
+            .instruments
+            .get(market_pair)
+            .cloned()
+            .ok_or(OpenLimitsError::SymbolNotFound())
+    }
+
+    /// The symbol of the exchange of `market_pair`, which is either already a symbol or an
+    /// instrument. A market whose currencies are the ones of the instrument is preferred to a
+    /// market whose currencies are only aliases of them, the smallest symbol being used when
+    /// several markets are.
+    pub fn symbol(&self, market_pair: &str) -> Result<String> {
+        let instrument = match Instrument::parse(market_pair) {
+            Some(instrument) => instrument,
+            None => return Ok(market_pair.to_string()),
+        };
+        let canonical = self.aliases()?.instrument(&instrument);
+        let index = self
+            .index
+            .read()
+            .map_err(|_| OpenLimitsError::PoisonError())?;
+        index
+            .listed
+            .get(&instrument)
+            .or_else(|| index.canonical.get(&canonical))
+            .cloned()
+            .ok_or(OpenLimitsError::SymbolNotFound())
+    }
+
+    /// Sets the instruments of a response of the exchange from its symbols. Symbols missing
+    /// from the markets are left without instrument.
+    pub fn translate<T: Translate>(&self, response: T) -> T {
+        response.translate(&|symbol| self.instrument(symbol).ok())
+    }
+
+    /// Sets the symbol and the instrument of a market data response of `market_pair`, which is
+    /// either a symbol of the exchange or an instrument
+    pub fn translate_market<T>(&self, market_pair: &str, response: T) -> T
+    where
+        T: Translate + MarketResponse,
+    {
+        let symbol = self
+            .symbol(market_pair)
+            .unwrap_or_else(|_| market_pair.to_string());
+        self.translate(response.with_market_pair(&symbol))
+    }
+
     pub fn list_pairs(&self) -> Vec<MarketPairHandle> {
         let market_map = self.pairs.read().expect("Couldn't read pairs.");
         market_map
@@ -41,19 +187,128 @@ impl ExchangeInfo {
         retrieval: &dyn ExchangeInfoRetrieval,
     ) -> Result<Vec<MarketPairHandle>> {
         let pairs = retrieval.retrieve_pairs().await?;
+        self.update(pairs)?;
+        Ok(self.list_pairs())
+    }
+
+    /// Replaces the markets by `pairs`, the markets of the exchange. The markets missing from
+    /// `pairs` are removed, unless it's empty as an exchange without markets is more likely a
+    /// failed retrieval than a delisting of everything.
+    pub fn update(&self, pairs: Vec<MarketPair>) -> Result<Vec<MarketPairEvent>> {
+        let mut events = Vec::new();
+        {
+            let mut markets = self
+                .pairs
+                .write()
+                .map_err(|_| OpenLimitsError::PoisonError())?;
+
+            if !pairs.is_empty() {
+                let listed: HashSet<&str> = pairs.iter().map(|pair| pair.symbol.as_str()).collect();
+                let delisted: Vec<String> = markets
+                    .keys()
+                    .filter(|symbol| !listed.contains(symbol.as_str()))
+                    .cloned()
+                    .collect();
+                for symbol in delisted {
+                    if let Some(market) = markets.remove(&symbol) {
+                        let mut pair = market
+                            .write()
+                            .map_err(|_| OpenLimitsError::PoisonError())?;
+                        events.push(MarketPairEvent::Removed(pair.clone()));
+                        pair.status = MarketStatus::Halted;
+                    }
+                }
+            }
 
-        if let Ok(mut writable_pairs) = self.pairs.write() {
             for pair in pairs {
-                let entry = writable_pairs
-                    .entry(pair.symbol.clone())
-                    .or_insert_with(|| Arc::new(RwLock::new(pair.clone())));
-                if let Ok(mut entry) = entry.write() {
-                    *entry = pair;
+                match markets.get(&pair.symbol) {
+                    Some(market) => {
+                        let mut current = market
+                            .write()
+                            .map_err(|_| OpenLimitsError::PoisonError())?;
+                        if *current != pair {
+                            events.push(MarketPairEvent::Changed {
+                                previous: current.clone(),
+                                pair: pair.clone(),
+                            });
+                            *current = pair;
+                        }
+                    }
+                    None => {
+                        events.push(MarketPairEvent::Added(pair.clone()));
+                        markets.insert(pair.symbol.clone(), Arc::new(RwLock::new(pair)));
+                    }
                 }
             }
         }
 
-        Ok(self.list_pairs())
+        self.reindex()?;
+        self.subscribers.publish(&events);
+        Ok(events)
+    }
+
+    /// Writes the markets to a cache file. The file is replaced at once, a process reading it
+    /// meanwhile never reads a partial cache. Every save writes a temporary file of its own,
+    /// named after the process and the save, so concurrent saves don't mix their writes.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let pairs = self
+            .list_pairs()
+            .iter()
+            .map(|handle| handle.read())
+            .collect::<Result<Vec<MarketPair>>>()?;
+        let cache = CachedPairs {
+            saved_at: Utc::now().timestamp_millis() as u64,
+            pairs,
+        };
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let temporary = path.with_file_name(format!(
+            ".{}.{}-{}.tmp",
+            name,
+            process::id(),
+            SAVES.fetch_add(1, Ordering::Relaxed)
+        ));
+        let saved = fs::write(&temporary, serde_json::to_vec(&cache)?)
+            .and_then(|_| fs::rename(&temporary, path));
+        if saved.is_err() {
+            fs::remove_file(&temporary).ok();
+        }
+        saved?;
+        Ok(())
+    }
+
+    /// Reads the markets from a cache file saved less than `ttl` ago, returns whether they
+    /// were read
+    pub fn load(&self, path: impl AsRef<Path>, ttl: Duration) -> Result<bool> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(false);
+        }
+        let cache: CachedPairs = serde_json::from_slice(&fs::read(path)?)?;
+        let age = (Utc::now().timestamp_millis() as u64).saturating_sub(cache.saved_at);
+        if age > ttl.as_millis() as u64 || cache.pairs.is_empty() {
+            return Ok(false);
+        }
+        self.update(cache.pairs)?;
+        Ok(true)
+    }
+
+    fn reindex(&self) -> Result<()> {
+        let pairs = self
+            .list_pairs()
+            .iter()
+            .map(|handle| handle.read())
+            .collect::<Result<Vec<MarketPair>>>()?;
+        let index = SymbolIndex::new(&pairs, &self.aliases()?);
+        *self
+            .index
+            .write()
+            .map_err(|_| OpenLimitsError::PoisonError())? = index;
+        Ok(())
     }
 }
 
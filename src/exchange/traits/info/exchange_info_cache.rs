@@ -0,0 +1,67 @@
+use std::path::PathBuf;
+use std::time::Duration;
+use crate::exchange::shared::Result;
+use super::ExchangeInfo;
+use super::ExchangeInfoRetrieval;
+
+/// This struct represents the file caching the markets of an exchange between processes.
+/// A cache older than `ttl` isn't used. When `lazy` the exchange is created without waiting
+/// for its markets, which are refreshed in the background.
+#[derive(Debug, Clone)]
+pub struct ExchangeInfoCache {
+    pub path: PathBuf,
+    pub ttl: Duration,
+    pub lazy: bool,
+}
+
+impl ExchangeInfoCache {
+    pub fn new(path: impl Into<PathBuf>, ttl: Duration) -> Self {
+        Self {
+            path: path.into(),
+            ttl,
+            lazy: false,
+        }
+    }
+
+    pub fn with_lazy(mut self, lazy: bool) -> Self {
+        self.lazy = lazy;
+        self
+    }
+
+    /// Fills the markets of an exchange being created. They're read from the cache when it's
+    /// fresh, otherwise they're retrieved and cached, in the background when `lazy`. A lazy
+    /// cache always refreshes the markets in the background.
+    pub async fn initialize<E>(&self, exchange: &E, exchange_info: &ExchangeInfo) -> Result<()>
+    where
+        E: ExchangeInfoRetrieval + Clone + Send + Sync + 'static,
+    {
+        let loaded = self.load(exchange_info);
+
+        if self.lazy {
+            let cache = self.clone();
+            let exchange = exchange.clone();
+            let exchange_info = exchange_info.clone();
+            tokio::spawn(async move { cache.refresh(&exchange, &exchange_info).await.ok() });
+        } else if !loaded {
+            self.refresh(exchange, exchange_info).await?;
+        }
+        Ok(())
+    }
+
+    /// Reads the markets from the cache when it's fresh, returns whether they were read.
+    /// A cache that can't be read is ignored.
+    pub fn load(&self, exchange_info: &ExchangeInfo) -> bool {
+        exchange_info.load(&self.path, self.ttl).unwrap_or(false)
+    }
+
+    /// Retrieves the markets of the exchange and caches them. A cache that can't be written is
+    /// skipped.
+    pub async fn refresh<E>(&self, exchange: &E, exchange_info: &ExchangeInfo) -> Result<()>
+    where
+        E: ExchangeInfoRetrieval,
+    {
+        exchange.refresh_market_info().await?;
+        exchange_info.save(&self.path).ok();
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::{interval_at, Instant};
+use super::ExchangeInfoRetrieval;
+
+/// This struct represents a task refreshing the markets of an exchange in the background.
+/// The changes are received by the subscribers of its `ExchangeInfo`, and the task is stopped
+/// when the refresher is dropped.
+pub struct ExchangeInfoRefresher {
+    task: JoinHandle<()>,
+}
+
+impl ExchangeInfoRefresher {
+    /// Refreshes the markets every `interval`, the first time after `interval`.
+    /// Failed refreshes are skipped.
+    pub fn spawn<E>(exchange: Arc<E>, interval: Duration) -> Self
+    where
+        E: ExchangeInfoRetrieval + Send + Sync + 'static,
+    {
+        let task = tokio::spawn(async move {
+            let mut interval = interval_at(Instant::now() + interval, interval);
+            loop {
+                interval.tick().await;
+                exchange.refresh_market_info().await.ok();
+            }
+        });
+        Self { task }
+    }
+
+    pub fn stop(self) {
+        self.task.abort();
+    }
+}
+
+impl Drop for ExchangeInfoRefresher {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
@@ -1,6 +1,14 @@
 use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde::Serialize;
+use crate::model::OrderType;
+use super::MarketStatus;
 
-#[derive(Debug, Clone)]
+/// This struct represents a market and its trading rules.
+/// `min_quote_trade_size` and `max_quote_trade_size` bound the notional of an order, the
+/// rules an exchange doesn't publish are `None`. An empty `order_types` means the supported
+/// order types aren't known.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
 pub struct MarketPair {
     pub base: String,
     pub quote: String,
@@ -9,4 +17,23 @@ pub struct MarketPair {
     pub quote_increment: Decimal,
     pub min_base_trade_size: Option<Decimal>,
     pub min_quote_trade_size: Option<Decimal>,
-}
\ No newline at end of file
+    pub max_base_trade_size: Option<Decimal>,
+    pub max_quote_trade_size: Option<Decimal>,
+    pub min_price: Option<Decimal>,
+    pub max_price: Option<Decimal>,
+    pub status: MarketStatus,
+    pub order_types: Vec<OrderType>,
+    pub base_precision: Option<u32>,
+    pub quote_precision: Option<u32>,
+}
+
+impl MarketPair {
+    /// Whether new orders can be placed
+    pub fn is_trading(&self) -> bool {
+        matches!(self.status, MarketStatus::Trading | MarketStatus::PostOnly)
+    }
+
+    pub fn supports(&self, order_type: &OrderType) -> bool {
+        self.order_types.is_empty() || self.order_types.contains(order_type)
+    }
+}
@@ -0,0 +1,22 @@
+use super::MarketPair;
+
+/// This enum represents a change of the markets of an exchange found by a refresh
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarketPairEvent {
+    Added(MarketPair),
+    /// The market isn't listed anymore, the handles still held on it are marked as halted
+    Removed(MarketPair),
+    /// The rules or the status of the market changed
+    Changed {
+        previous: MarketPair,
+        pair: MarketPair,
+    },
+}
+
+impl MarketPairEvent {
+    pub fn pair(&self) -> &MarketPair {
+        match self {
+            Self::Added(pair) | Self::Removed(pair) | Self::Changed { pair, .. } => pair,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This enum represents the trading status of a market
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MarketStatus {
+    Trading,
+    Halted,
+    PostOnly,
+    CancelOnly,
+}
+
+impl Default for MarketStatus {
+    fn default() -> Self {
+        Self::Trading
+    }
+}
@@ -1,14 +1,24 @@
 //! This module provides informations about the exchanges
+mod currency_aliases;
+mod exchange_info_cache;
+mod exchange_info_refresher;
 mod exchange_info_retrieval;
 mod exchange_info;
 mod market_pair_handle;
 mod market_pair;
+mod market_pair_event;
+mod market_status;
 mod utils;
 
+pub use currency_aliases::CurrencyAliases;
+pub use exchange_info_cache::ExchangeInfoCache;
+pub use exchange_info_refresher::ExchangeInfoRefresher;
 pub use exchange_info_retrieval::ExchangeInfoRetrieval;
 pub use exchange_info::ExchangeInfo;
 pub use market_pair_handle::MarketPairHandle;
 pub use market_pair::MarketPair;
+pub use market_pair_event::MarketPairEvent;
+pub use market_status::MarketStatus;
 pub use utils::*;
 pub use super::shared;
 
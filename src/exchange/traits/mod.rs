@@ -3,11 +3,16 @@
 mod exchange_account;
 mod exchange_market_data;
 mod exchange;
+mod http_config;
+mod http_transport;
 pub mod info;
+pub mod pagination;
 pub mod stream;
 
 pub use exchange_account::ExchangeAccount;
 pub use exchange_market_data::ExchangeMarketData;
 pub use exchange::Exchange;
+pub use http_config::HttpConfig;
+pub use http_transport::HttpTransport;
 pub use super::shared;
 
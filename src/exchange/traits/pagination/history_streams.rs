@@ -0,0 +1,85 @@
+use futures::stream::BoxStream;
+use crate::exchange::traits::{ExchangeAccount, ExchangeMarketData};
+use crate::model::{
+    Candle, GetHistoricRatesRequest, GetHistoricTradesRequest, GetOrderHistoryRequest, Order,
+    Trade, TradeHistoryRequest,
+};
+use super::{paginate, PaginationConfig};
+use super::shared::Result;
+
+/// This trait adds streams of the whole order and trade histories to every `ExchangeAccount`.
+/// The paginator of the request gives the first page, the following ones are requested as
+/// the stream is consumed.
+pub trait ExchangeAccountStreams: ExchangeAccount + Sync {
+    fn order_history_stream(
+        &self,
+        req: &GetOrderHistoryRequest,
+        config: PaginationConfig,
+    ) -> BoxStream<'_, Result<Order>> {
+        let req = req.clone();
+        paginate(req.paginator.clone().unwrap_or_default(), config, move |paginator| {
+            let req = GetOrderHistoryRequest {
+                paginator: Some(paginator),
+                ..req.clone()
+            };
+            async move { self.get_order_history(&req).await }
+        })
+    }
+
+    fn trade_history_stream(
+        &self,
+        req: &TradeHistoryRequest,
+        config: PaginationConfig,
+    ) -> BoxStream<'_, Result<Trade>> {
+        let market_pair = req.market_pair.clone();
+        let order_id = req.order_id.clone();
+        paginate(req.paginator.clone().unwrap_or_default(), config, move |paginator| {
+            let req = TradeHistoryRequest {
+                market_pair: market_pair.clone(),
+                order_id: order_id.clone(),
+                paginator: Some(paginator),
+            };
+            async move { self.get_trade_history(&req).await }
+        })
+    }
+}
+
+impl<T: ExchangeAccount + Sync> ExchangeAccountStreams for T {}
+
+/// This trait adds streams of the whole candle and trade histories to every
+/// `ExchangeMarketData`.
+pub trait ExchangeMarketDataStreams: ExchangeMarketData + Sync {
+    fn historic_rates_stream(
+        &self,
+        req: &GetHistoricRatesRequest,
+        config: PaginationConfig,
+    ) -> BoxStream<'_, Result<Candle>> {
+        let req = req.clone();
+        paginate(req.paginator.clone().unwrap_or_default(), config, move |paginator| {
+            let req = GetHistoricRatesRequest {
+                paginator: Some(paginator),
+                ..req.clone()
+            };
+            async move { self.get_historic_rates(&req).await }
+        })
+    }
+
+    /// The stream of the exchanges that don't serve historic trades only has their
+    /// `MissingImplementation` error
+    fn historic_trades_stream(
+        &self,
+        req: &GetHistoricTradesRequest,
+        config: PaginationConfig,
+    ) -> BoxStream<'_, Result<Trade>> {
+        let req = req.clone();
+        paginate(req.paginator.clone().unwrap_or_default(), config, move |paginator| {
+            let req = GetHistoricTradesRequest {
+                paginator: Some(paginator),
+                ..req.clone()
+            };
+            async move { self.get_historic_trades(&req).await }
+        })
+    }
+}
+
+impl<T: ExchangeMarketData + Sync> ExchangeMarketDataStreams for T {}
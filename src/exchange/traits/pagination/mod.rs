@@ -0,0 +1,47 @@
+//! This module provides streams of paginated histories that request page after page until the
+//! requested range is exhausted.
+//! # Example
+//! ```no_run
+//! use futures::stream::StreamExt;
+//! use openlimits::exchange::binance::{Binance, BinanceParameters};
+//! use openlimits::exchange::traits::pagination::{ExchangeMarketDataStreams, PageCursor, PaginationConfig};
+//! use openlimits::model::{GetHistoricRatesRequest, Interval, Paginator};
+//! use openlimits::prelude::*;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let binance = Binance::new(BinanceParameters::prod())
+//!                     .await
+//!                     .expect("Couldn't create binance client");
+//!     let req = GetHistoricRatesRequest {
+//!         market_pair: "BTCUSDT".to_string(),
+//!         interval: Interval::OneMinute,
+//!         paginator: Some(Paginator {
+//!             start_time: Some(1609459200000),
+//!             end_time: Some(1612137600000),
+//!             ..Default::default()
+//!         }),
+//!     };
+//!     let config = PaginationConfig::new(PageCursor::Time).with_page_size(1000);
+//!
+//!     let mut candles = binance.historic_rates_stream(&req, config);
+//!     while let Some(candle) = candles.next().await {
+//!         println!("{:?}", candle.expect("Couldn't get candle"));
+//!     }
+//! }
+//! ```
+
+mod history_streams;
+mod page;
+mod page_cursor;
+mod paginate;
+mod paginated;
+mod pagination_config;
+
+pub use history_streams::{ExchangeAccountStreams, ExchangeMarketDataStreams};
+pub use page::Page;
+pub use page_cursor::PageCursor;
+pub use paginate::{next_paginator, paginate, paginate_pages};
+pub use paginated::Paginated;
+pub use pagination_config::PaginationConfig;
+pub use super::shared;
@@ -0,0 +1,14 @@
+use crate::model::Paginator;
+
+/// This struct represents a page of a history and the paginator of the page after it, if any
+#[derive(Clone, Debug)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next: Option<Paginator>,
+}
+
+impl<T> Page<T> {
+    pub fn new(items: Vec<T>, next: Option<Paginator>) -> Self {
+        Self { items, next }
+    }
+}
@@ -0,0 +1,18 @@
+/// This enum represents how the paginator of the next page is derived from the items of a page.
+/// Exchanges differ: Binance reads forward from `start_time` or from the id given as `after`,
+/// Coinbase reads backward from the id given as `after`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PageCursor {
+    /// `start_time` moves to the newest item, until `end_time`
+    Time,
+    /// `after` is set to the greatest id of the page
+    NextId,
+    /// `after` is set to the smallest id of the page
+    PreviousId,
+}
+
+impl Default for PageCursor {
+    fn default() -> Self {
+        PageCursor::Time
+    }
+}
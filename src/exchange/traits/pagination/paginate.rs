@@ -0,0 +1,157 @@
+use std::collections::HashSet;
+use std::future::Future;
+use futures::stream::{self, BoxStream, StreamExt};
+use tokio::time::{sleep, sleep_until, Instant};
+use crate::model::Paginator;
+use super::{Page, PageCursor, Paginated, PaginationConfig};
+use super::shared::Result;
+
+struct State<F> {
+    fetch: F,
+    next: Option<Paginator>,
+    previous: HashSet<String>,
+    last_request: Option<Instant>,
+    pages: usize,
+}
+
+/// Streams the items of the pages returned by `fetch`, starting with `paginator` and following
+/// the paginator of every page until one has none or brings no new item. The page size of the
+/// configuration is the limit of the paginators that don't have one.
+/// A page is only requested once the items of the previous one have been consumed. Items
+/// already returned by the previous page are skipped, and the stream ends after an error.
+pub fn paginate_pages<'a, T, F, Fut>(
+    paginator: Paginator,
+    config: PaginationConfig,
+    fetch: F,
+) -> BoxStream<'a, Result<T>>
+where
+    T: Paginated + Send + 'a,
+    F: FnMut(Paginator) -> Fut + Send + 'a,
+    Fut: Future<Output = Result<Page<T>>> + Send + 'a,
+{
+    let mut paginator = paginator;
+    if paginator.limit.is_none() {
+        paginator.limit = config.page_size;
+    }
+    let state = State {
+        fetch,
+        next: Some(paginator),
+        previous: HashSet::new(),
+        last_request: None,
+        pages: 0,
+    };
+
+    stream::unfold(state, move |mut state| async move {
+        let paginator = state.next.take()?;
+        if config.max_pages.map_or(false, |max_pages| state.pages >= max_pages) {
+            return None;
+        }
+        state.pages += 1;
+
+        let mut retries = 0;
+        let page = loop {
+            if let Some(last_request) = state.last_request {
+                sleep_until(last_request + config.interval).await;
+            }
+            state.last_request = Some(Instant::now());
+            match (state.fetch)(paginator.clone()).await {
+                Ok(page) => break page,
+                Err(err) if retries < config.max_retries && err.is_retryable() => {
+                    sleep(config.backoff * 2u32.pow(retries)).await;
+                    retries += 1;
+                }
+                Err(err) => return Some((stream::iter(vec![Err(err)]), state)),
+            }
+        };
+
+        let previous = std::mem::take(&mut state.previous);
+        let mut items = Vec::new();
+        for item in page.items {
+            let key = page_key(&item);
+            if !previous.contains(&key) {
+                items.push(Ok(item));
+            }
+            state.previous.insert(key);
+        }
+        if !items.is_empty() {
+            state.next = page.next;
+        }
+
+        Some((stream::iter(items), state))
+    })
+    .flatten()
+    .boxed()
+}
+
+/// Streams a history whose pages are requested with the paginators derived from the items of
+/// the previous page, as described by the cursor of the configuration.
+pub fn paginate<'a, T, F, Fut>(
+    paginator: Paginator,
+    config: PaginationConfig,
+    mut fetch: F,
+) -> BoxStream<'a, Result<T>>
+where
+    T: Paginated + Send + 'a,
+    F: FnMut(Paginator) -> Fut + Send + 'a,
+    Fut: Future<Output = Result<Vec<T>>> + Send + 'a,
+{
+    paginate_pages(paginator, config, move |paginator: Paginator| {
+        let request = fetch(paginator.clone());
+        async move {
+            let items = request.await?;
+            let next = next_paginator(&paginator, &items, config.cursor);
+            Ok(Page::new(items, next))
+        }
+    })
+}
+
+/// Paginator of the page following `items`, none when the history is exhausted
+pub fn next_paginator<T: Paginated>(
+    paginator: &Paginator,
+    items: &[T],
+    cursor: PageCursor,
+) -> Option<Paginator> {
+    if items.is_empty() {
+        return None;
+    }
+    if let Some(limit) = paginator.limit {
+        if (items.len() as u64) < limit {
+            return None;
+        }
+    }
+
+    let mut next = paginator.clone();
+    match cursor {
+        PageCursor::Time => {
+            let first = items.iter().map(Paginated::page_time).min()?;
+            let last = items.iter().map(Paginated::page_time).max()?;
+            if paginator.end_time.map_or(false, |end_time| last >= end_time) {
+                return None;
+            }
+            // A page sharing a single time would be requested again, the items of that time
+            // beyond the page are skipped to move on
+            next.start_time = Some(if first == last { last + 1 } else { last });
+        }
+        PageCursor::NextId => next.after = Some(edge_id(items, true)?),
+        PageCursor::PreviousId => next.after = Some(edge_id(items, false)?),
+    }
+    Some(next)
+}
+
+/// Greatest or smallest id of a page, numerically when they're numbers
+fn edge_id<T: Paginated>(items: &[T], greatest: bool) -> Option<String> {
+    let ids: Vec<&str> = items.iter().filter_map(Paginated::page_id).collect();
+    let numbers: Option<Vec<u64>> = ids.iter().map(|id| id.parse::<u64>().ok()).collect();
+    match numbers {
+        Some(numbers) if greatest => numbers.into_iter().max().map(|id| id.to_string()),
+        Some(numbers) => numbers.into_iter().min().map(|id| id.to_string()),
+        None if greatest => ids.last().map(|id| id.to_string()),
+        None => ids.first().map(|id| id.to_string()),
+    }
+}
+
+fn page_key<T: Paginated>(item: &T) -> String {
+    item.page_id()
+        .map(String::from)
+        .unwrap_or_else(|| item.page_time().to_string())
+}
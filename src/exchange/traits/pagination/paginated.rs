@@ -0,0 +1,40 @@
+use crate::model::{Candle, Order, Trade};
+
+/// This trait represents the items of a paginated history
+pub trait Paginated {
+    /// Millisecond timestamp of the item
+    fn page_time(&self) -> u64;
+
+    /// Id the exchange pages with, if any
+    fn page_id(&self) -> Option<&str>;
+}
+
+impl Paginated for Candle {
+    fn page_time(&self) -> u64 {
+        self.time
+    }
+
+    fn page_id(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl Paginated for Trade {
+    fn page_time(&self) -> u64 {
+        self.created_at
+    }
+
+    fn page_id(&self) -> Option<&str> {
+        Some(&self.id)
+    }
+}
+
+impl Paginated for Order {
+    fn page_time(&self) -> u64 {
+        self.created_at.unwrap_or_default()
+    }
+
+    fn page_id(&self) -> Option<&str> {
+        Some(&self.id)
+    }
+}
@@ -0,0 +1,60 @@
+use std::time::Duration;
+use super::PageCursor;
+
+/// This struct represents how a history is paginated.
+/// Pages are requested at least `interval` apart, requests failing with a retryable error, e.g.
+/// because of the rate limits, are retried up to `max_retries` times, waiting `backoff` and
+/// then twice as long every time.
+#[derive(Clone, Copy, Debug)]
+pub struct PaginationConfig {
+    pub cursor: PageCursor,
+    pub page_size: Option<u64>,
+    pub max_pages: Option<usize>,
+    pub interval: Duration,
+    pub max_retries: u32,
+    pub backoff: Duration,
+}
+
+impl Default for PaginationConfig {
+    fn default() -> Self {
+        Self {
+            cursor: PageCursor::default(),
+            page_size: None,
+            max_pages: None,
+            interval: Duration::from_millis(0),
+            max_retries: 3,
+            backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+impl PaginationConfig {
+    pub fn new(cursor: PageCursor) -> Self {
+        Self {
+            cursor,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the `limit` of the requests that don't have one
+    pub fn with_page_size(mut self, page_size: u64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn with_max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = Some(max_pages);
+        self
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn with_retries(mut self, max_retries: u32, backoff: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.backoff = backoff;
+        self
+    }
+}
@@ -5,11 +5,11 @@
 //! use openlimits::exchange::binance::BinanceWebsocket;
 //! use openlimits::exchange::binance::BinanceParameters;
 //! 
-//! let mut binance_websocket = OpenLimitsWs {
-//!     websocket: BinanceWebsocket::new(BinanceParameters::prod())
+//! let mut binance_websocket = OpenLimitsWs::new(
+//!     BinanceWebsocket::new(BinanceParameters::prod())
 //!         .await
 //!         .expect("Failed to create Client"),
-//! };
+//! );
 //! ```
 
 use std::convert::TryFrom;
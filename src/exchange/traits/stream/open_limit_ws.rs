@@ -1,5 +1,5 @@
-use derive_more::Constructor;
 use futures::stream::BoxStream;
+use crate::exchange::traits::info::ExchangeInfo;
 use crate::model::websocket::WebSocketResponse;
 use crate::model::websocket::Subscription;
 use super::shared::Result;
@@ -7,15 +7,29 @@ use super::CallbackHandle;
 use super::ExchangeWs;
 use super::Subscriptions;
 
-#[derive(Constructor)]
+/// `exchange_info` translates the instruments of the subscriptions and of the messages of
+/// `AnyWsExchange`, e.g. the one of the `AnyExchange` of the same exchange
 pub struct OpenLimitsWs<E: ExchangeWs> {
     pub websocket: E,
+    pub exchange_info: Option<ExchangeInfo>,
 }
 
 impl<E: ExchangeWs> OpenLimitsWs<E> {
+    pub fn new(websocket: E) -> Self {
+        Self {
+            websocket,
+            exchange_info: None,
+        }
+    }
+
+    pub fn with_exchange_info(mut self, exchange_info: ExchangeInfo) -> Self {
+        self.exchange_info = Some(exchange_info);
+        self
+    }
+
     pub async fn instantiate(params: E::InitParams) -> Result<Self> {
         let websocket = E::new(params).await?;
-        Ok(Self { websocket })
+        Ok(Self::new(websocket))
     }
 
     pub async fn create_stream_specific(
@@ -0,0 +1,65 @@
+use std::time::Duration;
+use rust_decimal::prelude::Decimal;
+use crate::model::Side;
+
+/// This enum represents what happens to the size left when the schedule of an algorithm ends.
+/// `FinishAggressively` takes it from the book, within the limit price when there's one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EndOfSchedule {
+    CancelRemaining,
+    FinishAggressively,
+}
+
+impl Default for EndOfSchedule {
+    fn default() -> Self {
+        Self::CancelRemaining
+    }
+}
+
+/// This struct represents the order an execution algorithm works: no child order is sent
+/// beyond `limit_price`, and resting orders are polled every `poll_interval`. A taking order
+/// that isn't settled after `settle_timeout` is canceled.
+#[derive(Clone, Debug)]
+pub struct ExecutionConfig {
+    pub market_pair: String,
+    pub side: Side,
+    pub size: Decimal,
+    pub limit_price: Option<Decimal>,
+    pub end_of_schedule: EndOfSchedule,
+    pub poll_interval: Duration,
+    pub settle_timeout: Duration,
+}
+
+impl ExecutionConfig {
+    pub fn new(market_pair: &str, side: Side, size: Decimal) -> Self {
+        Self {
+            market_pair: market_pair.to_string(),
+            side,
+            size,
+            limit_price: None,
+            end_of_schedule: EndOfSchedule::default(),
+            poll_interval: Duration::from_secs(1),
+            settle_timeout: Duration::from_secs(30),
+        }
+    }
+
+    pub fn with_limit_price(mut self, limit_price: Decimal) -> Self {
+        self.limit_price = Some(limit_price);
+        self
+    }
+
+    pub fn with_end_of_schedule(mut self, end_of_schedule: EndOfSchedule) -> Self {
+        self.end_of_schedule = end_of_schedule;
+        self
+    }
+
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    pub fn with_settle_timeout(mut self, settle_timeout: Duration) -> Self {
+        self.settle_timeout = settle_timeout;
+        self
+    }
+}
@@ -0,0 +1,34 @@
+use rust_decimal::prelude::{Decimal, Zero};
+use crate::model::Order;
+
+/// This struct represents how much of the order of an algorithm is executed
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExecutionProgress {
+    pub size: Decimal,
+    pub filled: Decimal,
+    pub average_price: Option<Decimal>,
+    pub orders: usize,
+}
+
+impl ExecutionProgress {
+    pub fn remaining(&self) -> Decimal {
+        (self.size - self.filled).max(Decimal::zero())
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.remaining().is_zero()
+    }
+}
+
+/// This enum represents what an execution algorithm reports while it runs.
+/// `PriceLimited` carries the best price of the book when it's beyond the limit price and a
+/// slice is held back. The algorithm ends with `Finished` or `Cancelled`.
+#[derive(Clone, Debug)]
+pub enum ExecutionEvent {
+    OrderPlaced(Order),
+    OrderCanceled(String),
+    Progress(ExecutionProgress),
+    PriceLimited(Decimal),
+    Finished(ExecutionProgress),
+    Cancelled(ExecutionProgress),
+}
@@ -0,0 +1,43 @@
+use tokio::sync::mpsc::UnboundedReceiver;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use crate::errors::OpenLimitsError;
+use crate::exchange::shared::Result;
+use super::{ExecutionEvent, ExecutionProgress};
+
+/// This struct represents a running execution algorithm: its events, and the task returning
+/// its final progress.
+pub struct ExecutionHandle {
+    events: UnboundedReceiver<ExecutionEvent>,
+    cancel: watch::Sender<bool>,
+    task: JoinHandle<Result<ExecutionProgress>>,
+}
+
+impl ExecutionHandle {
+    pub fn new(
+        events: UnboundedReceiver<ExecutionEvent>,
+        cancel: watch::Sender<bool>,
+        task: JoinHandle<Result<ExecutionProgress>>,
+    ) -> Self {
+        Self {
+            events,
+            cancel,
+            task,
+        }
+    }
+
+    /// Next event, `None` once the algorithm ended and its events were read
+    pub async fn next_event(&mut self) -> Option<ExecutionEvent> {
+        self.events.recv().await
+    }
+
+    /// Stops the algorithm, its resting order is canceled and the size left isn't executed
+    pub fn cancel(&self) {
+        self.cancel.send(true).ok();
+    }
+
+    /// Waits for the algorithm to end, a task that panicked is reported as poisoned
+    pub async fn wait(self) -> Result<ExecutionProgress> {
+        self.task.await.map_err(|_| OpenLimitsError::PoisonError())?
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+use std::time::Duration;
+use rust_decimal::prelude::Decimal;
+use tokio::time::Instant;
+use crate::errors::OpenLimitsError;
+use crate::exchange::traits::info::ExchangeInfoRetrieval;
+use crate::exchange::traits::{ExchangeAccount, ExchangeMarketData};
+use super::slice_executor::is_open;
+use super::{sleep_until_cancelled, spawn, ExecutionConfig, ExecutionHandle};
+
+/// This struct represents an iceberg execution: only `display_size` rests in the book at the
+/// limit price, and a new order is placed each time the previous one is filled. The schedule
+/// ends once the order is filled or, when there's one, after `duration`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Iceberg {
+    pub display_size: Decimal,
+    pub duration: Option<Duration>,
+}
+
+impl Iceberg {
+    pub fn new(display_size: Decimal) -> Self {
+        Self {
+            display_size,
+            duration: None,
+        }
+    }
+
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// The configuration must have a limit price
+    pub fn start<E>(&self, exchange: Arc<E>, config: ExecutionConfig) -> ExecutionHandle
+    where
+        E: ExchangeAccount + ExchangeMarketData + ExchangeInfoRetrieval + Send + Sync + 'static,
+    {
+        let display_size = self.display_size;
+        let duration = self.duration;
+        spawn(exchange, config, move |mut executor, mut cancel| async move {
+            let price = executor.config.limit_price.ok_or_else(|| {
+                OpenLimitsError::MissingParameter(String::from("An iceberg needs a limit price"))
+            })?;
+            let poll_interval = executor.config.poll_interval;
+            let deadline = duration.map(|duration| Instant::now() + duration);
+
+            while !executor.remaining().is_zero() {
+                let mut order = match executor.rest(display_size, price).await? {
+                    Some(order) => order,
+                    None => break,
+                };
+                while is_open(&order) {
+                    let next_poll = Instant::now() + poll_interval;
+                    let until = deadline.map_or(next_poll, |deadline| deadline.min(next_poll));
+                    if sleep_until_cancelled(&mut cancel, until).await {
+                        executor.cancel(&order).await?;
+                        return Ok(executor.cancelled());
+                    }
+                    order = executor.poll(&order).await?;
+                    let expired = deadline.map_or(false, |deadline| Instant::now() >= deadline);
+                    if expired && is_open(&order) {
+                        executor.cancel(&order).await?;
+                        return executor.finish(&mut cancel).await;
+                    }
+                }
+            }
+            executor.finish(&mut cancel).await
+        })
+    }
+}
@@ -0,0 +1,96 @@
+//! This module provides execution algorithms working an order over time with the
+//! `ExchangeAccount` and `ExchangeMarketData` of an exchange: time-sliced `Twap`, `Vwap`
+//! following a volume profile, volume participation `Pov` driven by the trade feed, and
+//! `Iceberg`.
+//! Every algorithm runs as a task that reports its child orders and fills as events and can
+//! be cancelled. Child orders never cross the limit price of the configuration, and what's
+//! left at the end of the schedule is canceled or taken aggressively.
+//! # Example
+//! ```no_run
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//! use openlimits::exchange::binance::{Binance, BinanceParameters};
+//! use openlimits::execution::{EndOfSchedule, ExecutionConfig, Twap};
+//! use openlimits::model::Side;
+//! use openlimits::prelude::*;
+//! use rust_decimal::Decimal;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let binance = Binance::new(BinanceParameters::sandbox())
+//!                         .await
+//!                         .expect("Couldn't create binance client");
+//!     let config = ExecutionConfig::new("BNBBTC", Side::Buy, Decimal::new(10, 0))
+//!                         .with_limit_price(Decimal::new(1, 2))
+//!                         .with_end_of_schedule(EndOfSchedule::FinishAggressively);
+//!     let twap = Twap::new(Duration::from_secs(600), 10);
+//!     let mut execution = twap.start(Arc::new(binance), config);
+//!
+//!     while let Some(event) = execution.next_event().await {
+//!         println!("{:?}", event);
+//!     }
+//!     let progress = execution.wait().await.expect("Execution failed");
+//!     println!("{} filled at {:?}", progress.filled, progress.average_price);
+//! }
+//! ```
+
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::mpsc::unbounded_channel;
+use tokio::sync::watch;
+use tokio::time::{sleep_until, Instant};
+use crate::exchange::shared::Result;
+use crate::exchange::traits::info::ExchangeInfoRetrieval;
+use crate::exchange::traits::{ExchangeAccount, ExchangeMarketData};
+
+mod execution_config;
+mod execution_event;
+mod execution_handle;
+mod iceberg;
+mod pov;
+mod slice_executor;
+mod twap;
+mod vwap;
+
+pub use execution_config::{EndOfSchedule, ExecutionConfig};
+pub use execution_event::{ExecutionEvent, ExecutionProgress};
+pub use execution_handle::ExecutionHandle;
+pub use iceberg::Iceberg;
+pub use pov::Pov;
+pub use twap::Twap;
+pub use vwap::Vwap;
+use slice_executor::SliceExecutor;
+
+/// Runs an algorithm in a task
+fn spawn<E, F, Fut>(exchange: Arc<E>, config: ExecutionConfig, run: F) -> ExecutionHandle
+where
+    E: ExchangeAccount + ExchangeMarketData + ExchangeInfoRetrieval + Send + Sync + 'static,
+    F: FnOnce(SliceExecutor<E>, watch::Receiver<bool>) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<ExecutionProgress>> + Send + 'static,
+{
+    let (events, receiver) = unbounded_channel();
+    let (cancel, cancelled) = watch::channel(false);
+    let task = tokio::spawn(async move {
+        let executor = SliceExecutor::new(exchange, config, events).await?;
+        run(executor, cancelled).await
+    });
+    ExecutionHandle::new(receiver, cancel, task)
+}
+
+/// Waits until `deadline`, returns whether the algorithm was cancelled meanwhile
+async fn sleep_until_cancelled(cancel: &mut watch::Receiver<bool>, deadline: Instant) -> bool {
+    if *cancel.borrow() {
+        return true;
+    }
+    tokio::select! {
+        _ = sleep_until(deadline) => false,
+        changed = cancel.changed() => match changed {
+            Ok(()) => *cancel.borrow(),
+            // The handle was dropped, the algorithm can't be cancelled anymore
+            Err(_) => {
+                sleep_until(deadline).await;
+                false
+            }
+        },
+    }
+}
@@ -0,0 +1,84 @@
+use std::sync::Arc;
+use std::time::Duration;
+use futures::stream::{BoxStream, StreamExt};
+use rust_decimal::prelude::{Decimal, One, Zero};
+use tokio::time::{sleep_until, Instant};
+use crate::errors::OpenLimitsError;
+use crate::exchange::shared::Result;
+use crate::exchange::traits::info::ExchangeInfoRetrieval;
+use crate::exchange::traits::{ExchangeAccount, ExchangeMarketData};
+use crate::model::Trade;
+use super::{spawn, ExecutionConfig, ExecutionHandle};
+
+/// This struct represents a volume participation execution, which follows the VWAP of the
+/// market: every trade of the feed raises the executed target to `participation` of the
+/// volume traded since the start, and the difference is taken from the book. The schedule
+/// ends after `duration` or when the trade feed ends.
+/// The feed includes the trades of the algorithm, so the participation is a share of the
+/// whole volume and has to be below 1.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pov {
+    pub participation: Decimal,
+    pub duration: Duration,
+}
+
+impl Pov {
+    pub fn new(participation: Decimal, duration: Duration) -> Result<Self> {
+        if participation <= Decimal::zero() || participation >= Decimal::one() {
+            return Err(OpenLimitsError::InvalidParameter(format!(
+                "The participation must be between 0 and 1, got {}",
+                participation
+            )));
+        }
+        Ok(Self {
+            participation,
+            duration,
+        })
+    }
+
+    pub fn start<E>(
+        &self,
+        exchange: Arc<E>,
+        config: ExecutionConfig,
+        trades: BoxStream<'static, Result<Trade>>,
+    ) -> ExecutionHandle
+    where
+        E: ExchangeAccount + ExchangeMarketData + ExchangeInfoRetrieval + Send + Sync + 'static,
+    {
+        let participation = self.participation;
+        let duration = self.duration;
+        spawn(exchange, config, move |mut executor, mut cancel| async move {
+            let deadline = Instant::now() + duration;
+            let mut trades = trades;
+            let mut volume = Decimal::zero();
+            let mut cancellable = true;
+            loop {
+                tokio::select! {
+                    _ = sleep_until(deadline) => break,
+                    changed = cancel.changed(), if cancellable => match changed {
+                        Ok(()) if *cancel.borrow() => return Ok(executor.cancelled()),
+                        Ok(()) => (),
+                        // The handle was dropped, the algorithm can't be cancelled anymore
+                        Err(_) => cancellable = false,
+                    },
+                    trade = trades.next() => match trade {
+                        Some(Ok(trade)) => {
+                            volume += trade.qty;
+                            let behind = volume * participation - executor.progress().filled;
+                            if behind > Decimal::zero() {
+                                executor.take(behind, &mut cancel).await?;
+                            }
+                            // Cancelling while taking is seen by `finish`
+                            if executor.remaining().is_zero() || *cancel.borrow() {
+                                break;
+                            }
+                        }
+                        Some(Err(_)) => continue,
+                        None => break,
+                    },
+                }
+            }
+            executor.finish(&mut cancel).await
+        })
+    }
+}
@@ -0,0 +1,297 @@
+use std::sync::Arc;
+use rust_decimal::prelude::{Decimal, Zero};
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::watch;
+use tokio::time::Instant;
+use crate::exchange::shared::Result;
+use crate::exchange::traits::info::{ExchangeInfoRetrieval, MarketPair};
+use crate::exchange::traits::{ExchangeAccount, ExchangeMarketData};
+use crate::model::{
+    CancelOrderRequest, GetOrderRequest, OpenLimitOrderRequest, OpenMarketOrderRequest, Order,
+    OrderBookRequest, OrderStatus, Side, TimeInForce,
+};
+use super::{
+    sleep_until_cancelled, EndOfSchedule, ExecutionConfig, ExecutionEvent, ExecutionProgress,
+};
+
+/// This struct represents the state shared by the algorithms: it sends the child orders,
+/// keeps track of their fills and reports them.
+pub struct SliceExecutor<E> {
+    exchange: Arc<E>,
+    pub config: ExecutionConfig,
+    pair: MarketPair,
+    events: UnboundedSender<ExecutionEvent>,
+    filled: Decimal,
+    notional: Decimal,
+    orders: usize,
+}
+
+impl<E> SliceExecutor<E>
+where
+    E: ExchangeAccount + ExchangeMarketData + ExchangeInfoRetrieval + Send + Sync + 'static,
+{
+    pub async fn new(
+        exchange: Arc<E>,
+        config: ExecutionConfig,
+        events: UnboundedSender<ExecutionEvent>,
+    ) -> Result<Self> {
+        let pair = exchange.get_pair(&config.market_pair).await?.read()?;
+        Ok(Self {
+            exchange,
+            config,
+            pair,
+            events,
+            filled: Decimal::zero(),
+            notional: Decimal::zero(),
+            orders: 0,
+        })
+    }
+
+    pub fn progress(&self) -> ExecutionProgress {
+        ExecutionProgress {
+            size: self.config.size,
+            filled: self.filled,
+            average_price: match self.filled.is_zero() {
+                true => None,
+                false => Some(self.notional / self.filled),
+            },
+            orders: self.orders,
+        }
+    }
+
+    pub fn remaining(&self) -> Decimal {
+        self.progress().remaining()
+    }
+
+    pub fn emit(&self, event: ExecutionEvent) {
+        self.events.send(event).ok();
+    }
+
+    /// Size that can be sent: rounded down to the increment of the market and capped by the
+    /// size left, zero when it's below the minimum of the market
+    pub fn tradable(&self, size: Decimal) -> Decimal {
+        let size = size.min(self.remaining());
+        let size = match self.pair.base_increment.is_zero() {
+            true => size,
+            false => (size / self.pair.base_increment).floor() * self.pair.base_increment,
+        };
+        match self.pair.min_base_trade_size {
+            Some(min) if size < min => Decimal::zero(),
+            _ => size,
+        }
+    }
+
+    /// Takes `size` from the book, with an immediate-or-cancel order at the limit price or a
+    /// market order without one. Nothing is sent while the book is beyond the limit price.
+    /// The order is canceled when it isn't settled in time or the execution is cancelled.
+    pub async fn take(
+        &mut self,
+        size: Decimal,
+        cancel: &mut watch::Receiver<bool>,
+    ) -> Result<Decimal> {
+        let size = self.tradable(size);
+        if size.is_zero() {
+            return Ok(Decimal::zero());
+        }
+        let best = self.best_price().await?;
+        let order = match self.config.limit_price {
+            Some(limit) => {
+                let beyond = best.map_or(true, |best| match self.config.side {
+                    Side::Buy => best > limit,
+                    Side::Sell => best < limit,
+                });
+                if beyond {
+                    self.emit(ExecutionEvent::PriceLimited(best.unwrap_or(limit)));
+                    return Ok(Decimal::zero());
+                }
+                self.limit(size, limit, TimeInForce::ImmediateOrCancelled).await?
+            }
+            None => {
+                let req = OpenMarketOrderRequest {
+                    market_pair: self.config.market_pair.clone(),
+                    size,
+                };
+                match self.config.side {
+                    Side::Buy => self.exchange.market_buy(&req).await?,
+                    Side::Sell => self.exchange.market_sell(&req).await?,
+                }
+            }
+        };
+        self.orders += 1;
+        self.emit(ExecutionEvent::OrderPlaced(order.clone()));
+        let order = self.settle(order, cancel).await?;
+        let (qty, notional) = fill(&order, best.unwrap_or_default());
+        self.record(qty, notional);
+        Ok(qty)
+    }
+
+    /// Places a resting order at the limit price
+    pub async fn rest(&mut self, size: Decimal, price: Decimal) -> Result<Option<Order>> {
+        let size = self.tradable(size);
+        if size.is_zero() {
+            return Ok(None);
+        }
+        let order = self.limit(size, price, TimeInForce::GoodTillCancelled).await?;
+        self.orders += 1;
+        self.emit(ExecutionEvent::OrderPlaced(order.clone()));
+        Ok(Some(order))
+    }
+
+    /// Reads a resting order again and records what it filled since `previous`
+    pub async fn poll(&mut self, previous: &Order) -> Result<Order> {
+        let req = GetOrderRequest {
+            id: previous.id.clone(),
+            market_pair: Some(self.config.market_pair.clone()),
+        };
+        let order = self.exchange.get_order(&req).await?;
+        self.record_change(previous, &order);
+        Ok(order)
+    }
+
+    /// Cancels a resting order, what it filled meanwhile is recorded
+    pub async fn cancel(&mut self, order: &Order) -> Result<Order> {
+        let req = CancelOrderRequest {
+            id: order.id.clone(),
+            market_pair: Some(self.config.market_pair.clone()),
+        };
+        self.exchange.cancel_order(&req).await?;
+        self.emit(ExecutionEvent::OrderCanceled(order.id.clone()));
+        self.poll(order).await
+    }
+
+    /// Applies the end of schedule behaviour to the size left, unless the execution was
+    /// cancelled meanwhile
+    pub async fn finish(
+        &mut self,
+        cancel: &mut watch::Receiver<bool>,
+    ) -> Result<ExecutionProgress> {
+        if *cancel.borrow() {
+            return Ok(self.cancelled());
+        }
+        if self.config.end_of_schedule == EndOfSchedule::FinishAggressively {
+            let remaining = self.remaining();
+            self.take(remaining, cancel).await?;
+        }
+        let progress = self.progress();
+        self.emit(ExecutionEvent::Finished(progress.clone()));
+        Ok(progress)
+    }
+
+    pub fn cancelled(&self) -> ExecutionProgress {
+        let progress = self.progress();
+        self.emit(ExecutionEvent::Cancelled(progress.clone()));
+        progress
+    }
+
+    async fn limit(
+        &self,
+        size: Decimal,
+        price: Decimal,
+        time_in_force: TimeInForce,
+    ) -> Result<Order> {
+        let req = OpenLimitOrderRequest {
+            market_pair: self.config.market_pair.clone(),
+            size,
+            price,
+            time_in_force,
+            post_only: false,
+        };
+        match self.config.side {
+            Side::Buy => self.exchange.limit_buy(&req).await,
+            Side::Sell => self.exchange.limit_sell(&req).await,
+        }
+    }
+
+    /// Reads a taking order again every poll interval until it's in a final status, some
+    /// venues return it before it's matched. It's canceled when the settle timeout is reached
+    /// or the execution is cancelled.
+    async fn settle(
+        &self,
+        mut order: Order,
+        cancel: &mut watch::Receiver<bool>,
+    ) -> Result<Order> {
+        let deadline = Instant::now() + self.config.settle_timeout;
+        let req = GetOrderRequest {
+            id: order.id.clone(),
+            market_pair: Some(self.config.market_pair.clone()),
+        };
+        while !order.status.is_final() {
+            let next_poll = (Instant::now() + self.config.poll_interval).min(deadline);
+            let cancelled = sleep_until_cancelled(cancel, next_poll).await;
+            if !cancelled {
+                order = self.exchange.get_order(&req).await?;
+                if order.status.is_final() || Instant::now() < deadline {
+                    continue;
+                }
+            }
+            let cancel_req = CancelOrderRequest {
+                id: order.id.clone(),
+                market_pair: Some(self.config.market_pair.clone()),
+            };
+            // The order can settle before it's canceled, what it filled is read below
+            if self.exchange.cancel_order(&cancel_req).await.is_ok() {
+                self.emit(ExecutionEvent::OrderCanceled(order.id.clone()));
+            }
+            return self.exchange.get_order(&req).await;
+        }
+        Ok(order)
+    }
+
+    async fn best_price(&self) -> Result<Option<Decimal>> {
+        let req = OrderBookRequest {
+            market_pair: self.config.market_pair.clone(),
+        };
+        let book = self.exchange.order_book(&req).await?;
+        let best = match self.config.side {
+            Side::Buy => book.asks.iter().map(|ask| ask.price).min(),
+            Side::Sell => book.bids.iter().map(|bid| bid.price).max(),
+        };
+        Ok(best)
+    }
+
+    fn record_change(&mut self, previous: &Order, order: &Order) {
+        let price = order.price.unwrap_or_default();
+        let (qty, notional) = fill(order, price);
+        let (previous_qty, previous_notional) = fill(previous, price);
+        self.record(qty - previous_qty, notional - previous_notional);
+    }
+
+    fn record(&mut self, qty: Decimal, notional: Decimal) {
+        if qty <= Decimal::zero() {
+            return;
+        }
+        self.filled += qty;
+        self.notional += notional;
+        self.emit(ExecutionEvent::Progress(self.progress()));
+    }
+}
+
+/// Whether a resting order can still be filled
+pub fn is_open(order: &Order) -> bool {
+    matches!(
+        order.status,
+        OrderStatus::New
+            | OrderStatus::PartiallyFilled
+            | OrderStatus::Open
+            | OrderStatus::Pending
+            | OrderStatus::Active
+    )
+}
+
+/// Size and quote amount an order executed, from its trades when the venue returns them
+fn fill(order: &Order, price: Decimal) -> (Decimal, Decimal) {
+    if !order.trades.is_empty() {
+        return order
+            .trades
+            .iter()
+            .fold((Decimal::zero(), Decimal::zero()), |(qty, notional), trade| {
+                (qty + trade.qty, notional + trade.qty * trade.price)
+            });
+    }
+    let qty = match (&order.status, order.remaining) {
+        (OrderStatus::Filled, _) => order.size,
+        (_, Some(remaining)) => order.size - remaining,
+        (_, None) => Decimal::zero(),
+    };
+    (qty, qty * order.price.unwrap_or(price))
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+use std::time::Duration;
+use rust_decimal::prelude::Decimal;
+use tokio::time::Instant;
+use crate::exchange::traits::info::ExchangeInfoRetrieval;
+use crate::exchange::traits::{ExchangeAccount, ExchangeMarketData};
+use super::{sleep_until_cancelled, spawn, ExecutionConfig, ExecutionHandle};
+
+/// This struct represents a time-weighted execution: the order is split in `slices` taken
+/// at regular intervals over `duration`. What a slice doesn't fill is added to the next ones.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Twap {
+    pub duration: Duration,
+    pub slices: u32,
+}
+
+impl Twap {
+    pub fn new(duration: Duration, slices: u32) -> Self {
+        Self { duration, slices }
+    }
+
+    pub fn start<E>(&self, exchange: Arc<E>, config: ExecutionConfig) -> ExecutionHandle
+    where
+        E: ExchangeAccount + ExchangeMarketData + ExchangeInfoRetrieval + Send + Sync + 'static,
+    {
+        let slices = self.slices.max(1);
+        let interval = self.duration / slices;
+        spawn(exchange, config, move |mut executor, mut cancel| async move {
+            let start = Instant::now();
+            for slice in 0..slices {
+                if sleep_until_cancelled(&mut cancel, start + interval * slice).await {
+                    return Ok(executor.cancelled());
+                }
+                if executor.remaining().is_zero() {
+                    break;
+                }
+                let size = executor.remaining() / Decimal::from(slices - slice);
+                executor.take(size, &mut cancel).await?;
+            }
+            executor.finish(&mut cancel).await
+        })
+    }
+}
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+use std::time::Duration;
+use rust_decimal::prelude::{Decimal, Zero};
+use tokio::time::Instant;
+use crate::errors::OpenLimitsError;
+use crate::exchange::shared::Result;
+use crate::exchange::traits::info::ExchangeInfoRetrieval;
+use crate::exchange::traits::{ExchangeAccount, ExchangeMarketData};
+use crate::model::Candle;
+use super::{sleep_until_cancelled, spawn, ExecutionConfig, ExecutionHandle};
+
+/// This struct represents a volume-weighted execution: `duration` is split in as many slices
+/// as the volume `profile` has buckets, and every slice is sized after the share of the
+/// volume expected in its bucket. What a slice doesn't fill is spread over the next ones.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Vwap {
+    pub duration: Duration,
+    pub profile: Vec<Decimal>,
+}
+
+impl Vwap {
+    /// `profile` holds the volume expected in each bucket, it needs some positive volume
+    pub fn new(duration: Duration, profile: Vec<Decimal>) -> Result<Self> {
+        if profile.iter().any(|volume| volume.is_sign_negative()) {
+            return Err(OpenLimitsError::InvalidParameter(String::from(
+                "A volume profile can't have negative volumes",
+            )));
+        }
+        if profile.iter().all(|volume| volume.is_zero()) {
+            return Err(OpenLimitsError::InvalidParameter(String::from(
+                "A volume profile needs some volume",
+            )));
+        }
+        Ok(Self { duration, profile })
+    }
+
+    /// Profile of the volumes of historic candles, usually the same period of previous days
+    pub fn from_candles(duration: Duration, candles: &[Candle]) -> Result<Self> {
+        Self::new(duration, candles.iter().map(|candle| candle.volume).collect())
+    }
+
+    pub fn start<E>(&self, exchange: Arc<E>, config: ExecutionConfig) -> ExecutionHandle
+    where
+        E: ExchangeAccount + ExchangeMarketData + ExchangeInfoRetrieval + Send + Sync + 'static,
+    {
+        let profile = self.profile.clone();
+        let interval = self.duration / profile.len().max(1) as u32;
+        spawn(exchange, config, move |mut executor, mut cancel| async move {
+            let start = Instant::now();
+            for (slice, volume) in profile.iter().enumerate() {
+                if sleep_until_cancelled(&mut cancel, start + interval * slice as u32).await {
+                    return Ok(executor.cancelled());
+                }
+                if executor.remaining().is_zero() {
+                    break;
+                }
+                let expected: Decimal = profile[slice..].iter().sum();
+                let size = match expected.is_zero() {
+                    true => executor.remaining(),
+                    false => executor.remaining() * *volume / expected,
+                };
+                executor.take(size, &mut cancel).await?;
+            }
+            executor.finish(&mut cancel).await
+        })
+    }
+}
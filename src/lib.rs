@@ -44,7 +44,15 @@ use crate::exchange::shared::Result;
 pub mod exchange;
 pub mod model;
 pub mod prelude;
+pub mod aggregator;
+pub mod backtest;
+pub mod candles;
+pub mod recorder;
+pub mod router;
 pub mod errors;
+pub mod execution;
+pub mod order_manager;
+pub mod validation;
 
 /// Can be used to initiate exchanges
 pub struct OpenLimits {}
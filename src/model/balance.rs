@@ -3,10 +3,42 @@ use rust_decimal::prelude::Decimal;
 use serde::Deserialize;
 use serde::Serialize;
 
-/// This struct represents the account balance
-#[derive(Serialize, Deserialize, Clone, Constructor, Debug, PartialEq)]
+/// This struct represents the account balance.
+/// `total` is what the account owns and `free` what can be used for new orders, `locked` being
+/// in orders or otherwise on hold. The amounts an exchange doesn't report are zero, and
+/// `wallet` identifies the wallet or account holding the balance when the exchange has several.
+#[derive(Serialize, Deserialize, Clone, Constructor, Debug, Default, PartialEq)]
 pub struct Balance {
     pub asset: String,
     pub total: Decimal,
     pub free: Decimal,
-}
\ No newline at end of file
+    #[serde(default)]
+    pub locked: Decimal,
+    #[serde(default)]
+    pub pending_deposit: Decimal,
+    #[serde(default)]
+    pub pending_withdrawal: Decimal,
+    #[serde(default)]
+    pub borrowed: Decimal,
+    #[serde(default)]
+    pub wallet: Option<String>,
+}
+
+impl Balance {
+    /// Sum of two balances of the same asset, the wallet is kept only when it's the same
+    pub fn merge(self, other: Balance) -> Self {
+        Self {
+            total: self.total + other.total,
+            free: self.free + other.free,
+            locked: self.locked + other.locked,
+            pending_deposit: self.pending_deposit + other.pending_deposit,
+            pending_withdrawal: self.pending_withdrawal + other.pending_withdrawal,
+            borrowed: self.borrowed + other.borrowed,
+            wallet: match self.wallet == other.wallet {
+                true => self.wallet,
+                false => None,
+            },
+            asset: self.asset,
+        }
+    }
+}
@@ -2,10 +2,17 @@ use derive_more::Constructor;
 use rust_decimal::prelude::Decimal;
 use serde::Deserialize;
 use serde::Serialize;
+use super::Instrument;
 
 /// This struct represents a candle
 #[derive(Serialize, Deserialize, Clone, Constructor, Debug, PartialEq)]
 pub struct Candle {
+    /// The symbol of the exchange, set when the venue reports it or by `ExchangeInfo::translate`
+    #[serde(default)]
+    pub market_pair: Option<String>,
+    /// Set by the exchanges translating symbols, e.g. `AnyExchange`
+    #[serde(default)]
+    pub instrument: Option<Instrument>,
     pub time: u64,
     pub low: Decimal,
     pub high: Decimal,
@@ -0,0 +1,40 @@
+use std::fmt;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents a market independently of the exchange, e.g. `BTC/USDT`.
+/// Currencies are upper case, the exchange symbols are translated from and to instruments by
+/// the `ExchangeInfo` of every exchange.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Instrument {
+    pub base: String,
+    pub quote: String,
+}
+
+impl Instrument {
+    pub const SEPARATOR: char = '/';
+
+    pub fn new(base: &str, quote: &str) -> Self {
+        Self {
+            base: base.to_uppercase(),
+            quote: quote.to_uppercase(),
+        }
+    }
+
+    /// Parses the canonical form, `BASE/QUOTE`. Exchange symbols never contain the separator.
+    pub fn parse(market_pair: &str) -> Option<Self> {
+        let mut currencies = market_pair.split(Self::SEPARATOR);
+        match (currencies.next(), currencies.next(), currencies.next()) {
+            (Some(base), Some(quote), None) if !base.is_empty() && !quote.is_empty() => {
+                Some(Self::new(base.trim(), quote.trim()))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Instrument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.base, Self::SEPARATOR, self.quote)
+    }
+}
@@ -4,6 +4,7 @@ mod request;
 mod ask_bid;
 mod balance;
 mod candle;
+mod instrument;
 mod interval;
 mod liquidity;
 mod order_canceled;
@@ -17,11 +18,13 @@ mod time_in_force_visitor;
 mod time_in_force;
 mod trade;
 mod transaction;
+mod translate;
 
 pub use request::*;
 pub use ask_bid::AskBid;
 pub use balance::Balance;
 pub use candle::Candle;
+pub use instrument::Instrument;
 pub use interval::Interval;
 pub use liquidity::Liquidity;
 pub use order_canceled::OrderCanceled;
@@ -35,6 +38,7 @@ pub use time_in_force_visitor::TimeInForceVisitor;
 pub use time_in_force::TimeInForce;
 pub use trade::Trade;
 pub use transaction::Transaction;
+pub use translate::{MarketResponse, Translate};
 
 #[cfg(feature = "python")]
 pub mod python;
@@ -2,6 +2,7 @@ use derive_more::Constructor;
 use rust_decimal::prelude::Decimal;
 use serde::Deserialize;
 use serde::Serialize;
+use super::Instrument;
 use super::OrderStatus;
 use super::OrderType;
 use super::Side;
@@ -12,6 +13,9 @@ use super::Trade;
 pub struct Order {
     pub id: String,
     pub market_pair: String,
+    /// Set by the exchanges translating symbols, e.g. `AnyExchange`
+    #[serde(default)]
+    pub instrument: Option<Instrument>,
     pub client_order_id: Option<String>,
     pub created_at: Option<u64>,
     pub order_type: OrderType,
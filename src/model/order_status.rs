@@ -15,4 +15,35 @@ pub enum OrderStatus {
     Open,
     Pending,
     Active,
-}
\ No newline at end of file
+}
+
+impl OrderStatus {
+    /// Whether the order can't change anymore
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            OrderStatus::Filled
+                | OrderStatus::Canceled
+                | OrderStatus::Rejected
+                | OrderStatus::Expired
+        )
+    }
+
+    /// Whether an order can go from this status to `next`. A final status never changes, an
+    /// order that was accepted or filled can't be new or pending again, and a partially filled
+    /// order can't go back to open, e.g. from a snapshot taken before its fill.
+    pub fn can_transition_to(&self, next: &OrderStatus) -> bool {
+        if self == next {
+            return true;
+        }
+        match self {
+            status if status.is_final() => false,
+            OrderStatus::New | OrderStatus::Pending => true,
+            OrderStatus::PartiallyFilled => !matches!(
+                next,
+                OrderStatus::New | OrderStatus::Pending | OrderStatus::Open | OrderStatus::Active
+            ),
+            _ => !matches!(next, OrderStatus::New | OrderStatus::Pending),
+        }
+    }
+}
@@ -1,12 +1,20 @@
 use super::websocket::{OpenLimitsWebSocketMessage, Subscription};
 use crate::exchange::any::InitAnyExchange;
-use crate::binance::{BinanceCredentials, BinanceParameters};
+use crate::binance::{BinanceCredentials, BinanceEnvironment, BinanceParameters};
+use crate::bitfinex::{BitfinexCredentials, BitfinexParameters};
 use crate::coinbase::{CoinbaseCredentials, CoinbaseParameters};
+use crate::gemini::{GeminiCredentials, GeminiParameters};
+use crate::kucoin::{KucoinCredentials, KucoinParameters};
 use crate::model::{Interval, Paginator, TimeInForce};
 use crate::nash::{Environment, NashCredentials, NashParameters};
+use crate::okx::{model::InstrumentType, OkxCredentials, OkxParameters};
+use crate::paper::PaperParameters;
 use pyo3::exceptions::PyException;
 use pyo3::prelude::{FromPyObject, IntoPy, PyObject, PyResult, Python, ToPyObject};
 use pyo3::types::PyDict;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::str::FromStr;
 use std::time::Duration;
 
 // Python to Rust...
@@ -38,10 +46,30 @@ impl<'a> FromPyObject<'a> for InitAnyExchange {
         if let Ok(binance) = maybe_binance {
             return Ok(InitAnyExchange::Binance(binance));
         }
+        let maybe_bitfinex: PyResult<BitfinexParameters> = ob.extract();
+        if let Ok(bitfinex) = maybe_bitfinex {
+            return Ok(InitAnyExchange::Bitfinex(bitfinex));
+        }
         let maybe_coinbase: PyResult<CoinbaseParameters> = ob.extract();
         if let Ok(coinbase) = maybe_coinbase {
             return Ok(InitAnyExchange::Coinbase(coinbase));
         }
+        let maybe_kucoin: PyResult<KucoinParameters> = ob.extract();
+        if let Ok(kucoin) = maybe_kucoin {
+            return Ok(InitAnyExchange::Kucoin(kucoin));
+        }
+        let maybe_okx: PyResult<OkxParameters> = ob.extract();
+        if let Ok(okx) = maybe_okx {
+            return Ok(InitAnyExchange::Okx(okx));
+        }
+        let maybe_gemini: PyResult<GeminiParameters> = ob.extract();
+        if let Ok(gemini) = maybe_gemini {
+            return Ok(InitAnyExchange::Gemini(gemini));
+        }
+        let maybe_paper: PyResult<PaperParameters<InitAnyExchange>> = ob.extract();
+        if let Ok(paper) = maybe_paper {
+            return Ok(InitAnyExchange::Paper(Box::new(paper)));
+        }
         Err(PyException::new_err(
             "invalid exchange initialization params",
         ))
@@ -79,15 +107,88 @@ impl<'a> FromPyObject<'a> for BinanceParameters {
                 "credentials not included in binance params",
             ))?
             .extract()?;
-        let sandbox: bool = py_dict
-            .get_item("sandbox")
+        let environment: Option<String> = match py_dict.get_item("environment") {
+            Some(environment) => environment.extract()?,
+            None => None,
+        };
+        let environment = match environment.as_deref() {
+            Some("global") => BinanceEnvironment::Global,
+            Some("us") => BinanceEnvironment::Us,
+            Some("testnet") => BinanceEnvironment::Testnet,
+            Some("custom") => {
+                let rest_url: String = py_dict
+                    .get_item("rest_url")
+                    .ok_or(PyException::new_err(
+                        "rest_url not included in binance params",
+                    ))?
+                    .extract()?;
+                let ws_url: String = py_dict
+                    .get_item("ws_url")
+                    .ok_or(PyException::new_err(
+                        "ws_url not included in binance params",
+                    ))?
+                    .extract()?;
+                BinanceEnvironment::Custom { rest_url, ws_url }
+            }
+            Some(_) => return Err(PyException::new_err("Invalid binance environment")),
+            // Parameters without environment choose between the global exchange and the testnet
+            None => {
+                let sandbox: bool = py_dict
+                    .get_item("sandbox")
+                    .ok_or(PyException::new_err(
+                        "sandbox not included in binance params",
+                    ))?
+                    .extract()?;
+                match sandbox {
+                    true => BinanceEnvironment::Testnet,
+                    false => BinanceEnvironment::Global,
+                }
+            }
+        };
+        Ok(BinanceParameters {
+            environment,
+            credentials,
+            http: Default::default(),
+            validation: Default::default(),
+            exchange_info_cache: None,
+        })
+    }
+}
+
+impl<'a> FromPyObject<'a> for BitfinexCredentials {
+    fn extract(ob: &'a pyo3::PyAny) -> PyResult<Self> {
+        let py_dict = ob.get_item("bitfinex_credentials")?.downcast::<PyDict>()?;
+        let api_key: String = py_dict
+            .get_item("api_key")
             .ok_or(PyException::new_err(
-                "session not included in binance credentials",
+                "api_key not included in bitfinex credentials",
             ))?
             .extract()?;
-        Ok(BinanceParameters {
-            sandbox,
+        let api_secret: String = py_dict
+            .get_item("api_secret")
+            .ok_or(PyException::new_err(
+                "api_secret not included in bitfinex credentials",
+            ))?
+            .extract()?;
+        Ok(BitfinexCredentials {
+            api_key,
+            api_secret,
+        })
+    }
+}
+
+impl<'a> FromPyObject<'a> for BitfinexParameters {
+    fn extract(ob: &'a pyo3::PyAny) -> PyResult<Self> {
+        let py_dict = ob.get_item("bitfinex")?.downcast::<PyDict>()?;
+        let credentials: Option<BitfinexCredentials> = py_dict
+            .get_item("credentials")
+            .ok_or(PyException::new_err(
+                "credentials not included in bitfinex params",
+            ))?
+            .extract()?;
+        Ok(BitfinexParameters {
             credentials,
+            exchange_info_cache: None,
         })
     }
 }
@@ -139,10 +240,213 @@ impl<'a> FromPyObject<'a> for CoinbaseParameters {
         Ok(CoinbaseParameters {
             sandbox,
             credentials,
+            http: Default::default(),
+            exchange_info_cache: None,
+        })
+    }
+}
+
+impl<'a> FromPyObject<'a> for KucoinCredentials {
+    fn extract(ob: &'a pyo3::PyAny) -> PyResult<Self> {
+        let py_dict = ob.get_item("kucoin_credentials")?.downcast::<PyDict>()?;
+        let api_key: String = py_dict
+            .get_item("api_key")
+            .ok_or(PyException::new_err(
+                "api_key not included in kucoin credentials",
+            ))?
+            .extract()?;
+        let api_secret: String = py_dict
+            .get_item("api_secret")
+            .ok_or(PyException::new_err(
+                "api_secret not included in kucoin credentials",
+            ))?
+            .extract()?;
+        let passphrase: String = py_dict
+            .get_item("passphrase")
+            .ok_or(PyException::new_err(
+                "passphrase not included in kucoin credentials",
+            ))?
+            .extract()?;
+        Ok(KucoinCredentials {
+            api_key,
+            api_secret,
+            passphrase,
+        })
+    }
+}
+
+impl<'a> FromPyObject<'a> for KucoinParameters {
+    fn extract(ob: &'a pyo3::PyAny) -> PyResult<Self> {
+        let py_dict = ob.get_item("kucoin")?.downcast::<PyDict>()?;
+        let credentials: Option<KucoinCredentials> = py_dict
+            .get_item("credentials")
+            .ok_or(PyException::new_err(
+                "credentials not included in kucoin params",
+            ))?
+            .extract()?;
+        let sandbox: bool = py_dict
+            .get_item("sandbox")
+            .ok_or(PyException::new_err(
+                "sandbox not included in kucoin params",
+            ))?
+            .extract()?;
+        Ok(KucoinParameters {
+            sandbox,
+            credentials,
+            exchange_info_cache: None,
         })
     }
 }
 
+impl<'a> FromPyObject<'a> for OkxCredentials {
+    fn extract(ob: &'a pyo3::PyAny) -> PyResult<Self> {
+        let py_dict = ob.get_item("okx_credentials")?.downcast::<PyDict>()?;
+        let api_key: String = py_dict
+            .get_item("api_key")
+            .ok_or(PyException::new_err(
+                "api_key not included in okx credentials",
+            ))?
+            .extract()?;
+        let api_secret: String = py_dict
+            .get_item("api_secret")
+            .ok_or(PyException::new_err(
+                "api_secret not included in okx credentials",
+            ))?
+            .extract()?;
+        let passphrase: String = py_dict
+            .get_item("passphrase")
+            .ok_or(PyException::new_err(
+                "passphrase not included in okx credentials",
+            ))?
+            .extract()?;
+        Ok(OkxCredentials {
+            api_key,
+            api_secret,
+            passphrase,
+        })
+    }
+}
+
+impl<'a> FromPyObject<'a> for OkxParameters {
+    fn extract(ob: &'a pyo3::PyAny) -> PyResult<Self> {
+        let py_dict = ob.get_item("okx")?.downcast::<PyDict>()?;
+        let credentials: Option<OkxCredentials> = py_dict
+            .get_item("credentials")
+            .ok_or(PyException::new_err(
+                "credentials not included in okx params",
+            ))?
+            .extract()?;
+        let demo: bool = py_dict
+            .get_item("demo")
+            .ok_or(PyException::new_err(
+                "demo not included in okx params",
+            ))?
+            .extract()?;
+        let instrument_type: Option<String> = match py_dict.get_item("instrument_type") {
+            Some(instrument_type) => instrument_type.extract()?,
+            None => None,
+        };
+        let instrument_type = match instrument_type.as_deref() {
+            None | Some("spot") => InstrumentType::Spot,
+            Some("margin") => InstrumentType::Margin,
+            Some("swap") => InstrumentType::Swap,
+            Some("futures") => InstrumentType::Futures,
+            Some("option") => InstrumentType::Option,
+            _ => return Err(PyException::new_err("Invalid instrument type")),
+        };
+        Ok(OkxParameters {
+            demo,
+            instrument_type,
+            credentials,
+            exchange_info_cache: None,
+        })
+    }
+}
+
+impl<'a> FromPyObject<'a> for GeminiCredentials {
+    fn extract(ob: &'a pyo3::PyAny) -> PyResult<Self> {
+        let py_dict = ob.get_item("gemini_credentials")?.downcast::<PyDict>()?;
+        let api_key: String = py_dict
+            .get_item("api_key")
+            .ok_or(PyException::new_err(
+                "api_key not included in gemini credentials",
+            ))?
+            .extract()?;
+        let api_secret: String = py_dict
+            .get_item("api_secret")
+            .ok_or(PyException::new_err(
+                "api_secret not included in gemini credentials",
+            ))?
+            .extract()?;
+        Ok(GeminiCredentials {
+            api_key,
+            api_secret,
+        })
+    }
+}
+
+impl<'a> FromPyObject<'a> for GeminiParameters {
+    fn extract(ob: &'a pyo3::PyAny) -> PyResult<Self> {
+        let py_dict = ob.get_item("gemini")?.downcast::<PyDict>()?;
+        let credentials: Option<GeminiCredentials> = py_dict
+            .get_item("credentials")
+            .ok_or(PyException::new_err(
+                "credentials not included in gemini params",
+            ))?
+            .extract()?;
+        let sandbox: bool = py_dict
+            .get_item("sandbox")
+            .ok_or(PyException::new_err(
+                "sandbox not included in gemini params",
+            ))?
+            .extract()?;
+        Ok(GeminiParameters {
+            sandbox,
+            credentials,
+            exchange_info_cache: None,
+        })
+    }
+}
+
+impl<'a> FromPyObject<'a> for PaperParameters<InitAnyExchange> {
+    fn extract(ob: &'a pyo3::PyAny) -> PyResult<Self> {
+        let py_dict = ob.get_item("paper")?.downcast::<PyDict>()?;
+        let market: InitAnyExchange = py_dict
+            .get_item("exchange")
+            .ok_or(PyException::new_err(
+                "exchange not included in paper params",
+            ))?
+            .extract()?;
+        let balances: HashMap<String, String> = py_dict
+            .get_item("balances")
+            .map(|balances| balances.extract())
+            .transpose()?
+            .unwrap_or_default();
+        let balances = balances
+            .into_iter()
+            .map(|(asset, amount)| Ok((asset, parse_decimal(&amount)?)))
+            .collect::<PyResult<HashMap<String, Decimal>>>()?;
+        let maker_fee = match py_dict.get_item("maker_fee") {
+            Some(fee) => parse_decimal(&fee.extract::<String>()?)?,
+            None => Decimal::default(),
+        };
+        let taker_fee = match py_dict.get_item("taker_fee") {
+            Some(fee) => parse_decimal(&fee.extract::<String>()?)?,
+            None => Decimal::default(),
+        };
+        Ok(PaperParameters {
+            market,
+            balances,
+            maker_fee,
+            taker_fee,
+        })
+    }
+}
+
+fn parse_decimal(value: &str) -> PyResult<Decimal> {
+    Decimal::from_str(value).map_err(|err| PyException::new_err(err.to_string()))
+}
+
 impl<'a> FromPyObject<'a> for NashCredentials {
     fn extract(ob: &'a pyo3::PyAny) -> PyResult<Self> {
         let py_dict = ob.get_item("nash_credentials")?.downcast::<PyDict>()?;
@@ -215,6 +519,7 @@ impl<'a> FromPyObject<'a> for NashParameters {
             environment,
             timeout,
             sign_states_loop_interval,
+            exchange_info_cache: None,
         })
     }
 }
@@ -304,6 +609,7 @@ impl ToPyObject for OpenLimitsWebSocketMessage {
             OpenLimitsWebSocketMessage::OrderBook(resp) => resp.to_object(py),
             OpenLimitsWebSocketMessage::OrderBookDiff(resp) => resp.to_object(py),
             OpenLimitsWebSocketMessage::Trades(resp) => resp.to_object(py),
+            OpenLimitsWebSocketMessage::Orders(resp) => resp.to_object(py),
         }
     }
 }
@@ -348,6 +654,12 @@ impl ToPyObject for Ticker {
     fn to_object(&self, py: Python) -> PyObject {
         let dict = PyDict::new(py);
         let inner_dict = PyDict::new(py);
+        inner_dict
+            .set_item("market_pair", self.market_pair.clone())
+            .expect("Couldn't set market_pair.");
+        inner_dict
+            .set_item("instrument", self.instrument.as_ref().map(|x| x.to_string()))
+            .expect("Couldn't set instrument.");
         // TODO: why does ticker have so few fields?
         inner_dict
             .set_item(
@@ -371,6 +683,12 @@ impl ToPyObject for Candle {
     fn to_object(&self, py: Python) -> PyObject {
         let dict = PyDict::new(py);
         let inner_dict = PyDict::new(py);
+        inner_dict
+            .set_item("market_pair", self.market_pair.clone())
+            .expect("Couldn't set market_pair.");
+        inner_dict
+            .set_item("instrument", self.instrument.as_ref().map(|x| x.to_string()))
+            .expect("Couldn't set instrument.");
         inner_dict
             .set_item("low", self.low.to_string())
             .expect("Couldn't set low.");
@@ -445,6 +763,21 @@ impl ToPyObject for Balance {
         inner_dict
             .set_item("total", self.total.to_string())
             .expect("Couldn't set total.");
+        inner_dict
+            .set_item("locked", self.locked.to_string())
+            .expect("Couldn't set locked.");
+        inner_dict
+            .set_item("pending_deposit", self.pending_deposit.to_string())
+            .expect("Couldn't set pending_deposit.");
+        inner_dict
+            .set_item("pending_withdrawal", self.pending_withdrawal.to_string())
+            .expect("Couldn't set pending_withdrawal.");
+        inner_dict
+            .set_item("borrowed", self.borrowed.to_string())
+            .expect("Couldn't set borrowed.");
+        inner_dict
+            .set_item("wallet", self.wallet.clone())
+            .expect("Couldn't set wallet.");
         dict.set_item("balance", inner_dict)
             .expect("Couldn't set balance.");
         dict.into()
@@ -482,6 +815,9 @@ impl ToPyObject for Order {
         inner_dict
             .set_item("market_pair", self.market_pair.clone())
             .expect("Couldn't set market_pair.");
+        inner_dict
+            .set_item("instrument", self.instrument.as_ref().map(|x| x.to_string()))
+            .expect("Couldn't set instrument.");
         inner_dict
             .set_item("price", self.price.map(|x| x.to_string()))
             .expect("Couldn't set price.");
@@ -522,6 +858,12 @@ impl ToPyObject for OrderBookResponse {
     fn to_object(&self, py: Python) -> PyObject {
         let dict = PyDict::new(py);
         let inner_dict = PyDict::new(py);
+        inner_dict
+            .set_item("market_pair", self.market_pair.clone())
+            .expect("Couldn't set market_pair.");
+        inner_dict
+            .set_item("instrument", self.instrument.as_ref().map(|x| x.to_string()))
+            .expect("Couldn't set instrument.");
         inner_dict
             .set_item("asks", self.asks.clone())
             .expect("Couldn't set asks.");
@@ -566,6 +908,9 @@ impl ToPyObject for Trade {
         inner_dict
             .set_item("market_pair", self.market_pair.clone())
             .expect("Couldn't set maret_pair.");
+        inner_dict
+            .set_item("instrument", self.instrument.as_ref().map(|x| x.to_string()))
+            .expect("Couldn't set instrument.");
         inner_dict
             .set_item("price", self.price.to_string())
             .expect("Couldn't set price.");
@@ -2,10 +2,17 @@ use derive_more::Constructor;
 use serde::Deserialize;
 use serde::Serialize;
 use crate::model::AskBid;
+use crate::model::Instrument;
 
 /// This struct represents an order book response
 #[derive(Serialize, Deserialize, Clone, Constructor, Debug, Default, PartialEq)]
 pub struct OrderBookResponse {
+    /// The symbol of the exchange, set when the venue reports it or by `ExchangeInfo::translate`
+    #[serde(default)]
+    pub market_pair: Option<String>,
+    /// Set by the exchanges translating symbols, e.g. `AnyExchange`
+    #[serde(default)]
+    pub instrument: Option<Instrument>,
     pub update_id: Option<u64>,
     pub last_update_id: Option<u64>,
     pub bids: Vec<AskBid>,
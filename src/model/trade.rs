@@ -2,6 +2,7 @@ use derive_more::Constructor;
 use rust_decimal::prelude::Decimal;
 use serde::Deserialize;
 use serde::Serialize;
+use super::Instrument;
 use super::Liquidity;
 use super::Side;
 
@@ -12,6 +13,9 @@ pub struct Trade {
     pub buyer_order_id: Option<String>,
     pub seller_order_id: Option<String>,
     pub market_pair: String,
+    /// Set by the exchanges translating symbols, e.g. `AnyExchange`
+    #[serde(default)]
+    pub instrument: Option<Instrument>,
     pub price: Decimal,
     pub qty: Decimal,
     pub fees: Option<Decimal>,
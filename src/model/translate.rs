@@ -0,0 +1,123 @@
+use super::websocket::OpenLimitsWebSocketMessage;
+use super::Candle;
+use super::Instrument;
+use super::Order;
+use super::OrderBookResponse;
+use super::Ticker;
+use super::Trade;
+
+/// This trait sets the instruments of the responses of an exchange from their symbols, see
+/// `ExchangeInfo::translate`
+pub trait Translate {
+    /// `instrument` gives the instrument of a symbol of the exchange. Responses whose symbol
+    /// is unknown keep their instrument.
+    fn translate(self, instrument: &dyn Fn(&str) -> Option<Instrument>) -> Self;
+}
+
+/// This trait is implemented by the market data responses, which the venues don't always name
+/// the market of
+pub trait MarketResponse {
+    /// Sets the symbol of the market, unless the venue already did
+    fn with_market_pair(self, market_pair: &str) -> Self;
+}
+
+impl Translate for Order {
+    fn translate(self, instrument: &dyn Fn(&str) -> Option<Instrument>) -> Self {
+        Self {
+            instrument: instrument(&self.market_pair).or(self.instrument),
+            trades: self.trades.translate(instrument),
+            ..self
+        }
+    }
+}
+
+impl Translate for Trade {
+    fn translate(self, instrument: &dyn Fn(&str) -> Option<Instrument>) -> Self {
+        Self {
+            instrument: instrument(&self.market_pair).or(self.instrument),
+            ..self
+        }
+    }
+}
+
+impl Translate for OrderBookResponse {
+    fn translate(self, instrument: &dyn Fn(&str) -> Option<Instrument>) -> Self {
+        Self {
+            instrument: self.market_pair.as_deref().and_then(instrument).or(self.instrument),
+            ..self
+        }
+    }
+}
+
+impl MarketResponse for OrderBookResponse {
+    fn with_market_pair(self, market_pair: &str) -> Self {
+        Self {
+            market_pair: self.market_pair.or_else(|| Some(market_pair.to_string())),
+            ..self
+        }
+    }
+}
+
+impl Translate for Ticker {
+    fn translate(self, instrument: &dyn Fn(&str) -> Option<Instrument>) -> Self {
+        Self {
+            instrument: self.market_pair.as_deref().and_then(instrument).or(self.instrument),
+            ..self
+        }
+    }
+}
+
+impl MarketResponse for Ticker {
+    fn with_market_pair(self, market_pair: &str) -> Self {
+        Self {
+            market_pair: self.market_pair.or_else(|| Some(market_pair.to_string())),
+            ..self
+        }
+    }
+}
+
+impl Translate for Candle {
+    fn translate(self, instrument: &dyn Fn(&str) -> Option<Instrument>) -> Self {
+        Self {
+            instrument: self.market_pair.as_deref().and_then(instrument).or(self.instrument),
+            ..self
+        }
+    }
+}
+
+impl MarketResponse for Candle {
+    fn with_market_pair(self, market_pair: &str) -> Self {
+        Self {
+            market_pair: self.market_pair.or_else(|| Some(market_pair.to_string())),
+            ..self
+        }
+    }
+}
+
+impl<T: Translate> Translate for Vec<T> {
+    fn translate(self, instrument: &dyn Fn(&str) -> Option<Instrument>) -> Self {
+        self.into_iter()
+            .map(|response| response.translate(instrument))
+            .collect()
+    }
+}
+
+impl<T: MarketResponse> MarketResponse for Vec<T> {
+    fn with_market_pair(self, market_pair: &str) -> Self {
+        self.into_iter()
+            .map(|response| response.with_market_pair(market_pair))
+            .collect()
+    }
+}
+
+impl Translate for OpenLimitsWebSocketMessage {
+    fn translate(self, instrument: &dyn Fn(&str) -> Option<Instrument>) -> Self {
+        match self {
+            Self::OrderBook(book) => Self::OrderBook(book.translate(instrument)),
+            Self::OrderBookDiff(book) => Self::OrderBookDiff(book.translate(instrument)),
+            Self::Trades(trades) => Self::Trades(trades.translate(instrument)),
+            Self::Orders(orders) => Self::Orders(orders.translate(instrument)),
+            Self::Ping => Self::Ping,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::{OrderBookResponse, Trade};
+use super::{Order, OrderBookResponse, Trade};
 use crate::model::{OrderStatus, OrderType, Side};
 use serde::{Deserialize, Serialize};
 use std::ops::Range;
@@ -31,11 +31,13 @@ pub enum WebSocketResponse<T> {
     Raw(T),
 }
 
-/// This enum represents a websocket message type
-#[derive(Debug, Clone, Serialize)]
+/// This enum represents a websocket message type.
+/// `Orders` are updates of the orders of the account, with the fills they received.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum OpenLimitsWebSocketMessage {
     Ping,
     OrderBook(OrderBookResponse),
     OrderBookDiff(OrderBookResponse),
     Trades(Vec<Trade>),
+    Orders(Vec<Order>),
 }
@@ -0,0 +1,302 @@
+//! This module provides an order manager keeping the state of our orders locally.
+//! `OrderManager` wraps the `ExchangeAccount` of an exchange: the orders it places, cancels
+//! and reads are tracked, and the order updates and fills received from the websocket are
+//! applied as they happen. Subscribers receive an `OrderEvent` for every change, and updates
+//! that would move an order back from a final status are ignored.
+//! # Example
+//! ```no_run
+//! use std::time::Duration;
+//! use openlimits::exchange::binance::{Binance, BinanceParameters, BinanceWebsocket};
+//! use openlimits::model::{OpenLimitOrderRequest, TimeInForce};
+//! use openlimits::model::websocket::Subscription;
+//! use openlimits::order_manager::OrderManager;
+//! use openlimits::prelude::*;
+//! use rust_decimal::Decimal;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let binance = Binance::new(BinanceParameters::sandbox())
+//!                         .await
+//!                         .expect("Couldn't create binance client");
+//!     let websocket = BinanceWebsocket::new(BinanceParameters::sandbox())
+//!                         .await
+//!                         .expect("Couldn't create binance websocket client");
+//!     let manager = OrderManager::new(binance);
+//!     let mut events = manager.subscribe();
+//!
+//!     manager.limit_buy(&OpenLimitOrderRequest {
+//!                         market_pair: "BNBBTC".to_string(),
+//!                         size: Decimal::new(1, 0),
+//!                         price: Decimal::new(1, 3),
+//!                         time_in_force: TimeInForce::GoodTillCancelled,
+//!                         post_only: false,
+//!                     })
+//!                     .await
+//!                     .expect("Couldn't place order");
+//!
+//!     let subscriptions = [Subscription::Trades("bnbbtc".to_string())];
+//!     tokio::spawn(async move {
+//!         while let Some(event) = events.recv().await {
+//!             println!("{:?}", event);
+//!         }
+//!     });
+//!     manager.track(&websocket, &subscriptions, Duration::from_secs(5))
+//!                     .await
+//!                     .expect("Tracking failed");
+//! }
+//! ```
+
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use async_trait::async_trait;
+use futures::StreamExt;
+use tokio::sync::mpsc::UnboundedReceiver;
+use crate::errors::OpenLimitsError;
+use crate::exchange::shared::{Result, Subscribers};
+use crate::exchange::traits::ExchangeAccount;
+use crate::exchange::traits::stream::ExchangeWs;
+use crate::model::{
+    Balance, CancelAllOrdersRequest, CancelOrderRequest, GetOrderHistoryRequest,
+    GetOrderRequest, OpenLimitOrderRequest, OpenMarketOrderRequest, Order, OrderCanceled,
+    OrderStatus, Paginator, Trade, TradeHistoryRequest,
+};
+use crate::model::websocket::{OpenLimitsWebSocketMessage, Subscription, WebSocketResponse};
+
+mod order_event;
+mod order_store;
+
+pub use order_event::OrderEvent;
+pub use order_store::OrderStore;
+
+/// This struct represents the order manager of one account, it can be shared by the tasks
+/// placing orders and the one tracking the websocket.
+pub struct OrderManager<E> {
+    exchange: Arc<E>,
+    store: Arc<Mutex<OrderStore>>,
+    subscribers: Subscribers<OrderEvent>,
+}
+
+impl<E> Clone for OrderManager<E> {
+    fn clone(&self) -> Self {
+        Self {
+            exchange: self.exchange.clone(),
+            store: self.store.clone(),
+            subscribers: self.subscribers.clone(),
+        }
+    }
+}
+
+impl<E> OrderManager<E> {
+    pub fn new(exchange: E) -> Self {
+        Self {
+            exchange: Arc::new(exchange),
+            store: Default::default(),
+            subscribers: Default::default(),
+        }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.exchange
+    }
+
+    /// Receives the events of the updates applied from now on
+    pub fn subscribe(&self) -> UnboundedReceiver<OrderEvent> {
+        self.subscribers.subscribe()
+    }
+
+    pub fn order(&self, id: &str) -> Result<Option<Order>> {
+        Ok(self.store()?.get(id).cloned())
+    }
+
+    pub fn order_by_client_id(&self, client_order_id: &str) -> Result<Option<Order>> {
+        Ok(self.store()?.get_by_client_id(client_order_id).cloned())
+    }
+
+    pub fn orders(&self) -> Result<Vec<Order>> {
+        Ok(self.store()?.orders())
+    }
+
+    pub fn open_orders(&self) -> Result<Vec<Order>> {
+        Ok(self.store()?.open_orders())
+    }
+
+    /// Applies an order received from the exchange, e.g. from a venue specific websocket
+    pub fn apply_order(&self, order: Order) -> Result<Vec<OrderEvent>> {
+        let events = self.store()?.apply_order(order);
+        self.subscribers.publish(&events);
+        Ok(events)
+    }
+
+    /// Applies a trade received from the exchange, trades of orders we don't track are ignored
+    pub fn apply_fill(&self, trade: Trade) -> Result<Vec<OrderEvent>> {
+        let events = self.store()?.apply_fill(trade);
+        self.subscribers.publish(&events);
+        Ok(events)
+    }
+
+    /// Applies the order updates and the trades of a websocket message
+    pub fn apply_message(&self, message: OpenLimitsWebSocketMessage) -> Result<Vec<OrderEvent>> {
+        let mut events = Vec::new();
+        match message {
+            OpenLimitsWebSocketMessage::Orders(orders) => {
+                for order in orders {
+                    events.extend(self.apply_order(order)?);
+                }
+            }
+            OpenLimitsWebSocketMessage::Trades(trades) => {
+                for trade in trades {
+                    events.extend(self.apply_fill(trade)?);
+                }
+            }
+            _ => (),
+        }
+        Ok(events)
+    }
+
+    /// Forgets the orders in a final status
+    pub fn prune(&self) -> Result<()> {
+        self.store()?.prune();
+        Ok(())
+    }
+
+    fn apply_status(&self, id: &str, status: OrderStatus) -> Result<()> {
+        let events = self.store()?.apply_status(id, status);
+        self.subscribers.publish(&events);
+        Ok(())
+    }
+
+    fn store(&self) -> Result<MutexGuard<OrderStore>> {
+        self.store
+            .lock()
+            .map_err(|_| OpenLimitsError::PoisonError())
+    }
+}
+
+impl<E> OrderManager<E>
+where
+    E: ExchangeAccount + Send + Sync,
+{
+    /// Reads the open orders from the exchange. Orders placed elsewhere are added, and the
+    /// tracked orders that aren't open anymore are read one by one to get their final status.
+    pub async fn reconcile(&self) -> Result<Vec<OrderEvent>> {
+        let open_orders = self.exchange.get_all_open_orders().await?;
+        let tracked = self.open_orders()?;
+
+        let mut events = Vec::new();
+        for order in tracked {
+            if open_orders.iter().any(|open| open.id == order.id) {
+                continue;
+            }
+            let req = GetOrderRequest {
+                id: order.id.clone(),
+                market_pair: Some(order.market_pair.clone()),
+            };
+            events.extend(self.apply_order(self.exchange.get_order(&req).await?)?);
+        }
+        for order in open_orders {
+            events.extend(self.apply_order(order)?);
+        }
+        Ok(events)
+    }
+
+    /// Applies the order updates of the subscriptions, and their trades as fills, forever.
+    /// The orders are reconciled every time the stream is created, so nothing missed while
+    /// disconnected is lost, and the stream is created again `reconnect_interval` after it
+    /// ends or fails.
+    pub async fn track<W>(
+        &self,
+        websocket: &W,
+        subscriptions: &[Subscription],
+        reconnect_interval: Duration,
+    ) -> Result<()>
+    where
+        W: ExchangeWs,
+    {
+        loop {
+            if let Ok(mut stream) = websocket.create_stream(subscriptions).await {
+                if self.reconcile().await.is_ok() {
+                    while let Some(message) = stream.next().await {
+                        match message {
+                            Ok(WebSocketResponse::Generic(message)) => {
+                                self.apply_message(message)?;
+                            }
+                            Ok(_) => continue,
+                            Err(_) => break,
+                        }
+                    }
+                }
+            }
+            tokio::time::sleep(reconnect_interval).await;
+        }
+    }
+}
+
+#[async_trait]
+impl<E> ExchangeAccount for OrderManager<E>
+where
+    E: ExchangeAccount + Send + Sync,
+{
+    async fn limit_buy(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        let order = self.exchange.limit_buy(req).await?;
+        self.apply_order(order.clone())?;
+        Ok(order)
+    }
+
+    async fn limit_sell(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        let order = self.exchange.limit_sell(req).await?;
+        self.apply_order(order.clone())?;
+        Ok(order)
+    }
+
+    async fn market_buy(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        let order = self.exchange.market_buy(req).await?;
+        self.apply_order(order.clone())?;
+        Ok(order)
+    }
+
+    async fn market_sell(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        let order = self.exchange.market_sell(req).await?;
+        self.apply_order(order.clone())?;
+        Ok(order)
+    }
+
+    async fn cancel_order(&self, req: &CancelOrderRequest) -> Result<OrderCanceled> {
+        let canceled = self.exchange.cancel_order(req).await?;
+        self.apply_status(&canceled.id, OrderStatus::Canceled)?;
+        Ok(canceled)
+    }
+
+    async fn cancel_all_orders(&self, req: &CancelAllOrdersRequest) -> Result<Vec<OrderCanceled>> {
+        let canceled = self.exchange.cancel_all_orders(req).await?;
+        for order in canceled.iter() {
+            self.apply_status(&order.id, OrderStatus::Canceled)?;
+        }
+        Ok(canceled)
+    }
+
+    async fn get_all_open_orders(&self) -> Result<Vec<Order>> {
+        let orders = self.exchange.get_all_open_orders().await?;
+        for order in orders.iter() {
+            self.apply_order(order.clone())?;
+        }
+        Ok(orders)
+    }
+
+    async fn get_order_history(&self, req: &GetOrderHistoryRequest) -> Result<Vec<Order>> {
+        self.exchange.get_order_history(req).await
+    }
+
+    async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>> {
+        self.exchange.get_trade_history(req).await
+    }
+
+    async fn get_account_balances(&self, paginator: Option<Paginator>) -> Result<Vec<Balance>> {
+        self.exchange.get_account_balances(paginator).await
+    }
+
+    async fn get_order(&self, req: &GetOrderRequest) -> Result<Order> {
+        let order = self.exchange.get_order(req).await?;
+        self.apply_order(order.clone())?;
+        Ok(order)
+    }
+}
@@ -0,0 +1,23 @@
+use crate::model::{Order, OrderStatus, Trade};
+
+/// This enum represents a change of the state of a tracked order.
+/// `InvalidTransition` reports an update that was ignored because the order can't go from
+/// its status to the one of the update, e.g. a stale REST response.
+#[derive(Clone, Debug)]
+pub enum OrderEvent {
+    New(Order),
+    StatusChanged { previous: OrderStatus, order: Order },
+    Filled { trade: Trade, order: Order },
+    InvalidTransition { order: Order, status: OrderStatus },
+}
+
+impl OrderEvent {
+    pub fn order(&self) -> &Order {
+        match self {
+            OrderEvent::New(order)
+            | OrderEvent::StatusChanged { order, .. }
+            | OrderEvent::Filled { order, .. }
+            | OrderEvent::InvalidTransition { order, .. } => order,
+        }
+    }
+}
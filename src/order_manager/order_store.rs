@@ -0,0 +1,161 @@
+use std::collections::{HashMap, HashSet};
+use rust_decimal::prelude::{Decimal, Zero};
+use crate::model::{Order, OrderStatus, Trade};
+use super::OrderEvent;
+
+/// This struct represents the state of our orders, keyed by id and by client id.
+/// Updates are applied in the order they're received and return the events they caused.
+/// The trades applied to every order are remembered, so a fill received both from the trade
+/// feed and in a REST response is only counted once.
+#[derive(Clone, Debug, Default)]
+pub struct OrderStore {
+    orders: HashMap<String, Order>,
+    client_ids: HashMap<String, String>,
+    trades: HashSet<(String, String)>,
+}
+
+impl OrderStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Order> {
+        self.orders.get(id)
+    }
+
+    pub fn get_by_client_id(&self, client_order_id: &str) -> Option<&Order> {
+        self.client_ids
+            .get(client_order_id)
+            .and_then(|id| self.orders.get(id))
+    }
+
+    pub fn orders(&self) -> Vec<Order> {
+        self.orders.values().cloned().collect()
+    }
+
+    pub fn open_orders(&self) -> Vec<Order> {
+        self.orders
+            .values()
+            .filter(|order| !order.status.is_final())
+            .cloned()
+            .collect()
+    }
+
+    /// Applies an order returned by the exchange. Its remaining size is trusted, and its
+    /// trades not seen before are recorded as fills.
+    pub fn apply_order(&mut self, order: Order) -> Vec<OrderEvent> {
+        if let Some(client_order_id) = &order.client_order_id {
+            self.client_ids
+                .insert(client_order_id.clone(), order.id.clone());
+        }
+        let mut events = Vec::new();
+        if let Some(tracked) = self.orders.get(&order.id) {
+            if !tracked.status.can_transition_to(&order.status) {
+                return vec![OrderEvent::InvalidTransition {
+                    order: tracked.clone(),
+                    status: order.status,
+                }];
+            }
+        }
+        let seen = &mut self.trades;
+        let new_trades: Vec<Trade> = order
+            .trades
+            .iter()
+            .filter(|trade| seen.insert((order.id.clone(), trade.id.clone())))
+            .cloned()
+            .collect();
+
+        let tracked = match self.orders.get_mut(&order.id) {
+            Some(tracked) => tracked,
+            None => {
+                events.push(OrderEvent::New(order.clone()));
+                events.extend(new_trades.into_iter().map(|trade| OrderEvent::Filled {
+                    trade,
+                    order: order.clone(),
+                }));
+                self.orders.insert(order.id.clone(), order);
+                return events;
+            }
+        };
+
+        let previous = tracked.status.clone();
+        let mut trades = std::mem::take(&mut tracked.trades);
+        trades.extend(new_trades.iter().cloned());
+        *tracked = Order { trades, ..order };
+        events.extend(new_trades.into_iter().map(|trade| OrderEvent::Filled {
+            trade,
+            order: tracked.clone(),
+        }));
+        if previous != tracked.status {
+            events.push(OrderEvent::StatusChanged {
+                previous,
+                order: tracked.clone(),
+            });
+        }
+        events
+    }
+
+    /// Applies a trade of one of our orders, the order is matched by its id on either side
+    /// of the trade. Trades already applied are skipped.
+    pub fn apply_fill(&mut self, trade: Trade) -> Vec<OrderEvent> {
+        let id = [&trade.buyer_order_id, &trade.seller_order_id]
+            .iter()
+            .filter_map(|id| id.as_ref())
+            .find(|id| self.orders.contains_key(*id))
+            .cloned();
+        let id = match id {
+            Some(id) => id,
+            None => return Vec::new(),
+        };
+        if !self.trades.insert((id.clone(), trade.id.clone())) {
+            return Vec::new();
+        }
+        let tracked = match self.orders.get_mut(&id) {
+            Some(tracked) => tracked,
+            None => return Vec::new(),
+        };
+
+        let remaining = tracked.remaining.unwrap_or(tracked.size) - trade.qty;
+        let remaining = remaining.max(Decimal::zero());
+        tracked.remaining = Some(remaining);
+        tracked.trades.push(trade.clone());
+        let mut events = vec![OrderEvent::Filled {
+            trade,
+            order: tracked.clone(),
+        }];
+
+        let status = match remaining.is_zero() {
+            true => OrderStatus::Filled,
+            false => OrderStatus::PartiallyFilled,
+        };
+        if tracked.status != status && tracked.status.can_transition_to(&status) {
+            let previous = std::mem::replace(&mut tracked.status, status);
+            events.push(OrderEvent::StatusChanged {
+                previous,
+                order: tracked.clone(),
+            });
+        }
+        events
+    }
+
+    /// Applies a status without the rest of the order, e.g. after a cancellation
+    pub fn apply_status(&mut self, id: &str, status: OrderStatus) -> Vec<OrderEvent> {
+        let mut order = match self.orders.get(id) {
+            Some(order) => order.clone(),
+            None => return Vec::new(),
+        };
+        order.status = status;
+        order.trades = Vec::new();
+        self.apply_order(order)
+    }
+
+    /// Forgets the orders in a final status
+    pub fn prune(&mut self) {
+        let orders = &self.orders;
+        self.client_ids
+            .retain(|_, id| orders.get(id).map_or(false, |order| !order.status.is_final()));
+        self.orders.retain(|_, order| !order.status.is_final());
+        let orders = &self.orders;
+        self.trades.retain(|(id, _)| orders.contains_key(id));
+    }
+}
@@ -0,0 +1,212 @@
+//! This module provides a recorder of the market data received from the exchanges.
+//! The messages of the websocket streams, and optionally periodic order book snapshots of the
+//! REST api, are written with their time, exchange and symbol to rotating JSON lines files
+//! so they can be replayed later.
+//! # Example
+//! ```no_run
+//! use openlimits::exchange::binance::{BinanceParameters, BinanceWebsocket};
+//! use openlimits::exchange::binance::Binance;
+//! use openlimits::model::websocket::Subscription;
+//! use openlimits::prelude::*;
+//! use openlimits::recorder::{Recorder, RecorderConfig};
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let websocket = BinanceWebsocket::new(BinanceParameters::prod())
+//!                         .await
+//!                         .expect("Couldn't create binance websocket client");
+//!     let binance = Binance::new(BinanceParameters::prod())
+//!                         .await
+//!                         .expect("Couldn't create binance client");
+//!     let config = RecorderConfig::new("recordings", "binance")
+//!                         .with_snapshot_interval(std::time::Duration::from_secs(60));
+//!     let recorder = Recorder::new(config).expect("Couldn't create recorder");
+//!
+//!     recorder.record_with_snapshots(&websocket, &binance, &[
+//!                         Subscription::OrderBookUpdates("bnbbtc".to_string()),
+//!                         Subscription::Trades("bnbbtc".to_string()),
+//!                     ])
+//!                     .await
+//!                     .expect("Recording failed");
+//! }
+//! ```
+
+use std::slice;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use chrono::Utc;
+use futures::stream::{select_all, StreamExt};
+use serde::Serialize;
+use crate::errors::OpenLimitsError;
+use crate::exchange::shared::Result;
+use crate::exchange::traits::ExchangeMarketData;
+use crate::exchange::traits::stream::ExchangeWs;
+use crate::model::OrderBookRequest;
+use crate::model::websocket::{Subscription, WebSocketResponse};
+
+mod record_reader;
+mod record_writer;
+mod recorded_message;
+mod recorder_config;
+mod recording_index;
+
+pub use record_reader::RecordReader;
+pub use record_writer::RecordWriter;
+pub use recorded_message::{RecordedMessage, RecordedPayload};
+pub use recorder_config::RecorderConfig;
+pub use recording_index::{
+    Checkpoint, RecordingIndex, RecordingIndexEntry, SymbolRange, INDEX_FILE,
+};
+
+/// This struct represents a recorder of one exchange, it can be shared by the tasks feeding it.
+#[derive(Clone)]
+pub struct Recorder {
+    pub config: RecorderConfig,
+    writer: Arc<Mutex<RecordWriter>>,
+}
+
+impl Recorder {
+    pub fn new(config: RecorderConfig) -> Result<Self> {
+        let writer = RecordWriter::new(config.clone())?;
+        Ok(Self {
+            config,
+            writer: Arc::new(Mutex::new(writer)),
+        })
+    }
+
+    /// Writes a payload received now. The time is read under the lock of the writer so the
+    /// records of a file are in time order.
+    pub fn write(&self, symbol: Option<String>, payload: RecordedPayload) -> Result<()> {
+        let mut writer = self
+            .writer
+            .lock()
+            .map_err(|_| OpenLimitsError::PoisonError())?;
+        let record = RecordedMessage {
+            timestamp: Utc::now().timestamp_millis() as u64,
+            exchange: self.config.exchange.clone(),
+            symbol,
+            payload,
+        };
+        writer.write(&record)
+    }
+
+    /// Writes the buffered records to the current file
+    pub fn flush(&self) -> Result<()> {
+        self.writer
+            .lock()
+            .map_err(|_| OpenLimitsError::PoisonError())?
+            .flush()
+    }
+
+    /// Closes the current file so it's indexed
+    pub fn close(&self) -> Result<()> {
+        self.writer
+            .lock()
+            .map_err(|_| OpenLimitsError::PoisonError())?
+            .close()
+    }
+
+    /// Records the subscriptions until all their streams end. Every subscription gets its own
+    /// stream so the messages can be tagged with their symbol. Errors received from the
+    /// streams are recorded too, and the records are flushed every flush interval.
+    pub async fn record<E>(&self, websocket: &E, subscriptions: &[Subscription]) -> Result<()>
+    where
+        E: ExchangeWs,
+        E::Response: Serialize,
+    {
+        let mut streams = Vec::new();
+        for subscription in subscriptions {
+            let symbol = subscription_symbol(subscription);
+            let stream = websocket
+                .create_stream(slice::from_ref(subscription))
+                .await?;
+            streams.push(stream.map(move |message| (symbol.clone(), message)).boxed());
+        }
+
+        let mut messages = select_all(streams);
+        let mut flush = tokio::time::interval(self.config.flush_interval);
+        loop {
+            let (symbol, message) = tokio::select! {
+                message = messages.next() => match message {
+                    Some(message) => message,
+                    None => break,
+                },
+                _ = flush.tick() => {
+                    self.flush()?;
+                    continue;
+                }
+            };
+            let payload = match message {
+                Ok(WebSocketResponse::Generic(message)) => RecordedPayload::Message(message),
+                Ok(WebSocketResponse::Raw(raw)) => RecordedPayload::Raw(serde_json::to_value(raw)?),
+                Err(err) => RecordedPayload::Error(err.to_string()),
+            };
+            self.write(symbol, payload)?;
+        }
+
+        self.close()
+    }
+
+    /// Records the order books of `symbols` read from the REST api every `interval`, forever.
+    /// Failed requests are skipped.
+    pub async fn snapshots<M>(&self, market: &M, symbols: &[String], interval: Duration) -> Result<()>
+    where
+        M: ExchangeMarketData,
+    {
+        let mut interval = tokio::time::interval(interval);
+        loop {
+            interval.tick().await;
+            for symbol in symbols {
+                let req = OrderBookRequest {
+                    market_pair: symbol.clone(),
+                };
+                if let Ok(book) = market.order_book(&req).await {
+                    self.write(Some(symbol.clone()), RecordedPayload::Snapshot(book))?;
+                }
+            }
+        }
+    }
+
+    /// Records the subscriptions and, when the configuration has a snapshot interval, the
+    /// order books of the symbols subscribed to order book updates.
+    pub async fn record_with_snapshots<E, M>(
+        &self,
+        websocket: &E,
+        market: &M,
+        subscriptions: &[Subscription],
+    ) -> Result<()>
+    where
+        E: ExchangeWs,
+        E::Response: Serialize,
+        M: ExchangeMarketData,
+    {
+        let interval = match self.config.snapshot_interval {
+            Some(interval) => interval,
+            None => return self.record(websocket, subscriptions).await,
+        };
+        let symbols: Vec<String> = subscriptions
+            .iter()
+            .filter_map(|subscription| match subscription {
+                Subscription::OrderBookUpdates(symbol) => Some(symbol.clone()),
+                _ => None,
+            })
+            .collect();
+
+        tokio::select! {
+            result = self.record(websocket, subscriptions) => result,
+            result = self.snapshots(market, &symbols, interval) => result,
+        }
+    }
+}
+
+/// Symbol a subscription is about
+pub fn subscription_symbol(subscription: &Subscription) -> Option<String> {
+    match subscription {
+        Subscription::Ticker(symbol)
+        | Subscription::OrderBookUpdates(symbol)
+        | Subscription::Trades(symbol)
+        | Subscription::AccountTrades(symbol)
+        | Subscription::AccountBalance(symbol) => Some(symbol.clone()),
+        Subscription::AccountOrders(orders) => orders.market.clone(),
+    }
+}
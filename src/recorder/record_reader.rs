@@ -0,0 +1,58 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Lines, Read, Seek, SeekFrom};
+use std::path::Path;
+use flate2::read::GzDecoder;
+use crate::errors::OpenLimitsError;
+use crate::exchange::shared::Result;
+use super::RecordedMessage;
+
+/// This struct represents a reader of a recording file, compressed files are recognized by
+/// their `.gz` extension.
+pub struct RecordReader {
+    lines: Lines<Box<dyn BufRead + Send>>,
+}
+
+impl RecordReader {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::open_at(path, 0)
+    }
+
+    /// Opens a file to read it from `offset` of the uncompressed file, which must be the
+    /// offset of a record. Plain files are seeked, compressed ones are decompressed up to it.
+    pub fn open_at(path: impl AsRef<Path>, offset: u64) -> Result<Self> {
+        let path = path.as_ref();
+        let mut file = File::open(path)?;
+        let reader: Box<dyn BufRead + Send> = match path.extension() {
+            Some(extension) if extension == "gz" => {
+                let mut reader = BufReader::new(GzDecoder::new(file));
+                io::copy(&mut (&mut reader).take(offset), &mut io::sink())?;
+                Box::new(reader)
+            }
+            _ => {
+                file.seek(SeekFrom::Start(offset))?;
+                Box::new(BufReader::new(file))
+            }
+        };
+
+        Ok(Self {
+            lines: reader.lines(),
+        })
+    }
+}
+
+impl Iterator for RecordReader {
+    type Item = Result<RecordedMessage>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(err) => return Some(Err(OpenLimitsError::IoError(err))),
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            return Some(serde_json::from_str(&line).map_err(OpenLimitsError::JsonError));
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use crate::exchange::shared::Result;
+use super::{RecordedMessage, RecorderConfig, RecordingIndex, RecordingIndexEntry};
+
+/// Destination of the lines of a recording file
+enum Sink {
+    Plain(BufWriter<File>),
+    Gzip(GzEncoder<BufWriter<File>>),
+}
+
+impl Sink {
+    fn writer(&mut self) -> &mut dyn Write {
+        match self {
+            Sink::Plain(writer) => writer,
+            Sink::Gzip(writer) => writer,
+        }
+    }
+
+    fn write_line(&mut self, line: &[u8]) -> io::Result<()> {
+        let writer = self.writer();
+        writer.write_all(line)?;
+        writer.write_all(b"\n")
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer().flush()
+    }
+
+    fn finish(self) -> io::Result<()> {
+        match self {
+            Sink::Plain(mut writer) => writer.flush(),
+            Sink::Gzip(writer) => writer.finish()?.flush(),
+        }
+    }
+}
+
+struct OpenFile {
+    sink: Sink,
+    size: u64,
+    checkpoint: u64,
+    entry: RecordingIndexEntry,
+}
+
+/// This struct represents the writer of a recording directory.
+/// Records are appended as JSON lines to the current file, which is closed and indexed when
+/// it has to be rotated. Files are never reopened nor overwritten. The index marks the offset
+/// of the first record of every symbol, and a checkpoint every checkpoint interval, so replays
+/// can start reading a file where its records begin to matter.
+/// The records are buffered until `flush` or the rotation. A file being written can be read
+/// up to its last flush, a compressed one then ends with an error until it's closed.
+pub struct RecordWriter {
+    config: RecorderConfig,
+    current: Option<OpenFile>,
+    sequence: u64,
+}
+
+impl RecordWriter {
+    pub fn new(config: RecorderConfig) -> Result<Self> {
+        fs::create_dir_all(&config.directory)?;
+        Ok(Self {
+            config,
+            current: None,
+            sequence: 0,
+        })
+    }
+
+    pub fn write(&mut self, record: &RecordedMessage) -> Result<()> {
+        let line = serde_json::to_vec(record)?;
+        if self.should_rotate(record.timestamp) {
+            self.close()?;
+        }
+        if self.current.is_none() {
+            self.current = Some(self.open(record.timestamp)?);
+        }
+
+        if let Some(file) = self.current.as_mut() {
+            if file.size >= file.checkpoint + self.config.checkpoint_interval {
+                file.entry.checkpoint(file.size);
+                file.checkpoint = file.size;
+            }
+            file.sink.write_line(&line)?;
+            file.entry.add(record.symbol.as_deref(), record.timestamp, file.size);
+            file.size += line.len() as u64 + 1;
+        }
+        Ok(())
+    }
+
+    /// Writes the buffered records to the current file
+    pub fn flush(&mut self) -> Result<()> {
+        if let Some(file) = self.current.as_mut() {
+            file.sink.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Closes the current file and adds it to the index
+    pub fn close(&mut self) -> Result<()> {
+        if let Some(file) = self.current.take() {
+            file.sink.finish()?;
+            RecordingIndex::append(&self.config.directory, &file.entry)?;
+        }
+        Ok(())
+    }
+
+    fn should_rotate(&self, timestamp: u64) -> bool {
+        match &self.current {
+            Some(file) => {
+                file.size >= self.config.max_file_size
+                    || timestamp.saturating_sub(file.entry.start)
+                        >= self.config.rotation_interval.as_millis() as u64
+            }
+            None => false,
+        }
+    }
+
+    fn open(&mut self, timestamp: u64) -> Result<OpenFile> {
+        let name = format!(
+            "{}-{}-{}.jsonl{}",
+            self.config.exchange,
+            timestamp,
+            self.sequence,
+            if self.config.compression { ".gz" } else { "" }
+        );
+        self.sequence += 1;
+
+        let file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(self.config.directory.join(&name))?;
+        let writer = BufWriter::new(file);
+        let sink = match self.config.compression {
+            true => Sink::Gzip(GzEncoder::new(writer, Compression::default())),
+            false => Sink::Plain(writer),
+        };
+
+        Ok(OpenFile {
+            sink,
+            size: 0,
+            checkpoint: 0,
+            entry: RecordingIndexEntry::new(&name, &self.config.exchange),
+        })
+    }
+}
+
+impl Drop for RecordWriter {
+    fn drop(&mut self) {
+        self.close().ok();
+    }
+}
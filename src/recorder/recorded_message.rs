@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::model::OrderBookResponse;
+use crate::model::websocket::OpenLimitsWebSocketMessage;
+
+/// This struct represents a line of a recording: what was received, when, from which
+/// exchange and for which symbol.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RecordedMessage {
+    pub timestamp: u64,
+    pub exchange: String,
+    pub symbol: Option<String>,
+    pub payload: RecordedPayload,
+}
+
+/// This enum represents the content of a recorded message.
+/// Raw messages are exchange specific and are kept as the JSON of the message of the exchange,
+/// errors received from the streams as their description.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordedPayload {
+    Message(OpenLimitsWebSocketMessage),
+    Snapshot(OrderBookResponse),
+    Raw(Value),
+    Error(String),
+}
@@ -0,0 +1,65 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// This struct represents where and how a recording is written.
+/// Files are rotated when they reach `max_file_size` bytes of records or when they span
+/// `rotation_interval`, whichever comes first. The records are flushed to the current file
+/// every `flush_interval` while recording, and indexed with a checkpoint every
+/// `checkpoint_interval` bytes of records.
+#[derive(Clone, Debug)]
+pub struct RecorderConfig {
+    pub directory: PathBuf,
+    pub exchange: String,
+    pub rotation_interval: Duration,
+    pub max_file_size: u64,
+    pub compression: bool,
+    pub flush_interval: Duration,
+    pub checkpoint_interval: u64,
+    pub snapshot_interval: Option<Duration>,
+}
+
+impl RecorderConfig {
+    pub fn new(directory: impl Into<PathBuf>, exchange: &str) -> Self {
+        Self {
+            directory: directory.into(),
+            exchange: exchange.to_string(),
+            rotation_interval: Duration::from_secs(60 * 60),
+            max_file_size: 64 * 1024 * 1024,
+            compression: true,
+            flush_interval: Duration::from_secs(1),
+            checkpoint_interval: 1024 * 1024,
+            snapshot_interval: None,
+        }
+    }
+
+    pub fn with_rotation_interval(mut self, rotation_interval: Duration) -> Self {
+        self.rotation_interval = rotation_interval;
+        self
+    }
+
+    pub fn with_max_file_size(mut self, max_file_size: u64) -> Self {
+        self.max_file_size = max_file_size;
+        self
+    }
+
+    pub fn with_compression(mut self, compression: bool) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    pub fn with_flush_interval(mut self, flush_interval: Duration) -> Self {
+        self.flush_interval = flush_interval;
+        self
+    }
+
+    pub fn with_checkpoint_interval(mut self, checkpoint_interval: u64) -> Self {
+        self.checkpoint_interval = checkpoint_interval;
+        self
+    }
+
+    /// Order books are also read through the REST api every `snapshot_interval`
+    pub fn with_snapshot_interval(mut self, snapshot_interval: Duration) -> Self {
+        self.snapshot_interval = Some(snapshot_interval);
+        self
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use crate::errors::OpenLimitsError;
+use crate::exchange::shared::Result;
+
+pub const INDEX_FILE: &str = "index.jsonl";
+
+/// This struct represents the time span and number of records of a symbol in a file, and the
+/// offset of its first record
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SymbolRange {
+    pub start: u64,
+    pub end: u64,
+    pub records: u64,
+    #[serde(default)]
+    pub offset: u64,
+}
+
+/// This struct represents a position in a file: the offset of a record in the uncompressed
+/// file and the latest timestamp of the records before it
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Checkpoint {
+    pub offset: u64,
+    pub timestamp: u64,
+}
+
+/// This struct represents the index of a closed recording file
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RecordingIndexEntry {
+    pub file: String,
+    pub exchange: String,
+    pub start: u64,
+    pub end: u64,
+    pub records: u64,
+    pub symbols: BTreeMap<String, SymbolRange>,
+    #[serde(default)]
+    pub checkpoints: Vec<Checkpoint>,
+}
+
+impl RecordingIndexEntry {
+    pub fn new(file: &str, exchange: &str) -> Self {
+        Self {
+            file: file.to_string(),
+            exchange: exchange.to_string(),
+            start: 0,
+            end: 0,
+            records: 0,
+            symbols: BTreeMap::new(),
+            checkpoints: Vec::new(),
+        }
+    }
+
+    /// Adds a record written at `offset` of the uncompressed file
+    pub fn add(&mut self, symbol: Option<&str>, timestamp: u64, offset: u64) {
+        if self.records == 0 {
+            self.start = timestamp;
+        }
+        self.end = self.end.max(timestamp);
+        self.records += 1;
+
+        if let Some(symbol) = symbol {
+            let range = self
+                .symbols
+                .entry(symbol.to_string())
+                .or_insert(SymbolRange {
+                    start: timestamp,
+                    end: timestamp,
+                    records: 0,
+                    offset,
+                });
+            range.end = range.end.max(timestamp);
+            range.records += 1;
+        }
+    }
+
+    /// Marks `offset` of the uncompressed file, where the next record will be written, as a
+    /// position reading can begin at
+    pub fn checkpoint(&mut self, offset: u64) {
+        self.checkpoints.push(Checkpoint {
+            offset,
+            timestamp: self.end,
+        });
+    }
+
+    pub fn overlaps(&self, symbol: Option<&str>, start: u64, end: u64) -> bool {
+        match symbol {
+            Some(symbol) => self
+                .symbols
+                .get(symbol)
+                .map_or(false, |range| range.start <= end && range.end >= start),
+            None => self.start <= end && self.end >= start,
+        }
+    }
+
+    /// Offset reading the records of `symbols`, or of any symbol, from `start` can begin at.
+    /// The records before it are all older than `start` or of other symbols.
+    pub fn offset(&self, symbols: Option<&[String]>, start: u64) -> u64 {
+        let checkpoint = self
+            .checkpoints
+            .iter()
+            .take_while(|checkpoint| checkpoint.timestamp < start)
+            .last()
+            .map_or(0, |checkpoint| checkpoint.offset);
+        let first = symbols
+            .and_then(|symbols| {
+                symbols
+                    .iter()
+                    .filter_map(|symbol| self.symbols.get(symbol))
+                    .map(|range| range.offset)
+                    .min()
+            })
+            .unwrap_or(0);
+        checkpoint.max(first)
+    }
+}
+
+/// This struct represents the index of a recording directory.
+/// It's an append-only file with one entry per closed recording file, in the order they
+/// were written.
+#[derive(Clone, Debug, Default)]
+pub struct RecordingIndex {
+    pub directory: PathBuf,
+    pub entries: Vec<RecordingIndexEntry>,
+}
+
+impl RecordingIndex {
+    pub fn load(directory: impl AsRef<Path>) -> Result<Self> {
+        let directory = directory.as_ref().to_path_buf();
+        let path = directory.join(INDEX_FILE);
+        let mut entries = Vec::new();
+        if path.exists() {
+            for line in BufReader::new(File::open(&path)?).lines() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                entries.push(serde_json::from_str(&line).map_err(OpenLimitsError::JsonError)?);
+            }
+        }
+
+        Ok(Self { directory, entries })
+    }
+
+    pub fn append(directory: impl AsRef<Path>, entry: &RecordingIndexEntry) -> Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(directory.as_ref().join(INDEX_FILE))?;
+        writeln!(file, "{}", serde_json::to_string(entry)?)?;
+        Ok(())
+    }
+
+    /// Files with records of `symbol`, or of any symbol, between `start` and `end`
+    pub fn files(&self, symbol: Option<&str>, start: u64, end: u64) -> Vec<PathBuf> {
+        let mut entries: Vec<&RecordingIndexEntry> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.overlaps(symbol, start, end))
+            .collect();
+        entries.sort_by_key(|entry| entry.start);
+        entries
+            .into_iter()
+            .map(|entry| self.directory.join(&entry.file))
+            .collect()
+    }
+
+    /// Files with records of any of `symbols`, or of any symbol, between `start` and `end`,
+    /// with the offset reading them can begin at
+    pub fn positions(
+        &self,
+        symbols: Option<&[String]>,
+        start: u64,
+        end: u64,
+    ) -> Vec<(PathBuf, u64)> {
+        let mut entries: Vec<&RecordingIndexEntry> = self
+            .entries
+            .iter()
+            .filter(|entry| match symbols {
+                Some(symbols) => symbols
+                    .iter()
+                    .any(|symbol| entry.overlaps(Some(symbol), start, end)),
+                None => entry.overlaps(None, start, end),
+            })
+            .collect();
+        entries.sort_by_key(|entry| entry.start);
+        entries
+            .into_iter()
+            .map(|entry| (self.directory.join(&entry.file), entry.offset(symbols, start)))
+            .collect()
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+use rust_decimal::prelude::{Decimal, Zero};
+use crate::errors::OpenLimitsError;
+use crate::model::{OpenLimitOrderRequest, Order, OrderStatus};
+
+/// This struct represents an order sent to a venue for a parent order, with the order
+/// returned by the venue or the error it was rejected with, shared so the status stays `Clone`.
+#[derive(Clone, Debug)]
+pub struct ChildOrder {
+    pub venue: String,
+    pub request: OpenLimitOrderRequest,
+    pub order: Option<Order>,
+    pub error: Option<Arc<OpenLimitsError>>,
+}
+
+impl ChildOrder {
+    /// Size executed, from the trades of the order when the venue returns them
+    pub fn filled(&self) -> Decimal {
+        match &self.order {
+            None => Decimal::zero(),
+            Some(order) if !order.trades.is_empty() => order
+                .trades
+                .iter()
+                .fold(Decimal::zero(), |filled, trade| filled + trade.qty),
+            Some(order) if order.status == OrderStatus::Filled => order.size,
+            Some(order) => order
+                .remaining
+                .map_or(Decimal::zero(), |remaining| order.size - remaining),
+        }
+    }
+
+    /// Quote amount executed, fees excluded
+    pub fn notional(&self) -> Decimal {
+        match &self.order {
+            Some(order) if !order.trades.is_empty() => order
+                .trades
+                .iter()
+                .fold(Decimal::zero(), |notional, trade| notional + trade.qty * trade.price),
+            Some(order) => self.filled() * order.price.unwrap_or(self.request.price),
+            None => Decimal::zero(),
+        }
+    }
+
+    /// Whether the venue can still fill the order
+    pub fn is_open(&self) -> bool {
+        self.order.as_ref().map_or(false, |order| {
+            matches!(
+                order.status,
+                OrderStatus::New
+                    | OrderStatus::PartiallyFilled
+                    | OrderStatus::Open
+                    | OrderStatus::Pending
+                    | OrderStatus::Active
+            )
+        })
+    }
+}
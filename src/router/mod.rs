@@ -0,0 +1,287 @@
+//! This module provides a smart order router. A parent order is split across the venues
+//! quoting a market, from their current books, fees and minimum sizes, and its child orders
+//! are sent as immediate-or-cancel limit orders whose fills make up the parent status.
+//! # Example
+//! ```no_run
+//! use openlimits::exchange::any::{AnyExchange, InitAnyExchange};
+//! use openlimits::exchange::binance::BinanceParameters;
+//! use openlimits::exchange::coinbase::CoinbaseParameters;
+//! use openlimits::model::Side;
+//! use openlimits::prelude::*;
+//! use openlimits::router::{ParentOrder, RouterVenue, RoutingPolicy, SmartOrderRouter};
+//! use rust_decimal::Decimal;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let binance = AnyExchange::new(InitAnyExchange::Binance(BinanceParameters::prod()))
+//!                         .await
+//!                         .expect("Couldn't create binance client");
+//!     let coinbase = AnyExchange::new(InitAnyExchange::Coinbase(CoinbaseParameters::prod()))
+//!                         .await
+//!                         .expect("Couldn't create coinbase client");
+//!     let router = SmartOrderRouter::new(vec![
+//!         RouterVenue::new("binance", &binance, "BTCUSDT").with_taker_fee(Decimal::new(1, 3)),
+//!         RouterVenue::new("coinbase", &coinbase, "BTC-USD").with_taker_fee(Decimal::new(5, 3)),
+//!     ]);
+//!
+//!     let parent = ParentOrder::new(Side::Buy, Decimal::new(2, 0), Decimal::new(30000, 0))
+//!                         .with_policy(RoutingPolicy::SweepToFill);
+//!     let status = router.route(&parent).await.expect("Couldn't route order");
+//!     println!("{:?} {} filled at {:?}", status.status, status.filled, status.average_price);
+//! }
+//! ```
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use futures::future::join_all;
+use rust_decimal::prelude::{Decimal, One, Zero};
+use crate::errors::OpenLimitsError;
+use crate::exchange::shared::Result;
+use crate::exchange::traits::info::{ExchangeInfoRetrieval, MarketPair};
+use crate::exchange::traits::{ExchangeAccount, ExchangeMarketData};
+use crate::model::{
+    GetOrderRequest, OpenLimitOrderRequest, OrderBookRequest, OrderBookResponse, Side, TimeInForce,
+};
+
+mod child_order;
+mod parent_order;
+mod parent_order_status;
+mod route_plan;
+mod router_venue;
+mod routing_policy;
+
+pub use child_order::ChildOrder;
+pub use parent_order::ParentOrder;
+pub use parent_order_status::ParentOrderStatus;
+pub use route_plan::{RouteAllocation, RoutePlan};
+pub use router_venue::RouterVenue;
+pub use routing_policy::RoutingPolicy;
+
+/// This struct represents a router of parent orders across venues of the same market.
+pub struct SmartOrderRouter<'a, E> {
+    pub venues: Vec<RouterVenue<'a, E>>,
+}
+
+impl<'a, E> SmartOrderRouter<'a, E>
+where
+    E: ExchangeAccount + ExchangeMarketData + ExchangeInfoRetrieval + Sync,
+{
+    pub fn new(venues: Vec<RouterVenue<'a, E>>) -> Self {
+        Self { venues }
+    }
+
+    /// Splits a parent order from the current books of the venues, without sending anything
+    pub async fn plan(&self, parent: &ParentOrder) -> Result<RoutePlan> {
+        let markets = join_all(self.venues.iter().map(market))
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
+        Ok(allocate(parent, &self.venues, &markets))
+    }
+
+    /// Plans a parent order and sends its child orders. Child orders that fail are kept with
+    /// their error, so the status tells what was executed.
+    pub async fn route(&self, parent: &ParentOrder) -> Result<ParentOrderStatus> {
+        let plan = self.plan(parent).await?;
+        let children = join_all(
+            plan.allocations
+                .iter()
+                .map(|allocation| self.send(parent, allocation)),
+        )
+        .await;
+        Ok(ParentOrderStatus::new(parent, children))
+    }
+
+    /// Reads the child orders that are still open from their venue
+    pub async fn refresh(&self, status: &ParentOrderStatus) -> Result<ParentOrderStatus> {
+        let mut children = status.children.clone();
+        for child in children.iter_mut().filter(|child| child.is_open()) {
+            let (venue, order) = match (self.venue(&child.venue), child.order.as_ref()) {
+                (Some(venue), Some(order)) => (venue, order),
+                _ => continue,
+            };
+            let req = GetOrderRequest {
+                id: order.id.clone(),
+                market_pair: Some(venue.market_pair.clone()),
+            };
+            child.order = Some(venue.exchange.get_order(&req).await?);
+        }
+        Ok(ParentOrderStatus::new(&status.parent, children))
+    }
+
+    async fn send(&self, parent: &ParentOrder, allocation: &RouteAllocation) -> ChildOrder {
+        let request = OpenLimitOrderRequest {
+            market_pair: allocation.market_pair.clone(),
+            size: allocation.size,
+            price: allocation.price,
+            time_in_force: TimeInForce::ImmediateOrCancelled,
+            post_only: false,
+        };
+        let result = match self.venue(&allocation.venue) {
+            Some(venue) => match parent.side {
+                Side::Buy => venue.exchange.limit_buy(&request).await,
+                Side::Sell => venue.exchange.limit_sell(&request).await,
+            },
+            None => Err(OpenLimitsError::InvalidParameter(format!(
+                "Unknown venue {}",
+                allocation.venue
+            ))),
+        };
+        let (order, error) = match result {
+            Ok(order) => (Some(order), None),
+            Err(err) => (None, Some(Arc::new(err))),
+        };
+
+        ChildOrder {
+            venue: allocation.venue.clone(),
+            request,
+            order,
+            error,
+        }
+    }
+
+    fn venue(&self, name: &str) -> Option<&RouterVenue<'a, E>> {
+        self.venues.iter().find(|venue| venue.name == name)
+    }
+}
+
+async fn market<E>(venue: &RouterVenue<'_, E>) -> Result<(OrderBookResponse, MarketPair)>
+where
+    E: ExchangeMarketData + ExchangeInfoRetrieval + Sync,
+{
+    let req = OrderBookRequest {
+        market_pair: venue.market_pair.clone(),
+    };
+    let book = venue.exchange.order_book(&req).await?;
+    let pair = venue.exchange.get_pair(&venue.market_pair).await?.read()?;
+    Ok((book, pair))
+}
+
+/// A level of a venue within the limit price, `effective` is its price including fees
+struct Level {
+    venue: usize,
+    price: Decimal,
+    qty: Decimal,
+    effective: Decimal,
+}
+
+/// Execution of `size` on the levels of a venue: worst price, quote amount with fees and
+/// without them
+fn execution(levels: &[Level], venue: usize, size: Decimal) -> (Decimal, Decimal, Decimal) {
+    let mut remaining = size;
+    let (mut price, mut cost, mut notional) = (Decimal::zero(), Decimal::zero(), Decimal::zero());
+    for level in levels.iter().filter(|level| level.venue == venue) {
+        if remaining.is_zero() {
+            break;
+        }
+        let qty = level.qty.min(remaining);
+        remaining -= qty;
+        price = level.price;
+        cost += qty * level.effective;
+        notional += qty * level.price;
+    }
+    (price, cost, notional)
+}
+
+fn round_down(size: Decimal, increment: Decimal) -> Decimal {
+    match increment.is_zero() {
+        true => size,
+        false => (size / increment).floor() * increment,
+    }
+}
+
+/// Takes the best levels across venues until the parent order is filled. The size of every
+/// venue is rounded down to its increment, and a venue whose size is below its minimums is
+/// left out and the order split again.
+fn allocate<E>(
+    parent: &ParentOrder,
+    venues: &[RouterVenue<'_, E>],
+    markets: &[(OrderBookResponse, MarketPair)],
+) -> RoutePlan {
+    let mut levels: Vec<Level> = Vec::new();
+    for (index, (venue, (book, _))) in venues.iter().zip(markets).enumerate() {
+        let side = match parent.side {
+            Side::Buy => &book.asks,
+            Side::Sell => &book.bids,
+        };
+        for level in side.iter().filter(|level| !level.qty.is_zero()) {
+            let (within_limit, effective) = match parent.side {
+                Side::Buy => (
+                    level.price <= parent.limit_price,
+                    level.price * (Decimal::one() + venue.taker_fee),
+                ),
+                Side::Sell => (
+                    level.price >= parent.limit_price,
+                    level.price * (Decimal::one() - venue.taker_fee),
+                ),
+            };
+            if within_limit {
+                levels.push(Level {
+                    venue: index,
+                    price: level.price,
+                    qty: level.qty,
+                    effective,
+                });
+            }
+        }
+    }
+    levels.sort_by(|a, b| match parent.side {
+        Side::Buy => a.effective.cmp(&b.effective),
+        Side::Sell => b.effective.cmp(&a.effective),
+    });
+    if parent.policy == RoutingPolicy::BestPriceOnly {
+        let best = match parent.side {
+            Side::Buy => levels.iter().map(|level| level.price).min(),
+            Side::Sell => levels.iter().map(|level| level.price).max(),
+        };
+        levels.retain(|level| Some(level.price) == best);
+    }
+
+    let mut excluded: HashSet<usize> = HashSet::new();
+    loop {
+        let mut sizes = vec![Decimal::zero(); venues.len()];
+        let mut remaining = parent.size;
+        for level in levels.iter().filter(|level| !excluded.contains(&level.venue)) {
+            if remaining.is_zero() {
+                break;
+            }
+            let qty = level.qty.min(remaining);
+            sizes[level.venue] += qty;
+            remaining -= qty;
+        }
+
+        let mut allocations = Vec::new();
+        let mut too_small = false;
+        for (index, size) in sizes.into_iter().enumerate() {
+            let pair = &markets[index].1;
+            let size = round_down(size, pair.base_increment);
+            if size.is_zero() {
+                continue;
+            }
+            let (price, cost, notional) = execution(&levels, index, size);
+            if pair.min_base_trade_size.map_or(false, |min| size < min)
+                || pair.min_quote_trade_size.map_or(false, |min| notional < min)
+            {
+                excluded.insert(index);
+                too_small = true;
+                continue;
+            }
+            allocations.push(RouteAllocation {
+                venue: venues[index].name.clone(),
+                market_pair: venues[index].market_pair.clone(),
+                size,
+                price,
+                cost,
+            });
+        }
+
+        if !too_small {
+            let mut plan = RoutePlan {
+                allocations,
+                unfilled: Decimal::zero(),
+            };
+            plan.unfilled = parent.size - plan.size();
+            return plan;
+        }
+    }
+}
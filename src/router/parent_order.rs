@@ -0,0 +1,29 @@
+use rust_decimal::prelude::Decimal;
+use crate::model::Side;
+use super::RoutingPolicy;
+
+/// This struct represents an order to split across venues, no child order is sent beyond
+/// `limit_price`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParentOrder {
+    pub side: Side,
+    pub size: Decimal,
+    pub limit_price: Decimal,
+    pub policy: RoutingPolicy,
+}
+
+impl ParentOrder {
+    pub fn new(side: Side, size: Decimal, limit_price: Decimal) -> Self {
+        Self {
+            side,
+            size,
+            limit_price,
+            policy: RoutingPolicy::default(),
+        }
+    }
+
+    pub fn with_policy(mut self, policy: RoutingPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+}
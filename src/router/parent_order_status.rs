@@ -0,0 +1,52 @@
+use rust_decimal::prelude::{Decimal, Zero};
+use crate::model::OrderStatus;
+use super::{ChildOrder, ParentOrder};
+
+/// This struct represents the execution of a parent order, aggregated from its child orders.
+#[derive(Clone, Debug)]
+pub struct ParentOrderStatus {
+    pub parent: ParentOrder,
+    pub filled: Decimal,
+    pub average_price: Option<Decimal>,
+    pub status: OrderStatus,
+    pub children: Vec<ChildOrder>,
+}
+
+impl ParentOrderStatus {
+    pub fn new(parent: &ParentOrder, children: Vec<ChildOrder>) -> Self {
+        let filled = children
+            .iter()
+            .fold(Decimal::zero(), |filled, child| filled + child.filled());
+        let notional = children
+            .iter()
+            .fold(Decimal::zero(), |notional, child| notional + child.notional());
+        let average_price = match filled.is_zero() {
+            true => None,
+            false => Some(notional / filled),
+        };
+        let open = children.iter().any(ChildOrder::is_open);
+        let status = if filled >= parent.size {
+            OrderStatus::Filled
+        } else if !filled.is_zero() {
+            OrderStatus::PartiallyFilled
+        } else if open {
+            OrderStatus::New
+        } else if children.iter().all(|child| child.order.is_none()) {
+            OrderStatus::Rejected
+        } else {
+            OrderStatus::Expired
+        };
+
+        Self {
+            parent: parent.clone(),
+            filled,
+            average_price,
+            status,
+            children,
+        }
+    }
+
+    pub fn remaining(&self) -> Decimal {
+        (self.parent.size - self.filled).max(Decimal::zero())
+    }
+}
@@ -0,0 +1,45 @@
+use rust_decimal::prelude::{Decimal, Zero};
+use serde::{Deserialize, Serialize};
+
+/// This struct represents the part of a parent order sent to a venue. `price` is the worst
+/// level taken, the limit of the child order, and `cost` is the quote amount including fees.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RouteAllocation {
+    pub venue: String,
+    pub market_pair: String,
+    pub size: Decimal,
+    pub price: Decimal,
+    pub cost: Decimal,
+}
+
+impl RouteAllocation {
+    /// Average price including fees
+    pub fn effective_price(&self) -> Decimal {
+        match self.size.is_zero() {
+            true => Decimal::zero(),
+            false => self.cost / self.size,
+        }
+    }
+}
+
+/// This struct represents how a parent order is split, `unfilled` is the size the books
+/// can't fill within the limit price and the venue minimums.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RoutePlan {
+    pub allocations: Vec<RouteAllocation>,
+    pub unfilled: Decimal,
+}
+
+impl RoutePlan {
+    pub fn size(&self) -> Decimal {
+        self.allocations
+            .iter()
+            .fold(Decimal::zero(), |size, allocation| size + allocation.size)
+    }
+
+    pub fn cost(&self) -> Decimal {
+        self.allocations
+            .iter()
+            .fold(Decimal::zero(), |cost, allocation| cost + allocation.cost)
+    }
+}
@@ -0,0 +1,26 @@
+use rust_decimal::prelude::{Decimal, Zero};
+
+/// This struct represents a venue the router can send child orders to: the exchange client,
+/// the symbol of the market on this exchange and the taker fee, as a fraction.
+pub struct RouterVenue<'a, E> {
+    pub name: String,
+    pub exchange: &'a E,
+    pub market_pair: String,
+    pub taker_fee: Decimal,
+}
+
+impl<'a, E> RouterVenue<'a, E> {
+    pub fn new(name: &str, exchange: &'a E, market_pair: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            exchange,
+            market_pair: market_pair.to_string(),
+            taker_fee: Decimal::zero(),
+        }
+    }
+
+    pub fn with_taker_fee(mut self, taker_fee: Decimal) -> Self {
+        self.taker_fee = taker_fee;
+        self
+    }
+}
@@ -0,0 +1,14 @@
+/// This enum represents how a parent order takes the liquidity of the venues.
+/// `SweepToFill` walks the books of every venue up to the limit price, `BestPriceOnly` only
+/// takes the levels quoted at the best price across venues.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RoutingPolicy {
+    SweepToFill,
+    BestPriceOnly,
+}
+
+impl Default for RoutingPolicy {
+    fn default() -> Self {
+        Self::SweepToFill
+    }
+}
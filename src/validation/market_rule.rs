@@ -0,0 +1,63 @@
+use std::fmt;
+use crate::errors::OpenLimitsError;
+
+/// This enum represents a trading rule of a market, named after the binance filter it comes
+/// from. The errors of a violated rule are `InvalidParameter` errors starting with its name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MarketRule {
+    LotSize,
+    MarketLotSize,
+    PriceFilter,
+    MinNotional,
+    MaxNotional,
+    PercentPrice,
+    MaxNumOrders,
+    IcebergParts,
+}
+
+impl MarketRule {
+    pub const ALL: [MarketRule; 8] = [
+        MarketRule::LotSize,
+        MarketRule::MarketLotSize,
+        MarketRule::PriceFilter,
+        MarketRule::MinNotional,
+        MarketRule::MaxNotional,
+        MarketRule::PercentPrice,
+        MarketRule::MaxNumOrders,
+        MarketRule::IcebergParts,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MarketRule::LotSize => "LOT_SIZE",
+            MarketRule::MarketLotSize => "MARKET_LOT_SIZE",
+            MarketRule::PriceFilter => "PRICE_FILTER",
+            MarketRule::MinNotional => "MIN_NOTIONAL",
+            MarketRule::MaxNotional => "MAX_NOTIONAL",
+            MarketRule::PercentPrice => "PERCENT_PRICE",
+            MarketRule::MaxNumOrders => "MAX_NUM_ORDERS",
+            MarketRule::IcebergParts => "ICEBERG_PARTS",
+        }
+    }
+
+    /// The error of a violation of this rule
+    pub fn violation(&self, message: String) -> OpenLimitsError {
+        OpenLimitsError::InvalidParameter(format!("{}: {}", self.name(), message))
+    }
+
+    /// The rule an error is the violation of
+    pub fn violated_by(error: &OpenLimitsError) -> Option<MarketRule> {
+        let message = match error {
+            OpenLimitsError::InvalidParameter(message) => message,
+            _ => return None,
+        };
+        let name = message.split(':').next()?;
+        MarketRule::ALL.iter().copied().find(|rule| rule.name() == name)
+    }
+}
+
+impl fmt::Display for MarketRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
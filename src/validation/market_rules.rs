@@ -0,0 +1,115 @@
+use rust_decimal::prelude::{Decimal, Zero};
+use crate::exchange::traits::info::MarketPair;
+
+/// This struct represents the bounds and the increment of a size or a price, every part is
+/// optional.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StepRange {
+    pub min: Option<Decimal>,
+    pub max: Option<Decimal>,
+    pub step: Option<Decimal>,
+}
+
+impl StepRange {
+    /// Zero values mean the bound or the increment isn't checked, as in binance filters
+    pub fn new(min: Decimal, max: Decimal, step: Decimal) -> Self {
+        let non_zero = |value: Decimal| Some(value).filter(|value| !value.is_zero());
+        Self {
+            min: non_zero(min),
+            max: non_zero(max),
+            step: non_zero(step),
+        }
+    }
+}
+
+/// This struct represents the limits a price can be from the average price of the market,
+/// as multipliers of it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PercentPrice {
+    pub multiplier_up: Decimal,
+    pub multiplier_down: Decimal,
+}
+
+/// This struct represents the trading rules of a market. Rules without a value aren't
+/// checked.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MarketRules {
+    pub lot_size: Option<StepRange>,
+    pub market_lot_size: Option<StepRange>,
+    pub price: Option<StepRange>,
+    pub min_notional: Option<Decimal>,
+    pub max_notional: Option<Decimal>,
+    pub percent_price: Option<PercentPrice>,
+    pub max_num_orders: Option<u64>,
+    pub iceberg_parts: Option<u64>,
+}
+
+impl MarketRules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_lot_size(mut self, lot_size: StepRange) -> Self {
+        self.lot_size = Some(lot_size);
+        self
+    }
+
+    pub fn with_market_lot_size(mut self, market_lot_size: StepRange) -> Self {
+        self.market_lot_size = Some(market_lot_size);
+        self
+    }
+
+    pub fn with_price(mut self, price: StepRange) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn with_min_notional(mut self, min_notional: Decimal) -> Self {
+        self.min_notional = Some(min_notional);
+        self
+    }
+
+    pub fn with_max_notional(mut self, max_notional: Decimal) -> Self {
+        self.max_notional = Some(max_notional);
+        self
+    }
+
+    pub fn with_percent_price(mut self, multiplier_up: Decimal, multiplier_down: Decimal) -> Self {
+        self.percent_price = Some(PercentPrice {
+            multiplier_up,
+            multiplier_down,
+        });
+        self
+    }
+
+    pub fn with_max_num_orders(mut self, max_num_orders: u64) -> Self {
+        self.max_num_orders = Some(max_num_orders);
+        self
+    }
+
+    pub fn with_iceberg_parts(mut self, iceberg_parts: u64) -> Self {
+        self.iceberg_parts = Some(iceberg_parts);
+        self
+    }
+}
+
+impl From<&MarketPair> for MarketRules {
+    fn from(pair: &MarketPair) -> Self {
+        let non_zero = |value: Decimal| Some(value).filter(|value| !value.is_zero());
+        MarketRules {
+            lot_size: Some(StepRange {
+                min: pair.min_base_trade_size,
+                max: pair.max_base_trade_size,
+                step: non_zero(pair.base_increment),
+            }),
+            price: Some(StepRange {
+                min: pair.min_price,
+                max: pair.max_price,
+                step: non_zero(pair.quote_increment),
+            }),
+            min_notional: pair.min_quote_trade_size,
+            max_notional: pair.max_quote_trade_size,
+            ..Default::default()
+        }
+    }
+}
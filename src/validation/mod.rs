@@ -0,0 +1,45 @@
+//! This module provides the validation of orders against the trading rules of a market
+//! before they're sent: the increments and bounds of sizes and prices, the notional, the
+//! distance of the price from the average price, the number of open orders and the parts of
+//! iceberg orders. A violated rule is reported as an `InvalidParameter` error naming it, and
+//! sizes and prices off their increment are rounded only when configured to.
+//! # Example
+//! ```no_run
+//! use openlimits::model::{OpenLimitOrderRequest, TimeInForce};
+//! use openlimits::validation::{
+//!     MarketRule, MarketRules, OrderContext, OrderValidator, Rounding, StepRange,
+//! };
+//! use rust_decimal::Decimal;
+//!
+//! let lot_size = StepRange::new(Decimal::new(1, 3), Decimal::new(1000, 0), Decimal::new(1, 3));
+//! let rules = MarketRules::new()
+//!     .with_lot_size(lot_size)
+//!     .with_min_notional(Decimal::new(10, 0));
+//! let validator = OrderValidator::new(rules).with_rounding(Rounding::Down);
+//! let req = OpenLimitOrderRequest {
+//!     market_pair: "BTCUSDT".to_string(),
+//!     size: Decimal::new(12345, 7),
+//!     price: Decimal::new(20000, 0),
+//!     time_in_force: TimeInForce::GoodTillCancelled,
+//!     post_only: false,
+//! };
+//!
+//! match validator.validate_limit(&req, &OrderContext::new()) {
+//!     Ok(req) => println!("Sending {:?}", req),
+//!     Err(err) => println!("{:?} violated: {:?}", MarketRule::violated_by(&err), err),
+//! }
+//! ```
+
+mod market_rule;
+mod market_rules;
+mod order_context;
+mod order_validator;
+mod rounding;
+mod validation_config;
+
+pub use market_rule::MarketRule;
+pub use market_rules::{MarketRules, PercentPrice, StepRange};
+pub use order_context::OrderContext;
+pub use order_validator::OrderValidator;
+pub use rounding::Rounding;
+pub use validation_config::ValidationConfig;
@@ -0,0 +1,34 @@
+use rust_decimal::prelude::Decimal;
+
+/// This struct represents the state of the market and of the account an order is checked
+/// against. The rules depending on a value that isn't known are skipped: `PERCENT_PRICE`
+/// needs the average price, `MAX_NUM_ORDERS` the number of open orders and `ICEBERG_PARTS`
+/// the visible size of an iceberg order. The average price is also used as the price of
+/// market orders for the notional rules.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OrderContext {
+    pub average_price: Option<Decimal>,
+    pub open_orders: Option<u64>,
+    pub iceberg_qty: Option<Decimal>,
+}
+
+impl OrderContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_average_price(mut self, average_price: Decimal) -> Self {
+        self.average_price = Some(average_price);
+        self
+    }
+
+    pub fn with_open_orders(mut self, open_orders: u64) -> Self {
+        self.open_orders = Some(open_orders);
+        self
+    }
+
+    pub fn with_iceberg_qty(mut self, iceberg_qty: Decimal) -> Self {
+        self.iceberg_qty = Some(iceberg_qty);
+        self
+    }
+}
@@ -0,0 +1,198 @@
+use rust_decimal::prelude::{Decimal, Zero};
+use crate::exchange::shared::Result;
+use crate::model::{OpenLimitOrderRequest, OpenMarketOrderRequest};
+use super::{MarketRule, MarketRules, OrderContext, Rounding, StepRange};
+
+/// This struct represents the validator of the orders of one market.
+/// Sizes and prices that aren't multiples of their increment are rounded as configured, then
+/// the request is checked against every rule. The request returned is the one to send.
+#[derive(Clone, Debug, Default)]
+pub struct OrderValidator {
+    pub rules: MarketRules,
+    pub rounding: Rounding,
+}
+
+impl OrderValidator {
+    pub fn new(rules: MarketRules) -> Self {
+        Self {
+            rules,
+            rounding: Rounding::default(),
+        }
+    }
+
+    pub fn with_rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    pub fn validate_limit(
+        &self,
+        req: &OpenLimitOrderRequest,
+        context: &OrderContext,
+    ) -> Result<OpenLimitOrderRequest> {
+        let size = self.step(MarketRule::LotSize, self.rules.lot_size.as_ref(), "size", req.size)?;
+        let price = self.step(
+            MarketRule::PriceFilter,
+            self.rules.price.as_ref(),
+            "price",
+            req.price,
+        )?;
+        self.check_notional(size * price)?;
+        self.check_percent_price(price, context)?;
+        self.check_open_orders(context)?;
+        self.check_iceberg_parts(size, context)?;
+
+        Ok(OpenLimitOrderRequest {
+            size,
+            price,
+            ..req.clone()
+        })
+    }
+
+    /// The notional rules are only checked when the context has the average price
+    pub fn validate_market(
+        &self,
+        req: &OpenMarketOrderRequest,
+        context: &OrderContext,
+    ) -> Result<OpenMarketOrderRequest> {
+        let size = self.step(MarketRule::LotSize, self.rules.lot_size.as_ref(), "size", req.size)?;
+        let size = self.step(
+            MarketRule::MarketLotSize,
+            self.rules.market_lot_size.as_ref(),
+            "size",
+            size,
+        )?;
+        if let Some(average_price) = context.average_price {
+            self.check_notional(size * average_price)?;
+        }
+        self.check_open_orders(context)?;
+
+        Ok(OpenMarketOrderRequest {
+            size,
+            ..req.clone()
+        })
+    }
+
+    fn step(
+        &self,
+        rule: MarketRule,
+        range: Option<&StepRange>,
+        name: &str,
+        value: Decimal,
+    ) -> Result<Decimal> {
+        if value <= Decimal::zero() {
+            return Err(rule.violation(format!("{} {} isn't positive", name, value)));
+        }
+        let range = match range {
+            Some(range) => range,
+            None => return Ok(value),
+        };
+        let value = match range.step {
+            Some(step) => self.round(rule, name, value, range.min, step)?,
+            None => value,
+        };
+        if value <= Decimal::zero() {
+            return Err(rule.violation(format!("{} {} isn't positive once rounded", name, value)));
+        }
+        if let Some(min) = range.min.filter(|min| value < *min) {
+            return Err(rule.violation(format!("{} {} is below the minimum {}", name, value, min)));
+        }
+        if let Some(max) = range.max.filter(|max| value > *max) {
+            return Err(rule.violation(format!("{} {} is above the maximum {}", name, value, max)));
+        }
+        Ok(value)
+    }
+
+    /// Valid values are the minimum plus a multiple of the step, as with the filters of Binance
+    fn round(
+        &self,
+        rule: MarketRule,
+        name: &str,
+        value: Decimal,
+        min: Option<Decimal>,
+        step: Decimal,
+    ) -> Result<Decimal> {
+        let offset = min.unwrap_or_else(Decimal::zero);
+        let steps = (value - offset) / step;
+        if steps.fract().is_zero() {
+            return Ok(value);
+        }
+        let steps = match self.rounding {
+            Rounding::Reject => {
+                return Err(rule.violation(format!(
+                    "{} {} isn't a multiple of {}",
+                    name, value, step
+                )))
+            }
+            Rounding::Down => steps.floor(),
+            Rounding::Up => steps.ceil(),
+        };
+        Ok((offset + steps * step).normalize())
+    }
+
+    fn check_notional(&self, notional: Decimal) -> Result<()> {
+        if let Some(min) = self.rules.min_notional.filter(|min| notional < *min) {
+            return Err(MarketRule::MinNotional.violation(format!(
+                "notional {} is below the minimum {}",
+                notional, min
+            )));
+        }
+        if let Some(max) = self.rules.max_notional.filter(|max| notional > *max) {
+            return Err(MarketRule::MaxNotional.violation(format!(
+                "notional {} is above the maximum {}",
+                notional, max
+            )));
+        }
+        Ok(())
+    }
+
+    fn check_percent_price(&self, price: Decimal, context: &OrderContext) -> Result<()> {
+        let (percent_price, average_price) =
+            match (self.rules.percent_price, context.average_price) {
+                (Some(percent_price), Some(average_price)) => (percent_price, average_price),
+                _ => return Ok(()),
+            };
+        let up = average_price * percent_price.multiplier_up;
+        let down = average_price * percent_price.multiplier_down;
+        if price > up || price < down {
+            return Err(MarketRule::PercentPrice.violation(format!(
+                "price {} is outside of {} - {}",
+                price, down, up
+            )));
+        }
+        Ok(())
+    }
+
+    fn check_open_orders(&self, context: &OrderContext) -> Result<()> {
+        match (self.rules.max_num_orders, context.open_orders) {
+            (Some(max), Some(open_orders)) if open_orders >= max => {
+                Err(MarketRule::MaxNumOrders.violation(format!(
+                    "{} orders are already open, the maximum is {}",
+                    open_orders, max
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn check_iceberg_parts(&self, size: Decimal, context: &OrderContext) -> Result<()> {
+        let (limit, iceberg_qty) = match (self.rules.iceberg_parts, context.iceberg_qty) {
+            (Some(limit), Some(iceberg_qty)) => (limit, iceberg_qty),
+            _ => return Ok(()),
+        };
+        if iceberg_qty <= Decimal::zero() {
+            return Err(MarketRule::IcebergParts.violation(format!(
+                "iceberg size {} isn't positive",
+                iceberg_qty
+            )));
+        }
+        let parts = (size / iceberg_qty).ceil();
+        if parts > Decimal::from(limit) {
+            return Err(MarketRule::IcebergParts.violation(format!(
+                "{} parts of {} are more than {}",
+                parts, iceberg_qty, limit
+            )));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+/// This enum represents what's done with a size or a price that isn't a multiple of the
+/// increment of its market: the order is rejected, or the value is rounded down or up to the
+/// closest multiple.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rounding {
+    Reject,
+    Down,
+    Up,
+}
+
+impl Default for Rounding {
+    fn default() -> Self {
+        Self::Reject
+    }
+}
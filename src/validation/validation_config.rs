@@ -0,0 +1,26 @@
+use super::Rounding;
+
+/// This struct represents how an exchange client validates orders before sending them.
+/// With `market_state` the average price and the open orders of the market are read before
+/// every order, so the rules depending on them are checked too.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ValidationConfig {
+    pub rounding: Rounding,
+    pub market_state: bool,
+}
+
+impl ValidationConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    pub fn with_market_state(mut self, market_state: bool) -> Self {
+        self.market_state = market_state;
+        self
+    }
+}
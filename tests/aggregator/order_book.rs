@@ -0,0 +1,332 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt};
+use openlimits::{
+    aggregator::{AggregatedBook, AggregatorVenue, OrderBookAggregator},
+    errors::OpenLimitsError,
+    exchange::shared::Result,
+    exchange::simulated::{
+        ManualClock, SimulatedExchange, SimulatedParameters, SimulatedVenue, SimulatedWebsocket,
+    },
+    exchange::traits::stream::Subscriptions,
+    model::websocket::{OpenLimitsWebSocketMessage, Subscription},
+    model::{
+        AskBid, Candle, GetHistoricRatesRequest, GetHistoricTradesRequest, GetPriceTickerRequest,
+        OpenLimitOrderRequest, OrderBookResponse, Ticker, TimeInForce, Trade,
+    },
+    prelude::*,
+};
+use rust_decimal::prelude::Decimal;
+
+fn level(price: i64, qty: i64) -> AskBid {
+    AskBid::new(Decimal::new(price, 0), Decimal::new(qty, 0))
+}
+
+fn book(bids: Vec<AskBid>, asks: Vec<AskBid>) -> OrderBookResponse {
+    OrderBookResponse {
+        market_pair: None,
+        instrument: None,
+        update_id: None,
+        last_update_id: None,
+        bids,
+        asks,
+    }
+}
+
+fn snapshot(bids: Vec<AskBid>, asks: Vec<AskBid>) -> OpenLimitsWebSocketMessage {
+    OpenLimitsWebSocketMessage::OrderBook(book(bids, asks))
+}
+
+fn diff(bids: Vec<AskBid>, asks: Vec<AskBid>) -> OpenLimitsWebSocketMessage {
+    OpenLimitsWebSocketMessage::OrderBookDiff(book(bids, asks))
+}
+
+/// An update covering the ids from `first` to `last`
+fn sequenced(first: u64, last: u64, bids: Vec<AskBid>) -> OpenLimitsWebSocketMessage {
+    OpenLimitsWebSocketMessage::OrderBook(OrderBookResponse {
+        market_pair: None,
+        instrument: None,
+        update_id: Some(last),
+        last_update_id: Some(first),
+        bids,
+        asks: vec![],
+    })
+}
+
+fn rest_snapshot(id: u64, bids: Vec<AskBid>) -> OrderBookResponse {
+    OrderBookResponse {
+        market_pair: None,
+        instrument: None,
+        update_id: Some(id),
+        last_update_id: None,
+        bids,
+        asks: vec![],
+    }
+}
+
+#[test]
+fn consolidated_levels() {
+    let mut aggregated = AggregatedBook::new();
+    let binance = snapshot(vec![level(99, 1), level(98, 2)], vec![level(101, 1)]);
+    let coinbase = snapshot(vec![level(100, 3)], vec![level(102, 1), level(103, 4)]);
+    aggregated.update("binance", &binance, true);
+    aggregated.update("coinbase", &coinbase, false);
+
+    let best_bid = aggregated.best_bid().expect("No bid");
+    assert_eq!((best_bid.venue.as_str(), best_bid.price), ("coinbase", Decimal::new(100, 0)));
+    let best_ask = aggregated.best_ask().expect("No ask");
+    assert_eq!((best_ask.venue.as_str(), best_ask.price), ("binance", Decimal::new(101, 0)));
+
+    let bids: Vec<(String, Decimal)> = aggregated
+        .bids()
+        .into_iter()
+        .map(|level| (level.venue, level.price))
+        .collect();
+    assert_eq!(
+        bids,
+        vec![
+            (String::from("coinbase"), Decimal::new(100, 0)),
+            (String::from("binance"), Decimal::new(99, 0)),
+            (String::from("binance"), Decimal::new(98, 0)),
+        ]
+    );
+    assert_eq!(aggregated.bid_depth(), Decimal::new(6, 0));
+    assert_eq!(aggregated.ask_depth(), Decimal::new(6, 0));
+    assert_eq!(aggregated.bid_depth_at(Decimal::new(99, 0)), Decimal::new(4, 0));
+    assert_eq!(aggregated.ask_depth_at(Decimal::new(102, 0)), Decimal::new(2, 0));
+    assert!(!aggregated.is_crossed());
+}
+
+#[test]
+fn updates() {
+    let mut aggregated = AggregatedBook::new();
+    aggregated.update("coinbase", &snapshot(vec![level(100, 3)], vec![level(102, 1)]), false);
+    aggregated.update("coinbase", &diff(vec![level(100, 0), level(99, 1)], vec![]), false);
+    // Snapshots replace the book
+    aggregated.update("nash", &snapshot(vec![level(97, 1)], vec![level(105, 1)]), false);
+    aggregated.update("nash", &snapshot(vec![level(96, 1)], vec![]), false);
+    // Unless the venue sends its updates as snapshots
+    aggregated.update("binance", &snapshot(vec![level(95, 1)], vec![level(104, 1)]), true);
+    aggregated.update("binance", &snapshot(vec![level(94, 1)], vec![level(104, 0)]), true);
+
+    let coinbase = &aggregated.venues["coinbase"];
+    assert_eq!(coinbase.best_bid(), Some(level(99, 1)));
+    let nash = &aggregated.venues["nash"];
+    assert_eq!(nash.best_ask(), None);
+    assert_eq!(nash.best_bid(), Some(level(96, 1)));
+    let binance = &aggregated.venues["binance"];
+    assert_eq!(binance.bids.len(), 2);
+    assert!(binance.asks.is_empty());
+}
+
+#[test]
+fn sequenced_updates() {
+    let mut aggregated = AggregatedBook::new();
+    aggregated.replace("binance", &rest_snapshot(10, vec![level(99, 1), level(98, 1)]));
+
+    // Already in the snapshot
+    assert!(aggregated.update("binance", &sequenced(8, 10, vec![level(99, 5)]), true));
+    assert!(aggregated.update("binance", &sequenced(9, 12, vec![level(97, 1)]), true));
+    assert!(aggregated.update("binance", &sequenced(13, 13, vec![level(98, 0)]), true));
+    let binance = &aggregated.venues["binance"];
+    assert_eq!(binance.best_bid(), Some(level(99, 1)));
+    assert_eq!(binance.bids.len(), 2);
+    assert_eq!(binance.update_id, Some(13));
+
+    // A missed update leaves the venue out until it's replaced
+    aggregated.update("coinbase", &snapshot(vec![level(90, 1)], vec![]), false);
+    assert!(!aggregated.update("binance", &sequenced(15, 16, vec![level(100, 1)]), true));
+    assert!(!aggregated.update("binance", &sequenced(17, 17, vec![level(100, 1)]), true));
+    assert!(aggregated.venues["binance"].stale);
+    assert_eq!(aggregated.best_bid().map(|bid| bid.venue), Some(String::from("coinbase")));
+    assert_eq!(aggregated.bid_depth(), Decimal::new(1, 0));
+
+    aggregated.replace("binance", &rest_snapshot(17, vec![level(100, 1)]));
+    assert_eq!(aggregated.best_bid().map(|bid| bid.venue), Some(String::from("binance")));
+    aggregated.mark_stale("coinbase");
+    assert_eq!(aggregated.bids().len(), 1);
+}
+
+#[test]
+fn crossed_markets() {
+    let mut aggregated = AggregatedBook::new();
+    // A venue crossing its own book isn't an arbitrage
+    aggregated.update("binance", &snapshot(vec![level(103, 1)], vec![level(101, 2)]), false);
+    assert!(!aggregated.is_crossed());
+
+    aggregated.update("coinbase", &snapshot(vec![level(102, 5)], vec![level(104, 1)]), false);
+    aggregated.update("nash", &snapshot(vec![level(99, 1)], vec![level(100, 3)]), false);
+
+    let crossed = aggregated.crossed().expect("Market should be crossed");
+    assert_eq!(crossed.bid.venue, "binance");
+    assert_eq!(crossed.ask.venue, "nash");
+    assert_eq!(crossed.spread(), Decimal::new(3, 0));
+    assert_eq!(crossed.qty(), Decimal::new(1, 0));
+}
+
+async fn venue() -> (SimulatedExchange, SimulatedWebsocket) {
+    let venue = SimulatedVenue::new(vec![MarketPair {
+        base: String::from("BTC"),
+        quote: String::from("USD"),
+        symbol: String::from("BTCUSD"),
+        base_increment: Decimal::new(1, 3),
+        quote_increment: Decimal::new(1, 2),
+        min_base_trade_size: None,
+        min_quote_trade_size: None,
+        ..Default::default()
+    }])
+    .with_clock(Arc::new(ManualClock::new(0)));
+    let parameters = SimulatedParameters::new(venue)
+        .with_balance("BTC", Decimal::new(10, 0))
+        .with_balance("USD", Decimal::new(10000, 0));
+
+    let exchange = SimulatedExchange::new(parameters.clone())
+        .await
+        .expect("Couldn't create simulated exchange.");
+    let websocket = SimulatedWebsocket::new(parameters)
+        .await
+        .expect("Failed to create Client");
+    (exchange, websocket)
+}
+
+fn limit(price: i64) -> OpenLimitOrderRequest {
+    OpenLimitOrderRequest {
+        market_pair: String::from("BTCUSD"),
+        size: Decimal::new(1, 0),
+        price: Decimal::new(price, 0),
+        time_in_force: TimeInForce::GoodTillCancelled,
+        post_only: false,
+    }
+}
+
+#[tokio::test]
+async fn aggregates_streams() {
+    let (first, first_websocket) = venue().await;
+    let (second, second_websocket) = venue().await;
+    let venues = [
+        AggregatorVenue::new("first", &first_websocket, "BTCUSD"),
+        AggregatorVenue::new("second", &second_websocket, "BTCUSD"),
+    ];
+    let aggregator = OrderBookAggregator::new();
+
+    let (result, _) = tokio::join!(aggregator.run(&venues), async {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        first.limit_buy(&limit(101)).await.expect("Couldn't place buy order");
+        second.limit_sell(&limit(100)).await.expect("Couldn't place sell order");
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let crossed = aggregator
+            .crossed()
+            .expect("Couldn't read book")
+            .expect("Market should be crossed");
+        assert_eq!(crossed.bid.venue, "first");
+        assert_eq!(crossed.ask.venue, "second");
+        assert_eq!(crossed.spread(), Decimal::new(1, 0));
+
+        first_websocket.disconnect().await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let book = aggregator.book().expect("Couldn't read book");
+        assert!(!book.venues.contains_key("first"));
+        let best_ask = aggregator.best_ask().expect("Couldn't read book");
+        assert_eq!(best_ask.map(|ask| ask.venue), Some(String::from("second")));
+
+        second_websocket.disconnect().await;
+    });
+    result.expect("Aggregation failed");
+    assert!(aggregator.book().expect("Couldn't read book").venues.is_empty());
+}
+
+/// A websocket streaming a fixed list of messages, then nothing
+#[derive(Default)]
+struct Scripted {
+    messages: Mutex<Vec<Result<OpenLimitsWebSocketMessage>>>,
+}
+
+#[async_trait]
+impl ExchangeWs for Scripted {
+    type InitParams = ();
+    type Subscription = Subscription;
+    type Response = OpenLimitsWebSocketMessage;
+
+    async fn new(_: Self::InitParams) -> Result<Self> {
+        Ok(Self::default())
+    }
+
+    async fn disconnect(&self) {}
+
+    async fn create_stream_specific(
+        &self,
+        _subscriptions: Subscriptions<Self::Subscription>,
+    ) -> Result<BoxStream<'static, Result<Self::Response>>> {
+        let messages = std::mem::take(&mut *self.messages.lock().expect("Couldn't lock"));
+        Ok(stream::iter(messages).chain(stream::pending()).boxed())
+    }
+}
+
+/// A REST api serving the snapshots of a list, the last one once the list is read
+struct Snapshots {
+    books: Vec<OrderBookResponse>,
+    reads: AtomicUsize,
+}
+
+#[async_trait]
+impl ExchangeMarketData for Snapshots {
+    async fn order_book(&self, _req: &OrderBookRequest) -> Result<OrderBookResponse> {
+        let read = self.reads.fetch_add(1, Ordering::SeqCst);
+        Ok(self.books[read.min(self.books.len() - 1)].clone())
+    }
+
+    async fn get_price_ticker(&self, _req: &GetPriceTickerRequest) -> Result<Ticker> {
+        Err(OpenLimitsError::WebSocketMessageNotSupported())
+    }
+
+    async fn get_historic_rates(&self, _req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
+        Err(OpenLimitsError::WebSocketMessageNotSupported())
+    }
+
+    async fn get_historic_trades(&self, _req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
+        Err(OpenLimitsError::WebSocketMessageNotSupported())
+    }
+}
+
+#[tokio::test]
+async fn seeded_and_resynced() {
+    let websocket = Scripted::default();
+    *websocket.messages.lock().expect("Couldn't lock") = vec![
+        Ok(sequenced(5, 10, vec![level(50, 1)])),
+        Ok(sequenced(11, 11, vec![level(97, 1)])),
+        // Missed 12
+        Ok(sequenced(13, 13, vec![level(96, 1)])),
+        Ok(sequenced(21, 21, vec![level(95, 1)])),
+        Err(OpenLimitsError::SocketError()),
+    ];
+    let snapshots = Snapshots {
+        books: vec![
+            rest_snapshot(10, vec![level(99, 1), level(98, 1)]),
+            rest_snapshot(20, vec![level(100, 1)]),
+            rest_snapshot(30, vec![level(101, 1)]),
+        ],
+        reads: AtomicUsize::new(0),
+    };
+    let venues = [AggregatorVenue::new("binance", &websocket, "BTCUSDT")
+        .with_incremental_books(true)
+        .with_snapshots(&snapshots)];
+    let aggregator = OrderBookAggregator::new();
+
+    tokio::select! {
+        result = aggregator.run(&venues) => panic!("Aggregation ended: {:?}", result.err()),
+        _ = tokio::time::sleep(Duration::from_millis(50)) => (),
+    }
+
+    // Seeded, then read again after the gap and after the error
+    assert_eq!(snapshots.reads.load(Ordering::SeqCst), 3);
+    let book = aggregator.book().expect("Couldn't read book");
+    let binance = &book.venues["binance"];
+    assert!(!binance.stale);
+    assert_eq!(binance.update_id, Some(30));
+    assert_eq!(binance.best_bid(), Some(level(101, 1)));
+    assert_eq!(binance.bids.len(), 1);
+}
@@ -5,7 +5,7 @@ use std::env;
 use openlimits::{
     exchange::binance::{
         model::{AllOrderReq, TimeInForce, TradeHistoryReq},
-        Binance, BinanceCredentials, BinanceParameters,
+        Binance, BinanceCredentials, BinanceEnvironment, BinanceParameters,
     },
     prelude::*,
 };
@@ -271,11 +271,14 @@ async fn trade_history() {
 async fn init() -> Binance {
     dotenv().ok();
     Binance::new(BinanceParameters {
-        sandbox: true,
+        environment: BinanceEnvironment::Testnet,
         credentials: Some(BinanceCredentials {
             api_key: env::var("BINANCE_API_KEY").expect("Couldn't get environment variable"),
             api_secret: env::var("BINANCE_API_SECRET").expect("Couldn't get environment variable"),
         }),
+        http: Default::default(),
+        validation: Default::default(),
+        exchange_info_cache: None,
     })
     .await
     .expect("Failed to create Client")
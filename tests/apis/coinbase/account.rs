@@ -417,6 +417,8 @@ async fn init() -> Coinbase {
             passphrase: env::var("COINBASE_PASSPHRASE")
                 .expect("Couldn't get environment varilable."),
         }),
+        http: Default::default(),
+        exchange_info_cache: None,
     })
     .await
     .expect("Failed to create Client")
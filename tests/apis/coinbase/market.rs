@@ -57,7 +57,7 @@ async fn trades() {
         .trades(
             "BTC-USD",
             Some(&Paginator {
-                after: Some(trade.trade_id),
+                after: Some(trade.trade_id.to_string()),
                 limit: Some(10),
                 before: None,
             }),
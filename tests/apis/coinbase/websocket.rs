@@ -57,5 +57,7 @@ async fn init() -> CoinbaseWebsocket {
     CoinbaseWebsocket::new(CoinbaseParameters {
         sandbox: true,
         credentials: None,
+        http: Default::default(),
+        exchange_info_cache: None,
     })
 }
@@ -0,0 +1,331 @@
+use async_trait::async_trait;
+use openlimits::{
+    errors::OpenLimitsError,
+    backtest::{
+        Backtester, BacktestConfig, BacktestStatistics, EquityPoint, MarketEvent, Strategy,
+    },
+    exchange::shared::Result,
+    prelude::*,
+    model::{
+        AskBid, CancelAllOrdersRequest, Candle, Interval, Liquidity, OpenLimitOrderRequest,
+        OpenMarketOrderRequest, OrderStatus, Side, TimeInForce, Trade,
+    },
+};
+use rust_decimal::prelude::Decimal;
+
+fn config() -> BacktestConfig {
+    BacktestConfig::new(
+        vec![MarketPair {
+            base: String::from("BTC"),
+            quote: String::from("USD"),
+            symbol: String::from("BTCUSD"),
+            base_increment: Decimal::new(1, 3),
+            quote_increment: Decimal::new(1, 2),
+            min_base_trade_size: None,
+            min_quote_trade_size: None,
+            ..Default::default()
+        }],
+        "USD",
+    )
+    .with_balance("USD", Decimal::new(10000, 0))
+}
+
+fn candle(time: u64, close: i64) -> Candle {
+    Candle {
+        market_pair: None,
+        instrument: None,
+        time,
+        low: Decimal::new(close - 1, 0),
+        high: Decimal::new(close + 1, 0),
+        open: Decimal::new(close, 0),
+        close: Decimal::new(close, 0),
+        volume: Decimal::new(10, 0),
+    }
+}
+
+fn book(timestamp: u64) -> MarketEvent {
+    MarketEvent::Book {
+        symbol: String::from("BTCUSD"),
+        timestamp,
+        book: OrderBookResponse {
+            market_pair: None,
+            instrument: None,
+            update_id: None,
+            last_update_id: None,
+            bids: vec![AskBid {
+                price: Decimal::new(100, 0),
+                qty: Decimal::new(5, 0),
+            }],
+            asks: vec![AskBid {
+                price: Decimal::new(101, 0),
+                qty: Decimal::new(5, 0),
+            }],
+        },
+    }
+}
+
+/// A book asking `qty` tenths at 99, through the bid of `Bid`
+fn crossed_book(timestamp: u64, qty: i64) -> MarketEvent {
+    match book(timestamp) {
+        MarketEvent::Book { symbol, timestamp, mut book } => {
+            book.asks[0] = AskBid {
+                price: Decimal::new(99, 0),
+                qty: Decimal::new(qty, 1),
+            };
+            MarketEvent::Book { symbol, timestamp, book }
+        }
+        event => event,
+    }
+}
+
+fn trade(timestamp: u64, price: i64, qty: i64) -> MarketEvent {
+    MarketEvent::Trade {
+        timestamp,
+        trade: Trade {
+            id: timestamp.to_string(),
+            buyer_order_id: None,
+            seller_order_id: None,
+            market_pair: String::from("BTCUSD"),
+            instrument: None,
+            price: Decimal::new(price, 0),
+            qty: Decimal::new(qty, 0),
+            fees: None,
+            side: Side::Sell,
+            liquidity: None,
+            created_at: timestamp,
+        },
+    }
+}
+
+#[derive(Default)]
+struct BuyOnce {
+    done: bool,
+}
+
+#[async_trait]
+impl Strategy for BuyOnce {
+    async fn on_candle<A>(&mut self, account: &A, symbol: &str, _candle: &Candle) -> Result<()>
+    where
+        A: ExchangeAccount + Sync,
+    {
+        if !self.done {
+            self.done = true;
+            account
+                .market_buy(&OpenMarketOrderRequest {
+                    market_pair: symbol.to_string(),
+                    size: Decimal::new(1, 0),
+                })
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+/// Bids 1 at 100 on the first book, good till cancelled or until `expiry`, cancels on the
+/// first trade at 101 when asked to
+#[derive(Default)]
+struct Bid {
+    placed: bool,
+    cancel: bool,
+    expiry: Option<chrono::Duration>,
+    fills: usize,
+}
+
+#[async_trait]
+impl Strategy for Bid {
+    async fn on_book<A>(&mut self, account: &A, symbol: &str, _book: &OrderBookResponse) -> Result<()>
+    where
+        A: ExchangeAccount + Sync,
+    {
+        if !self.placed {
+            self.placed = true;
+            account
+                .limit_buy(&OpenLimitOrderRequest {
+                    market_pair: symbol.to_string(),
+                    size: Decimal::new(1, 0),
+                    price: Decimal::new(100, 0),
+                    time_in_force: self
+                        .expiry
+                        .map_or(TimeInForce::GoodTillCancelled, TimeInForce::GoodTillTime),
+                    post_only: false,
+                })
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn on_trade<A>(&mut self, account: &A, trade: &Trade) -> Result<()>
+    where
+        A: ExchangeAccount + Sync,
+    {
+        if self.cancel && trade.price == Decimal::new(101, 0) {
+            account
+                .cancel_all_orders(&CancelAllOrdersRequest { market_pair: None })
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn on_fill<A>(&mut self, _account: &A, _fill: &Trade) -> Result<()>
+    where
+        A: ExchangeAccount + Sync,
+    {
+        self.fills += 1;
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn candles_with_fees_and_slippage() {
+    let config = config()
+        .with_fees(Decimal::new(1, 3), Decimal::new(1, 3))
+        .with_slippage(Decimal::new(1, 2));
+    let events = MarketEvent::from_candles(
+        "BTCUSD",
+        Interval::OneMinute,
+        vec![candle(0, 100), candle(60000, 110), candle(120000, 90)],
+    );
+    let report = Backtester::new(config)
+        .run(&mut BuyOnce::default(), events)
+        .await
+        .expect("Backtest failed");
+
+    assert_eq!(report.fills.len(), 1);
+    let fill = &report.fills[0];
+    assert_eq!(fill.price, Decimal::new(101, 0));
+    assert_eq!(fill.fees, Some(Decimal::new(101, 3)));
+    assert_eq!(fill.liquidity, Some(Liquidity::Taker));
+    assert_eq!(fill.created_at, 60000);
+
+    let equity: Vec<Decimal> = report.equity.iter().map(|point| point.equity).collect();
+    assert_eq!(
+        equity,
+        vec![
+            Decimal::new(10000, 0),
+            Decimal::new(10008899, 3),
+            Decimal::new(9988899, 3)
+        ]
+    );
+    let statistics = &report.statistics;
+    assert_eq!(statistics.pnl, Decimal::new(-11101, 3));
+    assert_eq!(statistics.volume, Decimal::new(101, 0));
+    assert_eq!(statistics.turnover, Decimal::new(101, 4));
+    assert_eq!(statistics.fees, Decimal::new(101, 3));
+    assert_eq!(
+        statistics.max_drawdown,
+        Decimal::new(20, 0) / Decimal::new(10008899, 3)
+    );
+}
+
+#[tokio::test]
+async fn queue_position() {
+    let mut strategy = Bid::default();
+    let events = vec![book(0), trade(10, 100, 3), trade(20, 100, 3)];
+    let report = Backtester::new(config())
+        .run(&mut strategy, events)
+        .await
+        .expect("Backtest failed");
+
+    assert_eq!(strategy.fills, 1);
+    assert_eq!(report.fills.len(), 1);
+    assert_eq!(report.fills[0].price, Decimal::new(100, 0));
+    assert_eq!(report.fills[0].qty, Decimal::new(1, 0));
+    assert_eq!(report.fills[0].liquidity, Some(Liquidity::Maker));
+    assert_eq!(report.fills[0].created_at, 20);
+    assert_eq!(report.orders[0].status, OrderStatus::Filled);
+}
+
+#[tokio::test]
+async fn books_fill_with_the_same_levels_once() {
+    let events = vec![book(0), crossed_book(10, 5), crossed_book(20, 5), crossed_book(30, 8)];
+    let report = Backtester::new(config())
+        .run(&mut Bid::default(), events)
+        .await
+        .expect("Backtest failed");
+
+    assert_eq!(report.fills.len(), 2);
+    assert_eq!(report.fills[0].qty, Decimal::new(5, 1));
+    assert_eq!(report.fills[0].created_at, 10);
+    assert_eq!(report.fills[1].qty, Decimal::new(5, 1));
+    assert_eq!(report.fills[1].created_at, 30);
+    assert_eq!(report.orders[0].status, OrderStatus::Filled);
+}
+
+#[tokio::test]
+async fn latency() {
+    let events = vec![book(0), trade(50, 99, 10), trade(150, 99, 10)];
+    let report = Backtester::new(config().with_latency(100))
+        .run(&mut Bid::default(), events)
+        .await
+        .expect("Backtest failed");
+
+    assert_eq!(report.fills.len(), 1);
+    assert_eq!(report.fills[0].created_at, 150);
+    assert_eq!(report.fills[0].price, Decimal::new(100, 0));
+}
+
+#[tokio::test]
+async fn cancel_latency() {
+    let mut strategy = Bid {
+        cancel: true,
+        ..Default::default()
+    };
+    let events = vec![book(0), trade(120, 101, 1), trade(200, 99, 10)];
+    let report = Backtester::new(config().with_latency(100))
+        .run(&mut strategy, events)
+        .await
+        .expect("Backtest failed");
+    assert_eq!(report.fills.len(), 1);
+    assert_eq!(report.orders[0].status, OrderStatus::Filled);
+
+    let mut strategy = Bid {
+        cancel: true,
+        ..Default::default()
+    };
+    let events = vec![book(0), trade(120, 101, 1), trade(250, 99, 10)];
+    let report = Backtester::new(config().with_latency(100))
+        .run(&mut strategy, events)
+        .await
+        .expect("Backtest failed");
+    assert!(report.fills.is_empty());
+    assert_eq!(report.orders[0].status, OrderStatus::Canceled);
+    assert_eq!(report.balances[0].free, Decimal::new(10000, 0));
+}
+
+#[tokio::test]
+async fn good_till_time_needs_a_positive_duration() {
+    let mut strategy = Bid {
+        expiry: Some(chrono::Duration::milliseconds(-100)),
+        ..Default::default()
+    };
+    let result = Backtester::new(config())
+        .run(&mut strategy, vec![book(0), trade(50, 99, 10)])
+        .await;
+
+    assert!(matches!(result, Err(OpenLimitsError::InvalidParameter(_))));
+}
+
+#[test]
+fn sharpe_of_periodic_returns() {
+    let point = |timestamp: u64, equity: i64| EquityPoint {
+        timestamp,
+        equity: Decimal::new(equity, 1),
+    };
+    // The points within a period don't weigh on the returns
+    let equity = [
+        point(0, 1000),
+        point(10, 1050),
+        point(20, 1100),
+        point(1000, 1100),
+        point(1500, 1000),
+        point(2000, 990),
+        point(3000, 1089),
+        point(3500, 1200),
+    ];
+    let statistics =
+        BacktestStatistics::new(&equity, 1000, 0, Decimal::new(0, 0), Decimal::new(0, 0));
+
+    // Returns of 10%, -10% and 10% over periods of a second
+    let expected = (1.0 / 30.0) / (1.0f64 / 75.0).sqrt() * (365.0 * 24.0 * 60.0 * 60.0f64).sqrt();
+    assert!((statistics.sharpe - expected).abs() < 1e-6);
+}
@@ -5,6 +5,7 @@ use openlimits::{
     OpenLimits,
     exchange::binance::Binance,
     exchange::binance::BinanceCredentials,
+    exchange::binance::BinanceEnvironment,
     exchange::binance::BinanceParameters,
     prelude::*,
     model::{
@@ -241,7 +242,10 @@ async fn init() -> Binance {
             api_key: env::var("BINANCE_API_KEY").expect("Couldn't get environment variable."),
             api_secret: env::var("BINANCE_API_SECRET").expect("Couldn't get environment variable."),
         }),
-        sandbox: true,
+        environment: BinanceEnvironment::Testnet,
+        http: Default::default(),
+        validation: Default::default(),
+        exchange_info_cache: None,
     };
 
     OpenLimits::instantiate(parameters)
@@ -0,0 +1,68 @@
+use openlimits::exchange::binance::{
+    model::{Symbol, SymbolFilter},
+    BinanceEnvironment,
+};
+use openlimits::exchange::traits::info::{MarketPair, MarketStatus};
+use rust_decimal::prelude::Decimal;
+
+#[test]
+fn unknown_filters() {
+    // Binance.US symbol, its filters aren't the same as the global exchange ones
+    let symbol = r#"{
+        "symbol": "BTCUSD",
+        "status": "TRADING",
+        "baseAsset": "BTC",
+        "baseAssetPrecision": 8,
+        "quoteAsset": "USD",
+        "quotePrecision": 4,
+        "filters": [
+            {"filterType": "PRICE_FILTER", "minPrice": "0.01", "maxPrice": "100000.00", "tickSize": "0.01"},
+            {"filterType": "LOT_SIZE", "minQty": "0.000001", "maxQty": "9000.00", "stepSize": "0.000001"},
+            {"filterType": "TRAILING_DELTA", "minTrailingAboveDelta": 10, "maxTrailingAboveDelta": 2000},
+            {"filterType": "NOTIONAL", "minNotional": "1.00", "applyMinToMarket": true, "maxNotional": "9000000.00", "applyMaxToMarket": false, "avgPriceMins": 5}
+        ]
+    }"#;
+
+    let symbol: Symbol = serde_json::from_str(symbol).expect("Couldn't parse symbol.");
+
+    assert_eq!(symbol.filters.len(), 4);
+    assert!(matches!(symbol.filters[2], SymbolFilter::Unknown));
+    assert!(matches!(symbol.filters[3], SymbolFilter::Notional { .. }));
+}
+
+#[test]
+fn environment_urls() {
+    assert_eq!(BinanceEnvironment::Us.rest_url(), "https://api.binance.us");
+
+    let custom = BinanceEnvironment::Custom {
+        rest_url: "https://api.example.com".to_string(),
+        ws_url: "wss://stream.example.com/stream".to_string(),
+    };
+    assert_eq!(custom.rest_url(), "https://api.example.com");
+    assert_eq!(custom.ws_url(), "wss://stream.example.com/stream");
+    assert!(!custom.supports_cancel_all());
+}
+
+#[test]
+fn symbol_without_filters() {
+    // Binance compatible exchanges don't always set the lot size and price filters
+    let symbol = r#"{
+        "symbol": "BTCUSD",
+        "status": "TRADING",
+        "baseAsset": "BTC",
+        "baseAssetPrecision": 8,
+        "quoteAsset": "USD",
+        "quotePrecision": 4,
+        "filters": []
+    }"#;
+
+    let symbol: Symbol = serde_json::from_str(symbol).expect("Couldn't parse symbol.");
+    let pair = MarketPair::from(symbol);
+
+    assert_eq!(pair.symbol, "BTCUSD");
+    assert_eq!(pair.status, MarketStatus::Trading);
+    assert_eq!(pair.base_increment, Decimal::new(0, 0));
+    assert_eq!(pair.quote_increment, Decimal::new(0, 0));
+    assert_eq!(pair.min_base_trade_size, None);
+    assert_eq!(pair.min_price, None);
+}
@@ -0,0 +1,264 @@
+use std::fs;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use openlimits::{
+    OpenLimits,
+    errors::ErrorKind,
+    exchange::binance::Binance,
+    exchange::binance::BinanceParameters,
+    exchange::shared::Result,
+    exchange::traits::{HttpConfig, HttpTransport},
+    exchange::traits::info::{ExchangeInfoCache, MarketStatus},
+    model::OrderType,
+    prelude::*,
+};
+use rust_decimal::prelude::Decimal;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+const EXCHANGE_INFO: &str = r#"{
+    "timezone": "UTC",
+    "serverTime": 1565246363776,
+    "rateLimits": [],
+    "exchangeFilters": [],
+    "symbols": [{
+        "symbol": "BTCUSDT",
+        "status": "TRADING",
+        "baseAsset": "BTC",
+        "baseAssetPrecision": 8,
+        "quoteAsset": "USDT",
+        "quotePrecision": 8,
+        "orderTypes": ["LIMIT", "MARKET"],
+        "icebergAllowed": true,
+        "filters": [
+            {"filterType": "PRICE_FILTER", "minPrice": "0.01", "maxPrice": "1000000.00", "tickSize": "0.01"},
+            {"filterType": "LOT_SIZE", "minQty": "0.00001", "maxQty": "9000.00", "stepSize": "0.00001"},
+            {"filterType": "MIN_NOTIONAL", "minNotional": "10.00"}
+        ]
+    }]
+}"#;
+
+/// Counts the requests sent through the injected transport
+struct CountingTransport {
+    client: reqwest::Client,
+    requests: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl HttpTransport for CountingTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+        self.client.execute(request).await
+    }
+}
+
+/// Answers every request with the exchange information
+async fn serve() -> String {
+    serve_response("200 OK", EXCHANGE_INFO).await
+}
+
+/// Answers every request with `status` and `body`
+async fn serve_response(status: &'static str, body: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Couldn't bind local server.");
+    let address = listener.local_addr().expect("Couldn't get local address.");
+
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut buffer = [0; 4096];
+            socket.read(&mut buffer).await.ok();
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.ok();
+        }
+    });
+
+    format!("http://{}", address)
+}
+
+#[tokio::test]
+async fn local_server() {
+    let base_url = serve().await;
+    let requests = Arc::new(AtomicUsize::new(0));
+    let transport = CountingTransport {
+        client: reqwest::Client::new(),
+        requests: requests.clone(),
+    };
+
+    let parameters = BinanceParameters {
+        http: HttpConfig::default()
+            .with_transport(transport)
+            .with_base_url(&base_url)
+            .with_timeout(Duration::from_secs(5)),
+        ..BinanceParameters::prod()
+    };
+    let exchange: Binance = OpenLimits::instantiate(parameters)
+        .await
+        .expect("Failed to create Client");
+
+    exchange
+        .get_pair("BTCUSDT")
+        .await
+        .expect("Couldn't get pair.");
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn market_pair_rules() {
+    let base_url = serve().await;
+    let parameters = BinanceParameters {
+        http: HttpConfig::default()
+            .with_base_url(&base_url)
+            .with_timeout(Duration::from_secs(5)),
+        ..BinanceParameters::prod()
+    };
+    let exchange: Binance = OpenLimits::instantiate(parameters)
+        .await
+        .expect("Failed to create Client");
+
+    let pair = exchange
+        .get_pair("BTCUSDT")
+        .await
+        .expect("Couldn't get pair.")
+        .read()
+        .expect("Couldn't read pair.");
+    assert_eq!(pair.min_base_trade_size, Some(Decimal::new(1, 5)));
+    assert_eq!(pair.max_base_trade_size, Some(Decimal::new(9000, 0)));
+    assert_eq!(pair.min_quote_trade_size, Some(Decimal::new(10, 0)));
+    assert_eq!(pair.max_quote_trade_size, None);
+    assert_eq!(pair.min_price, Some(Decimal::new(1, 2)));
+    assert_eq!(pair.max_price, Some(Decimal::new(1000000, 0)));
+    assert_eq!(pair.status, MarketStatus::Trading);
+    assert_eq!(pair.order_types, vec![OrderType::Limit, OrderType::Market]);
+    assert_eq!(pair.base_precision, Some(8));
+    assert!(pair.is_trading());
+    assert!(!pair.supports(&OrderType::StopLimit));
+}
+
+#[tokio::test]
+async fn cached_exchange_info() {
+    let base_url = serve().await;
+    let path = std::env::temp_dir()
+        .join(format!("openlimits-binance-cache-{}", process::id()))
+        .join("exchange_info.json");
+    let cache = ExchangeInfoCache::new(&path, Duration::from_secs(60));
+
+    for expected_requests in [1, 0].iter() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let transport = CountingTransport {
+            client: reqwest::Client::new(),
+            requests: requests.clone(),
+        };
+        let parameters = BinanceParameters {
+            http: HttpConfig::default()
+                .with_transport(transport)
+                .with_base_url(&base_url)
+                .with_timeout(Duration::from_secs(5)),
+            ..BinanceParameters::prod()
+        }
+        .with_exchange_info_cache(cache.clone());
+        let exchange: Binance = OpenLimits::instantiate(parameters)
+            .await
+            .expect("Failed to create Client");
+
+        exchange
+            .get_pair("BTCUSDT")
+            .await
+            .expect("Couldn't get pair.");
+        exchange
+            .order_validator("BTCUSDT")
+            .expect("Couldn't get validator.");
+        assert_eq!(requests.load(Ordering::SeqCst), *expected_requests);
+    }
+
+    fs::remove_dir_all(path.parent().expect("Couldn't get cache directory.")).ok();
+}
+
+#[tokio::test]
+async fn lazy_exchange_info() {
+    let path = std::env::temp_dir()
+        .join(format!("openlimits-binance-lazy-{}", process::id()))
+        .join("exchange_info.json");
+    let cache = ExchangeInfoCache::new(&path, Duration::from_secs(60)).with_lazy(true);
+    // Nothing listens on the discard port, the exchange information can't be downloaded
+    let parameters = BinanceParameters {
+        http: HttpConfig::default()
+            .with_base_url("http://127.0.0.1:9")
+            .with_timeout(Duration::from_secs(1)),
+        ..BinanceParameters::prod()
+    }
+    .with_exchange_info_cache(cache);
+
+    let exchange: Binance = OpenLimits::instantiate(parameters.clone())
+        .await
+        .expect("Failed to create Client");
+    assert!(exchange.get_pair("BTCUSDT").await.is_err());
+
+    let info = ExchangeInfo::new();
+    info.update(vec![MarketPair {
+        base: String::from("BTC"),
+        quote: String::from("USDT"),
+        symbol: String::from("BTCUSDT"),
+        base_increment: Decimal::new(1, 5),
+        quote_increment: Decimal::new(1, 2),
+        ..Default::default()
+    }])
+    .expect("Couldn't update markets.");
+    info.save(&path).expect("Couldn't save cache.");
+
+    let exchange: Binance = OpenLimits::instantiate(parameters)
+        .await
+        .expect("Failed to create Client");
+    exchange
+        .get_pair("BTCUSDT")
+        .await
+        .expect("Couldn't get pair.");
+
+    fs::remove_dir_all(path.parent().expect("Couldn't get cache directory.")).ok();
+}
+
+#[tokio::test]
+async fn error_responses() {
+    let base_url = serve_response(
+        "429 Too Many Requests",
+        r#"{"code": -1003, "msg": "Too much request weight used; IP banned until 1565246363776."}"#,
+    )
+    .await;
+    let parameters = BinanceParameters {
+        http: HttpConfig::default()
+            .with_base_url(&base_url)
+            .with_timeout(Duration::from_secs(5)),
+        ..BinanceParameters::prod()
+    };
+    let error = match Binance::new(parameters).await {
+        Ok(_) => panic!("Created a client without exchange information."),
+        Err(error) => error,
+    };
+    assert_eq!(error.kind(), ErrorKind::RateLimited);
+    assert_eq!(error.code(), Some(String::from("-1003")));
+    assert_eq!(error.status(), Some(429));
+    assert!(error.is_retryable());
+
+    let base_url = serve_response("502 Bad Gateway", "<html>Bad Gateway</html>").await;
+    let parameters = BinanceParameters {
+        http: HttpConfig::default()
+            .with_base_url(&base_url)
+            .with_timeout(Duration::from_secs(5)),
+        ..BinanceParameters::prod()
+    };
+    let error = match Binance::new(parameters).await {
+        Ok(_) => panic!("Created a client without exchange information."),
+        Err(error) => error,
+    };
+    assert_eq!(error.kind(), ErrorKind::Transient);
+    assert_eq!(error.status(), Some(502));
+    assert!(error.to_string().contains("Bad Gateway"));
+}
@@ -1,6 +1,7 @@
 use openlimits::{
     OpenLimits,
     exchange::binance::Binance,
+    exchange::binance::BinanceEnvironment,
     exchange::binance::BinanceParameters,
     prelude::*,
     model::{GetHistoricRatesRequest, GetPriceTickerRequest, Interval, OrderBookRequest},
@@ -58,7 +59,10 @@ async fn pair() {
 async fn init() -> Binance {
     let parameters = BinanceParameters {
         credentials: None,
-        sandbox: true,
+        environment: BinanceEnvironment::Testnet,
+        http: Default::default(),
+        validation: Default::default(),
+        exchange_info_cache: None,
     };
 
     OpenLimits::instantiate(parameters)
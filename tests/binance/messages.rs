@@ -0,0 +1,54 @@
+use std::convert::TryFrom;
+
+use openlimits::{
+    exchange::binance::model::websocket::BinanceWebsocketMessage,
+    model::websocket::{OpenLimitsWebSocketMessage, WebSocketResponse},
+    model::{Liquidity, OrderStatus},
+};
+use rust_decimal::prelude::Decimal;
+
+#[test]
+fn execution_report() {
+    let message = r#"{
+        "stream": "pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1",
+        "data": {
+            "e": "executionReport", "E": 1499405658658, "s": "ETHBTC",
+            "c": "mUvoqJxFIILMdfAW5iGSOW",
+            "S": "BUY", "o": "LIMIT", "f": "GTC", "q": "2.00000000", "p": "0.10264410",
+            "P": "0.00000000", "F": "0.00000000", "g": -1, "C": "", "x": "TRADE",
+            "X": "PARTIALLY_FILLED", "r": "NONE", "i": 4293153, "l": "1.00000000",
+            "z": "1.00000000", "L": "0.10264410", "n": "0.00100000", "N": "ETH",
+            "T": 1499405658657, "t": 12345, "I": 8641984, "w": true, "m": true, "M": false,
+            "O": 1499405658657, "Z": "0.10264410", "Y": "0.10264410", "Q": "0.00000000"
+        }
+    }"#;
+    let message: BinanceWebsocketMessage =
+        serde_json::from_str(message).expect("Couldn't parse execution report.");
+    let response = WebSocketResponse::try_from(message).expect("Couldn't convert report.");
+
+    match response {
+        WebSocketResponse::Generic(OpenLimitsWebSocketMessage::Orders(orders)) => {
+            assert_eq!(orders.len(), 1);
+            assert_eq!(orders[0].id, "4293153");
+            assert_eq!(orders[0].status, OrderStatus::PartiallyFilled);
+            assert_eq!(orders[0].remaining, Some(Decimal::new(1, 0)));
+            assert_eq!(orders[0].trades.len(), 1);
+            assert_eq!(orders[0].trades[0].id, "12345");
+            assert_eq!(orders[0].trades[0].buyer_order_id.as_deref(), Some("4293153"));
+            assert_eq!(orders[0].trades[0].liquidity, Some(Liquidity::Maker));
+        }
+        _ => panic!("Unexpected response {:?}", response),
+    }
+}
+
+#[test]
+fn unknown_user_data_event() {
+    let message = r#"{
+        "stream": "pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1",
+        "data": {"e": "balanceUpdate", "E": 1573200697110, "a": "BTC", "d": "100.00000000"}
+    }"#;
+    let message: BinanceWebsocketMessage =
+        serde_json::from_str(message).expect("Couldn't parse balance update.");
+
+    assert!(matches!(message, BinanceWebsocketMessage::Unknown(_)));
+}
@@ -1,4 +1,7 @@
 mod account;
+mod filters;
+mod http;
 mod market;
+mod messages;
 mod ws_callbacks;
 mod ws_streams;
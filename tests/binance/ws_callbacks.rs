@@ -35,9 +35,9 @@ async fn trades() {
 }
 
 async fn init() -> OpenLimitsWs<BinanceWebsocket> {
-    OpenLimitsWs {
-        websocket: BinanceWebsocket::new(BinanceParameters::prod())
+    OpenLimitsWs::new(
+        BinanceWebsocket::new(BinanceParameters::prod())
             .await
             .expect("Failed to create Client"),
-    }
+    )
 }
@@ -0,0 +1,147 @@
+use dotenv::dotenv;
+use std::env;
+
+use openlimits::{
+    OpenLimits,
+    exchange::bitfinex::Bitfinex,
+    exchange::bitfinex::BitfinexCredentials,
+    exchange::bitfinex::BitfinexParameters,
+    prelude::*,
+    model::{
+        CancelAllOrdersRequest, CancelOrderRequest, GetOrderHistoryRequest, GetOrderRequest,
+        OpenLimitOrderRequest, TimeInForce, TradeHistoryRequest,
+    },
+};
+use rust_decimal::prelude::Decimal;
+
+// Bitfinex has no sandbox, the tests placing orders are ignored so they don't trade on the
+// account used by the CI.
+
+#[tokio::test]
+#[ignore]
+async fn limit_buy() {
+    let exchange = init().await;
+    let req = OpenLimitOrderRequest {
+        price: Decimal::new(1000, 0),
+        size: Decimal::new(1, 3),
+        market_pair: String::from("tBTCUSD"),
+        post_only: true,
+        time_in_force: TimeInForce::GoodTillCancelled,
+    };
+    let resp = exchange.limit_buy(&req).await.expect("Couldn't limit buy.");
+    println!("{:?}", resp);
+}
+
+#[tokio::test]
+#[ignore]
+async fn cancel_order() {
+    let exchange = init().await;
+    let req = OpenLimitOrderRequest {
+        price: Decimal::new(1000, 0),
+        size: Decimal::new(1, 3),
+        market_pair: String::from("tBTCUSD"),
+        post_only: true,
+        time_in_force: TimeInForce::GoodTillCancelled,
+    };
+    let order = exchange.limit_buy(&req).await.expect("Couldn't limit buy.");
+
+    let req = GetOrderRequest {
+        id: order.id.clone(),
+        market_pair: Some(order.market_pair.clone()),
+    };
+    exchange.get_order(&req).await.expect("Couldn't get order.");
+
+    let req = CancelOrderRequest {
+        id: order.id,
+        market_pair: Some(order.market_pair),
+    };
+    let resp = exchange
+        .cancel_order(&req)
+        .await
+        .expect("Couldn't cancel order.");
+    println!("{:?}", resp);
+}
+
+#[tokio::test]
+#[ignore]
+async fn cancel_all_orders() {
+    let exchange = init().await;
+    let req = CancelAllOrdersRequest {
+        market_pair: Some("tBTCUSD".to_string()),
+    };
+
+    let resp = exchange
+        .cancel_all_orders(&req)
+        .await
+        .expect("Couldn't cancel all orders.");
+    println!("{:?}", resp);
+}
+
+#[tokio::test]
+async fn get_order_history() {
+    let exchange = init().await;
+    let req = GetOrderHistoryRequest {
+        market_pair: Some(String::from("tBTCUSD")),
+        order_status: None,
+        paginator: None,
+    };
+
+    let resp = exchange
+        .get_order_history(&req)
+        .await
+        .expect("Couldn't get order history.");
+    println!("{:?}", resp);
+}
+
+#[tokio::test]
+async fn get_all_open_orders() {
+    let exchange = init().await;
+
+    let resp = exchange
+        .get_all_open_orders()
+        .await
+        .expect("Couldn't get all open orders.");
+    println!("{:?}", resp);
+}
+
+#[tokio::test]
+async fn get_account_balances() {
+    let exchange = init().await;
+
+    let resp = exchange
+        .get_account_balances(None)
+        .await
+        .expect("Couldn't get acount balances.");
+    println!("{:?}", resp);
+}
+
+#[tokio::test]
+async fn get_trade_history() {
+    let exchange = init().await;
+    let req = TradeHistoryRequest {
+        market_pair: Some("tBTCUSD".to_string()),
+        ..Default::default()
+    };
+
+    let resp = exchange
+        .get_trade_history(&req)
+        .await
+        .expect("Couldn't get trade history.");
+    println!("{:?}", resp);
+}
+
+async fn init() -> Bitfinex {
+    dotenv().ok();
+
+    let parameters = BitfinexParameters {
+        credentials: Some(BitfinexCredentials {
+            api_key: env::var("BITFINEX_API_KEY").expect("Couldn't get environment variable."),
+            api_secret: env::var("BITFINEX_API_SECRET").expect("Couldn't get environment variable."),
+        }),
+        exchange_info_cache: None,
+    };
+
+    OpenLimits::instantiate(parameters)
+        .await
+        .expect("Failed to create Client")
+}
@@ -0,0 +1,50 @@
+use std::str::FromStr;
+
+use openlimits::exchange::bitfinex::model::{RawBook, RawBookEntry};
+use rust_decimal::prelude::Decimal;
+
+fn entry(order_id: u64, price: &str, amount: &str) -> RawBookEntry {
+    RawBookEntry {
+        order_id,
+        price: Decimal::from_str(price).expect("Couldn't parse price."),
+        amount: Decimal::from_str(amount).expect("Couldn't parse amount."),
+    }
+}
+
+fn book() -> RawBook {
+    RawBook::from_entries(vec![
+        entry(1, "100.0", "1.50"),
+        entry(2, "99", "2"),
+        entry(3, "101", "-0.5"),
+    ])
+}
+
+#[test]
+fn checksum() {
+    let book = book();
+    assert_eq!(book.checksum(), -758732997);
+    assert!(book.is_valid(-758732997));
+}
+
+#[test]
+fn checksum_after_removal() {
+    let mut book = book();
+    let levels = book.apply(entry(3, "0", "-1"));
+
+    assert!(levels.bids.is_empty());
+    assert_eq!(levels.asks.len(), 1);
+    assert_eq!(levels.asks[0].qty, Decimal::from(0));
+    assert_eq!(book.checksum(), -1002777570);
+}
+
+#[test]
+fn aggregated_levels() {
+    let mut book = book();
+    let levels = book.apply(entry(4, "99", "0.5"));
+    assert_eq!(levels.bids[0].qty, Decimal::from_str("2.5").expect("Couldn't parse qty."));
+
+    let order_book = book.to_order_book();
+    assert_eq!(order_book.bids.len(), 2);
+    assert_eq!(order_book.bids[0].price, Decimal::from(100));
+    assert_eq!(order_book.asks[0].price, Decimal::from(101));
+}
@@ -0,0 +1,76 @@
+use openlimits::{
+    OpenLimits,
+    exchange::bitfinex::Bitfinex,
+    exchange::bitfinex::BitfinexParameters,
+    prelude::*,
+    model::{
+        GetHistoricRatesRequest, GetHistoricTradesRequest, GetPriceTickerRequest, Interval,
+        OrderBookRequest,
+    },
+};
+
+#[tokio::test]
+async fn order_book() {
+    let exchange = init().await;
+    let req = OrderBookRequest {
+        market_pair: "tBTCUSD".to_string(),
+    };
+    let _response = exchange
+        .order_book(&req)
+        .await
+        .expect("Couldn't get order book.");
+}
+
+#[tokio::test]
+async fn get_price_ticker() {
+    let exchange = init().await;
+    let req = GetPriceTickerRequest {
+        market_pair: "tBTCUSD".to_string(),
+    };
+    let _response = exchange
+        .get_price_ticker(&req)
+        .await
+        .expect("Couldn't get price ticker.");
+}
+
+#[tokio::test]
+async fn get_historic_rates() {
+    let exchange = init().await;
+    let req = GetHistoricRatesRequest {
+        market_pair: "tBTCUSD".to_string(),
+        interval: Interval::OneHour,
+        paginator: None,
+    };
+    let _response = exchange
+        .get_historic_rates(&req)
+        .await
+        .expect("Couldn't get historic rates.");
+}
+
+#[tokio::test]
+async fn get_historic_trades() {
+    let exchange = init().await;
+    let req = GetHistoricTradesRequest {
+        market_pair: "tBTCUSD".to_string(),
+        paginator: None,
+    };
+    let _response = exchange
+        .get_historic_trades(&req)
+        .await
+        .expect("Couldn't get historic trades.");
+}
+
+#[tokio::test]
+async fn pair() {
+    let exchange = init().await;
+    let _response = exchange
+        .get_pair("tBTCUSD")
+        .await
+        .expect("Couldn't get pair.");
+}
+
+async fn init() -> Bitfinex {
+    OpenLimits::instantiate(BitfinexParameters::prod())
+        .await
+        .expect("Failed to create Client")
+}
@@ -0,0 +1,4 @@
+mod account;
+mod checksum;
+mod market;
+mod ws_streams;
@@ -0,0 +1,158 @@
+use std::fs;
+use std::process;
+use std::sync::atomic::Ordering;
+
+use openlimits::{
+    candles::{CandleDownloader, CandleDownloaderConfig, CandleLimits, TimeRange},
+    errors::OpenLimitsError,
+    model::Interval,
+};
+use rust_decimal::prelude::Decimal;
+
+use super::{candle, MockMarket, MINUTE};
+
+fn config(max_candles: u64) -> CandleDownloaderConfig {
+    CandleDownloaderConfig::new("mock", CandleLimits::new(max_candles))
+}
+
+fn cache_directory(name: &str) -> std::path::PathBuf {
+    let directory = std::env::temp_dir().join(format!("openlimits-candles-{}-{}", name, process::id()));
+    fs::remove_dir_all(&directory).ok();
+    directory
+}
+
+#[tokio::test]
+async fn chunks_and_concurrency() {
+    let market = MockMarket::new(100, &[]);
+    let downloader = CandleDownloader::new(&market, config(10).with_concurrency(3));
+    let series = downloader
+        .download("BTCUSD", Interval::OneMinute, 0, 100 * MINUTE)
+        .await
+        .expect("Couldn't download candles");
+
+    assert_eq!(market.requests(), 10);
+    assert_eq!(market.max_in_flight.load(Ordering::SeqCst), 3);
+    assert_eq!(series.candles.len(), 100);
+    assert!(series.gaps.is_empty());
+}
+
+#[tokio::test]
+async fn sorted_without_duplicates() {
+    let market = MockMarket::new(50, &[]);
+    let downloader = CandleDownloader::new(&market, config(7).with_concurrency(4));
+    let series = downloader
+        .download("BTCUSD", Interval::OneMinute, 5 * MINUTE, 45 * MINUTE)
+        .await
+        .expect("Couldn't download candles");
+
+    let times: Vec<u64> = series.candles.iter().map(|candle| candle.time).collect();
+    let expected: Vec<u64> = (5..45).map(|minute| minute * MINUTE).collect();
+    assert_eq!(times, expected);
+}
+
+#[tokio::test]
+async fn gaps() {
+    let market = MockMarket::new(20, &[3, 4, 10]);
+    let downloader = CandleDownloader::new(&market, config(1000));
+    let series = downloader
+        .download("BTCUSD", Interval::OneMinute, 0, 20 * MINUTE)
+        .await
+        .expect("Couldn't download candles");
+
+    assert_eq!(series.candles.len(), 17);
+    assert_eq!(
+        series.gaps,
+        vec![
+            TimeRange::new(3 * MINUTE, 5 * MINUTE),
+            TimeRange::new(10 * MINUTE, 11 * MINUTE),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn filled_gaps() {
+    let market = MockMarket::new(20, &[0, 3, 4]);
+    let downloader = CandleDownloader::new(&market, config(1000).with_gap_filling(true));
+    let series = downloader
+        .download("BTCUSD", Interval::OneMinute, 0, 20 * MINUTE)
+        .await
+        .expect("Couldn't download candles");
+
+    assert_eq!(series.candles.len(), 20);
+    assert!(series.gaps.is_empty());
+    // The first candle opens at the open of the next one
+    assert_eq!(series.candles[0].close, candle(1).open);
+    // The others stay at the previous close, without volume
+    assert_eq!(series.candles[3].open, candle(2).close);
+    assert_eq!(series.candles[4].close, candle(2).close);
+    assert_eq!(series.candles[4].volume, Decimal::new(0, 0));
+    assert_eq!(series.candles[5], candle(5));
+}
+
+#[tokio::test]
+async fn cache() {
+    let directory = cache_directory("cache");
+    let market = MockMarket::new(100, &[50]);
+    let downloader = CandleDownloader::new(&market, config(10).with_cache(&directory));
+
+    let first = downloader
+        .download("BTCUSD", Interval::OneMinute, 0, 60 * MINUTE)
+        .await
+        .expect("Couldn't download candles");
+    assert_eq!(market.requests(), 6);
+
+    let second = downloader
+        .download("BTCUSD", Interval::OneMinute, 0, 60 * MINUTE)
+        .await
+        .expect("Couldn't download candles");
+    assert_eq!(market.requests(), 6);
+    assert_eq!(first, second);
+
+    // Only the new range is requested
+    let extended = downloader
+        .download("BTCUSD", Interval::OneMinute, 0, 80 * MINUTE)
+        .await
+        .expect("Couldn't download candles");
+    assert_eq!(market.requests(), 8);
+    assert_eq!(extended.candles.len(), 79);
+    assert_eq!(extended.gaps, vec![TimeRange::new(50 * MINUTE, 51 * MINUTE)]);
+
+    fs::remove_dir_all(&directory).ok();
+}
+
+#[tokio::test]
+async fn cache_skips_unconfirmed_empty_ranges() {
+    let directory = cache_directory("unconfirmed");
+    // No candles before the 10th minute nor after the 30th
+    let missing: Vec<u64> = (0..10).collect();
+    let market = MockMarket::new(30, &missing);
+    let downloader = CandleDownloader::new(&market, config(10).with_cache(&directory));
+
+    downloader
+        .download("BTCUSD", Interval::OneMinute, 0, 60 * MINUTE)
+        .await
+        .expect("Couldn't download candles");
+    assert_eq!(market.requests(), 6);
+
+    // The empty range before the candles is known to be empty, the one after them isn't
+    let series = downloader
+        .download("BTCUSD", Interval::OneMinute, 0, 60 * MINUTE)
+        .await
+        .expect("Couldn't download candles");
+    assert_eq!(market.requests(), 9);
+    assert_eq!(series.candles.len(), 20);
+
+    fs::remove_dir_all(&directory).ok();
+}
+
+#[tokio::test]
+async fn unsupported_interval() {
+    let market = MockMarket::new(10, &[]);
+    let downloader = CandleDownloader::new(&market, CandleDownloaderConfig::new("coinbase", CandleLimits::coinbase()));
+    let result = downloader
+        .download("BTCUSD", Interval::ThreeMinutes, 0, 10 * MINUTE)
+        .await;
+
+    assert!(matches!(result, Err(OpenLimitsError::InvalidParameter(_))));
+    assert_eq!(market.requests(), 0);
+}
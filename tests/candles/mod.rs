@@ -0,0 +1,99 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use openlimits::{
+    errors::OpenLimitsError,
+    exchange::shared::Result,
+    exchange::traits::ExchangeMarketData,
+    model::{
+        Candle, GetHistoricRatesRequest, GetHistoricTradesRequest, GetPriceTickerRequest,
+        OrderBookRequest, OrderBookResponse, Ticker, Trade,
+    },
+};
+use rust_decimal::prelude::Decimal;
+
+mod downloader;
+mod resampler;
+
+pub const MINUTE: u64 = 60000;
+
+/// Market with a candle every minute but the missing ones. Every response repeats its first
+/// candle and requests are counted.
+pub struct MockMarket {
+    pub candles: u64,
+    pub missing: Vec<u64>,
+    pub requests: AtomicUsize,
+    pub in_flight: AtomicUsize,
+    pub max_in_flight: AtomicUsize,
+    pub last_request: Mutex<Option<GetHistoricRatesRequest>>,
+}
+
+impl MockMarket {
+    pub fn new(candles: u64, missing: &[u64]) -> Self {
+        Self {
+            candles,
+            missing: missing.to_vec(),
+            requests: AtomicUsize::new(0),
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: AtomicUsize::new(0),
+            last_request: Mutex::new(None),
+        }
+    }
+
+    pub fn requests(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+}
+
+pub fn candle(minute: u64) -> Candle {
+    let price = Decimal::from(minute + 1);
+    Candle {
+        market_pair: None,
+        instrument: None,
+        time: minute * MINUTE,
+        low: price,
+        high: price,
+        open: price,
+        close: price,
+        volume: Decimal::new(1, 0),
+    }
+}
+
+#[async_trait]
+impl ExchangeMarketData for MockMarket {
+    async fn order_book(&self, _req: &OrderBookRequest) -> Result<OrderBookResponse> {
+        Err(OpenLimitsError::WebSocketMessageNotSupported())
+    }
+
+    async fn get_price_ticker(&self, _req: &GetPriceTickerRequest) -> Result<Ticker> {
+        Err(OpenLimitsError::WebSocketMessageNotSupported())
+    }
+
+    async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+        *self.last_request.lock().unwrap() = Some(req.clone());
+        let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        let paginator = req.paginator.clone().unwrap_or_default();
+        let start = paginator.start_time.unwrap_or(0);
+        let end = paginator.end_time.unwrap_or(u64::MAX);
+        let mut candles: Vec<Candle> = (0..self.candles)
+            .filter(|minute| !self.missing.contains(minute))
+            .map(candle)
+            .filter(|candle| candle.time >= start && candle.time <= end)
+            .take(paginator.limit.unwrap_or(u64::MAX) as usize)
+            .collect();
+        if let Some(first) = candles.first().cloned() {
+            candles.push(first);
+        }
+        Ok(candles)
+    }
+
+    async fn get_historic_trades(&self, _req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
+        Ok(Vec::new())
+    }
+}
@@ -0,0 +1,280 @@
+use chrono::Duration;
+use futures::stream::{self, StreamExt};
+use openlimits::{
+    candles::{
+        aggregate_trades, detect_gaps, resample, trade_candles, CandleLimits, CandlePeriod,
+        ResampledMarketData, TimeRange,
+    },
+    errors::OpenLimitsError,
+    exchange::traits::ExchangeMarketData,
+    model::{Candle, GetHistoricRatesRequest, Interval, Paginator, Side, Trade},
+};
+use rust_decimal::prelude::Decimal;
+
+use super::{MockMarket, MINUTE};
+
+const DAY: u64 = 24 * 60 * MINUTE;
+// 2021-01-01, a Friday
+const NEW_YEAR: u64 = 1_609_459_200_000;
+const FEBRUARY: u64 = 1_612_137_600_000;
+const MARCH: u64 = 1_614_556_800_000;
+
+fn price(value: i64) -> Decimal {
+    Decimal::new(value, 0)
+}
+
+fn minute_candle(minute: u64, open: i64, high: i64, low: i64, close: i64) -> Candle {
+    Candle {
+        market_pair: None,
+        instrument: None,
+        time: minute * MINUTE,
+        low: price(low),
+        high: price(high),
+        open: price(open),
+        close: price(close),
+        volume: price(2),
+    }
+}
+
+fn trade(time: u64, value: i64, qty: i64) -> Trade {
+    Trade {
+        id: time.to_string(),
+        buyer_order_id: None,
+        seller_order_id: None,
+        market_pair: String::from("BTCUSD"),
+        instrument: None,
+        price: price(value),
+        qty: price(qty),
+        fees: None,
+        side: Side::Buy,
+        liquidity: None,
+        created_at: time,
+    }
+}
+
+#[test]
+fn resamples_candles() {
+    // Unsorted, and the last period is incomplete
+    let candles = vec![
+        minute_candle(1, 12, 15, 11, 14),
+        minute_candle(0, 10, 13, 9, 12),
+        minute_candle(2, 14, 14, 8, 9),
+        minute_candle(3, 9, 10, 9, 10),
+    ];
+    let resampled = resample(&candles, Interval::ThreeMinutes.into());
+
+    assert_eq!(
+        resampled,
+        vec![
+            Candle {
+                market_pair: None,
+                instrument: None,
+                time: 0,
+                low: price(8),
+                high: price(15),
+                open: price(10),
+                close: price(9),
+                volume: price(6),
+            },
+            Candle {
+                market_pair: None,
+                instrument: None,
+                time: 3 * MINUTE,
+                low: price(9),
+                high: price(10),
+                open: price(9),
+                close: price(10),
+                volume: price(2),
+            },
+        ]
+    );
+}
+
+#[test]
+fn custom_period() {
+    let candles: Vec<Candle> = (0..20)
+        .map(|minute| minute_candle(minute, 1, 1, 1, 1))
+        .collect();
+    let resampled = resample(&candles, CandlePeriod::from_duration(Duration::minutes(7)));
+
+    let times: Vec<u64> = resampled.iter().map(|candle| candle.time).collect();
+    assert_eq!(times, vec![0, 7 * MINUTE, 14 * MINUTE]);
+    let volumes: Vec<Decimal> = resampled.iter().map(|candle| candle.volume).collect();
+    assert_eq!(volumes, vec![price(14), price(14), price(12)]);
+}
+
+#[test]
+fn aggregates_trades() {
+    let trades = vec![
+        trade(MINUTE + 5, 11, 1),
+        trade(10, 10, 2),
+        trade(MINUTE + 30, 13, 1),
+        trade(MINUTE + 20, 9, 3),
+        trade(3 * MINUTE, 12, 1),
+    ];
+    let candles = aggregate_trades(&trades, Interval::OneMinute.into());
+
+    assert_eq!(
+        candles,
+        vec![
+            Candle {
+                market_pair: Some(String::from("BTCUSD")),
+                instrument: None,
+                time: 0,
+                low: price(10),
+                high: price(10),
+                open: price(10),
+                close: price(10),
+                volume: price(2),
+            },
+            Candle {
+                market_pair: Some(String::from("BTCUSD")),
+                instrument: None,
+                time: MINUTE,
+                low: price(9),
+                high: price(13),
+                open: price(11),
+                close: price(13),
+                volume: price(5),
+            },
+            Candle {
+                market_pair: Some(String::from("BTCUSD")),
+                instrument: None,
+                time: 3 * MINUTE,
+                low: price(12),
+                high: price(12),
+                open: price(12),
+                close: price(12),
+                volume: price(1),
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn live_candles() {
+    let trades = vec![
+        Ok(trade(10, 10, 1)),
+        Ok(trade(20, 12, 1)),
+        Ok(trade(MINUTE, 11, 1)),
+        Err(OpenLimitsError::SocketError()),
+        Ok(trade(2 * MINUTE + 1, 13, 1)),
+        Ok(trade(2 * MINUTE + 2, 14, 1)),
+    ];
+    let candles: Vec<_> = trade_candles(stream::iter(trades), Interval::OneMinute.into())
+        .collect()
+        .await;
+
+    assert_eq!(candles.len(), 4);
+    let first = candles[0].as_ref().expect("Couldn't build candle");
+    assert_eq!((first.time, first.open, first.close), (0, price(10), price(12)));
+    assert!(matches!(candles[1], Err(OpenLimitsError::SocketError())));
+    let second = candles[2].as_ref().expect("Couldn't build candle");
+    assert_eq!((second.time, second.volume), (MINUTE, price(1)));
+    // The candle in progress is returned when the trades end
+    let last = candles[3].as_ref().expect("Couldn't build candle");
+    assert_eq!((last.time, last.close, last.volume), (2 * MINUTE, price(14), price(2)));
+}
+
+#[test]
+fn weeks_start_on_monday() {
+    let week = CandlePeriod::from(Interval::OneWeek);
+    let monday = NEW_YEAR + 3 * DAY;
+
+    assert_eq!(week.start(NEW_YEAR), monday - 7 * DAY);
+    assert_eq!(week.start(monday + DAY), monday);
+    assert_eq!(week.next(monday + DAY), monday + 7 * DAY);
+    // The week of the Unix epoch starts on the epoch, the next one on 1970-01-05
+    assert_eq!(week.start(DAY), 0);
+    assert_eq!(week.next(DAY), 4 * DAY);
+}
+
+#[test]
+fn calendar_months() {
+    let month = CandlePeriod::from(Interval::OneMonth);
+
+    assert_eq!(month.start(FEBRUARY + 10 * DAY), FEBRUARY);
+    assert_eq!(month.next(FEBRUARY), MARCH);
+    assert_eq!(month.start(NEW_YEAR - 1), NEW_YEAR - 31 * DAY);
+    assert_eq!(month.next(NEW_YEAR - 1), NEW_YEAR);
+
+    let days: Vec<Candle> = (0..59)
+        .map(|day| minute_candle((NEW_YEAR + day * DAY) / MINUTE, 1, 1, 1, 1))
+        .collect();
+    let resampled = resample(&days, month);
+    let times: Vec<u64> = resampled.iter().map(|candle| candle.time).collect();
+    assert_eq!(times, vec![NEW_YEAR, FEBRUARY]);
+    let volumes: Vec<Decimal> = resampled.iter().map(|candle| candle.volume).collect();
+    assert_eq!(volumes, vec![price(62), price(56)]);
+
+    let candles = vec![days[0].clone(), minute_candle(MARCH / MINUTE, 1, 1, 1, 1)];
+    let gaps = detect_gaps(&candles, Interval::OneMonth, TimeRange::new(NEW_YEAR, MARCH + 1));
+    assert_eq!(gaps, vec![TimeRange::new(FEBRUARY, MARCH)]);
+}
+
+#[test]
+fn base_interval() {
+    let nash = CandleLimits::nash();
+    assert_eq!(nash.base_interval(Interval::ThreeMinutes.into()), Some(Interval::OneMinute));
+    assert_eq!(nash.base_interval(Interval::TwoHours.into()), Some(Interval::OneHour));
+    assert_eq!(nash.base_interval(Interval::EightHours.into()), Some(Interval::OneHour));
+    assert_eq!(nash.base_interval(Interval::OneWeek.into()), Some(Interval::OneDay));
+    assert_eq!(nash.base_interval(Interval::OneMonth.into()), Some(Interval::OneDay));
+    assert_eq!(
+        nash.base_interval(CandlePeriod::from_duration(Duration::minutes(45))),
+        Some(Interval::FifteenMinutes)
+    );
+    assert_eq!(nash.base_interval(CandlePeriod::from_millis(90000)), None);
+}
+
+#[tokio::test]
+async fn resampled_market_data() {
+    let market = MockMarket::new(30, &[]);
+    let limits = CandleLimits::new(1000).with_intervals(&[Interval::OneMinute]);
+    let resampled = ResampledMarketData::new(&market, limits);
+
+    let req = GetHistoricRatesRequest {
+        market_pair: String::from("BTCUSD"),
+        interval: Interval::FiveMinutes,
+        paginator: Some(Paginator {
+            start_time: Some(7 * MINUTE),
+            limit: Some(3),
+            ..Default::default()
+        }),
+    };
+    let candles = resampled
+        .get_historic_rates(&req)
+        .await
+        .expect("Couldn't get candles");
+
+    let sent = market.last_request.lock().unwrap().clone().expect("No request sent");
+    assert_eq!(sent.interval, Interval::OneMinute);
+    let paginator = sent.paginator.expect("No paginator sent");
+    assert_eq!(paginator.start_time, Some(5 * MINUTE));
+    assert_eq!(paginator.limit, Some(15));
+
+    let times: Vec<u64> = candles.iter().map(|candle| candle.time).collect();
+    assert_eq!(times, vec![5 * MINUTE, 10 * MINUTE, 15 * MINUTE]);
+    assert_eq!(candles[0].open, price(6));
+    assert_eq!(candles[0].close, price(10));
+    assert_eq!(candles[0].volume, price(5));
+}
+
+#[tokio::test]
+async fn supported_intervals_are_forwarded() {
+    let market = MockMarket::new(10, &[]);
+    let resampled = ResampledMarketData::new(&market, CandleLimits::binance());
+    let req = GetHistoricRatesRequest {
+        market_pair: String::from("BTCUSD"),
+        interval: Interval::ThreeMinutes,
+        paginator: None,
+    };
+    let candles = resampled
+        .get_historic_rates(&req)
+        .await
+        .expect("Couldn't get candles");
+
+    assert_eq!(candles.len(), 11);
+    let sent = market.last_request.lock().unwrap().clone().expect("No request sent");
+    assert_eq!(sent.interval, Interval::ThreeMinutes);
+}
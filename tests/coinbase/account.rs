@@ -229,6 +229,8 @@ async fn init() -> Coinbase {
                 .expect("Couldn't get environment variable."),
         }),
         sandbox: true,
+        http: Default::default(),
+        exchange_info_cache: None,
     };
 
     OpenLimits::instantiate(parameters)
@@ -1,6 +1,12 @@
+use std::fs;
+use std::process;
+
 use openlimits::exchange::coinbase::{Coinbase, CoinbaseParameters};
+use openlimits::exchange::traits::HttpConfig;
+use openlimits::exchange::traits::info::ExchangeInfoCache;
 use openlimits::OpenLimits;
 use openlimits::prelude::*;
+use rust_decimal::prelude::Decimal;
 
 #[tokio::test]
 async fn retrieve_pairs() {
@@ -9,10 +15,55 @@ async fn retrieve_pairs() {
     println!("{:#?}", result);
 }
 
+#[tokio::test]
+async fn lazy_exchange_info() {
+    let path = std::env::temp_dir()
+        .join(format!("openlimits-coinbase-lazy-{}", process::id()))
+        .join("exchange_info.json");
+    let cache = ExchangeInfoCache::new(&path, Duration::from_secs(60)).with_lazy(true);
+    // Nothing listens on the discard port, the exchange information can't be downloaded
+    let parameters = CoinbaseParameters {
+        http: HttpConfig::default()
+            .with_base_url("http://127.0.0.1:9")
+            .with_timeout(Duration::from_secs(1)),
+        ..CoinbaseParameters::prod()
+    }
+    .with_exchange_info_cache(cache);
+
+    let exchange: Coinbase = OpenLimits::instantiate(parameters.clone())
+        .await
+        .expect("Failed to create Client");
+    assert!(exchange.get_pair("BTC-USD").await.is_err());
+
+    let info = ExchangeInfo::new();
+    info.update(vec![MarketPair {
+        base: String::from("BTC"),
+        quote: String::from("USD"),
+        symbol: String::from("BTC-USD"),
+        base_increment: Decimal::new(1, 8),
+        quote_increment: Decimal::new(1, 2),
+        ..Default::default()
+    }])
+    .expect("Couldn't update markets.");
+    info.save(&path).expect("Couldn't save cache.");
+
+    let exchange: Coinbase = OpenLimits::instantiate(parameters)
+        .await
+        .expect("Failed to create Client");
+    exchange
+        .get_pair("BTC-USD")
+        .await
+        .expect("Couldn't get pair.");
+
+    fs::remove_dir_all(path.parent().expect("Couldn't get cache directory.")).ok();
+}
+
 async fn init() -> Coinbase {
     let parameters = CoinbaseParameters {
         credentials: None,
         sandbox: true,
+        http: Default::default(),
+        exchange_info_cache: None,
     };
 
     OpenLimits::instantiate(parameters)
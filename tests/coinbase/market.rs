@@ -73,6 +73,8 @@ async fn init() -> Coinbase {
     let parameters = CoinbaseParameters {
         credentials: None,
         sandbox: true,
+        http: Default::default(),
+        exchange_info_cache: None,
     };
 
     OpenLimits::instantiate(parameters)
@@ -0,0 +1,116 @@
+use openlimits::{
+    exchange::coinbase::model::websocket::{
+        CoinbaseSubscription, CoinbaseWebsocketMessage, OrderTracker,
+    },
+    model::websocket::{AccountOrders, Subscription},
+    model::{Liquidity, Order, OrderStatus},
+};
+use rust_decimal::prelude::Decimal;
+
+const ORDER_ID: &str = "d50ec984-77a8-460a-b958-66f114b0de9b";
+
+fn apply(tracker: &mut OrderTracker, message: &str) -> Vec<Order> {
+    let message: CoinbaseWebsocketMessage =
+        serde_json::from_str(message).expect("Couldn't parse message.");
+    match message {
+        CoinbaseWebsocketMessage::Full(full) => tracker.apply(&full),
+        message => panic!("Unexpected message {:?}", message),
+    }
+}
+
+fn received(tracker: &mut OrderTracker) -> Vec<Order> {
+    apply(
+        tracker,
+        r#"{
+            "type": "received", "time": "2014-11-07T08:19:27.028459Z",
+            "product_id": "BTC-USD", "sequence": 10,
+            "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b",
+            "client_oid": "c1", "size": "1.34", "price": "502.1", "side": "buy",
+            "order_type": "limit", "user_id": "5844eceecf7e803e259d0365"
+        }"#,
+    )
+}
+
+#[test]
+fn user_order_updates() {
+    let mut tracker = OrderTracker::new();
+    let orders = received(&mut tracker);
+    assert_eq!(orders.len(), 1);
+    assert_eq!(orders[0].id, ORDER_ID);
+    assert_eq!(orders[0].status, OrderStatus::New);
+    assert_eq!(orders[0].client_order_id, Some(String::from("c1")));
+
+    let orders = apply(
+        &mut tracker,
+        r#"{
+            "type": "open", "time": "2014-11-07T08:19:27.028459Z", "product_id": "BTC-USD",
+            "sequence": 11, "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b",
+            "price": "502.1", "remaining_size": "1.34", "side": "buy",
+            "user_id": "5844eceecf7e803e259d0365"
+        }"#,
+    );
+    assert_eq!(orders[0].status, OrderStatus::Open);
+
+    let orders = apply(
+        &mut tracker,
+        r#"{
+            "type": "match", "trade_id": 10, "sequence": 50,
+            "maker_order_id": "d50ec984-77a8-460a-b958-66f114b0de9b",
+            "taker_order_id": "ac928c66-ca53-498f-9c13-a110027a60e8",
+            "time": "2014-11-07T08:19:28.464459Z", "product_id": "BTC-USD",
+            "size": "0.34", "price": "502.1", "side": "buy",
+            "user_id": "5844eceecf7e803e259d0365"
+        }"#,
+    );
+    assert_eq!(orders[0].status, OrderStatus::PartiallyFilled);
+    assert_eq!(orders[0].remaining, Some(Decimal::new(1, 0)));
+    assert_eq!(orders[0].trades.len(), 1);
+    assert_eq!(orders[0].trades[0].id, "10");
+    assert_eq!(orders[0].trades[0].buyer_order_id, Some(String::from(ORDER_ID)));
+    assert_eq!(orders[0].trades[0].liquidity, Some(Liquidity::Maker));
+
+    let orders = apply(
+        &mut tracker,
+        r#"{
+            "type": "done", "time": "2014-11-07T08:19:29.028459Z", "product_id": "BTC-USD",
+            "sequence": 51, "price": "502.1", "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b",
+            "reason": "canceled", "side": "buy", "remaining_size": "1",
+            "user_id": "5844eceecf7e803e259d0365"
+        }"#,
+    );
+    assert_eq!(orders[0].status, OrderStatus::Canceled);
+    assert_eq!(orders[0].trades.len(), 1);
+}
+
+#[test]
+fn untracked_orders_are_ignored() {
+    let mut tracker = OrderTracker::new();
+    let orders = apply(
+        &mut tracker,
+        r#"{
+            "type": "open", "time": "2014-11-07T08:19:27.028459Z", "product_id": "BTC-USD",
+            "sequence": 11, "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b",
+            "price": "502.1", "remaining_size": "1.34", "side": "buy"
+        }"#,
+    );
+    assert!(orders.is_empty());
+}
+
+#[test]
+fn account_subscriptions() {
+    let orders = AccountOrders {
+        market: Some(String::from("BTC-USD")),
+        order_type: None,
+        buy_or_sell: None,
+        range: None,
+        status: None,
+    };
+    assert_eq!(
+        CoinbaseSubscription::from(Subscription::AccountOrders(orders)),
+        CoinbaseSubscription::User(String::from("BTC-USD"))
+    );
+    assert_eq!(
+        CoinbaseSubscription::from(Subscription::Ticker(String::from("BTC-USD"))),
+        CoinbaseSubscription::Unsupported(Subscription::Ticker(String::from("BTC-USD")))
+    );
+}
@@ -1,3 +1,4 @@
 mod account;
 mod market;
+mod messages;
 mod exchange;
\ No newline at end of file
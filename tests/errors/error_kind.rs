@@ -0,0 +1,95 @@
+use openlimits::{
+    errors::{ErrorKind, OpenLimitsError},
+    exchange::binance::BinanceContentError,
+    exchange::coinbase::CoinbaseContentError,
+    validation::MarketRule,
+};
+
+fn binance(code: i16, msg: &str, status: u16) -> OpenLimitsError {
+    let body = format!(r#"{{"code": {}, "msg": "{}"}}"#, code, msg);
+    let error: BinanceContentError = serde_json::from_str(&body).expect("Couldn't parse error.");
+    OpenLimitsError::BinanceError(error.with_status(status))
+}
+
+fn coinbase(message: &str, status: u16) -> OpenLimitsError {
+    coinbase_request(message, status, "/accounts")
+}
+
+fn coinbase_request(message: &str, status: u16, path: &str) -> OpenLimitsError {
+    let body = format!(r#"{{"message": "{}"}}"#, message);
+    let error: CoinbaseContentError = serde_json::from_str(&body).expect("Couldn't parse error.");
+    OpenLimitsError::CoinbaseError(error.with_status(status).with_path(path))
+}
+
+#[test]
+fn binance_codes() {
+    let insufficient = binance(
+        -2010,
+        "Account has insufficient balance for requested action.",
+        400,
+    );
+    assert_eq!(insufficient.kind(), ErrorKind::InsufficientFunds);
+    assert_eq!(insufficient.code(), Some(String::from("-2010")));
+    assert_eq!(insufficient.status(), Some(400));
+    assert!(!insufficient.is_retryable());
+
+    let rejected = binance(-2010, "Order would immediately match and take.", 400);
+    assert_eq!(rejected.kind(), ErrorKind::InvalidOrder);
+    let unknown_order = binance(-2011, "Unknown order sent.", 400);
+    assert_eq!(unknown_order.kind(), ErrorKind::OrderNotFound);
+    let bad_symbol = binance(-1121, "Invalid symbol.", 400);
+    assert_eq!(bad_symbol.kind(), ErrorKind::SymbolNotFound);
+    let filter = binance(-1013, "Filter failure: LOT_SIZE", 400);
+    assert_eq!(filter.kind(), ErrorKind::InvalidOrder);
+    let bad_key = binance(-2015, "Invalid API-key, IP, or permissions for action.", 401);
+    assert_eq!(bad_key.kind(), ErrorKind::AuthFailure);
+
+    let rate_limited = binance(-1003, "Too much request weight used.", 429);
+    assert_eq!(rate_limited.kind(), ErrorKind::RateLimited);
+    assert!(rate_limited.is_retryable());
+    let banned = binance(-1003, "Way too many requests; IP banned until 1600000000000.", 418);
+    assert_eq!(banned.kind(), ErrorKind::Banned);
+    assert!(!banned.is_retryable());
+    let unknown_code = binance(-9999, "Something happened.", 503);
+    assert_eq!(unknown_code.kind(), ErrorKind::Transient);
+    assert!(unknown_code.is_retryable());
+}
+
+#[test]
+fn coinbase_messages() {
+    assert_eq!(coinbase("Insufficient funds", 400).kind(), ErrorKind::InsufficientFunds);
+    assert_eq!(coinbase("invalid signature", 401).kind(), ErrorKind::AuthFailure);
+    assert_eq!(coinbase("Private rate limit exceeded", 429).kind(), ErrorKind::RateLimited);
+    let too_small = coinbase("size is too small. Minimum size is 0.001", 400);
+    assert_eq!(too_small.kind(), ErrorKind::InvalidOrder);
+    let order = coinbase_request("NotFound", 404, "/orders/client:1");
+    assert_eq!(order.kind(), ErrorKind::OrderNotFound);
+    let product = coinbase_request("NotFound", 404, "/products/BTC-EUR/book");
+    assert_eq!(product.kind(), ErrorKind::Unknown);
+    assert_eq!(coinbase("Something happened", 502).kind(), ErrorKind::Transient);
+    assert_eq!(coinbase("Insufficient funds", 400).status(), Some(400));
+    assert_eq!(coinbase("Insufficient funds", 400).code(), None);
+}
+
+#[test]
+fn library_errors() {
+    let http = OpenLimitsError::HttpError {
+        status: 418,
+        body: String::from("banned"),
+    };
+    assert_eq!(http.kind(), ErrorKind::Banned);
+    assert!(!http.is_retryable());
+    assert_eq!(http.status(), Some(418));
+    assert_eq!(http.to_string(), "http status 418: banned");
+
+    let violation = MarketRule::LotSize.violation(String::from("size isn't a step multiple"));
+    assert_eq!(violation.kind(), ErrorKind::InvalidOrder);
+    let invalid = OpenLimitsError::InvalidParameter(String::from("page"));
+    assert_eq!(invalid.kind(), ErrorKind::InvalidRequest);
+    assert_eq!(invalid.to_string(), "invalid parameter: page");
+
+    assert_eq!(OpenLimitsError::SymbolNotFound().to_string(), "symbol not found");
+    assert_eq!(OpenLimitsError::TooManyRequests().kind(), ErrorKind::RateLimited);
+    assert!(OpenLimitsError::ServiceUnavailable().is_retryable());
+    assert!(!OpenLimitsError::PoisonError().is_retryable());
+}
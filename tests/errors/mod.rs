@@ -0,0 +1 @@
+mod error_kind;
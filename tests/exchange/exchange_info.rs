@@ -0,0 +1,167 @@
+use std::fs;
+use std::process;
+use std::sync::{Arc, Mutex};
+use async_trait::async_trait;
+use openlimits::{
+    errors::OpenLimitsError,
+    exchange::shared::Result,
+    exchange::traits::info::{ExchangeInfoRefresher, MarketPairEvent, MarketStatus},
+    prelude::*,
+};
+use rust_decimal::prelude::Decimal;
+
+/// Exchange whose markets are set by the tests
+#[derive(Default)]
+struct Listings {
+    exchange_info: ExchangeInfo,
+    pairs: Mutex<Vec<MarketPair>>,
+}
+
+impl Listings {
+    fn list(&self, pairs: Vec<MarketPair>) {
+        *self.pairs.lock().expect("Couldn't lock pairs.") = pairs;
+    }
+}
+
+#[async_trait]
+impl ExchangeInfoRetrieval for Listings {
+    async fn retrieve_pairs(&self) -> Result<Vec<MarketPair>> {
+        Ok(self.pairs.lock().map_err(|_| OpenLimitsError::PoisonError())?.clone())
+    }
+
+    async fn refresh_market_info(&self) -> Result<Vec<MarketPairHandle>> {
+        self.exchange_info
+            .refresh(self as &dyn ExchangeInfoRetrieval)
+            .await
+    }
+
+    async fn get_pair(&self, name: &str) -> Result<MarketPairHandle> {
+        self.exchange_info.get_pair(name)
+    }
+}
+
+fn pair(symbol: &str, base_increment: i64) -> MarketPair {
+    MarketPair {
+        base: symbol[..3].to_string(),
+        quote: symbol[3..].to_string(),
+        symbol: symbol.to_string(),
+        base_increment: Decimal::new(base_increment, 3),
+        quote_increment: Decimal::new(1, 2),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn update() {
+    let info = ExchangeInfo::new();
+    let events = info
+        .update(vec![pair("BTCUSD", 1), pair("ETHUSD", 1)])
+        .expect("Couldn't update.");
+    assert_eq!(events.len(), 2);
+    assert!(events.iter().all(|event| matches!(event, MarketPairEvent::Added(_))));
+
+    let handle = info.get_pair("BTCUSD").expect("Couldn't get pair.");
+    let delisted = info.get_pair("ETHUSD").expect("Couldn't get pair.");
+    let events = info
+        .update(vec![pair("BTCUSD", 10), pair("LTCUSD", 1)])
+        .expect("Couldn't update.");
+    assert_eq!(events.len(), 3);
+    assert!(events.contains(&MarketPairEvent::Changed {
+        previous: pair("BTCUSD", 1),
+        pair: pair("BTCUSD", 10),
+    }));
+    assert!(events.contains(&MarketPairEvent::Removed(pair("ETHUSD", 1))));
+    assert!(events.contains(&MarketPairEvent::Added(pair("LTCUSD", 1))));
+
+    let btc = handle.read().expect("Couldn't read pair.");
+    assert_eq!(btc.base_increment, Decimal::new(10, 3));
+    let eth = delisted.read().expect("Couldn't read pair.");
+    assert_eq!(eth.status, MarketStatus::Halted);
+    assert!(info.get_pair("ETHUSD").is_err());
+    assert_eq!(info.list_pairs().len(), 2);
+
+    let events = info
+        .update(vec![pair("BTCUSD", 10), pair("LTCUSD", 1)])
+        .expect("Couldn't update.");
+    assert!(events.is_empty());
+    let events = info.update(vec![]).expect("Couldn't update.");
+    assert!(events.is_empty());
+    assert_eq!(info.list_pairs().len(), 2);
+}
+
+#[tokio::test]
+async fn refresher() {
+    let listings = Arc::new(Listings::default());
+    listings.list(vec![pair("BTCUSD", 1)]);
+    listings.refresh_market_info().await.expect("Couldn't refresh.");
+    let mut events = listings.exchange_info.subscribe();
+
+    let refresher = ExchangeInfoRefresher::spawn(listings.clone(), Duration::from_millis(10));
+    listings.list(vec![pair("BTCUSD", 1), pair("ETHUSD", 1)]);
+    let event = tokio::time::timeout(Duration::from_secs(1), events.recv())
+        .await
+        .expect("No event received.");
+    assert_eq!(event, Some(MarketPairEvent::Added(pair("ETHUSD", 1))));
+
+    refresher.stop();
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    listings.list(vec![pair("BTCUSD", 1)]);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(events.try_recv().is_err());
+    assert!(listings.exchange_info.get_pair("ETHUSD").is_ok());
+}
+
+#[tokio::test]
+async fn cache() {
+    let path = std::env::temp_dir()
+        .join(format!("openlimits-exchange-info-{}.json", process::id()));
+    let info = ExchangeInfo::new();
+    info.update(vec![pair("BTCUSD", 1), pair("ETHUSD", 1)])
+        .expect("Couldn't update.");
+    info.save(&path).expect("Couldn't save cache.");
+
+    let cached = ExchangeInfo::new();
+    assert!(cached.load(&path, Duration::from_secs(60)).expect("Couldn't load cache."));
+    let handle = cached.get_pair("ETHUSD").expect("Couldn't get pair.");
+    assert_eq!(handle.read().expect("Couldn't read pair."), pair("ETHUSD", 1));
+    assert_eq!(cached.list_pairs().len(), 2);
+
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    let stale = ExchangeInfo::new();
+    assert!(!stale.load(&path, Duration::from_millis(1)).expect("Couldn't load cache."));
+    assert!(stale.list_pairs().is_empty());
+
+    fs::remove_file(&path).ok();
+    assert!(!stale.load(&path, Duration::from_secs(60)).expect("Couldn't load cache."));
+}
+
+#[tokio::test]
+async fn concurrent_saves() {
+    let directory = std::env::temp_dir()
+        .join(format!("openlimits-exchange-info-saves-{}", process::id()));
+    let path = directory.join("exchange_info.json");
+    let info = ExchangeInfo::new();
+    info.update(vec![pair("BTCUSD", 1), pair("ETHUSD", 1)])
+        .expect("Couldn't update.");
+
+    let saves: Vec<_> = (0..8)
+        .map(|_| {
+            let info = info.clone();
+            let path = path.clone();
+            std::thread::spawn(move || info.save(&path))
+        })
+        .collect();
+    for save in saves {
+        save.join()
+            .expect("Couldn't join save.")
+            .expect("Couldn't save cache.");
+    }
+
+    let cached = ExchangeInfo::new();
+    assert!(cached.load(&path, Duration::from_secs(60)).expect("Couldn't load cache."));
+    assert_eq!(cached.list_pairs().len(), 2);
+    let files = fs::read_dir(&directory).expect("Couldn't read directory.").count();
+    assert_eq!(files, 1);
+
+    fs::remove_dir_all(&directory).ok();
+}
@@ -0,0 +1,150 @@
+use openlimits::{
+    exchange::simulated::{SimulatedExchange, SimulatedParameters, SimulatedVenue},
+    exchange::traits::info::CurrencyAliases,
+    model::websocket::OpenLimitsWebSocketMessage,
+    model::{Instrument, Side, Trade},
+    prelude::*,
+};
+use rust_decimal::prelude::Decimal;
+
+fn pair(base: &str, quote: &str, symbol: &str) -> MarketPair {
+    MarketPair {
+        base: String::from(base),
+        quote: String::from(quote),
+        symbol: String::from(symbol),
+        base_increment: Decimal::new(1, 3),
+        quote_increment: Decimal::new(1, 2),
+        ..Default::default()
+    }
+}
+
+async fn init() -> SimulatedExchange {
+    let venue = SimulatedVenue::new(vec![
+        pair("XBT", "USD", "XBTUSD"),
+        pair("ETH", "USD", "ETHUSD"),
+        pair("ETH", "USDC", "ETHUSDC"),
+        pair("sol", "usdc", "sol_usdc"),
+    ]);
+    SimulatedExchange::new(SimulatedParameters::new(venue))
+        .await
+        .expect("Couldn't create exchange.")
+}
+
+#[test]
+fn parse() {
+    let instrument = Instrument::parse("btc/usdt").expect("Couldn't parse instrument.");
+    assert_eq!(instrument, Instrument::new("BTC", "USDT"));
+    assert_eq!(instrument.to_string(), "BTC/USDT");
+    assert_eq!(Instrument::parse("BTCUSDT"), None);
+    assert_eq!(Instrument::parse("BTC/"), None);
+    assert_eq!(Instrument::parse("BTC/USD/T"), None);
+}
+
+#[tokio::test]
+async fn symbol_to_instrument() {
+    let exchange = init().await;
+    let info = &exchange.exchange_info;
+    let instrument = info.instrument("XBTUSD").expect("Couldn't get instrument.");
+    assert_eq!(instrument, Instrument::new("BTC", "USD"));
+    let instrument = info.instrument("sol_usdc").expect("Couldn't get instrument.");
+    assert_eq!(instrument, Instrument::new("SOL", "USDC"));
+    assert!(info.instrument("LTCUSD").is_err());
+}
+
+#[tokio::test]
+async fn instrument_to_symbol() {
+    let exchange = init().await;
+    let info = &exchange.exchange_info;
+    assert_eq!(info.symbol("BTC/USD").expect("Couldn't get symbol."), "XBTUSD");
+    assert_eq!(info.symbol("xbt/usd").expect("Couldn't get symbol."), "XBTUSD");
+    assert_eq!(info.symbol("SOL/USDC").expect("Couldn't get symbol."), "sol_usdc");
+    assert_eq!(info.symbol("XBTUSD").expect("Couldn't get symbol."), "XBTUSD");
+    assert!(info.symbol("LTC/USD").is_err());
+}
+
+#[tokio::test]
+async fn configurable_aliases() {
+    let exchange = init().await;
+    let info = &exchange.exchange_info;
+    assert!(info.symbol("SOL/USD").is_err());
+
+    info.set_aliases(CurrencyAliases::default().with_alias("USDC", "USD"))
+        .expect("Couldn't set aliases.");
+    assert_eq!(info.symbol("SOL/USD").expect("Couldn't get symbol."), "sol_usdc");
+    assert_eq!(info.symbol("ETH/USD").expect("Couldn't get symbol."), "ETHUSD");
+    assert_eq!(info.symbol("ETH/USDC").expect("Couldn't get symbol."), "ETHUSDC");
+    let instrument = info.instrument("ETHUSDC").expect("Couldn't get instrument.");
+    assert_eq!(instrument, Instrument::new("ETH", "USD"));
+
+    info.set_aliases(CurrencyAliases::empty())
+        .expect("Couldn't set aliases.");
+    assert!(info.symbol("BTC/USD").is_err());
+}
+
+#[tokio::test]
+async fn get_pair_by_instrument() {
+    let exchange = init().await;
+    let handle = exchange.get_pair("BTC/USD").await.expect("Couldn't get pair.");
+    let pair = handle.read().expect("Couldn't read pair.");
+    assert_eq!(pair.symbol, "XBTUSD");
+}
+
+#[tokio::test]
+async fn market_data_carry_both_forms() {
+    let exchange = init().await;
+    let book = exchange
+        .order_book(&OrderBookRequest {
+            market_pair: String::from("XBTUSD"),
+        })
+        .await
+        .expect("Couldn't get order book.");
+    assert_eq!(book.market_pair.as_deref(), Some("XBTUSD"));
+    assert_eq!(book.instrument, Some(Instrument::new("BTC", "USD")));
+
+    let book = exchange
+        .exchange_info
+        .translate_market("SOL/USDC", OrderBookResponse::default());
+    assert_eq!(book.market_pair.as_deref(), Some("sol_usdc"));
+    assert_eq!(book.instrument, Some(Instrument::new("SOL", "USDC")));
+}
+
+#[tokio::test]
+async fn websocket_messages_carry_both_forms() {
+    let exchange = init().await;
+    let trade = Trade {
+        id: String::from("1"),
+        buyer_order_id: None,
+        seller_order_id: None,
+        market_pair: String::from("ETHUSDC"),
+        instrument: None,
+        price: Decimal::new(2000, 0),
+        qty: Decimal::new(1, 0),
+        fees: None,
+        side: Side::Buy,
+        liquidity: None,
+        created_at: 0,
+    };
+    let message = exchange
+        .exchange_info
+        .translate(OpenLimitsWebSocketMessage::Trades(vec![trade]));
+
+    match message {
+        OpenLimitsWebSocketMessage::Trades(trades) => {
+            assert_eq!(trades[0].instrument, Some(Instrument::new("ETH", "USDC")));
+        }
+        message => panic!("Unexpected message {:?}", message),
+    }
+}
+
+#[tokio::test]
+async fn index_follows_the_markets() {
+    let exchange = init().await;
+    let info = &exchange.exchange_info;
+    info.update(vec![pair("XBT", "USD", "XBTUSD"), pair("LTC", "USD", "LTCUSD")])
+        .expect("Couldn't update markets.");
+
+    assert_eq!(info.symbol("LTC/USD").expect("Couldn't get symbol."), "LTCUSD");
+    assert_eq!(info.symbol("BTC/USD").expect("Couldn't get symbol."), "XBTUSD");
+    assert!(info.symbol("ETH/USD").is_err());
+    assert!(info.instrument("ETHUSD").is_err());
+}
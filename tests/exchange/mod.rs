@@ -1 +1,4 @@
 pub mod websocket;
+pub mod pagination;
+pub mod exchange_info;
+pub mod instrument;
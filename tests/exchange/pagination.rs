@@ -0,0 +1,234 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::stream::StreamExt;
+use openlimits::{
+    errors::OpenLimitsError,
+    exchange::traits::pagination::{paginate, PageCursor, PaginationConfig},
+    model::{Candle, Paginator, Side, Trade},
+};
+use rust_decimal::prelude::Decimal;
+
+fn candles() -> Vec<Candle> {
+    (0..10)
+        .map(|minute| Candle {
+            market_pair: None,
+            instrument: None,
+            time: minute * 60000,
+            low: Decimal::new(1, 0),
+            high: Decimal::new(1, 0),
+            open: Decimal::new(1, 0),
+            close: Decimal::new(1, 0),
+            volume: Decimal::new(1, 0),
+        })
+        .collect()
+}
+
+fn trades() -> Vec<Trade> {
+    (1..=7)
+        .map(|id| Trade {
+            id: id.to_string(),
+            buyer_order_id: None,
+            seller_order_id: None,
+            market_pair: String::from("BTCUSD"),
+            instrument: None,
+            price: Decimal::new(1, 0),
+            qty: Decimal::new(1, 0),
+            fees: None,
+            side: Side::Buy,
+            liquidity: None,
+            created_at: id,
+        })
+        .collect()
+}
+
+/// Candles from `start_time` to `end_time` included, oldest first
+fn candles_page(paginator: &Paginator) -> Vec<Candle> {
+    candles()
+        .into_iter()
+        .filter(|candle| paginator.start_time.map_or(true, |start| candle.time >= start))
+        .filter(|candle| paginator.end_time.map_or(true, |end| candle.time <= end))
+        .take(paginator.limit.unwrap_or(u64::MAX) as usize)
+        .collect()
+}
+
+fn ids(trades: &[Trade]) -> Vec<u64> {
+    trades.iter().map(|trade| trade.id.parse().unwrap()).collect()
+}
+
+#[tokio::test]
+async fn time_cursor() {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    let paginator = Paginator {
+        start_time: Some(60000),
+        end_time: Some(480000),
+        ..Default::default()
+    };
+    let candles: Vec<Candle> = paginate(
+        paginator,
+        PaginationConfig::new(PageCursor::Time).with_page_size(3),
+        move |paginator| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async move { Ok(candles_page(&paginator)) }
+        },
+    )
+    .map(|candle| candle.expect("Couldn't get candle"))
+    .collect()
+    .await;
+
+    let times: Vec<u64> = candles.iter().map(|candle| candle.time / 60000).collect();
+    assert_eq!(times, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(requests.load(Ordering::SeqCst), 4);
+}
+
+#[tokio::test]
+async fn time_cursor_moves_past_pages_of_a_single_time() {
+    let trades: Vec<Trade> = paginate(
+        Paginator::default(),
+        PaginationConfig::new(PageCursor::Time).with_page_size(3),
+        |paginator| async move {
+            let start = paginator.start_time.unwrap_or(0);
+            Ok(trades()
+                .into_iter()
+                .map(|trade| Trade {
+                    created_at: trade.created_at.saturating_sub(3).max(1),
+                    ..trade
+                })
+                .filter(|trade| trade.created_at >= start)
+                .take(3)
+                .collect())
+        },
+    )
+    .map(|trade| trade.expect("Couldn't get trade"))
+    .collect()
+    .await;
+
+    assert_eq!(ids(&trades), vec![1, 2, 3, 5, 6, 7]);
+}
+
+#[tokio::test]
+async fn next_id_cursor() {
+    // Binance: `after` is the first id of the page
+    let trades: Vec<Trade> = paginate(
+        Paginator::default(),
+        PaginationConfig::new(PageCursor::NextId).with_page_size(3),
+        |paginator| async move {
+            let from: u64 = paginator.after.map_or(0, |after| after.parse().unwrap());
+            Ok(trades()
+                .into_iter()
+                .filter(|trade| trade.created_at >= from)
+                .take(3)
+                .collect())
+        },
+    )
+    .map(|trade| trade.expect("Couldn't get trade"))
+    .collect()
+    .await;
+
+    assert_eq!(ids(&trades), vec![1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[tokio::test]
+async fn previous_id_cursor() {
+    // Coinbase: newest first, `after` excludes the ids from it
+    let trades: Vec<Trade> = paginate(
+        Paginator::default(),
+        PaginationConfig::new(PageCursor::PreviousId).with_page_size(3),
+        |paginator| async move {
+            let before: u64 = paginator.after.map_or(u64::MAX, |after| after.parse().unwrap());
+            Ok(trades()
+                .into_iter()
+                .rev()
+                .filter(|trade| trade.created_at < before)
+                .take(3)
+                .collect())
+        },
+    )
+    .map(|trade| trade.expect("Couldn't get trade"))
+    .collect()
+    .await;
+
+    assert_eq!(ids(&trades), vec![7, 6, 5, 4, 3, 2, 1]);
+}
+
+#[tokio::test]
+async fn pages_are_requested_on_demand() {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    let stream = paginate(
+        Paginator::default(),
+        PaginationConfig::new(PageCursor::Time).with_page_size(3),
+        move |paginator| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async move { Ok(candles_page(&paginator)) }
+        },
+    );
+
+    let first: Vec<_> = stream.take(3).collect().await;
+    assert_eq!(first.len(), 3);
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn max_pages() {
+    let candles: Vec<_> = paginate(
+        Paginator::default(),
+        PaginationConfig::new(PageCursor::Time)
+            .with_page_size(3)
+            .with_max_pages(2),
+        |paginator| async move { Ok(candles_page(&paginator)) },
+    )
+    .collect()
+    .await;
+
+    assert_eq!(candles.len(), 5);
+}
+
+#[tokio::test]
+async fn rate_limited_requests_are_retried() {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    let candles: Vec<_> = paginate(
+        Paginator::default(),
+        PaginationConfig::new(PageCursor::Time)
+            .with_page_size(20)
+            .with_retries(2, Duration::from_millis(1)),
+        move |paginator| {
+            let attempt = counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                match attempt {
+                    0 => Err(OpenLimitsError::TooManyRequests()),
+                    _ => Ok(candles_page(&paginator)),
+                }
+            }
+        },
+    )
+    .collect()
+    .await;
+
+    assert_eq!(candles.len(), 10);
+    assert!(candles.iter().all(Result::is_ok));
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn errors_end_the_stream() {
+    let candles: Vec<_> = paginate(
+        Paginator::default(),
+        PaginationConfig::new(PageCursor::Time).with_page_size(3),
+        |paginator| async move {
+            match paginator.start_time {
+                None => Ok(candles_page(&paginator)),
+                Some(_) => Err(OpenLimitsError::InvalidParameter(String::from("page"))),
+            }
+        },
+    )
+    .collect()
+    .await;
+
+    assert_eq!(candles.len(), 4);
+    assert!(candles[..3].iter().all(Result::is_ok));
+    assert!(matches!(candles[3], Err(OpenLimitsError::InvalidParameter(_))));
+}
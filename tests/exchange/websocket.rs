@@ -5,7 +5,7 @@
 use dotenv::dotenv;
 use nash_native_client::Environment;
 use openlimits::exchange::any::{AnyExchange, AnyWsExchange};
-use openlimits::exchange::binance::{Binance, BinanceCredentials, BinanceParameters};
+use openlimits::exchange::binance::{Binance, BinanceCredentials, BinanceEnvironment, BinanceParameters};
 use openlimits::exchange::coinbase::client::websocket::CoinbaseWebsocket;
 use openlimits::exchange::coinbase::{Coinbase, CoinbaseCredentials, CoinbaseParameters};
 use openlimits::OpenLimits;
@@ -38,7 +38,8 @@ async fn _nash() -> Result<Nash> {
         sign_states_loop_interval: None,
         fill_pool_loop_interval: None,
         turn_off_sign_states: false,
-        fill_pool_loop_blockchains: None
+        fill_pool_loop_blockchains: None,
+        exchange_info_cache: None,
     };
     OpenLimits::instantiate(parameters).await
 }
@@ -49,7 +50,10 @@ async fn _binance() -> Result<Binance> {
             api_key: env::var("BINANCE_API_KEY").expect("Couldn't get environment variable."),
             api_secret: env::var("BINANCE_API_SECRET").expect("Couldn't get environment variable."),
         }),
-        sandbox: true,
+        environment: BinanceEnvironment::Testnet,
+        http: Default::default(),
+        validation: Default::default(),
+        exchange_info_cache: None,
     };
     OpenLimits::instantiate(parameters).await
 }
@@ -62,6 +66,8 @@ async fn coinbase() -> Result<Coinbase> {
             api_secret: env::var("COINBASE_API_SECRET").unwrap(),
             passphrase: env::var("COINBASE_PASSPHRASE").unwrap(),
         }),
+        http: Default::default(),
+        exchange_info_cache: None,
     };
     OpenLimits::instantiate(parameters).await
 }
@@ -81,8 +87,10 @@ async fn coinbase_websocket() -> OpenLimitsWs<CoinbaseWebsocket> {
             api_key: env::var("COINBASE_API_KEY").unwrap(),
             passphrase: env::var("COINBASE_PASSPHRASE").unwrap(),
         }),
+        http: Default::default(),
+        exchange_info_cache: None,
     });
-    OpenLimitsWs { websocket }
+    OpenLimitsWs::new(websocket)
 }
 
 async fn init_ws() -> AnyWsExchange {
@@ -0,0 +1,410 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
+use openlimits::{
+    errors::OpenLimitsError,
+    exchange::shared::Result,
+    exchange::simulated::SimulatedExchange,
+    execution::{
+        EndOfSchedule, ExecutionConfig, ExecutionEvent, ExecutionHandle, Iceberg, Pov, Twap, Vwap,
+    },
+    model::{
+        Balance, CancelAllOrdersRequest, CancelOrderRequest, Candle, GetHistoricRatesRequest,
+        GetHistoricTradesRequest, GetOrderHistoryRequest, GetOrderRequest,
+        GetPriceTickerRequest, OpenLimitOrderRequest, OpenMarketOrderRequest, Order,
+        OrderCanceled, OrderStatus, Paginator, Side, Ticker, Trade, TradeHistoryRequest,
+    },
+    prelude::*,
+};
+use rust_decimal::prelude::Decimal;
+
+use crate::fixtures::{self, limit};
+
+/// The maker resting orders and the client the algorithms trade with
+async fn init() -> (SimulatedExchange, Arc<SimulatedExchange>) {
+    let venue = fixtures::venue(fixtures::btcusd());
+    let maker = fixtures::exchange(&venue, "maker").await;
+    let taker = fixtures::exchange(&venue, "taker").await;
+    (maker, Arc::new(taker))
+}
+
+/// A venue returning new orders before they're matched, their fills are only seen by reading
+/// them again when it `settles`
+struct Delayed {
+    exchange: SimulatedExchange,
+    settles: bool,
+}
+
+fn pending(order: Order) -> Order {
+    Order {
+        status: OrderStatus::Pending,
+        remaining: None,
+        trades: Vec::new(),
+        ..order
+    }
+}
+
+#[async_trait]
+impl ExchangeAccount for Delayed {
+    async fn limit_buy(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        self.exchange.limit_buy(req).await.map(pending)
+    }
+
+    async fn limit_sell(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        self.exchange.limit_sell(req).await.map(pending)
+    }
+
+    async fn market_buy(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        self.exchange.market_buy(req).await.map(pending)
+    }
+
+    async fn market_sell(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        self.exchange.market_sell(req).await.map(pending)
+    }
+
+    async fn cancel_order(&self, req: &CancelOrderRequest) -> Result<OrderCanceled> {
+        self.exchange.cancel_order(req).await
+    }
+
+    async fn cancel_all_orders(&self, req: &CancelAllOrdersRequest) -> Result<Vec<OrderCanceled>> {
+        self.exchange.cancel_all_orders(req).await
+    }
+
+    async fn get_all_open_orders(&self) -> Result<Vec<Order>> {
+        self.exchange.get_all_open_orders().await
+    }
+
+    async fn get_order_history(&self, req: &GetOrderHistoryRequest) -> Result<Vec<Order>> {
+        self.exchange.get_order_history(req).await
+    }
+
+    async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>> {
+        self.exchange.get_trade_history(req).await
+    }
+
+    async fn get_account_balances(&self, paginator: Option<Paginator>) -> Result<Vec<Balance>> {
+        self.exchange.get_account_balances(paginator).await
+    }
+
+    async fn get_order(&self, req: &GetOrderRequest) -> Result<Order> {
+        let order = self.exchange.get_order(req).await?;
+        Ok(if self.settles { order } else { pending(order) })
+    }
+}
+
+#[async_trait]
+impl ExchangeMarketData for Delayed {
+    async fn order_book(&self, req: &OrderBookRequest) -> Result<OrderBookResponse> {
+        self.exchange.order_book(req).await
+    }
+
+    async fn get_price_ticker(&self, req: &GetPriceTickerRequest) -> Result<Ticker> {
+        self.exchange.get_price_ticker(req).await
+    }
+
+    async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
+        self.exchange.get_historic_rates(req).await
+    }
+
+    async fn get_historic_trades(&self, req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
+        self.exchange.get_historic_trades(req).await
+    }
+}
+
+#[async_trait]
+impl ExchangeInfoRetrieval for Delayed {
+    async fn get_pair(&self, name: &str) -> Result<MarketPairHandle> {
+        self.exchange.get_pair(name).await
+    }
+
+    async fn retrieve_pairs(&self) -> Result<Vec<MarketPair>> {
+        self.exchange.retrieve_pairs().await
+    }
+
+    async fn refresh_market_info(&self) -> Result<Vec<MarketPairHandle>> {
+        self.exchange.refresh_market_info().await
+    }
+}
+
+fn trade(qty: i64) -> Trade {
+    Trade {
+        id: String::from("1"),
+        buyer_order_id: None,
+        seller_order_id: None,
+        market_pair: String::from("BTCUSD"),
+        instrument: None,
+        price: Decimal::new(100, 0),
+        qty: Decimal::new(qty, 0),
+        fees: None,
+        side: Side::Buy,
+        liquidity: None,
+        created_at: 0,
+    }
+}
+
+async fn events(handle: &mut ExecutionHandle) -> Vec<ExecutionEvent> {
+    let mut events = Vec::new();
+    while let Some(event) = handle.next_event().await {
+        events.push(event);
+    }
+    events
+}
+
+fn placed(events: &[ExecutionEvent]) -> usize {
+    events
+        .iter()
+        .filter(|event| matches!(event, ExecutionEvent::OrderPlaced(_)))
+        .count()
+}
+
+#[tokio::test]
+async fn twap() {
+    let (maker, taker) = init().await;
+    maker.limit_sell(&limit(100, 10)).await.expect("Couldn't place sell order");
+    let config = ExecutionConfig::new("BTCUSD", Side::Buy, Decimal::new(4, 0))
+        .with_limit_price(Decimal::new(101, 0));
+
+    let mut handle = Twap::new(Duration::from_millis(40), 4).start(taker, config);
+    let events = events(&mut handle).await;
+    let progress = handle.wait().await.expect("Execution failed");
+
+    assert_eq!(placed(&events), 4);
+    assert_eq!(progress.filled, Decimal::new(4, 0));
+    assert_eq!(progress.average_price, Some(Decimal::new(100, 0)));
+    assert!(matches!(events.last(), Some(ExecutionEvent::Finished(_))));
+}
+
+#[tokio::test]
+async fn delayed_fills() {
+    let (maker, taker) = init().await;
+    maker.limit_sell(&limit(100, 10)).await.expect("Couldn't place sell order");
+    let taker = Arc::new(Delayed {
+        exchange: taker.as_ref().clone(),
+        settles: true,
+    });
+    let config = ExecutionConfig::new("BTCUSD", Side::Buy, Decimal::new(4, 0))
+        .with_limit_price(Decimal::new(101, 0))
+        .with_poll_interval(Duration::from_millis(5));
+
+    let progress = Twap::new(Duration::from_millis(20), 2)
+        .start(taker.clone(), config)
+        .wait()
+        .await
+        .expect("Execution failed");
+
+    // The fills of every slice are read before the next one is sized
+    assert_eq!(progress.filled, Decimal::new(4, 0));
+    assert_eq!(progress.orders, 2);
+    let btc = taker.get_balance("BTC").await.expect("Couldn't get balance");
+    assert_eq!(btc.total, Decimal::new(104, 0));
+}
+
+#[tokio::test]
+async fn unsettled_orders_time_out() {
+    let (maker, taker) = init().await;
+    maker.limit_sell(&limit(100, 10)).await.expect("Couldn't place sell order");
+    let taker = Arc::new(Delayed {
+        exchange: taker.as_ref().clone(),
+        settles: false,
+    });
+    let config = ExecutionConfig::new("BTCUSD", Side::Buy, Decimal::new(2, 0))
+        .with_limit_price(Decimal::new(101, 0))
+        .with_poll_interval(Duration::from_millis(5))
+        .with_settle_timeout(Duration::from_millis(20));
+
+    let execution = Twap::new(Duration::from_millis(10), 2).start(taker, config).wait();
+    let progress = tokio::time::timeout(Duration::from_secs(1), execution)
+        .await
+        .expect("Execution didn't time out")
+        .expect("Execution failed");
+
+    assert_eq!(progress.orders, 2);
+}
+
+#[tokio::test]
+async fn cancel_unsettled_order() {
+    let (maker, taker) = init().await;
+    maker.limit_sell(&limit(100, 10)).await.expect("Couldn't place sell order");
+    let taker = Arc::new(Delayed {
+        exchange: taker.as_ref().clone(),
+        settles: false,
+    });
+    let config = ExecutionConfig::new("BTCUSD", Side::Buy, Decimal::new(2, 0))
+        .with_limit_price(Decimal::new(101, 0))
+        .with_end_of_schedule(EndOfSchedule::FinishAggressively)
+        .with_poll_interval(Duration::from_millis(5));
+
+    let mut handle = Twap::new(Duration::from_millis(10), 1).start(taker, config);
+    tokio::time::sleep(Duration::from_millis(30)).await;
+    handle.cancel();
+    let events = tokio::time::timeout(Duration::from_secs(1), events(&mut handle))
+        .await
+        .expect("Execution wasn't cancelled");
+
+    // The slice being settled is left, and the end of schedule doesn't take what's left
+    assert_eq!(placed(&events), 1);
+    assert!(matches!(events.last(), Some(ExecutionEvent::Cancelled(_))));
+}
+
+#[tokio::test]
+async fn price_protection() {
+    let (maker, taker) = init().await;
+    maker.limit_sell(&limit(105, 10)).await.expect("Couldn't place sell order");
+    let config = ExecutionConfig::new("BTCUSD", Side::Buy, Decimal::new(2, 0))
+        .with_limit_price(Decimal::new(101, 0))
+        .with_end_of_schedule(EndOfSchedule::FinishAggressively);
+
+    let mut handle = Twap::new(Duration::from_millis(20), 2).start(taker, config);
+    let events = events(&mut handle).await;
+    let progress = handle.wait().await.expect("Execution failed");
+
+    assert_eq!(placed(&events), 0);
+    // Both slices and the end of the schedule are held back
+    let best = Decimal::new(105, 0);
+    let limited = events
+        .iter()
+        .filter(|event| matches!(event, ExecutionEvent::PriceLimited(price) if *price == best))
+        .count();
+    assert_eq!(limited, 3);
+    assert_eq!(progress.filled, Decimal::new(0, 0));
+}
+
+#[tokio::test]
+async fn pov() {
+    let (maker, taker) = init().await;
+    maker.limit_sell(&limit(100, 10)).await.expect("Couldn't place sell order");
+    let config = ExecutionConfig::new("BTCUSD", Side::Buy, Decimal::new(5, 0));
+    let trades = stream::iter(vec![Ok(trade(10)), Ok(trade(10))]).boxed();
+
+    let pov = Pov::new(Decimal::new(1, 1), Duration::from_secs(10)).expect("Invalid pov");
+    let mut handle = pov.start(taker, config, trades);
+    let events = events(&mut handle).await;
+    let progress = handle.wait().await.expect("Execution failed");
+
+    // 10% of the 20 traded, the rest is canceled when the feed ends
+    assert_eq!(placed(&events), 2);
+    assert_eq!(progress.filled, Decimal::new(2, 0));
+    assert_eq!(progress.remaining(), Decimal::new(3, 0));
+}
+
+#[test]
+fn pov_participation() {
+    let duration = Duration::from_secs(10);
+    for participation in [Decimal::new(0, 0), Decimal::new(1, 0), Decimal::new(15, 1)].iter() {
+        let result = Pov::new(*participation, duration);
+        assert!(matches!(result, Err(OpenLimitsError::InvalidParameter(_))));
+    }
+}
+
+#[tokio::test]
+async fn vwap() {
+    let (maker, taker) = init().await;
+    maker.limit_sell(&limit(100, 10)).await.expect("Couldn't place sell order");
+    let config = ExecutionConfig::new("BTCUSD", Side::Buy, Decimal::new(4, 0))
+        .with_limit_price(Decimal::new(101, 0));
+    let profile = vec![Decimal::new(1, 0), Decimal::new(0, 0), Decimal::new(3, 0)];
+
+    let vwap = Vwap::new(Duration::from_millis(30), profile).expect("Invalid vwap");
+    let mut handle = vwap.start(taker, config);
+    let events = events(&mut handle).await;
+    let progress = handle.wait().await.expect("Execution failed");
+
+    let sizes: Vec<Decimal> = events
+        .iter()
+        .filter_map(|event| match event {
+            ExecutionEvent::OrderPlaced(order) => Some(order.size),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(sizes, vec![Decimal::new(1, 0), Decimal::new(3, 0)]);
+    assert_eq!(progress.filled, Decimal::new(4, 0));
+}
+
+#[test]
+fn vwap_profile() {
+    let duration = Duration::from_secs(10);
+    let empty = Vwap::new(duration, vec![Decimal::new(0, 0)]);
+    let negative = Vwap::new(duration, vec![Decimal::new(1, 0), Decimal::new(-1, 0)]);
+
+    assert!(matches!(empty, Err(OpenLimitsError::InvalidParameter(_))));
+    assert!(matches!(negative, Err(OpenLimitsError::InvalidParameter(_))));
+}
+
+#[tokio::test]
+async fn finish_aggressively() {
+    let (maker, taker) = init().await;
+    maker.limit_sell(&limit(100, 10)).await.expect("Couldn't place sell order");
+    let config = ExecutionConfig::new("BTCUSD", Side::Buy, Decimal::new(5, 0))
+        .with_limit_price(Decimal::new(101, 0))
+        .with_end_of_schedule(EndOfSchedule::FinishAggressively);
+    let trades = stream::iter(vec![Ok(trade(10))]).boxed();
+
+    let pov = Pov::new(Decimal::new(1, 1), Duration::from_secs(10)).expect("Invalid pov");
+    let progress = pov
+        .start(taker, config, trades)
+        .wait()
+        .await
+        .expect("Execution failed");
+
+    assert!(progress.is_complete());
+    assert_eq!(progress.orders, 2);
+}
+
+#[tokio::test]
+async fn iceberg() {
+    let (maker, taker) = init().await;
+    let config = ExecutionConfig::new("BTCUSD", Side::Buy, Decimal::new(3, 0))
+        .with_limit_price(Decimal::new(100, 0))
+        .with_poll_interval(Duration::from_millis(5));
+
+    let mut handle = Iceberg::new(Decimal::new(1, 0)).start(taker.clone(), config);
+    for _ in 0..3 {
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        // Only the displayed size rests in the book
+        let book = taker
+            .order_book(&OrderBookRequest {
+                market_pair: String::from("BTCUSD"),
+            })
+            .await
+            .expect("Couldn't read book");
+        assert_eq!(book.bids.len(), 1);
+        assert_eq!(book.bids[0].qty, Decimal::new(1, 0));
+        maker.limit_sell(&limit(100, 1)).await.expect("Couldn't place sell order");
+    }
+    let events = events(&mut handle).await;
+    let progress = handle.wait().await.expect("Execution failed");
+
+    assert_eq!(placed(&events), 3);
+    assert_eq!(progress.filled, Decimal::new(3, 0));
+}
+
+#[tokio::test]
+async fn cancel() {
+    let (_maker, taker) = init().await;
+    let config = ExecutionConfig::new("BTCUSD", Side::Buy, Decimal::new(3, 0))
+        .with_limit_price(Decimal::new(100, 0))
+        .with_poll_interval(Duration::from_millis(5));
+
+    let mut handle = Iceberg::new(Decimal::new(1, 0)).start(taker.clone(), config);
+    tokio::time::sleep(Duration::from_millis(30)).await;
+    handle.cancel();
+    let events = events(&mut handle).await;
+    let progress = handle.wait().await.expect("Execution failed");
+
+    assert!(events.iter().any(|event| matches!(event, ExecutionEvent::OrderCanceled(_))));
+    assert!(matches!(events.last(), Some(ExecutionEvent::Cancelled(_))));
+    assert_eq!(progress.filled, Decimal::new(0, 0));
+    let open = taker.get_all_open_orders().await.expect("Couldn't get orders");
+    assert!(open.is_empty());
+}
+
+#[tokio::test]
+async fn iceberg_needs_limit_price() {
+    let (_maker, taker) = init().await;
+    let config = ExecutionConfig::new("BTCUSD", Side::Buy, Decimal::new(3, 0));
+    let result = Iceberg::new(Decimal::new(1, 0)).start(taker, config).wait().await;
+
+    assert!(matches!(result, Err(OpenLimitsError::MissingParameter(_))));
+}
@@ -0,0 +1,54 @@
+//! Fixtures shared by the tests trading on simulated venues
+
+use std::sync::Arc;
+
+use openlimits::{
+    exchange::simulated::{ManualClock, SimulatedExchange, SimulatedParameters, SimulatedVenue},
+    model::{OpenLimitOrderRequest, TimeInForce},
+    prelude::MarketPair,
+};
+use rust_decimal::prelude::Decimal;
+
+/// The BTCUSD market of the simulated venues
+pub fn btcusd() -> MarketPair {
+    MarketPair {
+        base: String::from("BTC"),
+        quote: String::from("USD"),
+        symbol: String::from("BTCUSD"),
+        base_increment: Decimal::new(1, 3),
+        quote_increment: Decimal::new(1, 2),
+        min_base_trade_size: None,
+        min_quote_trade_size: None,
+        ..Default::default()
+    }
+}
+
+/// A venue trading `market` with a manual clock started at 0
+pub fn venue(market: MarketPair) -> SimulatedVenue {
+    SimulatedVenue::new(vec![market]).with_clock(Arc::new(ManualClock::new(0)))
+}
+
+/// The parameters of an account of the venue holding 100 BTC and 100000 USD
+pub fn parameters(venue: &SimulatedVenue, account: &str) -> SimulatedParameters {
+    SimulatedParameters::new(venue.clone())
+        .with_account(account)
+        .with_balance("BTC", Decimal::new(100, 0))
+        .with_balance("USD", Decimal::new(100000, 0))
+}
+
+pub async fn exchange(venue: &SimulatedVenue, account: &str) -> SimulatedExchange {
+    SimulatedExchange::new(parameters(venue, account))
+        .await
+        .expect("Couldn't create simulated exchange.")
+}
+
+/// A good till cancelled BTCUSD order
+pub fn limit(price: i64, size: i64) -> OpenLimitOrderRequest {
+    OpenLimitOrderRequest {
+        market_pair: String::from("BTCUSD"),
+        size: Decimal::new(size, 0),
+        price: Decimal::new(price, 0),
+        time_in_force: TimeInForce::GoodTillCancelled,
+        post_only: false,
+    }
+}
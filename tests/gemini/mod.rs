@@ -0,0 +1,2 @@
+mod signature;
+mod ws_streams;
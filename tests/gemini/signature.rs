@@ -0,0 +1,66 @@
+use hmac::{Hmac, Mac, NewMac};
+use openlimits::{errors::OpenLimitsError, exchange::gemini::client::Transport};
+use serde_json::{json, Value};
+use sha2::Sha384;
+
+fn transport() -> Transport {
+    Transport::with_credential("key", "secret", false).expect("Couldn't create transport.")
+}
+
+fn decode(payload: &str) -> Value {
+    let payload = base64::decode(payload).expect("Payload isn't base64.");
+    serde_json::from_slice(&payload).expect("Payload isn't JSON.")
+}
+
+#[test]
+fn signed_payload() {
+    let transport = transport();
+    let params = json!({"symbol": "btcusd", "amount": "1"});
+    let (key, payload, signature) = transport
+        .signed_payload("/v1/order/new", Some(&params))
+        .expect("Couldn't sign payload.");
+    assert_eq!(key, "key");
+
+    let decoded = decode(&payload);
+    assert_eq!(decoded["request"], "/v1/order/new");
+    assert_eq!(decoded["symbol"], "btcusd");
+    assert_eq!(decoded["amount"], "1");
+    assert!(decoded["nonce"].as_u64().is_some());
+
+    let mut mac = Hmac::<Sha384>::new_varkey(b"secret").expect("Couldn't create HMAC.");
+    mac.update(payload.as_bytes());
+    assert_eq!(signature, hex::encode(mac.finalize().into_bytes()));
+    assert_eq!(signature.len(), 96);
+}
+
+#[test]
+fn payload_without_parameters() {
+    let (_, payload, _) = transport()
+        .signed_payload::<()>("/v1/balances", None)
+        .expect("Couldn't sign payload.");
+    let decoded = decode(&payload);
+    assert_eq!(decoded["request"], "/v1/balances");
+    assert_eq!(decoded.as_object().map(|map| map.len()), Some(2));
+}
+
+#[test]
+fn nonces_increase() {
+    let transport = transport();
+    let nonces: Vec<u64> = (0..100).map(|_| transport.nonce()).collect();
+    assert!(nonces.windows(2).all(|pair| pair[0] < pair[1]));
+
+    // Clones share the last nonce
+    let clone = transport.clone();
+    assert!(clone.nonce() > nonces[nonces.len() - 1]);
+    let (_, payload, _) = transport
+        .signed_payload::<()>("/v1/balances", None)
+        .expect("Couldn't sign payload.");
+    assert!(decode(&payload)["nonce"].as_u64() > Some(nonces[nonces.len() - 1]));
+}
+
+#[test]
+fn requires_credentials() {
+    let transport = Transport::new(false).expect("Couldn't create transport.");
+    let payload = transport.signed_payload::<()>("/v1/balances", None);
+    assert!(matches!(payload, Err(OpenLimitsError::NoApiKeySet())));
+}
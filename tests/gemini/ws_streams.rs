@@ -0,0 +1,50 @@
+use futures::stream::StreamExt;
+
+use openlimits::{
+    errors::OpenLimitsError,
+    exchange::gemini::{GeminiParameters, GeminiWebsocket},
+    model::websocket::Subscription,
+};
+use openlimits::exchange::traits::stream::{ExchangeWs, OpenLimitsWs};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn orderbook() {
+    let ws = init().await;
+    let s = ws
+        .create_stream(&[Subscription::OrderBookUpdates("BTCUSD".to_string())])
+        .await;
+
+    let ob = s.expect("Couldn't create stream.").next().await;
+
+    print!("{:?}", ob);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn trades() {
+    let ws = init().await;
+    let s = ws
+        .create_stream(&[Subscription::Trades("BTCUSD".to_string())])
+        .await;
+
+    let trades = s.expect("Couldn't create stream.").next().await;
+
+    print!("{:?}", trades);
+}
+
+#[tokio::test]
+async fn unsupported_subscription() {
+    let ws = init().await;
+    let s = ws
+        .create_stream(&[Subscription::Ticker("BTCUSD".to_string())])
+        .await;
+
+    assert!(matches!(s, Err(OpenLimitsError::MissingImplementation(_))));
+}
+
+async fn init() -> OpenLimitsWs<GeminiWebsocket> {
+    OpenLimitsWs::new(
+        GeminiWebsocket::new(GeminiParameters::prod())
+            .await
+            .expect("Failed to create Client"),
+    )
+}
@@ -0,0 +1,29 @@
+use openlimits::exchange::kucoin::model::BulletToken;
+
+#[test]
+fn bullet_token() {
+    let token = r#"{
+        "token": "2neAiuYvAU61ZDXANAGAsiL4-iAExhsBXZxftpOeh_55i3Ysy2q2LEsEWU64mdzU",
+        "instanceServers": [
+            {
+                "endpoint": "wss://push1-v2.kucoin.com/endpoint",
+                "protocol": "websocket",
+                "encrypt": true,
+                "pingInterval": 50000,
+                "pingTimeout": 10000
+            }
+        ]
+    }"#;
+    let token: BulletToken = serde_json::from_str(token).expect("Couldn't parse bullet token.");
+
+    assert_eq!(
+        token.token,
+        "2neAiuYvAU61ZDXANAGAsiL4-iAExhsBXZxftpOeh_55i3Ysy2q2LEsEWU64mdzU"
+    );
+    assert_eq!(token.instance_servers.len(), 1);
+    let server = &token.instance_servers[0];
+    assert_eq!(server.endpoint, "wss://push1-v2.kucoin.com/endpoint");
+    assert!(server.encrypt);
+    assert_eq!(server.ping_interval, 50000);
+    assert_eq!(server.ping_timeout, 10000);
+}
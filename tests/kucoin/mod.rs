@@ -0,0 +1,3 @@
+mod bullet_token;
+mod signature;
+mod ws_streams;
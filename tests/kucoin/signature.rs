@@ -0,0 +1,65 @@
+use openlimits::{errors::OpenLimitsError, exchange::kucoin::client::Transport};
+use reqwest::Method;
+
+const TIMESTAMP: &str = "1600000000000";
+
+fn transport() -> Transport {
+    Transport::with_credential("key", "secret", "passphrase", false)
+        .expect("Couldn't create transport.")
+}
+
+fn header(headers: &reqwest::header::HeaderMap, name: &str) -> String {
+    headers
+        .get(name)
+        .expect("Missing header.")
+        .to_str()
+        .expect("Header isn't a string.")
+        .to_string()
+}
+
+#[test]
+fn signs_the_body() {
+    let body = r#"{"clientOid":"1","side":"buy"}"#;
+    let headers = transport()
+        .signed_headers(TIMESTAMP, &Method::POST, "/api/v1/orders", body)
+        .expect("Couldn't sign request.");
+
+    assert_eq!(header(&headers, "KC-API-KEY"), "key");
+    assert_eq!(header(&headers, "KC-API-TIMESTAMP"), TIMESTAMP);
+    assert_eq!(
+        header(&headers, "KC-API-SIGN"),
+        "mnjnhYg32LcOKYQTJSRNArUMDZ2ibPWM9yXqMQjhNXQ="
+    );
+    assert_eq!(header(&headers, "KC-API-KEY-VERSION"), "2");
+}
+
+#[test]
+fn signs_the_query() {
+    let headers = transport()
+        .signed_headers(TIMESTAMP, &Method::GET, "/api/v1/orders?status=active", "")
+        .expect("Couldn't sign request.");
+
+    assert_eq!(
+        header(&headers, "KC-API-SIGN"),
+        "llLCCh9nKNG/FH2Wwt63he++s3zAX0CMCPNuwY5+QtU="
+    );
+}
+
+#[test]
+fn signs_the_passphrase() {
+    let headers = transport()
+        .signed_headers(TIMESTAMP, &Method::GET, "/api/v1/accounts", "")
+        .expect("Couldn't sign request.");
+
+    assert_eq!(
+        header(&headers, "KC-API-PASSPHRASE"),
+        "sWd5rQWAxDzYJTY6K2sov6seA0l3uNP70anWxITg8IA="
+    );
+}
+
+#[test]
+fn requires_credentials() {
+    let transport = Transport::new(false).expect("Couldn't create transport.");
+    let headers = transport.signed_headers(TIMESTAMP, &Method::GET, "/api/v1/accounts", "");
+    assert!(matches!(headers, Err(OpenLimitsError::NoApiKeySet())));
+}
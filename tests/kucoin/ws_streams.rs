@@ -0,0 +1,39 @@
+use futures::stream::StreamExt;
+
+use openlimits::{
+    exchange::kucoin::{KucoinParameters, KucoinWebsocket},
+    model::websocket::Subscription,
+};
+use openlimits::exchange::traits::stream::{ExchangeWs, OpenLimitsWs};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn orderbook() {
+    let ws = init().await;
+    let s = ws
+        .create_stream(&[Subscription::OrderBookUpdates("BTC-USDT".to_string())])
+        .await;
+
+    let ob = s.expect("Couldn't create stream.").next().await;
+
+    print!("{:?}", ob);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn trades() {
+    let ws = init().await;
+    let s = ws
+        .create_stream(&[Subscription::Trades("BTC-USDT".to_string())])
+        .await;
+
+    let trades = s.expect("Couldn't create stream.").next().await;
+
+    print!("{:?}", trades);
+}
+
+async fn init() -> OpenLimitsWs<KucoinWebsocket> {
+    OpenLimitsWs::new(
+        KucoinWebsocket::new(KucoinParameters::prod())
+            .await
+            .expect("Failed to create Client"),
+    )
+}
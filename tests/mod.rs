@@ -1,7 +1,24 @@
 extern crate openlimits;
 
 mod exchange;
+mod fixtures;
+mod execution;
+mod aggregator;
 mod apis;
+mod backtest;
 mod binance;
+mod bitfinex;
+mod candles;
 mod coinbase;
+mod errors;
+mod gemini;
+mod kucoin;
 mod nash;
+mod okx;
+mod order_manager;
+mod paper;
+mod recorder;
+mod replay;
+mod router;
+mod simulated;
+mod validation;
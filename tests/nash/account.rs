@@ -263,7 +263,8 @@ async fn init() -> Nash {
         sign_states_loop_interval: None,
         fill_pool_loop_interval: None,
         turn_off_sign_states: false,
-        fill_pool_loop_blockchains: None
+        fill_pool_loop_blockchains: None,
+        exchange_info_cache: None,
     };
 
     OpenLimits::instantiate(parameters)
@@ -105,6 +105,7 @@ async fn init() -> Nash {
         fill_pool_loop_interval: None,
         turn_off_sign_states: false,
         fill_pool_loop_blockchains: None,
+        exchange_info_cache: None,
     };
 
     OpenLimits::instantiate(parameters)
@@ -30,7 +30,8 @@ async fn init_exchange() -> Nash {
         sign_states_loop_interval: None,
         fill_pool_loop_interval: None,
         turn_off_sign_states: false,
-        fill_pool_loop_blockchains: None
+        fill_pool_loop_blockchains: None,
+        exchange_info_cache: None,
     };
 
     OpenLimits::instantiate(parameters)
@@ -152,10 +153,11 @@ async fn init() -> OpenLimitsWs<NashWebsocket> {
         sign_states_loop_interval: None,
         fill_pool_loop_interval: None,
         turn_off_sign_states: false,
-        fill_pool_loop_blockchains: None
+        fill_pool_loop_blockchains: None,
+        exchange_info_cache: None,
     })
     .await
     .expect("Couldn't connect.");
 
-    OpenLimitsWs { websocket }
+    OpenLimitsWs::new(websocket)
 }
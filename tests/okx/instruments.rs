@@ -0,0 +1,80 @@
+use openlimits::{
+    exchange::okx::model::{Instrument, InstrumentType},
+    exchange::traits::info::{MarketPair, MarketStatus},
+};
+use rust_decimal::prelude::Decimal;
+
+fn instrument(json: &str) -> Instrument {
+    serde_json::from_str(json).expect("Couldn't parse instrument.")
+}
+
+#[test]
+fn spot_instrument() {
+    let instrument = instrument(
+        r#"{
+            "instType": "SPOT", "instId": "BTC-USDT", "uly": "", "baseCcy": "BTC",
+            "quoteCcy": "USDT", "settleCcy": "", "ctVal": "", "ctValCcy": "",
+            "tickSz": "0.1", "lotSz": "0.00000001", "minSz": "0.00001", "expTime": "",
+            "state": "live"
+        }"#,
+    );
+    assert_eq!(instrument.inst_type, InstrumentType::Spot);
+    assert_eq!(instrument.ct_val, None);
+
+    let pair = MarketPair::from(instrument);
+    assert_eq!(pair.symbol, "BTC-USDT");
+    assert_eq!(pair.base, "BTC");
+    assert_eq!(pair.quote, "USDT");
+    assert_eq!(pair.base_increment, Decimal::new(1, 8));
+    assert_eq!(pair.quote_increment, Decimal::new(1, 1));
+    assert_eq!(pair.min_base_trade_size, Some(Decimal::new(1, 5)));
+    assert_eq!(pair.status, MarketStatus::Trading);
+}
+
+#[test]
+fn swap_instrument() {
+    let instrument = instrument(
+        r#"{
+            "instType": "SWAP", "instId": "BTC-USD-SWAP", "uly": "BTC-USD", "baseCcy": "",
+            "quoteCcy": "", "settleCcy": "BTC", "ctVal": "100", "ctValCcy": "USD",
+            "tickSz": "0.1", "lotSz": "1", "minSz": "1", "expTime": "", "state": "live"
+        }"#,
+    );
+    assert_eq!(instrument.inst_type, InstrumentType::Swap);
+    assert_eq!(instrument.ct_val, Some(Decimal::new(100, 0)));
+
+    let pair = MarketPair::from(instrument);
+    assert_eq!(pair.symbol, "BTC-USD-SWAP");
+    assert_eq!(pair.base, "BTC");
+    assert_eq!(pair.quote, "USD");
+    assert_eq!(pair.base_increment, Decimal::new(1, 0));
+}
+
+#[test]
+fn suspended_instrument() {
+    let instrument = instrument(
+        r#"{
+            "instType": "FUTURES", "instId": "BTC-USD-210326", "uly": "BTC-USD",
+            "settleCcy": "BTC", "ctVal": "100", "ctValCcy": "USD", "tickSz": "0.01",
+            "lotSz": "1", "minSz": "1", "expTime": "1616745600000", "state": "suspend"
+        }"#,
+    );
+    assert_eq!(instrument.inst_type, InstrumentType::Futures);
+    assert_eq!(MarketPair::from(instrument).status, MarketStatus::Halted);
+}
+
+#[test]
+fn instrument_types() {
+    for inst_type in &[
+        InstrumentType::Spot,
+        InstrumentType::Margin,
+        InstrumentType::Swap,
+        InstrumentType::Futures,
+        InstrumentType::Option,
+    ] {
+        let json = serde_json::to_string(inst_type).expect("Couldn't serialize type.");
+        assert_eq!(json, format!("\"{}\"", inst_type.as_str()));
+    }
+    assert_eq!(InstrumentType::Spot.trade_mode(), "cash");
+    assert_eq!(InstrumentType::Swap.trade_mode(), "cross");
+}
@@ -0,0 +1,92 @@
+use std::convert::TryFrom;
+
+use openlimits::{
+    exchange::okx::model::websocket::OkxWebsocketMessage,
+    model::websocket::{OpenLimitsWebSocketMessage, WebSocketResponse},
+    model::{Liquidity, OrderStatus},
+};
+use rust_decimal::prelude::Decimal;
+
+#[test]
+fn order_book_snapshot() {
+    let message = r#"{
+        "arg": {"channel": "books", "instId": "BTC-USDT"},
+        "action": "snapshot",
+        "data": [{
+            "asks": [["8476.98", "415", "0", "13"], ["8477", "7", "0", "2"]],
+            "bids": [["8476.97", "256", "0", "12"]],
+            "ts": "1597026383085",
+            "checksum": -855196043,
+            "prevSeqId": -1,
+            "seqId": 123456
+        }]
+    }"#;
+    let message: OkxWebsocketMessage =
+        serde_json::from_str(message).expect("Couldn't parse order book.");
+    let response = WebSocketResponse::try_from(message).expect("Couldn't convert order book.");
+
+    match response {
+        WebSocketResponse::Generic(OpenLimitsWebSocketMessage::OrderBook(book)) => {
+            assert_eq!(book.asks.len(), 2);
+            assert_eq!(book.bids.len(), 1);
+            assert_eq!(book.update_id, Some(123456));
+            assert_eq!(book.last_update_id, None);
+        }
+        _ => panic!("Unexpected response {:?}", response),
+    }
+}
+
+#[test]
+fn login_error() {
+    let message = r#"{"event": "error", "code": "60009", "msg": "Login failed."}"#;
+    let message: OkxWebsocketMessage =
+        serde_json::from_str(message).expect("Couldn't parse error.");
+
+    assert!(WebSocketResponse::try_from(message).is_err());
+}
+
+#[test]
+fn order_update_with_fill() {
+    let message = r#"{
+        "arg": {"channel": "orders", "instType": "SPOT"},
+        "data": [{
+            "instType": "SPOT",
+            "instId": "BTC-USDT",
+            "ordId": "312269865356374016",
+            "clOrdId": "",
+            "px": "8476.98",
+            "sz": "2",
+            "ordType": "limit",
+            "side": "sell",
+            "state": "partially_filled",
+            "accFillSz": "1",
+            "avgPx": "8476.98",
+            "fee": "-0.01",
+            "feeCcy": "USDT",
+            "tradeId": "242589207",
+            "fillPx": "8476.98",
+            "fillSz": "1",
+            "fillFee": "-0.01",
+            "execType": "M",
+            "cTime": "1597026383085",
+            "uTime": "1597026383090"
+        }]
+    }"#;
+    let message: OkxWebsocketMessage =
+        serde_json::from_str(message).expect("Couldn't parse order.");
+    let response = WebSocketResponse::try_from(message).expect("Couldn't convert order.");
+
+    match response {
+        WebSocketResponse::Generic(OpenLimitsWebSocketMessage::Orders(orders)) => {
+            assert_eq!(orders.len(), 1);
+            assert_eq!(orders[0].status, OrderStatus::PartiallyFilled);
+            assert_eq!(orders[0].remaining, Some(Decimal::new(1, 0)));
+            assert_eq!(orders[0].trades.len(), 1);
+            assert_eq!(orders[0].trades[0].id, "242589207");
+            assert_eq!(orders[0].trades[0].fees, Some(Decimal::new(1, 2)));
+            assert_eq!(orders[0].trades[0].liquidity, Some(Liquidity::Maker));
+            assert_eq!(orders[0].trades[0].created_at, 1597026383090);
+        }
+        _ => panic!("Unexpected response {:?}", response),
+    }
+}
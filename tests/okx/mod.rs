@@ -0,0 +1,3 @@
+mod instruments;
+mod messages;
+mod ws_streams;
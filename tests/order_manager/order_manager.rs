@@ -0,0 +1,263 @@
+use std::time::Duration;
+
+use openlimits::{
+    exchange::simulated::{SimulatedExchange, SimulatedParameters, SimulatedWebsocket},
+    model::{
+        websocket::{OpenLimitsWebSocketMessage, Subscription},
+        CancelOrderRequest, Order, OrderStatus, Side, Trade,
+    },
+    order_manager::{OrderEvent, OrderManager},
+    prelude::*,
+};
+use rust_decimal::prelude::Decimal;
+
+use crate::fixtures::{self, limit};
+
+async fn init() -> (SimulatedParameters, SimulatedExchange, SimulatedExchange) {
+    let venue = fixtures::venue(fixtures::btcusd());
+    let maker = fixtures::exchange(&venue, "maker").await;
+    let taker = fixtures::exchange(&venue, "taker").await;
+    (fixtures::parameters(&venue, "maker"), maker, taker)
+}
+
+fn fill(id: &str, order: &Order, qty: i64) -> Trade {
+    Trade {
+        id: String::from(id),
+        buyer_order_id: None,
+        seller_order_id: Some(order.id.clone()),
+        market_pair: String::from("BTCUSD"),
+        instrument: None,
+        price: Decimal::new(100, 0),
+        qty: Decimal::new(qty, 0),
+        fees: None,
+        side: Side::Buy,
+        liquidity: None,
+        created_at: 0,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn tracks_orders_placed_through_it() {
+    let (_, maker, taker) = init().await;
+    let manager = OrderManager::new(maker);
+    let mut events = manager.subscribe();
+
+    let order = manager
+        .limit_sell(&limit(100, 2))
+        .await
+        .expect("Couldn't place order.");
+    assert!(matches!(events.recv().await, Some(OrderEvent::New(_))));
+    assert_eq!(manager.open_orders().expect("Couldn't read orders.").len(), 1);
+
+    taker
+        .limit_buy(&limit(100, 2))
+        .await
+        .expect("Couldn't place order.");
+    manager.reconcile().await.expect("Couldn't reconcile.");
+    assert!(matches!(events.recv().await, Some(OrderEvent::Filled { .. })));
+    match events.recv().await {
+        Some(OrderEvent::StatusChanged { previous, order }) => {
+            assert_eq!(previous, OrderStatus::Open);
+            assert_eq!(order.status, OrderStatus::Filled);
+        }
+        event => panic!("Unexpected event {:?}", event),
+    }
+
+    let tracked = manager
+        .order(&order.id)
+        .expect("Couldn't read order.")
+        .expect("Order not tracked.");
+    assert_eq!(tracked.remaining, Some(Decimal::new(0, 0)));
+    assert!(manager.open_orders().expect("Couldn't read orders.").is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn applies_fills_once() {
+    let (_, maker, _) = init().await;
+    let manager = OrderManager::new(maker);
+    let order = manager
+        .limit_sell(&limit(100, 3))
+        .await
+        .expect("Couldn't place order.");
+
+    let events = manager
+        .apply_fill(fill("1", &order, 1))
+        .expect("Couldn't apply fill.");
+    assert_eq!(events.len(), 2);
+    assert!(manager
+        .apply_fill(fill("1", &order, 1))
+        .expect("Couldn't apply fill.")
+        .is_empty());
+
+    let tracked = manager
+        .order(&order.id)
+        .expect("Couldn't read order.")
+        .expect("Order not tracked.");
+    assert_eq!(tracked.status, OrderStatus::PartiallyFilled);
+    assert_eq!(tracked.remaining, Some(Decimal::new(2, 0)));
+    assert_eq!(tracked.trades.len(), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn ignores_stale_snapshots_after_fills() {
+    let (_, maker, _) = init().await;
+    let manager = OrderManager::new(maker);
+    let order = manager
+        .limit_sell(&limit(100, 3))
+        .await
+        .expect("Couldn't place order.");
+    manager
+        .apply_fill(fill("1", &order, 1))
+        .expect("Couldn't apply fill.");
+
+    let events = manager.apply_order(order.clone()).expect("Couldn't apply order.");
+    assert!(matches!(
+        events.as_slice(),
+        [OrderEvent::InvalidTransition { status: OrderStatus::Open, .. }]
+    ));
+    let tracked = manager
+        .order(&order.id)
+        .expect("Couldn't read order.")
+        .expect("Order not tracked.");
+    assert_eq!(tracked.status, OrderStatus::PartiallyFilled);
+    assert_eq!(tracked.remaining, Some(Decimal::new(2, 0)));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn ignores_updates_out_of_final_status() {
+    let (_, maker, _) = init().await;
+    let manager = OrderManager::new(maker);
+    let order = manager
+        .limit_sell(&limit(100, 1))
+        .await
+        .expect("Couldn't place order.");
+    manager
+        .cancel_order(&CancelOrderRequest {
+            id: order.id.clone(),
+            market_pair: Some(order.market_pair.clone()),
+        })
+        .await
+        .expect("Couldn't cancel order.");
+
+    let events = manager.apply_order(order.clone()).expect("Couldn't apply order.");
+    assert!(matches!(
+        events.as_slice(),
+        [OrderEvent::InvalidTransition { status: OrderStatus::Open, .. }]
+    ));
+    let tracked = manager
+        .order(&order.id)
+        .expect("Couldn't read order.")
+        .expect("Order not tracked.");
+    assert_eq!(tracked.status, OrderStatus::Canceled);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn finds_orders_by_client_id() {
+    let (_, maker, _) = init().await;
+    let manager = OrderManager::new(maker);
+    let order = manager
+        .limit_sell(&limit(100, 1))
+        .await
+        .expect("Couldn't place order.");
+    manager
+        .apply_order(Order {
+            client_order_id: Some(String::from("client")),
+            ..order.clone()
+        })
+        .expect("Couldn't apply order.");
+
+    let tracked = manager
+        .order_by_client_id("client")
+        .expect("Couldn't read order.")
+        .expect("Order not tracked.");
+    assert_eq!(tracked.id, order.id);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reconciles_orders_placed_elsewhere() {
+    let (_, maker, _) = init().await;
+    let order = maker
+        .limit_sell(&limit(100, 1))
+        .await
+        .expect("Couldn't place order.");
+    let manager = OrderManager::new(maker);
+    assert!(manager.order(&order.id).expect("Couldn't read order.").is_none());
+
+    manager.reconcile().await.expect("Couldn't reconcile.");
+    assert!(manager.order(&order.id).expect("Couldn't read order.").is_some());
+
+    manager.prune().expect("Couldn't prune.");
+    assert_eq!(manager.orders().expect("Couldn't read orders.").len(), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn tracks_fills_of_the_trade_feed() {
+    let (parameters, maker, taker) = init().await;
+    let websocket = SimulatedWebsocket::new(parameters)
+        .await
+        .expect("Couldn't create simulated websocket.");
+    let manager = OrderManager::new(maker);
+    let order = manager
+        .limit_sell(&limit(100, 2))
+        .await
+        .expect("Couldn't place order.");
+    let mut events = manager.subscribe();
+
+    let tracker = manager.clone();
+    tokio::spawn(async move {
+        let subscriptions = [Subscription::Trades(String::from("BTCUSD"))];
+        tracker
+            .track(&websocket, &subscriptions, Duration::from_millis(10))
+            .await
+    });
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    taker
+        .limit_buy(&limit(100, 1))
+        .await
+        .expect("Couldn't place order.");
+
+    let event = tokio::time::timeout(Duration::from_secs(5), events.recv())
+        .await
+        .expect("No fill received.");
+    match event {
+        Some(OrderEvent::Filled { trade, order: filled }) => {
+            assert_eq!(filled.id, order.id);
+            assert_eq!(trade.qty, Decimal::new(1, 0));
+        }
+        event => panic!("Unexpected event {:?}", event),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn applies_order_updates_of_the_websocket() {
+    let (_, maker, _) = init().await;
+    let manager = OrderManager::new(maker);
+    let order = manager
+        .limit_sell(&limit(100, 2))
+        .await
+        .expect("Couldn't place order.");
+
+    let update = Order {
+        status: OrderStatus::PartiallyFilled,
+        remaining: Some(Decimal::new(1, 0)),
+        trades: vec![fill("1", &order, 1)],
+        ..order.clone()
+    };
+    let events = manager
+        .apply_message(OpenLimitsWebSocketMessage::Orders(vec![update]))
+        .expect("Couldn't apply message.");
+    assert!(events
+        .iter()
+        .any(|event| matches!(event, OrderEvent::Filled { .. })));
+    assert!(manager
+        .apply_message(OpenLimitsWebSocketMessage::Trades(vec![fill("1", &order, 1)]))
+        .expect("Couldn't apply message.")
+        .is_empty());
+
+    let tracked = manager
+        .order(&order.id)
+        .expect("Couldn't read order.")
+        .expect("Order not tracked.");
+    assert_eq!(tracked.status, OrderStatus::PartiallyFilled);
+    assert_eq!(tracked.trades.len(), 1);
+}
@@ -0,0 +1,377 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use openlimits::{
+    errors::{ErrorKind, MissingImplementationContent, OpenLimitsError},
+    exchange::paper::{PaperAccount, PaperExchange},
+    exchange::shared::Result,
+    exchange::traits::info::{ExchangeInfoRetrieval, MarketPair, MarketPairHandle},
+    exchange::traits::{ExchangeAccount, ExchangeMarketData},
+    model::{
+        AskBid, CancelOrderRequest, Candle, GetHistoricRatesRequest, GetHistoricTradesRequest,
+        GetOrderRequest, GetPriceTickerRequest, Liquidity, OpenLimitOrderRequest, OpenMarketOrderRequest,
+        OrderBookRequest, OrderBookResponse, OrderStatus, Side, Ticker, TimeInForce, Trade,
+        websocket::OpenLimitsWebSocketMessage,
+    },
+};
+use rust_decimal::prelude::Decimal;
+
+/// Serves a fixed order book for BTCUSD
+struct MockMarket {
+    pair: Arc<RwLock<MarketPair>>,
+    book: OrderBookResponse,
+}
+
+impl MockMarket {
+    fn new() -> Self {
+        Self {
+            pair: Arc::new(RwLock::new(MarketPair {
+                base: String::from("BTC"),
+                quote: String::from("USD"),
+                symbol: String::from("BTCUSD"),
+                base_increment: Decimal::new(1, 8),
+                quote_increment: Decimal::new(1, 2),
+                min_base_trade_size: None,
+                min_quote_trade_size: None,
+                ..Default::default()
+            })),
+            book: OrderBookResponse {
+                market_pair: None,
+                instrument: None,
+                update_id: None,
+                last_update_id: None,
+                bids: vec![
+                    AskBid::new(Decimal::new(99, 0), Decimal::new(1, 0)),
+                    AskBid::new(Decimal::new(98, 0), Decimal::new(1, 0)),
+                ],
+                asks: vec![
+                    AskBid::new(Decimal::new(100, 0), Decimal::new(1, 0)),
+                    AskBid::new(Decimal::new(101, 0), Decimal::new(1, 0)),
+                ],
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl ExchangeInfoRetrieval for MockMarket {
+    async fn get_pair(&self, _name: &str) -> Result<MarketPairHandle> {
+        Ok(MarketPairHandle::new(self.pair.clone()))
+    }
+
+    async fn retrieve_pairs(&self) -> Result<Vec<MarketPair>> {
+        Ok(vec![self.pair.read().expect("Couldn't read pair.").clone()])
+    }
+
+    async fn refresh_market_info(&self) -> Result<Vec<MarketPairHandle>> {
+        Ok(vec![MarketPairHandle::new(self.pair.clone())])
+    }
+}
+
+#[async_trait]
+impl ExchangeMarketData for MockMarket {
+    async fn order_book(&self, _req: &OrderBookRequest) -> Result<OrderBookResponse> {
+        Ok(self.book.clone())
+    }
+
+    async fn get_price_ticker(&self, _req: &GetPriceTickerRequest) -> Result<Ticker> {
+        Err(missing())
+    }
+
+    async fn get_historic_rates(&self, _req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
+        Err(missing())
+    }
+
+    async fn get_historic_trades(&self, _req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
+        Err(missing())
+    }
+}
+
+fn missing() -> OpenLimitsError {
+    OpenLimitsError::MissingImplementation(MissingImplementationContent {
+        message: String::from("Not served by the mock market"),
+    })
+}
+
+/// 1000 USD and 1 BTC, 0.1% maker and 0.2% taker fees
+fn init() -> PaperExchange<MockMarket> {
+    let mut balances = HashMap::new();
+    balances.insert(String::from("USD"), Decimal::new(1000, 0));
+    balances.insert(String::from("BTC"), Decimal::new(1, 0));
+    let account = PaperAccount::new(balances, Decimal::new(1, 3), Decimal::new(2, 3));
+
+    PaperExchange::with_account(MockMarket::new(), account)
+}
+
+fn limit(price: i64, size: Decimal, time_in_force: TimeInForce, post_only: bool) -> OpenLimitOrderRequest {
+    OpenLimitOrderRequest {
+        market_pair: String::from("BTCUSD"),
+        size,
+        price: Decimal::new(price, 0),
+        time_in_force,
+        post_only,
+    }
+}
+
+async fn balance(exchange: &PaperExchange<MockMarket>, asset: &str) -> (Decimal, Decimal) {
+    let balances = exchange
+        .get_account_balances(None)
+        .await
+        .expect("Couldn't get balances.");
+    let balance = balances
+        .into_iter()
+        .find(|balance| balance.asset == asset)
+        .expect("Couldn't find asset.");
+    (balance.total, balance.free)
+}
+
+#[tokio::test]
+async fn market_buy_walks_the_book() {
+    let exchange = init();
+    let order = exchange
+        .market_buy(&OpenMarketOrderRequest {
+            market_pair: String::from("BTCUSD"),
+            size: Decimal::new(15, 1),
+        })
+        .await
+        .expect("Couldn't market buy.");
+
+    assert_eq!(order.status, OrderStatus::Filled);
+    assert_eq!(order.trades.len(), 2);
+    assert_eq!(order.trades[0].price, Decimal::new(100, 0));
+    assert_eq!(order.trades[1].price, Decimal::new(101, 0));
+    assert_eq!(order.trades[1].qty, Decimal::new(5, 1));
+
+    // 150.5 USD plus the 0.2% taker fee
+    let (usd, _) = balance(&exchange, "USD").await;
+    assert_eq!(usd, Decimal::new(1000, 0) - Decimal::new(1505, 1) * Decimal::new(1002, 3));
+    let (btc, _) = balance(&exchange, "BTC").await;
+    assert_eq!(btc, Decimal::new(25, 1));
+}
+
+#[tokio::test]
+async fn market_buy_beyond_the_book_expires() {
+    let exchange = init();
+    let order = exchange
+        .market_buy(&OpenMarketOrderRequest {
+            market_pair: String::from("BTCUSD"),
+            size: Decimal::new(3, 0),
+        })
+        .await
+        .expect("Couldn't market buy.");
+
+    assert_eq!(order.status, OrderStatus::Expired);
+    assert_eq!(order.trades.len(), 2);
+    assert_eq!(order.remaining, Some(Decimal::new(1, 0)));
+}
+
+#[tokio::test]
+async fn market_sell_checks_the_balance() {
+    // 2 BTC are bid but the account only holds 1
+    let exchange = init();
+    let order = exchange
+        .market_sell(&OpenMarketOrderRequest {
+            market_pair: String::from("BTCUSD"),
+            size: Decimal::new(2, 0),
+        })
+        .await;
+    let err = order.expect_err("The order should need more funds.");
+    assert_eq!(err.kind(), ErrorKind::InsufficientFunds);
+
+    let order = exchange
+        .market_sell(&OpenMarketOrderRequest {
+            market_pair: String::from("BTCUSD"),
+            size: Decimal::new(1, 0),
+        })
+        .await
+        .expect("Couldn't market sell.");
+    assert_eq!(order.status, OrderStatus::Filled);
+    assert_eq!(balance(&exchange, "BTC").await.0, Decimal::new(0, 0));
+}
+
+#[tokio::test]
+async fn limit_buy_rests_until_a_trade_crosses_it() {
+    let exchange = init();
+    let order = exchange
+        .limit_buy(&limit(95, Decimal::new(2, 0), TimeInForce::GoodTillCancelled, false))
+        .await
+        .expect("Couldn't limit buy.");
+    assert_eq!(order.status, OrderStatus::Open);
+
+    let (usd, free) = balance(&exchange, "USD").await;
+    assert_eq!(usd, Decimal::new(1000, 0));
+    assert_eq!(free, Decimal::new(1000, 0) - Decimal::new(190, 0) * Decimal::new(1002, 3));
+
+    let trade = |price: i64, qty: i64| Trade {
+        id: String::from("1"),
+        buyer_order_id: None,
+        seller_order_id: None,
+        market_pair: String::from("btcusd"),
+        instrument: None,
+        price: Decimal::new(price, 0),
+        qty: Decimal::new(qty, 0),
+        fees: None,
+        side: Side::Sell,
+        liquidity: None,
+        created_at: 0,
+    };
+
+    let fills = exchange
+        .process_message(&OpenLimitsWebSocketMessage::Trades(vec![trade(96, 1)]))
+        .expect("Couldn't process trades.");
+    assert!(fills.is_empty());
+
+    let fills = exchange
+        .process_message(&OpenLimitsWebSocketMessage::Trades(vec![trade(95, 1)]))
+        .expect("Couldn't process trades.");
+    assert_eq!(fills.len(), 1);
+    assert_eq!(fills[0].liquidity, Some(Liquidity::Maker));
+    assert_eq!(fills[0].fees, Some(Decimal::new(95, 0) * Decimal::new(1, 3)));
+    let order = exchange
+        .get_order(&GetOrderRequest {
+            id: order.id.clone(),
+            market_pair: None,
+        })
+        .await
+        .expect("Couldn't get order.");
+    assert_eq!(order.status, OrderStatus::PartiallyFilled);
+
+    exchange
+        .process_message(&OpenLimitsWebSocketMessage::Trades(vec![trade(94, 5)]))
+        .expect("Couldn't process trades.");
+    let open_orders = exchange
+        .get_all_open_orders()
+        .await
+        .expect("Couldn't get open orders.");
+    assert!(open_orders.is_empty());
+
+    let (usd, free) = balance(&exchange, "USD").await;
+    assert_eq!(usd, Decimal::new(1000, 0) - Decimal::new(190, 0) * Decimal::new(1001, 3));
+    assert_eq!(usd, free);
+}
+
+#[tokio::test]
+async fn marketable_limit_sell_takes_the_book() {
+    let exchange = init();
+    let order = exchange
+        .limit_sell(&limit(99, Decimal::new(1, 0), TimeInForce::GoodTillCancelled, false))
+        .await
+        .expect("Couldn't limit sell.");
+
+    assert_eq!(order.status, OrderStatus::Filled);
+    assert_eq!(order.trades[0].liquidity, Some(Liquidity::Taker));
+}
+
+#[tokio::test]
+async fn time_in_force_and_post_only() {
+    let exchange = init();
+
+    let order = exchange
+        .limit_buy(&limit(100, Decimal::new(1, 0), TimeInForce::GoodTillCancelled, true))
+        .await
+        .expect("Couldn't limit buy.");
+    assert_eq!(order.status, OrderStatus::Rejected);
+
+    let order = exchange
+        .limit_buy(&limit(100, Decimal::new(2, 0), TimeInForce::FillOrKill, false))
+        .await
+        .expect("Couldn't limit buy.");
+    assert_eq!(order.status, OrderStatus::Expired);
+    assert!(order.trades.is_empty());
+
+    let order = exchange
+        .limit_buy(&limit(100, Decimal::new(2, 0), TimeInForce::ImmediateOrCancelled, false))
+        .await
+        .expect("Couldn't limit buy.");
+    assert_eq!(order.status, OrderStatus::Expired);
+    assert_eq!(order.remaining, Some(Decimal::new(1, 0)));
+
+    let (usd, free) = balance(&exchange, "USD").await;
+    assert_eq!(usd, free);
+}
+
+#[tokio::test]
+async fn good_till_time_needs_a_positive_duration() {
+    let exchange = init();
+
+    let time_in_force = TimeInForce::GoodTillTime(chrono::Duration::seconds(-1));
+    let result = exchange
+        .limit_buy(&limit(95, Decimal::new(1, 0), time_in_force, false))
+        .await;
+    assert!(matches!(result, Err(OpenLimitsError::InvalidParameter(_))));
+
+    let (usd, free) = balance(&exchange, "USD").await;
+    assert_eq!(usd, free);
+}
+
+#[tokio::test]
+async fn cancel_releases_the_funds() {
+    let exchange = init();
+    let order = exchange
+        .limit_sell(&limit(120, Decimal::new(1, 0), TimeInForce::GoodTillCancelled, false))
+        .await
+        .expect("Couldn't limit sell.");
+    assert_eq!(balance(&exchange, "BTC").await.1, Decimal::new(0, 0));
+
+    let canceled = exchange
+        .cancel_order(&CancelOrderRequest {
+            id: order.id.clone(),
+            market_pair: None,
+        })
+        .await
+        .expect("Couldn't cancel order.");
+    assert_eq!(canceled.id, order.id);
+    assert_eq!(balance(&exchange, "BTC").await.1, Decimal::new(1, 0));
+
+    let canceled = exchange
+        .cancel_order(&CancelOrderRequest {
+            id: order.id,
+            market_pair: None,
+        })
+        .await;
+    assert!(canceled.is_err());
+}
+
+#[tokio::test]
+async fn poll_fills_with_the_book() {
+    let mut exchange = init();
+    let order = exchange
+        .limit_buy(&limit(95, Decimal::new(1, 0), TimeInForce::GoodTillCancelled, false))
+        .await
+        .expect("Couldn't limit buy.");
+    assert_eq!(order.status, OrderStatus::Open);
+
+    exchange.market.book.asks.insert(0, AskBid::new(Decimal::new(94, 0), Decimal::new(3, 0)));
+    let fills = exchange.poll().await.expect("Couldn't poll.");
+    assert_eq!(fills.len(), 1);
+    assert_eq!(fills[0].price, Decimal::new(95, 0));
+    assert_eq!(fills[0].buyer_order_id, Some(order.id));
+}
+
+#[tokio::test]
+async fn poll_fills_with_the_same_levels_once() {
+    let mut exchange = init();
+    let order = exchange
+        .limit_buy(&limit(95, Decimal::new(2, 0), TimeInForce::GoodTillCancelled, false))
+        .await
+        .expect("Couldn't limit buy.");
+
+    exchange.market.book.asks.insert(0, AskBid::new(Decimal::new(94, 0), Decimal::new(1, 0)));
+    let fills = exchange.poll().await.expect("Couldn't poll.");
+    assert_eq!(fills.len(), 1);
+    assert!(exchange.poll().await.expect("Couldn't poll.").is_empty());
+
+    exchange.market.book.asks[0].qty = Decimal::new(3, 0);
+    let fills = exchange.poll().await.expect("Couldn't poll.");
+    assert_eq!(fills.len(), 1);
+    assert_eq!(fills[0].qty, Decimal::new(1, 0));
+    let order = exchange
+        .get_order(&GetOrderRequest {
+            id: order.id,
+            market_pair: None,
+        })
+        .await
+        .expect("Couldn't get order.");
+    assert_eq!(order.status, OrderStatus::Filled);
+}
@@ -0,0 +1,2 @@
+mod recorder;
+mod writer;
@@ -0,0 +1,98 @@
+use std::fs;
+use std::process;
+use std::sync::Arc;
+use std::time::Duration;
+
+use openlimits::{
+    exchange::simulated::{
+        ManualClock, SimulatedExchange, SimulatedParameters, SimulatedVenue, SimulatedWebsocket,
+    },
+    prelude::*,
+    model::{OpenLimitOrderRequest, TimeInForce},
+    model::websocket::{OpenLimitsWebSocketMessage, Subscription},
+    recorder::{RecordReader, RecordedPayload, Recorder, RecorderConfig, RecordingIndex},
+};
+use rust_decimal::prelude::Decimal;
+
+async fn init() -> (SimulatedExchange, SimulatedWebsocket) {
+    let venue = SimulatedVenue::new(vec![MarketPair {
+        base: String::from("BTC"),
+        quote: String::from("USD"),
+        symbol: String::from("BTCUSD"),
+        base_increment: Decimal::new(1, 3),
+        quote_increment: Decimal::new(1, 2),
+        min_base_trade_size: None,
+        min_quote_trade_size: None,
+        ..Default::default()
+    }])
+    .with_clock(Arc::new(ManualClock::new(0)));
+    let parameters = SimulatedParameters::new(venue)
+        .with_balance("BTC", Decimal::new(10, 0))
+        .with_balance("USD", Decimal::new(10000, 0));
+
+    let exchange = SimulatedExchange::new(parameters.clone())
+        .await
+        .expect("Couldn't create simulated exchange.");
+    let websocket = SimulatedWebsocket::new(parameters)
+        .await
+        .expect("Failed to create Client");
+    (exchange, websocket)
+}
+
+fn limit(price: i64) -> OpenLimitOrderRequest {
+    OpenLimitOrderRequest {
+        market_pair: String::from("BTCUSD"),
+        size: Decimal::new(1, 0),
+        price: Decimal::new(price, 0),
+        time_in_force: TimeInForce::GoodTillCancelled,
+        post_only: false,
+    }
+}
+
+#[tokio::test]
+async fn records_websocket_messages() {
+    let directory = std::env::temp_dir().join(format!("openlimits-recorder-{}", process::id()));
+    fs::remove_dir_all(&directory).ok();
+    let (exchange, websocket) = init().await;
+    let recorder = Recorder::new(RecorderConfig::new(&directory, "simulated"))
+        .expect("Couldn't create recorder");
+
+    let subscriptions = [
+        Subscription::OrderBookUpdates(String::from("BTCUSD")),
+        Subscription::Trades(String::from("BTCUSD")),
+    ];
+    let (recorded, _) = tokio::join!(recorder.record(&websocket, &subscriptions), async {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        exchange
+            .limit_buy(&limit(100))
+            .await
+            .expect("Couldn't place buy order");
+        exchange
+            .limit_sell(&limit(100))
+            .await
+            .expect("Couldn't place sell order");
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        websocket.disconnect().await;
+    });
+    recorded.expect("Recording failed");
+
+    let index = RecordingIndex::load(&directory).expect("Couldn't load index");
+    assert_eq!(index.entries.len(), 1);
+    let records = index
+        .files(Some("BTCUSD"), 0, u64::MAX)
+        .into_iter()
+        .flat_map(|file| RecordReader::open(file).expect("Couldn't open recording"))
+        .collect::<Result<Vec<_>, _>>()
+        .expect("Couldn't read recording");
+
+    assert!(records.iter().all(|record| record.exchange == "simulated"));
+    assert!(records.iter().all(|record| record.symbol.as_deref() == Some("BTCUSD")));
+    assert!(records.iter().any(|record| matches!(
+        record.payload,
+        RecordedPayload::Message(OpenLimitsWebSocketMessage::OrderBook(_))
+    )));
+    assert!(records.iter().any(|record| matches!(
+        record.payload,
+        RecordedPayload::Message(OpenLimitsWebSocketMessage::Trades(_))
+    )));
+}
@@ -0,0 +1,196 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use std::time::Duration;
+
+use openlimits::{
+    model::{AskBid, OrderBookResponse},
+    model::websocket::OpenLimitsWebSocketMessage,
+    recorder::{RecordReader, RecordWriter, RecordedMessage, RecordedPayload, RecorderConfig, RecordingIndex},
+};
+use rust_decimal::prelude::Decimal;
+
+fn directory(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("openlimits-{}-{}", name, process::id()));
+    fs::remove_dir_all(&directory).ok();
+    directory
+}
+
+fn book(price: i64) -> OrderBookResponse {
+    OrderBookResponse {
+        market_pair: None,
+        instrument: None,
+        update_id: None,
+        last_update_id: None,
+        bids: vec![AskBid {
+            price: Decimal::new(price, 0),
+            qty: Decimal::new(1, 0),
+        }],
+        asks: vec![],
+    }
+}
+
+fn record(timestamp: u64, symbol: &str, price: i64) -> RecordedMessage {
+    RecordedMessage {
+        timestamp,
+        exchange: String::from("simulated"),
+        symbol: Some(symbol.to_string()),
+        payload: RecordedPayload::Message(OpenLimitsWebSocketMessage::OrderBook(book(price))),
+    }
+}
+
+fn read(directory: &PathBuf, index: &RecordingIndex, symbol: Option<&str>) -> Vec<RecordedMessage> {
+    index
+        .files(symbol, 0, u64::MAX)
+        .into_iter()
+        .inspect(|file| assert!(file.starts_with(directory)))
+        .flat_map(|file| RecordReader::open(file).expect("Couldn't open recording"))
+        .collect::<Result<Vec<_>, _>>()
+        .expect("Couldn't read recording")
+}
+
+#[test]
+fn rotates_on_size() {
+    let directory = directory("rotates-on-size");
+    let config = RecorderConfig::new(&directory, "simulated")
+        .with_compression(false)
+        .with_max_file_size(1);
+    let mut writer = RecordWriter::new(config).expect("Couldn't create writer");
+    for timestamp in 0..3 {
+        writer
+            .write(&record(timestamp, "BTCUSD", 100))
+            .expect("Couldn't write record");
+    }
+    writer.close().expect("Couldn't close writer");
+
+    let index = RecordingIndex::load(&directory).expect("Couldn't load index");
+    assert_eq!(index.entries.len(), 3);
+    assert!(index.entries.iter().all(|entry| entry.records == 1));
+    assert_eq!(read(&directory, &index, None).len(), 3);
+}
+
+#[test]
+fn rotates_on_interval() {
+    let directory = directory("rotates-on-interval");
+    let config = RecorderConfig::new(&directory, "simulated")
+        .with_rotation_interval(Duration::from_secs(1));
+    let mut writer = RecordWriter::new(config).expect("Couldn't create writer");
+    for timestamp in &[0, 500, 1000, 1500] {
+        writer
+            .write(&record(*timestamp, "BTCUSD", 100))
+            .expect("Couldn't write record");
+    }
+    writer.close().expect("Couldn't close writer");
+
+    let index = RecordingIndex::load(&directory).expect("Couldn't load index");
+    let spans: Vec<(u64, u64)> = index.entries.iter().map(|entry| (entry.start, entry.end)).collect();
+    assert_eq!(spans, vec![(0, 500), (1000, 1500)]);
+}
+
+#[test]
+fn reads_back_compressed_and_plain_files() {
+    for compression in &[true, false] {
+        let directory = directory(&format!("reads-back-{}", compression));
+        let config = RecorderConfig::new(&directory, "simulated").with_compression(*compression);
+        let mut writer = RecordWriter::new(config).expect("Couldn't create writer");
+        let records = vec![record(1, "BTCUSD", 100), record(2, "ETHUSD", 10)];
+        for record in &records {
+            writer.write(record).expect("Couldn't write record");
+        }
+        writer.close().expect("Couldn't close writer");
+
+        let index = RecordingIndex::load(&directory).expect("Couldn't load index");
+        assert_eq!(index.entries.len(), 1);
+        assert_eq!(index.entries[0].file.ends_with(".gz"), *compression);
+        let read = read(&directory, &index, None);
+        assert_eq!(read.len(), records.len());
+        assert_eq!(read[1].symbol.as_deref(), Some("ETHUSD"));
+        assert_eq!(read[1].timestamp, 2);
+    }
+}
+
+#[test]
+fn index_finds_files_by_symbol_and_time() {
+    let directory = directory("index-queries");
+    let config = RecorderConfig::new(&directory, "simulated")
+        .with_rotation_interval(Duration::from_millis(100));
+    let mut writer = RecordWriter::new(config).expect("Couldn't create writer");
+    writer.write(&record(0, "BTCUSD", 100)).expect("Couldn't write record");
+    writer.write(&record(50, "ETHUSD", 10)).expect("Couldn't write record");
+    writer.write(&record(100, "BTCUSD", 101)).expect("Couldn't write record");
+    writer.write(&record(200, "BTCUSD", 102)).expect("Couldn't write record");
+    drop(writer);
+
+    let index = RecordingIndex::load(&directory).expect("Couldn't load index");
+    assert_eq!(index.entries.len(), 3);
+    assert_eq!(index.files(Some("BTCUSD"), 0, u64::MAX).len(), 3);
+    assert_eq!(index.files(Some("ETHUSD"), 0, u64::MAX).len(), 1);
+    assert_eq!(index.files(Some("ETHUSD"), 60, u64::MAX).len(), 0);
+    assert_eq!(index.files(Some("BTCUSD"), 100, 150).len(), 1);
+    assert_eq!(index.files(None, 40, 100).len(), 2);
+    assert!(index.files(Some("LTCUSD"), 0, u64::MAX).is_empty());
+}
+
+#[test]
+fn flushes_compressed_files() {
+    let directory = directory("flushes-compressed-files");
+    let config = RecorderConfig::new(&directory, "simulated").with_compression(true);
+    let mut writer = RecordWriter::new(config).expect("Couldn't create writer");
+    writer
+        .write(&record(0, "BTCUSD", 100))
+        .expect("Couldn't write record");
+    writer.flush().expect("Couldn't flush writer");
+
+    // The file isn't closed, its records can be read up to the flush
+    let file = fs::read_dir(&directory)
+        .expect("Couldn't read directory")
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .find(|path| path.extension().map_or(false, |extension| extension == "gz"))
+        .expect("No recording file");
+    let mut reader = RecordReader::open(file).expect("Couldn't open recording");
+    let first = reader
+        .next()
+        .expect("No record")
+        .expect("Couldn't read record");
+    assert_eq!(first.timestamp, 0);
+    let index = RecordingIndex::load(&directory).expect("Couldn't load index");
+    assert!(index.entries.is_empty());
+
+    writer.close().expect("Couldn't close writer");
+    let index = RecordingIndex::load(&directory).expect("Couldn't load index");
+    assert_eq!(read(&directory, &index, None).len(), 1);
+}
+
+#[test]
+fn reads_from_checkpoints_and_symbol_offsets() {
+    for compression in &[false, true] {
+        let directory = directory(&format!("checkpoints-{}", compression));
+        let config = RecorderConfig::new(&directory, "simulated")
+            .with_compression(*compression)
+            .with_checkpoint_interval(1);
+        let mut writer = RecordWriter::new(config).expect("Couldn't create writer");
+        writer.write(&record(0, "BTCUSD", 100)).expect("Couldn't write record");
+        writer.write(&record(10, "BTCUSD", 101)).expect("Couldn't write record");
+        writer.write(&record(20, "ETHUSD", 10)).expect("Couldn't write record");
+        writer.write(&record(30, "BTCUSD", 102)).expect("Couldn't write record");
+        writer.close().expect("Couldn't close writer");
+
+        let index = RecordingIndex::load(&directory).expect("Couldn't load index");
+        assert_eq!(index.entries[0].checkpoints.len(), 3);
+        let timestamps = |symbols: Option<&[String]>, start: u64| -> Vec<u64> {
+            index
+                .positions(symbols, start, u64::MAX)
+                .into_iter()
+                .flat_map(|(file, offset)| {
+                    RecordReader::open_at(file, offset).expect("Couldn't open recording")
+                })
+                .map(|record| record.expect("Couldn't read record").timestamp)
+                .collect()
+        };
+        assert_eq!(timestamps(None, 0), vec![0, 10, 20, 30]);
+        assert_eq!(timestamps(None, 10), vec![10, 20, 30]);
+        assert_eq!(timestamps(None, 25), vec![30]);
+        assert_eq!(timestamps(Some(&[String::from("ETHUSD")]), 0), vec![20, 30]);
+        assert!(timestamps(Some(&[String::from("LTCUSD")]), 0).is_empty());
+    }
+}
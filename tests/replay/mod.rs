@@ -0,0 +1 @@
+mod ws_streams;
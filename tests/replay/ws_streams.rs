@@ -0,0 +1,222 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use std::time::{Duration, Instant};
+
+use futures::stream::StreamExt;
+use openlimits::{
+    errors::OpenLimitsError,
+    exchange::replay::{ReplayMessage, ReplayParameters, ReplaySpeed, ReplayWs},
+    exchange::traits::stream::OpenLimitsWs,
+    prelude::*,
+    model::{AskBid, Liquidity, Side, Trade},
+    model::websocket::{OpenLimitsWebSocketMessage, Subscription, WebSocketResponse},
+    recorder::{RecordWriter, RecordedMessage, RecordedPayload, RecorderConfig},
+};
+use rust_decimal::prelude::Decimal;
+
+fn trade(id: &str, symbol: &str) -> Trade {
+    Trade {
+        id: id.to_string(),
+        buyer_order_id: None,
+        seller_order_id: None,
+        market_pair: symbol.to_string(),
+        instrument: None,
+        price: Decimal::new(100, 0),
+        qty: Decimal::new(1, 0),
+        fees: None,
+        side: Side::Buy,
+        liquidity: Some(Liquidity::Taker),
+        created_at: 0,
+    }
+}
+
+fn book() -> OrderBookResponse {
+    OrderBookResponse {
+        market_pair: None,
+        instrument: None,
+        update_id: None,
+        last_update_id: Some(1),
+        bids: vec![AskBid {
+            price: Decimal::new(99, 0),
+            qty: Decimal::new(1, 0),
+        }],
+        asks: vec![],
+    }
+}
+
+fn record(timestamp: u64, symbol: &str, payload: RecordedPayload) -> RecordedMessage {
+    RecordedMessage {
+        timestamp,
+        exchange: String::from("simulated"),
+        symbol: Some(symbol.to_string()),
+        payload,
+    }
+}
+
+fn recording(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("openlimits-replay-{}-{}", name, process::id()));
+    fs::remove_dir_all(&directory).ok();
+    let config = RecorderConfig::new(&directory, "simulated").with_rotation_interval(Duration::from_millis(250));
+    let mut writer = RecordWriter::new(config).expect("Couldn't create writer");
+    let records = vec![
+        record(0, "BTCUSD", RecordedPayload::Snapshot(book())),
+        record(100, "BTCUSD", RecordedPayload::Message(OpenLimitsWebSocketMessage::Trades(vec![trade("1", "BTCUSD")]))),
+        record(150, "ETHUSD", RecordedPayload::Message(OpenLimitsWebSocketMessage::Trades(vec![trade("2", "ETHUSD")]))),
+        record(200, "BTCUSD", RecordedPayload::Raw(serde_json::Value::from("raw"))),
+        record(300, "BTCUSD", RecordedPayload::Message(OpenLimitsWebSocketMessage::Trades(vec![trade("3", "BTCUSD")]))),
+    ];
+    for record in &records {
+        writer.write(record).expect("Couldn't write record");
+    }
+    writer.close().expect("Couldn't close writer");
+    directory
+}
+
+async fn replay(
+    parameters: ReplayParameters,
+    subscriptions: &[Subscription],
+) -> Vec<WebSocketResponse<ReplayMessage>> {
+    let ws = OpenLimitsWs::new(
+        ReplayWs::new(parameters)
+            .await
+            .expect("Failed to create Client"),
+    );
+    ws.create_stream(subscriptions)
+        .await
+        .expect("Couldn't create stream.")
+        .map(|message| message.expect("Couldn't replay message."))
+        .collect()
+        .await
+}
+
+fn trade_ids(messages: &[WebSocketResponse<ReplayMessage>]) -> Vec<String> {
+    messages
+        .iter()
+        .filter_map(|message| match message {
+            WebSocketResponse::Generic(OpenLimitsWebSocketMessage::Trades(trades)) => Some(trades),
+            _ => None,
+        })
+        .flatten()
+        .map(|trade| trade.id.clone())
+        .collect()
+}
+
+#[tokio::test]
+async fn trades() {
+    let parameters = ReplayParameters::from_directory(recording("trades"))
+        .expect("Couldn't load recording.")
+        .with_speed(ReplaySpeed::AsFastAsPossible);
+    let messages = replay(parameters, &[Subscription::Trades(String::from("BTCUSD"))]).await;
+
+    assert_eq!(trade_ids(&messages), vec!["1", "3"]);
+    assert!(messages.iter().any(|message| matches!(
+        message,
+        WebSocketResponse::Raw(ReplayMessage::Raw(raw)) if raw == "raw"
+    )));
+}
+
+#[tokio::test]
+async fn errors() {
+    let directory =
+        std::env::temp_dir().join(format!("openlimits-replay-errors-{}", process::id()));
+    fs::remove_dir_all(&directory).ok();
+    let mut writer = RecordWriter::new(RecorderConfig::new(&directory, "simulated"))
+        .expect("Couldn't create writer");
+    let error = RecordedPayload::Error(String::from("socket error"));
+    writer.write(&record(0, "BTCUSD", error)).expect("Couldn't write record");
+    writer.close().expect("Couldn't close writer");
+
+    let parameters = ReplayParameters::from_directory(&directory)
+        .expect("Couldn't load recording.")
+        .with_speed(ReplaySpeed::AsFastAsPossible);
+    let ws = OpenLimitsWs::new(
+        ReplayWs::new(parameters)
+            .await
+            .expect("Failed to create Client"),
+    );
+    let messages: Vec<_> = ws
+        .create_stream(&[Subscription::Trades(String::from("BTCUSD"))])
+        .await
+        .expect("Couldn't create stream.")
+        .collect()
+        .await;
+
+    assert_eq!(messages.len(), 1);
+    assert!(matches!(
+        &messages[0],
+        Err(OpenLimitsError::UnkownResponse(error)) if error == "socket error"
+    ));
+}
+
+#[tokio::test]
+async fn orderbook_snapshots() {
+    let parameters = ReplayParameters::from_directory(recording("orderbook"))
+        .expect("Couldn't load recording.")
+        .with_speed(ReplaySpeed::AsFastAsPossible);
+    let messages = replay(parameters, &[Subscription::OrderBookUpdates(String::from("BTCUSD"))]).await;
+
+    match messages.first() {
+        Some(WebSocketResponse::Generic(OpenLimitsWebSocketMessage::OrderBook(snapshot))) => {
+            assert_eq!(snapshot, &book())
+        }
+        message => panic!("Unexpected message {:?}", message),
+    }
+    assert!(trade_ids(&messages).is_empty());
+}
+
+#[tokio::test]
+async fn seek_and_until() {
+    let parameters = ReplayParameters::from_directory(recording("seek"))
+        .expect("Couldn't load recording.")
+        .with_speed(ReplaySpeed::AsFastAsPossible)
+        .seek(100)
+        .until(200);
+    let subscriptions = [
+        Subscription::Trades(String::from("BTCUSD")),
+        Subscription::Trades(String::from("ETHUSD")),
+    ];
+    let messages = replay(parameters.clone(), &subscriptions).await;
+    assert_eq!(trade_ids(&messages), vec!["1", "2"]);
+
+    let mut websocket = ReplayWs::new(parameters.until(u64::MAX))
+        .await
+        .expect("Failed to create Client");
+    websocket.seek(250);
+    let ws = OpenLimitsWs::new(websocket);
+    let messages: Vec<_> = ws
+        .create_stream(&subscriptions)
+        .await
+        .expect("Couldn't create stream.")
+        .filter_map(|message| async move { message.ok() })
+        .collect()
+        .await;
+    assert_eq!(trade_ids(&messages), vec!["3"]);
+}
+
+#[tokio::test]
+async fn symbol_filter() {
+    let parameters = ReplayParameters::from_directory(recording("symbols"))
+        .expect("Couldn't load recording.")
+        .with_speed(ReplaySpeed::AsFastAsPossible)
+        .with_symbols(&["ETHUSD"]);
+    let subscriptions = [
+        Subscription::Trades(String::from("BTCUSD")),
+        Subscription::Trades(String::from("ETHUSD")),
+    ];
+    let messages = replay(parameters, &subscriptions).await;
+
+    assert_eq!(trade_ids(&messages), vec!["2"]);
+}
+
+#[tokio::test]
+async fn accelerated_speed() {
+    let parameters = ReplayParameters::from_directory(recording("speed"))
+        .expect("Couldn't load recording.")
+        .with_speed(ReplaySpeed::Accelerated(2.0));
+    let started = Instant::now();
+    let messages = replay(parameters, &[Subscription::Trades(String::from("BTCUSD"))]).await;
+
+    assert_eq!(trade_ids(&messages), vec!["1", "3"]);
+    assert!(started.elapsed() >= Duration::from_millis(100));
+}
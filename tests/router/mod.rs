@@ -0,0 +1 @@
+mod smart_order_router;
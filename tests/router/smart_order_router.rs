@@ -0,0 +1,153 @@
+use openlimits::{
+    exchange::simulated::SimulatedExchange,
+    model::{OrderStatus, Side},
+    prelude::*,
+    router::{ParentOrder, RouterVenue, RoutingPolicy, SmartOrderRouter},
+};
+use rust_decimal::prelude::Decimal;
+
+use crate::fixtures::{self, limit};
+
+/// A venue with resting orders of a maker, and the client of the account routing orders
+async fn venue(
+    min_size: Option<Decimal>,
+    asks: &[(i64, i64)],
+    bids: &[(i64, i64)],
+) -> SimulatedExchange {
+    let venue = fixtures::venue(MarketPair {
+        base_increment: Decimal::new(1, 1),
+        min_base_trade_size: min_size,
+        ..fixtures::btcusd()
+    });
+    let maker = fixtures::exchange(&venue, "maker").await;
+    for (price, size) in asks {
+        maker
+            .limit_sell(&limit(*price, *size))
+            .await
+            .expect("Couldn't place sell order");
+    }
+    for (price, size) in bids {
+        maker
+            .limit_buy(&limit(*price, *size))
+            .await
+            .expect("Couldn't place buy order");
+    }
+
+    fixtures::exchange(&venue, "taker").await
+}
+
+fn sizes(plan: &openlimits::router::RoutePlan) -> Vec<(String, Decimal, Decimal)> {
+    plan.allocations
+        .iter()
+        .map(|allocation| (allocation.venue.clone(), allocation.size, allocation.price))
+        .collect()
+}
+
+#[tokio::test]
+async fn sweep_to_fill() {
+    let first = venue(None, &[(100, 1), (102, 1)], &[]).await;
+    let second = venue(None, &[(101, 1), (103, 2)], &[]).await;
+    let router = SmartOrderRouter::new(vec![
+        RouterVenue::new("first", &first, "BTCUSD"),
+        RouterVenue::new("second", &second, "BTCUSD"),
+    ]);
+    let parent = ParentOrder::new(Side::Buy, Decimal::new(25, 1), Decimal::new(1025, 1));
+
+    let plan = router.plan(&parent).await.expect("Couldn't plan order");
+    assert_eq!(
+        sizes(&plan),
+        vec![
+            (String::from("first"), Decimal::new(15, 1), Decimal::new(102, 0)),
+            (String::from("second"), Decimal::new(1, 0), Decimal::new(101, 0)),
+        ]
+    );
+    assert_eq!(plan.unfilled, Decimal::new(0, 0));
+
+    let status = router.route(&parent).await.expect("Couldn't route order");
+    assert_eq!(status.status, OrderStatus::Filled);
+    assert_eq!(status.filled, Decimal::new(25, 1));
+    assert_eq!(status.average_price, Some(Decimal::new(1008, 1)));
+    assert!(status.children.iter().all(|child| child.error.is_none()));
+
+    // The books were taken
+    let plan = router.plan(&parent).await.expect("Couldn't plan order");
+    assert_eq!(
+        sizes(&plan),
+        vec![(String::from("first"), Decimal::new(5, 1), Decimal::new(102, 0))]
+    );
+    assert_eq!(plan.unfilled, Decimal::new(2, 0));
+}
+
+#[tokio::test]
+async fn fees() {
+    let first = venue(None, &[(100, 1), (102, 1)], &[]).await;
+    let second = venue(None, &[(101, 1), (103, 2)], &[]).await;
+    let router = SmartOrderRouter::new(vec![
+        RouterVenue::new("first", &first, "BTCUSD").with_taker_fee(Decimal::new(2, 2)),
+        RouterVenue::new("second", &second, "BTCUSD"),
+    ]);
+    let parent = ParentOrder::new(Side::Buy, Decimal::new(1, 0), Decimal::new(110, 0));
+
+    let plan = router.plan(&parent).await.expect("Couldn't plan order");
+    assert_eq!(
+        sizes(&plan),
+        vec![(String::from("second"), Decimal::new(1, 0), Decimal::new(101, 0))]
+    );
+    assert_eq!(plan.cost(), Decimal::new(101, 0));
+}
+
+#[tokio::test]
+async fn best_price_only() {
+    let first = venue(None, &[(100, 1), (102, 1)], &[]).await;
+    let second = venue(None, &[(100, 2), (101, 2)], &[]).await;
+    let router = SmartOrderRouter::new(vec![
+        RouterVenue::new("first", &first, "BTCUSD"),
+        RouterVenue::new("second", &second, "BTCUSD"),
+    ]);
+    let parent = ParentOrder::new(Side::Buy, Decimal::new(5, 0), Decimal::new(110, 0))
+        .with_policy(RoutingPolicy::BestPriceOnly);
+
+    let plan = router.plan(&parent).await.expect("Couldn't plan order");
+    assert_eq!(
+        sizes(&plan),
+        vec![
+            (String::from("first"), Decimal::new(1, 0), Decimal::new(100, 0)),
+            (String::from("second"), Decimal::new(2, 0), Decimal::new(100, 0)),
+        ]
+    );
+    assert_eq!(plan.unfilled, Decimal::new(2, 0));
+}
+
+#[tokio::test]
+async fn venue_minimums() {
+    let first = venue(None, &[(100, 1), (102, 1)], &[]).await;
+    let second = venue(Some(Decimal::new(15, 1)), &[(101, 1), (103, 2)], &[]).await;
+    let router = SmartOrderRouter::new(vec![
+        RouterVenue::new("first", &first, "BTCUSD"),
+        RouterVenue::new("second", &second, "BTCUSD"),
+    ]);
+    let parent = ParentOrder::new(Side::Buy, Decimal::new(25, 1), Decimal::new(1025, 1));
+
+    let plan = router.plan(&parent).await.expect("Couldn't plan order");
+    assert_eq!(
+        sizes(&plan),
+        vec![(String::from("first"), Decimal::new(2, 0), Decimal::new(102, 0))]
+    );
+    assert_eq!(plan.unfilled, Decimal::new(5, 1));
+}
+
+#[tokio::test]
+async fn sell() {
+    let first = venue(None, &[], &[(99, 1), (97, 1)]).await;
+    let second = venue(None, &[], &[(98, 3)]).await;
+    let router = SmartOrderRouter::new(vec![
+        RouterVenue::new("first", &first, "BTCUSD"),
+        RouterVenue::new("second", &second, "BTCUSD"),
+    ]);
+    let parent = ParentOrder::new(Side::Sell, Decimal::new(3, 0), Decimal::new(98, 0));
+
+    let status = router.route(&parent).await.expect("Couldn't route order");
+    assert_eq!(status.status, OrderStatus::Filled);
+    assert_eq!(status.average_price, Some(Decimal::new(295, 0) / Decimal::new(3, 0)));
+    assert_eq!(status.children.len(), 2);
+}
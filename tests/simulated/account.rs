@@ -0,0 +1,334 @@
+use std::sync::Arc;
+
+use openlimits::{
+    exchange::simulated::{ManualClock, SimulatedExchange, SimulatedParameters, SimulatedVenue},
+    prelude::*,
+    model::{
+        Balance, CancelAllOrdersRequest, CancelOrderRequest, GetOrderHistoryRequest,
+        GetOrderRequest, Liquidity, OpenLimitOrderRequest, OpenMarketOrderRequest, OrderStatus,
+        TimeInForce, TradeHistoryRequest,
+    },
+};
+use rust_decimal::prelude::Decimal;
+
+use crate::fixtures;
+
+fn venue(clock: Arc<ManualClock>) -> SimulatedVenue {
+    SimulatedVenue::new(vec![MarketPair {
+        min_base_trade_size: Some(Decimal::new(1, 3)),
+        min_quote_trade_size: Some(Decimal::new(10, 0)),
+        ..fixtures::btcusd()
+    }])
+    .with_clock(clock)
+    .with_fees(Decimal::new(1, 3), Decimal::new(2, 3))
+}
+
+/// A maker and a taker account trading on the same venue
+async fn init() -> (SimulatedExchange, SimulatedExchange, Arc<ManualClock>) {
+    let clock = Arc::new(ManualClock::new(1_000));
+    let venue = venue(clock.clone());
+    let maker = SimulatedExchange::new(
+        SimulatedParameters::new(venue.clone())
+            .with_account("maker")
+            .with_balance("BTC", Decimal::new(10, 0))
+            .with_balance("USD", Decimal::new(10000, 0)),
+    )
+    .await
+    .expect("Couldn't create maker.");
+    let taker = SimulatedExchange::new(
+        SimulatedParameters::new(venue)
+            .with_account("taker")
+            .with_balance("BTC", Decimal::new(10, 0))
+            .with_balance("USD", Decimal::new(10000, 0)),
+    )
+    .await
+    .expect("Couldn't create taker.");
+
+    (maker, taker, clock)
+}
+
+fn limit(price: i64, size: i64, time_in_force: TimeInForce) -> OpenLimitOrderRequest {
+    OpenLimitOrderRequest {
+        time_in_force,
+        ..fixtures::limit(price, size)
+    }
+}
+
+async fn balance(exchange: &SimulatedExchange, asset: &str) -> (Decimal, Decimal) {
+    let balance = exchange
+        .get_account_balances(None)
+        .await
+        .expect("Couldn't get balances.")
+        .into_iter()
+        .find(|balance| balance.asset == asset)
+        .expect("Couldn't find asset.");
+    (balance.total, balance.free)
+}
+
+#[tokio::test]
+async fn price_time_priority() {
+    let (maker, taker, clock) = init().await;
+    let first = maker
+        .limit_sell(&limit(101, 1, TimeInForce::GoodTillCancelled))
+        .await
+        .expect("Couldn't limit sell.");
+    clock.advance(1);
+    let second = maker
+        .limit_sell(&limit(100, 1, TimeInForce::GoodTillCancelled))
+        .await
+        .expect("Couldn't limit sell.");
+    clock.advance(1);
+    let third = maker
+        .limit_sell(&limit(100, 1, TimeInForce::GoodTillCancelled))
+        .await
+        .expect("Couldn't limit sell.");
+
+    let order = taker
+        .market_buy(&OpenMarketOrderRequest {
+            market_pair: String::from("BTCUSD"),
+            size: Decimal::new(25, 1),
+        })
+        .await
+        .expect("Couldn't market buy.");
+
+    assert_eq!(order.status, OrderStatus::Filled);
+    let makers: Vec<Option<String>> = order
+        .trades
+        .iter()
+        .map(|trade| trade.seller_order_id.clone())
+        .collect();
+    assert_eq!(makers, vec![Some(second.id), Some(third.id.clone()), Some(first.id)]);
+    assert!(order
+        .trades
+        .iter()
+        .all(|trade| trade.liquidity == Some(Liquidity::Taker)));
+
+    let third = maker
+        .get_order(&GetOrderRequest {
+            id: third.id,
+            market_pair: None,
+        })
+        .await
+        .expect("Couldn't get order.");
+    assert_eq!(third.status, OrderStatus::Filled);
+}
+
+#[tokio::test]
+async fn settlement_and_fees() {
+    let (maker, taker, _) = init().await;
+    maker
+        .limit_buy(&limit(100, 2, TimeInForce::GoodTillCancelled))
+        .await
+        .expect("Couldn't limit buy.");
+    assert_eq!(
+        balance(&maker, "USD").await,
+        (Decimal::new(10000, 0), Decimal::new(10000, 0) - Decimal::new(200, 0) * Decimal::new(1002, 3))
+    );
+
+    let order = taker
+        .limit_sell(&limit(99, 2, TimeInForce::GoodTillCancelled))
+        .await
+        .expect("Couldn't limit sell.");
+    assert_eq!(order.status, OrderStatus::Filled);
+    assert_eq!(order.trades[0].price, Decimal::new(100, 0));
+
+    // The maker pays 0.1% and the taker 0.2% of 200 USD
+    assert_eq!(balance(&maker, "BTC").await, (Decimal::new(12, 0), Decimal::new(12, 0)));
+    let usd = Decimal::new(10000, 0) - Decimal::new(200, 0) * Decimal::new(1001, 3);
+    assert_eq!(balance(&maker, "USD").await, (usd, usd));
+    assert_eq!(balance(&taker, "BTC").await, (Decimal::new(8, 0), Decimal::new(8, 0)));
+    let usd = Decimal::new(10000, 0) + Decimal::new(200, 0) * Decimal::new(998, 3);
+    assert_eq!(balance(&taker, "USD").await, (usd, usd));
+
+    let trades = maker
+        .get_trade_history(&TradeHistoryRequest::default())
+        .await
+        .expect("Couldn't get trade history.");
+    assert_eq!(trades.len(), 1);
+    assert_eq!(trades[0].liquidity, Some(Liquidity::Maker));
+}
+
+#[tokio::test]
+async fn market_rules() {
+    let (maker, _, _) = init().await;
+    let mut req = limit(100, 1, TimeInForce::GoodTillCancelled);
+
+    req.price = Decimal::new(100001, 3);
+    assert!(maker.limit_buy(&req).await.is_err());
+
+    req.price = Decimal::new(100, 0);
+    req.size = Decimal::new(15, 4);
+    assert!(maker.limit_buy(&req).await.is_err());
+
+    req.size = Decimal::new(5, 2);
+    assert!(maker.limit_buy(&req).await.is_err());
+
+    req.size = Decimal::new(1000, 0);
+    assert!(maker.limit_buy(&req).await.is_err());
+
+    req.size = Decimal::new(1, 1);
+    assert!(maker.limit_buy(&req).await.is_ok());
+}
+
+#[tokio::test]
+async fn time_in_force() {
+    let (maker, taker, clock) = init().await;
+    maker
+        .limit_sell(&limit(100, 1, TimeInForce::GoodTillCancelled))
+        .await
+        .expect("Couldn't limit sell.");
+
+    let order = taker
+        .limit_buy(&limit(100, 2, TimeInForce::FillOrKill))
+        .await
+        .expect("Couldn't limit buy.");
+    assert_eq!(order.status, OrderStatus::Expired);
+    assert!(order.trades.is_empty());
+
+    let order = taker
+        .limit_buy(&OpenLimitOrderRequest {
+            post_only: true,
+            ..limit(100, 1, TimeInForce::GoodTillCancelled)
+        })
+        .await
+        .expect("Couldn't limit buy.");
+    assert_eq!(order.status, OrderStatus::Rejected);
+
+    let order = taker
+        .limit_buy(&limit(100, 2, TimeInForce::ImmediateOrCancelled))
+        .await
+        .expect("Couldn't limit buy.");
+    assert_eq!(order.status, OrderStatus::Expired);
+    assert_eq!(order.remaining, Some(Decimal::new(1, 0)));
+
+    let order = taker
+        .limit_buy(&limit(90, 1, TimeInForce::GoodTillTime(chrono::Duration::seconds(60))))
+        .await
+        .expect("Couldn't limit buy.");
+    assert_eq!(order.status, OrderStatus::Open);
+
+    clock.advance(60_000);
+    let order = taker
+        .get_order(&GetOrderRequest {
+            id: order.id,
+            market_pair: None,
+        })
+        .await
+        .expect("Couldn't get order.");
+    assert_eq!(order.status, OrderStatus::Expired);
+
+    for seconds in &[0, -60] {
+        let time_in_force = TimeInForce::GoodTillTime(chrono::Duration::seconds(*seconds));
+        assert!(taker.limit_buy(&limit(90, 1, time_in_force)).await.is_err());
+    }
+
+    let (usd, free) = balance(&taker, "USD").await;
+    assert_eq!(usd, free);
+}
+
+#[tokio::test]
+async fn cancel() {
+    let (maker, taker, _) = init().await;
+    let order = maker
+        .limit_sell(&limit(100, 1, TimeInForce::GoodTillCancelled))
+        .await
+        .expect("Couldn't limit sell.");
+    maker
+        .limit_sell(&limit(110, 1, TimeInForce::GoodTillCancelled))
+        .await
+        .expect("Couldn't limit sell.");
+
+    // Orders of other accounts can't be canceled
+    assert!(taker
+        .cancel_order(&CancelOrderRequest {
+            id: order.id.clone(),
+            market_pair: None,
+        })
+        .await
+        .is_err());
+
+    maker
+        .cancel_order(&CancelOrderRequest {
+            id: order.id,
+            market_pair: None,
+        })
+        .await
+        .expect("Couldn't cancel order.");
+    let canceled = maker
+        .cancel_all_orders(&CancelAllOrdersRequest {
+            market_pair: Some(String::from("BTCUSD")),
+        })
+        .await
+        .expect("Couldn't cancel all orders.");
+    assert_eq!(canceled.len(), 1);
+
+    let book = maker
+        .order_book(&OrderBookRequest {
+            market_pair: String::from("BTCUSD"),
+        })
+        .await
+        .expect("Couldn't get order book.");
+    assert!(book.asks.is_empty());
+
+    let history = maker
+        .get_order_history(&GetOrderHistoryRequest {
+            market_pair: None,
+            order_status: Some(vec![OrderStatus::Canceled]),
+            paginator: None,
+        })
+        .await
+        .expect("Couldn't get order history.");
+    assert_eq!(history.len(), 2);
+    assert_eq!(balance(&maker, "BTC").await.1, Decimal::new(10, 0));
+}
+
+#[tokio::test]
+async fn single_balance() {
+    let (maker, _, _) = init().await;
+    maker
+        .limit_sell(&limit(100, 2, TimeInForce::GoodTillCancelled))
+        .await
+        .expect("Couldn't limit sell.");
+
+    let btc = maker.get_balance("btc").await.expect("Couldn't get balance.");
+    assert_eq!(btc.asset, "BTC");
+    assert_eq!(btc.total, Decimal::new(10, 0));
+    assert_eq!(btc.free, Decimal::new(8, 0));
+    assert_eq!(btc.locked, Decimal::new(2, 0));
+    assert_eq!(btc.wallet, None);
+
+    let eth = maker.get_balance("ETH").await.expect("Couldn't get balance.");
+    assert_eq!(eth.asset, "ETH");
+    assert_eq!(eth.total, Decimal::new(0, 0));
+}
+
+#[test]
+fn merged_balances() {
+    let exchange = Balance {
+        asset: String::from("BTC"),
+        total: Decimal::new(3, 0),
+        free: Decimal::new(2, 0),
+        locked: Decimal::new(1, 0),
+        wallet: Some(String::from("exchange")),
+        ..Default::default()
+    };
+    let margin = Balance {
+        asset: String::from("BTC"),
+        total: Decimal::new(1, 0),
+        free: Decimal::new(1, 0),
+        borrowed: Decimal::new(5, 1),
+        wallet: Some(String::from("margin")),
+        ..Default::default()
+    };
+
+    let merged = exchange.clone().merge(margin);
+    assert_eq!(merged.total, Decimal::new(4, 0));
+    assert_eq!(merged.free, Decimal::new(3, 0));
+    assert_eq!(merged.locked, Decimal::new(1, 0));
+    assert_eq!(merged.borrowed, Decimal::new(5, 1));
+    assert_eq!(merged.wallet, None);
+    assert_eq!(
+        exchange.clone().merge(exchange).wallet,
+        Some(String::from("exchange"))
+    );
+}
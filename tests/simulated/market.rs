@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use openlimits::{
+    exchange::simulated::{ManualClock, SimulatedExchange, SimulatedParameters, SimulatedVenue},
+    prelude::*,
+    model::{
+        AskBid, GetHistoricRatesRequest, GetHistoricTradesRequest, GetPriceTickerRequest, Interval,
+        OpenLimitOrderRequest, Paginator, TimeInForce,
+    },
+};
+use rust_decimal::prelude::Decimal;
+
+async fn init() -> (SimulatedExchange, Arc<ManualClock>) {
+    let clock = Arc::new(ManualClock::new(0));
+    let venue = SimulatedVenue::new(vec![MarketPair {
+        base: String::from("ETH"),
+        quote: String::from("BTC"),
+        symbol: String::from("ETHBTC"),
+        base_increment: Decimal::new(1, 3),
+        quote_increment: Decimal::new(1, 5),
+        min_base_trade_size: None,
+        min_quote_trade_size: None,
+        ..Default::default()
+    }])
+    .with_clock(clock.clone());
+
+    let exchange = SimulatedExchange::new(
+        SimulatedParameters::new(venue)
+            .with_balance("ETH", Decimal::new(100, 0))
+            .with_balance("BTC", Decimal::new(100, 0)),
+    )
+    .await
+    .expect("Couldn't create simulated exchange.");
+
+    (exchange, clock)
+}
+
+fn limit(price: i64, size: i64) -> OpenLimitOrderRequest {
+    OpenLimitOrderRequest {
+        market_pair: String::from("ETHBTC"),
+        size: Decimal::new(size, 0),
+        price: Decimal::new(price, 3),
+        time_in_force: TimeInForce::GoodTillCancelled,
+        post_only: false,
+    }
+}
+
+/// Trades `size` at `price` against itself
+async fn trade(exchange: &SimulatedExchange, price: i64, size: i64) {
+    exchange.limit_sell(&limit(price, size)).await.expect("Couldn't limit sell.");
+    exchange.limit_buy(&limit(price, size)).await.expect("Couldn't limit buy.");
+}
+
+#[tokio::test]
+async fn order_book() {
+    let (exchange, _) = init().await;
+    exchange.limit_buy(&limit(50, 1)).await.expect("Couldn't limit buy.");
+    exchange.limit_buy(&limit(50, 2)).await.expect("Couldn't limit buy.");
+    exchange.limit_buy(&limit(49, 1)).await.expect("Couldn't limit buy.");
+    exchange.limit_sell(&limit(52, 1)).await.expect("Couldn't limit sell.");
+    exchange.limit_sell(&limit(51, 1)).await.expect("Couldn't limit sell.");
+
+    let book = exchange
+        .order_book(&OrderBookRequest {
+            market_pair: String::from("ETHBTC"),
+        })
+        .await
+        .expect("Couldn't get order book.");
+
+    assert_eq!(
+        book.bids,
+        vec![
+            AskBid::new(Decimal::new(50, 3), Decimal::new(3, 0)),
+            AskBid::new(Decimal::new(49, 3), Decimal::new(1, 0)),
+        ]
+    );
+    assert_eq!(
+        book.asks,
+        vec![
+            AskBid::new(Decimal::new(51, 3), Decimal::new(1, 0)),
+            AskBid::new(Decimal::new(52, 3), Decimal::new(1, 0)),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn unknown_market() {
+    let (exchange, _) = init().await;
+    let book = exchange
+        .order_book(&OrderBookRequest {
+            market_pair: String::from("BTCUSD"),
+        })
+        .await;
+    assert!(book.is_err());
+    assert!(exchange.get_pair("BTCUSD").await.is_err());
+    assert!(exchange.get_pair("ETHBTC").await.is_ok());
+}
+
+#[tokio::test]
+async fn ticker_and_history() {
+    let (exchange, clock) = init().await;
+    trade(&exchange, 50, 1).await;
+    clock.advance(30_000);
+    trade(&exchange, 53, 2).await;
+    clock.advance(60_000);
+    trade(&exchange, 48, 1).await;
+
+    let ticker = exchange
+        .get_price_ticker(&GetPriceTickerRequest {
+            market_pair: String::from("ETHBTC"),
+        })
+        .await
+        .expect("Couldn't get ticker.");
+    assert_eq!(ticker.price, Some(Decimal::new(48, 3)));
+    assert_eq!(ticker.price_24h, Some(Decimal::new(50, 3)));
+
+    let trades = exchange
+        .get_historic_trades(&GetHistoricTradesRequest {
+            market_pair: String::from("ETHBTC"),
+            paginator: Some(Paginator {
+                limit: Some(2),
+                ..Default::default()
+            }),
+        })
+        .await
+        .expect("Couldn't get historic trades.");
+    assert_eq!(trades.len(), 2);
+    assert_eq!(trades[1].created_at, 90_000);
+
+    let candles = exchange
+        .get_historic_rates(&GetHistoricRatesRequest {
+            market_pair: String::from("ETHBTC"),
+            paginator: None,
+            interval: Interval::OneMinute,
+        })
+        .await
+        .expect("Couldn't get historic rates.");
+    assert_eq!(candles.len(), 2);
+    assert_eq!(candles[0].time, 0);
+    assert_eq!(candles[0].open, Decimal::new(50, 3));
+    assert_eq!(candles[0].high, Decimal::new(53, 3));
+    assert_eq!(candles[0].close, Decimal::new(53, 3));
+    assert_eq!(candles[0].volume, Decimal::new(3, 0));
+    assert_eq!(candles[1].time, 60_000);
+    assert_eq!(candles[1].low, Decimal::new(48, 3));
+}
@@ -0,0 +1,3 @@
+mod account;
+mod market;
+mod ws_streams;
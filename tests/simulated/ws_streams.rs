@@ -0,0 +1,70 @@
+use futures::stream::StreamExt;
+use openlimits::{
+    exchange::simulated::{SimulatedExchange, SimulatedWebsocket},
+    prelude::*,
+    model::websocket::{OpenLimitsWebSocketMessage, Subscription, WebSocketResponse},
+};
+use openlimits::exchange::traits::stream::OpenLimitsWs;
+use rust_decimal::prelude::Decimal;
+
+use crate::fixtures::{self, limit};
+
+async fn init() -> (SimulatedExchange, OpenLimitsWs<SimulatedWebsocket>) {
+    let parameters = fixtures::parameters(&fixtures::venue(fixtures::btcusd()), "default");
+
+    let exchange = SimulatedExchange::new(parameters.clone())
+        .await
+        .expect("Couldn't create simulated exchange.");
+    let ws = OpenLimitsWs::new(
+        SimulatedWebsocket::new(parameters)
+            .await
+            .expect("Failed to create Client"),
+    );
+    (exchange, ws)
+}
+
+#[tokio::test]
+async fn orderbook() {
+    let (exchange, ws) = init().await;
+    let mut s = ws
+        .create_stream(&[Subscription::OrderBookUpdates("BTCUSD".to_string())])
+        .await
+        .expect("Couldn't create stream.");
+
+    match s.next().await {
+        Some(Ok(WebSocketResponse::Generic(OpenLimitsWebSocketMessage::OrderBook(book)))) => {
+            assert!(book.bids.is_empty())
+        }
+        message => panic!("Unexpected message {:?}", message),
+    }
+
+    exchange.limit_buy(&limit(100, 1)).await.expect("Couldn't limit buy.");
+    match s.next().await {
+        Some(Ok(WebSocketResponse::Generic(OpenLimitsWebSocketMessage::OrderBook(book)))) => {
+            assert_eq!(book.bids.len(), 1)
+        }
+        message => panic!("Unexpected message {:?}", message),
+    }
+}
+
+#[tokio::test]
+async fn trades() {
+    let (exchange, ws) = init().await;
+    let mut s = ws
+        .create_stream(&[Subscription::Trades("BTCUSD".to_string())])
+        .await
+        .expect("Couldn't create stream.");
+
+    exchange.limit_sell(&limit(100, 1)).await.expect("Couldn't limit sell.");
+    exchange.limit_buy(&limit(100, 1)).await.expect("Couldn't limit buy.");
+    match s.next().await {
+        Some(Ok(WebSocketResponse::Generic(OpenLimitsWebSocketMessage::Trades(trades)))) => {
+            assert_eq!(trades.len(), 1);
+            assert_eq!(trades[0].price, Decimal::new(100, 0));
+        }
+        message => panic!("Unexpected message {:?}", message),
+    }
+
+    ws.disconnect().await;
+    assert!(s.next().await.is_none());
+}
@@ -0,0 +1,223 @@
+use openlimits::{
+    exchange::shared::Result,
+    model::{OpenLimitOrderRequest, OpenMarketOrderRequest, TimeInForce},
+    prelude::*,
+    validation::{MarketRule, MarketRules, OrderContext, OrderValidator, Rounding, StepRange},
+};
+use rust_decimal::prelude::Decimal;
+
+fn rules() -> MarketRules {
+    MarketRules::new()
+        .with_lot_size(StepRange::new(
+            Decimal::new(1, 2),
+            Decimal::new(100, 0),
+            Decimal::new(1, 2),
+        ))
+        .with_market_lot_size(StepRange::new(
+            Decimal::new(1, 1),
+            Decimal::new(10, 0),
+            Decimal::new(1, 1),
+        ))
+        .with_price(StepRange::new(
+            Decimal::new(1, 0),
+            Decimal::new(0, 0),
+            Decimal::new(5, 1),
+        ))
+        .with_min_notional(Decimal::new(10, 0))
+        .with_percent_price(Decimal::new(5, 0), Decimal::new(2, 1))
+        .with_max_num_orders(2)
+        .with_iceberg_parts(10)
+}
+
+fn limit(size: Decimal, price: Decimal) -> OpenLimitOrderRequest {
+    OpenLimitOrderRequest {
+        market_pair: String::from("BTCUSDT"),
+        size,
+        price,
+        time_in_force: TimeInForce::GoodTillCancelled,
+        post_only: false,
+    }
+}
+
+fn market(size: Decimal) -> OpenMarketOrderRequest {
+    OpenMarketOrderRequest {
+        market_pair: String::from("BTCUSDT"),
+        size,
+    }
+}
+
+/// The rule a limit order violates without any market state
+fn rejected(validator: &OrderValidator, size: Decimal, price: Decimal) -> Option<MarketRule> {
+    violation(validator.validate_limit(&limit(size, price), &OrderContext::new()))
+}
+
+fn violation<T: std::fmt::Debug>(result: Result<T>) -> Option<MarketRule> {
+    MarketRule::violated_by(&result.expect_err("The order should be rejected."))
+}
+
+#[test]
+fn accepts_valid_orders() {
+    let validator = OrderValidator::new(rules());
+    let req = limit(Decimal::new(15, 1), Decimal::new(100, 0));
+    let validated = validator
+        .validate_limit(&req, &OrderContext::new())
+        .expect("The order should be accepted.");
+    assert_eq!(validated, req);
+}
+
+#[test]
+fn rejects_sizes_and_prices_off_their_increment() {
+    let validator = OrderValidator::new(rules());
+    assert_eq!(
+        rejected(&validator, Decimal::new(1505, 3), Decimal::new(100, 0)),
+        Some(MarketRule::LotSize)
+    );
+    assert_eq!(
+        rejected(&validator, Decimal::new(1, 0), Decimal::new(1002, 1)),
+        Some(MarketRule::PriceFilter)
+    );
+}
+
+#[test]
+fn rounds_as_configured() {
+    let req = limit(Decimal::new(1505, 3), Decimal::new(1002, 1));
+    let down = OrderValidator::new(rules())
+        .with_rounding(Rounding::Down)
+        .validate_limit(&req, &OrderContext::new())
+        .expect("The order should be accepted.");
+    assert_eq!(down.size, Decimal::new(15, 1));
+    assert_eq!(down.price, Decimal::new(100, 0));
+
+    let up = OrderValidator::new(rules())
+        .with_rounding(Rounding::Up)
+        .validate_limit(&req, &OrderContext::new())
+        .expect("The order should be accepted.");
+    assert_eq!(up.size, Decimal::new(151, 2));
+    assert_eq!(up.price, Decimal::new(1005, 1));
+}
+
+#[test]
+fn steps_from_the_minimum() {
+    let rules = MarketRules::new().with_price(StepRange::new(
+        Decimal::new(3, 1),
+        Decimal::new(0, 0),
+        Decimal::new(5, 1),
+    ));
+    let validator = OrderValidator::new(rules.clone());
+    let req = limit(Decimal::new(1, 0), Decimal::new(13, 1));
+    let validated = validator
+        .validate_limit(&req, &OrderContext::new())
+        .expect("The order should be accepted.");
+    assert_eq!(validated, req);
+    assert_eq!(
+        rejected(&validator, Decimal::new(1, 0), Decimal::new(15, 1)),
+        Some(MarketRule::PriceFilter)
+    );
+
+    let down = OrderValidator::new(rules)
+        .with_rounding(Rounding::Down)
+        .validate_limit(&limit(Decimal::new(1, 0), Decimal::new(15, 1)), &OrderContext::new())
+        .expect("The order should be accepted.");
+    assert_eq!(down.price, Decimal::new(13, 1));
+}
+
+#[test]
+fn rejects_sizes_rounded_to_zero() {
+    let rules = MarketRules::new().with_lot_size(StepRange::new(
+        Decimal::new(0, 0),
+        Decimal::new(0, 0),
+        Decimal::new(1, 2),
+    ));
+    let validator = OrderValidator::new(rules).with_rounding(Rounding::Down);
+    assert_eq!(
+        rejected(&validator, Decimal::new(5, 3), Decimal::new(100, 0)),
+        Some(MarketRule::LotSize)
+    );
+}
+
+#[test]
+fn checks_bounds_and_notional() {
+    let validator = OrderValidator::new(rules()).with_rounding(Rounding::Down);
+    assert_eq!(
+        rejected(&validator, Decimal::new(101, 0), Decimal::new(100, 0)),
+        Some(MarketRule::LotSize)
+    );
+    assert_eq!(
+        rejected(&validator, Decimal::new(1, 3), Decimal::new(100, 0)),
+        Some(MarketRule::LotSize)
+    );
+    assert_eq!(
+        rejected(&validator, Decimal::new(1, 0), Decimal::new(5, 1)),
+        Some(MarketRule::PriceFilter)
+    );
+    assert_eq!(
+        rejected(&validator, Decimal::new(5, 2), Decimal::new(100, 0)),
+        Some(MarketRule::MinNotional)
+    );
+}
+
+#[test]
+fn checks_the_market_state_when_known() {
+    let validator = OrderValidator::new(rules());
+    let req = limit(Decimal::new(1, 0), Decimal::new(1000, 0));
+    validator
+        .validate_limit(&req, &OrderContext::new())
+        .expect("The order should be accepted.");
+
+    let context = OrderContext::new().with_average_price(Decimal::new(100, 0));
+    assert_eq!(violation(validator.validate_limit(&req, &context)), Some(MarketRule::PercentPrice));
+
+    let req = limit(Decimal::new(1, 0), Decimal::new(100, 0));
+    let context = context.with_open_orders(2);
+    assert_eq!(violation(validator.validate_limit(&req, &context)), Some(MarketRule::MaxNumOrders));
+
+    let context = OrderContext::new().with_iceberg_qty(Decimal::new(5, 2));
+    assert_eq!(violation(validator.validate_limit(&req, &context)), Some(MarketRule::IcebergParts));
+    let context = OrderContext::new().with_iceberg_qty(Decimal::new(1, 1));
+    validator
+        .validate_limit(&req, &context)
+        .expect("The order should be accepted.");
+}
+
+#[test]
+fn checks_market_orders() {
+    let validator = OrderValidator::new(rules());
+    assert_eq!(
+        violation(validator.validate_market(&market(Decimal::new(11, 0)), &OrderContext::new())),
+        Some(MarketRule::MarketLotSize)
+    );
+    assert_eq!(
+        violation(validator.validate_market(&market(Decimal::new(15, 2)), &OrderContext::new())),
+        Some(MarketRule::MarketLotSize)
+    );
+
+    let req = market(Decimal::new(1, 0));
+    validator
+        .validate_market(&req, &OrderContext::new())
+        .expect("The order should be accepted.");
+    let context = OrderContext::new().with_average_price(Decimal::new(5, 0));
+    assert_eq!(violation(validator.validate_market(&req, &context)), Some(MarketRule::MinNotional));
+}
+
+#[test]
+fn derives_rules_from_market_pairs() {
+    let pair = MarketPair {
+        base: String::from("BTC"),
+        quote: String::from("USDT"),
+        symbol: String::from("BTCUSDT"),
+        base_increment: Decimal::new(1, 3),
+        quote_increment: Decimal::new(1, 2),
+        min_base_trade_size: Some(Decimal::new(1, 2)),
+        min_quote_trade_size: None,
+        ..Default::default()
+    };
+    let validator = OrderValidator::new(MarketRules::from(&pair));
+    let context = OrderContext::new();
+    validator
+        .validate_limit(&limit(Decimal::new(15, 3), Decimal::new(10001, 2)), &context)
+        .expect("The order should be accepted.");
+    assert_eq!(
+        rejected(&validator, Decimal::new(5, 3), Decimal::new(100, 0)),
+        Some(MarketRule::LotSize)
+    );
+}